@@ -0,0 +1,49 @@
+//! Locales supported by [Alexa skills](https://developer.amazon.com/en-US/docs/alexa/custom-skills/develop-skills-in-multiple-languages.html).
+//!
+//! Alexa identifies every language by a locale, and only supports a few regions of each
+//! language.
+//!
+//! # Examples
+//!
+//! ```
+//! use isolanguage_1::{alexa, LanguageCode, Locale};
+//!
+//! assert_eq!(alexa::code(LanguageCode::En), Some("en-US"));
+//! assert_eq!(alexa::locale("en-GB".parse().unwrap()), Some("en-GB"));
+//! assert_eq!(alexa::locale("en-NZ".parse().unwrap()), Some("en-US"));
+//! assert_eq!(alexa::parse_locale("fr-ca"), "fr-CA".parse::<Locale>().ok());
+//! assert!(!alexa::LANGUAGES.contains(LanguageCode::Ko));
+//! ```
+
+voice_locales! {
+    "Alexa",
+    (Ar, ["ar-SA"]),
+    (Nl, ["nl-NL"]),
+    (En, ["en-US", "en-AU", "en-CA", "en-GB", "en-IN"]),
+    (Fr, ["fr-FR", "fr-CA"]),
+    (De, ["de-DE"]),
+    (Hi, ["hi-IN"]),
+    (It, ["it-IT"]),
+    (Ja, ["ja-JP"]),
+    (Pt, ["pt-BR"]),
+    (Es, ["es-ES", "es-MX", "es-US"]),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{code, locale, parse_locale, LANGUAGES, LOCALES};
+    use crate::{LanguageCode, Locale};
+
+    #[test]
+    fn locales() {
+        assert_eq!(LOCALES.len(), 17);
+        for &code in LOCALES {
+            let parsed = parse_locale(code).unwrap();
+            assert!(LANGUAGES.contains(parsed.language));
+            assert_eq!(locale(parsed), Some(code));
+        }
+        assert_eq!(code(LanguageCode::Es), Some("es-ES"));
+        assert_eq!(locale(Locale::from(LanguageCode::Ko)), None);
+        assert_eq!(parse_locale("de-AT"), None);
+    }
+}
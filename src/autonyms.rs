@@ -0,0 +1,245 @@
+//! Looking up languages by their names in the languages themselves.
+
+use crate::{maps, LanguageCode};
+
+impl LanguageCode {
+    /// Looks up a language by its name in that language, like `Deutsch` or `日本語`, ignoring case
+    /// and surrounding whitespace.
+    ///
+    /// Both [`autonym`](Self::autonym) and the names in CLDR's display names are recognized.
+    /// `isiNdebele`, the autonym of both North and South Ndebele, is ambiguous and isn't.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_autonym("Deutsch"), Some(LanguageCode::De));
+    /// assert_eq!(LanguageCode::from_autonym("Español"), Some(LanguageCode::Es));
+    /// assert_eq!(LanguageCode::from_autonym("日本語"), Some(LanguageCode::Ja));
+    /// assert_eq!(LanguageCode::from_autonym("German"), None);
+    /// ```
+    #[must_use]
+    pub fn from_autonym(name: &str) -> Option<Self> {
+        maps::get(&AUTONYM_TO_LANGUAGE, &name.trim().to_lowercase())
+    }
+}
+
+/// Every unambiguous autonym in lowercase, sorted in byte order. Generated from
+/// [`LanguageCode::autonym`] and the CLDR 45 display names.
+#[rustfmt::skip]
+static AUTONYM_TO_LANGUAGE: [(&str, LanguageCode); 168] = [
+    ("afrikaans", LanguageCode::Af),
+    ("akan", LanguageCode::Ak),
+    ("aragonés", LanguageCode::An),
+    ("azərbaycan", LanguageCode::Az),
+    ("bahasa indonesia", LanguageCode::Id),
+    ("bamanakan", LanguageCode::Bm),
+    ("basa jawa", LanguageCode::Jv),
+    ("basa sunda", LanguageCode::Su),
+    ("bislama", LanguageCode::Bi),
+    ("bosanski", LanguageCode::Bs),
+    ("brezhoneg", LanguageCode::Br),
+    ("català", LanguageCode::Ca),
+    ("chamorru", LanguageCode::Ch),
+    ("chichewa", LanguageCode::Ny),
+    ("chishona", LanguageCode::Sn),
+    ("corsu", LanguageCode::Co),
+    ("cymraeg", LanguageCode::Cy),
+    ("dansk", LanguageCode::Da),
+    ("davvisámegiella", LanguageCode::Se),
+    ("deutsch", LanguageCode::De),
+    ("diné", LanguageCode::Nv),
+    ("eesti", LanguageCode::Et),
+    ("english", LanguageCode::En),
+    ("español", LanguageCode::Es),
+    ("esperanto", LanguageCode::Eo),
+    ("euskara", LanguageCode::Eu),
+    ("eʋegbe", LanguageCode::Ee),
+    ("français", LanguageCode::Fr),
+    ("frysk", LanguageCode::Fy),
+    ("føroyskt", LanguageCode::Fo),
+    ("gaeilge", LanguageCode::Ga),
+    ("gaelg", LanguageCode::Gv),
+    ("gagana samoa", LanguageCode::Sm),
+    ("galego", LanguageCode::Gl),
+    ("gikuyu", LanguageCode::Ki),
+    ("gàidhlig", LanguageCode::Gd),
+    ("hausa", LanguageCode::Ha),
+    ("hrvatski", LanguageCode::Hr),
+    ("igbo", LanguageCode::Ig),
+    ("ikinyarwanda", LanguageCode::Rw),
+    ("ikirundi", LanguageCode::Rn),
+    ("indonesia", LanguageCode::Id),
+    ("interlingua", LanguageCode::Ia),
+    ("interlingue", LanguageCode::Ie),
+    ("isixhosa", LanguageCode::Xh),
+    ("isizulu", LanguageCode::Zu),
+    ("italiano", LanguageCode::It),
+    ("jawa", LanguageCode::Jv),
+    ("kalaallisut", LanguageCode::Kl),
+    ("kernewek", LanguageCode::Kw),
+    ("kinyarwanda", LanguageCode::Rw),
+    ("kiswahili", LanguageCode::Sw),
+    ("kreyòl ayisyen", LanguageCode::Ht),
+    ("kurdî", LanguageCode::Ku),
+    ("kurdî (kurmancî)", LanguageCode::Ku),
+    ("latviešu", LanguageCode::Lv),
+    ("lea fakatonga", LanguageCode::To),
+    ("lietuvių", LanguageCode::Lt),
+    ("limburgs", LanguageCode::Li),
+    ("lingála", LanguageCode::Ln),
+    ("luganda", LanguageCode::Lg),
+    ("lëtzebuergesch", LanguageCode::Lb),
+    ("magyar", LanguageCode::Hu),
+    ("malagasy", LanguageCode::Mg),
+    ("malti", LanguageCode::Mt),
+    ("melayu", LanguageCode::Ms),
+    ("māori", LanguageCode::Mi),
+    ("nederlands", LanguageCode::Nl),
+    ("norsk", LanguageCode::No),
+    ("norsk bokmål", LanguageCode::Nb),
+    ("norsk nynorsk", LanguageCode::Nn),
+    ("nynorsk", LanguageCode::Nn),
+    ("occitan", LanguageCode::Oc),
+    ("oromoo", LanguageCode::Om),
+    ("oshikwanyama", LanguageCode::Kj),
+    ("o‘zbek", LanguageCode::Uz),
+    ("polski", LanguageCode::Pl),
+    ("português", LanguageCode::Pt),
+    ("pulaar", LanguageCode::Ff),
+    ("qafar", LanguageCode::Aa),
+    ("reo tahiti", LanguageCode::Ty),
+    ("română", LanguageCode::Ro),
+    ("rumantsch", LanguageCode::Rm),
+    ("runasimi", LanguageCode::Qu),
+    ("sardu", LanguageCode::Sc),
+    ("sesotho", LanguageCode::St),
+    ("setswana", LanguageCode::Tn),
+    ("shqip", LanguageCode::Sq),
+    ("siswati", LanguageCode::Ss),
+    ("slovenčina", LanguageCode::Sk),
+    ("slovenščina", LanguageCode::Sl),
+    ("soomaali", LanguageCode::So),
+    ("suomi", LanguageCode::Fi),
+    ("svenska", LanguageCode::Sv),
+    ("sängö", LanguageCode::Sg),
+    ("tagalog", LanguageCode::Tl),
+    ("te reo māori", LanguageCode::Mi),
+    ("tiếng việt", LanguageCode::Vi),
+    ("tshiluba", LanguageCode::Lu),
+    ("tshivenḓa", LanguageCode::Ve),
+    ("türkmen dili", LanguageCode::Tk),
+    ("türkçe", LanguageCode::Tr),
+    ("vahcuengh", LanguageCode::Za),
+    ("volapük", LanguageCode::Vo),
+    ("wolof", LanguageCode::Wo),
+    ("xitsonga", LanguageCode::Ts),
+    ("èdè yorùbá", LanguageCode::Yo),
+    ("íslenska", LanguageCode::Is),
+    ("čeština", LanguageCode::Cs),
+    ("ελληνικά", LanguageCode::El),
+    ("авар мацӏ", LanguageCode::Av),
+    ("аҧсуа бызшәа", LanguageCode::Ab),
+    ("башҡорт теле", LanguageCode::Ba),
+    ("беларуская", LanguageCode::Be),
+    ("български", LanguageCode::Bg),
+    ("ирон", LanguageCode::Os),
+    ("кыргызча", LanguageCode::Ky),
+    ("македонски", LanguageCode::Mk),
+    ("монгол", LanguageCode::Mn),
+    ("нохчийн", LanguageCode::Ce),
+    ("русский", LanguageCode::Ru),
+    ("српски", LanguageCode::Sr),
+    ("татар", LanguageCode::Tt),
+    ("тоҷикӣ", LanguageCode::Tg),
+    ("українська", LanguageCode::Uk),
+    ("церковнослове́нскїй", LanguageCode::Cu),
+    ("чӑваш", LanguageCode::Cv),
+    ("чӑвашла", LanguageCode::Cv),
+    ("қазақ тілі", LanguageCode::Kk),
+    ("հայերեն", LanguageCode::Hy),
+    ("ייִדיש", LanguageCode::Yi),
+    ("עברית", LanguageCode::He),
+    ("ئۇيغۇرچە", LanguageCode::Ug),
+    ("اردو", LanguageCode::Ur),
+    ("العربية", LanguageCode::Ar),
+    ("سنڌي", LanguageCode::Sd),
+    ("فارسی", LanguageCode::Fa),
+    ("پښتو", LanguageCode::Ps),
+    ("کٲشُر", LanguageCode::Ks),
+    ("ދިވެހިބަސް", LanguageCode::Dv),
+    ("नेपाली", LanguageCode::Ne),
+    ("मराठी", LanguageCode::Mr),
+    ("संस्कृत भाषा", LanguageCode::Sa),
+    ("हिन्दी", LanguageCode::Hi),
+    ("অসমীয়া", LanguageCode::As),
+    ("বাংলা", LanguageCode::Bn),
+    ("ਪੰਜਾਬੀ", LanguageCode::Pa),
+    ("ગુજરાતી", LanguageCode::Gu),
+    ("ଓଡ଼ିଆ", LanguageCode::Or),
+    ("தமிழ்", LanguageCode::Ta),
+    ("తెలుగు", LanguageCode::Te),
+    ("ಕನ್ನಡ", LanguageCode::Kn),
+    ("മലയാളം", LanguageCode::Ml),
+    ("සිංහල", LanguageCode::Si),
+    ("ไทย", LanguageCode::Th),
+    ("ລາວ", LanguageCode::Lo),
+    ("བོད་སྐད་", LanguageCode::Bo),
+    ("རྫོང་ཁ", LanguageCode::Dz),
+    ("မြန်မာ", LanguageCode::My),
+    ("ქართული", LanguageCode::Ka),
+    ("ትግርኛ", LanguageCode::Ti),
+    ("አማርኛ", LanguageCode::Am),
+    ("ᐃᓄᒃᑎᑐᑦ", LanguageCode::Iu),
+    ("ខ្មែរ", LanguageCode::Km),
+    ("中文", LanguageCode::Zh),
+    ("日本語", LanguageCode::Ja),
+    ("ꆈꌠꉙ", LanguageCode::Ii),
+    ("한국어", LanguageCode::Ko),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::AUTONYM_TO_LANGUAGE;
+    use crate::LanguageCode;
+
+    #[test]
+    fn table() {
+        assert!(AUTONYM_TO_LANGUAGE
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+        for &(autonym, _) in &AUTONYM_TO_LANGUAGE {
+            assert_eq!(autonym, autonym.to_lowercase());
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for language in LanguageCode::iter() {
+            if let Some(autonym) = language.autonym() {
+                if autonym != "isiNdebele" {
+                    assert_eq!(LanguageCode::from_autonym(autonym), Some(language));
+                }
+            }
+        }
+        assert_eq!(LanguageCode::from_autonym("isiNdebele"), None);
+    }
+
+    #[test]
+    fn from_autonym() {
+        assert_eq!(
+            LanguageCode::from_autonym("  NEDERLANDS "),
+            Some(LanguageCode::Nl)
+        );
+        assert_eq!(
+            LanguageCode::from_autonym("ΕΛΛΗΝΙΚΆ"),
+            Some(LanguageCode::El)
+        );
+        assert_eq!(
+            LanguageCode::from_autonym("русский"),
+            Some(LanguageCode::Ru)
+        );
+        assert_eq!(LanguageCode::from_autonym(""), None);
+    }
+}
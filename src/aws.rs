@@ -0,0 +1,103 @@
+//! Language identifiers used by [Amazon Translate](https://docs.aws.amazon.com/translate/latest/dg/what-is-languages.html).
+//!
+//! # Examples
+//!
+//! ```
+//! use isolanguage_1::{aws, LanguageCode};
+//!
+//! assert_eq!(aws::code(LanguageCode::Zh), Some("zh"));
+//! assert_eq!(aws::codes(LanguageCode::Zh), ["zh", "zh-TW"]);
+//! assert_eq!(aws::from_code("fa-AF"), Some(LanguageCode::Fa));
+//! ```
+
+provider_codes! {
+    "Amazon Translate",
+    (Af, ["af"]),
+    (Sq, ["sq"]),
+    (Am, ["am"]),
+    (Ar, ["ar"]),
+    (Hy, ["hy"]),
+    (Az, ["az"]),
+    (Bn, ["bn"]),
+    (Bs, ["bs"]),
+    (Bg, ["bg"]),
+    (Ca, ["ca"]),
+    (Zh, ["zh", "zh-TW"]),
+    (Hr, ["hr"]),
+    (Cs, ["cs"]),
+    (Da, ["da"]),
+    (Nl, ["nl"]),
+    (En, ["en"]),
+    (Et, ["et"]),
+    (Fa, ["fa", "fa-AF"]),
+    (Tl, ["tl"]),
+    (Fi, ["fi"]),
+    (Fr, ["fr", "fr-CA"]),
+    (Ka, ["ka"]),
+    (De, ["de"]),
+    (El, ["el"]),
+    (Gu, ["gu"]),
+    (Ht, ["ht"]),
+    (Ha, ["ha"]),
+    (He, ["he"]),
+    (Hi, ["hi"]),
+    (Hu, ["hu"]),
+    (Is, ["is"]),
+    (Id, ["id"]),
+    (Ga, ["ga"]),
+    (It, ["it"]),
+    (Ja, ["ja"]),
+    (Kn, ["kn"]),
+    (Kk, ["kk"]),
+    (Ko, ["ko"]),
+    (Lv, ["lv"]),
+    (Lt, ["lt"]),
+    (Mk, ["mk"]),
+    (Ms, ["ms"]),
+    (Ml, ["ml"]),
+    (Mt, ["mt"]),
+    (Mr, ["mr"]),
+    (Mn, ["mn"]),
+    (No, ["no"]),
+    (Ps, ["ps"]),
+    (Pl, ["pl"]),
+    (Pt, ["pt", "pt-PT"]),
+    (Pa, ["pa"]),
+    (Ro, ["ro"]),
+    (Ru, ["ru"]),
+    (Sr, ["sr"]),
+    (Si, ["si"]),
+    (Sk, ["sk"]),
+    (Sl, ["sl"]),
+    (So, ["so"]),
+    (Es, ["es", "es-MX"]),
+    (Sw, ["sw"]),
+    (Sv, ["sv"]),
+    (Ta, ["ta"]),
+    (Te, ["te"]),
+    (Th, ["th"]),
+    (Tr, ["tr"]),
+    (Uk, ["uk"]),
+    (Ur, ["ur"]),
+    (Uz, ["uz"]),
+    (Vi, ["vi"]),
+    (Cy, ["cy"]),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{code, codes, from_code, LANGUAGES};
+    use crate::LanguageCode;
+
+    #[test]
+    fn codes_round_trip() {
+        for language in LANGUAGES {
+            assert_eq!(code(language), codes(language).first().copied());
+            for code in codes(language) {
+                assert_eq!(from_code(code), Some(language));
+            }
+        }
+        assert_eq!(code(LanguageCode::Yo), None);
+        assert_eq!(from_code("zh-tw"), Some(LanguageCode::Zh));
+    }
+}
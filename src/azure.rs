@@ -0,0 +1,144 @@
+//! Language identifiers used by [Azure AI Translator](https://learn.microsoft.com/azure/ai-services/translator/language-support).
+//!
+//! Azure identifies some languages by script rather than region, such as `zh-Hans` and `sr-Cyrl`,
+//! and uses ISO 639-3 codes for a few languages whose ISO 639-1 code is a macrolanguage.
+//!
+//! # Examples
+//!
+//! ```
+//! use isolanguage_1::{azure, LanguageCode};
+//!
+//! assert_eq!(azure::code(LanguageCode::Zh), Some("zh-Hans"));
+//! assert_eq!(azure::codes(LanguageCode::Sr), ["sr-Cyrl", "sr-Latn"]);
+//! assert_eq!(azure::from_code("ZH-HANT"), Some(LanguageCode::Zh));
+//! ```
+
+provider_codes! {
+    "Azure AI Translator",
+    (Af, ["af"]),
+    (Sq, ["sq"]),
+    (Am, ["am"]),
+    (Ar, ["ar"]),
+    (Hy, ["hy"]),
+    (As, ["as"]),
+    (Az, ["az"]),
+    (Ba, ["ba"]),
+    (Eu, ["eu"]),
+    (Bn, ["bn"]),
+    (Bs, ["bs"]),
+    (Bg, ["bg"]),
+    (Ca, ["ca"]),
+    (Zh, ["zh-Hans", "zh-Hant"]),
+    (Hr, ["hr"]),
+    (Cs, ["cs"]),
+    (Da, ["da"]),
+    (Dv, ["dv"]),
+    (Nl, ["nl"]),
+    (En, ["en"]),
+    (Et, ["et"]),
+    (Fo, ["fo"]),
+    (Fj, ["fj"]),
+    (Fi, ["fi"]),
+    (Fr, ["fr", "fr-CA"]),
+    (Gl, ["gl"]),
+    (Ka, ["ka"]),
+    (De, ["de"]),
+    (El, ["el"]),
+    (Gu, ["gu"]),
+    (Ht, ["ht"]),
+    (Ha, ["ha"]),
+    (He, ["he"]),
+    (Hi, ["hi"]),
+    (Hu, ["hu"]),
+    (Is, ["is"]),
+    (Ig, ["ig"]),
+    (Id, ["id"]),
+    (Iu, ["iu", "iu-Latn"]),
+    (Ga, ["ga"]),
+    (It, ["it"]),
+    (Ja, ["ja"]),
+    (Kn, ["kn"]),
+    (Ks, ["ks"]),
+    (Kk, ["kk"]),
+    (Km, ["km"]),
+    (Rw, ["rw"]),
+    (Ko, ["ko"]),
+    (Ku, ["ku", "kmr"]),
+    (Ky, ["ky"]),
+    (Lo, ["lo"]),
+    (Lv, ["lv"]),
+    (Ln, ["ln"]),
+    (Lt, ["lt"]),
+    (Lg, ["lug"]),
+    (Mk, ["mk"]),
+    (Mg, ["mg"]),
+    (Ms, ["ms"]),
+    (Ml, ["ml"]),
+    (Mt, ["mt"]),
+    (Mi, ["mi"]),
+    (Mr, ["mr"]),
+    (Mn, ["mn-Cyrl", "mn-Mong"]),
+    (My, ["my"]),
+    (Ne, ["ne"]),
+    (Nb, ["nb"]),
+    (Ny, ["nya"]),
+    (Or, ["or"]),
+    (Ps, ["ps"]),
+    (Fa, ["fa", "prs"]),
+    (Pl, ["pl"]),
+    (Pt, ["pt", "pt-PT"]),
+    (Pa, ["pa"]),
+    (Ro, ["ro"]),
+    (Rn, ["run"]),
+    (Ru, ["ru"]),
+    (Sm, ["sm"]),
+    (Sr, ["sr-Cyrl", "sr-Latn"]),
+    (St, ["st"]),
+    (Tn, ["tn"]),
+    (Sn, ["sn"]),
+    (Sd, ["sd"]),
+    (Si, ["si"]),
+    (Sk, ["sk"]),
+    (Sl, ["sl"]),
+    (So, ["so"]),
+    (Es, ["es"]),
+    (Sw, ["sw"]),
+    (Sv, ["sv"]),
+    (Ty, ["ty"]),
+    (Ta, ["ta"]),
+    (Tt, ["tt"]),
+    (Te, ["te"]),
+    (Th, ["th"]),
+    (Bo, ["bo"]),
+    (Ti, ["ti"]),
+    (To, ["to"]),
+    (Tr, ["tr"]),
+    (Tk, ["tk"]),
+    (Uk, ["uk"]),
+    (Ur, ["ur"]),
+    (Ug, ["ug"]),
+    (Uz, ["uz"]),
+    (Vi, ["vi"]),
+    (Cy, ["cy"]),
+    (Xh, ["xh"]),
+    (Yo, ["yo"]),
+    (Zu, ["zu"]),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{code, codes, from_code, LANGUAGES};
+    use crate::LanguageCode;
+
+    #[test]
+    fn codes_round_trip() {
+        for language in LANGUAGES {
+            assert_eq!(code(language), codes(language).first().copied());
+            for code in codes(language) {
+                assert_eq!(from_code(code), Some(language));
+            }
+        }
+        assert_eq!(code(LanguageCode::Cu), None);
+        assert_eq!(from_code("sr-latn"), Some(LanguageCode::Sr));
+    }
+}
@@ -0,0 +1,69 @@
+//! Language attributes of caption and subtitle formats.
+//!
+//! WebVTT files, TTML documents and HTML `<track>` elements all identify their language with a
+//! BCP 47 tag. These helpers write the attributes from a [`LanguageTag`] in its normalized form,
+//! and validate attribute values read from existing files.
+//!
+//! # Examples
+//!
+//! ```
+//! use isolanguage_1::{captions, LanguageCode, LanguageTag};
+//!
+//! let tag = LanguageTag::from(LanguageCode::Fr);
+//! assert_eq!(captions::track_srclang(&tag), r#"srclang="fr""#);
+//! assert_eq!(captions::ttml_xml_lang(&tag), r#"xml:lang="fr""#);
+//! assert_eq!(captions::webvtt_language_header(&tag), "Language: fr");
+//!
+//! let tag = captions::parse_attribute(" en-us ").unwrap();
+//! assert_eq!(tag.to_string(), "en-US");
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+
+use crate::{LanguageTag, ParseLanguageTagError};
+
+/// Returns the `srclang` attribute of an HTML `<track>` element.
+#[must_use]
+pub fn track_srclang(tag: &LanguageTag) -> String {
+    format!("srclang=\"{}\"", tag)
+}
+
+/// Returns the `xml:lang` attribute of a TTML element.
+#[must_use]
+pub fn ttml_xml_lang(tag: &LanguageTag) -> String {
+    format!("xml:lang=\"{}\"", tag)
+}
+
+/// Returns the `Language` header line used in WebVTT files, such as those served by HLS players
+/// and video platforms.
+#[must_use]
+pub fn webvtt_language_header(tag: &LanguageTag) -> String {
+    format!("Language: {}", tag)
+}
+
+/// Parses the value of a `srclang`, `xml:lang` or WebVTT `Language` attribute, ignoring
+/// surrounding whitespace.
+///
+/// # Errors
+///
+/// Fails if the value is not a well-formed BCP 47 tag whose primary language has an ISO 639-1
+/// code. This includes the empty value, which TTML uses for undetermined languages.
+pub fn parse_attribute(value: &str) -> Result<LanguageTag, ParseLanguageTagError> {
+    value.trim().parse()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_attribute, track_srclang, ttml_xml_lang, webvtt_language_header};
+
+    #[test]
+    fn round_trip() {
+        let tag = parse_attribute("zh-hant").unwrap();
+        assert_eq!(track_srclang(&tag), r#"srclang="zh-Hant""#);
+        assert_eq!(ttml_xml_lang(&tag), r#"xml:lang="zh-Hant""#);
+        assert_eq!(webvtt_language_header(&tag), "Language: zh-Hant");
+        assert!(parse_attribute("").is_err());
+        assert!(parse_attribute("english").is_err());
+    }
+}
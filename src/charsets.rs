@@ -0,0 +1,228 @@
+use crate::LanguageCode;
+
+impl LanguageCode {
+    /// Returns the [IANA names](https://www.iana.org/assignments/character-sets/) of the legacy
+    /// character encodings historically used for the language, most common first.
+    ///
+    /// This is useful as a guess when decoding email, feeds or files that don't declare their
+    /// encoding. Languages that were mostly written in Unicode from the start, or whose legacy
+    /// encodings were never registered with IANA, return an empty slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::Ja.legacy_charsets()[0], "Shift_JIS");
+    /// assert!(LanguageCode::Ru.legacy_charsets().contains(&"ISO-8859-5"));
+    /// assert!(LanguageCode::Am.legacy_charsets().is_empty());
+    /// ```
+    #[must_use]
+    pub const fn legacy_charsets(self) -> &'static [&'static str] {
+        match self {
+            Self::Af
+            | Self::Br
+            | Self::Ca
+            | Self::Co
+            | Self::Da
+            | Self::De
+            | Self::En
+            | Self::Es
+            | Self::Eu
+            | Self::Fi
+            | Self::Fo
+            | Self::Fr
+            | Self::Fy
+            | Self::Ga
+            | Self::Gd
+            | Self::Gl
+            | Self::Id
+            | Self::Is
+            | Self::It
+            | Self::Kl
+            | Self::La
+            | Self::Lb
+            | Self::Ms
+            | Self::Nb
+            | Self::Nl
+            | Self::Nn
+            | Self::No
+            | Self::Oc
+            | Self::Pt
+            | Self::Rm
+            | Self::Sq
+            | Self::Sv
+            | Self::Sw
+            | Self::Wa => &["ISO-8859-1", "windows-1252", "ISO-8859-15"],
+            Self::Bs | Self::Cs | Self::Hr | Self::Hu | Self::Pl | Self::Sk | Self::Sl => {
+                &["ISO-8859-2", "windows-1250"]
+            }
+            Self::Ro => &["ISO-8859-2", "windows-1250", "ISO-8859-16"],
+            Self::Eo | Self::Mt => &["ISO-8859-3"],
+            Self::Et => &["ISO-8859-15", "windows-1257", "ISO-8859-13"],
+            Self::Lt | Self::Lv => &["ISO-8859-13", "windows-1257", "ISO-8859-4"],
+            Self::Cy => &["ISO-8859-14", "windows-1252"],
+            Self::Ru => &["windows-1251", "KOI8-R", "ISO-8859-5", "IBM866"],
+            Self::Uk => &["windows-1251", "KOI8-U", "ISO-8859-5"],
+            Self::Be | Self::Bg | Self::Mk | Self::Sr => &["windows-1251", "ISO-8859-5"],
+            Self::Ba | Self::Ky | Self::Mn | Self::Tg | Self::Tt => &["windows-1251"],
+            Self::Kk => &["KZ-1048", "windows-1251"],
+            Self::El => &["ISO-8859-7", "windows-1253"],
+            Self::Az | Self::Ku | Self::Tr => &["ISO-8859-9", "windows-1254"],
+            Self::He | Self::Yi => &["windows-1255", "ISO-8859-8"],
+            Self::Ar => &["windows-1256", "ISO-8859-6"],
+            Self::Fa | Self::Ps | Self::Ug | Self::Ur => &["windows-1256"],
+            Self::Uz => &["windows-1254"],
+            Self::Th => &["TIS-620", "windows-874"],
+            Self::Vi => &["windows-1258", "VISCII"],
+            Self::Ja => &["Shift_JIS", "EUC-JP", "ISO-2022-JP"],
+            Self::Ko => &["EUC-KR", "ISO-2022-KR"],
+            Self::Zh => &["GB2312", "GBK", "GB18030", "Big5"],
+            _ => &[],
+        }
+    }
+
+    /// Returns the legacy ANSI and OEM Windows codepages conventionally used for the language, or
+    /// `None` if Windows only supports it with Unicode.
+    ///
+    /// These are the defaults of the language's main Windows locale; Serbian uses its Cyrillic
+    /// locale and Chinese its simplified one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, WindowsCodepages};
+    ///
+    /// assert_eq!(
+    ///     LanguageCode::Ru.windows_codepage(),
+    ///     Some(WindowsCodepages { ansi: 1251, oem: 866 })
+    /// );
+    /// assert_eq!(LanguageCode::Hi.windows_codepage(), None);
+    /// ```
+    #[must_use]
+    pub const fn windows_codepage(self) -> Option<WindowsCodepages> {
+        let (ansi, oem) = match self {
+            Self::En | Self::Sw => (1252, 437),
+            Self::Af
+            | Self::Br
+            | Self::Ca
+            | Self::Co
+            | Self::Cy
+            | Self::Da
+            | Self::De
+            | Self::Es
+            | Self::Eu
+            | Self::Fi
+            | Self::Fo
+            | Self::Fr
+            | Self::Fy
+            | Self::Ga
+            | Self::Gd
+            | Self::Gl
+            | Self::Id
+            | Self::Is
+            | Self::It
+            | Self::Kl
+            | Self::Lb
+            | Self::Ms
+            | Self::Nb
+            | Self::Nl
+            | Self::Nn
+            | Self::No
+            | Self::Oc
+            | Self::Pt
+            | Self::Rm
+            | Self::Sv => (1252, 850),
+            Self::Bs
+            | Self::Cs
+            | Self::Hr
+            | Self::Hu
+            | Self::Pl
+            | Self::Ro
+            | Self::Sk
+            | Self::Sl
+            | Self::Sq => (1250, 852),
+            Self::Sr => (1251, 855),
+            Self::Ba
+            | Self::Be
+            | Self::Bg
+            | Self::Kk
+            | Self::Ky
+            | Self::Mk
+            | Self::Mn
+            | Self::Ru
+            | Self::Tg
+            | Self::Tt
+            | Self::Uk => (1251, 866),
+            Self::El => (1253, 737),
+            Self::Az | Self::Tr | Self::Uz => (1254, 857),
+            Self::He => (1255, 862),
+            Self::Ar | Self::Fa | Self::Ug | Self::Ur => (1256, 720),
+            Self::Et | Self::Lt | Self::Lv => (1257, 775),
+            Self::Vi => (1258, 1258),
+            Self::Th => (874, 874),
+            Self::Ja => (932, 932),
+            Self::Zh => (936, 936),
+            Self::Ko => (949, 949),
+            _ => return None,
+        };
+        Some(WindowsCodepages { ansi, oem })
+    }
+}
+
+/// The legacy Windows codepages of a language, returned by [`LanguageCode::windows_codepage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowsCodepages {
+    /// The ANSI codepage, used by GUI programs, like 1252.
+    pub ansi: u16,
+    /// The OEM codepage, used by the console, like 850.
+    pub oem: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, WindowsCodepages};
+
+    #[test]
+    fn legacy_charsets() {
+        assert_eq!(
+            LanguageCode::Ar.legacy_charsets(),
+            ["windows-1256", "ISO-8859-6"]
+        );
+        assert_eq!(
+            LanguageCode::Nb.legacy_charsets(),
+            LanguageCode::No.legacy_charsets()
+        );
+        for language in LanguageCode::iter() {
+            let charsets = language.legacy_charsets();
+            for (i, charset) in charsets.iter().enumerate() {
+                assert!(!charsets[..i].contains(charset), "{}", language.code());
+            }
+        }
+    }
+
+    #[test]
+    fn windows_codepage() {
+        assert_eq!(
+            LanguageCode::Ja.windows_codepage(),
+            Some(WindowsCodepages {
+                ansi: 932,
+                oem: 932
+            })
+        );
+        assert_eq!(
+            LanguageCode::Nb.windows_codepage(),
+            LanguageCode::No.windows_codepage()
+        );
+        assert_eq!(LanguageCode::Bo.windows_codepage(), None);
+        for language in LanguageCode::iter() {
+            if language.windows_codepage().is_some() {
+                assert!(
+                    !language.legacy_charsets().is_empty(),
+                    "{}",
+                    language.code()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! WebAssembly component bindings for the [`wit/isolanguage.wit`] interface. Enabled by the
+//! `component` feature.
+//!
+//! Build a `cdylib` crate that depends on this one with the feature enabled for the
+//! `wasm32-wasip2` target to get a component exporting the `languages` interface.
+//!
+//! [`wit/isolanguage.wit`]: https://github.com/Kestrer/isolanguage-1/blob/main/wit/isolanguage.wit
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::{negotiate, LanguageCode, LanguageRange, LanguageTag};
+
+mod bindings {
+    wit_bindgen::generate!({
+        world: "isolanguage",
+        path: "wit",
+    });
+}
+
+use bindings::exports::kestrer::isolanguage::languages::{Guest, LanguageInfo};
+
+struct Component;
+
+fn info(language: LanguageCode) -> LanguageInfo {
+    LanguageInfo {
+        code: language.code().into(),
+        code_t: language.code_t().into(),
+        code_b: language.code_b().into(),
+        name: language.name().into(),
+        family: language.family().into(),
+    }
+}
+
+impl Guest for Component {
+    fn codes() -> Vec<String> {
+        LanguageCode::codes().map(String::from).collect()
+    }
+
+    fn lookup(code: String) -> Option<LanguageInfo> {
+        code.parse().ok().map(info)
+    }
+
+    fn lookup_part2(code: String) -> Option<LanguageInfo> {
+        LanguageCode::from_code_639_2(&code)
+            .filter(|parsed| !parsed.deprecated)
+            .map(|parsed| info(parsed.language))
+    }
+
+    fn negotiate(requested: Vec<String>, available: Vec<String>) -> Option<String> {
+        let requested: Vec<LanguageRange> = requested
+            .iter()
+            .filter_map(|range| range.parse().ok())
+            .collect();
+        let available: Vec<LanguageTag> = available
+            .iter()
+            .filter_map(|tag| tag.parse().ok())
+            .collect();
+        negotiate::lookup(&requested, &available).map(ToString::to_string)
+    }
+}
+
+bindings::export!(Component with_types_in bindings);
+
+#[cfg(test)]
+mod tests {
+    use super::{Component, Guest};
+
+    #[test]
+    fn guest() {
+        assert_eq!(Component::codes().len(), 184);
+        assert_eq!(Component::lookup("nl".into()).unwrap().code_b, "dut");
+        assert_eq!(Component::lookup_part2("dut".into()).unwrap().code, "nl");
+        assert!(Component::lookup_part2("scc".into()).is_none());
+        assert_eq!(
+            Component::negotiate(
+                vec!["de-AT".into(), "!".into()],
+                vec!["en".into(), "de".into()]
+            )
+            .as_deref(),
+            Some("de")
+        );
+    }
+}
@@ -0,0 +1,884 @@
+//! The languages spoken in each country, for choosing defaults from a visitor's location.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{LanguageCode, Region, LANGUAGE_CODES};
+
+/// The legal status of a language in a country, returned by [`LanguageCode::status_in`].
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, OfficialStatus, Region};
+///
+/// let spain = Region::from_const("ES");
+/// assert_eq!(LanguageCode::Es.status_in(spain), Some(OfficialStatus::Official));
+/// assert_eq!(LanguageCode::Ca.status_in(spain), Some(OfficialStatus::Regional));
+/// assert_eq!(LanguageCode::An.status_in(spain), Some(OfficialStatus::Minority));
+/// assert_eq!(LanguageCode::Fr.status_in(spain), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[non_exhaustive]
+pub enum OfficialStatus {
+    /// The only official language of the country, like French in France.
+    Official,
+    /// One of several official languages of the country, like French in Switzerland.
+    CoOfficial,
+    /// Official in part of the country but not nationally, like Welsh in the United Kingdom.
+    Regional,
+    /// Recognized or protected as a minority language without being official, like Sardinian in
+    /// Italy.
+    Minority,
+}
+
+impl LanguageCode {
+    /// Returns the language most likely used in a region, from CLDR's likely subtags.
+    ///
+    /// Returns `None` for uninhabited regions and regions whose most likely language has no
+    /// 2 letter code, like the Philippines (Filipino).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, Region};
+    ///
+    /// assert_eq!(
+    ///     LanguageCode::primary_language_of(Region::from_const("BR")),
+    ///     Some(LanguageCode::Pt)
+    /// );
+    /// assert_eq!(LanguageCode::primary_language_of(Region::from_const("AQ")), None);
+    /// ```
+    #[must_use]
+    pub fn primary_language_of(region: Region) -> Option<Self> {
+        row(region).and_then(|&(_, primary, _)| primary)
+    }
+
+    /// Returns the languages used in a region: the [primary
+    /// language](Self::primary_language_of) followed by the languages with a glibc locale for the
+    /// region, in order of their codes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, Region};
+    ///
+    /// use LanguageCode::*;
+    /// assert_eq!(
+    ///     LanguageCode::languages_of(Region::from_const("CH")),
+    ///     [De, Fr, It]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn languages_of(region: Region) -> &'static [Self] {
+        row(region).map_or(&[], |&(_, _, languages)| languages)
+    }
+
+    /// Returns the ISO 3166 alpha-2 codes of the countries and territories where the language is
+    /// official at the national level, in alphabetical order.
+    ///
+    /// This follows national constitutions and laws, so languages only official in a subdivision,
+    /// like Welsh in Wales or Catalan in Catalonia, aren't official in the country as a whole.
+    /// Returns an empty slice for languages that aren't official anywhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(
+    ///     LanguageCode::De.official_in(),
+    ///     ["AT", "BE", "CH", "DE", "LI", "LU"]
+    /// );
+    /// assert!(LanguageCode::Cy.official_in().is_empty());
+    /// ```
+    #[must_use]
+    #[rustfmt::skip]
+    pub const fn official_in(self) -> &'static [&'static str] {
+        match self {
+            Self::Af | Self::Nr | Self::Zu => &["ZA"],
+            Self::Am => &["ET"],
+            Self::Ar => &["AE", "BH", "DJ", "DZ", "EG", "EH", "IQ", "JO", "KM", "KW", "LB", "LY", "MA", "MR", "OM", "PS", "QA", "SA", "SD", "SO", "SY", "TD", "TN", "YE"],
+            Self::Ay | Self::Qu => &["BO", "PE"],
+            Self::Az => &["AZ"],
+            Self::Be => &["BY"],
+            Self::Bg => &["BG"],
+            Self::Bi => &["VU"],
+            Self::Bn => &["BD"],
+            Self::Bs => &["BA"],
+            Self::Ca => &["AD"],
+            Self::Ch => &["GU", "MP"],
+            Self::Cs => &["CZ"],
+            Self::Da => &["DK", "FO"],
+            Self::De => &["AT", "BE", "CH", "DE", "LI", "LU"],
+            Self::Dv => &["MV"],
+            Self::Dz => &["BT"],
+            Self::El => &["CY", "GR"],
+            Self::En => &["AG", "AI", "AS", "AU", "BB", "BI", "BM", "BS", "BW", "BZ", "CA", "CC", "CK", "CM", "CX", "DM", "FJ", "FK", "FM", "GB", "GD", "GG", "GH", "GI", "GM", "GU", "GY", "HK", "IE", "IM", "IN", "IO", "JE", "JM", "KE", "KI", "KN", "KY", "LC", "LR", "LS", "MH", "MP", "MS", "MT", "MU", "MW", "NA", "NF", "NG", "NR", "NU", "NZ", "PG", "PH", "PK", "PN", "PR", "PW", "RW", "SB", "SC", "SD", "SG", "SH", "SL", "SS", "SX", "SZ", "TC", "TK", "TO", "TT", "TV", "TZ", "UG", "UM", "US", "VC", "VG", "VI", "VU", "WS", "ZA", "ZM", "ZW"],
+            Self::Es => &["AR", "BO", "CL", "CO", "CR", "CU", "DO", "EC", "ES", "GQ", "GT", "HN", "MX", "NI", "PA", "PE", "PR", "PY", "SV", "UY", "VE"],
+            Self::Et => &["EE"],
+            Self::Fa => &["AF", "IR"],
+            Self::Fi => &["FI"],
+            Self::Fj => &["FJ"],
+            Self::Fo => &["FO"],
+            Self::Fr => &["BE", "BI", "BJ", "BL", "CA", "CD", "CF", "CG", "CH", "CI", "CM", "DJ", "FR", "GA", "GF", "GN", "GP", "HT", "KM", "LU", "MC", "MF", "MG", "MQ", "NC", "PF", "PM", "RE", "RW", "SC", "SN", "TD", "TG", "VU", "WF", "YT"],
+            Self::Ga => &["IE"],
+            Self::Gn => &["PY"],
+            Self::Gv => &["IM"],
+            Self::He => &["IL"],
+            Self::Hi => &["IN"],
+            Self::Ho => &["PG"],
+            Self::Hr => &["BA", "HR"],
+            Self::Ht => &["HT"],
+            Self::Hu => &["HU"],
+            Self::Hy => &["AM"],
+            Self::Id => &["ID"],
+            Self::Is => &["IS"],
+            Self::It => &["CH", "IT", "SM", "VA"],
+            Self::Ja => &["JP"],
+            Self::Ka => &["GE"],
+            Self::Kk => &["KZ"],
+            Self::Kl => &["GL"],
+            Self::Km => &["KH"],
+            Self::Ko => &["KP", "KR"],
+            Self::Ku => &["IQ"],
+            Self::Ky => &["KG"],
+            Self::La => &["VA"],
+            Self::Lb => &["LU"],
+            Self::Lo => &["LA"],
+            Self::Lt => &["LT"],
+            Self::Lv => &["LV"],
+            Self::Mg => &["MG"],
+            Self::Mh => &["MH"],
+            Self::Mi => &["NZ"],
+            Self::Mk => &["MK"],
+            Self::Mn => &["MN"],
+            Self::Ms => &["BN", "MY", "SG"],
+            Self::Mt => &["MT"],
+            Self::My => &["MM"],
+            Self::Na => &["NR"],
+            Self::Nb | Self::Nn | Self::No => &["NO"],
+            Self::Nd | Self::Sn => &["ZW"],
+            Self::Ne => &["NP"],
+            Self::Nl => &["AW", "BE", "BQ", "CW", "NL", "SR", "SX"],
+            Self::Ny => &["MW", "ZW"],
+            Self::Pl => &["PL"],
+            Self::Ps => &["AF"],
+            Self::Pt => &["AO", "BR", "CV", "GQ", "GW", "MO", "MZ", "PT", "ST", "TL"],
+            Self::Rm => &["CH"],
+            Self::Rn => &["BI"],
+            Self::Ro => &["MD", "RO"],
+            Self::Ru => &["BY", "KG", "KZ", "RU"],
+            Self::Rw => &["RW"],
+            Self::Sg => &["CF"],
+            Self::Si => &["LK"],
+            Self::Sk => &["SK"],
+            Self::Sl => &["SI"],
+            Self::Sm => &["AS", "WS"],
+            Self::So => &["SO"],
+            Self::Sq => &["AL", "MK"],
+            Self::Sr => &["BA", "RS"],
+            Self::Ss => &["SZ", "ZA"],
+            Self::St => &["LS", "ZA", "ZW"],
+            Self::Sv => &["AX", "FI", "SE"],
+            Self::Sw => &["KE", "RW", "TZ", "UG"],
+            Self::Ta => &["LK", "SG"],
+            Self::Tg => &["TJ"],
+            Self::Th => &["TH"],
+            Self::Tk => &["TM"],
+            Self::Tn | Self::Ts | Self::Ve | Self::Xh => &["ZA", "ZW"],
+            Self::To => &["TO"],
+            Self::Tr => &["CY", "TR"],
+            Self::Uk => &["UA"],
+            Self::Ur => &["PK"],
+            Self::Uz => &["UZ"],
+            Self::Vi => &["VN"],
+            Self::Zh => &["CN", "HK", "MO", "SG", "TW"],
+            _ => &[],
+        }
+    }
+
+    /// Returns the ISO 3166 alpha-2 codes of the countries and territories where the language is
+    /// widely spoken without being [official](Self::official_in), in alphabetical order.
+    ///
+    /// These are places where a large minority speaks the language natively, like Spanish in the
+    /// United States, where it is official only in a subdivision, like Welsh in the United
+    /// Kingdom, or where it is an everyday second language, like French in Morocco.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert!(LanguageCode::Es.spoken_in().contains(&"US"));
+    /// assert_eq!(LanguageCode::Cy.spoken_in(), ["GB"]);
+    /// assert!(LanguageCode::La.spoken_in().is_empty());
+    /// ```
+    #[must_use]
+    #[rustfmt::skip]
+    pub const fn spoken_in(self) -> &'static [&'static str] {
+        match self {
+            Self::Aa => &["DJ", "ER", "ET"],
+            Self::Ak => &["GH"],
+            Self::An | Self::Gl => &["ES"],
+            Self::Ar => &["ER", "IL"],
+            Self::As | Self::Bn | Self::Gu | Self::Kn | Self::Ks | Self::Ml | Self::Mr | Self::Or | Self::Te | Self::Ur => &["IN"],
+            Self::Av | Self::Ba | Self::Ce | Self::Cv | Self::Kv | Self::Tt => &["RU"],
+            Self::Az => &["GE", "IR"],
+            Self::Bm => &["ML"],
+            Self::Bo | Self::Ii | Self::Ko | Self::Mn | Self::Ug | Self::Za => &["CN"],
+            Self::Br | Self::Co => &["FR"],
+            Self::Ca | Self::Oc => &["ES", "FR", "IT"],
+            Self::Cr | Self::Iu | Self::Oj => &["CA"],
+            Self::Cy | Self::Ga | Self::Gd | Self::Kw => &["GB"],
+            Self::Da => &["DE", "GL"],
+            Self::De => &["DK", "IT", "NA"],
+            Self::Ee => &["GH", "TG"],
+            Self::En => &["AE", "BH", "BN", "CY", "IL", "LK", "MY", "QA"],
+            Self::Es => &["AD", "BZ", "GI", "US"],
+            Self::Eu => &["ES", "FR"],
+            Self::Ff => &["BF", "CM", "GM", "GN", "ML", "MR", "NE", "NG", "SN"],
+            Self::Fi => &["SE"],
+            Self::Fr => &["BF", "DZ", "LB", "MA", "ML", "NE", "TN"],
+            Self::Fy => &["NL"],
+            Self::Gn => &["AR"],
+            Self::Ha => &["GH", "NE", "NG"],
+            Self::Hi => &["FJ"],
+            Self::Ht => &["DO"],
+            Self::Hu => &["RO", "RS", "SK", "UA"],
+            Self::Hz | Self::Ng => &["NA"],
+            Self::Ig => &["NG"],
+            Self::Ik | Self::Nv => &["US"],
+            Self::It => &["HR", "MT", "SI"],
+            Self::Jv | Self::Su => &["ID"],
+            Self::Kg => &["AO", "CD", "CG"],
+            Self::Ki => &["KE"],
+            Self::Kj => &["AO", "NA"],
+            Self::Ku => &["IR", "SY", "TR"],
+            Self::Lg => &["UG"],
+            Self::Li => &["BE", "NL"],
+            Self::Ln => &["CD", "CG"],
+            Self::Lo | Self::Ms => &["TH"],
+            Self::Lu | Self::Sw => &["CD"],
+            Self::Ne => &["BT", "IN"],
+            Self::Ny => &["MZ", "ZM"],
+            Self::Om => &["ET", "KE"],
+            Self::Os => &["GE", "RU"],
+            Self::Pa | Self::Sd => &["IN", "PK"],
+            Self::Pl => &["LT"],
+            Self::Ps => &["PK"],
+            Self::Pt => &["AD", "LU"],
+            Self::Qu => &["EC"],
+            Self::Ro => &["UA"],
+            Self::Ru => &["EE", "IL", "LT", "LV", "MD", "UA"],
+            Self::Sc => &["IT"],
+            Self::Se => &["FI", "NO", "SE"],
+            Self::Sl => &["AT", "IT"],
+            Self::So => &["DJ", "ET", "KE"],
+            Self::Sq | Self::Sr => &["ME"],
+            Self::Ta => &["IN", "MY"],
+            Self::Ti => &["ER", "ET"],
+            Self::Tk => &["AF"],
+            Self::Tl => &["PH"],
+            Self::Tn => &["BW"],
+            Self::Tr => &["BG", "DE"],
+            Self::Ts => &["MZ"],
+            Self::Ty => &["PF"],
+            Self::Uz => &["AF", "KG", "TJ"],
+            Self::Wa => &["BE"],
+            Self::Wo => &["GM", "MR", "SN"],
+            Self::Yi => &["IL", "US"],
+            Self::Yo => &["BJ", "NG"],
+            Self::Zh => &["MY"],
+            _ => &[],
+        }
+    }
+
+    /// Returns the region the language is most likely used in, from CLDR's likely subtags, for
+    /// completing a bare language into a [`Locale`](crate::Locale).
+    ///
+    /// Constructed languages return the world, `001`, and ancient languages return the region
+    /// they are most studied or used in today, like the Vatican City for Latin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, Locale, Region};
+    ///
+    /// assert_eq!(LanguageCode::Pt.likely_region(), Region::from_const("BR"));
+    /// assert_eq!(LanguageCode::Eo.likely_region(), Region::from_const("001"));
+    ///
+    /// let locale = Locale::new(LanguageCode::Ja, Some(LanguageCode::Ja.likely_region()));
+    /// assert_eq!(locale.to_string(), "ja-JP");
+    /// ```
+    #[must_use]
+    pub const fn likely_region(self) -> Region {
+        Region::from_const(match self {
+            Self::Eo | Self::Ia | Self::Io | Self::Vo => "001",
+            Self::Ps => "AF",
+            Self::Sq => "AL",
+            Self::Hy => "AM",
+            Self::Az => "AZ",
+            Self::Bs => "BA",
+            Self::Bn => "BD",
+            Self::Wa => "BE",
+            Self::Bg => "BG",
+            Self::Rn => "BI",
+            Self::Ay => "BO",
+            Self::Pt => "BR",
+            Self::Dz => "BT",
+            Self::Be => "BY",
+            Self::Cr | Self::Iu | Self::Oj => "CA",
+            Self::Kg | Self::Ln | Self::Lu => "CD",
+            Self::Sg => "CF",
+            Self::Rm => "CH",
+            Self::Bo | Self::Ii | Self::Ug | Self::Za | Self::Zh => "CN",
+            Self::Cs => "CZ",
+            Self::De => "DE",
+            Self::Da => "DK",
+            Self::Et | Self::Ie => "EE",
+            Self::Ar => "EG",
+            Self::An | Self::Ca | Self::Es | Self::Eu | Self::Gl => "ES",
+            Self::Aa | Self::Am | Self::Om | Self::Ti => "ET",
+            Self::Fi => "FI",
+            Self::Fj => "FJ",
+            Self::Fo => "FO",
+            Self::Br | Self::Co | Self::Fr | Self::Oc => "FR",
+            Self::Cy | Self::Gd | Self::Kw | Self::Pi => "GB",
+            Self::Ab | Self::Ka | Self::Os => "GE",
+            Self::Ak | Self::Ee | Self::Tw => "GH",
+            Self::Kl => "GL",
+            Self::El => "GR",
+            Self::Ch => "GU",
+            Self::Hr => "HR",
+            Self::Ht => "HT",
+            Self::Hu => "HU",
+            Self::Id | Self::Jv | Self::Su => "ID",
+            Self::Ga => "IE",
+            Self::He => "IL",
+            Self::Gv => "IM",
+            Self::As
+            | Self::Bh
+            | Self::Gu
+            | Self::Hi
+            | Self::Kn
+            | Self::Ks
+            | Self::Ml
+            | Self::Mr
+            | Self::Or
+            | Self::Pa
+            | Self::Sa
+            | Self::Ta
+            | Self::Te => "IN",
+            Self::Ae | Self::Fa => "IR",
+            Self::Is => "IS",
+            Self::It | Self::Sc => "IT",
+            Self::Ja => "JP",
+            Self::Ki => "KE",
+            Self::Ky => "KG",
+            Self::Km => "KH",
+            Self::Ko => "KR",
+            Self::Kk => "KZ",
+            Self::Lo => "LA",
+            Self::Si => "LK",
+            Self::Lt => "LT",
+            Self::Lb => "LU",
+            Self::Lv => "LV",
+            Self::Mg => "MG",
+            Self::Mh => "MH",
+            Self::Mk => "MK",
+            Self::Bm => "ML",
+            Self::My => "MM",
+            Self::Mn => "MN",
+            Self::Mt => "MT",
+            Self::Dv => "MV",
+            Self::Ny => "MW",
+            Self::Ms => "MY",
+            Self::Hz | Self::Kj | Self::Ng => "NA",
+            Self::Ha | Self::Ig | Self::Kr | Self::Yo => "NG",
+            Self::Fy | Self::Li | Self::Nl => "NL",
+            Self::Nb | Self::Nn | Self::No | Self::Se => "NO",
+            Self::Ne => "NP",
+            Self::Na => "NR",
+            Self::Mi => "NZ",
+            Self::Qu => "PE",
+            Self::Ty => "PF",
+            Self::Ho => "PG",
+            Self::Tl => "PH",
+            Self::Sd | Self::Ur => "PK",
+            Self::Pl => "PL",
+            Self::Gn => "PY",
+            Self::Ro => "RO",
+            Self::Sr => "RS",
+            Self::Av
+            | Self::Ba
+            | Self::Ce
+            | Self::Cu
+            | Self::Cv
+            | Self::Kv
+            | Self::Ru
+            | Self::Tt => "RU",
+            Self::Rw => "RW",
+            Self::Sv => "SE",
+            Self::Sl => "SI",
+            Self::Sk => "SK",
+            Self::Ff | Self::Wo => "SN",
+            Self::So => "SO",
+            Self::Th => "TH",
+            Self::Tg => "TJ",
+            Self::Tk => "TM",
+            Self::To => "TO",
+            Self::Ku | Self::Tr => "TR",
+            Self::Sw => "TZ",
+            Self::Uk | Self::Yi => "UA",
+            Self::Lg => "UG",
+            Self::En | Self::Ik | Self::Nv => "US",
+            Self::Uz => "UZ",
+            Self::La => "VA",
+            Self::Vi => "VN",
+            Self::Bi => "VU",
+            Self::Sm => "WS",
+            Self::Af
+            | Self::Nr
+            | Self::Ss
+            | Self::St
+            | Self::Tn
+            | Self::Ts
+            | Self::Ve
+            | Self::Xh
+            | Self::Zu => "ZA",
+            Self::Nd | Self::Sn => "ZW",
+        })
+    }
+
+    /// Returns the legal status of the language in a country, or `None` if it has none there.
+    ///
+    /// A language is [official](OfficialStatus::Official) or
+    /// [co-official](OfficialStatus::CoOfficial) in the countries listed by
+    /// [`official_in`](Self::official_in), depending on whether any other language is too.
+    #[must_use]
+    pub fn status_in(self, region: Region) -> Option<OfficialStatus> {
+        let region = region.as_str();
+        if self.official_in().contains(&region) {
+            let shared = LANGUAGE_CODES
+                .iter()
+                .any(|&other| other != self && other.official_in().contains(&region));
+            Some(if shared {
+                OfficialStatus::CoOfficial
+            } else {
+                OfficialStatus::Official
+            })
+        } else if self.regional_in().contains(&region) {
+            Some(OfficialStatus::Regional)
+        } else if self.minority_in().contains(&region) {
+            Some(OfficialStatus::Minority)
+        } else {
+            None
+        }
+    }
+
+    /// The countries where the language is official in a subdivision but not nationally.
+    #[rustfmt::skip]
+    const fn regional_in(self) -> &'static [&'static str] {
+        match self {
+            Self::As | Self::Bn | Self::Gu | Self::Kn | Self::Ks | Self::Ml | Self::Mr | Self::Ne | Self::Or | Self::Pa | Self::Sa | Self::Ta | Self::Te | Self::Ur => &["IN"],
+            Self::Av | Self::Ba | Self::Ce | Self::Cv | Self::Kv | Self::Os | Self::Tt => &["RU"],
+            Self::Bo | Self::Ii | Self::Ko | Self::Mn | Self::Ug | Self::Za => &["CN"],
+            Self::Ca | Self::Eu | Self::Gl | Self::Oc => &["ES"],
+            Self::Cr | Self::Iu => &["CA"],
+            Self::Cy | Self::Ga | Self::Gd => &["GB"],
+            Self::De | Self::Fr => &["IT"],
+            Self::Fy => &["NL"],
+            Self::Gn => &["AR"],
+            Self::Hr => &["AT", "RS"],
+            Self::Hu => &["AT", "RS", "SI"],
+            Self::Ik => &["US"],
+            Self::It => &["HR", "SI"],
+            Self::Ro | Self::Sk => &["RS"],
+            Self::Sd => &["PK"],
+            Self::Se => &["FI", "NO"],
+            Self::Sl => &["AT"],
+            Self::Tk | Self::Uz => &["AF"],
+            _ => &[],
+        }
+    }
+
+    /// The countries where the language is a recognized minority language without being official.
+    #[rustfmt::skip]
+    const fn minority_in(self) -> &'static [&'static str] {
+        match self {
+            Self::An => &["ES"],
+            Self::Be | Self::Lt | Self::Uk => &["PL"],
+            Self::Br | Self::Co | Self::Eu => &["FR"],
+            Self::Ca | Self::Oc => &["FR", "IT"],
+            Self::Cs | Self::Sk | Self::Sr => &["HR"],
+            Self::Da => &["DE"],
+            Self::De => &["DK", "PL", "RO"],
+            Self::Fi | Self::Se | Self::Yi => &["SE"],
+            Self::Hu => &["HR", "RO", "SK", "UA"],
+            Self::Kw => &["GB"],
+            Self::Li => &["NL"],
+            Self::Pl | Self::Ru => &["LT"],
+            Self::Ro => &["UA"],
+            Self::Sc | Self::Sl => &["IT"],
+            Self::Wa => &["BE"],
+            _ => &[],
+        }
+    }
+}
+
+fn row(region: Region) -> Option<&'static Row> {
+    REGIONS
+        .binary_search_by_key(&region.as_str(), |&(region, _, _)| region)
+        .ok()
+        .map(|i| &REGIONS[i])
+}
+
+type Row = (&'static str, Option<LanguageCode>, &'static [LanguageCode]);
+
+/// Regions sorted by code, with their primary language and all their languages.
+#[rustfmt::skip]
+static REGIONS: [Row; 241] = {
+    use LanguageCode::*;
+    [
+        ("419", Some(Es), &[Es]),
+        ("AD", Some(Ca), &[Ca]),
+        ("AE", Some(Ar), &[Ar]),
+        ("AF", Some(Fa), &[Fa, Ps]),
+        ("AG", Some(En), &[En]),
+        ("AI", Some(En), &[En]),
+        ("AL", Some(Sq), &[Sq]),
+        ("AM", Some(Hy), &[Hy]),
+        ("AO", Some(Pt), &[Pt]),
+        ("AR", Some(Es), &[Es]),
+        ("AS", Some(Sm), &[Sm]),
+        ("AT", Some(De), &[De]),
+        ("AU", Some(En), &[En]),
+        ("AW", Some(Nl), &[Nl]),
+        ("AX", Some(Sv), &[Sv]),
+        ("AZ", Some(Az), &[Az]),
+        ("BA", Some(Bs), &[Bs]),
+        ("BB", Some(En), &[En]),
+        ("BD", Some(Bn), &[Bn]),
+        ("BE", Some(Nl), &[Nl, De, Fr, Li, Wa]),
+        ("BF", Some(Fr), &[Fr]),
+        ("BG", Some(Bg), &[Bg]),
+        ("BH", Some(Ar), &[Ar]),
+        ("BI", Some(Rn), &[Rn]),
+        ("BJ", Some(Fr), &[Fr]),
+        ("BL", Some(Fr), &[Fr]),
+        ("BM", Some(En), &[En]),
+        ("BN", Some(Ms), &[Ms]),
+        ("BO", Some(Es), &[Es]),
+        ("BR", Some(Pt), &[Pt]),
+        ("BS", Some(En), &[En]),
+        ("BT", Some(Dz), &[Dz]),
+        ("BV", Some(No), &[No]),
+        ("BW", Some(En), &[En]),
+        ("BY", Some(Ru), &[Ru, Be]),
+        ("BZ", Some(En), &[En]),
+        ("CA", Some(En), &[En, Fr, Ik, Iu]),
+        ("CC", Some(Ms), &[Ms]),
+        ("CD", Some(Fr), &[Fr, Ln]),
+        ("CF", Some(Sg), &[Sg]),
+        ("CG", Some(Fr), &[Fr]),
+        ("CH", Some(De), &[De, Fr, It]),
+        ("CI", Some(Fr), &[Fr]),
+        ("CK", Some(En), &[En]),
+        ("CL", Some(Es), &[Es]),
+        ("CM", Some(Fr), &[Fr]),
+        ("CN", Some(Zh), &[Zh, Bo, Ug]),
+        ("CO", Some(Es), &[Es]),
+        ("CR", Some(Es), &[Es]),
+        ("CU", Some(Es), &[Es]),
+        ("CV", Some(Pt), &[Pt]),
+        ("CX", Some(En), &[En]),
+        ("CY", Some(El), &[El, Tr]),
+        ("CZ", Some(Cs), &[Cs]),
+        ("DE", Some(De), &[De, Fy]),
+        ("DJ", Some(Fr), &[Fr, Aa, So]),
+        ("DK", Some(Da), &[Da, En]),
+        ("DM", Some(En), &[En]),
+        ("DO", Some(Es), &[Es]),
+        ("DZ", Some(Ar), &[Ar]),
+        ("EC", Some(Es), &[Es]),
+        ("EE", Some(Et), &[Et]),
+        ("EG", Some(Ar), &[Ar]),
+        ("EH", Some(Ar), &[Ar]),
+        ("ER", Some(Ti), &[Ti, Aa]),
+        ("ES", Some(Es), &[Es, An, Ca, Eu, Gl]),
+        ("ET", Some(Am), &[Am, Aa, Om, So, Ti]),
+        ("FI", Some(Fi), &[Fi, Sv]),
+        ("FJ", Some(En), &[En]),
+        ("FK", Some(En), &[En]),
+        ("FM", Some(En), &[En]),
+        ("FO", Some(Fo), &[Fo]),
+        ("FR", Some(Fr), &[Fr, Br, Ca, Ia, Oc]),
+        ("GA", Some(Fr), &[Fr]),
+        ("GB", Some(En), &[En, Cy, Gd, Gv, Kw]),
+        ("GD", Some(En), &[En]),
+        ("GE", Some(Ka), &[Ka]),
+        ("GF", Some(Fr), &[Fr]),
+        ("GG", Some(En), &[En]),
+        ("GH", Some(Ak), &[Ak]),
+        ("GI", Some(En), &[En]),
+        ("GL", Some(Kl), &[Kl]),
+        ("GM", Some(En), &[En]),
+        ("GN", Some(Fr), &[Fr]),
+        ("GP", Some(Fr), &[Fr]),
+        ("GQ", Some(Es), &[Es]),
+        ("GR", Some(El), &[El]),
+        ("GT", Some(Es), &[Es]),
+        ("GU", Some(En), &[En]),
+        ("GW", Some(Pt), &[Pt]),
+        ("GY", Some(En), &[En]),
+        ("HK", Some(Zh), &[Zh, En]),
+        ("HN", Some(Es), &[Es]),
+        ("HR", Some(Hr), &[Hr]),
+        ("HT", Some(Ht), &[Ht]),
+        ("HU", Some(Hu), &[Hu]),
+        ("ID", Some(Id), &[Id]),
+        ("IE", Some(En), &[En, Ga]),
+        ("IL", Some(He), &[He, En]),
+        ("IM", Some(En), &[En]),
+        ("IN", Some(Hi), &[Hi, Ar, As, Bn, Bo, En, Gu, Kn, Ks, Ml, Mr, Or, Pa, Sa, Sd, Ta, Te, Ur]),
+        ("IO", Some(En), &[En]),
+        ("IQ", Some(Ar), &[Ar]),
+        ("IR", Some(Fa), &[Fa, Az]),
+        ("IS", Some(Is), &[Is]),
+        ("IT", Some(It), &[It, Ca, De, Sc]),
+        ("JE", Some(En), &[En]),
+        ("JM", Some(En), &[En]),
+        ("JO", Some(Ar), &[Ar]),
+        ("JP", Some(Ja), &[Ja]),
+        ("KE", Some(Sw), &[Sw, Om, So]),
+        ("KG", Some(Ky), &[Ky]),
+        ("KH", Some(Km), &[Km]),
+        ("KI", Some(En), &[En]),
+        ("KM", Some(Ar), &[Ar]),
+        ("KN", Some(En), &[En]),
+        ("KP", Some(Ko), &[Ko]),
+        ("KR", Some(Ko), &[Ko]),
+        ("KW", Some(Ar), &[Ar]),
+        ("KY", Some(En), &[En]),
+        ("KZ", Some(Ru), &[Ru, Kk]),
+        ("LA", Some(Lo), &[Lo]),
+        ("LB", Some(Ar), &[Ar]),
+        ("LC", Some(En), &[En]),
+        ("LI", Some(De), &[De]),
+        ("LK", Some(Si), &[Si, Ta]),
+        ("LR", Some(En), &[En]),
+        ("LS", Some(St), &[St]),
+        ("LT", Some(Lt), &[Lt]),
+        ("LU", Some(Fr), &[Fr, De, Lb]),
+        ("LV", Some(Lv), &[Lv]),
+        ("LY", Some(Ar), &[Ar]),
+        ("MA", Some(Ar), &[Ar]),
+        ("MC", Some(Fr), &[Fr]),
+        ("MD", Some(Ro), &[Ro]),
+        ("ME", Some(Sr), &[Sr]),
+        ("MF", Some(Fr), &[Fr]),
+        ("MG", Some(Mg), &[Mg]),
+        ("MH", Some(En), &[En]),
+        ("MK", Some(Mk), &[Mk, Sq]),
+        ("ML", Some(Bm), &[Bm]),
+        ("MM", Some(My), &[My]),
+        ("MN", Some(Mn), &[Mn]),
+        ("MO", Some(Zh), &[Zh]),
+        ("MP", Some(En), &[En]),
+        ("MQ", Some(Fr), &[Fr]),
+        ("MR", Some(Ar), &[Ar]),
+        ("MS", Some(En), &[En]),
+        ("MT", Some(Mt), &[Mt]),
+        ("MU", Some(Fr), &[Fr]),
+        ("MV", Some(Dv), &[Dv]),
+        ("MW", Some(En), &[En]),
+        ("MX", Some(Es), &[Es]),
+        ("MY", Some(Ms), &[Ms]),
+        ("MZ", Some(Pt), &[Pt]),
+        ("NA", Some(Af), &[Af]),
+        ("NC", Some(Fr), &[Fr]),
+        ("NE", Some(Ha), &[Ha]),
+        ("NF", Some(En), &[En]),
+        ("NG", Some(En), &[En, Ha, Ig, Yo]),
+        ("NI", Some(Es), &[Es]),
+        ("NL", Some(Nl), &[Nl, Fy, Li]),
+        ("NO", Some(Nb), &[Nb, Nn, Se]),
+        ("NP", Some(Ne), &[Ne]),
+        ("NR", Some(En), &[En]),
+        ("NU", Some(En), &[En]),
+        ("NZ", Some(En), &[En, Mi]),
+        ("OM", Some(Ar), &[Ar]),
+        ("PA", Some(Es), &[Es]),
+        ("PE", Some(Es), &[Es]),
+        ("PF", Some(Fr), &[Fr]),
+        ("PH", None, &[En, Tl]),
+        ("PK", Some(Ur), &[Ur, Pa]),
+        ("PL", Some(Pl), &[Pl]),
+        ("PM", Some(Fr), &[Fr]),
+        ("PN", Some(En), &[En]),
+        ("PR", Some(Es), &[Es]),
+        ("PS", Some(Ar), &[Ar]),
+        ("PT", Some(Pt), &[Pt]),
+        ("PY", Some(Gn), &[Gn, Es]),
+        ("QA", Some(Ar), &[Ar]),
+        ("RE", Some(Fr), &[Fr]),
+        ("RO", Some(Ro), &[Ro]),
+        ("RS", Some(Sr), &[Sr]),
+        ("RU", Some(Ru), &[Ru, Ce, Cv, Os, Tt]),
+        ("RW", Some(Rw), &[Rw]),
+        ("SA", Some(Ar), &[Ar]),
+        ("SB", Some(En), &[En]),
+        ("SC", Some(Fr), &[Fr, En]),
+        ("SD", Some(Ar), &[Ar]),
+        ("SE", Some(Sv), &[Sv]),
+        ("SG", Some(En), &[En, Zh]),
+        ("SH", Some(En), &[En]),
+        ("SI", Some(Sl), &[Sl]),
+        ("SJ", Some(Nb), &[Nb]),
+        ("SK", Some(Sk), &[Sk]),
+        ("SL", Some(En), &[En]),
+        ("SM", Some(It), &[It]),
+        ("SN", Some(Wo), &[Wo, Ff]),
+        ("SO", Some(So), &[So]),
+        ("SR", Some(Nl), &[Nl]),
+        ("SS", Some(Ar), &[Ar]),
+        ("ST", Some(Pt), &[Pt]),
+        ("SV", Some(Es), &[Es]),
+        ("SX", Some(En), &[En]),
+        ("SY", Some(Ar), &[Ar]),
+        ("SZ", Some(En), &[En]),
+        ("TC", Some(En), &[En]),
+        ("TD", Some(Ar), &[Ar]),
+        ("TF", Some(Fr), &[Fr]),
+        ("TG", Some(Fr), &[Fr]),
+        ("TH", Some(Th), &[Th]),
+        ("TJ", Some(Tg), &[Tg]),
+        ("TL", Some(Pt), &[Pt]),
+        ("TM", Some(Tk), &[Tk]),
+        ("TN", Some(Ar), &[Ar]),
+        ("TO", Some(To), &[To]),
+        ("TR", Some(Tr), &[Tr, Ku]),
+        ("TT", Some(En), &[En]),
+        ("TW", Some(Zh), &[Zh]),
+        ("TZ", Some(Sw), &[Sw]),
+        ("UA", Some(Uk), &[Uk, Ru]),
+        ("UG", Some(Sw), &[Sw, Lg]),
+        ("UM", Some(En), &[En]),
+        ("US", Some(En), &[En, Es, Yi]),
+        ("UY", Some(Es), &[Es]),
+        ("UZ", Some(Uz), &[Uz]),
+        ("VA", Some(It), &[It]),
+        ("VC", Some(En), &[En]),
+        ("VE", Some(Es), &[Es]),
+        ("VG", Some(En), &[En]),
+        ("VI", Some(En), &[En]),
+        ("VN", Some(Vi), &[Vi]),
+        ("VU", Some(Bi), &[Bi]),
+        ("WF", Some(Fr), &[Fr]),
+        ("WS", Some(Sm), &[Sm]),
+        ("YE", Some(Ar), &[Ar]),
+        ("YT", Some(Fr), &[Fr]),
+        ("ZA", Some(En), &[En, Af, Nr, Ss, St, Tn, Ts, Ve, Xh, Zu]),
+        ("ZM", Some(En), &[En]),
+        ("ZW", Some(Sn), &[Sn, En]),
+    ]
+};
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, OfficialStatus, Region};
+
+    #[test]
+    fn sorted() {
+        assert!(super::REGIONS.windows(2).all(|w| w[0].0 < w[1].0));
+        for &(_, primary, languages) in &super::REGIONS {
+            assert!(primary.is_none() || primary == languages.first().copied());
+        }
+    }
+
+    #[test]
+    fn lookups() {
+        let region = |s| Region::from_const(s);
+        assert_eq!(
+            LanguageCode::primary_language_of(region("us")),
+            Some(LanguageCode::En)
+        );
+        assert_eq!(
+            LanguageCode::primary_language_of(region("419")),
+            Some(LanguageCode::Es)
+        );
+        assert_eq!(LanguageCode::primary_language_of(region("PH")), None);
+        assert!(LanguageCode::languages_of(region("PH")).contains(&LanguageCode::Tl));
+        assert!(LanguageCode::languages_of(region("ZZ")).is_empty());
+    }
+
+    #[test]
+    fn official_in() {
+        for language in LanguageCode::iter() {
+            let regions = language.official_in();
+            assert!(regions.windows(2).all(|w| w[0] < w[1]), "{:?}", language);
+            assert!(regions
+                .iter()
+                .all(|region| region.len() == 2 && region.bytes().all(|b| b.is_ascii_uppercase())));
+        }
+        assert!(LanguageCode::Fr.official_in().contains(&"CA"));
+        assert_eq!(LanguageCode::Eo.official_in(), [] as [&str; 0]);
+    }
+
+    #[test]
+    fn spoken_in() {
+        for language in LanguageCode::iter() {
+            let regions = language.spoken_in();
+            assert!(regions.windows(2).all(|w| w[0] < w[1]), "{:?}", language);
+            assert!(regions
+                .iter()
+                .all(|region| !language.official_in().contains(region)));
+        }
+        assert!(LanguageCode::Ru.spoken_in().contains(&"UA"));
+    }
+
+    #[test]
+    fn status_in() {
+        for language in LanguageCode::iter() {
+            for region in language.regional_in().iter().chain(language.minority_in()) {
+                assert!(!language.official_in().contains(region), "{:?}", language);
+            }
+        }
+        let region = |s| Region::from_const(s);
+        assert_eq!(
+            LanguageCode::Fr.status_in(region("FR")),
+            Some(OfficialStatus::Official)
+        );
+        assert_eq!(
+            LanguageCode::Fr.status_in(region("CH")),
+            Some(OfficialStatus::CoOfficial)
+        );
+        assert_eq!(
+            LanguageCode::Cy.status_in(region("GB")),
+            Some(OfficialStatus::Regional)
+        );
+        assert_eq!(LanguageCode::Cy.status_in(region("FR")), None);
+    }
+
+    #[test]
+    fn likely_region() {
+        assert_eq!(LanguageCode::En.likely_region(), Region::from_const("US"));
+        assert_eq!(LanguageCode::Es.likely_region(), Region::from_const("ES"));
+        assert_eq!(LanguageCode::Tw.likely_region(), Region::from_const("GH"));
+        let world: Vec<_> = LanguageCode::iter()
+            .filter(|language| !language.likely_region().is_country())
+            .collect();
+        assert_eq!(
+            world,
+            [
+                LanguageCode::Eo,
+                LanguageCode::Ia,
+                LanguageCode::Io,
+                LanguageCode::Vo
+            ]
+        );
+    }
+}
@@ -0,0 +1,454 @@
+//! Identifiers of languages in other databases, for joining the crate's data with them.
+
+use crate::LanguageCode;
+
+impl LanguageCode {
+    /// Returns the [Glottolog](https://glottolog.org/) code of the language, like `stan1293` for
+    /// English.
+    ///
+    /// Macrolanguages that Glottolog doesn't treat as a single language return the code of their
+    /// most widely spoken member, so Chinese returns Mandarin's `mand1415`. Every language
+    /// currently has a code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::En.glottocode(), Some("stan1293"));
+    /// assert_eq!(LanguageCode::Ja.glottocode(), Some("nucl1643"));
+    /// ```
+    #[must_use]
+    pub const fn glottocode(self) -> Option<&'static str> {
+        match self {
+            Self::Aa => Some("afar1241"),
+            Self::Ab => Some("abkh1244"),
+            Self::Ae => Some("aves1237"),
+            Self::Af => Some("afri1274"),
+            Self::Ak => Some("akan1250"),
+            Self::Am => Some("amha1245"),
+            Self::An => Some("arag1245"),
+            Self::Ar => Some("stan1318"),
+            Self::As => Some("assa1263"),
+            Self::Av => Some("avar1256"),
+            Self::Ay => Some("nucl1667"),
+            Self::Az => Some("nort2697"),
+            Self::Ba => Some("bash1264"),
+            Self::Be => Some("bela1254"),
+            Self::Bg => Some("bulg1262"),
+            Self::Bh => Some("bhoj1244"),
+            Self::Bi => Some("bisl1239"),
+            Self::Bm => Some("bamb1269"),
+            Self::Bn => Some("beng1280"),
+            Self::Bo => Some("tibe1272"),
+            Self::Br => Some("bret1244"),
+            Self::Bs => Some("bosn1245"),
+            Self::Ca => Some("stan1289"),
+            Self::Ce => Some("chec1245"),
+            Self::Ch => Some("cham1312"),
+            Self::Co => Some("cors1241"),
+            Self::Cr => Some("plai1258"),
+            Self::Cs => Some("czec1258"),
+            Self::Cu => Some("chur1257"),
+            Self::Cv => Some("chuv1255"),
+            Self::Cy => Some("wels1247"),
+            Self::Da => Some("dani1285"),
+            Self::De => Some("stan1295"),
+            Self::Dv => Some("dhiv1236"),
+            Self::Dz => Some("nucl1307"),
+            Self::Ee => Some("ewee1241"),
+            Self::El => Some("mode1248"),
+            Self::En => Some("stan1293"),
+            Self::Eo => Some("espe1235"),
+            Self::Es => Some("stan1288"),
+            Self::Et => Some("esto1258"),
+            Self::Eu => Some("basq1248"),
+            Self::Fa => Some("west2369"),
+            Self::Ff => Some("fula1264"),
+            Self::Fi => Some("finn1318"),
+            Self::Fj => Some("fiji1243"),
+            Self::Fo => Some("faro1244"),
+            Self::Fr => Some("stan1290"),
+            Self::Fy => Some("west2354"),
+            Self::Ga => Some("iris1253"),
+            Self::Gd => Some("scot1245"),
+            Self::Gl => Some("gali1258"),
+            Self::Gn => Some("para1311"),
+            Self::Gu => Some("guja1252"),
+            Self::Gv => Some("manx1243"),
+            Self::Ha => Some("haus1257"),
+            Self::He => Some("hebr1245"),
+            Self::Hi => Some("hind1269"),
+            Self::Ho => Some("hiri1237"),
+            Self::Hr => Some("croa1245"),
+            Self::Ht => Some("hait1244"),
+            Self::Hu => Some("hung1274"),
+            Self::Hy => Some("nucl1235"),
+            Self::Hz => Some("here1253"),
+            Self::Ia => Some("inte1239"),
+            Self::Id => Some("indo1316"),
+            Self::Ie => Some("occi1241"),
+            Self::Ig => Some("nucl1417"),
+            Self::Ii => Some("sich1238"),
+            Self::Ik => Some("inup1234"),
+            Self::Io => Some("idoo1234"),
+            Self::Is => Some("icel1247"),
+            Self::It => Some("ital1282"),
+            Self::Iu => Some("east2534"),
+            Self::Ja => Some("nucl1643"),
+            Self::Jv => Some("java1254"),
+            Self::Ka => Some("nucl1302"),
+            Self::Kg => Some("koon1244"),
+            Self::Ki => Some("kiku1240"),
+            Self::Kj => Some("kuan1248"),
+            Self::Kk => Some("kaza1248"),
+            Self::Kl => Some("kala1399"),
+            Self::Km => Some("cent1989"),
+            Self::Kn => Some("nucl1305"),
+            Self::Ko => Some("kore1280"),
+            Self::Kr => Some("kanu1279"),
+            Self::Ks => Some("kash1277"),
+            Self::Ku => Some("nort2641"),
+            Self::Kv => Some("komi1268"),
+            Self::Kw => Some("corn1251"),
+            Self::Ky => Some("kirg1245"),
+            Self::La => Some("lati1261"),
+            Self::Lb => Some("luxe1241"),
+            Self::Lg => Some("gand1255"),
+            Self::Li => Some("limb1263"),
+            Self::Ln => Some("ling1263"),
+            Self::Lo => Some("laoo1244"),
+            Self::Lt => Some("lith1251"),
+            Self::Lu => Some("luba1249"),
+            Self::Lv => Some("latv1249"),
+            Self::Mg => Some("plat1254"),
+            Self::Mh => Some("mars1254"),
+            Self::Mi => Some("maor1246"),
+            Self::Mk => Some("mace1250"),
+            Self::Ml => Some("mala1464"),
+            Self::Mn => Some("halh1238"),
+            Self::Mr => Some("mara1378"),
+            Self::Ms => Some("stan1306"),
+            Self::Mt => Some("malt1254"),
+            Self::My => Some("nucl1310"),
+            Self::Na => Some("naur1243"),
+            Self::Nb => Some("norw1259"),
+            Self::Nd => Some("nort2795"),
+            Self::Ne => Some("nepa1254"),
+            Self::Ng => Some("ndon1254"),
+            Self::Nl => Some("dutc1256"),
+            Self::Nn => Some("norw1262"),
+            Self::No => Some("norw1258"),
+            Self::Nr => Some("sout2808"),
+            Self::Nv => Some("nava1243"),
+            Self::Ny => Some("nyan1308"),
+            Self::Oc => Some("occi1239"),
+            Self::Oj => Some("ojib1241"),
+            Self::Om => Some("nucl1736"),
+            Self::Or => Some("oriy1255"),
+            Self::Os => Some("osse1243"),
+            Self::Pa => Some("panj1256"),
+            Self::Pi => Some("pali1273"),
+            Self::Pl => Some("poli1260"),
+            Self::Ps => Some("pash1269"),
+            Self::Pt => Some("port1283"),
+            Self::Qu => Some("quec1387"),
+            Self::Rm => Some("roma1326"),
+            Self::Rn => Some("rund1242"),
+            Self::Ro => Some("roma1327"),
+            Self::Ru => Some("russ1263"),
+            Self::Rw => Some("kiny1244"),
+            Self::Sa => Some("sans1269"),
+            Self::Sc => Some("sard1257"),
+            Self::Sd => Some("sind1272"),
+            Self::Se => Some("nort2671"),
+            Self::Sg => Some("sang1328"),
+            Self::Si => Some("sinh1246"),
+            Self::Sk => Some("slov1269"),
+            Self::Sl => Some("slov1268"),
+            Self::Sm => Some("samo1305"),
+            Self::Sn => Some("shon1251"),
+            Self::So => Some("soma1255"),
+            Self::Sq => Some("tosk1239"),
+            Self::Sr => Some("serb1264"),
+            Self::Ss => Some("swat1243"),
+            Self::St => Some("sout2807"),
+            Self::Su => Some("sund1252"),
+            Self::Sv => Some("swed1254"),
+            Self::Sw => Some("swah1253"),
+            Self::Ta => Some("tami1289"),
+            Self::Te => Some("telu1262"),
+            Self::Tg => Some("taji1245"),
+            Self::Th => Some("thai1261"),
+            Self::Ti => Some("tigr1271"),
+            Self::Tk => Some("turk1304"),
+            Self::Tl => Some("taga1270"),
+            Self::Tn => Some("tswa1253"),
+            Self::To => Some("tong1325"),
+            Self::Tr => Some("nucl1301"),
+            Self::Ts => Some("tson1249"),
+            Self::Tt => Some("tata1255"),
+            Self::Tw => Some("asan1239"),
+            Self::Ty => Some("tahi1242"),
+            Self::Ug => Some("uigh1240"),
+            Self::Uk => Some("ukra1253"),
+            Self::Ur => Some("urdu1245"),
+            Self::Uz => Some("nort2690"),
+            Self::Ve => Some("vend1245"),
+            Self::Vi => Some("viet1252"),
+            Self::Vo => Some("vola1234"),
+            Self::Wa => Some("wall1255"),
+            Self::Wo => Some("nucl1347"),
+            Self::Xh => Some("xhos1239"),
+            Self::Yi => Some("yidd1255"),
+            Self::Yo => Some("yoru1245"),
+            Self::Za => Some("yong1276"),
+            Self::Zh => Some("mand1415"),
+            Self::Zu => Some("zulu1248"),
+        }
+    }
+
+    /// Returns the [Wikidata](https://www.wikidata.org/) item of the language, like `Q1860` for
+    /// English.
+    ///
+    /// Every language currently has an item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::En.wikidata_id(), Some("Q1860"));
+    /// assert_eq!(LanguageCode::De.wikidata_id(), Some("Q188"));
+    /// ```
+    #[must_use]
+    pub const fn wikidata_id(self) -> Option<&'static str> {
+        match self {
+            Self::Aa => Some("Q27811"),
+            Self::Ab => Some("Q5111"),
+            Self::Ae => Some("Q29572"),
+            Self::Af => Some("Q14196"),
+            Self::Ak => Some("Q28026"),
+            Self::Am => Some("Q28244"),
+            Self::An => Some("Q8765"),
+            Self::Ar => Some("Q13955"),
+            Self::As => Some("Q29401"),
+            Self::Av => Some("Q29561"),
+            Self::Ay => Some("Q4627"),
+            Self::Az => Some("Q9292"),
+            Self::Ba => Some("Q13389"),
+            Self::Be => Some("Q9091"),
+            Self::Bg => Some("Q7918"),
+            Self::Bh => Some("Q135305"),
+            Self::Bi => Some("Q35452"),
+            Self::Bm => Some("Q33243"),
+            Self::Bn => Some("Q9610"),
+            Self::Bo => Some("Q34271"),
+            Self::Br => Some("Q12107"),
+            Self::Bs => Some("Q9303"),
+            Self::Ca => Some("Q7026"),
+            Self::Ce => Some("Q33350"),
+            Self::Ch => Some("Q33262"),
+            Self::Co => Some("Q33111"),
+            Self::Cr => Some("Q33390"),
+            Self::Cs => Some("Q9056"),
+            Self::Cu => Some("Q35499"),
+            Self::Cv => Some("Q33348"),
+            Self::Cy => Some("Q9309"),
+            Self::Da => Some("Q9035"),
+            Self::De => Some("Q188"),
+            Self::Dv => Some("Q32656"),
+            Self::Dz => Some("Q33081"),
+            Self::Ee => Some("Q30005"),
+            Self::El => Some("Q9129"),
+            Self::En => Some("Q1860"),
+            Self::Eo => Some("Q143"),
+            Self::Es => Some("Q1321"),
+            Self::Et => Some("Q9072"),
+            Self::Eu => Some("Q8752"),
+            Self::Fa => Some("Q9168"),
+            Self::Ff => Some("Q33454"),
+            Self::Fi => Some("Q1412"),
+            Self::Fj => Some("Q33295"),
+            Self::Fo => Some("Q25258"),
+            Self::Fr => Some("Q150"),
+            Self::Fy => Some("Q27175"),
+            Self::Ga => Some("Q9142"),
+            Self::Gd => Some("Q9314"),
+            Self::Gl => Some("Q9307"),
+            Self::Gn => Some("Q35876"),
+            Self::Gu => Some("Q5137"),
+            Self::Gv => Some("Q12175"),
+            Self::Ha => Some("Q56475"),
+            Self::He => Some("Q9288"),
+            Self::Hi => Some("Q1568"),
+            Self::Ho => Some("Q33617"),
+            Self::Hr => Some("Q6654"),
+            Self::Ht => Some("Q33491"),
+            Self::Hu => Some("Q9067"),
+            Self::Hy => Some("Q8785"),
+            Self::Hz => Some("Q33315"),
+            Self::Ia => Some("Q35934"),
+            Self::Id => Some("Q9240"),
+            Self::Ie => Some("Q35850"),
+            Self::Ig => Some("Q33578"),
+            Self::Ii => Some("Q34235"),
+            Self::Ik => Some("Q27183"),
+            Self::Io => Some("Q35224"),
+            Self::Is => Some("Q294"),
+            Self::It => Some("Q652"),
+            Self::Iu => Some("Q29921"),
+            Self::Ja => Some("Q5287"),
+            Self::Jv => Some("Q33549"),
+            Self::Ka => Some("Q8108"),
+            Self::Kg => Some("Q33702"),
+            Self::Ki => Some("Q33587"),
+            Self::Kj => Some("Q1405077"),
+            Self::Kk => Some("Q9252"),
+            Self::Kl => Some("Q25355"),
+            Self::Km => Some("Q9205"),
+            Self::Kn => Some("Q33673"),
+            Self::Ko => Some("Q9176"),
+            Self::Kr => Some("Q36094"),
+            Self::Ks => Some("Q33552"),
+            Self::Ku => Some("Q36368"),
+            Self::Kv => Some("Q36126"),
+            Self::Kw => Some("Q25289"),
+            Self::Ky => Some("Q9255"),
+            Self::La => Some("Q397"),
+            Self::Lb => Some("Q9051"),
+            Self::Lg => Some("Q33368"),
+            Self::Li => Some("Q102172"),
+            Self::Ln => Some("Q36217"),
+            Self::Lo => Some("Q9211"),
+            Self::Lt => Some("Q9083"),
+            Self::Lu => Some("Q36157"),
+            Self::Lv => Some("Q9078"),
+            Self::Mg => Some("Q7930"),
+            Self::Mh => Some("Q36280"),
+            Self::Mi => Some("Q36451"),
+            Self::Mk => Some("Q9296"),
+            Self::Ml => Some("Q36236"),
+            Self::Mn => Some("Q9246"),
+            Self::Mr => Some("Q1571"),
+            Self::Ms => Some("Q9237"),
+            Self::Mt => Some("Q9166"),
+            Self::My => Some("Q9228"),
+            Self::Na => Some("Q13307"),
+            Self::Nb => Some("Q25167"),
+            Self::Nd => Some("Q35613"),
+            Self::Ne => Some("Q33823"),
+            Self::Ng => Some("Q33900"),
+            Self::Nl => Some("Q7411"),
+            Self::Nn => Some("Q25164"),
+            Self::No => Some("Q9043"),
+            Self::Nr => Some("Q36785"),
+            Self::Nv => Some("Q13310"),
+            Self::Ny => Some("Q33273"),
+            Self::Oc => Some("Q14185"),
+            Self::Oj => Some("Q33875"),
+            Self::Om => Some("Q33864"),
+            Self::Or => Some("Q33810"),
+            Self::Os => Some("Q33968"),
+            Self::Pa => Some("Q58635"),
+            Self::Pi => Some("Q36727"),
+            Self::Pl => Some("Q809"),
+            Self::Ps => Some("Q58680"),
+            Self::Pt => Some("Q5146"),
+            Self::Qu => Some("Q5218"),
+            Self::Rm => Some("Q13199"),
+            Self::Rn => Some("Q33583"),
+            Self::Ro => Some("Q7913"),
+            Self::Ru => Some("Q7737"),
+            Self::Rw => Some("Q33573"),
+            Self::Sa => Some("Q11059"),
+            Self::Sc => Some("Q33976"),
+            Self::Sd => Some("Q33997"),
+            Self::Se => Some("Q33947"),
+            Self::Sg => Some("Q33954"),
+            Self::Si => Some("Q13267"),
+            Self::Sk => Some("Q9058"),
+            Self::Sl => Some("Q9063"),
+            Self::Sm => Some("Q34011"),
+            Self::Sn => Some("Q34004"),
+            Self::So => Some("Q13275"),
+            Self::Sq => Some("Q8748"),
+            Self::Sr => Some("Q9299"),
+            Self::Ss => Some("Q34014"),
+            Self::St => Some("Q34340"),
+            Self::Su => Some("Q34002"),
+            Self::Sv => Some("Q9027"),
+            Self::Sw => Some("Q7838"),
+            Self::Ta => Some("Q5885"),
+            Self::Te => Some("Q8097"),
+            Self::Tg => Some("Q9260"),
+            Self::Th => Some("Q9217"),
+            Self::Ti => Some("Q29291"),
+            Self::Tk => Some("Q9267"),
+            Self::Tl => Some("Q34057"),
+            Self::Tn => Some("Q34137"),
+            Self::To => Some("Q34094"),
+            Self::Tr => Some("Q256"),
+            Self::Ts => Some("Q34327"),
+            Self::Tt => Some("Q25285"),
+            Self::Tw => Some("Q36850"),
+            Self::Ty => Some("Q34128"),
+            Self::Ug => Some("Q13263"),
+            Self::Uk => Some("Q8798"),
+            Self::Ur => Some("Q1617"),
+            Self::Uz => Some("Q9264"),
+            Self::Ve => Some("Q32704"),
+            Self::Vi => Some("Q9199"),
+            Self::Vo => Some("Q36986"),
+            Self::Wa => Some("Q34219"),
+            Self::Wo => Some("Q34257"),
+            Self::Xh => Some("Q13218"),
+            Self::Yi => Some("Q8641"),
+            Self::Yo => Some("Q34311"),
+            Self::Za => Some("Q13216"),
+            Self::Zh => Some("Q7850"),
+            Self::Zu => Some("Q10179"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LanguageCode;
+
+    #[test]
+    fn glottocodes() {
+        let mut codes = Vec::new();
+        for language in LanguageCode::iter() {
+            let code = language
+                .glottocode()
+                .unwrap_or_else(|| panic!("{:?}", language));
+            let (name, number) = code.split_at(4);
+            assert!(name.bytes().all(|b| b.is_ascii_lowercase()), "{}", code);
+            assert!(number.len() == 4 && number.bytes().all(|b| b.is_ascii_digit()));
+            codes.push(code);
+        }
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), LanguageCode::iter().count());
+        assert_eq!(LanguageCode::Zh.glottocode(), Some("mand1415"));
+    }
+
+    #[test]
+    fn wikidata_ids() {
+        let mut ids: Vec<_> = LanguageCode::iter()
+            .map(|language| {
+                language
+                    .wikidata_id()
+                    .unwrap_or_else(|| panic!("{:?}", language))
+            })
+            .collect();
+        assert!(ids
+            .iter()
+            .all(|id| id.starts_with('Q') && id[1..].bytes().all(|b| b.is_ascii_digit())));
+        let len = ids.len();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), len);
+    }
+}
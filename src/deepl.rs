@@ -0,0 +1,133 @@
+//! Language identifiers used by the [DeepL API](https://developers.deepl.com/docs/resources/supported-languages).
+//!
+//! DeepL accepts plain language codes for source languages, but distinguishes regional variants
+//! for some target languages, such as `EN-GB` and `EN-US`.
+//!
+//! # Examples
+//!
+//! ```
+//! use isolanguage_1::{deepl, LanguageCode};
+//!
+//! assert_eq!(deepl::source_code(LanguageCode::Pt), Some("PT"));
+//! assert_eq!(deepl::target_codes(LanguageCode::Pt), ["PT-BR", "PT-PT"]);
+//! assert_eq!(deepl::from_code("pt-br"), Some(LanguageCode::Pt));
+//! assert!(!deepl::SOURCE_LANGUAGES.contains(LanguageCode::Cy));
+//! ```
+
+use crate::{LanguageCode, LanguageSet};
+
+macro_rules! deepl_table {
+    ($(($variant:ident, $source:literal, [$($target:literal),+]),)+) => {
+        /// Every language DeepL can translate from.
+        pub const SOURCE_LANGUAGES: LanguageSet =
+            LanguageSet::from_slice(&[$(LanguageCode::$variant,)+]);
+
+        /// Every language DeepL can translate into.
+        ///
+        /// DeepL translates into the same languages it translates from, and only differs in the
+        /// regional variants of its [target codes](target_codes), so this is an alias of
+        /// [`SOURCE_LANGUAGES`].
+        pub const TARGET_LANGUAGES: LanguageSet = SOURCE_LANGUAGES;
+
+        /// Returns the DeepL `source_lang` code of a language, or `None` if DeepL doesn't support it.
+        #[must_use]
+        pub const fn source_code(language: LanguageCode) -> Option<&'static str> {
+            match language {
+                $(LanguageCode::$variant => Some($source),)+
+                _ => None,
+            }
+        }
+
+        /// Returns the DeepL `target_lang` codes of a language.
+        ///
+        /// Languages that DeepL splits into regional variants have more than one code; languages
+        /// that DeepL doesn't support have none.
+        #[must_use]
+        pub const fn target_codes(language: LanguageCode) -> &'static [&'static str] {
+            match language {
+                $(LanguageCode::$variant => &[$($target),+],)+
+                _ => &[],
+            }
+        }
+
+        /// Parses a DeepL source or target language code, ignoring ASCII case.
+        #[must_use]
+        pub fn from_code(code: &str) -> Option<LanguageCode> {
+            $(
+                if code.eq_ignore_ascii_case($source)
+                    $(|| code.eq_ignore_ascii_case($target))+
+                {
+                    return Some(LanguageCode::$variant);
+                }
+            )+
+            None
+        }
+    }
+}
+
+deepl_table! {
+    (Ar, "AR", ["AR"]),
+    (Bg, "BG", ["BG"]),
+    (Cs, "CS", ["CS"]),
+    (Da, "DA", ["DA"]),
+    (De, "DE", ["DE"]),
+    (El, "EL", ["EL"]),
+    (En, "EN", ["EN-GB", "EN-US"]),
+    (Es, "ES", ["ES"]),
+    (Et, "ET", ["ET"]),
+    (Fi, "FI", ["FI"]),
+    (Fr, "FR", ["FR"]),
+    (Hu, "HU", ["HU"]),
+    (Id, "ID", ["ID"]),
+    (It, "IT", ["IT"]),
+    (Ja, "JA", ["JA"]),
+    (Ko, "KO", ["KO"]),
+    (Lt, "LT", ["LT"]),
+    (Lv, "LV", ["LV"]),
+    (Nb, "NB", ["NB"]),
+    (Nl, "NL", ["NL"]),
+    (Pl, "PL", ["PL"]),
+    (Pt, "PT", ["PT-BR", "PT-PT"]),
+    (Ro, "RO", ["RO"]),
+    (Ru, "RU", ["RU"]),
+    (Sk, "SK", ["SK"]),
+    (Sl, "SL", ["SL"]),
+    (Sv, "SV", ["SV"]),
+    (Tr, "TR", ["TR"]),
+    (Uk, "UK", ["UK"]),
+    (Zh, "ZH", ["ZH-HANS", "ZH-HANT"]),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_code, source_code, target_codes, SOURCE_LANGUAGES, TARGET_LANGUAGES};
+    use crate::LanguageCode;
+
+    #[test]
+    fn codes() {
+        assert_eq!(source_code(LanguageCode::En), Some("EN"));
+        assert_eq!(target_codes(LanguageCode::En), ["EN-GB", "EN-US"]);
+        assert_eq!(source_code(LanguageCode::Ga), None);
+        assert!(target_codes(LanguageCode::Ga).is_empty());
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(from_code("EN"), Some(LanguageCode::En));
+        assert_eq!(from_code("en-us"), Some(LanguageCode::En));
+        assert_eq!(from_code("ZH-HANT"), Some(LanguageCode::Zh));
+        assert_eq!(from_code("EN-AU"), None);
+        assert_eq!(from_code("GA"), None);
+    }
+
+    #[test]
+    fn sets() {
+        for language in SOURCE_LANGUAGES.union(TARGET_LANGUAGES) {
+            let source = source_code(language).unwrap();
+            assert_eq!(from_code(source), Some(language));
+            for target in target_codes(language) {
+                assert_eq!(from_code(target), Some(language));
+            }
+        }
+    }
+}
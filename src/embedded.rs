@@ -0,0 +1,59 @@
+//! Conversions into [`heapless`] strings, for targets without an allocator. Enabled by the
+//! `embedded` feature.
+
+use heapless::String;
+
+use crate::LanguageCode;
+
+impl LanguageCode {
+    /// Returns the name of the language in a fixed-capacity string, or `None` if it is longer than
+    /// `N` bytes.
+    ///
+    /// A capacity of [`MAX_NAME_LEN`](Self::MAX_NAME_LEN) fits every name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let name = LanguageCode::Fi.heapless_name::<{ LanguageCode::MAX_NAME_LEN }>().unwrap();
+    /// assert_eq!(name, "Finnish");
+    /// assert_eq!(LanguageCode::Fi.heapless_name::<4>(), None);
+    /// ```
+    #[must_use]
+    pub fn heapless_name<const N: usize>(self) -> Option<String<N>> {
+        String::try_from(self.name()).ok()
+    }
+}
+
+impl From<LanguageCode> for String<2> {
+    /// Converts the language to its 2 letter code.
+    fn from(language: LanguageCode) -> Self {
+        String::try_from(language.code()).expect("codes are 2 bytes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heapless::String;
+
+    use crate::LanguageCode;
+
+    #[test]
+    fn conversions() {
+        for language in LanguageCode::iter() {
+            assert_eq!(String::<2>::from(language), language.code());
+            assert_eq!(
+                language
+                    .heapless_name::<{ LanguageCode::MAX_NAME_LEN }>()
+                    .unwrap(),
+                language.name()
+            );
+            let code = String::<2>::from(language);
+            assert_eq!(
+                LanguageCode::from_bytes_ignore_case(code.as_bytes()),
+                Some(language)
+            );
+        }
+    }
+}
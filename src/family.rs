@@ -0,0 +1,525 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{bytes_eq, LanguageCode, BRANCHES, LANGUAGE_CODES};
+
+macro_rules! families_table {
+    ($(($variant:ident, $languages:ident, $name:literal, $code:expr),)+) => {
+        /// A language family, one of [`FAMILIES`](crate::FAMILIES).
+        ///
+        /// With serde, families are serialized as their [names](Self::name).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use isolanguage_1::{LanguageCode, LanguageFamily};
+        ///
+        /// assert_eq!(LanguageFamily::NigerCongo.name(), "Niger–Congo");
+        /// assert_eq!(LanguageFamily::ALL.len(), isolanguage_1::FAMILIES.len());
+        /// assert!(LanguageFamily::TURKIC_LANGUAGES.contains(&LanguageCode::Tr));
+        /// ```
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub enum LanguageFamily {
+            $(
+                #[doc=$name]
+                #[cfg_attr(feature = "serde", serde(rename=$name))]
+                $variant,
+            )+
+        }
+
+        /// Every language family with its languages, in the order of [`FAMILIES`](crate::FAMILIES),
+        /// for building indices of families at compile time.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use isolanguage_1::{LanguageCode, LanguageFamily, FAMILY_LANGUAGES};
+        ///
+        /// let (family, languages) = FAMILY_LANGUAGES[0];
+        /// assert_eq!(family, LanguageFamily::AfroAsiatic);
+        /// assert!(languages.contains(&LanguageCode::Ar));
+        /// ```
+        pub const FAMILY_LANGUAGES: [(LanguageFamily, &[LanguageCode]); crate::FAMILIES.len()] =
+            [$((LanguageFamily::$variant, LanguageFamily::$languages)),+];
+
+        impl LanguageFamily {
+            /// Every language family, in the order of [`FAMILIES`](crate::FAMILIES).
+            pub const ALL: [Self; crate::FAMILIES.len()] = [$(Self::$variant),+];
+
+            $(
+                #[doc = concat!("The ", $name, " languages, in the order of [`LANGUAGE_CODES`](crate::LANGUAGE_CODES).")]
+                pub const $languages: &'static [LanguageCode] =
+                    &languages_of::<{ count(Self::$variant) }>(Self::$variant);
+            )+
+
+            /// Returns the English name of the family, as returned by
+            /// [`LanguageCode::family`](crate::LanguageCode::family).
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)+
+                }
+            }
+
+            /// Returns the [ISO 639-5](https://en.wikipedia.org/wiki/ISO_639-5) code of the
+            /// family, like `trk` for Turkic.
+            ///
+            /// Returns `None` for families without an ISO 639-5 code, like Koreanic and language
+            /// isolates. Both Caucasian families return the code of the North Caucasian languages,
+            /// `ccn`, and creoles return the code of creoles and pidgins, `crp`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use isolanguage_1::LanguageFamily;
+            ///
+            /// assert_eq!(LanguageFamily::Dravidian.code(), Some("dra"));
+            /// assert_eq!(LanguageFamily::Koreanic.code(), None);
+            /// ```
+            #[must_use]
+            pub const fn code(self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => $code,)+
+                }
+            }
+        }
+    };
+}
+
+families_table! {
+    (AfroAsiatic, AFRO_ASIATIC_LANGUAGES, "Afro-Asiatic", Some("afa")),
+    (Algonquian, ALGONQUIAN_LANGUAGES, "Algonquian", Some("alg")),
+    (Austroasiatic, AUSTROASIATIC_LANGUAGES, "Austroasiatic", Some("aav")),
+    (Austronesian, AUSTRONESIAN_LANGUAGES, "Austronesian", Some("map")),
+    (Aymaran, AYMARAN_LANGUAGES, "Aymaran", None),
+    (Constructed, CONSTRUCTED_LANGUAGES, "Constructed", Some("art")),
+    (Creole, CREOLE_LANGUAGES, "Creole", Some("crp")),
+    (DeneYeniseian, DENE_YENISEIAN_LANGUAGES, "Dené–Yeniseian", None),
+    (Dravidian, DRAVIDIAN_LANGUAGES, "Dravidian", Some("dra")),
+    (EskimoAleut, ESKIMO_ALEUT_LANGUAGES, "Eskimo–Aleut", Some("esx")),
+    (IndoEuropean, INDO_EUROPEAN_LANGUAGES, "Indo-European", Some("ine")),
+    (Japonic, JAPONIC_LANGUAGES, "Japonic", Some("jpx")),
+    (Kartvelian, KARTVELIAN_LANGUAGES, "Kartvelian", Some("ccs")),
+    (Koreanic, KOREANIC_LANGUAGES, "Koreanic", None),
+    (LanguageIsolate, LANGUAGE_ISOLATE_LANGUAGES, "Language isolate", None),
+    (Mongolic, MONGOLIC_LANGUAGES, "Mongolic", Some("xgn")),
+    (NigerCongo, NIGER_CONGO_LANGUAGES, "Niger–Congo", Some("nic")),
+    (NiloSaharan, NILO_SAHARAN_LANGUAGES, "Nilo-Saharan", Some("ssa")),
+    (NortheastCaucasian, NORTHEAST_CAUCASIAN_LANGUAGES, "Northeast Caucasian", Some("ccn")),
+    (NorthwestCaucasian, NORTHWEST_CAUCASIAN_LANGUAGES, "Northwest Caucasian", Some("ccn")),
+    (Quechuan, QUECHUAN_LANGUAGES, "Quechuan", Some("qwe")),
+    (SinoTibetan, SINO_TIBETAN_LANGUAGES, "Sino-Tibetan", Some("sit")),
+    (TaiKadai, TAI_KADAI_LANGUAGES, "Tai–Kadai", Some("tai")),
+    (Tupian, TUPIAN_LANGUAGES, "Tupian", Some("tup")),
+    (Turkic, TURKIC_LANGUAGES, "Turkic", Some("trk")),
+    (Uralic, URALIC_LANGUAGES, "Uralic", Some("urj")),
+}
+
+/// Returns the number of languages in a family.
+const fn count(family: LanguageFamily) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < LANGUAGE_CODES.len() {
+        if LANGUAGE_CODES[i].family_enum() as u8 == family as u8 {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Returns the languages in a family, which must number `N`.
+const fn languages_of<const N: usize>(family: LanguageFamily) -> [LanguageCode; N] {
+    let mut languages = [LanguageCode::Aa; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < LANGUAGE_CODES.len() {
+        if LANGUAGE_CODES[i].family_enum() as u8 == family as u8 {
+            languages[len] = LANGUAGE_CODES[i];
+            len += 1;
+        }
+        i += 1;
+    }
+    languages
+}
+
+impl LanguageFamily {
+    /// Returns the family with an ISO 639-5 [code](Self::code), or `None` if no family has the
+    /// code.
+    ///
+    /// `ccn` is shared by both Caucasian families, so it returns `None` as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageFamily;
+    ///
+    /// assert_eq!(LanguageFamily::from_code("trk"), Some(LanguageFamily::Turkic));
+    /// assert_eq!(LanguageFamily::from_code("ccn"), None);
+    /// assert_eq!(LanguageFamily::from_code("TRK"), None);
+    /// ```
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        let mut families = Self::ALL
+            .into_iter()
+            .filter(|family| family.code() == Some(code));
+        match (families.next(), families.next()) {
+            (Some(family), None) => Some(family),
+            _ => None,
+        }
+    }
+}
+
+impl LanguageCode {
+    /// Returns the [family](Self::family) of the language as a [`LanguageFamily`], which can be
+    /// matched on exhaustively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, LanguageFamily};
+    ///
+    /// assert_eq!(LanguageCode::Kk.family_enum(), LanguageFamily::Turkic);
+    /// assert_eq!(LanguageCode::Sw.family_enum(), LanguageFamily::NigerCongo);
+    /// ```
+    #[must_use]
+    pub const fn family_enum(self) -> LanguageFamily {
+        let family = self.family().as_bytes();
+        let mut i = 0;
+        while i < LanguageFamily::ALL.len() {
+            if bytes_eq(LanguageFamily::ALL[i].name().as_bytes(), family) {
+                return LanguageFamily::ALL[i];
+            }
+            i += 1;
+        }
+        panic!("language family is missing from LanguageFamily")
+    }
+
+    /// Returns the languages in a family, in the order of [`LANGUAGE_CODES`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, LanguageFamily};
+    ///
+    /// let turkic: Vec<_> = LanguageCode::languages_in_family(LanguageFamily::Turkic).collect();
+    /// assert!(turkic.contains(&LanguageCode::Tr));
+    /// assert!(!turkic.contains(&LanguageCode::Hu));
+    /// ```
+    pub fn languages_in_family(family: LanguageFamily) -> impl Iterator<Item = Self> {
+        LANGUAGE_CODES
+            .into_iter()
+            .filter(move |language| language.family_enum() == family)
+    }
+}
+
+impl LanguageFamily {
+    /// Returns the languages in the family, like
+    /// [`LanguageCode::languages_in_family`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, LanguageFamily};
+    ///
+    /// assert_eq!(LanguageFamily::Koreanic.languages().collect::<Vec<_>>(), [LanguageCode::Ko]);
+    /// ```
+    pub fn languages(self) -> impl Iterator<Item = LanguageCode> {
+        LanguageCode::languages_in_family(self)
+    }
+}
+
+impl Display for LanguageFamily {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl TryFrom<&str> for LanguageFamily {
+    type Error = ParseLanguageFamilyError;
+
+    /// Tries to convert from the name or ISO 639-5 code of a family, ignoring ASCII case. Hyphens
+    /// and dashes in names are interchangeable, so both `Niger-Congo` and `Niger–Congo` are
+    /// accepted.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let normalize = |c: char| match c {
+            '-' | '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2014}' | '\u{2212}' => {
+                '-'
+            }
+            _ => c.to_ascii_lowercase(),
+        };
+        Self::ALL
+            .into_iter()
+            .find(|family| {
+                family
+                    .name()
+                    .chars()
+                    .map(normalize)
+                    .eq(s.chars().map(normalize))
+            })
+            .or_else(|| Self::from_code(&s.to_ascii_lowercase()))
+            .ok_or_else(|| ParseLanguageFamilyError {
+                family: s.to_owned(),
+            })
+    }
+}
+
+impl FromStr for LanguageFamily {
+    type Err = ParseLanguageFamilyError;
+
+    /// Calls TryFrom.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+/// An error parsing a [`LanguageFamily`].
+#[derive(Debug, Clone)]
+pub struct ParseLanguageFamilyError {
+    /// The family that could not be parsed.
+    pub family: String,
+}
+
+impl Display for ParseLanguageFamilyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} is not a language family", self.family)
+    }
+}
+
+/// A node of the language family tree: a family, a [branch](LanguageCode::branch) of a family, or a
+/// language.
+///
+/// Families contain their branches and the languages not in any branch, and branches contain
+/// their languages. The crate has no data on subgroups within branches, so branches are the only
+/// level between families and languages.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{Clade, LanguageCode, LanguageFamily};
+///
+/// let germanic = Clade::Language(LanguageCode::De).parent().unwrap();
+/// assert_eq!(germanic, Clade::Branch("Germanic"));
+/// assert_eq!(germanic.parent(), Some(Clade::Family(LanguageFamily::IndoEuropean)));
+/// assert!(germanic.children().contains(&Clade::Language(LanguageCode::Nl)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Clade {
+    /// A language family.
+    Family(LanguageFamily),
+    /// A branch of a family, one of [`BRANCHES`](crate::BRANCHES).
+    Branch(&'static str),
+    /// A single language.
+    Language(LanguageCode),
+}
+
+impl Clade {
+    /// Returns the English name of the family, branch or language.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Family(family) => family.name(),
+            Self::Branch(branch) => branch,
+            Self::Language(language) => language.name(),
+        }
+    }
+
+    /// Returns the node containing this one, or `None` for families and unknown branches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{Clade, LanguageCode, LanguageFamily};
+    ///
+    /// assert_eq!(
+    ///     Clade::Language(LanguageCode::Ja).parent(),
+    ///     Some(Clade::Family(LanguageFamily::Japonic))
+    /// );
+    /// assert_eq!(Clade::Family(LanguageFamily::Japonic).parent(), None);
+    /// ```
+    #[must_use]
+    pub fn parent(self) -> Option<Self> {
+        match self {
+            Self::Family(_) => None,
+            Self::Branch(branch) => LANGUAGE_CODES
+                .into_iter()
+                .find(|language| language.branch() == Some(branch))
+                .map(|language| Self::Family(language.family_enum())),
+            Self::Language(language) => Some(match language.branch() {
+                Some(branch) => Self::Branch(branch),
+                None => Self::Family(language.family_enum()),
+            }),
+        }
+    }
+
+    /// Returns the nodes directly contained in this one: for a family, its branches in
+    /// alphabetical order followed by its languages outside any branch; for a branch, its
+    /// languages. Languages are in the order of [`LANGUAGE_CODES`], and have no children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{Clade, LanguageCode, LanguageFamily};
+    ///
+    /// assert_eq!(
+    ///     Clade::Family(LanguageFamily::Kartvelian).children(),
+    ///     [Clade::Language(LanguageCode::Ka)]
+    /// );
+    /// assert!(Clade::Family(LanguageFamily::IndoEuropean)
+    ///     .children()
+    ///     .contains(&Clade::Branch("Slavic")));
+    /// ```
+    #[must_use]
+    pub fn children(self) -> Vec<Self> {
+        match self {
+            Self::Family(family) => BRANCHES
+                .into_iter()
+                .map(Self::Branch)
+                .filter(|branch| branch.parent() == Some(self))
+                .chain(
+                    family
+                        .languages()
+                        .filter(|language| language.branch().is_none())
+                        .map(Self::Language),
+                )
+                .collect(),
+            Self::Branch(branch) => LANGUAGE_CODES
+                .into_iter()
+                .filter(|language| language.branch() == Some(branch))
+                .map(Self::Language)
+                .collect(),
+            Self::Language(_) => Vec::new(),
+        }
+    }
+}
+
+impl Display for Clade {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Clade, LanguageCode, LanguageFamily, BRANCHES, FAMILIES, FAMILY_LANGUAGES};
+
+    #[test]
+    fn names() {
+        let names = LanguageFamily::ALL.map(LanguageFamily::name);
+        assert_eq!(names, FAMILIES);
+        assert!(LanguageFamily::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(format!("{:>8}", LanguageFamily::Turkic), "  Turkic");
+    }
+
+    #[test]
+    fn family_enum() {
+        for language in LanguageCode::iter() {
+            assert_eq!(language.family_enum().name(), language.family());
+        }
+        assert_eq!(
+            LanguageCode::Eu.family_enum(),
+            LanguageFamily::LanguageIsolate
+        );
+    }
+
+    #[test]
+    fn languages() {
+        let total: usize = LanguageFamily::ALL
+            .into_iter()
+            .map(|family| {
+                let count = family.languages().count();
+                assert!(count > 0, "{}", family);
+                count
+            })
+            .sum();
+        assert_eq!(total, LanguageCode::iter().len());
+        assert!(LanguageFamily::URALIC_LANGUAGES
+            .iter()
+            .copied()
+            .eq(LanguageFamily::Uralic.languages()));
+        assert_eq!(LanguageFamily::KOREANIC_LANGUAGES, [LanguageCode::Ko]);
+        for (family, languages) in FAMILY_LANGUAGES {
+            assert!(languages.iter().copied().eq(family.languages()));
+        }
+    }
+
+    #[test]
+    fn codes() {
+        for family in LanguageFamily::ALL {
+            if let Some(code) = family.code() {
+                if code != "ccn" {
+                    assert_eq!(LanguageFamily::from_code(code), Some(family));
+                }
+            }
+        }
+        assert_eq!(LanguageFamily::from_code(""), None);
+    }
+
+    #[test]
+    fn clades() {
+        for branch in BRANCHES {
+            let branch = Clade::Branch(branch);
+            let family = branch.parent().unwrap();
+            // Every language of a branch is in the same family.
+            for child in branch.children() {
+                let Clade::Language(language) = child else {
+                    panic!("{:?}", child);
+                };
+                assert_eq!(Clade::Family(language.family_enum()), family);
+                assert_eq!(child.parent(), Some(branch));
+            }
+        }
+        let mut languages = 0;
+        for family in LanguageFamily::ALL {
+            for child in Clade::Family(family).children() {
+                assert_eq!(child.parent(), Some(Clade::Family(family)));
+                languages += match child {
+                    Clade::Branch(_) => child.children().len(),
+                    _ => 1,
+                };
+            }
+        }
+        assert_eq!(languages, LanguageCode::iter().len());
+        assert_eq!(Clade::Branch("Klingon").parent(), None);
+        assert!(Clade::Language(LanguageCode::En).children().is_empty());
+    }
+
+    #[test]
+    fn parse() {
+        for family in LanguageFamily::ALL {
+            assert_eq!(family.name().parse::<LanguageFamily>().unwrap(), family);
+            assert_eq!(
+                family
+                    .name()
+                    .to_ascii_uppercase()
+                    .parse::<LanguageFamily>()
+                    .unwrap(),
+                family
+            );
+        }
+        assert_eq!(
+            LanguageFamily::try_from("niger-congo").unwrap(),
+            LanguageFamily::NigerCongo
+        );
+        assert_eq!(
+            LanguageFamily::try_from("Afro–Asiatic").unwrap(),
+            LanguageFamily::AfroAsiatic
+        );
+        assert_eq!(
+            "TRK".parse::<LanguageFamily>().unwrap(),
+            LanguageFamily::Turkic
+        );
+        let error = "Klingon".parse::<LanguageFamily>().unwrap_err();
+        assert_eq!(error.to_string(), "Klingon is not a language family");
+        assert!("ccn".parse::<LanguageFamily>().is_err());
+        assert!("Niger Congo".parse::<LanguageFamily>().is_err());
+    }
+}
@@ -0,0 +1,172 @@
+//! Fuzzy matching of misspelled codes and names.
+
+use alloc::vec::Vec;
+
+use crate::{LanguageCode, ParseError, LANGUAGE_CODES};
+
+/// The lowest similarity a candidate returned by [`LanguageCode::closest_by`] can have.
+const MIN_SIMILARITY: f32 = 0.5;
+
+/// The most suggestions returned by [`ParseError::suggestions`].
+const MAX_SUGGESTIONS: usize = 3;
+
+impl LanguageCode {
+    /// Ranks the languages whose codes or English names are closest to the input, to suggest
+    /// "did you mean" alternatives for input that doesn't parse.
+    ///
+    /// Each language is scored by its most similar code or name, as one minus the edit distance
+    /// divided by the length of the longer string, ignoring case and surrounding whitespace. Only
+    /// languages scoring at least 0.5 are returned, best first, with ties in table order. An exact
+    /// match scores 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::closest("swedish")[0], (LanguageCode::Sv, 1.0));
+    /// assert_eq!(LanguageCode::closest("sweedish")[0].0, LanguageCode::Sv);
+    /// assert_eq!(LanguageCode::closest("deu")[0].0, LanguageCode::De);
+    /// assert!(LanguageCode::closest("xyzzy").is_empty());
+    /// ```
+    #[must_use]
+    pub fn closest(input: &str) -> Vec<(Self, f32)> {
+        let input = input.to_lowercase();
+        Self::closest_by(&input, |input, candidate| {
+            similarity(input, &candidate.to_lowercase())
+        })
+    }
+
+    /// Ranks languages like [`closest`](Self::closest), but with a custom similarity metric, for
+    /// example one that transliterates non-Latin input first.
+    ///
+    /// The metric is called with the input, trimmed of surrounding whitespace, and each code and
+    /// English name of every language, and should return a score from 0 to 1. Each language is
+    /// scored by its best candidate, and only languages scoring at least 0.5 are returned, best
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let starts_with = |input: &str, candidate: &str| {
+    ///     if candidate.starts_with(input) { 1.0 } else { 0.0 }
+    /// };
+    /// let matches = LanguageCode::closest_by("Norwegian", starts_with);
+    /// assert_eq!(matches.len(), 3);
+    /// ```
+    pub fn closest_by(input: &str, mut metric: impl FnMut(&str, &str) -> f32) -> Vec<(Self, f32)> {
+        let input = input.trim();
+        let mut candidates: Vec<(Self, f32)> = LANGUAGE_CODES
+            .iter()
+            .map(|&language| {
+                let similarity = [
+                    language.code(),
+                    language.code_t(),
+                    language.code_b(),
+                    language.name(),
+                ]
+                .into_iter()
+                .map(|candidate| metric(input, candidate))
+                .fold(0.0, f32::max);
+                (language, similarity)
+            })
+            .filter(|&(_, similarity)| similarity >= MIN_SIMILARITY)
+            .collect();
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+        candidates
+    }
+}
+
+impl ParseError {
+    /// Returns up to three languages the input was likely meant to be, best first, for showing
+    /// "did you mean" hints. They are computed on each call with [`LanguageCode::closest`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let error = "germna".parse::<LanguageCode>().unwrap_err();
+    /// assert_eq!(error.suggestions()[0], LanguageCode::De);
+    /// ```
+    #[must_use]
+    pub fn suggestions(&self) -> Vec<LanguageCode> {
+        LanguageCode::closest(&self.language)
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(language, _)| language)
+            .collect()
+    }
+}
+
+/// Returns one minus the Levenshtein distance between the strings divided by the length of the
+/// longer one, counted in characters.
+fn similarity(a: &str, b: &str) -> f32 {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    let mut a_len = 0;
+    for (i, a) in a.chars().enumerate() {
+        a_len += 1;
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    let longest = a_len.max(b.len());
+    if longest == 0 {
+        return 0.0;
+    }
+    1.0 - row[b.len()] as f32 / longest as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::similarity;
+    use crate::LanguageCode;
+
+    #[test]
+    fn similarity_scores() {
+        assert_eq!(similarity("kitten", "sitting"), 1.0 - 3.0 / 7.0);
+        assert_eq!(similarity("nl", "nl"), 1.0);
+        assert_eq!(similarity("", ""), 0.0);
+        assert_eq!(similarity("日本", "日本語"), 1.0 - 1.0 / 3.0);
+    }
+
+    #[test]
+    fn closest() {
+        let candidates = LanguageCode::closest(" SV ");
+        assert_eq!(candidates[0], (LanguageCode::Sv, 1.0));
+        assert!(candidates.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(candidates.iter().all(|&(_, similarity)| similarity >= 0.5));
+        assert_eq!(LanguageCode::closest("germn")[0].0, LanguageCode::De);
+        assert!(LanguageCode::closest("").is_empty());
+    }
+
+    #[test]
+    fn closest_by() {
+        let mut calls = 0;
+        let candidates = LanguageCode::closest_by(" x ", |input, _| {
+            assert_eq!(input, "x");
+            calls += 1;
+            0.75
+        });
+        assert_eq!(calls, 184 * 4);
+        assert_eq!(candidates.len(), 184);
+        assert_eq!(candidates[0], (LanguageCode::Ab, 0.75));
+        assert!(LanguageCode::closest_by("x", |_, _| 0.25).is_empty());
+    }
+
+    #[test]
+    fn suggestions() {
+        let error = "fra ".parse::<LanguageCode>().unwrap_err();
+        assert_eq!(error.suggestions()[0], LanguageCode::Fr);
+        assert!(error.suggestions().len() <= 3);
+        let error = "qqqqqqqq".parse::<LanguageCode>().unwrap_err();
+        assert!(error.suggestions().is_empty());
+    }
+}
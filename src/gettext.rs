@@ -0,0 +1,116 @@
+//! Resolution of gettext-style translation directories.
+//!
+//! gettext looks for translations in a directory per locale, named in POSIX form like `de_DE`,
+//! `de` or `de_DE.UTF-8`, each containing an `LC_MESSAGES` directory. Requires the `std` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use isolanguage_1::{gettext, LanguageRange};
+//!
+//! let preferred: Vec<LanguageRange> = vec!["de-AT".parse().unwrap(), "en".parse().unwrap()];
+//! if let Some(dir) = gettext::resolve_dir("/usr/share/locale", &preferred).unwrap() {
+//!     // Picks `de_AT`, then `de`, then another German locale like `de_DE`, then English.
+//!     println!("{}", dir.join("LC_MESSAGES/app.mo").display());
+//! }
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::slice;
+
+use crate::{negotiate, LanguageRange, LanguageTag, Locale};
+
+/// Returns the locale directory under `root` that best matches the preferred ranges, or `None` if
+/// none of them match.
+///
+/// Only directories containing `LC_MESSAGES` are considered, and the codeset and modifier of their
+/// names (`.UTF-8`, `@latin`) are ignored. Each range is tried in order of preference, first with
+/// RFC 4647 lookup, so `de-AT` falls back to `de`, and then by [filtering](negotiate::filter) with
+/// its language alone, so `de-AT` can also pick `de_DE`, before moving on to the next range.
+///
+/// # Errors
+///
+/// Fails if `root` can't be read.
+pub fn resolve_dir(
+    root: impl AsRef<Path>,
+    preferred: &[LanguageRange],
+) -> io::Result<Option<PathBuf>> {
+    let root = root.as_ref();
+
+    let mut names: Vec<String> = fs::read_dir(root)?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| root.join(name).join("LC_MESSAGES").is_dir())
+        .collect();
+    names.sort();
+
+    let mut locales: Vec<(Locale, String)> = Vec::new();
+    for name in names {
+        let locale = name.split(['.', '@']).next().unwrap_or_default();
+        if let Ok(locale) = locale.parse::<Locale>() {
+            if !locales.iter().any(|(other, _)| *other == locale) {
+                locales.push((locale, name));
+            }
+        }
+    }
+
+    let tags: Vec<LanguageTag> = locales
+        .iter()
+        .map(|&(locale, _)| LanguageTag::from(locale))
+        .collect();
+    let tag = preferred.iter().find_map(|range| {
+        negotiate::lookup(slice::from_ref(range), &tags).or_else(|| {
+            let language = range.subtags().next().filter(|&language| language != "*")?;
+            let language: LanguageRange = language.parse().ok()?;
+            negotiate::filter(&[language], &tags).into_iter().next()
+        })
+    });
+
+    Ok(tag.map(|tag| {
+        let (_, name) = &locales[tags
+            .iter()
+            .position(|t| t == tag)
+            .expect("tag is available")];
+        root.join(name)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::resolve_dir;
+    use crate::LanguageRange;
+
+    #[test]
+    fn resolve() {
+        let root =
+            std::env::temp_dir().join(format!("isolanguage-1-gettext-{}", std::process::id()));
+        for dir in ["de_DE.UTF-8", "de_DE", "en", "pt", "pt_BR", "sr@latin"] {
+            fs::create_dir_all(root.join(dir).join("LC_MESSAGES")).unwrap();
+        }
+        fs::create_dir_all(root.join("fr")).unwrap();
+
+        let resolve = |preferred: &[&str]| {
+            let preferred: Vec<LanguageRange> = preferred
+                .iter()
+                .map(|range| range.parse().unwrap())
+                .collect();
+            resolve_dir(&root, &preferred)
+                .unwrap()
+                .map(|dir| dir.file_name().unwrap().to_str().unwrap().to_owned())
+        };
+        assert_eq!(resolve(&["pt-PT"]).as_deref(), Some("pt"));
+        assert_eq!(resolve(&["pt-BR", "pt"]).as_deref(), Some("pt_BR"));
+        assert_eq!(resolve(&["de"]).as_deref(), Some("de_DE"));
+        assert_eq!(resolve(&["de", "pt"]).as_deref(), Some("de_DE"));
+        assert_eq!(resolve(&["de-AT", "en"]).as_deref(), Some("de_DE"));
+        assert_eq!(resolve(&["fr", "en"]).as_deref(), Some("en"));
+        assert_eq!(resolve(&["sr-RS"]).as_deref(), Some("sr@latin"));
+        assert_eq!(resolve(&["fr"]), None);
+        assert_eq!(resolve(&["*-BR"]), None);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,55 @@
+//! Locales supported by [Google Assistant actions](https://developers.google.com/assistant/console/languages-locales).
+//!
+//! Like Alexa, the Assistant identifies languages by locales, but it supports more regions of
+//! some languages.
+//!
+//! # Examples
+//!
+//! ```
+//! use isolanguage_1::{google_assistant, LanguageCode};
+//!
+//! assert_eq!(google_assistant::code(LanguageCode::Fr), Some("fr-FR"));
+//! assert_eq!(google_assistant::locale("fr-CH".parse().unwrap()), Some("fr-CH"));
+//! assert_eq!(google_assistant::locale("fr-LU".parse().unwrap()), Some("fr-FR"));
+//! assert!(google_assistant::LANGUAGES.contains(LanguageCode::Ko));
+//! ```
+
+voice_locales! {
+    "Google Assistant",
+    (Zh, ["zh-TW", "zh-HK"]),
+    (Da, ["da-DK"]),
+    (Nl, ["nl-NL", "nl-BE"]),
+    (En, ["en-US", "en-AU", "en-BE", "en-CA", "en-GB", "en-IE", "en-IN", "en-SG"]),
+    (Fr, ["fr-FR", "fr-BE", "fr-CA", "fr-CH"]),
+    (De, ["de-DE", "de-AT", "de-CH"]),
+    (Hi, ["hi-IN"]),
+    (Id, ["id-ID"]),
+    (It, ["it-IT"]),
+    (Ja, ["ja-JP"]),
+    (Ko, ["ko-KR"]),
+    (No, ["no-NO"]),
+    (Pl, ["pl-PL"]),
+    (Pt, ["pt-BR", "pt-PT"]),
+    (Ru, ["ru-RU"]),
+    (Es, ["es-ES", "es-419", "es-MX"]),
+    (Sv, ["sv-SE"]),
+    (Th, ["th-TH"]),
+    (Tr, ["tr-TR"]),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{locale, parse_locale, LANGUAGES, LOCALES};
+    use crate::{LanguageCode, Locale};
+
+    #[test]
+    fn locales() {
+        for &code in LOCALES {
+            let parsed = parse_locale(code).unwrap();
+            assert!(LANGUAGES.contains(parsed.language));
+            assert_eq!(locale(parsed), Some(code));
+        }
+        assert_eq!(locale("es-AR".parse().unwrap()), Some("es-ES"));
+        assert_eq!(locale(Locale::from(LanguageCode::Uk)), None);
+    }
+}
@@ -0,0 +1,103 @@
+//! Links between languages and the historical stages they descend from.
+
+use crate::{LanguageCode, LANGUAGE_CODES};
+
+impl LanguageCode {
+    /// Returns the ISO 639-3 codes of the earlier, attested stages of the language, most recent
+    /// first, like `enm` (Middle English) and `ang` (Old English) for English.
+    ///
+    /// Only stages with their own code are listed, so most languages return an empty slice. Latin
+    /// (`lat`) and Sanskrit (`san`) are listed for the Romance and Indo-Aryan languages, and can be
+    /// followed back with [`descendants`](Self::descendants).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::El.predecessors(), ["grc", "gmy"]);
+    /// assert_eq!(LanguageCode::No.predecessors(), ["non"]);
+    /// assert!(LanguageCode::Sw.predecessors().is_empty());
+    /// ```
+    #[must_use]
+    #[rustfmt::skip]
+    pub const fn predecessors(self) -> &'static [&'static str] {
+        match self {
+            Self::Am | Self::Ti => &["gez"],
+            Self::As | Self::Bn | Self::Gu | Self::Hi | Self::Mr | Self::Ne | Self::Or | Self::Pa | Self::Ur => &["san"],
+            Self::Be | Self::Ru | Self::Uk => &["orv"],
+            Self::Br => &["xbm", "obt"],
+            Self::Ca | Self::Co | Self::Gl | Self::It | Self::Pt | Self::Rm | Self::Ro | Self::Sc => &["lat"],
+            Self::Cy => &["wlm", "owl"],
+            Self::Da | Self::Fo | Self::Is | Self::Nb | Self::Nn | Self::No | Self::Sv => &["non"],
+            Self::De | Self::Lb => &["gmh", "goh"],
+            Self::El => &["grc", "gmy"],
+            Self::En => &["enm", "ang"],
+            Self::Es => &["osp", "lat"],
+            Self::Fa => &["pal", "peo"],
+            Self::Fr | Self::Wa => &["frm", "fro", "lat"],
+            Self::Fy => &["ofs"],
+            Self::Ga | Self::Gd | Self::Gv => &["mga", "sga"],
+            Self::He => &["hbo"],
+            Self::Hu => &["ohu"],
+            Self::Hy => &["xcl"],
+            Self::Ja => &["ojp"],
+            Self::Jv => &["kaw"],
+            Self::Ka => &["oge"],
+            Self::Km => &["okz"],
+            Self::Ko => &["oko"],
+            Self::Kw => &["cnx", "oco"],
+            Self::Li => &["odt"],
+            Self::Lt => &["olt"],
+            Self::Mn => &["cmg", "xng"],
+            Self::My => &["obr"],
+            Self::Nl => &["dum", "odt"],
+            Self::Oc => &["pro", "lat"],
+            Self::Sa => &["vsn"],
+            Self::Ta => &["oty"],
+            Self::Tr => &["ota", "otk"],
+            Self::Ug | Self::Uz => &["chg"],
+            Self::Yi => &["gmh"],
+            Self::Zh => &["ltc", "och"],
+            _ => &[],
+        }
+    }
+
+    /// Returns an iterator over the languages that list this language among their
+    /// [`predecessors`](Self::predecessors), in table order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert!(LanguageCode::La.descendants().any(|language| language == LanguageCode::Ro));
+    /// assert!(LanguageCode::Sa.descendants().any(|language| language == LanguageCode::Hi));
+    /// assert_eq!(LanguageCode::En.descendants().count(), 0);
+    /// ```
+    pub fn descendants(self) -> impl Iterator<Item = LanguageCode> {
+        LANGUAGE_CODES
+            .into_iter()
+            .filter(move |language| language.predecessors().contains(&self.code_t()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LanguageCode;
+
+    #[test]
+    fn predecessors() {
+        for language in LanguageCode::iter() {
+            for &code in language.predecessors() {
+                assert!(
+                    code.len() == 3 && code.bytes().all(|b| b.is_ascii_lowercase()),
+                    "{}",
+                    code
+                );
+                assert_ne!(code, language.code_t());
+            }
+        }
+        assert_eq!(LanguageCode::La.descendants().count(), 12);
+    }
+}
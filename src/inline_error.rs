@@ -0,0 +1,122 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::{LanguageCode, ParseError};
+
+impl LanguageCode {
+    /// Parses a two letter language code like [`try_from`](Self::try_from), but returns an error
+    /// that stores the input inline instead of allocating, so failed parses are cheap and work
+    /// without an allocator.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input isn't a valid ISO 639-1 code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::parse_inline("nl").unwrap(), LanguageCode::Nl);
+    /// assert_eq!(LanguageCode::parse_inline("xx").unwrap_err().input(), "xx");
+    /// ```
+    pub fn parse_inline(s: &str) -> Result<Self, InlineParseError> {
+        Self::from_bytes(s.as_bytes()).ok_or_else(|| InlineParseError::new(s))
+    }
+}
+
+/// An error parsing a language from its two letter language code, that stores the input in a
+/// fixed size buffer instead of a `String`. Inputs longer than
+/// [`CAPACITY`](Self::CAPACITY) bytes are truncated.
+///
+/// Returned by [`LanguageCode::parse_inline`], and convertible into a [`ParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InlineParseError {
+    buf: [u8; Self::CAPACITY],
+    len: u8,
+    truncated: bool,
+}
+
+impl InlineParseError {
+    /// The most bytes of the input that are stored.
+    pub const CAPACITY: usize = 16;
+
+    fn new(input: &str) -> Self {
+        let mut len = input.len().min(Self::CAPACITY);
+        while !input.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut buf = [0; Self::CAPACITY];
+        buf[..len].copy_from_slice(&input.as_bytes()[..len]);
+        Self {
+            buf,
+            len: len as u8,
+            truncated: len < input.len(),
+        }
+    }
+
+    /// Returns the input that could not be parsed, truncated to at most
+    /// [`CAPACITY`](Self::CAPACITY) bytes on a character boundary.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        core::str::from_utf8(&self.buf[..usize::from(self.len)])
+            .expect("input is truncated on a character boundary")
+    }
+
+    /// Returns whether the input was too long to be stored in full.
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl Display for InlineParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{} is not a valid ISO 639-1 2 letter language code",
+            self.input(),
+            if self.truncated { "…" } else { "" }
+        )
+    }
+}
+
+impl From<InlineParseError> for ParseError {
+    fn from(error: InlineParseError) -> Self {
+        Self::new(error.input())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InlineParseError, LanguageCode, ParseError};
+
+    #[test]
+    fn parse_inline() {
+        for language in LanguageCode::iter() {
+            assert_eq!(
+                LanguageCode::parse_inline(language.code()).unwrap(),
+                language
+            );
+        }
+        let error = LanguageCode::parse_inline("EN").unwrap_err();
+        assert_eq!(error.input(), "EN");
+        assert!(!error.is_truncated());
+        assert_eq!(
+            error.to_string(),
+            "EN is not a valid ISO 639-1 2 letter language code"
+        );
+        assert_eq!(ParseError::from(error).language, "EN");
+    }
+
+    #[test]
+    fn truncation() {
+        let error = LanguageCode::parse_inline("abcdefghijklmnopqrstuvwxyz").unwrap_err();
+        assert_eq!(error.input(), "abcdefghijklmnop");
+        assert!(error.is_truncated());
+        assert!(error.to_string().starts_with("abcdefghijklmnop… "));
+
+        let error = LanguageCode::parse_inline("日本語日本語").unwrap_err();
+        assert_eq!(error.input(), "日本語日本");
+        assert!(error.input().len() <= InlineParseError::CAPACITY);
+    }
+}
@@ -0,0 +1,128 @@
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter, Write};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::LanguageCode;
+
+/// Text paired with the language it is written in.
+///
+/// With the `serde` feature, it is (de)serialized as a JSON-LD value object,
+/// `{"@language": "en", "@value": "..."}`. It displays as an RDF literal, like `"Bonjour"@fr`.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LangString, LanguageCode};
+///
+/// let title = LangString::new(LanguageCode::Fr, "Le \"Titre\"");
+/// assert_eq!(title.to_string(), r#""Le \"Titre\""@fr"#);
+/// assert_eq!(title.as_lang_str().text, "Le \"Titre\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LangString {
+    /// The language of the text.
+    #[cfg_attr(feature = "serde", serde(rename = "@language"))]
+    pub lang: LanguageCode,
+    /// The text.
+    #[cfg_attr(feature = "serde", serde(rename = "@value"))]
+    pub text: String,
+}
+
+impl LangString {
+    /// Creates a language-tagged string.
+    #[must_use]
+    pub fn new(lang: LanguageCode, text: impl Into<String>) -> Self {
+        Self {
+            lang,
+            text: text.into(),
+        }
+    }
+
+    /// Borrows the string as a [`LangStr`].
+    #[must_use]
+    pub fn as_lang_str(&self) -> LangStr<'_> {
+        LangStr::new(self.lang, &self.text)
+    }
+}
+
+impl From<LangStr<'_>> for LangString {
+    fn from(s: LangStr<'_>) -> Self {
+        Self::new(s.lang, s.text)
+    }
+}
+
+impl Display for LangString {
+    /// Writes the string as an RDF literal, like `"Bonjour"@fr`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.as_lang_str(), f)
+    }
+}
+
+/// Borrowed text paired with the language it is written in; the borrowed form of [`LangString`].
+///
+/// It is (de)serialized and displayed the same way as [`LangString`], and borrows its text when
+/// deserialized from formats that allow it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LangStr<'a> {
+    /// The language of the text.
+    #[cfg_attr(feature = "serde", serde(rename = "@language"))]
+    pub lang: LanguageCode,
+    /// The text.
+    #[cfg_attr(feature = "serde", serde(rename = "@value"))]
+    pub text: &'a str,
+}
+
+impl<'a> LangStr<'a> {
+    /// Creates a borrowed language-tagged string.
+    #[must_use]
+    pub const fn new(lang: LanguageCode, text: &'a str) -> Self {
+        Self { lang, text }
+    }
+}
+
+impl Display for LangStr<'_> {
+    /// Writes the string as an RDF literal, like `"Bonjour"@fr`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = String::from('"');
+        for c in self.text.chars() {
+            match c {
+                '"' => s.push_str("\\\""),
+                '\\' => s.push_str("\\\\"),
+                '\n' => s.push_str("\\n"),
+                '\r' => s.push_str("\\r"),
+                c => s.push(c),
+            }
+        }
+        write!(s, "\"@{}", self.lang.code())?;
+        f.pad(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LangStr, LangString, LanguageCode};
+
+    #[test]
+    fn display() {
+        let s = LangStr::new(LanguageCode::En, "a\\b\nc");
+        assert_eq!(s.to_string(), r#""a\\b\nc"@en"#);
+        assert_eq!(LangString::from(s).to_string(), s.to_string());
+        let s = LangStr::new(LanguageCode::Fr, "Oui");
+        assert_eq!(format!("[{:>10}]", s), r#"[  "Oui"@fr]"#);
+        assert_eq!(format!("[{:<10}]", LangString::from(s)), r#"["Oui"@fr  ]"#);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn json_ld() {
+        let json = r#"{"@language":"de","@value":"Hallo"}"#;
+        let s: LangStr<'_> = serde_json::from_str(json).unwrap();
+        assert_eq!(s, LangStr::new(LanguageCode::De, "Hallo"));
+        let s: LangString = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&s).unwrap(), json);
+    }
+}
@@ -0,0 +1,233 @@
+//! Windows locale identifiers. Enabled by the `windows-lcid` feature.
+
+use crate::parser::from_code_639_2;
+use crate::LanguageCode;
+
+impl LanguageCode {
+    /// Returns the language of a Windows locale identifier (LCID) or language identifier (LANGID),
+    /// like `0x0409` for English (United States), as returned by `GetUserDefaultUILanguage` or
+    /// `GetUserDefaultLCID`.
+    ///
+    /// The sort ID in the upper bits of an LCID is ignored. Languages without an ISO 639-1 code,
+    /// like Upper Sorbian or Filipino, and the Lule, Southern, Skolt and Inari Sami sub-languages,
+    /// return `None`. Dari, Cusco Quechua and Central Kurdish are mapped to the macrolanguages
+    /// Persian, Quechua and Kurdish.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_lcid(0x0409), Some(LanguageCode::En));
+    /// assert_eq!(LanguageCode::from_lcid(0x0814), Some(LanguageCode::Nn));
+    /// assert_eq!(LanguageCode::from_lcid(0x241A), Some(LanguageCode::Sr));
+    /// assert_eq!(LanguageCode::from_lcid(0x0464), None);
+    /// ```
+    #[must_use]
+    pub const fn from_lcid(lcid: u32) -> Option<Self> {
+        let primary = lcid & 0x3FF;
+        let sub = (lcid >> 10) & 0x3F;
+        Some(match primary {
+            0x01 => Self::Ar,
+            0x02 => Self::Bg,
+            0x03 => Self::Ca,
+            0x04 => Self::Zh,
+            0x05 => Self::Cs,
+            0x06 => Self::Da,
+            0x07 => Self::De,
+            0x08 => Self::El,
+            0x09 => Self::En,
+            0x0A => Self::Es,
+            0x0B => Self::Fi,
+            0x0C => Self::Fr,
+            0x0D => Self::He,
+            0x0E => Self::Hu,
+            0x0F => Self::Is,
+            0x10 => Self::It,
+            0x11 => Self::Ja,
+            0x12 => Self::Ko,
+            0x13 => Self::Nl,
+            0x14 => match sub {
+                0 => Self::No,
+                1 | 0x1F => Self::Nb,
+                2 | 0x1E => Self::Nn,
+                _ => return None,
+            },
+            0x15 => Self::Pl,
+            0x16 => Self::Pt,
+            0x17 => Self::Rm,
+            0x18 => Self::Ro,
+            0x19 => Self::Ru,
+            0x1A => match sub {
+                0 | 1 | 4 => Self::Hr,
+                2 | 3 | 6 | 7 | 9..=12 | 0x1B | 0x1C | 0x1F => Self::Sr,
+                5 | 8 | 0x19 | 0x1A | 0x1E => Self::Bs,
+                _ => return None,
+            },
+            0x1B => Self::Sk,
+            0x1C => Self::Sq,
+            0x1D => Self::Sv,
+            0x1E => Self::Th,
+            0x1F => Self::Tr,
+            0x20 => Self::Ur,
+            0x21 => Self::Id,
+            0x22 => Self::Uk,
+            0x23 => Self::Be,
+            0x24 => Self::Sl,
+            0x25 => Self::Et,
+            0x26 => Self::Lv,
+            0x27 => Self::Lt,
+            0x28 => Self::Tg,
+            0x29 | 0x8C => Self::Fa,
+            0x2A => Self::Vi,
+            0x2B => Self::Hy,
+            0x2C => Self::Az,
+            0x2D => Self::Eu,
+            0x2F => Self::Mk,
+            0x30 => Self::St,
+            0x31 => Self::Ts,
+            0x32 => Self::Tn,
+            0x33 => Self::Ve,
+            0x34 => Self::Xh,
+            0x35 => Self::Zu,
+            0x36 => Self::Af,
+            0x37 => Self::Ka,
+            0x38 => Self::Fo,
+            0x39 => Self::Hi,
+            0x3A => Self::Mt,
+            0x3B => match sub {
+                0..=3 => Self::Se,
+                _ => return None,
+            },
+            0x3C => Self::Ga,
+            0x3D => Self::Yi,
+            0x3E => Self::Ms,
+            0x3F => Self::Kk,
+            0x40 => Self::Ky,
+            0x41 => Self::Sw,
+            0x42 => Self::Tk,
+            0x43 => Self::Uz,
+            0x44 => Self::Tt,
+            0x45 => Self::Bn,
+            0x46 => Self::Pa,
+            0x47 => Self::Gu,
+            0x48 => Self::Or,
+            0x49 => Self::Ta,
+            0x4A => Self::Te,
+            0x4B => Self::Kn,
+            0x4C => Self::Ml,
+            0x4D => Self::As,
+            0x4E => Self::Mr,
+            0x4F => Self::Sa,
+            0x50 => Self::Mn,
+            0x51 => Self::Bo,
+            0x52 => Self::Cy,
+            0x53 => Self::Km,
+            0x54 => Self::Lo,
+            0x55 => Self::My,
+            0x56 => Self::Gl,
+            0x59 => Self::Sd,
+            0x5B => Self::Si,
+            0x5D => Self::Iu,
+            0x5E => Self::Am,
+            0x60 => Self::Ks,
+            0x61 => Self::Ne,
+            0x62 => Self::Fy,
+            0x63 => Self::Ps,
+            0x65 => Self::Dv,
+            0x67 => Self::Ff,
+            0x68 => Self::Ha,
+            0x6A => Self::Yo,
+            0x6B => Self::Qu,
+            0x6D => Self::Ba,
+            0x6E => Self::Lb,
+            0x6F => Self::Kl,
+            0x70 => Self::Ig,
+            0x71 => Self::Kr,
+            0x72 => Self::Om,
+            0x73 => Self::Ti,
+            0x74 => Self::Gn,
+            0x76 => Self::La,
+            0x77 => Self::So,
+            0x78 => Self::Ii,
+            0x7E => Self::Br,
+            0x80 => Self::Ug,
+            0x81 => Self::Mi,
+            0x82 => Self::Oc,
+            0x83 => Self::Co,
+            0x87 => Self::Rw,
+            0x88 => Self::Wo,
+            0x91 => Self::Gd,
+            0x92 => Self::Ku,
+            _ => return None,
+        })
+    }
+
+    /// Returns the language of a Windows culture name, like `en-US`, `sr-Latn-RS` or the legacy
+    /// `zh-CHS`, as used by .NET's `CultureInfo` and `LocaleNameToLCID`.
+    ///
+    /// Culture names are mostly BCP 47 tags, but only the primary language is checked. Like
+    /// [`from_lcid`](Self::from_lcid), `prs`, `quz` and `ckb` are mapped to their
+    /// macrolanguages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_culture_name("de-CH"), Some(LanguageCode::De));
+    /// assert_eq!(LanguageCode::from_culture_name("zh-CHT"), Some(LanguageCode::Zh));
+    /// assert_eq!(LanguageCode::from_culture_name("prs-AF"), Some(LanguageCode::Fa));
+    /// assert_eq!(LanguageCode::from_culture_name("fil-PH"), None);
+    /// ```
+    #[must_use]
+    pub fn from_culture_name(name: &str) -> Option<Self> {
+        let primary = name.split(['-', '_']).next()?;
+        match primary.to_ascii_lowercase().as_str() {
+            "prs" => Some(Self::Fa),
+            "quz" => Some(Self::Qu),
+            "ckb" => Some(Self::Ku),
+            primary if primary.len() == 2 => Self::from_bytes(primary.as_bytes()),
+            primary => from_code_639_2(primary, false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LanguageCode;
+
+    #[test]
+    fn lcids() {
+        assert_eq!(LanguageCode::from_lcid(0x0804), Some(LanguageCode::Zh));
+        assert_eq!(LanguageCode::from_lcid(0x0414), Some(LanguageCode::Nb));
+        assert_eq!(LanguageCode::from_lcid(0x0014), Some(LanguageCode::No));
+        assert_eq!(LanguageCode::from_lcid(0x041A), Some(LanguageCode::Hr));
+        assert_eq!(LanguageCode::from_lcid(0x141A), Some(LanguageCode::Bs));
+        assert_eq!(LanguageCode::from_lcid(0x7C1A), Some(LanguageCode::Sr));
+        assert_eq!(LanguageCode::from_lcid(0x043B), Some(LanguageCode::Se));
+        assert_eq!(LanguageCode::from_lcid(0x103B), None);
+        assert_eq!(LanguageCode::from_lcid(0x042E), None);
+        // The sort ID is ignored.
+        assert_eq!(LanguageCode::from_lcid(0x0001_0407), Some(LanguageCode::De));
+        assert_eq!(LanguageCode::from_lcid(0), None);
+    }
+
+    #[test]
+    fn culture_names() {
+        assert_eq!(
+            LanguageCode::from_culture_name("sr-Cyrl-RS"),
+            Some(LanguageCode::Sr)
+        );
+        assert_eq!(
+            LanguageCode::from_culture_name("EN"),
+            Some(LanguageCode::En)
+        );
+        assert_eq!(
+            LanguageCode::from_culture_name("ckb-IQ"),
+            Some(LanguageCode::Ku)
+        );
+        assert_eq!(LanguageCode::from_culture_name(""), None);
+        assert_eq!(LanguageCode::from_culture_name("x-IV"), None);
+    }
+}
@@ -7,21 +7,166 @@
 //!
 //! The main type is the `LanguageCode` type, which is an enum for every single language in ISO
 //! 639-1. It optionally implements Serialize and Deserialize too.
+//!
+//! `LanguageCode` serializes as its 2 letter code, so maps keyed by it, like
+//! `HashMap<LanguageCode, T>`, are string-keyed maps in formats such as JSON, YAML and TOML.
+//! Non-self-describing formats such as bincode and postcard store the variant index instead.
+//!
+//! Other optional features are:
+//!
+//! - `std` (enabled by default): conversions from `OsStr` and the `gettext` module. Without it, the
+//!   crate is `no_std` but still requires `alloc`.
+//! - `bytemuck`: `bytemuck`'s `Contiguous`, `CheckedBitPattern` and `NoUninit` for languages.
+//! - `cldr-names`: the names of languages in other languages and lists of them, from CLDR.
+//! - `cldr-plurals`: the plural categories of each language, from CLDR.
+//! - `component`: WebAssembly component bindings for the interface in `wit/isolanguage.wit`.
+//! - `embedded`: conversions into `heapless` strings.
+//! - `mlua`: using languages as Lua userdata. The application must enable one of `mlua`'s Lua
+//!   version features.
+//! - `registry-snapshot`: checking the crate's tables against a snapshot of the registry.
+//! - `rhai`: using languages as a Rhai custom type.
+//! - `sample-text`: a sample sentence in each language, for testing fonts and text shaping.
+//! - `serde_with`: using [`Code2`], [`Code3T`], [`Code3B`] and [`Name`] as `serde_as` adapters.
+//! - `slog`: recording languages, locales and tags as `slog` values.
+//! - `system-locale`: displaying language names in the language of the system's locale.
+//! - `tantivy`: conversions to tantivy's stemmer languages.
+//! - `tracing`: recording languages as `tracing` field values.
+//! - `windows-lcid`: conversions from Windows locale identifiers and culture names.
+//! - `zerocopy`: `zerocopy`'s `TryFromBytes`, `IntoBytes`, `KnownLayout` and `Immutable` for
+//!   languages.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
-use std::convert::TryFrom;
-use std::fmt::{self, Display, Formatter};
-use std::iter::FusedIterator;
-use std::ops::Range;
-use std::str::FromStr;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::{self, Display, Formatter};
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::ffi::{OsStr, OsString};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use speakers::BY_SPEAKERS;
+
+#[macro_use]
+mod macros;
+
+pub mod alexa;
+mod autonyms;
+pub mod aws;
+pub mod azure;
+pub mod captions;
+mod charsets;
+#[cfg(feature = "component")]
+mod component;
+mod countries;
+mod crosswalk;
+pub mod deepl;
+#[cfg(feature = "embedded")]
+mod embedded;
+mod family;
+mod fuzzy;
+#[cfg(feature = "std")]
+pub mod gettext;
+pub mod google_assistant;
+mod history;
+mod inline_error;
+mod lang_string;
+#[cfg(feature = "windows-lcid")]
+mod lcid;
+mod locale;
+mod localized;
+#[cfg(any(feature = "slog", feature = "tracing"))]
+mod logging;
+mod macrolanguages;
+mod maps;
+#[cfg(feature = "cldr-names")]
+mod names;
+pub mod negotiate;
+mod organizations;
+mod parser;
+#[cfg(feature = "cldr-plurals")]
+mod plurals;
+mod postgres;
+mod range;
+mod registry;
+mod repr;
+#[cfg(feature = "sample-text")]
+mod samples;
+#[cfg(any(feature = "mlua", feature = "rhai"))]
+mod scripting;
+mod scripts;
+mod search;
+mod set;
+mod speakers;
+pub mod sql;
+#[cfg(feature = "tantivy")]
+mod stemmer;
+pub mod store;
+mod subtags;
+mod tag;
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+mod transmute;
+mod typology;
+#[cfg(feature = "registry-snapshot")]
+pub mod validate;
+mod world;
+#[cfg(feature = "tantivy")]
+pub use stemmer::NoStemmerError;
+
+pub use charsets::WindowsCodepages;
+pub use countries::OfficialStatus;
+pub use family::{Clade, LanguageFamily, ParseLanguageFamilyError, FAMILY_LANGUAGES};
+pub use inline_error::InlineParseError;
+pub use lang_string::{LangStr, LangString};
+pub use locale::{Locale, ParseLocaleError, PosixLocale, Region};
+pub use localized::LocalizedString;
+pub use macrolanguages::{LanguageType, Scope};
+pub use maps::{CODE_B_TO_LANGUAGE, CODE_TO_LANGUAGE, CODE_T_TO_LANGUAGE, NAME_TO_LANGUAGE};
+#[cfg(feature = "cldr-names")]
+pub use names::format_language_list;
+#[cfg(feature = "system-locale")]
+pub use names::DisplayLocalized;
+pub use organizations::{EU_LANGUAGES, UN_LANGUAGES};
+pub use parser::{ParseCodeBError, ParseCodeTError, ParseErrorKind, ParseList, ParsedCode, Parser};
+#[cfg(feature = "cldr-plurals")]
+pub use plurals::PluralCategory;
+pub use range::{LanguageRange, ParseLanguageRangeError};
+pub use registry::{CustomLanguage, Language, RegisterError, Registry};
+pub use repr::{Code2, Code3B, Code3T, Name};
+pub use scripts::Direction;
+pub use set::{LanguageSet, ParseLanguageSetError, SetIter};
+pub use speakers::{CoverageReport, SpeakerCount, WORLD_POPULATION_MILLIONS};
+pub use subtags::SubtagRecord;
+pub use tag::{LanguageTag, ParseLanguageTagError};
+pub use typology::{Formality, WordOrder};
+pub use world::WorldRegion;
+
 macro_rules! languages_table {
-    ($(($variant:ident, $code:literal, $code_t:literal, $code_b:literal, $name:literal, $family:literal),)+) => {
+    ($(($variant:ident, $code:literal, $code_t:literal, $code_b:literal, $name:literal, $family:literal, $branch:expr, $autonym:expr),)+) => {
         /// An enumeration of all ISO 639-1 language codes.
+        ///
+        /// The enum is `#[repr(u8)]`, with discriminants numbering the languages from 0 in the
+        /// order of [`LANGUAGE_CODES`].
         #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(
+            feature = "zerocopy",
+            derive(
+                zerocopy::TryFromBytes,
+                zerocopy::IntoBytes,
+                zerocopy::KnownLayout,
+                zerocopy::Immutable
+            )
+        )]
+        #[repr(u8)]
         pub enum LanguageCode {
             $(
                 #[doc=$name]
@@ -95,6 +240,28 @@ macro_rules! languages_table {
                 }
             }
 
+            /// Returns the name of the language in the language itself, capitalized for use on
+            /// its own, like in a language picker.
+            ///
+            /// Returns `None` for the few languages without a well-attested native name, mostly
+            /// extinct or constructed ones.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use isolanguage_1::LanguageCode;
+            ///
+            /// assert_eq!(LanguageCode::De.autonym(), Some("Deutsch"));
+            /// assert_eq!(LanguageCode::Ja.autonym(), Some("日本語"));
+            /// assert_eq!(LanguageCode::Es.autonym(), Some("Español"));
+            /// ```
+            #[must_use]
+            pub const fn autonym(self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => $autonym,)+
+                }
+            }
+
             /// Returns the ISO family of the language.
             ///
             /// # Examples
@@ -111,6 +278,28 @@ macro_rules! languages_table {
                     $(Self::$variant => $family,)+
                 }
             }
+
+            /// Returns the branch of the [family](Self::family) the language belongs to, like
+            /// Germanic or Slavic for Indo-European languages.
+            ///
+            /// Returns `None` for languages whose family has no branches in the crate, like
+            /// Japanese, or that aren't in a family, like Basque and constructed languages.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use isolanguage_1::LanguageCode;
+            ///
+            /// assert_eq!(LanguageCode::Pl.branch(), Some("Slavic"));
+            /// assert_eq!(LanguageCode::Hi.branch(), Some("Indo-Aryan"));
+            /// assert_eq!(LanguageCode::Ja.branch(), None);
+            /// ```
+            #[must_use]
+            pub const fn branch(self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => $branch,)+
+                }
+            }
         }
 
         impl TryFrom<&str> for LanguageCode {
@@ -120,9 +309,7 @@ macro_rules! languages_table {
             fn try_from(s: &str) -> Result<Self, Self::Error> {
                 match s {
                     $($code => Ok(Self::$variant),)+
-                    _ => Err(ParseError {
-                        language: s.to_owned(),
-                    }),
+                    _ => Err(ParseError::new(s)),
                 }
             }
         }
@@ -140,7 +327,7 @@ macro_rules! languages_table {
         impl Display for LanguageCode {
             /// Writes the ISO language name.
             fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-                f.write_str(self.name())
+                f.pad(self.name())
             }
         }
 
@@ -150,190 +337,190 @@ macro_rules! languages_table {
 }
 
 languages_table! {
-    (Ab, "ab", "abk", "abk", "Abkhazian", "Northwest Caucasian"),
-    (Aa, "aa", "aar", "aar", "Afar", "Afro-Asiatic"),
-    (Af, "af", "afr", "afr", "Afrikaans", "Indo-European"),
-    (Ak, "ak", "aka", "aka", "Akan", "Niger–Congo"),
-    (Sq, "sq", "sqi", "alb", "Albanian", "Indo-European"),
-    (Am, "am", "amh", "amh", "Amharic", "Afro-Asiatic"),
-    (Ar, "ar", "ara", "ara", "Arabic", "Afro-Asiatic"),
-    (An, "an", "arg", "arg", "Aragonese", "Indo-European"),
-    (Hy, "hy", "hye", "arm", "Armenian", "Indo-European"),
-    (As, "as", "asm", "asm", "Assamese", "Indo-European"),
-    (Av, "av", "ava", "ava", "Avaric", "Northeast Caucasian"),
-    (Ae, "ae", "ave", "ave", "Avestan", "Indo-European"),
-    (Ay, "ay", "aym", "aym", "Aymara", "Aymaran"),
-    (Az, "az", "aze", "aze", "Azerbaijani", "Turkic"),
-    (Bm, "bm", "bam", "bam", "Bambara", "Niger–Congo"),
-    (Ba, "ba", "bak", "bak", "Bashkir", "Turkic"),
-    (Eu, "eu", "eus", "baq", "Basque", "Language isolate"),
-    (Be, "be", "bel", "bel", "Belarusian", "Indo-European"),
-    (Bn, "bn", "ben", "ben", "Bengali", "Indo-European"),
-    (Bh, "bh", "bih", "bih", "Bihari languages", "Indo-European"),
-    (Bi, "bi", "bis", "bis", "Bislama", "Creole"),
-    (Bs, "bs", "bos", "bos", "Bosnian", "Indo-European"),
-    (Br, "br", "bre", "bre", "Breton", "Indo-European"),
-    (Bg, "bg", "bul", "bul", "Bulgarian", "Indo-European"),
-    (My, "my", "mya", "bur", "Burmese", "Sino-Tibetan"),
-    (Ca, "ca", "cat", "cat", "Catalan", "Indo-European"),
-    (Ch, "ch", "cha", "cha", "Chamorro", "Austronesian"),
-    (Ce, "ce", "che", "che", "Chechen", "Northeast Caucasian"),
-    (Ny, "ny", "nya", "nya", "Chichewa", "Niger–Congo"),
-    (Zh, "zh", "zho", "chi", "Chinese", "Sino-Tibetan"),
-    (Cv, "cv", "chv", "chv", "Chuvash", "Turkic"),
-    (Kw, "kw", "cor", "cor", "Cornish", "Indo-European"),
-    (Co, "co", "cos", "cos", "Corsican", "Indo-European"),
-    (Cr, "cr", "cre", "cre", "Cree", "Algonquian"),
-    (Hr, "hr", "hrv", "hrv", "Croatian", "Indo-European"),
-    (Cs, "cs", "ces", "cze", "Czech", "Indo-European"),
-    (Da, "da", "dan", "dan", "Danish", "Indo-European"),
-    (Dv, "dv", "div", "div", "Divehi", "Indo-European"),
-    (Nl, "nl", "nld", "dut", "Dutch", "Indo-European"),
-    (Dz, "dz", "dzo", "dzo", "Dzongkha", "Sino-Tibetan"),
-    (En, "en", "eng", "eng", "English", "Indo-European"),
-    (Eo, "eo", "epo", "epo", "Esperanto", "Constructed"),
-    (Et, "et", "est", "est", "Estonian", "Uralic"),
-    (Ee, "ee", "ewe", "ewe", "Ewe", "Niger–Congo"),
-    (Fo, "fo", "fao", "fao", "Faroese", "Indo-European"),
-    (Fj, "fj", "fij", "fij", "Fijian", "Austronesian"),
-    (Fi, "fi", "fin", "fin", "Finnish", "Uralic"),
-    (Fr, "fr", "fra", "fre", "French", "Indo-European"),
-    (Ff, "ff", "ful", "ful", "Fulah", "Niger–Congo"),
-    (Gl, "gl", "glg", "glg", "Galician", "Indo-European"),
-    (Ka, "ka", "kat", "geo", "Georgian", "Kartvelian"),
-    (De, "de", "deu", "ger", "German", "Indo-European"),
-    (El, "el", "ell", "gre", "Greek", "Indo-European"),
-    (Gn, "gn", "grn", "grn", "Guarani", "Tupian"),
-    (Gu, "gu", "guj", "guj", "Gujarati", "Indo-European"),
-    (Ht, "ht", "hat", "hat", "Haitian", "Creole"),
-    (Ha, "ha", "hau", "hau", "Hausa", "Afro-Asiatic"),
-    (He, "he", "heb", "heb", "Hebrew", "Afro-Asiatic"),
-    (Hz, "hz", "her", "her", "Herero", "Niger–Congo"),
-    (Hi, "hi", "hin", "hin", "Hindi", "Indo-European"),
-    (Ho, "ho", "hmo", "hmo", "Hiri Motu", "Austronesian"),
-    (Hu, "hu", "hun", "hun", "Hungarian", "Uralic"),
-    (Ia, "ia", "ina", "ina", "Interlingua", "Constructed"),
-    (Id, "id", "ind", "ind", "Indonesian", "Austronesian"),
-    (Ie, "ie", "ile", "ile", "Interlingue", "Constructed"),
-    (Ga, "ga", "gle", "gle", "Irish", "Indo-European"),
-    (Ig, "ig", "ibo", "ibo", "Igbo", "Niger–Congo"),
-    (Ik, "ik", "ipk", "ipk", "Inupiaq", "Eskimo–Aleut"),
-    (Io, "io", "ido", "ido", "Ido", "Constructed"),
-    (Is, "is", "isl", "ice", "Icelandic", "Indo-European"),
-    (It, "it", "ita", "ita", "Italian", "Indo-European"),
-    (Iu, "iu", "iku", "iku", "Inuktitut", "Eskimo–Aleut"),
-    (Ja, "ja", "jpn", "jpn", "Japanese", "Japonic"),
-    (Jv, "jv", "jav", "jav", "Javanese", "Austronesian"),
-    (Kl, "kl", "kal", "kal", "Kalaallisut", "Eskimo–Aleut"),
-    (Kn, "kn", "kan", "kan", "Kannada", "Dravidian"),
-    (Kr, "kr", "kau", "kau", "Kanuri", "Nilo-Saharan"),
-    (Ks, "ks", "kas", "kas", "Kashmiri", "Indo-European"),
-    (Kk, "kk", "kaz", "kaz", "Kazakh", "Turkic"),
-    (Km, "km", "khm", "khm", "Central Khmer", "Austroasiatic"),
-    (Ki, "ki", "kik", "kik", "Kikuyu", "Niger–Congo"),
-    (Rw, "rw", "kin", "kin", "Kinyarwanda", "Niger–Congo"),
-    (Ky, "ky", "kir", "kir", "Kirghiz", "Turkic"),
-    (Kv, "kv", "kom", "kom", "Komi", "Uralic"),
-    (Kg, "kg", "kon", "kon", "Kongo", "Niger–Congo"),
-    (Ko, "ko", "kor", "kor", "Korean", "Koreanic"),
-    (Ku, "ku", "kur", "kur", "Kurdish", "Indo-European"),
-    (Kj, "kj", "kua", "kua", "Kuanyama", "Niger–Congo"),
-    (La, "la", "lat", "lat", "Latin", "Indo-European"),
-    (Lb, "lb", "ltz", "ltz", "Luxembourgish", "Indo-European"),
-    (Lg, "lg", "lug", "lug", "Ganda", "Niger–Congo"),
-    (Li, "li", "lim", "lim", "Limburgan", "Indo-European"),
-    (Ln, "ln", "lin", "lin", "Lingala", "Niger–Congo"),
-    (Lo, "lo", "lao", "lao", "Lao", "Tai–Kadai"),
-    (Lt, "lt", "lit", "lit", "Lithuanian", "Indo-European"),
-    (Lu, "lu", "lub", "lub", "Luba-Katanga", "Niger–Congo"),
-    (Lv, "lv", "lav", "lav", "Latvian", "Indo-European"),
-    (Gv, "gv", "glv", "glv", "Manx", "Indo-European"),
-    (Mk, "mk", "mkd", "mac", "Macedonian", "Indo-European"),
-    (Mg, "mg", "mlg", "mlg", "Malagasy", "Austronesian"),
-    (Ms, "ms", "msa", "may", "Malay", "Austronesian"),
-    (Ml, "ml", "mal", "mal", "Malayalam", "Dravidian"),
-    (Mt, "mt", "mlt", "mlt", "Maltese", "Afro-Asiatic"),
-    (Mi, "mi", "mri", "mao", "Maori", "Austronesian"),
-    (Mr, "mr", "mar", "mar", "Marathi", "Indo-European"),
-    (Mh, "mh", "mah", "mah", "Marshallese", "Austronesian"),
-    (Mn, "mn", "mon", "mon", "Mongolian", "Mongolic"),
-    (Na, "na", "nau", "nau", "Nauru", "Austronesian"),
-    (Nv, "nv", "nav", "nav", "Navajo", "Dené–Yeniseian"),
-    (Nd, "nd", "nde", "nde", "North Ndebele", "Niger–Congo"),
-    (Ne, "ne", "nep", "nep", "Nepali", "Indo-European"),
-    (Ng, "ng", "ndo", "ndo", "Ndonga", "Niger–Congo"),
-    (Nb, "nb", "nob", "nob", "Norwegian Bokmål", "Indo-European"),
-    (Nn, "nn", "nno", "nno", "Norwegian Nynorsk", "Indo-European"),
-    (No, "no", "nor", "nor", "Norwegian", "Indo-European"),
-    (Ii, "ii", "iii", "iii", "Sichuan Yi", "Sino-Tibetan"),
-    (Nr, "nr", "nbl", "nbl", "South Ndebele", "Niger–Congo"),
-    (Oc, "oc", "oci", "oci", "Occitan", "Indo-European"),
-    (Oj, "oj", "oji", "oji", "Ojibwa", "Algonquian"),
-    (Cu, "cu", "chu", "chu", "Church Slavic", "Indo-European"),
-    (Om, "om", "orm", "orm", "Oromo", "Afro-Asiatic"),
-    (Or, "or", "ori", "ori", "Oriya", "Indo-European"),
-    (Os, "os", "oss", "oss", "Ossetian", "Indo-European"),
-    (Pa, "pa", "pan", "pan", "Punjabi", "Indo-European"),
-    (Pi, "pi", "pli", "pli", "Pali", "Indo-European"),
-    (Fa, "fa", "fas", "per", "Persian", "Indo-European"),
-    (Pl, "pl", "pol", "pol", "Polish", "Indo-European"),
-    (Ps, "ps", "pus", "pus", "Pashto", "Indo-European"),
-    (Pt, "pt", "por", "por", "Portuguese", "Indo-European"),
-    (Qu, "qu", "que", "que", "Quechua", "Quechuan"),
-    (Rm, "rm", "roh", "roh", "Romansh", "Indo-European"),
-    (Rn, "rn", "run", "run", "Rundi", "Niger–Congo"),
-    (Ro, "ro", "ron", "rum", "Romanian", "Indo-European"),
-    (Ru, "ru", "rus", "rus", "Russian", "Indo-European"),
-    (Sa, "sa", "san", "san", "Sanskrit", "Indo-European"),
-    (Sc, "sc", "srd", "srd", "Sardinian", "Indo-European"),
-    (Sd, "sd", "snd", "snd", "Sindhi", "Indo-European"),
-    (Se, "se", "sme", "sme", "Northern Sami", "Uralic"),
-    (Sm, "sm", "smo", "smo", "Samoan", "Austronesian"),
-    (Sg, "sg", "sag", "sag", "Sango", "Creole"),
-    (Sr, "sr", "srp", "srp", "Serbian", "Indo-European"),
-    (Gd, "gd", "gla", "gla", "Gaelic", "Indo-European"),
-    (Sn, "sn", "sna", "sna", "Shona", "Niger–Congo"),
-    (Si, "si", "sin", "sin", "Sinhala", "Indo-European"),
-    (Sk, "sk", "slk", "slo", "Slovak", "Indo-European"),
-    (Sl, "sl", "slv", "slv", "Slovenian", "Indo-European"),
-    (So, "so", "som", "som", "Somali", "Afro-Asiatic"),
-    (St, "st", "sot", "sot", "Southern Sotho", "Niger–Congo"),
-    (Es, "es", "spa", "spa", "Spanish", "Indo-European"),
-    (Su, "su", "sun", "sun", "Sundanese", "Austronesian"),
-    (Sw, "sw", "swa", "swa", "Swahili", "Niger–Congo"),
-    (Ss, "ss", "ssw", "ssw", "Swati", "Niger–Congo"),
-    (Sv, "sv", "swe", "swe", "Swedish", "Indo-European"),
-    (Ta, "ta", "tam", "tam", "Tamil", "Dravidian"),
-    (Te, "te", "tel", "tel", "Telugu", "Dravidian"),
-    (Tg, "tg", "tgk", "tgk", "Tajik", "Indo-European"),
-    (Th, "th", "tha", "tha", "Thai", "Tai–Kadai"),
-    (Ti, "ti", "tir", "tir", "Tigrinya", "Afro-Asiatic"),
-    (Bo, "bo", "bod", "tib", "Tibetan", "Sino-Tibetan"),
-    (Tk, "tk", "tuk", "tuk", "Turkmen", "Turkic"),
-    (Tl, "tl", "tgl", "tgl", "Tagalog", "Austronesian"),
-    (Tn, "tn", "tsn", "tsn", "Tswana", "Niger–Congo"),
-    (To, "to", "ton", "ton", "Tonga", "Austronesian"),
-    (Tr, "tr", "tur", "tur", "Turkish", "Turkic"),
-    (Ts, "ts", "tso", "tso", "Tsonga", "Niger–Congo"),
-    (Tt, "tt", "tat", "tat", "Tatar", "Turkic"),
-    (Tw, "tw", "twi", "twi", "Twi", "Niger–Congo"),
-    (Ty, "ty", "tah", "tah", "Tahitian", "Austronesian"),
-    (Ug, "ug", "uig", "uig", "Uighur", "Turkic"),
-    (Uk, "uk", "ukr", "ukr", "Ukrainian", "Indo-European"),
-    (Ur, "ur", "urd", "urd", "Urdu", "Indo-European"),
-    (Uz, "uz", "uzb", "uzb", "Uzbek", "Turkic"),
-    (Ve, "ve", "ven", "ven", "Venda", "Niger–Congo"),
-    (Vi, "vi", "vie", "vie", "Vietnamese", "Austroasiatic"),
-    (Vo, "vo", "vol", "vol", "Volapük", "Constructed"),
-    (Wa, "wa", "wln", "wln", "Walloon", "Indo-European"),
-    (Cy, "cy", "cym", "wel", "Welsh", "Indo-European"),
-    (Wo, "wo", "wol", "wol", "Wolof", "Niger–Congo"),
-    (Fy, "fy", "fry", "fry", "Western Frisian", "Indo-European"),
-    (Xh, "xh", "xho", "xho", "Xhosa", "Niger–Congo"),
-    (Yi, "yi", "yid", "yid", "Yiddish", "Indo-European"),
-    (Yo, "yo", "yor", "yor", "Yoruba", "Niger–Congo"),
-    (Za, "za", "zha", "zha", "Zhuang", "Tai–Kadai"),
-    (Zu, "zu", "zul", "zul", "Zulu", "Niger–Congo"),
+    (Ab, "ab", "abk", "abk", "Abkhazian", "Northwest Caucasian", None, Some("Аҧсуа бызшәа")),
+    (Aa, "aa", "aar", "aar", "Afar", "Afro-Asiatic", Some("Cushitic"), Some("Qafar")),
+    (Af, "af", "afr", "afr", "Afrikaans", "Indo-European", Some("Germanic"), Some("Afrikaans")),
+    (Ak, "ak", "aka", "aka", "Akan", "Niger–Congo", Some("Kwa"), Some("Akan")),
+    (Sq, "sq", "sqi", "alb", "Albanian", "Indo-European", Some("Albanian"), Some("Shqip")),
+    (Am, "am", "amh", "amh", "Amharic", "Afro-Asiatic", Some("Semitic"), Some("አማርኛ")),
+    (Ar, "ar", "ara", "ara", "Arabic", "Afro-Asiatic", Some("Semitic"), Some("العربية")),
+    (An, "an", "arg", "arg", "Aragonese", "Indo-European", Some("Romance"), Some("Aragonés")),
+    (Hy, "hy", "hye", "arm", "Armenian", "Indo-European", Some("Armenian"), Some("Հայերեն")),
+    (As, "as", "asm", "asm", "Assamese", "Indo-European", Some("Indo-Aryan"), Some("অসমীয়া")),
+    (Av, "av", "ava", "ava", "Avaric", "Northeast Caucasian", Some("Avar–Andic"), Some("Авар мацӏ")),
+    (Ae, "ae", "ave", "ave", "Avestan", "Indo-European", Some("Iranian"), None),
+    (Ay, "ay", "aym", "aym", "Aymara", "Aymaran", None, None),
+    (Az, "az", "aze", "aze", "Azerbaijani", "Turkic", Some("Oghuz"), Some("Azərbaycan")),
+    (Bm, "bm", "bam", "bam", "Bambara", "Niger–Congo", Some("Mande"), Some("Bamanakan")),
+    (Ba, "ba", "bak", "bak", "Bashkir", "Turkic", Some("Kipchak"), Some("Башҡорт теле")),
+    (Eu, "eu", "eus", "baq", "Basque", "Language isolate", None, Some("Euskara")),
+    (Be, "be", "bel", "bel", "Belarusian", "Indo-European", Some("Slavic"), Some("Беларуская")),
+    (Bn, "bn", "ben", "ben", "Bengali", "Indo-European", Some("Indo-Aryan"), Some("বাংলা")),
+    (Bh, "bh", "bih", "bih", "Bihari languages", "Indo-European", Some("Indo-Aryan"), None),
+    (Bi, "bi", "bis", "bis", "Bislama", "Creole", None, Some("Bislama")),
+    (Bs, "bs", "bos", "bos", "Bosnian", "Indo-European", Some("Slavic"), Some("Bosanski")),
+    (Br, "br", "bre", "bre", "Breton", "Indo-European", Some("Celtic"), Some("Brezhoneg")),
+    (Bg, "bg", "bul", "bul", "Bulgarian", "Indo-European", Some("Slavic"), Some("Български")),
+    (My, "my", "mya", "bur", "Burmese", "Sino-Tibetan", Some("Tibeto-Burman"), Some("မြန်မာ")),
+    (Ca, "ca", "cat", "cat", "Catalan", "Indo-European", Some("Romance"), Some("Català")),
+    (Ch, "ch", "cha", "cha", "Chamorro", "Austronesian", Some("Malayo-Polynesian"), Some("Chamorru")),
+    (Ce, "ce", "che", "che", "Chechen", "Northeast Caucasian", Some("Nakh"), Some("Нохчийн")),
+    (Ny, "ny", "nya", "nya", "Chichewa", "Niger–Congo", Some("Bantu"), Some("Chichewa")),
+    (Zh, "zh", "zho", "chi", "Chinese", "Sino-Tibetan", Some("Sinitic"), Some("中文")),
+    (Cv, "cv", "chv", "chv", "Chuvash", "Turkic", Some("Oghur"), Some("Чӑвашла")),
+    (Kw, "kw", "cor", "cor", "Cornish", "Indo-European", Some("Celtic"), Some("Kernewek")),
+    (Co, "co", "cos", "cos", "Corsican", "Indo-European", Some("Romance"), Some("Corsu")),
+    (Cr, "cr", "cre", "cre", "Cree", "Algonquian", None, None),
+    (Hr, "hr", "hrv", "hrv", "Croatian", "Indo-European", Some("Slavic"), Some("Hrvatski")),
+    (Cs, "cs", "ces", "cze", "Czech", "Indo-European", Some("Slavic"), Some("Čeština")),
+    (Da, "da", "dan", "dan", "Danish", "Indo-European", Some("Germanic"), Some("Dansk")),
+    (Dv, "dv", "div", "div", "Divehi", "Indo-European", Some("Indo-Aryan"), Some("ދިވެހިބަސް")),
+    (Nl, "nl", "nld", "dut", "Dutch", "Indo-European", Some("Germanic"), Some("Nederlands")),
+    (Dz, "dz", "dzo", "dzo", "Dzongkha", "Sino-Tibetan", Some("Tibeto-Burman"), Some("རྫོང་ཁ")),
+    (En, "en", "eng", "eng", "English", "Indo-European", Some("Germanic"), Some("English")),
+    (Eo, "eo", "epo", "epo", "Esperanto", "Constructed", None, Some("Esperanto")),
+    (Et, "et", "est", "est", "Estonian", "Uralic", Some("Finnic"), Some("Eesti")),
+    (Ee, "ee", "ewe", "ewe", "Ewe", "Niger–Congo", Some("Kwa"), Some("Eʋegbe")),
+    (Fo, "fo", "fao", "fao", "Faroese", "Indo-European", Some("Germanic"), Some("Føroyskt")),
+    (Fj, "fj", "fij", "fij", "Fijian", "Austronesian", Some("Malayo-Polynesian"), None),
+    (Fi, "fi", "fin", "fin", "Finnish", "Uralic", Some("Finnic"), Some("Suomi")),
+    (Fr, "fr", "fra", "fre", "French", "Indo-European", Some("Romance"), Some("Français")),
+    (Ff, "ff", "ful", "ful", "Fulah", "Niger–Congo", Some("Atlantic"), Some("Pulaar")),
+    (Gl, "gl", "glg", "glg", "Galician", "Indo-European", Some("Romance"), Some("Galego")),
+    (Ka, "ka", "kat", "geo", "Georgian", "Kartvelian", None, Some("ქართული")),
+    (De, "de", "deu", "ger", "German", "Indo-European", Some("Germanic"), Some("Deutsch")),
+    (El, "el", "ell", "gre", "Greek", "Indo-European", Some("Hellenic"), Some("Ελληνικά")),
+    (Gn, "gn", "grn", "grn", "Guarani", "Tupian", None, None),
+    (Gu, "gu", "guj", "guj", "Gujarati", "Indo-European", Some("Indo-Aryan"), Some("ગુજરાતી")),
+    (Ht, "ht", "hat", "hat", "Haitian", "Creole", None, Some("Kreyòl ayisyen")),
+    (Ha, "ha", "hau", "hau", "Hausa", "Afro-Asiatic", Some("Chadic"), Some("Hausa")),
+    (He, "he", "heb", "heb", "Hebrew", "Afro-Asiatic", Some("Semitic"), Some("עברית")),
+    (Hz, "hz", "her", "her", "Herero", "Niger–Congo", Some("Bantu"), None),
+    (Hi, "hi", "hin", "hin", "Hindi", "Indo-European", Some("Indo-Aryan"), Some("हिन्दी")),
+    (Ho, "ho", "hmo", "hmo", "Hiri Motu", "Austronesian", Some("Malayo-Polynesian"), None),
+    (Hu, "hu", "hun", "hun", "Hungarian", "Uralic", Some("Ugric"), Some("Magyar")),
+    (Ia, "ia", "ina", "ina", "Interlingua", "Constructed", None, Some("Interlingua")),
+    (Id, "id", "ind", "ind", "Indonesian", "Austronesian", Some("Malayo-Polynesian"), Some("Bahasa Indonesia")),
+    (Ie, "ie", "ile", "ile", "Interlingue", "Constructed", None, Some("Interlingue")),
+    (Ga, "ga", "gle", "gle", "Irish", "Indo-European", Some("Celtic"), Some("Gaeilge")),
+    (Ig, "ig", "ibo", "ibo", "Igbo", "Niger–Congo", Some("Volta–Niger"), Some("Igbo")),
+    (Ik, "ik", "ipk", "ipk", "Inupiaq", "Eskimo–Aleut", Some("Inuit"), None),
+    (Io, "io", "ido", "ido", "Ido", "Constructed", None, None),
+    (Is, "is", "isl", "ice", "Icelandic", "Indo-European", Some("Germanic"), Some("Íslenska")),
+    (It, "it", "ita", "ita", "Italian", "Indo-European", Some("Romance"), Some("Italiano")),
+    (Iu, "iu", "iku", "iku", "Inuktitut", "Eskimo–Aleut", Some("Inuit"), Some("ᐃᓄᒃᑎᑐᑦ")),
+    (Ja, "ja", "jpn", "jpn", "Japanese", "Japonic", None, Some("日本語")),
+    (Jv, "jv", "jav", "jav", "Javanese", "Austronesian", Some("Malayo-Polynesian"), Some("Basa Jawa")),
+    (Kl, "kl", "kal", "kal", "Kalaallisut", "Eskimo–Aleut", Some("Inuit"), Some("Kalaallisut")),
+    (Kn, "kn", "kan", "kan", "Kannada", "Dravidian", Some("South Dravidian"), Some("ಕನ್ನಡ")),
+    (Kr, "kr", "kau", "kau", "Kanuri", "Nilo-Saharan", Some("Saharan"), None),
+    (Ks, "ks", "kas", "kas", "Kashmiri", "Indo-European", Some("Indo-Aryan"), Some("کٲشُر")),
+    (Kk, "kk", "kaz", "kaz", "Kazakh", "Turkic", Some("Kipchak"), Some("Қазақ тілі")),
+    (Km, "km", "khm", "khm", "Central Khmer", "Austroasiatic", Some("Khmeric"), Some("ខ្មែរ")),
+    (Ki, "ki", "kik", "kik", "Kikuyu", "Niger–Congo", Some("Bantu"), Some("Gikuyu")),
+    (Rw, "rw", "kin", "kin", "Kinyarwanda", "Niger–Congo", Some("Bantu"), Some("Kinyarwanda")),
+    (Ky, "ky", "kir", "kir", "Kirghiz", "Turkic", Some("Kipchak"), Some("Кыргызча")),
+    (Kv, "kv", "kom", "kom", "Komi", "Uralic", Some("Permic"), None),
+    (Kg, "kg", "kon", "kon", "Kongo", "Niger–Congo", Some("Bantu"), None),
+    (Ko, "ko", "kor", "kor", "Korean", "Koreanic", None, Some("한국어")),
+    (Ku, "ku", "kur", "kur", "Kurdish", "Indo-European", Some("Iranian"), Some("Kurdî")),
+    (Kj, "kj", "kua", "kua", "Kuanyama", "Niger–Congo", Some("Bantu"), Some("Oshikwanyama")),
+    (La, "la", "lat", "lat", "Latin", "Indo-European", Some("Italic"), None),
+    (Lb, "lb", "ltz", "ltz", "Luxembourgish", "Indo-European", Some("Germanic"), Some("Lëtzebuergesch")),
+    (Lg, "lg", "lug", "lug", "Ganda", "Niger–Congo", Some("Bantu"), Some("Luganda")),
+    (Li, "li", "lim", "lim", "Limburgan", "Indo-European", Some("Germanic"), Some("Limburgs")),
+    (Ln, "ln", "lin", "lin", "Lingala", "Niger–Congo", Some("Bantu"), Some("Lingála")),
+    (Lo, "lo", "lao", "lao", "Lao", "Tai–Kadai", Some("Tai"), Some("ລາວ")),
+    (Lt, "lt", "lit", "lit", "Lithuanian", "Indo-European", Some("Baltic"), Some("Lietuvių")),
+    (Lu, "lu", "lub", "lub", "Luba-Katanga", "Niger–Congo", Some("Bantu"), Some("Tshiluba")),
+    (Lv, "lv", "lav", "lav", "Latvian", "Indo-European", Some("Baltic"), Some("Latviešu")),
+    (Gv, "gv", "glv", "glv", "Manx", "Indo-European", Some("Celtic"), Some("Gaelg")),
+    (Mk, "mk", "mkd", "mac", "Macedonian", "Indo-European", Some("Slavic"), Some("Македонски")),
+    (Mg, "mg", "mlg", "mlg", "Malagasy", "Austronesian", Some("Malayo-Polynesian"), Some("Malagasy")),
+    (Ms, "ms", "msa", "may", "Malay", "Austronesian", Some("Malayo-Polynesian"), Some("Melayu")),
+    (Ml, "ml", "mal", "mal", "Malayalam", "Dravidian", Some("South Dravidian"), Some("മലയാളം")),
+    (Mt, "mt", "mlt", "mlt", "Maltese", "Afro-Asiatic", Some("Semitic"), Some("Malti")),
+    (Mi, "mi", "mri", "mao", "Maori", "Austronesian", Some("Malayo-Polynesian"), Some("Te reo Māori")),
+    (Mr, "mr", "mar", "mar", "Marathi", "Indo-European", Some("Indo-Aryan"), Some("मराठी")),
+    (Mh, "mh", "mah", "mah", "Marshallese", "Austronesian", Some("Malayo-Polynesian"), None),
+    (Mn, "mn", "mon", "mon", "Mongolian", "Mongolic", None, Some("Монгол")),
+    (Na, "na", "nau", "nau", "Nauru", "Austronesian", Some("Malayo-Polynesian"), None),
+    (Nv, "nv", "nav", "nav", "Navajo", "Dené–Yeniseian", None, Some("Diné")),
+    (Nd, "nd", "nde", "nde", "North Ndebele", "Niger–Congo", Some("Bantu"), Some("isiNdebele")),
+    (Ne, "ne", "nep", "nep", "Nepali", "Indo-European", Some("Indo-Aryan"), Some("नेपाली")),
+    (Ng, "ng", "ndo", "ndo", "Ndonga", "Niger–Congo", Some("Bantu"), None),
+    (Nb, "nb", "nob", "nob", "Norwegian Bokmål", "Indo-European", Some("Germanic"), Some("Norsk bokmål")),
+    (Nn, "nn", "nno", "nno", "Norwegian Nynorsk", "Indo-European", Some("Germanic"), Some("Nynorsk")),
+    (No, "no", "nor", "nor", "Norwegian", "Indo-European", Some("Germanic"), Some("Norsk")),
+    (Ii, "ii", "iii", "iii", "Sichuan Yi", "Sino-Tibetan", Some("Tibeto-Burman"), Some("ꆈꌠꉙ")),
+    (Nr, "nr", "nbl", "nbl", "South Ndebele", "Niger–Congo", Some("Bantu"), Some("isiNdebele")),
+    (Oc, "oc", "oci", "oci", "Occitan", "Indo-European", Some("Romance"), Some("Occitan")),
+    (Oj, "oj", "oji", "oji", "Ojibwa", "Algonquian", None, None),
+    (Cu, "cu", "chu", "chu", "Church Slavic", "Indo-European", Some("Slavic"), Some("Церковнослове́нскїй")),
+    (Om, "om", "orm", "orm", "Oromo", "Afro-Asiatic", Some("Cushitic"), Some("Oromoo")),
+    (Or, "or", "ori", "ori", "Oriya", "Indo-European", Some("Indo-Aryan"), Some("ଓଡ଼ିଆ")),
+    (Os, "os", "oss", "oss", "Ossetian", "Indo-European", Some("Iranian"), Some("Ирон")),
+    (Pa, "pa", "pan", "pan", "Punjabi", "Indo-European", Some("Indo-Aryan"), Some("ਪੰਜਾਬੀ")),
+    (Pi, "pi", "pli", "pli", "Pali", "Indo-European", Some("Indo-Aryan"), None),
+    (Fa, "fa", "fas", "per", "Persian", "Indo-European", Some("Iranian"), Some("فارسی")),
+    (Pl, "pl", "pol", "pol", "Polish", "Indo-European", Some("Slavic"), Some("Polski")),
+    (Ps, "ps", "pus", "pus", "Pashto", "Indo-European", Some("Iranian"), Some("پښتو")),
+    (Pt, "pt", "por", "por", "Portuguese", "Indo-European", Some("Romance"), Some("Português")),
+    (Qu, "qu", "que", "que", "Quechua", "Quechuan", None, Some("Runasimi")),
+    (Rm, "rm", "roh", "roh", "Romansh", "Indo-European", Some("Romance"), Some("Rumantsch")),
+    (Rn, "rn", "run", "run", "Rundi", "Niger–Congo", Some("Bantu"), Some("Ikirundi")),
+    (Ro, "ro", "ron", "rum", "Romanian", "Indo-European", Some("Romance"), Some("Română")),
+    (Ru, "ru", "rus", "rus", "Russian", "Indo-European", Some("Slavic"), Some("Русский")),
+    (Sa, "sa", "san", "san", "Sanskrit", "Indo-European", Some("Indo-Aryan"), Some("संस्कृत भाषा")),
+    (Sc, "sc", "srd", "srd", "Sardinian", "Indo-European", Some("Romance"), Some("Sardu")),
+    (Sd, "sd", "snd", "snd", "Sindhi", "Indo-European", Some("Indo-Aryan"), Some("سنڌي")),
+    (Se, "se", "sme", "sme", "Northern Sami", "Uralic", Some("Sami"), Some("Davvisámegiella")),
+    (Sm, "sm", "smo", "smo", "Samoan", "Austronesian", Some("Malayo-Polynesian"), Some("Gagana Samoa")),
+    (Sg, "sg", "sag", "sag", "Sango", "Creole", None, Some("Sängö")),
+    (Sr, "sr", "srp", "srp", "Serbian", "Indo-European", Some("Slavic"), Some("Српски")),
+    (Gd, "gd", "gla", "gla", "Gaelic", "Indo-European", Some("Celtic"), Some("Gàidhlig")),
+    (Sn, "sn", "sna", "sna", "Shona", "Niger–Congo", Some("Bantu"), Some("chiShona")),
+    (Si, "si", "sin", "sin", "Sinhala", "Indo-European", Some("Indo-Aryan"), Some("සිංහල")),
+    (Sk, "sk", "slk", "slo", "Slovak", "Indo-European", Some("Slavic"), Some("Slovenčina")),
+    (Sl, "sl", "slv", "slv", "Slovenian", "Indo-European", Some("Slavic"), Some("Slovenščina")),
+    (So, "so", "som", "som", "Somali", "Afro-Asiatic", Some("Cushitic"), Some("Soomaali")),
+    (St, "st", "sot", "sot", "Southern Sotho", "Niger–Congo", Some("Bantu"), Some("Sesotho")),
+    (Es, "es", "spa", "spa", "Spanish", "Indo-European", Some("Romance"), Some("Español")),
+    (Su, "su", "sun", "sun", "Sundanese", "Austronesian", Some("Malayo-Polynesian"), Some("Basa Sunda")),
+    (Sw, "sw", "swa", "swa", "Swahili", "Niger–Congo", Some("Bantu"), Some("Kiswahili")),
+    (Ss, "ss", "ssw", "ssw", "Swati", "Niger–Congo", Some("Bantu"), Some("siSwati")),
+    (Sv, "sv", "swe", "swe", "Swedish", "Indo-European", Some("Germanic"), Some("Svenska")),
+    (Ta, "ta", "tam", "tam", "Tamil", "Dravidian", Some("South Dravidian"), Some("தமிழ்")),
+    (Te, "te", "tel", "tel", "Telugu", "Dravidian", Some("South-Central Dravidian"), Some("తెలుగు")),
+    (Tg, "tg", "tgk", "tgk", "Tajik", "Indo-European", Some("Iranian"), Some("Тоҷикӣ")),
+    (Th, "th", "tha", "tha", "Thai", "Tai–Kadai", Some("Tai"), Some("ไทย")),
+    (Ti, "ti", "tir", "tir", "Tigrinya", "Afro-Asiatic", Some("Semitic"), Some("ትግርኛ")),
+    (Bo, "bo", "bod", "tib", "Tibetan", "Sino-Tibetan", Some("Tibeto-Burman"), Some("བོད་སྐད་")),
+    (Tk, "tk", "tuk", "tuk", "Turkmen", "Turkic", Some("Oghuz"), Some("Türkmen dili")),
+    (Tl, "tl", "tgl", "tgl", "Tagalog", "Austronesian", Some("Malayo-Polynesian"), Some("Tagalog")),
+    (Tn, "tn", "tsn", "tsn", "Tswana", "Niger–Congo", Some("Bantu"), Some("Setswana")),
+    (To, "to", "ton", "ton", "Tonga", "Austronesian", Some("Malayo-Polynesian"), Some("Lea fakatonga")),
+    (Tr, "tr", "tur", "tur", "Turkish", "Turkic", Some("Oghuz"), Some("Türkçe")),
+    (Ts, "ts", "tso", "tso", "Tsonga", "Niger–Congo", Some("Bantu"), Some("Xitsonga")),
+    (Tt, "tt", "tat", "tat", "Tatar", "Turkic", Some("Kipchak"), Some("Татар")),
+    (Tw, "tw", "twi", "twi", "Twi", "Niger–Congo", Some("Kwa"), None),
+    (Ty, "ty", "tah", "tah", "Tahitian", "Austronesian", Some("Malayo-Polynesian"), Some("Reo Tahiti")),
+    (Ug, "ug", "uig", "uig", "Uighur", "Turkic", Some("Karluk"), Some("ئۇيغۇرچە")),
+    (Uk, "uk", "ukr", "ukr", "Ukrainian", "Indo-European", Some("Slavic"), Some("Українська")),
+    (Ur, "ur", "urd", "urd", "Urdu", "Indo-European", Some("Indo-Aryan"), Some("اردو")),
+    (Uz, "uz", "uzb", "uzb", "Uzbek", "Turkic", Some("Karluk"), Some("O‘zbek")),
+    (Ve, "ve", "ven", "ven", "Venda", "Niger–Congo", Some("Bantu"), Some("Tshivenḓa")),
+    (Vi, "vi", "vie", "vie", "Vietnamese", "Austroasiatic", Some("Vietic"), Some("Tiếng Việt")),
+    (Vo, "vo", "vol", "vol", "Volapük", "Constructed", None, Some("Volapük")),
+    (Wa, "wa", "wln", "wln", "Walloon", "Indo-European", Some("Romance"), None),
+    (Cy, "cy", "cym", "wel", "Welsh", "Indo-European", Some("Celtic"), Some("Cymraeg")),
+    (Wo, "wo", "wol", "wol", "Wolof", "Niger–Congo", Some("Atlantic"), Some("Wolof")),
+    (Fy, "fy", "fry", "fry", "Western Frisian", "Indo-European", Some("Germanic"), Some("Frysk")),
+    (Xh, "xh", "xho", "xho", "Xhosa", "Niger–Congo", Some("Bantu"), Some("isiXhosa")),
+    (Yi, "yi", "yid", "yid", "Yiddish", "Indo-European", Some("Germanic"), Some("ייִדיש")),
+    (Yo, "yo", "yor", "yor", "Yoruba", "Niger–Congo", Some("Volta–Niger"), Some("Èdè Yorùbá")),
+    (Za, "za", "zha", "zha", "Zhuang", "Tai–Kadai", Some("Tai"), Some("Vahcuengh")),
+    (Zu, "zu", "zul", "zul", "Zulu", "Niger–Congo", Some("Bantu"), Some("isiZulu")),
 }
 
 impl LanguageCode {
@@ -406,6 +593,354 @@ impl LanguageCode {
     pub fn families() -> Families {
         Families::default()
     }
+
+    /// Returns an iterator over all [branches](Self::branch) of language families.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let romance: Vec<_> = LanguageCode::iter()
+    ///     .filter(|language| language.branch() == Some("Romance"))
+    ///     .collect();
+    /// assert!(romance.contains(&LanguageCode::Ro));
+    /// assert!(LanguageCode::branches().any(|branch| branch == "Bantu"));
+    /// ```
+    #[inline]
+    pub fn branches() -> Branches {
+        Branches::default()
+    }
+
+    /// Returns the [ISO 639-5](https://en.wikipedia.org/wiki/ISO_639-5) code of the language's
+    /// [family](Self::family), like `ine` for Indo-European, or `None` if the family has none. See
+    /// [`LanguageFamily::code`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::De.family_code(), Some("ine"));
+    /// assert_eq!(LanguageCode::Kk.family_code(), Some("trk"));
+    /// assert_eq!(LanguageCode::Eu.family_code(), None);
+    /// ```
+    #[must_use]
+    pub const fn family_code(self) -> Option<&'static str> {
+        self.family_enum().code()
+    }
+
+    /// Parses a two letter language code at the start of the input, returning the language and the
+    /// rest of the input. The code must be followed by the end of the input or a character that
+    /// isn't ASCII alphanumeric, so `english` isn't parsed as `en`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(
+    ///     LanguageCode::parse_prefix("fr:Bonjour|de:Hallo"),
+    ///     Some((LanguageCode::Fr, ":Bonjour|de:Hallo"))
+    /// );
+    /// assert_eq!(LanguageCode::parse_prefix("xx:Hello"), None);
+    /// assert_eq!(LanguageCode::parse_prefix("english"), None);
+    /// ```
+    #[must_use]
+    pub fn parse_prefix(input: &str) -> Option<(Self, &str)> {
+        let code = input.get(..2)?;
+        let rest = &input[2..];
+        if rest
+            .bytes()
+            .next()
+            .is_some_and(|byte| byte.is_ascii_alphanumeric())
+        {
+            return None;
+        }
+        let language = Self::from_bytes(code.as_bytes())?;
+        Some((language, rest))
+    }
+
+    /// Parses a two letter language code from bytes, such as a field of a binary protocol, without
+    /// validating UTF-8 first. Like [`try_from`](Self::try_from), the code must be lowercase.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_bytes(b"fr"), Some(LanguageCode::Fr));
+    /// assert_eq!(LanguageCode::from_bytes(b"FR"), None);
+    /// assert_eq!(LanguageCode::from_bytes(b"\xff\xfe"), None);
+    /// ```
+    #[must_use]
+    pub const fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 2 {
+            return None;
+        }
+        let mut i = 0;
+        while i < LANGUAGE_CODES.len() {
+            let language = LANGUAGE_CODES[i];
+            let expected = language.code().as_bytes();
+            if expected[0] == bytes[0] && expected[1] == bytes[1] {
+                return Some(language);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns whether a string is a valid ISO 639-1 code, in a const context. Like
+    /// [`try_from`](Self::try_from), the code must be lowercase.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// const _: () = assert!(LanguageCode::is_valid_code("de"));
+    /// assert!(!LanguageCode::is_valid_code("deu"));
+    /// ```
+    #[must_use]
+    pub const fn is_valid_code(code: &str) -> bool {
+        Self::from_bytes(code.as_bytes()).is_some()
+    }
+
+    /// Returns whether a string is a valid ISO 639-2/T code, in a const context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// const _: () = assert!(LanguageCode::is_valid_code_t("deu"));
+    /// assert!(!LanguageCode::is_valid_code_t("ger"));
+    /// ```
+    #[must_use]
+    pub const fn is_valid_code_t(code: &str) -> bool {
+        let mut i = 0;
+        while i < LANGUAGE_CODES.len() {
+            if bytes_eq(LANGUAGE_CODES[i].code_t().as_bytes(), code.as_bytes()) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Returns whether a string is a valid ISO 639-2/B code, in a const context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// const _: () = assert!(LanguageCode::is_valid_code_b("ger"));
+    /// assert!(!LanguageCode::is_valid_code_b("deu"));
+    /// ```
+    #[must_use]
+    pub const fn is_valid_code_b(code: &str) -> bool {
+        let mut i = 0;
+        while i < LANGUAGE_CODES.len() {
+            if bytes_eq(LANGUAGE_CODES[i].code_b().as_bytes(), code.as_bytes()) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Parses a two letter language code from bytes, ignoring ASCII case.
+    ///
+    /// Unlike [`from_str`](Self::from_str), this never allocates, even when the input is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_bytes_ignore_case(b"FR"), Some(LanguageCode::Fr));
+    /// assert_eq!(LanguageCode::from_bytes_ignore_case(b"fra"), None);
+    /// ```
+    #[must_use]
+    pub const fn from_bytes_ignore_case(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 2 {
+            return None;
+        }
+        Self::from_bytes(&[bytes[0].to_ascii_lowercase(), bytes[1].to_ascii_lowercase()])
+    }
+
+    /// Parses a two letter language code in a const context, so that languages can be written as
+    /// codes in constants and statics.
+    ///
+    /// Like [`try_from`](Self::try_from), the code must be lowercase. Use
+    /// [`from_bytes_ignore_case`](Self::from_bytes_ignore_case) for a fallible const parse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the code isn't a valid ISO 639-1 code, which fails compilation when evaluated in a
+    /// const context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// const DEFAULT_LANG: LanguageCode = LanguageCode::from_code_const("en");
+    /// assert_eq!(DEFAULT_LANG, LanguageCode::En);
+    /// ```
+    #[must_use]
+    pub const fn from_code_const(code: &str) -> Self {
+        match Self::from_bytes(code.as_bytes()) {
+            Some(language) => language,
+            None => panic!("invalid ISO 639-1 language code"),
+        }
+    }
+
+    /// Returns the language at an index of [`LANGUAGE_CODES`], the reverse of
+    /// [`to_index`](Self::to_index).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_index(0), Some(LanguageCode::Ab));
+    /// assert_eq!(LanguageCode::from_index(183), Some(LanguageCode::Zu));
+    /// assert_eq!(LanguageCode::from_index(184), None);
+    /// ```
+    #[must_use]
+    pub const fn from_index(index: u8) -> Option<Self> {
+        if (index as usize) < LANGUAGE_CODES.len() {
+            Some(LANGUAGE_CODES[index as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the language in [`LANGUAGE_CODES`], which is also its discriminant,
+    /// for packing languages into a single byte.
+    ///
+    /// The indices follow the order of the ISO 639-1 table and only change in semver-incompatible
+    /// releases, so they can be stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::Ab.to_index(), 0);
+    /// assert_eq!(LanguageCode::Zu.to_index(), 183);
+    /// ```
+    #[must_use]
+    pub const fn to_index(self) -> u8 {
+        self as u8
+    }
+
+    /// Compares two languages by their 2 letter codes in a const context.
+    ///
+    /// Unlike the [`Ord`] implementation, which follows the order of the ISO 639-1 table, this sorts
+    /// `en` before `es` and `zh` last.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// const ORDER: Ordering = LanguageCode::cmp_by_code(LanguageCode::Ab, LanguageCode::Aa);
+    /// assert_eq!(ORDER, Ordering::Greater);
+    /// ```
+    #[must_use]
+    pub const fn cmp_by_code(a: Self, b: Self) -> Ordering {
+        let (a, b) = (a.code().as_bytes(), b.code().as_bytes());
+        let mut i = 0;
+        while i < 2 {
+            if a[i] < b[i] {
+                return Ordering::Less;
+            }
+            if a[i] > b[i] {
+                return Ordering::Greater;
+            }
+            i += 1;
+        }
+        Ordering::Equal
+    }
+
+    /// Returns whether the language's 2 letter code is exactly `code`, in a const context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// const IS_GERMAN: bool = LanguageCode::eq_code(LanguageCode::De, "de");
+    /// assert!(IS_GERMAN);
+    /// assert!(!LanguageCode::eq_code(LanguageCode::De, "DE"));
+    /// ```
+    #[must_use]
+    pub const fn eq_code(language: Self, code: &str) -> bool {
+        let (a, b) = (language.code().as_bytes(), code.as_bytes());
+        b.len() == 2 && a[0] == b[0] && a[1] == b[1]
+    }
+
+    /// The length in bytes of the longest language [`name`](Self::name), for sizing buffers passed
+    /// to [`write_name`](Self::write_name).
+    pub const MAX_NAME_LEN: usize = {
+        let mut max = 0;
+        let mut i = 0;
+        while i < LANGUAGE_CODES.len() {
+            let len = LANGUAGE_CODES[i].name().len();
+            if len > max {
+                max = len;
+            }
+            i += 1;
+        }
+        max
+    };
+
+    /// Writes the 2 letter code of the language to the start of `buf`, returning the number of
+    /// bytes written (always 2).
+    ///
+    /// This doesn't allocate or go through [`core::fmt`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than 2 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let mut buf = [0; 8];
+    /// let len = LanguageCode::Sv.write_code(&mut buf);
+    /// assert_eq!(&buf[..len], b"sv");
+    /// ```
+    pub fn write_code(self, buf: &mut [u8]) -> usize {
+        write_bytes(buf, self.code())
+    }
+
+    /// Writes the ISO language name to the start of `buf`, returning the number of bytes written.
+    ///
+    /// A buffer of [`MAX_NAME_LEN`](Self::MAX_NAME_LEN) bytes fits every name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than the name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let mut buf = [0; LanguageCode::MAX_NAME_LEN];
+    /// let len = LanguageCode::Sv.write_name(&mut buf);
+    /// assert_eq!(&buf[..len], b"Swedish");
+    /// ```
+    pub fn write_name(self, buf: &mut [u8]) -> usize {
+        write_bytes(buf, self.name())
+    }
 }
 
 /// All language families, sorted by alphabetical order.
@@ -438,6 +973,49 @@ pub const FAMILIES: [&str; 26] = [
     "Uralic",
 ];
 
+/// All branches of language families, sorted by alphabetical order.
+pub const BRANCHES: [&str; 39] = [
+    "Albanian",
+    "Armenian",
+    "Atlantic",
+    "Avar–Andic",
+    "Baltic",
+    "Bantu",
+    "Celtic",
+    "Chadic",
+    "Cushitic",
+    "Finnic",
+    "Germanic",
+    "Hellenic",
+    "Indo-Aryan",
+    "Inuit",
+    "Iranian",
+    "Italic",
+    "Karluk",
+    "Khmeric",
+    "Kipchak",
+    "Kwa",
+    "Malayo-Polynesian",
+    "Mande",
+    "Nakh",
+    "Oghur",
+    "Oghuz",
+    "Permic",
+    "Romance",
+    "Saharan",
+    "Sami",
+    "Semitic",
+    "Sinitic",
+    "Slavic",
+    "South Dravidian",
+    "South-Central Dravidian",
+    "Tai",
+    "Tibeto-Burman",
+    "Ugric",
+    "Vietic",
+    "Volta–Niger",
+];
+
 macro_rules! static_array_iterators {
     ($($(#[doc = $doc:literal])* $name:ident($array:ident) -> $item:ty $({ $($mapper:tt)* })?,)*) => { $(
         $(#[doc = $doc])*
@@ -517,6 +1095,34 @@ static_array_iterators! {
 
     /// An iterator over all language families, created by [`LanguageCode::families`].
     Families(FAMILIES) -> &'static str,
+
+    /// An iterator over all branches of language families, created by
+    /// [`LanguageCode::branches`].
+    Branches(BRANCHES) -> &'static str,
+
+    /// An iterator over languages by their number of native speakers, created by
+    /// [`LanguageCode::iter_by_speakers`].
+    BySpeakers(BY_SPEAKERS) -> LanguageCode,
+}
+
+fn write_bytes(buf: &mut [u8], s: &str) -> usize {
+    buf[..s.len()].copy_from_slice(s.as_bytes());
+    s.len()
+}
+
+/// Compares byte strings in a const context.
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
 }
 
 /// An error parsing a language from its two letter language code.
@@ -524,6 +1130,7 @@ static_array_iterators! {
 pub struct ParseError {
     /// The language that could not be parsed.
     pub language: String,
+    kind: ParseErrorKind,
 }
 
 impl Display for ParseError {
@@ -536,6 +1143,92 @@ impl Display for ParseError {
     }
 }
 
+impl TryFrom<u8> for LanguageCode {
+    type Error = InvalidIndexError;
+
+    /// Calls [`LanguageCode::from_index`].
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        Self::from_index(index).ok_or(InvalidIndexError { index })
+    }
+}
+
+impl From<LanguageCode> for u8 {
+    /// Calls [`LanguageCode::to_index`].
+    fn from(language: LanguageCode) -> Self {
+        language.to_index()
+    }
+}
+
+/// An error converting an index that is out of range of [`LANGUAGE_CODES`] to a language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidIndexError {
+    /// The invalid index.
+    pub index: u8,
+}
+
+impl Display for InvalidIndexError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} is not the index of a language", self.index)
+    }
+}
+
+impl TryFrom<[u8; 2]> for LanguageCode {
+    type Error = ParseError;
+
+    /// Tries to convert from the bytes of a two letter language code.
+    fn try_from(bytes: [u8; 2]) -> Result<Self, Self::Error> {
+        Self::from_bytes(&bytes).ok_or_else(|| ParseError::new(&String::from_utf8_lossy(&bytes)))
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<&OsStr> for LanguageCode {
+    type Error = ParseOsStrError;
+
+    /// Tries to convert from a two letter language code, such as a command line argument.
+    fn try_from(s: &OsStr) -> Result<Self, Self::Error> {
+        s.to_str()
+            .ok_or_else(|| ParseOsStrError::NotUnicode(s.to_owned()))?
+            .parse()
+            .map_err(ParseOsStrError::Invalid)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<OsString> for LanguageCode {
+    type Error = ParseOsStrError;
+
+    /// Tries to convert from a two letter language code, such as a command line argument.
+    #[inline]
+    fn try_from(s: OsString) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_os_str())
+    }
+}
+
+/// An error parsing a language from an [`OsStr`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub enum ParseOsStrError {
+    /// The string was not valid Unicode.
+    NotUnicode(OsString),
+    /// The string was not a valid language code.
+    Invalid(ParseError),
+}
+
+#[cfg(feature = "std")]
+impl Display for ParseOsStrError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::NotUnicode(s) => write!(
+                f,
+                "{} is not a valid ISO 639-1 2 letter language code: not valid Unicode",
+                s.to_string_lossy()
+            ),
+            Self::Invalid(e) => e.fmt(f),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Families, LanguageCode};
@@ -555,6 +1248,13 @@ mod tests {
         assert_eq!(LanguageCode::Sg.code_b(), "sag");
     }
 
+    #[test]
+    fn display_padding() {
+        assert_eq!(format!("{:<8}|", LanguageCode::Nl), "Dutch   |");
+        assert_eq!(format!("{:>8}|", LanguageCode::Nl), "   Dutch|");
+        assert_eq!(format!("{:.3}", LanguageCode::Nl), "Dut");
+    }
+
     #[test]
     fn names_families() {
         assert_eq!(LanguageCode::Ae.name(), "Avestan");
@@ -578,6 +1278,67 @@ mod tests {
         assert!("sag".parse::<LanguageCode>().is_err());
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_os_str() {
+        use std::convert::TryFrom;
+        use std::ffi::{OsStr, OsString};
+
+        use crate::ParseOsStrError;
+
+        assert_eq!(
+            LanguageCode::try_from(OsStr::new("zh")).unwrap(),
+            LanguageCode::Zh
+        );
+        assert_eq!(
+            LanguageCode::try_from(OsString::from("sg")).unwrap(),
+            LanguageCode::Sg
+        );
+        assert!(matches!(
+            LanguageCode::try_from(OsStr::new("zho")),
+            Err(ParseOsStrError::Invalid(_))
+        ));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+
+            assert!(matches!(
+                LanguageCode::try_from(OsStr::from_bytes(b"z\xFF")),
+                Err(ParseOsStrError::NotUnicode(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_prefix() {
+        let mut input = "en:Hello|fr:Bonjour";
+        let mut pairs = Vec::new();
+        while let Some((language, rest)) = LanguageCode::parse_prefix(input) {
+            let rest = rest.strip_prefix(':').unwrap();
+            let (text, rest) = rest.split_once('|').unwrap_or((rest, ""));
+            pairs.push((language, text));
+            input = rest;
+        }
+        assert_eq!(
+            pairs,
+            [(LanguageCode::En, "Hello"), (LanguageCode::Fr, "Bonjour")]
+        );
+
+        assert_eq!(LanguageCode::parse_prefix("e"), None);
+        assert_eq!(LanguageCode::parse_prefix("é"), None);
+        assert_eq!(LanguageCode::parse_prefix("english"), None);
+        assert_eq!(LanguageCode::parse_prefix("fr2"), None);
+        assert_eq!(
+            LanguageCode::parse_prefix("de_AT"),
+            Some((LanguageCode::De, "_AT"))
+        );
+        assert_eq!(
+            LanguageCode::parse_prefix("en"),
+            Some((LanguageCode::En, ""))
+        );
+    }
+
     #[test]
     fn format() {
         assert_eq!(LanguageCode::Ae.to_string(), "Avestan");
@@ -621,4 +1382,180 @@ mod tests {
         assert_eq!(families.by_ref().count(), 24);
         assert_eq!(families.next(), None);
     }
+
+    #[test]
+    fn branches() {
+        assert!(crate::BRANCHES.windows(2).all(|pair| pair[0] < pair[1]));
+        for branch in LanguageCode::branches() {
+            assert!(LanguageCode::iter().any(|language| language.branch() == Some(branch)));
+        }
+        for language in LanguageCode::iter() {
+            if let Some(branch) = language.branch() {
+                assert!(crate::BRANCHES.contains(&branch));
+            }
+        }
+        assert_eq!(LanguageCode::Cu.branch(), Some("Slavic"));
+        assert_eq!(LanguageCode::Eo.branch(), None);
+    }
+
+    #[test]
+    fn family_codes() {
+        let without_code: Vec<_> = crate::FAMILIES
+            .into_iter()
+            .filter(|&family| {
+                let language = LanguageCode::iter()
+                    .find(|language| language.family() == family)
+                    .unwrap();
+                language.family_code().is_none()
+            })
+            .collect();
+        assert_eq!(
+            without_code,
+            ["Aymaran", "Dené–Yeniseian", "Koreanic", "Language isolate"]
+        );
+        assert_eq!(LanguageCode::Ht.family_code(), Some("crp"));
+        assert_eq!(LanguageCode::Fi.family_code(), Some("urj"));
+    }
+
+    #[test]
+    fn const_comparisons() {
+        use std::cmp::Ordering;
+
+        let mut codes: Vec<LanguageCode> = LanguageCode::iter().collect();
+        codes.sort_by(|&a, &b| LanguageCode::cmp_by_code(a, b));
+        assert!(codes.windows(2).all(|pair| pair[0].code() < pair[1].code()));
+        assert_eq!(
+            LanguageCode::cmp_by_code(LanguageCode::Fr, LanguageCode::Fr),
+            Ordering::Equal
+        );
+
+        assert!(LanguageCode::eq_code(LanguageCode::Zh, "zh"));
+        assert!(!LanguageCode::eq_code(LanguageCode::Zh, "z"));
+        assert!(!LanguageCode::eq_code(LanguageCode::Zh, "zho"));
+    }
+
+    #[test]
+    fn from_bytes() {
+        assert_eq!(
+            LanguageCode::from_bytes_ignore_case(b"zh"),
+            Some(LanguageCode::Zh)
+        );
+        assert_eq!(
+            LanguageCode::from_bytes_ignore_case(b"Sg"),
+            Some(LanguageCode::Sg)
+        );
+        assert_eq!(LanguageCode::from_bytes_ignore_case(b"xx"), None);
+        assert_eq!(LanguageCode::from_bytes_ignore_case(b"z"), None);
+        assert_eq!(LanguageCode::from_bytes_ignore_case(b"\xff\xfe"), None);
+    }
+
+    #[test]
+    fn from_bytes_exact() {
+        for language in LanguageCode::iter() {
+            assert_eq!(
+                LanguageCode::from_bytes(language.code().as_bytes()),
+                Some(language)
+            );
+        }
+        assert_eq!(LanguageCode::from_bytes(b"Sg"), None);
+        assert_eq!(LanguageCode::from_bytes(b"nld"), None);
+        assert_eq!(LanguageCode::try_from(*b"nl").unwrap(), LanguageCode::Nl);
+        assert_eq!(
+            LanguageCode::try_from(*b"\xffx").unwrap_err().language,
+            "\u{FFFD}x"
+        );
+    }
+
+    #[test]
+    fn indices() {
+        for (i, language) in LanguageCode::iter().enumerate() {
+            assert_eq!(usize::from(language.to_index()), i);
+            assert_eq!(
+                LanguageCode::try_from(u8::from(language)).unwrap(),
+                language
+            );
+        }
+        assert_eq!(LanguageCode::try_from(255).unwrap_err().index, 255);
+        assert_eq!(LanguageCode::from_index(184), None);
+    }
+
+    #[test]
+    fn validators() {
+        for language in LanguageCode::iter() {
+            assert!(LanguageCode::is_valid_code(language.code()));
+            assert!(LanguageCode::is_valid_code_t(language.code_t()));
+            assert!(LanguageCode::is_valid_code_b(language.code_b()));
+        }
+        assert!(!LanguageCode::is_valid_code("EN"));
+        assert!(!LanguageCode::is_valid_code_t("chi"));
+        assert!(!LanguageCode::is_valid_code_b("zho"));
+        assert!(!LanguageCode::is_valid_code_t(""));
+        assert!(!LanguageCode::is_valid_code_b("ch"));
+    }
+
+    #[test]
+    fn from_code_const() {
+        const ZU: LanguageCode = LanguageCode::from_code_const("zu");
+        assert_eq!(ZU, LanguageCode::Zu);
+        for language in LanguageCode::iter() {
+            assert_eq!(LanguageCode::from_code_const(language.code()), language);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid ISO 639-1 language code")]
+    fn from_code_const_invalid() {
+        let _ = LanguageCode::from_code_const("EN");
+    }
+
+    #[test]
+    fn write_bytes() {
+        let mut buf = [0; LanguageCode::MAX_NAME_LEN];
+        for language in LanguageCode::iter() {
+            let len = language.write_name(&mut buf);
+            assert_eq!(&buf[..len], language.name().as_bytes());
+            let len = language.write_code(&mut buf);
+            assert_eq!(&buf[..len], language.code().as_bytes());
+        }
+        assert!(LanguageCode::iter().any(|l| l.name().len() == LanguageCode::MAX_NAME_LEN));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_map_keys() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let map: BTreeMap<LanguageCode, u32> =
+            [(LanguageCode::En, 1), (LanguageCode::Ja, 2)].into();
+        let hash_map: HashMap<LanguageCode, u32> = map.clone().into_iter().collect();
+
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"en":1,"ja":2}"#);
+        assert_eq!(serde_json::from_str::<BTreeMap<_, _>>(&json).unwrap(), map);
+        let json = serde_json::to_string(&hash_map).unwrap();
+        assert_eq!(
+            serde_json::from_str::<HashMap<_, _>>(&json).unwrap(),
+            hash_map
+        );
+
+        let yaml = serde_yaml::to_string(&map).unwrap();
+        assert_eq!(yaml, "en: 1\nja: 2\n");
+        assert_eq!(serde_yaml::from_str::<BTreeMap<_, _>>(&yaml).unwrap(), map);
+
+        let toml = toml::to_string(&map).unwrap();
+        assert_eq!(toml, "en = 1\nja = 2\n");
+        assert_eq!(toml::from_str::<BTreeMap<_, _>>(&toml).unwrap(), map);
+
+        let bincode = bincode::serialize(&hash_map).unwrap();
+        assert_eq!(
+            bincode::deserialize::<HashMap<_, _>>(&bincode).unwrap(),
+            hash_map
+        );
+
+        let postcard = postcard::to_allocvec(&map).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<BTreeMap<_, _>>(&postcard).unwrap(),
+            map
+        );
+    }
 }
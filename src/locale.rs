@@ -0,0 +1,426 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+use core::str::{self, FromStr};
+#[cfg(feature = "std")]
+use std::ffi::OsString;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{write_bytes, LanguageCode};
+
+/// A region, either an ISO 3166-1 alpha-2 country code like `US` or a UN M.49 area code like
+/// `419`.
+///
+/// Country codes are normalized to upper case.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::Region;
+///
+/// assert_eq!(Region::new("gb").unwrap().as_str(), "GB");
+/// assert_eq!(Region::new("419").unwrap().as_str(), "419");
+/// assert_eq!(Region::new("GBR"), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Region([u8; 3]);
+
+impl Region {
+    /// Parses a region from an alpha-2 country code (ignoring ASCII case) or a 3 digit area code.
+    #[must_use]
+    pub const fn new(s: &str) -> Option<Self> {
+        match *s.as_bytes() {
+            [a, b] if a.is_ascii_alphabetic() && b.is_ascii_alphabetic() => {
+                Some(Self([a.to_ascii_uppercase(), b.to_ascii_uppercase(), 0]))
+            }
+            [a, b, c] if a.is_ascii_digit() && b.is_ascii_digit() && c.is_ascii_digit() => {
+                Some(Self([a, b, c]))
+            }
+            _ => None,
+        }
+    }
+
+    /// Like [`Region::new`], but panics if the region is invalid. This is intended for const
+    /// contexts, where the panic becomes a compile error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` is not a valid region.
+    #[must_use]
+    pub const fn from_const(s: &str) -> Self {
+        match Self::new(s) {
+            Some(region) => region,
+            None => panic!("invalid region"),
+        }
+    }
+
+    /// Returns the region code.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        let len = if self.0[2] == 0 { 2 } else { 3 };
+        core::str::from_utf8(&self.0[..len]).expect("regions are ASCII")
+    }
+
+    /// Returns whether this is an ISO 3166-1 alpha-2 country code rather than a UN M.49 area code.
+    #[must_use]
+    pub const fn is_country(&self) -> bool {
+        self.0[2] == 0
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl FromStr for Region {
+    type Err = ParseLocaleError;
+
+    /// Calls [`Region::new`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or_else(|| ParseLocaleError {
+            locale: s.to_owned(),
+        })
+    }
+}
+
+/// A language with an optional region, like `en-GB` or `pt`.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, Locale, Region};
+///
+/// let locale: Locale = "pt-BR".parse().unwrap();
+/// assert_eq!(locale.language, LanguageCode::Pt);
+/// assert_eq!(locale.region, Region::new("BR"));
+/// assert_eq!(locale.to_string(), "pt-BR");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Locale {
+    /// The language of the locale.
+    pub language: LanguageCode,
+    /// The region of the locale, if it has one.
+    pub region: Option<Region>,
+}
+
+impl Locale {
+    /// Creates a locale from a language and an optional region.
+    #[must_use]
+    pub const fn new(language: LanguageCode, region: Option<Region>) -> Self {
+        Self { language, region }
+    }
+
+    /// Returns the locale in POSIX form, separated by `_` instead of `-`, like `en_GB`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::Locale;
+    ///
+    /// let locale: Locale = "de-CH".parse().unwrap();
+    /// assert_eq!(locale.to_posix(), "de_CH");
+    /// ```
+    #[must_use]
+    pub fn to_posix(&self) -> String {
+        let mut posix = String::from(self.language.code());
+        if let Some(region) = self.region {
+            posix.push('_');
+            posix.push_str(region.as_str());
+        }
+        posix
+    }
+}
+
+/// A POSIX locale identifier, in the form `language[_territory][.codeset][@modifier]`, like
+/// `en_US.UTF-8` or `sr_RS@latin`, as found in the `LANG` and `LC_*` environment variables.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, PosixLocale, Region};
+///
+/// let posix = PosixLocale::parse("sr_RS.UTF-8@latin").unwrap();
+/// assert_eq!(posix.locale.language, LanguageCode::Sr);
+/// assert_eq!(posix.locale.region, Region::new("RS"));
+/// assert_eq!(posix.codeset, Some("UTF-8"));
+/// assert_eq!(posix.modifier, Some("latin"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PosixLocale<'a> {
+    /// The language and territory.
+    pub locale: Locale,
+    /// The codeset, like `UTF-8`.
+    pub codeset: Option<&'a str>,
+    /// The modifier, like `latin` or `euro`.
+    pub modifier: Option<&'a str>,
+}
+
+impl<'a> PosixLocale<'a> {
+    /// Parses a POSIX locale identifier. The language must be a lowercase 2 letter code, so the
+    /// `C` and `POSIX` locales are rejected.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the identifier is malformed or its language or territory isn't valid.
+    pub fn parse(s: &'a str) -> Result<Self, ParseLocaleError> {
+        let error = || ParseLocaleError {
+            locale: s.to_owned(),
+        };
+        let (rest, modifier) = match s.split_once('@') {
+            Some((rest, modifier)) => (rest, Some(modifier)),
+            None => (s, None),
+        };
+        let (rest, codeset) = match rest.split_once('.') {
+            Some((rest, codeset)) => (rest, Some(codeset)),
+            None => (rest, None),
+        };
+        if codeset == Some("") || modifier == Some("") {
+            return Err(error());
+        }
+        let (language, region) = match rest.split_once('_') {
+            Some((language, region)) => (language, Some(Region::new(region).ok_or_else(error)?)),
+            None => (rest, None),
+        };
+        Ok(Self {
+            locale: Locale::new(language.parse().map_err(|_| error())?, region),
+            codeset,
+            modifier,
+        })
+    }
+}
+
+impl Locale {
+    /// Parses the language and territory of a POSIX locale identifier like `en_US.UTF-8`, ignoring
+    /// its codeset and modifier. See [`PosixLocale`] to keep them.
+    ///
+    /// # Errors
+    ///
+    /// Fails if [`PosixLocale::parse`] fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::Locale;
+    ///
+    /// let locale = Locale::from_posix("de_CH.UTF-8").unwrap();
+    /// assert_eq!(locale.to_string(), "de-CH");
+    /// assert!(Locale::from_posix("C").is_err());
+    /// ```
+    pub fn from_posix(s: &str) -> Result<Self, ParseLocaleError> {
+        PosixLocale::parse(s).map(|posix| posix.locale)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Locale {
+    /// Reads the locale used for messages from the `LC_ALL`, `LC_MESSAGES` and `LANG` environment
+    /// variables, in that order of precedence, as POSIX does.
+    ///
+    /// The first variable that is set and not empty is used. Returns `None` if there is none, or
+    /// if it isn't a POSIX locale identifier with an ISO 639-1 language, like the `C` locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::Locale;
+    ///
+    /// // Prints "en-US" with `LANG=en_US.UTF-8`.
+    /// if let Some(locale) = Locale::from_env() {
+    ///     println!("{}", locale);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        from_env_with(|name| std::env::var_os(name))
+    }
+}
+
+#[cfg(feature = "std")]
+impl LanguageCode {
+    /// Reads the language used for messages from the environment, like
+    /// [`Locale::from_env`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let language = LanguageCode::from_env().unwrap_or(LanguageCode::En);
+    /// ```
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        Locale::from_env().map(|locale| locale.language)
+    }
+}
+
+/// Reads the locale from environment variables looked up with `var`.
+#[cfg(feature = "std")]
+fn from_env_with(var: impl Fn(&str) -> Option<OsString>) -> Option<Locale> {
+    let value = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(var)
+        .find(|value| !value.is_empty())?;
+    Locale::from_posix(value.to_str()?).ok()
+}
+
+impl From<LanguageCode> for Locale {
+    fn from(language: LanguageCode) -> Self {
+        Self::new(language, None)
+    }
+}
+
+impl Display for Locale {
+    /// Writes the locale as a BCP 47 tag, like `en-GB`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Long enough for a 2 letter code, a hyphen and a 3 digit area code.
+        let mut buf = [0; 6];
+        let mut len = write_bytes(&mut buf, self.language.code());
+        if let Some(region) = self.region {
+            buf[len] = b'-';
+            len += 1 + write_bytes(&mut buf[len + 1..], region.as_str());
+        }
+        f.pad(str::from_utf8(&buf[..len]).expect("locale is ASCII"))
+    }
+}
+
+impl FromStr for Locale {
+    type Err = ParseLocaleError;
+
+    /// Parses a 2 letter language code optionally followed by a region, separated by `-` or `_`.
+    /// Both are case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, ['-', '_']);
+        let language = parts
+            .next()
+            .and_then(|language| LanguageCode::from_bytes_ignore_case(language.as_bytes()));
+        let region = parts.next().map(Region::new);
+        match (language, region) {
+            (Some(language), None) => Ok(Self::new(language, None)),
+            (Some(language), Some(Some(region))) => Ok(Self::new(language, Some(region))),
+            _ => Err(ParseLocaleError {
+                locale: s.to_owned(),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Locale {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// An error parsing a [`Locale`] or [`Region`].
+#[derive(Debug, Clone)]
+pub struct ParseLocaleError {
+    /// The locale that could not be parsed.
+    pub locale: String,
+}
+
+impl Display for ParseLocaleError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} is not a valid locale", self.locale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, Locale, PosixLocale, Region};
+
+    #[test]
+    fn regions() {
+        assert_eq!(Region::new("us"), Some(Region::from_const("US")));
+        assert!(Region::from_const("US").is_country());
+        assert!(!Region::from_const("419").is_country());
+        assert_eq!(Region::new("4a9"), None);
+        assert_eq!(Region::new(""), None);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "en_gb".parse::<Locale>().unwrap(),
+            Locale::new(LanguageCode::En, Region::new("GB"))
+        );
+        assert_eq!("es-419".parse::<Locale>().unwrap().to_string(), "es-419");
+        assert_eq!(
+            "fr".parse::<Locale>().unwrap(),
+            Locale::from(LanguageCode::Fr)
+        );
+        assert_eq!(
+            "EN-us".parse::<Locale>().unwrap(),
+            Locale::new(LanguageCode::En, Region::new("US"))
+        );
+        assert_eq!("Pt_br".parse::<Locale>().unwrap().to_string(), "pt-BR");
+        assert!("fr-".parse::<Locale>().is_err());
+        assert!("xx-FR".parse::<Locale>().is_err());
+        assert!("fr-FRA".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn padding() {
+        let locale = Locale::new(LanguageCode::En, Region::new("GB"));
+        assert_eq!(format!("[{:>8}]", locale), "[   en-GB]");
+        assert_eq!(
+            format!("[{:<8}]", Locale::from(LanguageCode::Fr)),
+            "[fr      ]"
+        );
+    }
+
+    #[test]
+    fn posix() {
+        let posix = PosixLocale::parse("en_US.UTF-8").unwrap();
+        assert_eq!(
+            posix.locale,
+            Locale::new(LanguageCode::En, Region::new("US"))
+        );
+        assert_eq!((posix.codeset, posix.modifier), (Some("UTF-8"), None));
+
+        let posix = PosixLocale::parse("ca@valencia").unwrap();
+        assert_eq!(posix.locale, Locale::from(LanguageCode::Ca));
+        assert_eq!((posix.codeset, posix.modifier), (None, Some("valencia")));
+
+        for invalid in [
+            "C", "POSIX", "C.UTF-8", "en_", "en_US.", "en@", "EN_US", "eng_US",
+        ] {
+            assert!(PosixLocale::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn env() {
+        use super::from_env_with;
+        use std::collections::HashMap;
+        use std::ffi::OsString;
+
+        let from = |vars: &[(&str, &str)]| {
+            let vars: HashMap<_, _> = vars.iter().copied().collect();
+            from_env_with(|name| vars.get(name).map(OsString::from))
+        };
+        assert_eq!(from(&[]), None);
+        assert_eq!(
+            from(&[("LANG", "de_AT.UTF-8"), ("LC_MESSAGES", "fr_CA")]),
+            Some(Locale::new(LanguageCode::Fr, Region::new("CA")))
+        );
+        assert_eq!(
+            from(&[("LANG", "de_AT.UTF-8"), ("LC_ALL", "")]),
+            Some(Locale::new(LanguageCode::De, Region::new("AT")))
+        );
+        assert_eq!(from(&[("LANG", "de_AT"), ("LC_ALL", "C")]), None);
+    }
+}
@@ -0,0 +1,179 @@
+use alloc::collections::btree_map::{self, BTreeMap};
+use alloc::string::String;
+use core::iter::FromIterator;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{LangStr, LangString, LanguageCode, LanguageSet};
+
+/// A text value with translations into several languages, such as a translated label.
+///
+/// With the `serde` feature, it is (de)serialized as an object keyed by language code, like
+/// `{"en": "Save", "fr": "Enregistrer"}`.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, LocalizedString};
+///
+/// let label = LocalizedString::new()
+///     .with(LanguageCode::En, "Save")
+///     .with(LanguageCode::Fr, "Enregistrer");
+///
+/// assert_eq!(label.get(LanguageCode::Fr), Some("Enregistrer"));
+/// let text = label
+///     .get_with_fallback(LanguageCode::De, &[LanguageCode::En])
+///     .unwrap();
+/// assert_eq!(text.lang, LanguageCode::En);
+/// assert_eq!(text.text, "Save");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct LocalizedString {
+    translations: BTreeMap<LanguageCode, String>,
+}
+
+impl LocalizedString {
+    /// Creates a value with no translations.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            translations: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a translation, replacing any existing one for the language.
+    #[must_use]
+    pub fn with(mut self, language: LanguageCode, text: impl Into<String>) -> Self {
+        self.insert(language, text);
+        self
+    }
+
+    /// Adds a translation, returning the one it replaced.
+    pub fn insert(&mut self, language: LanguageCode, text: impl Into<String>) -> Option<String> {
+        self.translations.insert(language, text.into())
+    }
+
+    /// Removes the translation for a language, returning it.
+    pub fn remove(&mut self, language: LanguageCode) -> Option<String> {
+        self.translations.remove(&language)
+    }
+
+    /// Returns the translation for a language.
+    #[must_use]
+    pub fn get(&self, language: LanguageCode) -> Option<&str> {
+        self.translations.get(&language).map(String::as_str)
+    }
+
+    /// Returns the translation for the requested language, or else for the first language of the
+    /// fallback chain that has one.
+    #[must_use]
+    pub fn get_with_fallback(
+        &self,
+        requested: LanguageCode,
+        chain: &[LanguageCode],
+    ) -> Option<LangStr<'_>> {
+        core::iter::once(&requested)
+            .chain(chain)
+            .find_map(|&language| Some(LangStr::new(language, self.get(language)?)))
+    }
+
+    /// Returns the number of translations.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.translations.len()
+    }
+
+    /// Returns whether there are no translations.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.translations.is_empty()
+    }
+
+    /// Returns the languages that have translations.
+    #[must_use]
+    pub fn languages(&self) -> LanguageSet {
+        self.translations.keys().copied().collect()
+    }
+
+    /// Iterates over the translations, in the order of [`LanguageCode`]'s [`Ord`] implementation.
+    pub fn iter(&self) -> impl Iterator<Item = LangStr<'_>> {
+        self.translations
+            .iter()
+            .map(|(&language, text)| LangStr::new(language, text))
+    }
+}
+
+impl<S: Into<String>> FromIterator<(LanguageCode, S)> for LocalizedString {
+    fn from_iter<I: IntoIterator<Item = (LanguageCode, S)>>(iter: I) -> Self {
+        let mut localized = Self::new();
+        localized.extend(iter);
+        localized
+    }
+}
+
+impl FromIterator<LangString> for LocalizedString {
+    fn from_iter<I: IntoIterator<Item = LangString>>(iter: I) -> Self {
+        iter.into_iter().map(|s| (s.lang, s.text)).collect()
+    }
+}
+
+impl<S: Into<String>> Extend<(LanguageCode, S)> for LocalizedString {
+    fn extend<I: IntoIterator<Item = (LanguageCode, S)>>(&mut self, iter: I) {
+        for (language, text) in iter {
+            self.insert(language, text);
+        }
+    }
+}
+
+impl IntoIterator for LocalizedString {
+    type Item = (LanguageCode, String);
+    type IntoIter = btree_map::IntoIter<LanguageCode, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.translations.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LangString, LanguageCode, LocalizedString};
+
+    #[test]
+    fn fallback() {
+        let localized: LocalizedString = [
+            LangString::new(LanguageCode::Nb, "Lagre"),
+            LangString::new(LanguageCode::En, "Save"),
+        ]
+        .into_iter()
+        .collect();
+        let get = |requested, chain: &[LanguageCode]| {
+            localized
+                .get_with_fallback(requested, chain)
+                .map(|s| s.text)
+        };
+        assert_eq!(get(LanguageCode::Nb, &[LanguageCode::En]), Some("Lagre"));
+        assert_eq!(
+            get(LanguageCode::No, &[LanguageCode::Nb, LanguageCode::En]),
+            Some("Lagre")
+        );
+        assert_eq!(get(LanguageCode::Fr, &[LanguageCode::En]), Some("Save"));
+        assert_eq!(get(LanguageCode::Fr, &[]), None);
+        assert_eq!(localized.languages().len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let localized = LocalizedString::new()
+            .with(LanguageCode::En, "Save")
+            .with(LanguageCode::Fr, "Enregistrer");
+        let json = serde_json::to_string(&localized).unwrap();
+        assert_eq!(json, r#"{"en":"Save","fr":"Enregistrer"}"#);
+        assert_eq!(
+            serde_json::from_str::<LocalizedString>(&json).unwrap(),
+            localized
+        );
+    }
+}
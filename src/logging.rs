@@ -0,0 +1,109 @@
+//! Structured logging support, recording languages by their codes instead of as `Debug`-formatted
+//! enum variants like `Nl`. Enabled by the `slog` and `tracing` features.
+
+use crate::LanguageCode;
+#[cfg(feature = "slog")]
+use crate::{LanguageTag, Locale};
+
+#[cfg(feature = "tracing")]
+impl LanguageCode {
+    /// Returns a [`tracing`](https://docs.rs/tracing) field value recording the language's 2 letter
+    /// code.
+    ///
+    /// `tracing`'s `Value` trait is sealed, so languages can't implement it directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// # let _ = |language: LanguageCode| {
+    /// tracing::info!(language = language.tracing_value(), "translating");
+    /// # };
+    /// ```
+    #[must_use]
+    pub fn tracing_value(self) -> impl tracing_core::field::Value {
+        self.code()
+    }
+}
+
+#[cfg(feature = "slog")]
+impl slog::Value for LanguageCode {
+    /// Records the language's 2 letter code.
+    fn serialize(
+        &self,
+        _record: &slog::Record,
+        key: slog::Key,
+        serializer: &mut dyn slog::Serializer,
+    ) -> slog::Result {
+        serializer.emit_str(key, self.code())
+    }
+}
+
+#[cfg(feature = "slog")]
+impl slog::Value for Locale {
+    /// Records the locale as a BCP 47 tag, like `en-GB`.
+    fn serialize(
+        &self,
+        _record: &slog::Record,
+        key: slog::Key,
+        serializer: &mut dyn slog::Serializer,
+    ) -> slog::Result {
+        serializer.emit_arguments(key, &format_args!("{}", self))
+    }
+}
+
+#[cfg(feature = "slog")]
+impl slog::Value for LanguageTag {
+    /// Records the tag in its normalized case.
+    fn serialize(
+        &self,
+        _record: &slog::Record,
+        key: slog::Key,
+        serializer: &mut dyn slog::Serializer,
+    ) -> slog::Result {
+        serializer.emit_arguments(key, &format_args!("{}", self))
+    }
+}
+
+#[cfg(all(test, feature = "slog"))]
+mod tests {
+    use std::fmt::{self, Write};
+
+    use slog::{o, Drain, Logger, Record, Serializer, KV};
+
+    use crate::{LanguageCode, LanguageTag};
+
+    struct Capture(String);
+
+    impl Serializer for Capture {
+        fn emit_arguments(&mut self, key: slog::Key, val: &fmt::Arguments) -> slog::Result {
+            write!(self.0, "{}={} ", key, val).unwrap();
+            Ok(())
+        }
+    }
+
+    struct CaptureDrain;
+
+    impl Drain for CaptureDrain {
+        type Ok = ();
+        type Err = slog::Never;
+
+        fn log(&self, record: &Record, values: &slog::OwnedKVList) -> Result<(), Self::Err> {
+            let mut capture = Capture(String::new());
+            values.serialize(record, &mut capture).unwrap();
+            assert_eq!(capture.0, "tag=zh-Hant language=nl ");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn slog_values() {
+        let tag: LanguageTag = "zh-hant".parse().unwrap();
+        let logger = Logger::root(
+            CaptureDrain,
+            o!("language" => LanguageCode::Nl, "tag" => tag),
+        );
+        slog::info!(logger, "message");
+    }
+}
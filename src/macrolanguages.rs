@@ -0,0 +1,277 @@
+//! ISO 639-3 scopes and language types, and the individual languages macrolanguages group.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::LanguageCode;
+
+/// What kind of entity a code denotes in the ISO 639 registry.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, Scope};
+///
+/// assert_eq!(LanguageCode::Fr.scope(), Scope::Individual);
+/// assert_eq!(LanguageCode::Ar.scope(), Scope::Macrolanguage);
+/// assert_eq!(LanguageCode::Bh.scope(), Scope::Collective);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Scope {
+    /// A single language, like French.
+    Individual,
+    /// A cluster of closely related individual languages, like Arabic. See
+    /// [`LanguageCode::members`].
+    Macrolanguage,
+    /// A group of languages that aren't a single language for any purpose, like the Bihari
+    /// languages.
+    Collective,
+    /// A code for a special situation, like `mul` for multiple languages. No ISO 639-1 code has
+    /// this scope.
+    Special,
+}
+
+/// Whether a language is spoken today, and if not, why it is still in use.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, LanguageType};
+///
+/// assert_eq!(LanguageCode::De.language_type(), LanguageType::Living);
+/// assert_eq!(LanguageCode::La.language_type(), LanguageType::Ancient);
+/// assert_eq!(LanguageCode::Eo.language_type(), LanguageType::Constructed);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum LanguageType {
+    /// A language with native speakers today.
+    Living,
+    /// A language that went extinct in ancient times, like Latin or Pali.
+    Ancient,
+    /// An earlier stage of a language that is distinct from its modern form, like Church Slavic.
+    Historical,
+    /// A language deliberately created, like Esperanto.
+    Constructed,
+}
+
+impl LanguageCode {
+    /// Returns whether the language is living, ancient, historical or constructed, following the
+    /// ISO 639-3 language types.
+    ///
+    /// Church Slavic is historical rather than ancient as in ISO 639-3, since it is an earlier stage
+    /// of the living Slavic languages.
+    #[must_use]
+    pub const fn language_type(self) -> LanguageType {
+        match self {
+            Self::Ae | Self::La | Self::Pi | Self::Sa => LanguageType::Ancient,
+            Self::Cu => LanguageType::Historical,
+            Self::Eo | Self::Ia | Self::Ie | Self::Io | Self::Vo => LanguageType::Constructed,
+            _ => LanguageType::Living,
+        }
+    }
+
+    /// Returns whether the language has native speakers today. Equivalent to
+    /// `self.language_type() == LanguageType::Living`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let pickable: Vec<_> = LanguageCode::iter().filter(|l| l.is_living()).collect();
+    /// assert!(pickable.contains(&LanguageCode::Ga));
+    /// assert!(!pickable.contains(&LanguageCode::Sa));
+    /// ```
+    #[must_use]
+    pub const fn is_living(self) -> bool {
+        matches!(self.language_type(), LanguageType::Living)
+    }
+
+    /// Returns the scope of the language in the ISO 639-3 registry, which can be used to skip
+    /// macrolanguages and collections when only individual languages make sense.
+    #[must_use]
+    pub const fn scope(self) -> Scope {
+        match self {
+            Self::Bh => Scope::Collective,
+            _ if !self.members().is_empty() => Scope::Macrolanguage,
+            _ => Scope::Individual,
+        }
+    }
+
+    /// Returns whether the language is an ISO 639-3 macrolanguage, a cluster of closely related
+    /// languages that are often treated as one, like Arabic or Chinese.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert!(LanguageCode::Zh.is_macrolanguage());
+    /// assert!(!LanguageCode::Fr.is_macrolanguage());
+    /// ```
+    #[must_use]
+    pub const fn is_macrolanguage(self) -> bool {
+        matches!(self.scope(), Scope::Macrolanguage)
+    }
+
+    /// Returns the ISO 639-3 codes of the individual languages in the macrolanguage, in
+    /// alphabetical order, or an empty slice if the language is not a macrolanguage.
+    ///
+    /// The codes follow the SIL macrolanguage mappings, without retired codes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::No.members(), ["nno", "nob"]);
+    /// assert!(LanguageCode::Zh.members().contains(&"yue"));
+    /// assert!(LanguageCode::En.members().is_empty());
+    /// ```
+    #[must_use]
+    #[rustfmt::skip]
+    pub const fn members(self) -> &'static [&'static str] {
+        match self {
+            Self::Ak => &["fat", "twi"],
+            Self::Ar => &["aao", "abh", "abv", "acm", "acq", "acw", "acx", "acy", "adf", "aeb", "aec", "afb", "apc", "apd", "arb", "arq", "ars", "ary", "arz", "auz", "avl", "ayh", "ayl", "ayn", "ayp", "pga", "shu", "ssh"],
+            Self::Ay => &["ayc", "ayr"],
+            Self::Az => &["azb", "azj"],
+            Self::Cr => &["crj", "crk", "crl", "crm", "csw", "cwd"],
+            Self::Et => &["ekk", "vro"],
+            Self::Fa => &["pes", "prs"],
+            Self::Ff => &["ffm", "fub", "fuc", "fue", "fuf", "fuh", "fui", "fuq", "fuv"],
+            Self::Gn => &["gnw", "gug", "gui", "gun", "nhd"],
+            Self::Ik => &["esi", "esk"],
+            Self::Iu => &["ike", "ikt"],
+            Self::Kg => &["kng", "kwy", "ldi"],
+            Self::Kr => &["bms", "kby", "knc", "krt"],
+            Self::Ku => &["ckb", "kmr", "sdh"],
+            Self::Kv => &["koi", "kpv"],
+            Self::Lv => &["ltg", "lvs"],
+            Self::Mg => &["bhr", "bmm", "bzc", "msh", "plt", "skg", "tdx", "tkg", "txy", "xmv", "xmw"],
+            Self::Mn => &["khk", "mvf"],
+            Self::Ms => &["bjn", "btj", "bve", "bvu", "coa", "dup", "hji", "ind", "jak", "jax", "kvb", "kvr", "kxd", "lce", "lcf", "liw", "max", "meo", "mfa", "mfb", "min", "mqg", "msi", "mui", "orn", "ors", "pel", "pse", "tmw", "urk", "vkk", "vkt", "xmm", "zlm", "zmi", "zsm"],
+            Self::Ne => &["dty", "npi"],
+            Self::No => &["nno", "nob"],
+            Self::Oj => &["ciw", "ojb", "ojc", "ojg", "ojs", "ojw", "otw"],
+            Self::Om => &["gax", "gaz", "hae", "orc"],
+            Self::Or => &["ory", "spv"],
+            Self::Ps => &["pbt", "pbu", "pst"],
+            Self::Qu => &["qub", "qud", "quf", "qug", "quh", "quk", "qul", "qup", "qur", "qus", "quw", "qux", "quy", "quz", "qva", "qvc", "qve", "qvh", "qvi", "qvj", "qvl", "qvm", "qvn", "qvo", "qvp", "qvs", "qvw", "qvz", "qwa", "qwc", "qwh", "qws", "qxa", "qxc", "qxh", "qxl", "qxn", "qxo", "qxp", "qxr", "qxt", "qxu", "qxw"],
+            Self::Sc => &["sdc", "sdn", "src", "sro"],
+            Self::Sq => &["aae", "aat", "aln", "als"],
+            Self::Sw => &["swc", "swh"],
+            Self::Uz => &["uzn", "uzs"],
+            Self::Yi => &["ydd", "yih"],
+            Self::Za => &["zch", "zeh", "zgb", "zgm", "zgn", "zhd", "zhn", "zlj", "zln", "zlq", "zqe", "zyb", "zyg", "zyj", "zyn", "zzj"],
+            Self::Zh => &["cdo", "cjy", "cmn", "cnp", "cpx", "csp", "czh", "czo", "gan", "hak", "hsn", "lzh", "mnp", "nan", "wuu", "yue"],
+            _ => &[],
+        }
+    }
+}
+
+impl LanguageCode {
+    /// Returns the macrolanguage that includes the language, if the macrolanguage has an ISO
+    /// 639-1 code too, like Norwegian for Norwegian Bokmål.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::Nn.parent_macro(), Some(LanguageCode::No));
+    /// assert_eq!(LanguageCode::Id.parent_macro(), Some(LanguageCode::Ms));
+    /// assert_eq!(LanguageCode::Da.parent_macro(), None);
+    /// ```
+    #[must_use]
+    pub const fn parent_macro(self) -> Option<Self> {
+        match self {
+            Self::Nb | Self::Nn => Some(Self::No),
+            Self::Id => Some(Self::Ms),
+            _ => None,
+        }
+    }
+
+    /// Returns the languages with their own ISO 639-1 code that the macrolanguage includes. This
+    /// is the reverse of [`parent_macro`](Self::parent_macro).
+    ///
+    /// Variants that only differ in script, like Serbian in Cyrillic and Latin, share a code and
+    /// are listed by [`scripts`](Self::scripts) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::No.variants(), [LanguageCode::Nb, LanguageCode::Nn]);
+    /// assert!(LanguageCode::Sr.variants().is_empty());
+    /// ```
+    #[must_use]
+    pub const fn variants(self) -> &'static [Self] {
+        match self {
+            Self::No => &[Self::Nb, Self::Nn],
+            Self::Ms => &[Self::Id],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, LanguageType, Scope};
+
+    #[test]
+    fn members() {
+        let macrolanguages: Vec<_> = LanguageCode::iter()
+            .filter(|language| language.is_macrolanguage())
+            .collect();
+        assert_eq!(macrolanguages.len(), 33);
+        for language in macrolanguages {
+            let members = language.members();
+            assert!(members.windows(2).all(|w| w[0] < w[1]), "{:?}", language);
+            assert!(members.iter().all(|member| member.len() == 3));
+        }
+        assert_eq!(LanguageCode::Fa.members(), ["pes", "prs"]);
+    }
+
+    #[test]
+    fn variants() {
+        for language in LanguageCode::iter() {
+            for &variant in language.variants() {
+                assert_eq!(variant.parent_macro(), Some(language));
+                assert!(language.members().contains(&variant.code_t()));
+            }
+            if let Some(parent) = language.parent_macro() {
+                assert!(parent.variants().contains(&language));
+            }
+        }
+    }
+
+    #[test]
+    fn scope() {
+        assert_eq!(LanguageCode::En.scope(), Scope::Individual);
+        assert_eq!(LanguageCode::Sw.scope(), Scope::Macrolanguage);
+        assert_eq!(LanguageCode::Bh.scope(), Scope::Collective);
+        assert!(LanguageCode::Bh.members().is_empty());
+        assert_eq!(
+            LanguageCode::iter()
+                .filter(|language| language.scope() == Scope::Individual)
+                .count(),
+            150
+        );
+    }
+
+    #[test]
+    fn language_type() {
+        assert_eq!(LanguageCode::Ae.language_type(), LanguageType::Ancient);
+        assert_eq!(LanguageCode::Cu.language_type(), LanguageType::Historical);
+        assert_eq!(LanguageCode::Vo.language_type(), LanguageType::Constructed);
+        assert!(LanguageCode::Kw.is_living());
+        assert_eq!(LanguageCode::iter().filter(|l| !l.is_living()).count(), 10);
+    }
+}
@@ -0,0 +1,76 @@
+/// Generates a module's items mapping languages to a translation provider's language identifiers.
+///
+/// The first identifier of each language is its preferred one; any others are script- or
+/// region-qualified variants that the provider also accepts.
+macro_rules! provider_codes {
+    ($provider:literal, $(($variant:ident, [$($code:literal),+]),)+) => {
+        #[doc = concat!("Every language ", $provider, " supports.")]
+        pub const LANGUAGES: $crate::LanguageSet =
+            $crate::LanguageSet::from_slice(&[$($crate::LanguageCode::$variant,)+]);
+
+        #[doc = concat!("Returns the preferred ", $provider, " identifier of a language, or `None` if it isn't supported.")]
+        #[must_use]
+        pub const fn code(language: $crate::LanguageCode) -> Option<&'static str> {
+            match codes(language) {
+                [code, ..] => Some(code),
+                [] => None,
+            }
+        }
+
+        #[doc = concat!("Returns every ", $provider, " identifier of a language, including script- and region-qualified variants.")]
+        #[must_use]
+        pub const fn codes(language: $crate::LanguageCode) -> &'static [&'static str] {
+            match language {
+                $($crate::LanguageCode::$variant => &[$($code),+],)+
+                _ => &[],
+            }
+        }
+
+        #[doc = concat!("Parses a ", $provider, " language identifier, ignoring ASCII case.")]
+        #[must_use]
+        pub fn from_code(code: &str) -> Option<$crate::LanguageCode> {
+            $(
+                if [$($code),+].iter().any(|c| c.eq_ignore_ascii_case(code)) {
+                    return Some($crate::LanguageCode::$variant);
+                }
+            )+
+            None
+        }
+    };
+}
+
+/// Generates a module's items mapping languages and locales to a voice assistant's locale
+/// identifiers, on top of [`provider_codes`].
+///
+/// The first locale of each language is the one used for locales the assistant doesn't support.
+macro_rules! voice_locales {
+    ($provider:literal, $(($variant:ident, [$($code:literal),+]),)+) => {
+        provider_codes! {
+            $provider,
+            $(($variant, [$($code),+]),)+
+        }
+
+        #[doc = concat!("Every locale ", $provider, " supports.")]
+        pub const LOCALES: &[&str] = &[$($($code,)+)+];
+
+        #[doc = concat!("Returns the ", $provider, " locale to use for a locale: the locale itself if it is supported, otherwise the preferred locale of its language.")]
+        #[must_use]
+        pub fn locale(locale: $crate::Locale) -> Option<&'static str> {
+            let tag = alloc::string::ToString::to_string(&locale);
+            codes(locale.language)
+                .iter()
+                .copied()
+                .find(|code| code.eq_ignore_ascii_case(&tag))
+                .or_else(|| code(locale.language))
+        }
+
+        #[doc = concat!("Parses a ", $provider, " locale, ignoring ASCII case.")]
+        #[must_use]
+        pub fn parse_locale(code: &str) -> Option<$crate::Locale> {
+            LOCALES
+                .iter()
+                .find(|locale| locale.eq_ignore_ascii_case(code))
+                .and_then(|locale| locale.parse().ok())
+        }
+    };
+}
@@ -0,0 +1,99 @@
+use crate::{LanguageCode, LANGUAGE_CODES};
+
+/// Every language keyed by its 2 letter code, sorted by code so it can be searched with
+/// [`binary_search_by_key`](slice::binary_search_by_key).
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, CODE_TO_LANGUAGE};
+///
+/// let i = CODE_TO_LANGUAGE.binary_search_by_key(&"nl", |&(code, _)| code).unwrap();
+/// assert_eq!(CODE_TO_LANGUAGE[i].1, LanguageCode::Nl);
+/// ```
+pub static CODE_TO_LANGUAGE: [(&str, LanguageCode); 184] = sorted_by(Key::Code);
+
+/// Every language keyed by its ISO 639-2 T code, sorted by code.
+pub static CODE_T_TO_LANGUAGE: [(&str, LanguageCode); 184] = sorted_by(Key::CodeT);
+
+/// Every language keyed by its ISO 639-2 B code, sorted by code.
+pub static CODE_B_TO_LANGUAGE: [(&str, LanguageCode); 184] = sorted_by(Key::CodeB);
+
+/// Every language keyed by its ISO name, sorted by name in byte order.
+pub static NAME_TO_LANGUAGE: [(&str, LanguageCode); 184] = sorted_by(Key::Name);
+
+/// Looks up a key in one of the maps.
+pub(crate) fn get(map: &[(&str, LanguageCode)], key: &str) -> Option<LanguageCode> {
+    map.binary_search_by_key(&key, |&(key, _)| key)
+        .ok()
+        .map(|i| map[i].1)
+}
+
+#[derive(Clone, Copy)]
+enum Key {
+    Code,
+    CodeT,
+    CodeB,
+    Name,
+}
+
+const fn sorted_by(key: Key) -> [(&'static str, LanguageCode); 184] {
+    let mut map = [("", LanguageCode::Aa); 184];
+    let mut i = 0;
+    while i < map.len() {
+        let language = LANGUAGE_CODES[i];
+        let key = match key {
+            Key::Code => language.code(),
+            Key::CodeT => language.code_t(),
+            Key::CodeB => language.code_b(),
+            Key::Name => language.name(),
+        };
+        map[i] = (key, language);
+        let mut j = i;
+        while j > 0 && is_less(map[j].0, map[j - 1].0) {
+            let swap = map[j];
+            map[j] = map[j - 1];
+            map[j - 1] = swap;
+            j -= 1;
+        }
+        i += 1;
+    }
+    map
+}
+
+const fn is_less(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get, CODE_B_TO_LANGUAGE, CODE_TO_LANGUAGE, CODE_T_TO_LANGUAGE, NAME_TO_LANGUAGE};
+    use crate::LanguageCode;
+
+    #[test]
+    fn maps() {
+        for map in [
+            &CODE_TO_LANGUAGE,
+            &CODE_T_TO_LANGUAGE,
+            &CODE_B_TO_LANGUAGE,
+            &NAME_TO_LANGUAGE,
+        ] {
+            assert!(map.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+        for language in LanguageCode::iter() {
+            assert_eq!(get(&CODE_TO_LANGUAGE, language.code()), Some(language));
+            assert_eq!(get(&CODE_T_TO_LANGUAGE, language.code_t()), Some(language));
+            assert_eq!(get(&CODE_B_TO_LANGUAGE, language.code_b()), Some(language));
+            assert_eq!(get(&NAME_TO_LANGUAGE, language.name()), Some(language));
+        }
+        assert_eq!(get(&NAME_TO_LANGUAGE, "Klingon"), None);
+    }
+}
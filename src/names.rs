@@ -0,0 +1,173 @@
+//! Language names in other languages, from the [CLDR](https://cldr.unicode.org/) display names.
+//! Enabled by the `cldr-names` feature.
+
+#[cfg(feature = "system-locale")]
+use std::fmt::{self, Display, Formatter};
+
+use crate::LanguageCode;
+
+mod data;
+mod list;
+
+pub use list::format_language_list;
+
+impl LanguageCode {
+    /// Returns the name of the language in a display language, as used in the middle of a
+    /// sentence.
+    ///
+    /// Returns `None` if CLDR doesn't have the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::De.name_in(LanguageCode::Fr), Some("allemand"));
+    /// assert_eq!(LanguageCode::De.name_in(LanguageCode::Ja), Some("ドイツ語"));
+    /// assert_eq!(LanguageCode::De.name_in(LanguageCode::De), Some("Deutsch"));
+    /// ```
+    #[must_use]
+    pub fn name_in(self, display: LanguageCode) -> Option<&'static str> {
+        Some(data::names(display)?[self as usize]).filter(|name| !name.is_empty())
+    }
+
+    /// Looks up a language by its name in a display language, ignoring case and surrounding
+    /// whitespace. This is the reverse of [`name_in`](Self::name_in).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(
+    ///     LanguageCode::from_name_in(LanguageCode::Fr, "allemand"),
+    ///     Some(LanguageCode::De)
+    /// );
+    /// assert_eq!(
+    ///     LanguageCode::from_name_in(LanguageCode::Es, "Alemán"),
+    ///     Some(LanguageCode::De)
+    /// );
+    /// assert_eq!(
+    ///     LanguageCode::from_name_in(LanguageCode::Ja, "ドイツ語"),
+    ///     Some(LanguageCode::De)
+    /// );
+    /// assert_eq!(LanguageCode::from_name_in(LanguageCode::Fr, "German"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name_in(display: LanguageCode, name: &str) -> Option<Self> {
+        let name = name.trim();
+        let lowercase = |s: &'static str| s.chars().flat_map(char::to_lowercase);
+        let names = data::names(display)?;
+        let i = names.iter().position(|&candidate| {
+            !candidate.is_empty()
+                && lowercase(candidate).eq(name.chars().flat_map(char::to_lowercase))
+        })?;
+        Some(crate::LANGUAGE_CODES[i])
+    }
+
+    /// Returns a value that displays the language's name in the language of the system's locale,
+    /// or its English [`name`](Self::name) if the locale can't be detected or has no name for it.
+    ///
+    /// Requires the `system-locale` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// // Prints "German" on an English system and "allemand" on a French one.
+    /// println!("{}", LanguageCode::De.display_localized());
+    /// ```
+    #[cfg(feature = "system-locale")]
+    #[must_use]
+    pub fn display_localized(self) -> DisplayLocalized {
+        DisplayLocalized {
+            language: self,
+            display: system_language(),
+        }
+    }
+}
+
+/// Detects the language of the system's locale.
+#[cfg(feature = "system-locale")]
+fn system_language() -> Option<LanguageCode> {
+    let locale = sys_locale::get_locale()?;
+    let language = locale.split(['-', '_', '.', '@']).next()?;
+    language.to_ascii_lowercase().parse().ok()
+}
+
+/// Displays a language's name in another language, created by
+/// [`LanguageCode::display_localized`].
+#[cfg(feature = "system-locale")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayLocalized {
+    language: LanguageCode,
+    display: Option<LanguageCode>,
+}
+
+#[cfg(feature = "system-locale")]
+impl DisplayLocalized {
+    /// Returns the language the name is displayed in, or `None` if the English name is used
+    /// because the system's language couldn't be detected.
+    #[must_use]
+    pub const fn display_language(&self) -> Option<LanguageCode> {
+        self.display
+    }
+}
+
+#[cfg(feature = "system-locale")]
+impl Display for DisplayLocalized {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let name = self
+            .display
+            .and_then(|display| self.language.name_in(display))
+            .unwrap_or_else(|| self.language.name());
+        f.pad(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LanguageCode;
+
+    #[test]
+    fn names() {
+        assert_eq!(LanguageCode::Ja.name_in(LanguageCode::Ja), Some("日本語"));
+        assert_eq!(LanguageCode::Ja.name_in(LanguageCode::Es), Some("japonés"));
+        assert_eq!(LanguageCode::Ja.name_in(LanguageCode::Ab), None);
+        for language in LanguageCode::iter() {
+            assert_ne!(language.name_in(LanguageCode::En), Some(""));
+        }
+    }
+
+    #[test]
+    fn from_name_in() {
+        for display in [LanguageCode::En, LanguageCode::Ru, LanguageCode::Zh] {
+            for language in LanguageCode::iter() {
+                if let Some(name) = language.name_in(display) {
+                    assert_eq!(LanguageCode::from_name_in(display, name), Some(language));
+                }
+            }
+        }
+        assert_eq!(
+            LanguageCode::from_name_in(LanguageCode::Ru, " НЕМЕЦКИЙ "),
+            Some(LanguageCode::De)
+        );
+        assert_eq!(LanguageCode::from_name_in(LanguageCode::Ab, "German"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "system-locale")]
+    fn display() {
+        let display = |display| {
+            super::DisplayLocalized {
+                language: LanguageCode::Fi,
+                display,
+            }
+            .to_string()
+        };
+        assert_eq!(display(Some(LanguageCode::De)), "Finnisch");
+        assert_eq!(display(Some(LanguageCode::Ab)), "Finnish");
+        assert_eq!(display(None), "Finnish");
+    }
+}
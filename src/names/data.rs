@@ -0,0 +1,20128 @@
+// Generated from the CLDR 45 language display names in icu_experimental_data 0.1.1 by
+// tools/gen_names.py, which describes how to regenerate it. Do not edit by hand.
+
+use crate::LanguageCode;
+
+/// Returns the names of every language in the display language, in the order of
+/// `LANGUAGE_CODES`. Missing names are empty.
+pub(super) const fn names(display: LanguageCode) -> Option<&'static [&'static str; 184]> {
+    Some(match display {
+        LanguageCode::Af => &AF,
+        LanguageCode::Ak => &AK,
+        LanguageCode::Sq => &SQ,
+        LanguageCode::Am => &AM,
+        LanguageCode::Ar => &AR,
+        LanguageCode::Hy => &HY,
+        LanguageCode::As => &AS,
+        LanguageCode::Az => &AZ,
+        LanguageCode::Eu => &EU,
+        LanguageCode::Be => &BE,
+        LanguageCode::Bn => &BN,
+        LanguageCode::Bs => &BS,
+        LanguageCode::Br => &BR,
+        LanguageCode::Bg => &BG,
+        LanguageCode::My => &MY,
+        LanguageCode::Ca => &CA,
+        LanguageCode::Zh => &ZH,
+        LanguageCode::Hr => &HR,
+        LanguageCode::Cs => &CS,
+        LanguageCode::Da => &DA,
+        LanguageCode::Nl => &NL,
+        LanguageCode::En => &EN,
+        LanguageCode::Eo => &EO,
+        LanguageCode::Et => &ET,
+        LanguageCode::Ee => &EE,
+        LanguageCode::Fo => &FO,
+        LanguageCode::Fi => &FI,
+        LanguageCode::Fr => &FR,
+        LanguageCode::Gl => &GL,
+        LanguageCode::Ka => &KA,
+        LanguageCode::De => &DE,
+        LanguageCode::El => &EL,
+        LanguageCode::Gu => &GU,
+        LanguageCode::Ha => &HA,
+        LanguageCode::He => &HE,
+        LanguageCode::Hi => &HI,
+        LanguageCode::Hu => &HU,
+        LanguageCode::Ia => &IA,
+        LanguageCode::Id => &ID,
+        LanguageCode::Ga => &GA,
+        LanguageCode::Ig => &IG,
+        LanguageCode::Is => &IS,
+        LanguageCode::It => &IT,
+        LanguageCode::Ja => &JA,
+        LanguageCode::Jv => &JV,
+        LanguageCode::Kn => &KN,
+        LanguageCode::Ks => &KS,
+        LanguageCode::Kk => &KK,
+        LanguageCode::Km => &KM,
+        LanguageCode::Rw => &RW,
+        LanguageCode::Ky => &KY,
+        LanguageCode::Ko => &KO,
+        LanguageCode::Ku => &KU,
+        LanguageCode::Lb => &LB,
+        LanguageCode::Lo => &LO,
+        LanguageCode::Lt => &LT,
+        LanguageCode::Lv => &LV,
+        LanguageCode::Mk => &MK,
+        LanguageCode::Ms => &MS,
+        LanguageCode::Ml => &ML,
+        LanguageCode::Mt => &MT,
+        LanguageCode::Mi => &MI,
+        LanguageCode::Mr => &MR,
+        LanguageCode::Mn => &MN,
+        LanguageCode::Ne => &NE,
+        LanguageCode::Nn => &NN,
+        LanguageCode::No => &NO,
+        LanguageCode::Or => &OR,
+        LanguageCode::Pa => &PA,
+        LanguageCode::Fa => &FA,
+        LanguageCode::Pl => &PL,
+        LanguageCode::Ps => &PS,
+        LanguageCode::Pt => &PT,
+        LanguageCode::Qu => &QU,
+        LanguageCode::Rm => &RM,
+        LanguageCode::Ro => &RO,
+        LanguageCode::Ru => &RU,
+        LanguageCode::Sc => &SC,
+        LanguageCode::Sd => &SD,
+        LanguageCode::Sr => &SR,
+        LanguageCode::Gd => &GD,
+        LanguageCode::Si => &SI,
+        LanguageCode::Sk => &SK,
+        LanguageCode::Sl => &SL,
+        LanguageCode::So => &SO,
+        LanguageCode::Es => &ES,
+        LanguageCode::Sw => &SW,
+        LanguageCode::Sv => &SV,
+        LanguageCode::Ta => &TA,
+        LanguageCode::Te => &TE,
+        LanguageCode::Tg => &TG,
+        LanguageCode::Th => &TH,
+        LanguageCode::Ti => &TI,
+        LanguageCode::Tk => &TK,
+        LanguageCode::To => &TO,
+        LanguageCode::Tr => &TR,
+        LanguageCode::Tt => &TT,
+        LanguageCode::Ug => &UG,
+        LanguageCode::Uk => &UK,
+        LanguageCode::Ur => &UR,
+        LanguageCode::Uz => &UZ,
+        LanguageCode::Vi => &VI,
+        LanguageCode::Cy => &CY,
+        LanguageCode::Wo => &WO,
+        LanguageCode::Fy => &FY,
+        LanguageCode::Yo => &YO,
+        LanguageCode::Zu => &ZU,
+        _ => return None,
+    })
+}
+
+static AF: [&str; 184] = [
+    "Abkasies",
+    "Afar",
+    "Afrikaans",
+    "Akan",
+    "Albanees",
+    "Amharies",
+    "Arabies",
+    "Aragonees",
+    "Armeens",
+    "Assamees",
+    "Avaries",
+    "",
+    "Aymara",
+    "Azerbeidjans",
+    "Bambara",
+    "Baskir",
+    "Baskies",
+    "Belarussies",
+    "Bengaals",
+    "",
+    "Bislama",
+    "Bosnies",
+    "Bretons",
+    "Bulgaars",
+    "Birmaans",
+    "Katalaans",
+    "Chamorro",
+    "Tsjetsjeens",
+    "Nyanja",
+    "Chinees",
+    "Chuvash",
+    "Kornies",
+    "Korsikaans",
+    "",
+    "Kroaties",
+    "Tsjeggies",
+    "Deens",
+    "Divehi",
+    "Nederlands",
+    "Dzongkha",
+    "Engels",
+    "Esperanto",
+    "Estnies",
+    "Ewe",
+    "Faroëes",
+    "Fidjiaans",
+    "Fins",
+    "Frans",
+    "Fulah",
+    "Galisies",
+    "Georgies",
+    "Duits",
+    "Grieks",
+    "Guarani",
+    "Goedjarati",
+    "Haïtiaans",
+    "Hausa",
+    "Hebreeus",
+    "Herero",
+    "Hindi",
+    "",
+    "Hongaars",
+    "Interlingua",
+    "Indonesies",
+    "Interlingue",
+    "Iers",
+    "Igbo",
+    "",
+    "Ido",
+    "Yslands",
+    "Italiaans",
+    "Inoektitoet",
+    "Japannees",
+    "Javaans",
+    "Kalaallisut",
+    "Kannada",
+    "Kanuri",
+    "Kasjmiri",
+    "Kazaks",
+    "Khmer",
+    "Kikuyu",
+    "Rwandees",
+    "Kirgisies",
+    "Komi",
+    "Kongolees",
+    "Koreaans",
+    "Koerdies",
+    "Kuanyama",
+    "Latyn",
+    "Luxemburgs",
+    "Ganda",
+    "Limburgs",
+    "Lingaals",
+    "Lao",
+    "Litaus",
+    "Luba-Katanga",
+    "Letties",
+    "Manx",
+    "Masedonies",
+    "Malgassies",
+    "Maleis",
+    "Malabaars",
+    "Maltees",
+    "Maori",
+    "Marathi",
+    "Marshallees",
+    "Mongools",
+    "Nauru",
+    "Navajo",
+    "Noord-Ndebele",
+    "Nepalees",
+    "Ndonga",
+    "Boeknoors",
+    "Nuwe Noors",
+    "Noors",
+    "Sichuan Yi",
+    "Suid-Ndebele",
+    "Oksitaans",
+    "",
+    "Kerkslawies",
+    "Oromo",
+    "Oriya",
+    "Osseties",
+    "Pandjabi",
+    "",
+    "Persies",
+    "Pools",
+    "Pasjtoe",
+    "Portugees",
+    "Quechua",
+    "Reto-Romaans",
+    "Rundi",
+    "Roemeens",
+    "Russies",
+    "Sanskrit",
+    "Sardinies",
+    "Sindhi",
+    "Noord-Sami",
+    "Samoaans",
+    "Sango",
+    "Serwies",
+    "Skotse Gallies",
+    "Shona",
+    "Sinhala",
+    "Slowaaks",
+    "Sloweens",
+    "Somalies",
+    "Suid-Sotho",
+    "Spaans",
+    "Sundanees",
+    "Swahili",
+    "Swazi",
+    "Sweeds",
+    "Tamil",
+    "Teloegoe",
+    "Tadjiks",
+    "Thai",
+    "Tigrinja",
+    "Tibettaans",
+    "Turkmeens",
+    "",
+    "Tswana",
+    "Tongaans",
+    "Turks",
+    "Tsonga",
+    "Tataars",
+    "Twi",
+    "Tahities",
+    "Uighur",
+    "Oekraïens",
+    "Oerdoe",
+    "Oesbekies",
+    "Venda",
+    "Viëtnamees",
+    "Volapük",
+    "Walloon",
+    "Wallies",
+    "Wolof",
+    "Fries",
+    "Xhosa",
+    "Jiddisj",
+    "Joroeba",
+    "Zhuang",
+    "Zoeloe",
+];
+
+static AK: [&str; 184] = [
+    "",
+    "",
+    "Afrikaans",
+    "Akan",
+    "Aabeniani",
+    "Amarik",
+    "Arabeke",
+    "",
+    "Aameniani",
+    "Asamese",
+    "",
+    "",
+    "",
+    "Asabegyanni",
+    "",
+    "",
+    "Baske",
+    "Belarus kasa",
+    "Bengali kasa",
+    "",
+    "",
+    "Bosniani",
+    "Britenni",
+    "Bɔlgeria kasa",
+    "Bɛɛmis kasa",
+    "Katalan",
+    "",
+    "",
+    "",
+    "Kyaena kasa",
+    "Kyuvahyi",
+    "",
+    "",
+    "",
+    "Kurowehyia kasa",
+    "Kyɛk kasa",
+    "Dane kasa",
+    "",
+    "Dɛɛkye",
+    "",
+    "Borɔfo",
+    "Esperanto",
+    "Estonia kasa",
+    "",
+    "Farosi",
+    "",
+    "Finlande kasa",
+    "Frɛnkye",
+    "Fula kasa",
+    "Galisia kasa",
+    "Gyɔɔgyia kasa",
+    "Gyaaman",
+    "Greek kasa",
+    "",
+    "Gugyarata",
+    "",
+    "Hausa",
+    "Hibri kasa",
+    "",
+    "Hindi",
+    "",
+    "Hangri kasa",
+    "Kasa ntam",
+    "Indonihyia kasa",
+    "Kasa afrafra",
+    "Aerelande kasa",
+    "Igbo kasa",
+    "",
+    "",
+    "Aeslande kasa",
+    "Italy kasa",
+    "",
+    "Gyapan kasa",
+    "Gyabanis kasa",
+    "",
+    "Kanada",
+    "",
+    "Kahyimiɛ",
+    "kasaki kasa",
+    "Kambodia kasa",
+    "",
+    "Rewanda kasa",
+    "Kɛgyese kasa",
+    "",
+    "",
+    "Korea kasa",
+    "Kɛɛde kasa",
+    "",
+    "",
+    "Lɔsimbɔge kasa",
+    "",
+    "",
+    "",
+    "Lawo kasa",
+    "Lituania kasa",
+    "",
+    "Latvia kasa",
+    "",
+    "Mɛsidonia kasa",
+    "",
+    "Malay kasa",
+    "Malayalam kasa",
+    "Malta kasa",
+    "Mawori",
+    "Marati",
+    "",
+    "Mongoliafoɔ kasa",
+    "",
+    "",
+    "",
+    "Nɛpal kasa",
+    "",
+    "",
+    "Nɔwefoɔ Ninɔso",
+    "Nɔwefoɔ kasa",
+    "",
+    "",
+    "Osita kasa",
+    "",
+    "",
+    "",
+    "Odia",
+    "",
+    "Pungyabi kasa",
+    "",
+    "Pɛɛhyia kasa",
+    "Pɔland kasa",
+    "Pahyito",
+    "Pɔɔtugal kasa",
+    "Kwɛkya",
+    "Romanhye kasa",
+    "",
+    "Romenia kasa",
+    "Rahyia kasa",
+    "Sanskrit kasa",
+    "Saadinia kasa",
+    "Sindi",
+    "",
+    "",
+    "",
+    "Sɛbia Kasa",
+    "Skotlandfoɔ Galek Kasa",
+    "",
+    "Sinhala",
+    "Slovak Kasa",
+    "Slovɛniafoɔ Kasa",
+    "Somalia kasa",
+    "",
+    "Spain kasa",
+    "Sunda Kasa",
+    "Swahili",
+    "",
+    "Sweden kasa",
+    "Tamil kasa",
+    "Telugu",
+    "Tɛgyeke kasa",
+    "Taeland kasa",
+    "Tigrinya kasa",
+    "",
+    "Tɛkmɛnistan Kasa",
+    "",
+    "",
+    "Tonga kasa",
+    "Tɛɛki kasa",
+    "",
+    "Tata kasa",
+    "",
+    "",
+    "Yugaa Kasa",
+    "Ukren kasa",
+    "Urdu kasa",
+    "Usbɛkistan Kasa",
+    "",
+    "Viɛtnam kasa",
+    "",
+    "",
+    "Wɛɛhye Kasa",
+    "Wolɔfo Kasa",
+    "Atɔeɛ Fam Frihyia Kasa",
+    "Hosa Kasa",
+    "",
+    "Yoruba",
+    "Zuang",
+    "Zulu",
+];
+
+static SQ: [&str; 184] = [
+    "abkazisht",
+    "afarisht",
+    "afrikanisht",
+    "akanisht",
+    "shqip",
+    "amarisht",
+    "arabisht",
+    "aragonezisht",
+    "armenisht",
+    "asamezisht",
+    "avarikisht",
+    "",
+    "ajmarisht",
+    "azerbajxhanisht",
+    "bambarisht",
+    "bashkirisht",
+    "baskisht",
+    "bjellorusisht",
+    "bengalisht",
+    "",
+    "bislamisht",
+    "boshnjakisht",
+    "bretonisht",
+    "bullgarisht",
+    "birmanisht",
+    "katalonisht",
+    "kamoroisht",
+    "çeçenisht",
+    "nianjisht",
+    "kinezisht",
+    "çuvashisht",
+    "kornisht",
+    "korsikisht",
+    "",
+    "kroatisht",
+    "çekisht",
+    "danisht",
+    "divehisht",
+    "holandisht",
+    "xhongaisht",
+    "anglisht",
+    "esperanto",
+    "estonisht",
+    "eveisht",
+    "faroisht",
+    "fixhianisht",
+    "finlandisht",
+    "frëngjisht",
+    "fulaisht",
+    "galicisht",
+    "gjeorgjisht",
+    "gjermanisht",
+    "greqisht",
+    "guaranisht",
+    "guxharatisht",
+    "kreolishte e Haitit",
+    "hausisht",
+    "hebraisht",
+    "hereroisht",
+    "indisht",
+    "",
+    "hungarisht",
+    "interlingua",
+    "indonezisht",
+    "gjuha oksidentale",
+    "irlandisht",
+    "igboisht",
+    "",
+    "idoisht",
+    "islandisht",
+    "italisht",
+    "inuktitutisht",
+    "japonisht",
+    "javanisht",
+    "kalalisutisht",
+    "kanadisht",
+    "kanurisht",
+    "kashmirisht",
+    "kazakisht",
+    "kmerisht",
+    "kikujuisht",
+    "kiniaruandisht",
+    "kirgizisht",
+    "komisht",
+    "",
+    "koreanisht",
+    "kurdisht",
+    "kuanjamaisht",
+    "latinisht",
+    "luksemburgisht",
+    "gandaisht",
+    "limburgisht",
+    "lingalisht",
+    "laosisht",
+    "lituanisht",
+    "luba-katangaisht",
+    "letonisht",
+    "manksisht",
+    "maqedonisht",
+    "madagaskarisht",
+    "malajisht",
+    "malajalamisht",
+    "maltisht",
+    "maorisht",
+    "maratisht",
+    "marshallisht",
+    "mongolisht",
+    "nauruisht",
+    "navahoisht",
+    "ndebelishte veriore",
+    "nepalisht",
+    "ndongaisht",
+    "norvegjishte letrare",
+    "norvegjishte nynorsk",
+    "norvegjisht",
+    "sishuanisht",
+    "ndebelishte jugore",
+    "oksitanisht",
+    "",
+    "sllavishte kishtare",
+    "oromoisht",
+    "odisht",
+    "osetisht",
+    "punxhabisht",
+    "",
+    "persisht",
+    "polonisht",
+    "pashtoisht",
+    "portugalisht",
+    "keçuaisht",
+    "retoromanisht",
+    "rundisht",
+    "rumanisht",
+    "rusisht",
+    "sanskritisht",
+    "sardenjisht",
+    "sindisht",
+    "samishte veriore",
+    "samoanisht",
+    "sangoisht",
+    "serbisht",
+    "galishte skoceze",
+    "shonisht",
+    "sinhalisht",
+    "sllovakisht",
+    "sllovenisht",
+    "somalisht",
+    "sotoishte jugore",
+    "spanjisht",
+    "sundanisht",
+    "suahilisht",
+    "suatisht",
+    "suedisht",
+    "tamilisht",
+    "teluguisht",
+    "taxhikisht",
+    "tajlandisht",
+    "tigrinjaisht",
+    "tibetisht",
+    "turkmenisht",
+    "",
+    "cuanaisht",
+    "tonganisht",
+    "turqisht",
+    "congaisht",
+    "tatarisht",
+    "tuisht",
+    "tahitisht",
+    "ujgurisht",
+    "ukrainisht",
+    "urduisht",
+    "uzbekisht",
+    "vendaisht",
+    "vietnamisht",
+    "volapykisht",
+    "ualunisht",
+    "uellsisht",
+    "uolofisht",
+    "frizianishte perëndimore",
+    "xhosaisht",
+    "jidisht",
+    "jorubaisht",
+    "zhuangisht",
+    "zuluisht",
+];
+
+static AM: [&str; 184] = [
+    "አብሐዚኛ",
+    "አፋርኛ",
+    "አፍሪካንኛ",
+    "አካንኛ",
+    "አልባንያንኛ",
+    "አማርኛ",
+    "ዓረብኛ",
+    "አራጎንስ",
+    "አርሜንኛ",
+    "አሳሜዝ",
+    "አቫሪክ",
+    "አቬስታን",
+    "አያማርኛ",
+    "አዘርባጃንኛ",
+    "ባምባርኛ",
+    "ባስኪርኛ",
+    "ባስክኛ",
+    "ቤላራሻኛ",
+    "ቤንጋሊኛ",
+    "",
+    "ቢስላምኛ",
+    "ቦስኒያንኛ",
+    "ብሬቶንኛ",
+    "ቡልጋሪኛ",
+    "ቡርማኛ",
+    "ካታላንኛ",
+    "ቻሞሮ",
+    "ችችን",
+    "ንያንጃ",
+    "ቻይንኛ",
+    "ቹቫሽኛ",
+    "ኮርኒሽ",
+    "ኮርሲካኛ",
+    "ክሪ",
+    "ክሮሽያንኛ",
+    "ቼክኛ",
+    "ዴኒሽ",
+    "ዲቬሂ",
+    "ደች",
+    "ድዞንግኻኛ",
+    "እንግሊዝኛ",
+    "ኤስፐራንቶ",
+    "ኢስቶኒያንኛ",
+    "ኢዊ",
+    "ፋሮኛ",
+    "ፊጂኛ",
+    "ፊንላንድኛ",
+    "ፈረንሳይኛ",
+    "ፉላኒኛ",
+    "ጋሊሺያንኛ",
+    "ጆርጂያንኛ",
+    "ጀርመንኛ",
+    "ግሪክኛ",
+    "ጓራኒኛ",
+    "ጉጃርቲኛ",
+    "ሃይትኛ ክሮሌ",
+    "ሃውሳኛ",
+    "ዕብራይስጥ",
+    "ሄሬሮ",
+    "ሕንድኛ",
+    "",
+    "ሀንጋሪኛ",
+    "ኢንቴርሊንጓ",
+    "ኢንዶኔዥያኛ",
+    "እንተርሊንግወ",
+    "አየርላንድኛ",
+    "ኢግቦኛ",
+    "እኑፒያቅኛ",
+    "ኢዶ",
+    "አይስላንድኛ",
+    "ጣሊያንኛ",
+    "እኑክቲቱትኛ",
+    "ጃፓንኛ",
+    "ጃቫኛ",
+    "ካላሊሱት",
+    "ካናዳ",
+    "ካኑሪ",
+    "ካሽሚርኛ",
+    "ካዛክኛ",
+    "ክመር",
+    "ኪኩዩ",
+    "ኪንያርዋንዳ",
+    "ክይርግይዝ",
+    "ኮሚ",
+    "ኮንጎኛ",
+    "ኮሪያኛ",
+    "ኩርድሽ",
+    "ኩዋንያማ",
+    "ላቲንኛ",
+    "ሉክሰምበርግኛ",
+    "ጋንዳኛ",
+    "ሊምቡርጊሽ",
+    "ሊንጋላ",
+    "ላኦኛ",
+    "ሊቱዌንያኛ",
+    "ሉባ-ካታንጋ",
+    "ላትቪያኛ",
+    "ማንክስ",
+    "ሜቄዶንኛ",
+    "ማላጋስይ",
+    "ማላይ",
+    "ማላያላም",
+    "ማልቲዝኛ",
+    "ማኦሪ",
+    "ማራቲ",
+    "ማርሻሊዝ",
+    "ሞንጎሊያኛ",
+    "ናኡሩ",
+    "ናቫጆ",
+    "ሰሜን ንዴብሌ",
+    "ኔፓሊኛ",
+    "ንዶንጋ",
+    "የኖርዌይ ቦክማል",
+    "የኖርዌይ ናይኖርስክ",
+    "ኖርዌይኛ",
+    "ሲቹዋን ዪ",
+    "ደቡብ ንደቤሌ",
+    "ኦሲታን",
+    "",
+    "ቸርች ስላቪክ",
+    "ኦሮሚኛ",
+    "ኦዲያ",
+    "ኦሴቲክ",
+    "ፑንጃብኛ",
+    "",
+    "ፐርሺያኛ",
+    "ፖሊሽ",
+    "ፓሽቶ",
+    "ፖርቹጋልኛ",
+    "ኩዌቹዋ",
+    "ሮማንሽ",
+    "ሩንዲ",
+    "ሮማኒያኛ",
+    "ራሽያኛ",
+    "ሳንስክሪት",
+    "ሳርዲንያን",
+    "ሲንዲ",
+    "ሰሜናዊ ሳሚ",
+    "ሳሞኣን",
+    "ሳንጎ",
+    "ሰርብያኛ",
+    "የስኮትላንድ ጌይሊክ",
+    "ሾና",
+    "ሲንሃላ",
+    "ስሎቫክኛ",
+    "ስሎቬንኛ",
+    "ሱማልኛ",
+    "ደቡባዊ ሶቶ",
+    "ስፓኒሽ",
+    "ሱዳንኛ",
+    "ስዋሂሊኛ",
+    "ስዋቲ",
+    "ስዊድንኛ",
+    "ታሚል",
+    "ተሉጉ",
+    "ታጂክ",
+    "ታይ",
+    "ትግርኛ",
+    "ቲቤታንኛ",
+    "ቱርክሜን",
+    "ታጋሎገኛ",
+    "ጽዋና",
+    "ቶንጋን",
+    "ቱርክኛ",
+    "ጾንጋ",
+    "ታታር",
+    "ትዊኛ",
+    "ታሂታንኛ",
+    "ኡይግሁር",
+    "ዩክሬንኛ",
+    "ኡርዱኛ",
+    "ኡዝቤክኛ",
+    "ቬንዳ",
+    "ቪየትናምኛ",
+    "ቮላፑክኛ",
+    "ዋሎን",
+    "ዌልሽ",
+    "ዎሎፍኛ",
+    "ምዕራባዊ ፍሪሲኛ",
+    "ዞሳኛ",
+    "ይዲሽኛ",
+    "ዮሩባዊኛ",
+    "ዡዋንግኛ",
+    "ዙሉኛ",
+];
+
+static AR: [&str; 184] = [
+    "الأبخازية",
+    "الأفارية",
+    "الأفريقانية",
+    "الأكانية",
+    "الألبانية",
+    "الأمهرية",
+    "العربية",
+    "الأراغونية",
+    "الأرمنية",
+    "الأسامية",
+    "الأوارية",
+    "الأفستية",
+    "الأيمارا",
+    "الأذربيجانية",
+    "البامبارا",
+    "الباشكيرية",
+    "الباسكية",
+    "البيلاروسية",
+    "البنغالية",
+    "",
+    "البيسلامية",
+    "البوسنية",
+    "البريتونية",
+    "البلغارية",
+    "البورمية",
+    "الكتالانية",
+    "التشامورو",
+    "الشيشانية",
+    "النيانجا",
+    "الصينية",
+    "التشوفاشي",
+    "الكورنية",
+    "الكورسيكية",
+    "الكرى",
+    "الكرواتية",
+    "التشيكية",
+    "الدانمركية",
+    "المالديفية",
+    "الهولندية",
+    "دزونكا",
+    "الإنجليزية",
+    "الإسبرانتو",
+    "الإستونية",
+    "الإيوي",
+    "الفاروية",
+    "الفيجية",
+    "الفنلندية",
+    "الفرنسية",
+    "الفولانية",
+    "الجاليكية",
+    "الجورجية",
+    "الألمانية",
+    "اليونانية",
+    "الغوارانية",
+    "الغوجاراتية",
+    "الكريولية الهايتية",
+    "الهوسا",
+    "العبرية",
+    "الهيريرو",
+    "الهندية",
+    "الهيري موتو",
+    "الهنغارية",
+    "اللّغة الوسيطة",
+    "الإندونيسية",
+    "الإنترلينج",
+    "الأيرلندية",
+    "الإيجبو",
+    "الإينبياك",
+    "الإيدو",
+    "الأيسلندية",
+    "الإيطالية",
+    "الإينكتيتت",
+    "اليابانية",
+    "الجاوية",
+    "الكالاليست",
+    "الكانادا",
+    "الكانوري",
+    "الكشميرية",
+    "الكازاخستانية",
+    "الخميرية",
+    "الكيكيو",
+    "الكينيارواندا",
+    "القيرغيزية",
+    "الكومي",
+    "الكونغو",
+    "الكورية",
+    "الكردية",
+    "كوانياما",
+    "اللاتينية",
+    "اللكسمبورغية",
+    "الغاندا",
+    "الليمبورغية",
+    "اللينجالا",
+    "اللاوية",
+    "الليتوانية",
+    "اللوبا كاتانغا",
+    "اللاتفية",
+    "المنكية",
+    "المقدونية",
+    "الملغاشي",
+    "الماليزية",
+    "المالايالامية",
+    "المالطية",
+    "الماورية",
+    "الماراثية",
+    "المارشالية",
+    "المنغولية",
+    "النورو",
+    "النافاجو",
+    "النديبيل الشمالية",
+    "النيبالية",
+    "الندونجا",
+    "النرويجية بوكمال",
+    "النرويجية نينورسك",
+    "النرويجية",
+    "السيتشيون يي",
+    "النديبيل الجنوبي",
+    "الأوكسيتانية",
+    "الأوجيبوا",
+    "سلافية كنسية",
+    "الأورومية",
+    "الأورية",
+    "الأوسيتيك",
+    "البنجابية",
+    "البالية",
+    "الفارسية",
+    "البولندية",
+    "البشتو",
+    "البرتغالية",
+    "كيشوا",
+    "الرومانشية",
+    "الرندي",
+    "الرومانية",
+    "الروسية",
+    "السنسكريتية",
+    "السردينية",
+    "السندية",
+    "سامي الشمالية",
+    "الساموائية",
+    "السانجو",
+    "الصربية",
+    "الغيلية الأسكتلندية",
+    "الشونا",
+    "السنهالية",
+    "السلوفاكية",
+    "السلوفانية",
+    "الصومالية",
+    "السوتو الجنوبية",
+    "الإسبانية",
+    "السوندانية",
+    "السواحلية",
+    "السواتي",
+    "السويدية",
+    "التاميلية",
+    "التيلوغوية",
+    "الطاجيكية",
+    "التايلاندية",
+    "التغرينية",
+    "التبتية",
+    "التركمانية",
+    "التاغالوغية",
+    "التسوانية",
+    "التونغية",
+    "التركية",
+    "السونجا",
+    "التترية",
+    "التوي",
+    "التاهيتية",
+    "الأويغورية",
+    "الأوكرانية",
+    "الأوردية",
+    "الأوزبكية",
+    "الفيندا",
+    "الفيتنامية",
+    "لغة الفولابوك",
+    "الولونية",
+    "الويلزية",
+    "الولوفية",
+    "الفريزيان",
+    "الخوسا",
+    "اليديشية",
+    "اليوروبا",
+    "الزهيونج",
+    "الزولو",
+];
+
+static HY: [&str; 184] = [
+    "աբխազերեն",
+    "աֆարերեն",
+    "աֆրիկաանս",
+    "աքան",
+    "ալբաներեն",
+    "ամհարերեն",
+    "արաբերեն",
+    "արագոներեն",
+    "հայերեն",
+    "ասամերեն",
+    "ավարերեն",
+    "",
+    "այմարա",
+    "ադրբեջաներեն",
+    "բամբարա",
+    "բաշկիրերեն",
+    "բասկերեն",
+    "բելառուսերեն",
+    "բենգալերեն",
+    "",
+    "բիսլամա",
+    "բոսնիերեն",
+    "բրետոներեն",
+    "բուլղարերեն",
+    "բիրմայերեն",
+    "կատալաներեն",
+    "չամոռո",
+    "չեչեներեն",
+    "նյանջա",
+    "չինարեն",
+    "չուվաշերեն",
+    "կոռներեն",
+    "կորսիկերեն",
+    "",
+    "խորվաթերեն",
+    "չեխերեն",
+    "դանիերեն",
+    "դիվեհի",
+    "հոլանդերեն",
+    "ջոնգքհա",
+    "անգլերեն",
+    "էսպերանտո",
+    "էստոներեն",
+    "էվե",
+    "ֆարյորերեն",
+    "ֆիջիերեն",
+    "ֆիններեն",
+    "ֆրանսերեն",
+    "ֆուլահ",
+    "գալիսերեն",
+    "վրացերեն",
+    "գերմաներեն",
+    "հունարեն",
+    "գուարանի",
+    "գուջարաթի",
+    "խառնակերտ հայիթերեն",
+    "հաուսա",
+    "եբրայերեն",
+    "հերերո",
+    "հինդի",
+    "",
+    "հունգարերեն",
+    "ինտերլինգուա",
+    "ինդոնեզերեն",
+    "ինտերլինգուե",
+    "իռլանդերեն",
+    "իգբո",
+    "",
+    "իդո",
+    "իսլանդերեն",
+    "իտալերեն",
+    "ինուկտիտուտ",
+    "ճապոներեն",
+    "ճավայերեն",
+    "կալաալիսուտ",
+    "կաննադա",
+    "կանուրի",
+    "քաշմիրերեն",
+    "ղազախերեն",
+    "քմերերեն",
+    "կիկույու",
+    "կինյառուանդա",
+    "ղրղզերեն",
+    "կոմիերեն",
+    "",
+    "կորեերեն",
+    "քրդերեն",
+    "կուանյամա",
+    "լատիներեն",
+    "լյուքսեմբուրգերեն",
+    "գանդա",
+    "լիմբուրգերեն",
+    "լինգալա",
+    "լաոսերեն",
+    "լիտվերեն",
+    "լուբա-կատանգա",
+    "լատվիերեն",
+    "մեներեն",
+    "մակեդոներեն",
+    "մալգաշերեն",
+    "մալայերեն",
+    "մալայալամ",
+    "մալթայերեն",
+    "մաորի",
+    "մարաթի",
+    "մարշալերեն",
+    "մոնղոլերեն",
+    "նաուրու",
+    "նավախո",
+    "հյուսիսային նդեբելե",
+    "նեպալերեն",
+    "նդոնգա",
+    "գրքային նորվեգերեն",
+    "նոր նորվեգերեն",
+    "նորվեգերեն",
+    "սիչուան",
+    "հարավային նդեբելե",
+    "օքսիտաներեն",
+    "օջիբվա",
+    "սլավոներեն, եկեղեցական",
+    "օրոմո",
+    "օրիյա",
+    "օսերեն",
+    "փենջաբերեն",
+    "պալի",
+    "պարսկերեն",
+    "լեհերեն",
+    "փուշթու",
+    "պորտուգալերեն",
+    "կեչուա",
+    "ռոմանշերեն",
+    "ռունդի",
+    "ռումիներեն",
+    "ռուսերեն",
+    "սանսկրիտ",
+    "սարդիներեն",
+    "սինդհի",
+    "հյուսիսային սաամի",
+    "սամոաերեն",
+    "սանգո",
+    "սերբերեն",
+    "շոտլանդական գաելերեն",
+    "շոնա",
+    "սինհալերեն",
+    "սլովակերեն",
+    "սլովեներեն",
+    "սոմալիերեն",
+    "հարավային սոթո",
+    "իսպաներեն",
+    "սունդաներեն",
+    "սուահիլի",
+    "սվազերեն",
+    "շվեդերեն",
+    "թամիլերեն",
+    "թելուգու",
+    "տաջիկերեն",
+    "թայերեն",
+    "տիգրինյա",
+    "տիբեթերեն",
+    "թուրքմեներեն",
+    "տագալերեն",
+    "ցվանա",
+    "տոնգերեն",
+    "թուրքերեն",
+    "ցոնգա",
+    "թաթարերեն",
+    "տուի",
+    "թաիտերեն",
+    "ույղուրերեն",
+    "ուկրաիներեն",
+    "ուրդու",
+    "ուզբեկերեն",
+    "վենդա",
+    "վիետնամերեն",
+    "վոլապյուկ",
+    "վալոներեն",
+    "ուելսերեն",
+    "վոլոֆ",
+    "արևմտաֆրիզերեն",
+    "քոսա",
+    "իդիշ",
+    "յորուբա",
+    "ժուանգ",
+    "զուլուերեն",
+];
+
+static AS: [&str; 184] = [
+    "আবখাজিয়ান",
+    "আফাৰ",
+    "আফ্ৰিকানছ্",
+    "আকান",
+    "আলবেনীয়",
+    "আমহাৰিক",
+    "আৰবী",
+    "আৰ্গোনিজ",
+    "আৰ্মেনীয়",
+    "অসমীয়া",
+    "আভেৰিক",
+    "",
+    "আয়মাৰা",
+    "আজেৰবাইজানী",
+    "বামবাৰা",
+    "বাছখিৰ",
+    "বাস্ক",
+    "বেলাৰুছীয়",
+    "বাংলা",
+    "",
+    "বিছলামা",
+    "বছনীয়",
+    "ব্ৰেটন",
+    "বুলগেৰীয়",
+    "বাৰ্মীজ",
+    "কাতালান",
+    "চামোৰো",
+    "চেচেন",
+    "ন্যাঞ্জা",
+    "চীনা",
+    "চুভাচ",
+    "কোৰ্নিচ",
+    "কোৰ্ছিকান",
+    "",
+    "ক্ৰোৱেচিয়ান",
+    "চেক",
+    "ডেনিচ",
+    "দিবেহি",
+    "ডাচ",
+    "জোংখা",
+    "ইংৰাজী",
+    "এস্পেৰান্তো",
+    "এষ্টোনিয়",
+    "ইৱে",
+    "ফাৰোইজ",
+    "ফিজিয়ান",
+    "ফিনিচ",
+    "ফ্ৰেন্স",
+    "ফুলাহ",
+    "গেলিচিয়ান",
+    "জৰ্জিয়ান",
+    "জাৰ্মান",
+    "গ্ৰীক",
+    "গুৱাৰাণী",
+    "গুজৰাটী",
+    "হেইটিয়ান ক্ৰিয়ল",
+    "হাউছা",
+    "হিব্ৰু",
+    "হেৰেৰো",
+    "হিন্দী",
+    "",
+    "হাঙ্গেৰিয়ান",
+    "ইণ্টাৰলিংগুৱা",
+    "ইণ্ডোনেচিয়",
+    "ইণ্টাৰলিংগুৱে",
+    "আইৰিচ",
+    "ইগ্বো",
+    "",
+    "ইডো",
+    "আইচলেণ্ডিক",
+    "ইটালিয়ান",
+    "ইনুক্টিটুট",
+    "জাপানী",
+    "জাভানী",
+    "কালালিছুট",
+    "কানাড়া",
+    "কানুৰি",
+    "কাশ্মিৰী",
+    "কাজাখ",
+    "খমেৰ",
+    "কিকুয়ু",
+    "কিনয়াৰোৱাণ্ডা",
+    "কিৰ্গিজ",
+    "কোমি",
+    "",
+    "কোৰিয়ান",
+    "কুৰ্ডিচ",
+    "কুয়ানিয়ামা",
+    "লেটিন",
+    "লাক্সেমবাৰ্গিচ",
+    "গান্দা",
+    "লিম্বুৰ্গিচ",
+    "লিংগালা",
+    "লাও",
+    "লিথুৱানিয়ান",
+    "লুবা-কাটাংগা",
+    "লাটভিয়ান",
+    "মেংক্স",
+    "মেচিডোনীয়",
+    "মালাগাছী",
+    "মালয়",
+    "মালায়ালম",
+    "মাল্টিজ",
+    "মাওৰি",
+    "মাৰাঠী",
+    "মাৰ্চলিজ",
+    "মংগোলীয়",
+    "নাউৰু",
+    "নাভাজো",
+    "উত্তৰ নিবেবেলে",
+    "নেপালী",
+    "এন্দোঙ্গা",
+    "নৰৱেজিয়ান বোকমাল",
+    "নৰৱেজিয়ান নায়নোৰ্স্ক",
+    "নৰৱেজিয়ান",
+    "ছিচুৱান ই",
+    "দক্ষিণ দেবেল",
+    "অ’চিটান",
+    "",
+    "চাৰ্চ শ্লেভিক",
+    "ওৰোমো",
+    "ওড়িয়া",
+    "ওছেটিক",
+    "পাঞ্জাবী",
+    "",
+    "ফাৰ্ছী",
+    "প’লিচ",
+    "পুস্ত",
+    "পৰ্তুগীজ",
+    "কুৱেচুৱা",
+    "ৰোমানচ",
+    "ৰুন্দি",
+    "ৰোমানীয়",
+    "ৰাছিয়ান",
+    "সংস্কৃত",
+    "ছাৰ্ডিনিয়ান",
+    "সিন্ধী",
+    "উদীচ্য ছামি",
+    "ছামোন",
+    "ছাঙ্গো",
+    "ছাৰ্বিয়ান",
+    "স্কটিচ গেইলিক",
+    "চোনা",
+    "সিংহলা",
+    "শ্লোভাক",
+    "শ্লোভেনিয়ান",
+    "ছোমালি",
+    "দাক্ষিণাত্য ছোথো",
+    "স্পেনিচ",
+    "ছুণ্ডানীজ",
+    "স্বাহিলি",
+    "স্বাতি",
+    "ছুইডিচ",
+    "তামিল",
+    "তেলুগু",
+    "তাজিক",
+    "থাই",
+    "টিগৰিনিয়া",
+    "তিব্বতী",
+    "তুৰ্কমেন",
+    "",
+    "ছোৱানা",
+    "টোঙ্গান",
+    "তুৰ্কী",
+    "ছোঙ্গা",
+    "তাতাৰ",
+    "",
+    "তাহিতিয়ান",
+    "উইঘুৰ",
+    "ইউক্ৰেইনীয়",
+    "উৰ্দু",
+    "উজবেক",
+    "ভেণ্ডা",
+    "ভিয়েটনামী",
+    "ভোলাপুক",
+    "ৱালুন",
+    "ৱেলচ",
+    "ৱোলাফ",
+    "ৱেষ্টাৰ্ণ ফ্ৰিছিয়ান",
+    "হোছা",
+    "ইদ্দিছ",
+    "ইউৰুবা",
+    "ঝুৱাং",
+    "ঝুলু",
+];
+
+static AZ: [&str; 184] = [
+    "abxaz",
+    "afar",
+    "afrikaans",
+    "akan",
+    "alban",
+    "amhar",
+    "ərəb",
+    "araqon",
+    "erməni",
+    "assam",
+    "avar",
+    "avestan",
+    "aymara",
+    "azərbaycan",
+    "bambara",
+    "başqırd",
+    "bask",
+    "belarus",
+    "benqal",
+    "",
+    "bislama",
+    "bosniya",
+    "breton",
+    "bolqar",
+    "birman",
+    "katalan",
+    "çamoro",
+    "çeçen",
+    "nyanca",
+    "çin",
+    "çuvaş",
+    "korn",
+    "korsika",
+    "kri",
+    "xorvat",
+    "çex",
+    "danimarka",
+    "maldiv",
+    "holland",
+    "dzonqxa",
+    "ingilis",
+    "esperanto",
+    "eston",
+    "eve",
+    "farer",
+    "fici",
+    "fin",
+    "fransız",
+    "fula",
+    "qalisiya",
+    "gürcü",
+    "alman",
+    "yunan",
+    "quarani",
+    "qucarat",
+    "haiti kreol",
+    "hausa",
+    "ivrit",
+    "herero",
+    "hind",
+    "hiri motu",
+    "macar",
+    "interlinqua",
+    "indoneziya",
+    "interlinqve",
+    "irland",
+    "iqbo",
+    "inupiaq",
+    "ido",
+    "island",
+    "italyan",
+    "inuktitut",
+    "yapon",
+    "yava",
+    "kalaallisut",
+    "kannada",
+    "kanuri",
+    "kəşmir",
+    "qazax",
+    "kxmer",
+    "kikuyu",
+    "kinyarvanda",
+    "qırğız",
+    "komi",
+    "konqo",
+    "koreya",
+    "kürd",
+    "kuanyama",
+    "latın",
+    "lüksemburq",
+    "qanda",
+    "limburq",
+    "linqala",
+    "laos",
+    "litva",
+    "luba-katanqa",
+    "latış",
+    "manks",
+    "makedon",
+    "malaqas",
+    "malay",
+    "malayalam",
+    "malta",
+    "māori",
+    "marathi",
+    "marşal",
+    "monqol",
+    "nauru",
+    "navayo",
+    "şimali ndebele",
+    "nepal",
+    "ndonqa",
+    "bokmal norveç",
+    "nünorsk norveç",
+    "norveç",
+    "siçuan yi",
+    "cənubi ndebele",
+    "oksitan",
+    "ocibva",
+    "slavyan",
+    "oromo",
+    "odiya",
+    "osetin",
+    "pəncab",
+    "pali",
+    "fars",
+    "polyak",
+    "puştu",
+    "portuqal",
+    "keçua",
+    "romanş",
+    "rundi",
+    "rumın",
+    "rus",
+    "sanskrit",
+    "sardin",
+    "sindhi",
+    "şimali sami",
+    "samoa",
+    "sanqo",
+    "serb",
+    "Şotlandiya keltcəsi",
+    "şona",
+    "sinhala",
+    "slovak",
+    "sloven",
+    "somali",
+    "sesoto",
+    "ispan",
+    "sundan",
+    "suahili",
+    "svati",
+    "isveç",
+    "tamil",
+    "teluqu",
+    "tacik",
+    "tay",
+    "tiqrin",
+    "tibet",
+    "türkmən",
+    "taqaloq",
+    "svana",
+    "tonqa",
+    "türk",
+    "sonqa",
+    "tatar",
+    "tvi",
+    "taxiti",
+    "uyğur",
+    "ukrayna",
+    "urdu",
+    "özbək",
+    "venda",
+    "vyetnam",
+    "volapük",
+    "valun",
+    "uels",
+    "volof",
+    "qərbi friz",
+    "xosa",
+    "idiş",
+    "yoruba",
+    "çjuan",
+    "zulu",
+];
+
+static EU: [&str; 184] = [
+    "abkhaziera",
+    "afarera",
+    "afrikaansa",
+    "akanera",
+    "albaniera",
+    "amharera",
+    "arabiera",
+    "aragoiera",
+    "armeniera",
+    "assamera",
+    "avarera",
+    "",
+    "aimara",
+    "azerbaijanera",
+    "bambarera",
+    "baxkirera",
+    "euskara",
+    "bielorrusiera",
+    "bengalera",
+    "",
+    "bislama",
+    "bosniera",
+    "bretoiera",
+    "bulgariera",
+    "birmaniera",
+    "katalana",
+    "txamorroera",
+    "txetxenera",
+    "chewera",
+    "txinera",
+    "txuvaxera",
+    "kornubiera",
+    "korsikera",
+    "",
+    "kroaziera",
+    "txekiera",
+    "daniera",
+    "dhivehia",
+    "nederlandera",
+    "dzongkha",
+    "ingelesa",
+    "esperantoa",
+    "estoniera",
+    "eweera",
+    "faroera",
+    "fijiera",
+    "finlandiera",
+    "frantsesa",
+    "fula",
+    "galiziera",
+    "georgiera",
+    "alemana",
+    "greziera",
+    "guaraniera",
+    "gujaratera",
+    "Haitiko kreolera",
+    "hausa",
+    "hebreera",
+    "hereroera",
+    "hindia",
+    "",
+    "hungariera",
+    "interlingua",
+    "indonesiera",
+    "interlinguea",
+    "irlandera",
+    "igboera",
+    "",
+    "idoa",
+    "islandiera",
+    "italiera",
+    "inuitera",
+    "japoniera",
+    "javera",
+    "groenlandiera",
+    "kannada",
+    "kanuriera",
+    "kaxmirera",
+    "kazakhera",
+    "khemerera",
+    "kikuyuera",
+    "kinyaruanda",
+    "kirgizera",
+    "komiera",
+    "kikongoa",
+    "koreera",
+    "kurduera",
+    "kuanyama",
+    "latina",
+    "luxenburgera",
+    "luganda",
+    "limburgera",
+    "lingala",
+    "laosera",
+    "lituaniera",
+    "Katangako lubera",
+    "letoniera",
+    "manxera",
+    "mazedoniera",
+    "malgaxea",
+    "malaysiera",
+    "malabarera",
+    "maltera",
+    "maoriera",
+    "marathera",
+    "marshallera",
+    "mongoliera",
+    "nauruera",
+    "navajoera",
+    "iparraldeko ndebeleera",
+    "nepalera",
+    "ndonga",
+    "bokmål (norvegiera)",
+    "nynorsk (norvegiera)",
+    "norvegiera",
+    "Sichuango yiera",
+    "hegoaldeko ndebeleera",
+    "okzitaniera",
+    "",
+    "elizako eslaviera",
+    "oromoera",
+    "oriya",
+    "osetiera",
+    "punjabera",
+    "",
+    "persiera",
+    "poloniera",
+    "paxtunera",
+    "portugesa",
+    "kitxua",
+    "erretorromaniera",
+    "rundiera",
+    "errumaniera",
+    "errusiera",
+    "sanskritoa",
+    "sardiniera",
+    "sindhia",
+    "iparraldeko samiera",
+    "samoera",
+    "sangoa",
+    "serbiera",
+    "Eskoziako gaelikoa",
+    "shonera",
+    "sinhala",
+    "eslovakiera",
+    "esloveniera",
+    "somaliera",
+    "hegoaldeko sothoera",
+    "gaztelania",
+    "sundanera",
+    "swahilia",
+    "swatiera",
+    "suediera",
+    "tamilera",
+    "telugua",
+    "tajikera",
+    "thailandiera",
+    "tigrinyera",
+    "tibetera",
+    "turkmenera",
+    "tagaloa",
+    "tswanera",
+    "tongera",
+    "turkiera",
+    "tsongera",
+    "tatarera",
+    "twia",
+    "tahitiera",
+    "uigurrera",
+    "ukrainera",
+    "urdua",
+    "uzbekera",
+    "vendera",
+    "vietnamera",
+    "volapük",
+    "valoniera",
+    "galesa",
+    "wolofera",
+    "mendebaldeko frisiera",
+    "xhosera",
+    "yiddisha",
+    "jorubera",
+    "zhuangera",
+    "zuluera",
+];
+
+static BE: [&str; 184] = [
+    "абхазская",
+    "афарская",
+    "афрыкаанс",
+    "акан",
+    "албанская",
+    "амхарская",
+    "арабская",
+    "арагонская",
+    "армянская",
+    "асамская",
+    "аварская",
+    "",
+    "аймара",
+    "азербайджанская",
+    "бамбара",
+    "башкірская",
+    "баскская",
+    "беларуская",
+    "бенгальская",
+    "",
+    "біслама",
+    "баснійская",
+    "брэтонская",
+    "балгарская",
+    "бірманская",
+    "каталанская",
+    "чамора",
+    "чачэнская",
+    "ньянджа",
+    "кітайская",
+    "чувашская",
+    "корнская",
+    "карсіканская",
+    "",
+    "харвацкая",
+    "чэшская",
+    "дацкая",
+    "мальдыўская",
+    "нідэрландская",
+    "дзонг-кэ",
+    "англійская",
+    "эсперанта",
+    "эстонская",
+    "эве",
+    "фарэрская",
+    "фіджыйская",
+    "фінская",
+    "французская",
+    "фула",
+    "галісійская",
+    "грузінская",
+    "нямецкая",
+    "грэчаская",
+    "гуарані",
+    "гуджараці",
+    "гаіцянская крэольская",
+    "хауса",
+    "іўрыт",
+    "герэра",
+    "хіндзі",
+    "",
+    "венгерская",
+    "інтэрлінгва",
+    "інданезійская",
+    "інтэрлінгвэ",
+    "ірландская",
+    "ігба",
+    "",
+    "іда",
+    "ісландская",
+    "італьянская",
+    "інуктытут",
+    "японская",
+    "яванская",
+    "грэнландская",
+    "канада",
+    "кануры",
+    "кашмірская",
+    "казахская",
+    "кхмерская",
+    "кікуйю",
+    "руанда",
+    "кіргізская",
+    "комі",
+    "",
+    "карэйская",
+    "курдская",
+    "куаньяма",
+    "лацінская",
+    "люксембургская",
+    "ганда",
+    "лімбургская",
+    "лінгала",
+    "лаоская",
+    "літоўская",
+    "луба-катанга",
+    "латышская",
+    "мэнская",
+    "македонская",
+    "малагасійская",
+    "малайская",
+    "малаялам",
+    "мальтыйская",
+    "маары",
+    "маратхі",
+    "маршальская",
+    "мангольская",
+    "науру",
+    "наваха",
+    "паўночная ндэбеле",
+    "непальская",
+    "ндонга",
+    "нарвежская (букмол)",
+    "нарвежская (нюношк)",
+    "нарвежская",
+    "сычуаньская йі",
+    "паўднёвая ндэбеле",
+    "аксітанская",
+    "аджыбвэ",
+    "царкоўнаславянская",
+    "арома",
+    "орыя",
+    "асецінская",
+    "панджабі",
+    "",
+    "фарсі",
+    "польская",
+    "пушту",
+    "партугальская",
+    "кечуа",
+    "рэтараманская",
+    "рундзі",
+    "румынская",
+    "руская",
+    "санскрыт",
+    "сардзінская",
+    "сіндхі",
+    "паўночнасаамская",
+    "самоа",
+    "санга",
+    "сербская",
+    "шатландская гэльская",
+    "шона",
+    "сінгальская",
+    "славацкая",
+    "славенская",
+    "самалі",
+    "сесута",
+    "іспанская",
+    "сунда",
+    "суахілі",
+    "суаці",
+    "шведская",
+    "тамільская",
+    "тэлугу",
+    "таджыкская",
+    "тайская",
+    "тыгрынья",
+    "тыбецкая",
+    "туркменская",
+    "",
+    "тсвана",
+    "танганская",
+    "турэцкая",
+    "тсонга",
+    "татарская",
+    "",
+    "таіці",
+    "уйгурская",
+    "украінская",
+    "урду",
+    "узбекская",
+    "венда",
+    "в’етнамская",
+    "валапюк",
+    "валонская",
+    "валійская",
+    "валоф",
+    "заходняя фрызская",
+    "коса",
+    "ідыш",
+    "ёруба",
+    "чжуанская",
+    "зулу",
+];
+
+static BN: [&str; 184] = [
+    "আবখাজিয়ান",
+    "আফার",
+    "আফ্রিকান",
+    "আকান",
+    "আলবেনীয়",
+    "আমহারিক",
+    "আরবি",
+    "আর্গোনিজ",
+    "আর্মেনিয়",
+    "অসমীয়া",
+    "আভেরিক",
+    "আবেস্তীয়",
+    "আয়মারা",
+    "আজারবাইজানী",
+    "বামবারা",
+    "বাশকির",
+    "বাস্ক",
+    "বেলারুশিয়",
+    "বাংলা",
+    "",
+    "বিসলামা",
+    "বসনীয়",
+    "ব্রেটন",
+    "বুলগেরিয়",
+    "বর্মি",
+    "কাতালান",
+    "চামোরো",
+    "চেচেন",
+    "নায়াঞ্জা",
+    "চীনা",
+    "চুবাস",
+    "কর্ণিশ",
+    "কর্সিকান",
+    "ক্রি",
+    "ক্রোয়েশীয়",
+    "চেক",
+    "ডেনিশ",
+    "দিবেহি",
+    "ওলন্দাজ",
+    "জোংখা",
+    "ইংরেজি",
+    "এস্পেরান্তো",
+    "এস্তোনীয়",
+    "ইউয়ি",
+    "ফেরোইস",
+    "ফিজিয়ান",
+    "ফিনিশ",
+    "ফরাসি",
+    "ফুলাহ্",
+    "গ্যালিশিয়",
+    "জর্জিয়ান",
+    "জার্মান",
+    "গ্রিক",
+    "গুয়ারানি",
+    "গুজরাটি",
+    "হাইতিয়ান ক্রেওল",
+    "হাউসা",
+    "হিব্রু",
+    "হেরেরো",
+    "হিন্দি",
+    "হিরি মোতু",
+    "হাঙ্গেরীয়",
+    "ইন্টারলিঙ্গুয়া",
+    "ইন্দোনেশীয়",
+    "ইন্টারলিঙ্গ",
+    "আইরিশ",
+    "ইগ্\u{200C}বো",
+    "ইনুপিয়াক",
+    "ইডো",
+    "আইসল্যান্ডীয়",
+    "ইতালিয়",
+    "ইনুক্টিটুট",
+    "জাপানি",
+    "জাভানিজ",
+    "কালাল্লিসুট",
+    "কন্নড়",
+    "কানুরি",
+    "কাশ্মীরি",
+    "কাজাখ",
+    "খমের",
+    "কিকুয়ু",
+    "কিনয়ারোয়ান্ডা",
+    "কির্গিজ",
+    "কোমি",
+    "কঙ্গো",
+    "কোরিয়ান",
+    "কুর্দিশ",
+    "কোয়ানিয়ামা",
+    "লাতিন",
+    "লুক্সেমবার্গীয়",
+    "গান্ডা",
+    "লিম্বুর্গিশ",
+    "লিঙ্গালা",
+    "লাও",
+    "লিথুয়েনীয়",
+    "লুবা-কাটাঙ্গা",
+    "লাত্\u{200C}ভীয়",
+    "ম্যাঙ্কস",
+    "ম্যাসিডোনীয়",
+    "মালাগাসি",
+    "মালয়",
+    "মালায়ালাম",
+    "মল্টিয়",
+    "মাওরি",
+    "মারাঠি",
+    "মার্শালিজ",
+    "মঙ্গোলিয়",
+    "নাউরু",
+    "নাভাজো",
+    "উত্তর এন্দেবেলে",
+    "নেপালী",
+    "এন্দোঙ্গা",
+    "নরওয়েজিয়ান বোকমাল",
+    "নরওয়েজিয়ান নিনর্স্ক",
+    "নরওয়েজীয়",
+    "সিচুয়ান য়ি",
+    "দক্ষিণ এনডেবেলে",
+    "অক্সিটান",
+    "ওজিবওয়া",
+    "চার্চ স্লাভিক",
+    "অরোমো",
+    "ওড়িয়া",
+    "ওসেটিক",
+    "পাঞ্জাবী",
+    "পালি",
+    "ফার্সি",
+    "পোলিশ",
+    "পাশতু",
+    "পর্তুগীজ",
+    "কেচুয়া",
+    "রোমান্স",
+    "রুন্দি",
+    "রোমানীয়",
+    "রুশ",
+    "সংস্কৃত",
+    "সার্ডিনিয়ান",
+    "সিন্ধি",
+    "উত্তরাঞ্চলীয় সামি",
+    "সামোয়ান",
+    "সাঙ্গো",
+    "সার্বীয়",
+    "স্কটিশ-গ্যেলিক",
+    "শোনা",
+    "সিংহলী",
+    "স্লোভাক",
+    "স্লোভেনীয়",
+    "সোমালি",
+    "দক্ষিন সোথো",
+    "স্প্যানিশ",
+    "সুদানী",
+    "সোয়াহিলি",
+    "সোয়াতি",
+    "সুইডিশ",
+    "তামিল",
+    "তেলুগু",
+    "তাজিক",
+    "থাই",
+    "তিগরিনিয়া",
+    "তিব্বতি",
+    "তুর্কমেনী",
+    "তাগালগ",
+    "সোয়ানা",
+    "টোঙ্গান",
+    "তুর্কী",
+    "সঙ্গা",
+    "তাতার",
+    "টোয়াই",
+    "তাহিতিয়ান",
+    "উইঘুর",
+    "ইউক্রেনীয়",
+    "উর্দু",
+    "উজবেক",
+    "ভেন্ডা",
+    "ভিয়েতনামী",
+    "ভোলাপুক",
+    "ওয়ালুন",
+    "ওয়েলশ",
+    "ওলোফ",
+    "পশ্চিম ফ্রিসিয়ান",
+    "জোসা",
+    "ইদ্দিশ",
+    "ইওরুবা",
+    "ঝু্য়াঙ",
+    "জুলু",
+];
+
+static BS: [&str; 184] = [
+    "abhaski",
+    "afarski",
+    "afrikans",
+    "akan",
+    "albanski",
+    "amharski",
+    "arapski",
+    "aragonski",
+    "armenski",
+    "asamski",
+    "avarski",
+    "avestanski",
+    "ajmara",
+    "azerbejdžanski",
+    "bambara",
+    "baškirski",
+    "baskijski",
+    "bjeloruski",
+    "bengalski",
+    "",
+    "bislama",
+    "bosanski",
+    "bretonski",
+    "bugarski",
+    "burmanski",
+    "katalonski",
+    "čamoro",
+    "čečenski",
+    "njanja",
+    "kineski",
+    "čuvaški",
+    "kornski",
+    "korzikanski",
+    "kri",
+    "hrvatski",
+    "češki",
+    "danski",
+    "divehi",
+    "nizozemski",
+    "džonga",
+    "engleski",
+    "esperanto",
+    "estonski",
+    "eve",
+    "farski",
+    "fidžijski",
+    "finski",
+    "francuski",
+    "fulah",
+    "galicijski",
+    "gruzijski",
+    "njemački",
+    "grčki",
+    "gvarani",
+    "gudžarati",
+    "haićanski kreolski",
+    "hausa",
+    "hebrejski",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "mađarski",
+    "interlingva",
+    "indonezijski",
+    "interlingve",
+    "irski",
+    "igbo",
+    "inupiak",
+    "ido",
+    "islandski",
+    "italijanski",
+    "inuktitut",
+    "japanski",
+    "javanski",
+    "kalalisutski",
+    "kanada",
+    "kanuri",
+    "kašmirski",
+    "kazaški",
+    "kmerski",
+    "kikuju",
+    "kinjaruanda",
+    "kirgiški",
+    "komi",
+    "kongo",
+    "korejski",
+    "kurdski",
+    "kuanjama",
+    "latinski",
+    "luksemburški",
+    "ganda",
+    "limburški",
+    "lingala",
+    "laoski",
+    "litvanski",
+    "luba-katanga",
+    "latvijski",
+    "manks",
+    "makedonski",
+    "malgaški",
+    "malajski",
+    "malajalam",
+    "malteški",
+    "maorski",
+    "marati",
+    "maršalski",
+    "mongolski",
+    "nauru",
+    "navaho",
+    "sjeverni ndebele",
+    "nepalski",
+    "ndonga",
+    "norveški (Bokmal)",
+    "norveški (Nynorsk)",
+    "norveški",
+    "sičuan ji",
+    "južni ndebele",
+    "oksitanski",
+    "ojibva",
+    "staroslavenski",
+    "oromo",
+    "odija",
+    "osetski",
+    "pandžapski",
+    "pali",
+    "perzijski",
+    "poljski",
+    "paštu",
+    "portugalski",
+    "kečua",
+    "retoromanski",
+    "rundi",
+    "rumunski",
+    "ruski",
+    "sanskrit",
+    "sardinijski",
+    "sindi",
+    "sjeverni sami",
+    "samoanski",
+    "sango",
+    "srpski",
+    "škotski galski",
+    "šona",
+    "sinhaleški",
+    "slovački",
+    "slovenski",
+    "somalski",
+    "južni soto",
+    "španski",
+    "sundanski",
+    "svahili",
+    "svati",
+    "švedski",
+    "tamilski",
+    "telugu",
+    "tadžički",
+    "tajlandski",
+    "tigrinja",
+    "tibetanski",
+    "turkmenski",
+    "tagalog",
+    "tsvana",
+    "tonganski",
+    "turski",
+    "tsonga",
+    "tatarski",
+    "tvi",
+    "tahićanski",
+    "ujgurski",
+    "ukrajinski",
+    "urdu",
+    "uzbečki",
+    "venda",
+    "vijetnamski",
+    "volapuk",
+    "valun",
+    "velški",
+    "volof",
+    "zapadni frizijski",
+    "hosa",
+    "jidiš",
+    "jorubanski",
+    "zuang",
+    "zulu",
+];
+
+static BR: [&str; 184] = [
+    "abkhazeg",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albaneg",
+    "amhareg",
+    "arabeg",
+    "aragoneg",
+    "armenianeg",
+    "asameg",
+    "avar",
+    "avesteg",
+    "aymara",
+    "azerbaidjaneg",
+    "bambara",
+    "bachkir",
+    "euskareg",
+    "belaruseg",
+    "bengali",
+    "",
+    "bislama",
+    "bosneg",
+    "brezhoneg",
+    "bulgareg",
+    "birmaneg",
+    "katalaneg",
+    "chamorru",
+    "tchetcheneg",
+    "nyanja",
+    "sinaeg",
+    "tchouvatch",
+    "kerneveureg",
+    "korseg",
+    "kri",
+    "kroateg",
+    "tchekeg",
+    "daneg",
+    "divehi",
+    "nederlandeg",
+    "dzongkha",
+    "saozneg",
+    "esperanteg",
+    "estoneg",
+    "ewe",
+    "faeroeg",
+    "fidjieg",
+    "finneg",
+    "galleg",
+    "fula",
+    "galizeg",
+    "jorjianeg",
+    "alamaneg",
+    "gresianeg",
+    "guarani",
+    "gujarati",
+    "haitieg",
+    "haousa",
+    "hebraeg",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "hungareg",
+    "interlingua",
+    "indonezeg",
+    "interlingue",
+    "iwerzhoneg",
+    "igbo",
+    "inupiaq",
+    "ido",
+    "islandeg",
+    "italianeg",
+    "inuktitut",
+    "japaneg",
+    "javaneg",
+    "greunlandeg",
+    "kanareg",
+    "kanouri",
+    "kashmiri",
+    "kazak",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirgiz",
+    "komieg",
+    "kongo",
+    "koreaneg",
+    "kurdeg",
+    "kwanyama",
+    "latin",
+    "luksembourgeg",
+    "ganda",
+    "limbourgeg",
+    "lingala",
+    "laoseg",
+    "lituaneg",
+    "luba-katanga",
+    "latvieg",
+    "manaveg",
+    "makedoneg",
+    "malgacheg",
+    "malayseg",
+    "malayalam",
+    "malteg",
+    "maori",
+    "marathi",
+    "marshall",
+    "mongoleg",
+    "naurueg",
+    "navacʼho",
+    "ndebele an Norzh",
+    "nepaleg",
+    "ndonga",
+    "norvegeg bokmål",
+    "norvegeg nynorsk",
+    "norvegeg",
+    "yieg Sichuan",
+    "ndebele ar Su",
+    "okitaneg",
+    "ojibweg",
+    "slavoneg iliz",
+    "oromoeg",
+    "oriya",
+    "oseteg",
+    "punjabi",
+    "pali",
+    "perseg",
+    "poloneg",
+    "pachto",
+    "portugaleg",
+    "kechuaeg",
+    "romañcheg",
+    "rundi",
+    "roumaneg",
+    "rusianeg",
+    "sanskriteg",
+    "sardeg",
+    "sindhi",
+    "sámi an Norzh",
+    "samoan",
+    "sango",
+    "serbeg",
+    "skoseg",
+    "shona",
+    "singhaleg",
+    "slovakeg",
+    "sloveneg",
+    "somali",
+    "sotho ar Su",
+    "spagnoleg",
+    "sundaneg",
+    "swahili",
+    "swati",
+    "svedeg",
+    "tamileg",
+    "telougou",
+    "tadjik",
+    "thai",
+    "tigrigna",
+    "tibetaneg",
+    "turkmeneg",
+    "tagalog",
+    "tswana",
+    "tonga",
+    "turkeg",
+    "tsonga",
+    "tatar",
+    "twi",
+    "tahitianeg",
+    "ouigoureg",
+    "ukraineg",
+    "ourdou",
+    "ouzbekeg",
+    "venda",
+    "vietnameg",
+    "volapük",
+    "walloneg",
+    "kembraeg",
+    "wolof",
+    "frizeg ar Cʼhornôg",
+    "xhosa",
+    "yiddish",
+    "yorouba",
+    "zhuang",
+    "zouloueg",
+];
+
+static BG: [&str; 184] = [
+    "абхазки",
+    "афарски",
+    "африканс",
+    "акан",
+    "албански",
+    "амхарски",
+    "арабски",
+    "арагонски",
+    "арменски",
+    "асамски",
+    "аварски",
+    "авестски",
+    "аймара",
+    "азербайджански",
+    "бамбара",
+    "башкирски",
+    "баски",
+    "беларуски",
+    "бенгалски",
+    "",
+    "бислама",
+    "босненски",
+    "бретонски",
+    "български",
+    "бирмански",
+    "каталонски",
+    "чаморо",
+    "чеченски",
+    "нянджа",
+    "китайски",
+    "чувашки",
+    "корнуолски",
+    "корсикански",
+    "крии",
+    "хърватски",
+    "чешки",
+    "датски",
+    "дивехи",
+    "нидерландски",
+    "дзонгкха",
+    "английски",
+    "есперанто",
+    "естонски",
+    "еве",
+    "фарьорски",
+    "фиджийски",
+    "фински",
+    "френски",
+    "фула",
+    "галисийски",
+    "грузински",
+    "немски",
+    "гръцки",
+    "гуарани",
+    "гуджарати",
+    "хаитянски креолски",
+    "хауса",
+    "иврит",
+    "хереро",
+    "хинди",
+    "хири моту",
+    "унгарски",
+    "интерлингва",
+    "индонезийски",
+    "интерлингве",
+    "ирландски",
+    "игбо",
+    "инупиак",
+    "идо",
+    "исландски",
+    "италиански",
+    "инуктитут",
+    "японски",
+    "явански",
+    "гренландски",
+    "каннада",
+    "канури",
+    "кашмирски",
+    "казахски",
+    "кхмерски",
+    "кикую",
+    "киняруанда",
+    "киргизки",
+    "коми",
+    "конгоански",
+    "корейски",
+    "кюрдски",
+    "кваняма",
+    "латински",
+    "люксембургски",
+    "ганда",
+    "лимбургски",
+    "лингала",
+    "лаоски",
+    "литовски",
+    "луба-катанга",
+    "латвийски",
+    "манкски",
+    "македонски",
+    "малгашки",
+    "малайски",
+    "малаялам",
+    "малтийски",
+    "маорски",
+    "марати",
+    "маршалезе",
+    "монголски",
+    "науру",
+    "навахо",
+    "северен ндебеле",
+    "непалски",
+    "ндонга",
+    "норвежки (букмол)",
+    "норвежки (нюношк)",
+    "норвежки",
+    "съчуански йи",
+    "южен ндебеле",
+    "окситански",
+    "оджибва",
+    "църковнославянски",
+    "оромо",
+    "ория",
+    "осетински",
+    "пенджабски",
+    "пали",
+    "персийски",
+    "полски",
+    "пущу",
+    "португалски",
+    "кечуа",
+    "реторомански",
+    "рунди",
+    "румънски",
+    "руски",
+    "санскрит",
+    "сардински",
+    "синдхи",
+    "северносаамски",
+    "самоански",
+    "санго",
+    "сръбски",
+    "шотландски келтски",
+    "шона",
+    "синхалски",
+    "словашки",
+    "словенски",
+    "сомалийски",
+    "сото",
+    "испански",
+    "сундански",
+    "суахили",
+    "свати",
+    "шведски",
+    "тамилски",
+    "телугу",
+    "таджикски",
+    "тайски",
+    "тигриня",
+    "тибетски",
+    "туркменски",
+    "тагалог",
+    "тсвана",
+    "тонгански",
+    "турски",
+    "цонга",
+    "татарски",
+    "туи",
+    "таитянски",
+    "уйгурски",
+    "украински",
+    "урду",
+    "узбекски",
+    "венда",
+    "виетнамски",
+    "волапюк",
+    "валонски",
+    "уелски",
+    "волоф",
+    "западнофризийски",
+    "кхоса",
+    "идиш",
+    "йоруба",
+    "зуанг",
+    "зулуски",
+];
+
+static MY: [&str; 184] = [
+    "အဘ်ခါဇီရာ",
+    "အာဖာ",
+    "အာဖရိကန်",
+    "အာကန်",
+    "အယ်လ်ဘေးနီးယား",
+    "အမ်ဟာရစ်ခ်",
+    "အာရဗီ",
+    "အာရာဂွန်",
+    "အာမေးနီးယား",
+    "အာသံ",
+    "အာဗာရစ်ခ်",
+    "",
+    "အိုင်မာရ",
+    "အဇာဘိုင်ဂျန်",
+    "ဘန်ဘာရာ",
+    "ဘက်ရှ်ကာ",
+    "ဘာစ်ခ်",
+    "ဘီလာရုစ်",
+    "ဘင်္ဂါလီ",
+    "",
+    "ဘစ်စ်လာမာ",
+    "ဘော့စ်နီးယား",
+    "ဘရီတွန်",
+    "ဘူလ်ဂေးရီးယား",
+    "မြန်မာ",
+    "ကတ်တလန်",
+    "ချမိုရို",
+    "ချက်ချန်း",
+    "နရန်ဂျာ",
+    "တရုတ်",
+    "ချူဗက်ရှ်",
+    "ခိုနီရှ်",
+    "ခိုစီကန်",
+    "ခရီး",
+    "ခရိုအေးရှား",
+    "ချက်",
+    "ဒိန်းမတ်",
+    "ဒီဗာဟီ",
+    "ဒတ်ခ်ျ",
+    "ဒဇွန်ကာ",
+    "အင်္ဂလိပ်",
+    "အက်စ်ပရန်တို",
+    "အက်စ်တိုးနီးယား",
+    "အီဝီ",
+    "ဖာရို",
+    "ဖီဂျီ",
+    "ဖင်လန်",
+    "ပြင်သစ်",
+    "ဖူလာ",
+    "ဂါလီစီယာ",
+    "ဂျော်ဂျီယာ",
+    "ဂျာမန်",
+    "ဂရိ",
+    "ဂူအာရာနီ",
+    "ဂူဂျာရသီ",
+    "ဟေတီ",
+    "ဟာဥစာ",
+    "ဟီဘရူး",
+    "ဟီရဲရို",
+    "ဟိန္ဒီ",
+    "",
+    "ဟန်ဂေရီ",
+    "အင်တာလင်ဂွါ",
+    "အင်ဒိုနီးရှား",
+    "အင်တာလင်း",
+    "အိုင်းရစ်ရှ်",
+    "အစ္ဂဘို",
+    "",
+    "အီဒို",
+    "အိုက်စ်လန်",
+    "အီတလီ",
+    "အီနုခ်တီတု",
+    "ဂျပန်",
+    "ဂျာဗား",
+    "ကလာအ်လီဆပ်",
+    "ကန်နာဒါ",
+    "ကနူရီ",
+    "ကက်ရှ်မီးယား",
+    "ကာဇာချ",
+    "ခမာ",
+    "ကီကူယူ",
+    "ကင်ရာဝန်ဒါ",
+    "ကာဂျစ်",
+    "ကိုမီ",
+    "ကွန်ဂို",
+    "ကိုရီးယား",
+    "ကဒ်",
+    "ကွန်းယာမာ",
+    "လက်တင်",
+    "လူဇင်ဘတ်",
+    "ဂန်ဒါ",
+    "လင်ဘာဂစ်ရှ်",
+    "လင်ဂါလာ",
+    "လာအို",
+    "လစ်သူဝေးနီးယား",
+    "လူဘာ-ကတန်ဂါ",
+    "လတ်ဗီးယား",
+    "မန်းဇ်",
+    "မက်ဆီဒိုးနီးယား",
+    "မာလဂက်စီ",
+    "မလေး",
+    "မလေယာလမ်",
+    "မော်လ်တာ",
+    "မာအိုရီ",
+    "မာရသီ",
+    "မာရှယ်လိဇ်",
+    "မွန်ဂိုလီးယား",
+    "နော်ရူး",
+    "နာဗာဟို",
+    "မြောက် အွန်န်ဒီဘီလီ",
+    "နီပေါ",
+    "အွန်ဒွန်ဂါ",
+    "နော်ဝေ ဘွတ်ခ်မော်လ်",
+    "နော်ဝေ နီးနောစ်",
+    "နော်ဝေ",
+    "စီချွမ် ရီ",
+    "တောင် အွန်န်ဘီလီ",
+    "အိုစီတန်",
+    "",
+    "ချပ်ချ် စလာဗစ်",
+    "အိုရိုမို",
+    "အိုရီရာ",
+    "အိုဆဲတစ်ခ်",
+    "ပန်ချာပီ",
+    "ပါဠိ",
+    "ပါရှန်",
+    "ပိုလန်",
+    "ပက်ရှ်တွန်း",
+    "ပေါ်တူဂီ",
+    "ခီချူဝါအိုဝါ",
+    "ရောမ",
+    "ရွန်ဒီ",
+    "ရိုမေနီယား",
+    "ရုရှ",
+    "သင်္သကရိုက်",
+    "ဆာဒီနီးယား",
+    "စင်ဒီ",
+    "မြောက် ဆာမိ",
+    "ဆမိုအာ",
+    "ဆန်ဂို",
+    "ဆားဘီးယား",
+    "စကော့တစ်ရှ် ဂေးလစ်ခ်",
+    "ရှိုနာ",
+    "စင်ဟာလာ",
+    "ဆလိုဗက်",
+    "ဆလိုဗေးနီးယား",
+    "ဆိုမာလီ",
+    "တောင်ပိုင်း ဆိုသို",
+    "စပိန်",
+    "ဆူဒန်",
+    "ဆွာဟီလီ",
+    "ဆွာဇီလန်",
+    "ဆွီဒင်",
+    "တမီးလ်",
+    "တီလီဂူ",
+    "တာဂျစ်",
+    "ထိုင်း",
+    "တီဂ်ရင်ယာ",
+    "တိဘက်",
+    "တာ့ခ်မင်နစ္စတန်",
+    "",
+    "တီဆဝါနာ",
+    "တွန်ဂါ",
+    "တူရကီ",
+    "ဆွန်ဂါ",
+    "တာတာ",
+    "",
+    "တဟီတီ",
+    "ဝီဂါ",
+    "ယူကရိန်း",
+    "အူရ်ဒူ",
+    "ဥဇဘတ်",
+    "ဗင်န်ဒါ",
+    "ဗီယက်နမ်",
+    "ဗိုလာပိုက်",
+    "ဝါလူးန်",
+    "ဝေလ",
+    "ဝူလိုဖ်",
+    "အနောက် ဖရီစီရန်",
+    "ဇိုစာ",
+    "ရဟူဒီ",
+    "ယိုရူဘာ",
+    "ဂျွမ်",
+    "ဇူးလူး",
+];
+
+static CA: [&str; 184] = [
+    "abkhaz",
+    "àfar",
+    "afrikaans",
+    "àkan",
+    "albanès",
+    "amhàric",
+    "àrab",
+    "aragonès",
+    "armeni",
+    "assamès",
+    "àvar",
+    "avèstic",
+    "aimara",
+    "azerbaidjanès",
+    "bambara",
+    "baixkir",
+    "basc",
+    "belarús",
+    "bengalí",
+    "",
+    "bislama",
+    "bosnià",
+    "bretó",
+    "búlgar",
+    "birmà",
+    "català",
+    "chamorro",
+    "txetxè",
+    "nyanja",
+    "xinès",
+    "txuvaix",
+    "còrnic",
+    "cors",
+    "cree",
+    "croat",
+    "txec",
+    "danès",
+    "divehi",
+    "neerlandès",
+    "dzongka",
+    "anglès",
+    "esperanto",
+    "estonià",
+    "ewe",
+    "feroès",
+    "fijià",
+    "finès",
+    "francès",
+    "ful",
+    "gallec",
+    "georgià",
+    "alemany",
+    "grec",
+    "guaraní",
+    "gujarati",
+    "crioll d’Haití",
+    "haussa",
+    "hebreu",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "hongarès",
+    "interlingua",
+    "indonesi",
+    "interlingue",
+    "irlandès",
+    "igbo",
+    "inupiak",
+    "ido",
+    "islandès",
+    "italià",
+    "inuktitut",
+    "japonès",
+    "javanès",
+    "groenlandès",
+    "kannada",
+    "kanuri",
+    "caixmiri",
+    "kazakh",
+    "khmer",
+    "kikuiu",
+    "ruandès",
+    "kirguís",
+    "komi",
+    "kongo",
+    "coreà",
+    "kurd",
+    "kuanyama",
+    "llatí",
+    "luxemburguès",
+    "ganda",
+    "limburguès",
+    "lingala",
+    "laosià",
+    "lituà",
+    "luba katanga",
+    "letó",
+    "manx",
+    "macedoni",
+    "malgaix",
+    "malai",
+    "malaiàlam",
+    "maltès",
+    "maori",
+    "marathi",
+    "marshallès",
+    "mongol",
+    "nauruà",
+    "navaho",
+    "ndebele septentrional",
+    "nepalès",
+    "ndonga",
+    "noruec bokmål",
+    "noruec nynorsk",
+    "noruec",
+    "yi sichuan",
+    "ndebele meridional",
+    "occità",
+    "ojibwa",
+    "eslau eclesiàstic",
+    "oromo",
+    "oriya",
+    "osseta",
+    "panjabi",
+    "pali",
+    "persa",
+    "polonès",
+    "paixtu",
+    "portuguès",
+    "quítxua",
+    "retoromànic",
+    "rundi",
+    "romanès",
+    "rus",
+    "sànscrit",
+    "sard",
+    "sindi",
+    "sami septentrional",
+    "samoà",
+    "sango",
+    "serbi",
+    "gaèlic escocès",
+    "shona",
+    "singalès",
+    "eslovac",
+    "eslovè",
+    "somali",
+    "sotho meridional",
+    "espanyol",
+    "sondanès",
+    "suahili",
+    "swazi",
+    "suec",
+    "tàmil",
+    "telugu",
+    "tadjik",
+    "tai",
+    "tigrinya",
+    "tibetà",
+    "turcman",
+    "tagal",
+    "setswana",
+    "tongalès",
+    "turc",
+    "tsonga",
+    "tàtar",
+    "twi",
+    "tahitià",
+    "uigur",
+    "ucraïnès",
+    "urdú",
+    "uzbek",
+    "venda",
+    "vietnamita",
+    "volapük",
+    "való",
+    "gal·lès",
+    "wòlof",
+    "frisó occidental",
+    "xosa",
+    "ídix",
+    "ioruba",
+    "zhuang",
+    "zulu",
+];
+
+static ZH: [&str; 184] = [
+    "阿布哈西亚语",
+    "阿法尔语",
+    "南非荷兰语",
+    "阿肯语",
+    "阿尔巴尼亚语",
+    "阿姆哈拉语",
+    "阿拉伯语",
+    "阿拉贡语",
+    "亚美尼亚语",
+    "阿萨姆语",
+    "阿瓦尔语",
+    "阿维斯塔语",
+    "艾马拉语",
+    "阿塞拜疆语",
+    "班巴拉语",
+    "巴什基尔语",
+    "巴斯克语",
+    "白俄罗斯语",
+    "孟加拉语",
+    "",
+    "比斯拉马语",
+    "波斯尼亚语",
+    "布列塔尼语",
+    "保加利亚语",
+    "缅甸语",
+    "加泰罗尼亚语",
+    "查莫罗语",
+    "车臣语",
+    "齐切瓦语",
+    "中文",
+    "楚瓦什语",
+    "康沃尔语",
+    "科西嘉语",
+    "克里语",
+    "克罗地亚语",
+    "捷克语",
+    "丹麦语",
+    "迪维希语",
+    "荷兰语",
+    "宗卡语",
+    "英语",
+    "世界语",
+    "爱沙尼亚语",
+    "埃维语",
+    "法罗语",
+    "斐济语",
+    "芬兰语",
+    "法语",
+    "富拉语",
+    "加利西亚语",
+    "格鲁吉亚语",
+    "德语",
+    "希腊语",
+    "瓜拉尼语",
+    "古吉拉特语",
+    "海地克里奥尔语",
+    "豪萨语",
+    "希伯来语",
+    "赫雷罗语",
+    "印地语",
+    "希里莫图语",
+    "匈牙利语",
+    "国际语",
+    "印度尼西亚语",
+    "国际文字（E）",
+    "爱尔兰语",
+    "伊博语",
+    "伊努皮克语",
+    "伊多语",
+    "冰岛语",
+    "意大利语",
+    "因纽特语",
+    "日语",
+    "爪哇语",
+    "格陵兰语",
+    "卡纳达语",
+    "卡努里语",
+    "克什米尔语",
+    "哈萨克语",
+    "高棉语",
+    "吉库尤语",
+    "卢旺达语",
+    "柯尔克孜语",
+    "科米语",
+    "刚果语",
+    "韩语",
+    "库尔德语",
+    "宽亚玛语",
+    "拉丁语",
+    "卢森堡语",
+    "卢干达语",
+    "林堡语",
+    "林加拉语",
+    "老挝语",
+    "立陶宛语",
+    "鲁巴加丹加语",
+    "拉脱维亚语",
+    "马恩语",
+    "马其顿语",
+    "马拉加斯语",
+    "马来语",
+    "马拉雅拉姆语",
+    "马耳他语",
+    "毛利语",
+    "马拉地语",
+    "马绍尔语",
+    "蒙古语",
+    "瑙鲁语",
+    "纳瓦霍语",
+    "北恩德贝勒语",
+    "尼泊尔语",
+    "恩东加语",
+    "书面挪威语",
+    "挪威尼诺斯克语",
+    "挪威语",
+    "凉山彝语",
+    "南恩德贝勒语",
+    "奥克语",
+    "奥吉布瓦语",
+    "教会斯拉夫语",
+    "奥罗莫语",
+    "奥里亚语",
+    "奥塞梯语",
+    "旁遮普语",
+    "巴利语",
+    "波斯语",
+    "波兰语",
+    "普什图语",
+    "葡萄牙语",
+    "克丘亚语",
+    "罗曼什语",
+    "隆迪语",
+    "罗马尼亚语",
+    "俄语",
+    "梵语",
+    "萨丁语",
+    "信德语",
+    "北方萨米语",
+    "萨摩亚语",
+    "桑戈语",
+    "塞尔维亚语",
+    "苏格兰盖尔语",
+    "绍纳语",
+    "僧伽罗语",
+    "斯洛伐克语",
+    "斯洛文尼亚语",
+    "索马里语",
+    "南索托语",
+    "西班牙语",
+    "巽他语",
+    "斯瓦希里语",
+    "斯瓦蒂语",
+    "瑞典语",
+    "泰米尔语",
+    "泰卢固语",
+    "塔吉克语",
+    "泰语",
+    "提格利尼亚语",
+    "藏语",
+    "土库曼语",
+    "他加禄语",
+    "茨瓦纳语",
+    "汤加语",
+    "土耳其语",
+    "聪加语",
+    "鞑靼语",
+    "契维语",
+    "塔希提语",
+    "维吾尔语",
+    "乌克兰语",
+    "乌尔都语",
+    "乌兹别克语",
+    "文达语",
+    "越南语",
+    "沃拉普克语",
+    "瓦隆语",
+    "威尔士语",
+    "沃洛夫语",
+    "西弗里西亚语",
+    "科萨语",
+    "意第绪语",
+    "约鲁巴语",
+    "壮语",
+    "祖鲁语",
+];
+
+static HR: [&str; 184] = [
+    "abhaski",
+    "afarski",
+    "afrikaans",
+    "akanski",
+    "albanski",
+    "amharski",
+    "arapski",
+    "aragonski",
+    "armenski",
+    "asamski",
+    "avarski",
+    "avestički",
+    "ajmarski",
+    "azerbajdžanski",
+    "bambara",
+    "baškirski",
+    "baskijski",
+    "bjeloruski",
+    "bangla",
+    "",
+    "bislama",
+    "bosanski",
+    "bretonski",
+    "bugarski",
+    "burmanski",
+    "katalonski",
+    "chamorro",
+    "čečenski",
+    "njandža",
+    "kineski",
+    "čuvaški",
+    "kornski",
+    "korzički",
+    "cree",
+    "hrvatski",
+    "češki",
+    "danski",
+    "divehi",
+    "nizozemski",
+    "dzongkha",
+    "engleski",
+    "esperanto",
+    "estonski",
+    "ewe",
+    "ferojski",
+    "fidžijski",
+    "finski",
+    "francuski",
+    "fula",
+    "galicijski",
+    "gruzijski",
+    "njemački",
+    "grčki",
+    "gvaranski",
+    "gudžaratski",
+    "haićanski kreolski",
+    "hausa",
+    "hebrejski",
+    "herero",
+    "hindski",
+    "hiri motu",
+    "mađarski",
+    "interlingua",
+    "indonezijski",
+    "interligua",
+    "irski",
+    "igbo",
+    "inupiaq",
+    "ido",
+    "islandski",
+    "talijanski",
+    "inuktitut",
+    "japanski",
+    "javanski",
+    "kalaallisut",
+    "karnatački",
+    "kanuri",
+    "kašmirski",
+    "kazaški",
+    "kmerski",
+    "kikuyu",
+    "kinyarwanda",
+    "kirgiski",
+    "komi",
+    "kongo",
+    "korejski",
+    "kurdski",
+    "kuanyama",
+    "latinski",
+    "luksemburški",
+    "ganda",
+    "limburški",
+    "lingala",
+    "laoski",
+    "litavski",
+    "luba-katanga",
+    "latvijski",
+    "manski",
+    "makedonski",
+    "malgaški",
+    "malajski",
+    "malajalamski",
+    "malteški",
+    "maorski",
+    "marathski",
+    "maršalski",
+    "mongolski",
+    "nauru",
+    "navajo",
+    "sjeverni ndebele",
+    "nepalski",
+    "ndonga",
+    "norveški bokmål",
+    "norveški nynorsk",
+    "norveški",
+    "sichuan ji",
+    "južni ndebele",
+    "okcitanski",
+    "ojibwa",
+    "crkvenoslavenski",
+    "oromski",
+    "orijski",
+    "osetski",
+    "pandžapski",
+    "pali",
+    "perzijski",
+    "poljski",
+    "paštunski",
+    "portugalski",
+    "kečuanski",
+    "retoromanski",
+    "rundi",
+    "rumunjski",
+    "ruski",
+    "sanskrtski",
+    "sardski",
+    "sindski",
+    "sjeverni sami",
+    "samoanski",
+    "sango",
+    "srpski",
+    "škotski gaelski",
+    "shona",
+    "sinhaleški",
+    "slovački",
+    "slovenski",
+    "somalski",
+    "sesotski",
+    "španjolski",
+    "sundanski",
+    "svahili",
+    "svati",
+    "švedski",
+    "tamilski",
+    "teluški",
+    "tadžički",
+    "tajlandski",
+    "tigrinja",
+    "tibetski",
+    "turkmenski",
+    "tagalog",
+    "cvana",
+    "tonganski",
+    "turski",
+    "tsonga",
+    "tatarski",
+    "twi",
+    "tahićanski",
+    "ujgurski",
+    "ukrajinski",
+    "urdski",
+    "uzbečki",
+    "venda",
+    "vijetnamski",
+    "volapük",
+    "valonski",
+    "velški",
+    "volof",
+    "zapadnofrizijski",
+    "xhosa",
+    "jidiš",
+    "jorupski",
+    "zhuang",
+    "zulu",
+];
+
+static CS: [&str; 184] = [
+    "abcházština",
+    "afarština",
+    "afrikánština",
+    "akanština",
+    "albánština",
+    "amharština",
+    "arabština",
+    "aragonština",
+    "arménština",
+    "ásámština",
+    "avarština",
+    "avestánština",
+    "ajmarština",
+    "ázerbájdžánština",
+    "bambarština",
+    "baškirština",
+    "baskičtina",
+    "běloruština",
+    "bengálština",
+    "",
+    "bislamština",
+    "bosenština",
+    "bretonština",
+    "bulharština",
+    "barmština",
+    "katalánština",
+    "čamoro",
+    "čečenština",
+    "ňandžština",
+    "čínština",
+    "čuvaština",
+    "kornština",
+    "korsičtina",
+    "kríjština",
+    "chorvatština",
+    "čeština",
+    "dánština",
+    "maledivština",
+    "nizozemština",
+    "dzongkä",
+    "angličtina",
+    "esperanto",
+    "estonština",
+    "eweština",
+    "faerština",
+    "fidžijština",
+    "finština",
+    "francouzština",
+    "fulbština",
+    "galicijština",
+    "gruzínština",
+    "němčina",
+    "řečtina",
+    "guaranština",
+    "gudžarátština",
+    "haitština",
+    "hauština",
+    "hebrejština",
+    "hererština",
+    "hindština",
+    "hiri motu",
+    "maďarština",
+    "interlingua",
+    "indonéština",
+    "interlingue",
+    "irština",
+    "igboština",
+    "inupiakština",
+    "ido",
+    "islandština",
+    "italština",
+    "inuktitutština",
+    "japonština",
+    "javánština",
+    "grónština",
+    "kannadština",
+    "kanuri",
+    "kašmírština",
+    "kazaština",
+    "khmérština",
+    "kikujština",
+    "kiňarwandština",
+    "kyrgyzština",
+    "komijština",
+    "konžština",
+    "korejština",
+    "kurdština",
+    "kuaňamština",
+    "latina",
+    "lucemburština",
+    "gandština",
+    "limburština",
+    "lingalština",
+    "laoština",
+    "litevština",
+    "lubu-katanžština",
+    "lotyština",
+    "manština",
+    "makedonština",
+    "malgaština",
+    "malajština",
+    "malajálamština",
+    "maltština",
+    "maorština",
+    "maráthština",
+    "maršálština",
+    "mongolština",
+    "naurština",
+    "navažština",
+    "ndebele (Zimbabwe)",
+    "nepálština",
+    "ndondština",
+    "norština (bokmål)",
+    "norština (nynorsk)",
+    "norština",
+    "iština (sečuánská)",
+    "ndebele (Jižní Afrika)",
+    "okcitánština",
+    "odžibvejština",
+    "staroslověnština",
+    "oromština",
+    "urijština",
+    "osetština",
+    "paňdžábština",
+    "pálí",
+    "perština",
+    "polština",
+    "paštština",
+    "portugalština",
+    "kečuánština",
+    "rétorománština",
+    "kirundština",
+    "rumunština",
+    "ruština",
+    "sanskrt",
+    "sardština",
+    "sindhština",
+    "sámština (severní)",
+    "samojština",
+    "sangština",
+    "srbština",
+    "skotská gaelština",
+    "šonština",
+    "sinhálština",
+    "slovenština",
+    "slovinština",
+    "somálština",
+    "sotština (jižní)",
+    "španělština",
+    "sundština",
+    "svahilština",
+    "siswatština",
+    "švédština",
+    "tamilština",
+    "telugština",
+    "tádžičtina",
+    "thajština",
+    "tigrinijština",
+    "tibetština",
+    "turkmenština",
+    "tagalog",
+    "setswanština",
+    "tongánština",
+    "turečtina",
+    "tsonga",
+    "tatarština",
+    "twi",
+    "tahitština",
+    "ujgurština",
+    "ukrajinština",
+    "urdština",
+    "uzbečtina",
+    "venda",
+    "vietnamština",
+    "volapük",
+    "valonština",
+    "velština",
+    "wolofština",
+    "fríština (západní)",
+    "xhoština",
+    "jidiš",
+    "jorubština",
+    "čuangština",
+    "zuluština",
+];
+
+static DA: [&str; 184] = [
+    "abkhasisk",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albansk",
+    "amharisk",
+    "arabisk",
+    "aragonsk",
+    "armensk",
+    "assamesisk",
+    "avarisk",
+    "avestan",
+    "aymara",
+    "aserbajdsjansk",
+    "bambara",
+    "bashkir",
+    "baskisk",
+    "belarusisk",
+    "bengali",
+    "",
+    "bislama",
+    "bosnisk",
+    "bretonsk",
+    "bulgarsk",
+    "burmesisk",
+    "catalansk",
+    "chamorro",
+    "tjetjensk",
+    "nyanja",
+    "kinesisk",
+    "tjuvasjisk",
+    "cornisk",
+    "korsikansk",
+    "cree",
+    "kroatisk",
+    "tjekkisk",
+    "dansk",
+    "divehi",
+    "nederlandsk",
+    "dzongkha",
+    "engelsk",
+    "esperanto",
+    "estisk",
+    "ewe",
+    "færøsk",
+    "fijiansk",
+    "finsk",
+    "fransk",
+    "fulah",
+    "galicisk",
+    "georgisk",
+    "tysk",
+    "græsk",
+    "guarani",
+    "gujarati",
+    "haitisk",
+    "hausa",
+    "hebraisk",
+    "herero",
+    "hindi",
+    "hirimotu",
+    "ungarsk",
+    "interlingua",
+    "indonesisk",
+    "interlingue",
+    "irsk",
+    "igbo",
+    "inupiaq",
+    "ido",
+    "islandsk",
+    "italiensk",
+    "inuktitut",
+    "japansk",
+    "javanesisk",
+    "grønlandsk",
+    "kannada",
+    "kanuri",
+    "kashmiri",
+    "kasakhisk",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirgisisk",
+    "komi",
+    "kongo",
+    "koreansk",
+    "kurdisk",
+    "kuanyama",
+    "latin",
+    "luxembourgsk",
+    "ganda",
+    "limburgsk",
+    "lingala",
+    "lao",
+    "litauisk",
+    "luba-Katanga",
+    "lettisk",
+    "manx",
+    "makedonsk",
+    "malagassisk",
+    "malajisk",
+    "malayalam",
+    "maltesisk",
+    "maori",
+    "marathi",
+    "marshallese",
+    "mongolsk",
+    "nauru",
+    "navajo",
+    "nordndebele",
+    "nepalesisk",
+    "ndonga",
+    "bokmål",
+    "nynorsk",
+    "norsk",
+    "sichuan yi",
+    "sydndebele",
+    "occitansk",
+    "ojibwa",
+    "kirkeslavisk",
+    "oromo",
+    "oriya",
+    "ossetisk",
+    "punjabi",
+    "pali",
+    "persisk",
+    "polsk",
+    "pashto",
+    "portugisisk",
+    "quechua",
+    "rætoromansk",
+    "rundi",
+    "rumænsk",
+    "russisk",
+    "sanskrit",
+    "sardinsk",
+    "sindhi",
+    "nordsamisk",
+    "samoansk",
+    "sango",
+    "serbisk",
+    "skotsk gælisk",
+    "shona",
+    "singalesisk",
+    "slovakisk",
+    "slovensk",
+    "somali",
+    "sydsotho",
+    "spansk",
+    "sundanesisk",
+    "swahili",
+    "swati",
+    "svensk",
+    "tamil",
+    "telugu",
+    "tadsjikisk",
+    "thai",
+    "tigrinya",
+    "tibetansk",
+    "turkmensk",
+    "tagalog",
+    "tswana",
+    "tongansk",
+    "tyrkisk",
+    "tsonga",
+    "tatarisk",
+    "twi",
+    "tahitiansk",
+    "uygurisk",
+    "ukrainsk",
+    "urdu",
+    "usbekisk",
+    "venda",
+    "vietnamesisk",
+    "volapyk",
+    "vallonsk",
+    "walisisk",
+    "wolof",
+    "vestfrisisk",
+    "xhosa",
+    "jiddisch",
+    "yoruba",
+    "zhuang",
+    "zulu",
+];
+
+static NL: [&str; 184] = [
+    "Abchazisch",
+    "Afar",
+    "Afrikaans",
+    "Akan",
+    "Albanees",
+    "Amhaars",
+    "Arabisch",
+    "Aragonees",
+    "Armeens",
+    "Assamees",
+    "Avarisch",
+    "Avestisch",
+    "Aymara",
+    "Azerbeidzjaans",
+    "Bambara",
+    "Basjkiers",
+    "Baskisch",
+    "Belarussisch",
+    "Bengaals",
+    "",
+    "Bislama",
+    "Bosnisch",
+    "Bretons",
+    "Bulgaars",
+    "Birmaans",
+    "Catalaans",
+    "Chamorro",
+    "Tsjetsjeens",
+    "Nyanja",
+    "Chinees",
+    "Tsjoevasjisch",
+    "Cornish",
+    "Corsicaans",
+    "Cree",
+    "Kroatisch",
+    "Tsjechisch",
+    "Deens",
+    "Divehi",
+    "Nederlands",
+    "Dzongkha",
+    "Engels",
+    "Esperanto",
+    "Estisch",
+    "Ewe",
+    "Faeröers",
+    "Fijisch",
+    "Fins",
+    "Frans",
+    "Fulah",
+    "Galicisch",
+    "Georgisch",
+    "Duits",
+    "Grieks",
+    "Guaraní",
+    "Gujarati",
+    "Haïtiaans Creools",
+    "Hausa",
+    "Hebreeuws",
+    "Herero",
+    "Hindi",
+    "Hiri Motu",
+    "Hongaars",
+    "Interlingua",
+    "Indonesisch",
+    "Interlingue",
+    "Iers",
+    "Igbo",
+    "Inupiaq",
+    "Ido",
+    "IJslands",
+    "Italiaans",
+    "Inuktitut",
+    "Japans",
+    "Javaans",
+    "Groenlands",
+    "Kannada",
+    "Kanuri",
+    "Kasjmiri",
+    "Kazachs",
+    "Khmer",
+    "Gikuyu",
+    "Kinyarwanda",
+    "Kirgizisch",
+    "Komi",
+    "Kongo",
+    "Koreaans",
+    "Koerdisch",
+    "Kuanyama",
+    "Latijn",
+    "Luxemburgs",
+    "Luganda",
+    "Limburgs",
+    "Lingala",
+    "Laotiaans",
+    "Litouws",
+    "Luba-Katanga",
+    "Lets",
+    "Manx",
+    "Macedonisch",
+    "Malagassisch",
+    "Maleis",
+    "Malayalam",
+    "Maltees",
+    "Maori",
+    "Marathi",
+    "Marshallees",
+    "Mongools",
+    "Nauruaans",
+    "Navajo",
+    "Noord-Ndebele",
+    "Nepalees",
+    "Ndonga",
+    "Noors - Bokmål",
+    "Noors - Nynorsk",
+    "Noors",
+    "Yi",
+    "Zuid-Ndbele",
+    "Occitaans",
+    "Ojibwa",
+    "Kerkslavisch",
+    "Afaan Oromo",
+    "Odia",
+    "Ossetisch",
+    "Punjabi",
+    "Pali",
+    "Perzisch",
+    "Pools",
+    "Pasjtoe",
+    "Portugees",
+    "Quechua",
+    "Reto-Romaans",
+    "Kirundi",
+    "Roemeens",
+    "Russisch",
+    "Sanskriet",
+    "Sardijns",
+    "Sindhi",
+    "Noord-Samisch",
+    "Samoaans",
+    "Sango",
+    "Servisch",
+    "Schots-Gaelisch",
+    "Shona",
+    "Singalees",
+    "Slowaaks",
+    "Sloveens",
+    "Somalisch",
+    "Zuid-Sotho",
+    "Spaans",
+    "Soendanees",
+    "Swahili",
+    "Swazi",
+    "Zweeds",
+    "Tamil",
+    "Telugu",
+    "Tadzjieks",
+    "Thai",
+    "Tigrinya",
+    "Tibetaans",
+    "Turkmeens",
+    "Tagalog",
+    "Tswana",
+    "Tongaans",
+    "Turks",
+    "Tsonga",
+    "Tataars",
+    "Twi",
+    "Tahitiaans",
+    "Oeigoers",
+    "Oekraïens",
+    "Urdu",
+    "Oezbeeks",
+    "Venda",
+    "Vietnamees",
+    "Volapük",
+    "Waals",
+    "Welsh",
+    "Wolof",
+    "Fries",
+    "Xhosa",
+    "Jiddisch",
+    "Yoruba",
+    "Zhuang",
+    "Zoeloe",
+];
+
+static EN: [&str; 184] = [
+    "Abkhazian",
+    "Afar",
+    "Afrikaans",
+    "Akan",
+    "Albanian",
+    "Amharic",
+    "Arabic",
+    "Aragonese",
+    "Armenian",
+    "Assamese",
+    "Avaric",
+    "Avestan",
+    "Aymara",
+    "Azerbaijani",
+    "Bambara",
+    "Bashkir",
+    "Basque",
+    "Belarusian",
+    "Bangla",
+    "",
+    "Bislama",
+    "Bosnian",
+    "Breton",
+    "Bulgarian",
+    "Burmese",
+    "Catalan",
+    "Chamorro",
+    "Chechen",
+    "Nyanja",
+    "Chinese",
+    "Chuvash",
+    "Cornish",
+    "Corsican",
+    "Cree",
+    "Croatian",
+    "Czech",
+    "Danish",
+    "Divehi",
+    "Dutch",
+    "Dzongkha",
+    "English",
+    "Esperanto",
+    "Estonian",
+    "Ewe",
+    "Faroese",
+    "Fijian",
+    "Finnish",
+    "French",
+    "Fula",
+    "Galician",
+    "Georgian",
+    "German",
+    "Greek",
+    "Guarani",
+    "Gujarati",
+    "Haitian Creole",
+    "Hausa",
+    "Hebrew",
+    "Herero",
+    "Hindi",
+    "Hiri Motu",
+    "Hungarian",
+    "Interlingua",
+    "Indonesian",
+    "Interlingue",
+    "Irish",
+    "Igbo",
+    "Inupiaq",
+    "Ido",
+    "Icelandic",
+    "Italian",
+    "Inuktitut",
+    "Japanese",
+    "Javanese",
+    "Kalaallisut",
+    "Kannada",
+    "Kanuri",
+    "Kashmiri",
+    "Kazakh",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kyrgyz",
+    "Komi",
+    "Kongo",
+    "Korean",
+    "Kurdish",
+    "Kuanyama",
+    "Latin",
+    "Luxembourgish",
+    "Ganda",
+    "Limburgish",
+    "Lingala",
+    "Lao",
+    "Lithuanian",
+    "Luba-Katanga",
+    "Latvian",
+    "Manx",
+    "Macedonian",
+    "Malagasy",
+    "Malay",
+    "Malayalam",
+    "Maltese",
+    "Māori",
+    "Marathi",
+    "Marshallese",
+    "Mongolian",
+    "Nauru",
+    "Navajo",
+    "North Ndebele",
+    "Nepali",
+    "Ndonga",
+    "Norwegian Bokmål",
+    "Norwegian Nynorsk",
+    "Norwegian",
+    "Sichuan Yi",
+    "South Ndebele",
+    "Occitan",
+    "Ojibwa",
+    "Church Slavic",
+    "Oromo",
+    "Odia",
+    "Ossetic",
+    "Punjabi",
+    "Pali",
+    "Persian",
+    "Polish",
+    "Pashto",
+    "Portuguese",
+    "Quechua",
+    "Romansh",
+    "Rundi",
+    "Romanian",
+    "Russian",
+    "Sanskrit",
+    "Sardinian",
+    "Sindhi",
+    "Northern Sami",
+    "Samoan",
+    "Sango",
+    "Serbian",
+    "Scottish Gaelic",
+    "Shona",
+    "Sinhala",
+    "Slovak",
+    "Slovenian",
+    "Somali",
+    "Southern Sotho",
+    "Spanish",
+    "Sundanese",
+    "Swahili",
+    "Swati",
+    "Swedish",
+    "Tamil",
+    "Telugu",
+    "Tajik",
+    "Thai",
+    "Tigrinya",
+    "Tibetan",
+    "Turkmen",
+    "Tagalog",
+    "Tswana",
+    "Tongan",
+    "Turkish",
+    "Tsonga",
+    "Tatar",
+    "Twi",
+    "Tahitian",
+    "Uyghur",
+    "Ukrainian",
+    "Urdu",
+    "Uzbek",
+    "Venda",
+    "Vietnamese",
+    "Volapük",
+    "Walloon",
+    "Welsh",
+    "Wolof",
+    "Western Frisian",
+    "Xhosa",
+    "Yiddish",
+    "Yoruba",
+    "Zhuang",
+    "Zulu",
+];
+
+static EO: [&str; 184] = [
+    "abĥaza",
+    "afara",
+    "afrikansa",
+    "",
+    "albana",
+    "amhara",
+    "araba",
+    "",
+    "armena",
+    "asama",
+    "",
+    "",
+    "ajmara",
+    "azerbajĝana",
+    "",
+    "baŝkira",
+    "eŭska",
+    "belorusa",
+    "bengala",
+    "",
+    "bislamo",
+    "bosna",
+    "bretona",
+    "bulgara",
+    "birma",
+    "kataluna",
+    "",
+    "",
+    "",
+    "ĉina",
+    "",
+    "",
+    "korsika",
+    "",
+    "kroata",
+    "ĉeĥa",
+    "dana",
+    "maldiva",
+    "nederlanda",
+    "dzonko",
+    "angla",
+    "Esperanto",
+    "estona",
+    "",
+    "feroa",
+    "fiĝia",
+    "finna",
+    "franca",
+    "",
+    "galega",
+    "kartvela",
+    "germana",
+    "greka",
+    "gvarania",
+    "guĝarata",
+    "haitia kreola",
+    "haŭsa",
+    "hebrea",
+    "",
+    "hinda",
+    "",
+    "hungara",
+    "Interlingvao",
+    "indonezia",
+    "Interlingveo",
+    "irlanda",
+    "",
+    "eskima",
+    "",
+    "islanda",
+    "itala",
+    "inuita",
+    "japana",
+    "java",
+    "gronlanda",
+    "kanara",
+    "",
+    "kaŝmira",
+    "kazaĥa",
+    "kmera",
+    "",
+    "ruanda",
+    "kirgiza",
+    "",
+    "",
+    "korea",
+    "kurda",
+    "",
+    "latino",
+    "luksemburga",
+    "",
+    "",
+    "lingala",
+    "laŭa",
+    "litova",
+    "",
+    "latva",
+    "",
+    "makedona",
+    "malagasa",
+    "malaja",
+    "malajalama",
+    "malta",
+    "maoria",
+    "marata",
+    "",
+    "mongola",
+    "naura",
+    "",
+    "",
+    "nepala",
+    "",
+    "dannorvega",
+    "novnorvega",
+    "norvega",
+    "",
+    "",
+    "okcitana",
+    "",
+    "",
+    "oroma",
+    "orijo",
+    "",
+    "panĝaba",
+    "",
+    "persa",
+    "pola",
+    "paŝtua",
+    "portugala",
+    "keĉua",
+    "romanĉa",
+    "burunda",
+    "rumana",
+    "rusa",
+    "sanskrito",
+    "",
+    "sinda",
+    "",
+    "samoa",
+    "sangoa",
+    "serba",
+    "skot-gaela",
+    "ŝona",
+    "sinhala",
+    "slovaka",
+    "slovena",
+    "somala",
+    "sota",
+    "hispana",
+    "sunda",
+    "svahila",
+    "svazia",
+    "sveda",
+    "tamila",
+    "telugua",
+    "taĝika",
+    "taja",
+    "tigraja",
+    "tibeta",
+    "turkmena",
+    "tagaloga",
+    "cvana",
+    "tongana",
+    "turka",
+    "conga",
+    "tatara",
+    "",
+    "",
+    "ujgura",
+    "ukraina",
+    "urduo",
+    "uzbeka",
+    "",
+    "vjetnama",
+    "Volapuko",
+    "",
+    "kimra",
+    "volofa",
+    "okcident-frisa",
+    "ksosa",
+    "jida",
+    "joruba",
+    "ĝuanga",
+    "zulua",
+];
+
+static ET: [&str; 184] = [
+    "abhaasi",
+    "afari",
+    "afrikaani",
+    "akani",
+    "albaania",
+    "amhara",
+    "araabia",
+    "aragoni",
+    "armeenia",
+    "assami",
+    "avaari",
+    "avesta",
+    "aimara",
+    "aserbaidžaani",
+    "bambara",
+    "baškiiri",
+    "baski",
+    "valgevene",
+    "bengali",
+    "",
+    "bislama",
+    "bosnia",
+    "bretooni",
+    "bulgaaria",
+    "birma",
+    "katalaani",
+    "tšamorro",
+    "tšetšeeni",
+    "njandža",
+    "hiina",
+    "tšuvaši",
+    "korni",
+    "korsika",
+    "krii",
+    "horvaadi",
+    "tšehhi",
+    "taani",
+    "maldiivi",
+    "hollandi",
+    "dzongkha",
+    "inglise",
+    "esperanto",
+    "eesti",
+    "eve",
+    "fääri",
+    "fidži",
+    "soome",
+    "prantsuse",
+    "fula",
+    "galeegi",
+    "gruusia",
+    "saksa",
+    "kreeka",
+    "guaranii",
+    "gudžarati",
+    "haiti",
+    "hausa",
+    "heebrea",
+    "herero",
+    "hindi",
+    "hirimotu",
+    "ungari",
+    "interlingua",
+    "indoneesia",
+    "interlingue",
+    "iiri",
+    "ibo",
+    "injupiaki",
+    "ido",
+    "islandi",
+    "itaalia",
+    "inuktituti",
+    "jaapani",
+    "jaava",
+    "grööni",
+    "kannada",
+    "kanuri",
+    "kašmiiri",
+    "kasahhi",
+    "khmeeri",
+    "kikuju",
+    "ruanda",
+    "kirgiisi",
+    "komi",
+    "kongo",
+    "korea",
+    "kurdi",
+    "kvanjama",
+    "ladina",
+    "letseburgi",
+    "ganda",
+    "limburgi",
+    "lingala",
+    "lao",
+    "leedu",
+    "Katanga luba",
+    "läti",
+    "mänksi",
+    "makedoonia",
+    "malagassi",
+    "malai",
+    "malajalami",
+    "malta",
+    "maoori",
+    "marathi",
+    "maršalli",
+    "mongoli",
+    "nauru",
+    "navaho",
+    "põhjandebele",
+    "nepali",
+    "ndonga",
+    "norra bokmål",
+    "uusnorra",
+    "norra",
+    "nuosu",
+    "lõunandebele",
+    "oksitaani",
+    "odžibvei",
+    "kirikuslaavi",
+    "oromo",
+    "oria",
+    "osseedi",
+    "pandžabi",
+    "paali",
+    "pärsia",
+    "poola",
+    "puštu",
+    "portugali",
+    "ketšua",
+    "romanši",
+    "rundi",
+    "rumeenia",
+    "vene",
+    "sanskriti",
+    "sardi",
+    "sindhi",
+    "põhjasaami",
+    "samoa",
+    "sango",
+    "serbia",
+    "gaeli",
+    "šona",
+    "singali",
+    "slovaki",
+    "sloveeni",
+    "somaali",
+    "lõunasotho",
+    "hispaania",
+    "sunda",
+    "suahiili",
+    "svaasi",
+    "rootsi",
+    "tamili",
+    "telugu",
+    "tadžiki",
+    "tai",
+    "tigrinja",
+    "tiibeti",
+    "türkmeeni",
+    "tagalogi",
+    "tsvana",
+    "tonga",
+    "türgi",
+    "tsonga",
+    "tatari",
+    "tvii",
+    "tahiti",
+    "uiguuri",
+    "ukraina",
+    "urdu",
+    "usbeki",
+    "venda",
+    "vietnami",
+    "volapüki",
+    "vallooni",
+    "kõmri",
+    "volofi",
+    "läänefriisi",
+    "koosa",
+    "jidiši",
+    "joruba",
+    "tšuangi",
+    "suulu",
+];
+
+static EE: [&str; 184] = [
+    "abkhaziagbe",
+    "",
+    "afrikaangbe",
+    "blugbe",
+    "albaniagbe",
+    "amhariagbe",
+    "Arabiagbe",
+    "",
+    "armeniagbe",
+    "assamegbe",
+    "",
+    "",
+    "aymargbe",
+    "azerbaijangbe",
+    "bambaragbe",
+    "",
+    "basqugbe",
+    "belarusiagbe",
+    "Bengaligbe",
+    "",
+    "",
+    "bosniagbe",
+    "bretongbe",
+    "bulgariagbe",
+    "burmagbe",
+    "katalagbe",
+    "",
+    "",
+    "nyanjagbe",
+    "Chinagbe",
+    "",
+    "",
+    "",
+    "",
+    "kroatiagbe",
+    "tsɛkgbe",
+    "denmarkgbe",
+    "divehgbe",
+    "Hollandgbe",
+    "dzongkhagbe",
+    "iŋlisigbe",
+    "esperantogbe",
+    "estoniagbe",
+    "eʋegbe",
+    "",
+    "fidzigbe",
+    "finlanɖgbe",
+    "Fransegbe",
+    "",
+    "galatagbe",
+    "gɔgiagbe",
+    "Germaniagbe",
+    "grisigbe",
+    "guarangbe",
+    "gujarati",
+    "haitigbe",
+    "hausagbe",
+    "hebrigbe",
+    "",
+    "Hindigbe",
+    "",
+    "hungarigbe",
+    "",
+    "Indonesiagbe",
+    "",
+    "irelanɖgbe",
+    "igbogbe",
+    "",
+    "",
+    "icelanɖgbe",
+    "Italiagbe",
+    "",
+    "Japangbe",
+    "dzavangbe",
+    "",
+    "kannadagbe",
+    "",
+    "kashmirgbe",
+    "kazakhstangbe",
+    "khmergbe",
+    "",
+    "ruwandagbe",
+    "kirghistangbe",
+    "",
+    "",
+    "Koreagbe",
+    "kurdiagbe",
+    "",
+    "latin",
+    "laksembɔggbe",
+    "",
+    "",
+    "lingala",
+    "laogbe",
+    "lithuaniagbe",
+    "",
+    "latviagbe",
+    "",
+    "makedoniagbe",
+    "malagasegbe",
+    "malaygbe",
+    "malayagbe",
+    "maltagbe",
+    "maorgbe",
+    "marathiagbe",
+    "",
+    "mongoliagbe",
+    "",
+    "",
+    "dziehe ndebelegbe",
+    "nepalgbe",
+    "",
+    "nɔweigbe bokmål",
+    "nɔweigbe ninɔsk",
+    "nɔweigbe",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "oriyagbe",
+    "ossetiagbe",
+    "pundzabgbe",
+    "",
+    "persiagbe",
+    "Polishgbe",
+    "pashtogbe",
+    "Portuguesegbe",
+    "kwetsuagbe",
+    "romanshgbe",
+    "rundigbe",
+    "romaniagbe",
+    "Russiagbe",
+    "sanskrigbe",
+    "",
+    "sindhgbe",
+    "dziehe samigbe",
+    "samoagbe",
+    "sangogbe",
+    "serbiagbe",
+    "",
+    "shonagbe",
+    "sinhalgbe",
+    "slovakiagbe",
+    "sloveniagbe",
+    "somaliagbe",
+    "anyiehe sothogbe",
+    "Spanishgbe",
+    "",
+    "swahili",
+    "swatgbe",
+    "swedengbe",
+    "tamilgbe",
+    "telegugbe",
+    "tadzikistangbe",
+    "Thailandgbe",
+    "tigrinyagbe",
+    "tibetagbe",
+    "tɛkmengbe",
+    "tagalogbe",
+    "tswanagbe",
+    "tongagbe",
+    "Turkishgbe",
+    "tsongagbe",
+    "",
+    "",
+    "tahitigbe",
+    "uighurgbe",
+    "ukraingbe",
+    "urdugbe",
+    "uzbekistangbe",
+    "vendagbe",
+    "vietnamgbe",
+    "",
+    "",
+    "walesgbe",
+    "wolofgbe",
+    "",
+    "xhosagbe",
+    "",
+    "yorubagbe",
+    "",
+    "zulugbe",
+];
+
+static FO: [&str; 184] = [
+    "abkhasiskt",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albanskt",
+    "amhariskt",
+    "arabiskt",
+    "aragoniskt",
+    "armenskt",
+    "assamesiskt",
+    "avariskt",
+    "",
+    "aymara",
+    "aserbajdsjanskt",
+    "bambara",
+    "bashkir",
+    "baskiskt",
+    "hvitarussiskt",
+    "bangla",
+    "",
+    "bislama",
+    "bosniskt",
+    "bretonskt",
+    "bulgarskt",
+    "burmesiskt",
+    "katalani",
+    "chamorro",
+    "tjetjenskt",
+    "nyanja",
+    "kinesiskt",
+    "chuvash",
+    "corniskt",
+    "korsikanskt",
+    "",
+    "kroatiskt",
+    "kekkiskt",
+    "danskt",
+    "divehi",
+    "hálendskt",
+    "dzongkha",
+    "enskt",
+    "esperanto",
+    "estiskt",
+    "ewe",
+    "føroyskt",
+    "fijimál",
+    "finskt",
+    "franskt",
+    "fulah",
+    "galisiskt",
+    "georgiskt",
+    "týskt",
+    "grikskt",
+    "guarani",
+    "gujarati",
+    "haitiskt creole",
+    "hausa",
+    "hebraiskt",
+    "herero",
+    "hindi",
+    "",
+    "ungarskt",
+    "interlingua",
+    "indonesiskt",
+    "interlingue",
+    "írskt",
+    "igbo",
+    "",
+    "ido",
+    "íslendskt",
+    "italskt",
+    "inuktitut",
+    "japanskt",
+    "javanskt",
+    "kalaallisut",
+    "kannada",
+    "kanuri",
+    "kashmiri",
+    "kazakh",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kyrgyz",
+    "komi",
+    "",
+    "koreanskt",
+    "kurdiskt",
+    "kuanyama",
+    "latín",
+    "luksemborgskt",
+    "ganda",
+    "limburgiskt",
+    "lingala",
+    "laoskt",
+    "litaviskt",
+    "luba-katanga",
+    "lettiskt",
+    "manx",
+    "makedónskt",
+    "malagassiskt",
+    "malaiiskt",
+    "malayalam",
+    "maltiskt",
+    "maori",
+    "marathi",
+    "marshallesiskt",
+    "mongolskt",
+    "nauru",
+    "navajo",
+    "norður ndebele",
+    "nepalskt",
+    "ndonga",
+    "norskt bókmál",
+    "nýnorskt",
+    "norskt",
+    "sichuan yi",
+    "suður ndebele",
+    "occitanskt",
+    "",
+    "kirkju sláviskt",
+    "oromo",
+    "odia",
+    "ossetiskt",
+    "punjabi",
+    "",
+    "persiskt",
+    "pólskt",
+    "pashto",
+    "portugiskiskt",
+    "quechua",
+    "retoromanskt",
+    "rundi",
+    "rumenskt",
+    "russiskt",
+    "sanskrit",
+    "sardiskt",
+    "sindhi",
+    "norður sámiskt",
+    "sámoiskt",
+    "sango",
+    "serbiskt",
+    "skotskt gæliskt",
+    "shona",
+    "singalesiskt",
+    "slovakiskt",
+    "slovenskt",
+    "somaliskt",
+    "sesotho",
+    "spanskt",
+    "sundanesiskt",
+    "swahili",
+    "swatiskt",
+    "svenskt",
+    "tamilskt",
+    "telugu",
+    "tajik",
+    "tailendskt",
+    "tigrinya",
+    "tibetskt",
+    "turkmenskt",
+    "tagalog",
+    "tswana",
+    "tonganskt",
+    "turkiskt",
+    "tsonga",
+    "tatar",
+    "twi",
+    "tahitiskt",
+    "uyghur",
+    "ukrainskt",
+    "urdu",
+    "usbekiskt",
+    "venda",
+    "vjetnamesiskt",
+    "volapykk",
+    "walloon",
+    "walisiskt",
+    "wolof",
+    "vestur frísiskt",
+    "xhosa",
+    "jiddiskt",
+    "yoruba",
+    "zhuang",
+    "sulu",
+];
+
+static FI: [&str; 184] = [
+    "abhaasi",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albania",
+    "amhara",
+    "arabia",
+    "aragonia",
+    "armenia",
+    "assami",
+    "avaari",
+    "avesta",
+    "aimara",
+    "azeri",
+    "bambara",
+    "baškiiri",
+    "baski",
+    "valkovenäjä",
+    "bengali",
+    "",
+    "bislama",
+    "bosnia",
+    "bretoni",
+    "bulgaria",
+    "burma",
+    "katalaani",
+    "tšamorro",
+    "tšetšeeni",
+    "njandža",
+    "kiina",
+    "tšuvassi",
+    "korni",
+    "korsika",
+    "cree",
+    "kroatia",
+    "tšekki",
+    "tanska",
+    "divehi",
+    "hollanti",
+    "dzongkha",
+    "englanti",
+    "esperanto",
+    "viro",
+    "ewe",
+    "fääri",
+    "fidži",
+    "suomi",
+    "ranska",
+    "fulani",
+    "galicia",
+    "georgia",
+    "saksa",
+    "kreikka",
+    "guarani",
+    "gudžarati",
+    "haiti",
+    "hausa",
+    "heprea",
+    "herero",
+    "hindi",
+    "hiri-motu",
+    "unkari",
+    "interlingua",
+    "indonesia",
+    "interlingue",
+    "iiri",
+    "igbo",
+    "inupiaq",
+    "ido",
+    "islanti",
+    "italia",
+    "inuktitut",
+    "japani",
+    "jaava",
+    "kalaallisut",
+    "kannada",
+    "kanuri",
+    "kašmiri",
+    "kazakki",
+    "khmer",
+    "kikuju",
+    "ruanda",
+    "kirgiisi",
+    "komi",
+    "kongo",
+    "korea",
+    "kurdi",
+    "kuanjama",
+    "latina",
+    "luxemburg",
+    "ganda",
+    "limburg",
+    "lingala",
+    "lao",
+    "liettua",
+    "katanganluba",
+    "latvia",
+    "manksi",
+    "makedonia",
+    "malagassi",
+    "malaiji",
+    "malajalam",
+    "malta",
+    "maori",
+    "marathi",
+    "marshall",
+    "mongoli",
+    "nauru",
+    "navajo",
+    "pohjois-ndebele",
+    "nepali",
+    "ndonga",
+    "norjan bokmål",
+    "norjan nynorsk",
+    "norja",
+    "sichuanin-yi",
+    "etelä-ndebele",
+    "oksitaani",
+    "odžibwa",
+    "kirkkoslaavi",
+    "oromo",
+    "orija",
+    "osseetti",
+    "pandžabi",
+    "paali",
+    "persia",
+    "puola",
+    "paštu",
+    "portugali",
+    "ketšua",
+    "retoromaani",
+    "rundi",
+    "romania",
+    "venäjä",
+    "sanskrit",
+    "sardi",
+    "sindhi",
+    "pohjoissaame",
+    "samoa",
+    "sango",
+    "serbia",
+    "gaeli",
+    "šona",
+    "sinhala",
+    "slovakki",
+    "sloveeni",
+    "somali",
+    "eteläsotho",
+    "espanja",
+    "sunda",
+    "swahili",
+    "swazi",
+    "ruotsi",
+    "tamili",
+    "telugu",
+    "tadžikki",
+    "thai",
+    "tigrinja",
+    "tiibet",
+    "turkmeeni",
+    "tagalog",
+    "tswana",
+    "tonga",
+    "turkki",
+    "tsonga",
+    "tataari",
+    "twi",
+    "tahiti",
+    "uiguuri",
+    "ukraina",
+    "urdu",
+    "uzbekki",
+    "venda",
+    "vietnam",
+    "volapük",
+    "valloni",
+    "kymri",
+    "wolof",
+    "länsifriisi",
+    "xhosa",
+    "jiddiš",
+    "joruba",
+    "zhuang",
+    "zulu",
+];
+
+static FR: [&str; 184] = [
+    "abkhaze",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albanais",
+    "amharique",
+    "arabe",
+    "aragonais",
+    "arménien",
+    "assamais",
+    "avar",
+    "avestique",
+    "aymara",
+    "azerbaïdjanais",
+    "bambara",
+    "bachkir",
+    "basque",
+    "biélorusse",
+    "bengali",
+    "",
+    "bichelamar",
+    "bosniaque",
+    "breton",
+    "bulgare",
+    "birman",
+    "catalan",
+    "chamorro",
+    "tchétchène",
+    "chewa",
+    "chinois",
+    "tchouvache",
+    "cornique",
+    "corse",
+    "cree",
+    "croate",
+    "tchèque",
+    "danois",
+    "maldivien",
+    "néerlandais",
+    "dzongkha",
+    "anglais",
+    "espéranto",
+    "estonien",
+    "éwé",
+    "féroïen",
+    "fidjien",
+    "finnois",
+    "français",
+    "peul",
+    "galicien",
+    "géorgien",
+    "allemand",
+    "grec",
+    "guarani",
+    "goudjarati",
+    "créole haïtien",
+    "haoussa",
+    "hébreu",
+    "héréro",
+    "hindi",
+    "hiri motu",
+    "hongrois",
+    "interlingua",
+    "indonésien",
+    "interlingue",
+    "irlandais",
+    "igbo",
+    "inupiaq",
+    "ido",
+    "islandais",
+    "italien",
+    "inuktitut",
+    "japonais",
+    "javanais",
+    "groenlandais",
+    "kannada",
+    "kanouri",
+    "cachemiri",
+    "kazakh",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirghize",
+    "komi",
+    "kikongo",
+    "coréen",
+    "kurde",
+    "kuanyama",
+    "latin",
+    "luxembourgeois",
+    "ganda",
+    "limbourgeois",
+    "lingala",
+    "lao",
+    "lituanien",
+    "luba-katanga (kiluba)",
+    "letton",
+    "mannois",
+    "macédonien",
+    "malgache",
+    "malais",
+    "malayalam",
+    "maltais",
+    "maori",
+    "marathi",
+    "marshallais",
+    "mongol",
+    "nauruan",
+    "navajo",
+    "ndébélé du Nord",
+    "népalais",
+    "ndonga",
+    "norvégien bokmål",
+    "norvégien nynorsk",
+    "norvégien",
+    "yi du Sichuan",
+    "ndébélé du Sud",
+    "occitan",
+    "ojibwa",
+    "slavon d’église",
+    "oromo",
+    "odia",
+    "ossète",
+    "pendjabi",
+    "pali",
+    "persan",
+    "polonais",
+    "pachto",
+    "portugais",
+    "quechua",
+    "romanche",
+    "roundi",
+    "roumain",
+    "russe",
+    "sanskrit",
+    "sarde",
+    "sindhi",
+    "same du Nord",
+    "samoan",
+    "sango",
+    "serbe",
+    "gaélique écossais",
+    "shona",
+    "cingalais",
+    "slovaque",
+    "slovène",
+    "somali",
+    "sotho du Sud",
+    "espagnol",
+    "soundanais",
+    "swahili",
+    "swati",
+    "suédois",
+    "tamoul",
+    "télougou",
+    "tadjik",
+    "thaï",
+    "tigrigna",
+    "tibétain",
+    "turkmène",
+    "tagalog",
+    "tswana",
+    "tongien",
+    "turc",
+    "tsonga",
+    "tatar",
+    "twi",
+    "tahitien",
+    "ouïghour",
+    "ukrainien",
+    "ourdou",
+    "ouzbek",
+    "venda",
+    "vietnamien",
+    "volapük",
+    "wallon",
+    "gallois",
+    "wolof",
+    "frison occidental",
+    "xhosa",
+    "yiddish",
+    "yoruba",
+    "zhuang",
+    "zoulou",
+];
+
+static GL: [&str; 184] = [
+    "abkhazo",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albanés",
+    "amhárico",
+    "árabe",
+    "aragonés",
+    "armenio",
+    "assamés",
+    "avar",
+    "",
+    "aimará",
+    "acerbaixano",
+    "bambara",
+    "baxkir",
+    "éuscaro",
+    "belaruso",
+    "bengalí",
+    "",
+    "bislama",
+    "bosníaco",
+    "bretón",
+    "búlgaro",
+    "birmano",
+    "catalán",
+    "chamorro",
+    "checheno",
+    "chewa",
+    "chinés",
+    "chuvaxo",
+    "córnico",
+    "corso",
+    "",
+    "croata",
+    "checo",
+    "dinamarqués",
+    "divehi",
+    "neerlandés",
+    "dzongkha",
+    "inglés",
+    "esperanto",
+    "estoniano",
+    "ewe",
+    "feroés",
+    "fixiano",
+    "finés",
+    "francés",
+    "fula",
+    "galego",
+    "xeorxiano",
+    "alemán",
+    "grego",
+    "guaraní",
+    "guxarati",
+    "crioulo haitiano",
+    "hausa",
+    "hebreo",
+    "herero",
+    "hindi",
+    "",
+    "húngaro",
+    "interlingua",
+    "indonesio",
+    "occidental",
+    "irlandés",
+    "igbo",
+    "",
+    "ido",
+    "islandés",
+    "italiano",
+    "inuktitut",
+    "xaponés",
+    "xavanés",
+    "kalaallisut",
+    "kannará",
+    "kanuri",
+    "caxemirés",
+    "kazako",
+    "khmer",
+    "kikuyu",
+    "kiñaruanda",
+    "kirguiz",
+    "komi",
+    "kongo",
+    "coreano",
+    "kurdo",
+    "kuanyama",
+    "latín",
+    "luxemburgués",
+    "ganda",
+    "limburgués",
+    "lingala",
+    "laosiano",
+    "lituano",
+    "luba-katanga",
+    "letón",
+    "manx",
+    "macedonio",
+    "malgaxe",
+    "malaio",
+    "malabar",
+    "maltés",
+    "maorí",
+    "marathi",
+    "marshalés",
+    "mongol",
+    "nauruano",
+    "navajo",
+    "ndebele setentrional",
+    "nepalí",
+    "ndonga",
+    "noruegués bokmål",
+    "noruegués nynorsk",
+    "noruegués",
+    "yi sichuanés",
+    "ndebele meridional",
+    "occitano",
+    "",
+    "eslavo eclesiástico",
+    "oromo",
+    "odiá",
+    "ossetio",
+    "panxabí",
+    "",
+    "persa",
+    "polaco",
+    "paxto",
+    "portugués",
+    "quechua",
+    "romanche",
+    "rundi",
+    "romanés",
+    "ruso",
+    "sánscrito",
+    "sardo",
+    "sindhi",
+    "saami setentrional",
+    "samoano",
+    "sango",
+    "serbio",
+    "gaélico escocés",
+    "shona",
+    "cingalés",
+    "eslovaco",
+    "esloveno",
+    "somalí",
+    "sesotho",
+    "español",
+    "sundanés",
+    "suahili",
+    "suazi",
+    "sueco",
+    "támil",
+    "telugu",
+    "taxico",
+    "tailandés",
+    "tigriña",
+    "tibetano",
+    "turkmeno",
+    "tagalo",
+    "tswana",
+    "tongano",
+    "turco",
+    "tsonga",
+    "tártaro",
+    "twi",
+    "tahitiano",
+    "uigur",
+    "ucraíno",
+    "urdú",
+    "uzbeko",
+    "venda",
+    "vietnamita",
+    "volapuk",
+    "valón",
+    "galés",
+    "wólof",
+    "frisón occidental",
+    "xhosa",
+    "yiddish",
+    "ioruba",
+    "zhuang",
+    "zulú",
+];
+
+static KA: [&str; 184] = [
+    "აფხაზური",
+    "აფარი",
+    "აფრიკაანსი",
+    "აკანი",
+    "ალბანური",
+    "ამჰარული",
+    "არაბული",
+    "არაგონული",
+    "სომხური",
+    "ასამური",
+    "ხუნძური",
+    "ავესტური",
+    "აიმარა",
+    "აზერბაიჯანული",
+    "ბამბარა",
+    "ბაშკირული",
+    "ბასკური",
+    "ბელარუსული",
+    "ბენგალური",
+    "",
+    "ბისლამა",
+    "ბოსნიური",
+    "ბრეტონული",
+    "ბულგარული",
+    "ბირმული",
+    "კატალანური",
+    "ჩამორო",
+    "ჩეჩნური",
+    "ნიანჯა",
+    "ჩინური",
+    "ჩუვაშური",
+    "კორნული",
+    "კორსიკული",
+    "კრი",
+    "ხორვატული",
+    "ჩეხური",
+    "დანიური",
+    "დივეჰი",
+    "ნიდერლანდური",
+    "ძონგკხა",
+    "ინგლისური",
+    "ესპერანტო",
+    "ესტონური",
+    "ევე",
+    "ფარერული",
+    "ფიჯი",
+    "ფინური",
+    "ფრანგული",
+    "ფულა",
+    "გალისიური",
+    "ქართული",
+    "გერმანული",
+    "ბერძნული",
+    "გუარანი",
+    "გუჯარათი",
+    "ჰაიტიური კრეოლი",
+    "ჰაუსა",
+    "ებრაული",
+    "ჰერერო",
+    "ჰინდი",
+    "",
+    "უნგრული",
+    "ინტერლინგუალური",
+    "ინდონეზიური",
+    "ინტერლინგი",
+    "ირლანდიური",
+    "იგბო",
+    "",
+    "იდო",
+    "ისლანდიური",
+    "იტალიური",
+    "ინუკტიტუტი",
+    "იაპონური",
+    "იავური",
+    "დასავლეთ გრენლანდიური",
+    "კანადა",
+    "კანური",
+    "ქაშმირული",
+    "ყაზახური",
+    "ქმერული",
+    "კიკუიუ",
+    "კინიარუანდა",
+    "ყირგიზული",
+    "კომი",
+    "კონგო",
+    "კორეული",
+    "ქურთული",
+    "კუნამა",
+    "ლათინური",
+    "ლუქსემბურგული",
+    "განდა",
+    "ლიმბურგული",
+    "ლინგალა",
+    "ლაოსური",
+    "ლიეტუვური",
+    "ლუბა-კატანგა",
+    "ლატვიური",
+    "მენური",
+    "მაკედონური",
+    "მალაგასიური",
+    "მალაიური",
+    "მალაიალამური",
+    "მალტური",
+    "მაორი",
+    "მარათჰი",
+    "მარშალური",
+    "მონღოლური",
+    "ნაურუ",
+    "ნავახო",
+    "ჩრდილოეთ ნდებელე",
+    "ნეპალური",
+    "ნდონგა",
+    "ნორვეგიული ბუკმოლი",
+    "ნორვეგიული ნიუნორსკი",
+    "ნორვეგიული",
+    "სიჩუანის ი",
+    "სამხრეთ ნდებელური",
+    "ოქსიტანური",
+    "ოჯიბვე",
+    "საეკლესიო სლავური",
+    "ორომო",
+    "ორია",
+    "ოსური",
+    "პენჯაბური",
+    "პალი",
+    "სპარსული",
+    "პოლონური",
+    "პუშტუ",
+    "პორტუგალიური",
+    "კეჩუა",
+    "რეტორომანული",
+    "რუნდი",
+    "რუმინული",
+    "რუსული",
+    "სანსკრიტი",
+    "სარდინიული",
+    "სინდჰური",
+    "ჩრდილოეთ საამური",
+    "სამოა",
+    "სანგო",
+    "სერბული",
+    "შოტლანდიური გელური",
+    "შონა",
+    "სინჰალური",
+    "სლოვაკური",
+    "სლოვენური",
+    "სომალიური",
+    "სამხრეთ სოთოს ენა",
+    "ესპანური",
+    "სუნდური",
+    "სუაჰილი",
+    "სუატი",
+    "შვედური",
+    "ტამილური",
+    "ტელუგუ",
+    "ტაჯიკური",
+    "ტაილანდური",
+    "ტიგრინია",
+    "ტიბეტური",
+    "თურქმენული",
+    "",
+    "ტსვანა",
+    "ტონგანური",
+    "თურქული",
+    "ტსონგა",
+    "თათრული",
+    "თუი",
+    "ტაიტური",
+    "უიღურული",
+    "უკრაინული",
+    "ურდუ",
+    "უზბეკური",
+    "ვენდა",
+    "ვიეტნამური",
+    "ვოლაპუკი",
+    "ვალონური",
+    "უელსური",
+    "ვოლოფური",
+    "დასავლეთფრიზიული",
+    "ქჰოსა",
+    "იდიში",
+    "იორუბა",
+    "ზჰუანგი",
+    "ზულუ",
+];
+
+static DE: [&str; 184] = [
+    "Abchasisch",
+    "Afar",
+    "Afrikaans",
+    "Akan",
+    "Albanisch",
+    "Amharisch",
+    "Arabisch",
+    "Aragonesisch",
+    "Armenisch",
+    "Assamesisch",
+    "Awarisch",
+    "Avestisch",
+    "Aymara",
+    "Aserbaidschanisch",
+    "Bambara",
+    "Baschkirisch",
+    "Baskisch",
+    "Belarussisch",
+    "Bengalisch",
+    "",
+    "Bislama",
+    "Bosnisch",
+    "Bretonisch",
+    "Bulgarisch",
+    "Birmanisch",
+    "Katalanisch",
+    "Chamorro",
+    "Tschetschenisch",
+    "Nyanja",
+    "Chinesisch",
+    "Tschuwaschisch",
+    "Kornisch",
+    "Korsisch",
+    "Cree",
+    "Kroatisch",
+    "Tschechisch",
+    "Dänisch",
+    "Dhivehi",
+    "Niederländisch",
+    "Dzongkha",
+    "Englisch",
+    "Esperanto",
+    "Estnisch",
+    "Ewe",
+    "Färöisch",
+    "Fidschi",
+    "Finnisch",
+    "Französisch",
+    "Ful",
+    "Galicisch",
+    "Georgisch",
+    "Deutsch",
+    "Griechisch",
+    "Guaraní",
+    "Gujarati",
+    "Haiti-Kreolisch",
+    "Haussa",
+    "Hebräisch",
+    "Herero",
+    "Hindi",
+    "Hiri-Motu",
+    "Ungarisch",
+    "Interlingua",
+    "Indonesisch",
+    "Interlingue",
+    "Irisch",
+    "Igbo",
+    "Inupiak",
+    "Ido",
+    "Isländisch",
+    "Italienisch",
+    "Inuktitut",
+    "Japanisch",
+    "Javanisch",
+    "Grönländisch",
+    "Kannada",
+    "Kanuri",
+    "Kaschmiri",
+    "Kasachisch",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kirgisisch",
+    "Komi",
+    "Kongolesisch",
+    "Koreanisch",
+    "Kurdisch",
+    "Kwanyama",
+    "Latein",
+    "Luxemburgisch",
+    "Ganda",
+    "Limburgisch",
+    "Lingala",
+    "Laotisch",
+    "Litauisch",
+    "Luba-Katanga",
+    "Lettisch",
+    "Manx",
+    "Mazedonisch",
+    "Malagasy",
+    "Malaiisch",
+    "Malayalam",
+    "Maltesisch",
+    "Māori",
+    "Marathi",
+    "Marschallesisch",
+    "Mongolisch",
+    "Nauruisch",
+    "Navajo",
+    "Nord-Ndebele",
+    "Nepalesisch",
+    "Ndonga",
+    "Norwegisch (Bokmål)",
+    "Norwegisch (Nynorsk)",
+    "Norwegisch",
+    "Yi",
+    "Süd-Ndebele",
+    "Okzitanisch",
+    "Ojibwa",
+    "Kirchenslawisch",
+    "Oromo",
+    "Oriya",
+    "Ossetisch",
+    "Punjabi",
+    "Pali",
+    "Persisch",
+    "Polnisch",
+    "Paschtu",
+    "Portugiesisch",
+    "Quechua",
+    "Rätoromanisch",
+    "Rundi",
+    "Rumänisch",
+    "Russisch",
+    "Sanskrit",
+    "Sardisch",
+    "Sindhi",
+    "Nordsamisch",
+    "Samoanisch",
+    "Sango",
+    "Serbisch",
+    "Gälisch (Schottland)",
+    "Shona",
+    "Singhalesisch",
+    "Slowakisch",
+    "Slowenisch",
+    "Somali",
+    "Süd-Sotho",
+    "Spanisch",
+    "Sundanesisch",
+    "Suaheli",
+    "Swazi",
+    "Schwedisch",
+    "Tamil",
+    "Telugu",
+    "Tadschikisch",
+    "Thailändisch",
+    "Tigrinya",
+    "Tibetisch",
+    "Turkmenisch",
+    "Tagalog",
+    "Tswana",
+    "Tongaisch",
+    "Türkisch",
+    "Tsonga",
+    "Tatarisch",
+    "Twi",
+    "Tahitisch",
+    "Uigurisch",
+    "Ukrainisch",
+    "Urdu",
+    "Usbekisch",
+    "Venda",
+    "Vietnamesisch",
+    "Volapük",
+    "Wallonisch",
+    "Walisisch",
+    "Wolof",
+    "Westfriesisch",
+    "Xhosa",
+    "Jiddisch",
+    "Yoruba",
+    "Zhuang",
+    "Zulu",
+];
+
+static EL: [&str; 184] = [
+    "Αμπχαζικά",
+    "Αφάρ",
+    "Αφρικάανς",
+    "Ακάν",
+    "Αλβανικά",
+    "Αμχαρικά",
+    "Αραβικά",
+    "Αραγονικά",
+    "Αρμενικά",
+    "Ασαμικά",
+    "Αβαρικά",
+    "Αβεστάν",
+    "Αϊμάρα",
+    "Αζερμπαϊτζανικά",
+    "Μπαμπάρα",
+    "Μπασκίρ",
+    "Βασκικά",
+    "Λευκορωσικά",
+    "Βεγγαλικά",
+    "",
+    "Μπισλάμα",
+    "Βοσνιακά",
+    "Βρετονικά",
+    "Βουλγαρικά",
+    "Βιρμανικά",
+    "Καταλανικά",
+    "Τσαμόρο",
+    "Τσετσενικά",
+    "Νιάντζα",
+    "Κινεζικά",
+    "Τσουβασικά",
+    "Κορνουαλικά",
+    "Κορσικανικά",
+    "Κρι",
+    "Κροατικά",
+    "Τσεχικά",
+    "Δανικά",
+    "Ντιβέχι",
+    "Ολλανδικά",
+    "Ντζόνγκχα",
+    "Αγγλικά",
+    "Εσπεράντο",
+    "Εσθονικά",
+    "Έουε",
+    "Φεροϊκά",
+    "Φίτζι",
+    "Φινλανδικά",
+    "Γαλλικά",
+    "Φουλά",
+    "Γαλικιανά",
+    "Γεωργιανά",
+    "Γερμανικά",
+    "Ελληνικά",
+    "Γκουαρανί",
+    "Γκουτζαρατικά",
+    "Αϊτιανά",
+    "Χάουσα",
+    "Εβραϊκά",
+    "Χερέρο",
+    "Χίντι",
+    "Χίρι Μότου",
+    "Ουγγρικά",
+    "Ιντερλίνγκουα",
+    "Ινδονησιακά",
+    "Ιντερλίνγκουε",
+    "Ιρλανδικά",
+    "Ίγκμπο",
+    "Ινουπιάκ",
+    "Ίντο",
+    "Ισλανδικά",
+    "Ιταλικά",
+    "Ινούκτιτουτ",
+    "Ιαπωνικά",
+    "Ιαβανικά",
+    "Καλαάλισουτ",
+    "Κανάντα",
+    "Κανούρι",
+    "Κασμιρικά",
+    "Καζακικά",
+    "Χμερ",
+    "Κικούγιου",
+    "Κινιαρουάντα",
+    "Κιργιζικά",
+    "Κόμι",
+    "Κονγκό",
+    "Κορεατικά",
+    "Κουρδικά",
+    "Κουανιάμα",
+    "Λατινικά",
+    "Λουξεμβουργιανά",
+    "Γκάντα",
+    "Λιμβουργιανά",
+    "Λινγκάλα",
+    "Λαοτινά",
+    "Λιθουανικά",
+    "Λούμπα-Κατάνγκα",
+    "Λετονικά",
+    "Μανξ",
+    "Σλαβομακεδονικά",
+    "Μαλγασικά",
+    "Μαλαισιανά",
+    "Μαλαγιαλαμικά",
+    "Μαλτεζικά",
+    "Μαορί",
+    "Μαραθικά",
+    "Μαρσαλέζικα",
+    "Μογγολικά",
+    "Ναούρου",
+    "Νάβαχο",
+    "Βόρεια Ντεμπέλε",
+    "Νεπαλικά",
+    "Ντόνγκα",
+    "Νορβηγικά Μποκμάλ",
+    "Νορβηγικά Νινόρσκ",
+    "Νορβηγικά",
+    "Σίτσουαν Γι",
+    "Νότια Ντεμπέλε",
+    "Οξιτανικά",
+    "Οζιβίγουα",
+    "Εκκλησιαστικά Σλαβικά",
+    "Ορόμο",
+    "Όντια",
+    "Οσετικά",
+    "Παντζαπικά",
+    "Πάλι",
+    "Περσικά",
+    "Πολωνικά",
+    "Πάστο",
+    "Πορτογαλικά",
+    "Κέτσουα",
+    "Ρομανικά",
+    "Ρούντι",
+    "Ρουμανικά",
+    "Ρωσικά",
+    "Σανσκριτικά",
+    "Σαρδηνιακά",
+    "Σίντι",
+    "Βόρεια Σάμι",
+    "Σαμοανά",
+    "Σάνγκο",
+    "Σερβικά",
+    "Σκωτικά Κελτικά",
+    "Σόνα",
+    "Σινχαλεζικά",
+    "Σλοβακικά",
+    "Σλοβενικά",
+    "Σομαλικά",
+    "Νότια Σόθο",
+    "Ισπανικά",
+    "Σουνδανικά",
+    "Σουαχίλι",
+    "Σουάτι",
+    "Σουηδικά",
+    "Ταμιλικά",
+    "Τελούγκου",
+    "Τατζικικά",
+    "Ταϊλανδικά",
+    "Τιγκρινικά",
+    "Θιβετιανά",
+    "Τουρκμενικά",
+    "Τάγκαλογκ",
+    "Τσουάνα",
+    "Τονγκανικά",
+    "Τουρκικά",
+    "Τσόνγκα",
+    "Ταταρικά",
+    "Τούι",
+    "Ταϊτιανά",
+    "Ουιγουρικά",
+    "Ουκρανικά",
+    "Ούρντου",
+    "Ουζμπεκικά",
+    "Βέντα",
+    "Βιετναμικά",
+    "Βολαπιούκ",
+    "Βαλλωνικά",
+    "Ουαλικά",
+    "Γουόλοφ",
+    "Δυτικά Φριζικά",
+    "Κόσα",
+    "Γίντις",
+    "Γιορούμπα",
+    "Ζουάνγκ",
+    "Ζουλού",
+];
+
+static GU: [&str; 184] = [
+    "અબખાજિયન",
+    "અફાર",
+    "આફ્રિકન્સ",
+    "અકાન",
+    "અલ્બેનિયન",
+    "એમ્હારિક",
+    "અરબી",
+    "અર્ગોનીઝ",
+    "આર્મેનિયન",
+    "આસામી",
+    "અવેરિક",
+    "અવેસ્તન",
+    "આયમારા",
+    "અઝરબૈજાની",
+    "બામ્બારા",
+    "બશ્કીર",
+    "બાસ્ક",
+    "બેલારુશિયન",
+    "બાંગ્લા",
+    "",
+    "બિસ્લામા",
+    "બોસ્નિયન",
+    "બ્રેટોન",
+    "બલ્ગેરિયન",
+    "બર્મીઝ",
+    "કતલાન",
+    "કેમોરો",
+    "ચેચન",
+    "ન્યાન્જા",
+    "ચાઇનીઝ",
+    "ચૂવાશ",
+    "કોર્નિશ",
+    "કોર્સિકન",
+    "ક્રી",
+    "ક્રોએશિયન",
+    "ચેક",
+    "ડેનિશ",
+    "દિવેહી",
+    "ડચ",
+    "ડ્ઝોંગ્ખા",
+    "અંગ્રેજી",
+    "એસ્પેરાન્ટો",
+    "એસ્ટોનિયન",
+    "ઈવ",
+    "ફોરિસ્ત",
+    "ફીજીયન",
+    "ફિનિશ",
+    "ફ્રેન્ચ",
+    "ફુલા",
+    "ગેલિશિયન",
+    "જ્યોર્જિયન",
+    "જર્મન",
+    "ગ્રીક",
+    "ગુઆરાની",
+    "ગુજરાતી",
+    "હૈતિઅન ક્રેઓલે",
+    "હૌસા",
+    "હીબ્રુ",
+    "હેરેરો",
+    "હિન્દી",
+    "હિરી મોટૂ",
+    "હંગેરિયન",
+    "ઇંટરલિંગુઆ",
+    "ઇન્ડોનેશિયન",
+    "ઇંટરલિંગ",
+    "આઇરિશ",
+    "ઇગ્બો",
+    "ઇનુપિયાક",
+    "ઈડો",
+    "આઇસલેન્ડિક",
+    "ઇટાલિયન",
+    "ઇનુકિટૂટ",
+    "જાપાનીઝ",
+    "જાવાનીસ",
+    "કલાલ્લિસુત",
+    "કન્નડ",
+    "કનુરી",
+    "કાશ્મીરી",
+    "કઝાખ",
+    "ખ્મેર",
+    "કિકુયૂ",
+    "કિન્યારવાન્ડા",
+    "કિર્ગીઝ",
+    "કોમી",
+    "કોંગો",
+    "કોરિયન",
+    "કુર્દિશ",
+    "ક્વાન્યામા",
+    "લેટિન",
+    "લક્ઝેમબર્ગિશ",
+    "ગાંડા",
+    "લિંબૂર્ગિશ",
+    "લિંગાલા",
+    "લાઓ",
+    "લિથુઆનિયન",
+    "લૂબા-કટાંગા",
+    "લાતવિયન",
+    "માંક્સ",
+    "મેસેડોનિયન",
+    "મલાગસી",
+    "મલય",
+    "મલયાલમ",
+    "માલ્ટિઝ",
+    "માઓરી",
+    "મરાઠી",
+    "માર્શલીઝ",
+    "મોંગોલિયન",
+    "નાઉરૂ",
+    "નાવાજો",
+    "ઉત્તર દેબેલ",
+    "નેપાળી",
+    "ડોન્ગા",
+    "નોર્વેજિયન બોકમાલ",
+    "નોર્વેજિયન નાયનૉર્સ્ક",
+    "નૉર્વેજીયન",
+    "સિચુઆન યી",
+    "દક્ષિણ દેબેલ",
+    "ઓક્સિટન",
+    "ઓજિબ્વા",
+    "ચર્ચ સ્લાવિક",
+    "ઓરોમો",
+    "ઉડિયા",
+    "ઓસ્સેટિક",
+    "પંજાબી",
+    "પાલી",
+    "ફારસી",
+    "પોલીશ",
+    "પશ્તો",
+    "પોર્ટુગીઝ",
+    "ક્વેચુઆ",
+    "રોમાન્શ",
+    "રૂન્દી",
+    "રોમાનિયન",
+    "રશિયન",
+    "સંસ્કૃત",
+    "સાર્દિનિયન",
+    "સિંધી",
+    "ઉત્તરી સામી",
+    "સામોન",
+    "સાંગો",
+    "સર્બિયન",
+    "સ્કોટીસ ગેલિક",
+    "શોના",
+    "સિંહાલી",
+    "સ્લોવૅક",
+    "સ્લોવેનિયન",
+    "સોમાલી",
+    "દક્ષિણ સોથો",
+    "સ્પેનિશ",
+    "સંડેનીઝ",
+    "સ્વાહિલી",
+    "સ્વાતી",
+    "સ્વીડિશ",
+    "તમિલ",
+    "તેલુગુ",
+    "તાજીક",
+    "થાઈ",
+    "ટાઇગ્રિનિયા",
+    "તિબેટીયન",
+    "તુર્કમેન",
+    "ટાગાલોગ",
+    "ત્સ્વાના",
+    "ટોંગાન",
+    "ટર્કિશ",
+    "સોંગા",
+    "તતાર",
+    "ટ્વાઇ",
+    "તાહિતિયન",
+    "ઉઇગુર",
+    "યુક્રેનિયન",
+    "ઉર્દૂ",
+    "ઉઝ્બેક",
+    "વેન્દા",
+    "વિયેતનામીસ",
+    "વોલાપુક",
+    "વાલૂન",
+    "વેલ્શ",
+    "વોલોફ",
+    "પશ્ચિમિ ફ્રિશિયન",
+    "ખોસા",
+    "યિદ્દિશ",
+    "યોરૂબા",
+    "ઝુઆગ",
+    "ઝુલુ",
+];
+
+static HA: [&str; 184] = [
+    "Abkhazian",
+    "",
+    "Afirkanci",
+    "Akan",
+    "Albaniyanci",
+    "Amharik",
+    "Larabci",
+    "Aragonesanci",
+    "Armeniyanci",
+    "Asamisanci",
+    "Avaric",
+    "",
+    "Aymaranci",
+    "Azerbaijanci",
+    "Bambara",
+    "Bashkir",
+    "Basque",
+    "Belarusanci",
+    "Bengali",
+    "",
+    "Bislama",
+    "Bosniyanci",
+    "Buretananci",
+    "Bulgariyanci",
+    "Burmanci",
+    "Kataloniyanci",
+    "Chamorro",
+    "Chechen",
+    "Nyanja",
+    "Harshen Sinanci",
+    "Chuvash",
+    "Cornish",
+    "Corsican",
+    "",
+    "Kuroshiyan",
+    "Cek",
+    "Danish",
+    "Divehi",
+    "Holanci",
+    "Dzongkha",
+    "Turanci",
+    "Esperanto",
+    "Istoniyanci",
+    "Ewe",
+    "Faroese",
+    "Fijiyanci",
+    "Yaren mutanen Finland",
+    "Faransanci",
+    "Fula",
+    "Bagalike",
+    "Jojiyanci",
+    "Jamusanci",
+    "Girkanci",
+    "Guwaraniyanci",
+    "Gujarati",
+    "Haitian Creole",
+    "Hausa",
+    "Ibrananci",
+    "Herero",
+    "Harshen Hindi",
+    "",
+    "Harshen Hungari",
+    "Yare Tsakanin Kasashe",
+    "Harshen Indunusiya",
+    "Intagulanci",
+    "Dan Irish",
+    "Igbo",
+    "",
+    "Ido",
+    "Yaren mutanen Iceland",
+    "Italiyanci",
+    "Inuktitut",
+    "Japananci",
+    "Javananci",
+    "Kalaallisut",
+    "Kannada",
+    "Kanuri",
+    "Kashmiri",
+    "Kazakh",
+    "Harshen Kimar",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kirgizanci",
+    "Komi",
+    "",
+    "Harshen Koriya",
+    "Kurdanci",
+    "Kuanyama",
+    "Dan Kabilar Latin",
+    "Luxembourgish",
+    "Ganda",
+    "Limburgish",
+    "Lingala",
+    "Lao",
+    "Lituweniyanci",
+    "Luba-Katanga",
+    "Latbiyanci",
+    "Manx",
+    "Dan Masedoniya",
+    "Malagasi",
+    "Harshen Malay",
+    "Malayalamci",
+    "Harshen Maltis",
+    "Maori",
+    "Maratinci",
+    "Marshallese",
+    "Mongoliyanci",
+    "Nauru",
+    "Navajo",
+    "North Ndebele",
+    "Nepali",
+    "Ndonga",
+    "Norwegian Bokmål",
+    "Norwegian Nynorsk",
+    "Harhsen Norway",
+    "Sichuan Yi",
+    "Ndebele na Kudu",
+    "Ositanci",
+    "",
+    "Church Slavic",
+    "Oromo",
+    "Odiya",
+    "Ossetic",
+    "Punjabi",
+    "",
+    "Farisanci",
+    "Harshen Polan",
+    "Pashtanci",
+    "Harshen Potugis",
+    "Quechua",
+    "Romansh",
+    "Rundi",
+    "Romaniyanci",
+    "Rashanci",
+    "Sanskrit",
+    "Sardiniyanci",
+    "Sindiyanci",
+    "Sami ta Arewa",
+    "Samoan",
+    "Sango",
+    "Sabiyan",
+    "Kʼabilan Scots Gaelic",
+    "Shona",
+    "Sinhalanci",
+    "Basulke",
+    "Basulabe",
+    "Somalianci",
+    "Sesotanci",
+    "Sifaniyanci",
+    "Harshen Sundanese",
+    "Harshen Suwahili",
+    "Swati",
+    "Harshen Suwedan",
+    "Tamil",
+    "Telugu",
+    "Tajik",
+    "Thai",
+    "Tigrinyanci",
+    "Tibetan",
+    "Tukmenistanci",
+    "",
+    "Tswana",
+    "Tonganci",
+    "Harshen Turkiyya",
+    "Tsonga",
+    "Tatar",
+    "Tiwiniyanci",
+    "Tahitiyanci",
+    "Ugiranci",
+    "Harshen Yukuren",
+    "Urdanci",
+    "Uzbek",
+    "Venda",
+    "Harshen Biyetinam",
+    "Volapük",
+    "Walloon",
+    "Welsh",
+    "Wolof",
+    "Frisian ta Yamma",
+    "Xhosa",
+    "Yaren Yiddish",
+    "Yarbanci",
+    "Zhuang",
+    "Harshen Zulu",
+];
+
+static HE: [&str; 184] = [
+    "אבחזית",
+    "אפארית",
+    "אפריקאנס",
+    "אקאן",
+    "אלבנית",
+    "אמהרית",
+    "ערבית",
+    "אראגונית",
+    "ארמנית",
+    "אסאמית",
+    "אווארית",
+    "אבסטן",
+    "איימארית",
+    "אזרית",
+    "במבארה",
+    "בשקירית",
+    "בסקית",
+    "בלארוסית",
+    "בנגלית",
+    "",
+    "ביסלמה",
+    "בוסנית",
+    "ברטונית",
+    "בולגרית",
+    "בורמזית",
+    "קטלאנית",
+    "צ׳מורו",
+    "צ׳צ׳נית",
+    "ניאנג׳ה",
+    "סינית",
+    "צ׳ובאש",
+    "קורנית",
+    "קורסיקנית",
+    "קרי",
+    "קרואטית",
+    "צ׳כית",
+    "דנית",
+    "דיבהי",
+    "הולנדית",
+    "דזונקה",
+    "אנגלית",
+    "אספרנטו",
+    "אסטונית",
+    "אווה",
+    "פארואזית",
+    "פיג׳ית",
+    "פינית",
+    "צרפתית",
+    "פולה",
+    "גליציאנית",
+    "גאורגית",
+    "גרמנית",
+    "יוונית",
+    "גוארני",
+    "גוג׳ארטי",
+    "קריאולית (האיטי)",
+    "האוסה",
+    "עברית",
+    "הררו",
+    "הינדי",
+    "הירי מוטו",
+    "הונגרית",
+    "\u{200F}אינטרלינגואה",
+    "אינדונזית",
+    "אינטרלינגה",
+    "אירית",
+    "איגבו",
+    "אינופיאק",
+    "אידו",
+    "איסלנדית",
+    "איטלקית",
+    "אינוקטיטוט",
+    "יפנית",
+    "יאוואית",
+    "גרינלנדית",
+    "קנאדה",
+    "קאנורי",
+    "קשמירית",
+    "קזחית",
+    "חמרית",
+    "קיקויו",
+    "קנירואנדית",
+    "קירגיזית",
+    "קומי",
+    "קונגו",
+    "קוריאנית",
+    "כורדית",
+    "קואניאמה",
+    "לטינית",
+    "לוקסמבורגית",
+    "גאנדה",
+    "לימבורגית",
+    "לינגלה",
+    "לאו",
+    "ליטאית",
+    "לובה-קטנגה",
+    "לטבית",
+    "מאנית",
+    "מקדונית",
+    "מלגשית",
+    "מלאית",
+    "מליאלאם",
+    "מלטית",
+    "מאורית",
+    "מראטהית",
+    "מרשלית",
+    "מונגולית",
+    "נאורית",
+    "נאוואחו",
+    "נדבלה צפונית",
+    "נפאלית",
+    "נדונגה",
+    "נורווגית ספרותית",
+    "נורווגית חדשה",
+    "נורווגית",
+    "סצ׳ואן יי",
+    "נדבלה דרומית",
+    "אוקסיטנית",
+    "אוג׳יבווה",
+    "סלאבית כנסייתית עתיקה",
+    "אורומו",
+    "אורייה",
+    "אוסטית",
+    "פנג׳אבי",
+    "פאלי",
+    "פרסית",
+    "פולנית",
+    "פאשטו",
+    "פורטוגזית",
+    "קצ׳ואה",
+    "רומאנש",
+    "קירונדי",
+    "רומנית",
+    "רוסית",
+    "סנסקריט",
+    "סרדינית",
+    "סינדהית",
+    "סמי צפונית",
+    "סמואית",
+    "סנגו",
+    "סרבית",
+    "גאלית סקוטית",
+    "שונה",
+    "סינהלה",
+    "סלובקית",
+    "סלובנית",
+    "סומלית",
+    "סותו דרומית",
+    "ספרדית",
+    "סונדנזית",
+    "סווהילי",
+    "סאווזי",
+    "שוודית",
+    "טמילית",
+    "טלוגו",
+    "טג׳יקית",
+    "תאית",
+    "תיגרינית",
+    "טיבטית",
+    "טורקמנית",
+    "טאגאלוג",
+    "סוואנה",
+    "טונגאית",
+    "טורקית",
+    "טסונגה",
+    "טטרית",
+    "טווי",
+    "טהיטית",
+    "אויגורית",
+    "אוקראינית",
+    "אורדו",
+    "אוזבקית",
+    "וונדה",
+    "וייטנאמית",
+    "\u{200F}וולאפיק",
+    "ולונית",
+    "וולשית",
+    "וולוף",
+    "פריזית מערבית",
+    "קוסה",
+    "יידיש",
+    "יורובה",
+    "זואנג",
+    "זולו",
+];
+
+static HI: [&str; 184] = [
+    "अब्ख़ाज़ियन",
+    "अफ़ार",
+    "अफ़्रीकी",
+    "अकन",
+    "अल्बानियाई",
+    "अम्हेरी",
+    "अरबी",
+    "अरागोनी",
+    "आर्मेनियाई",
+    "असमिया",
+    "अवेरिक",
+    "अवस्ताई",
+    "आयमारा",
+    "अज़रबैजानी",
+    "बाम्बारा",
+    "बशख़िर",
+    "बास्क",
+    "बेलारूसी",
+    "बंगाली",
+    "",
+    "बिस्लामा",
+    "बोस्नियाई",
+    "ब्रेटन",
+    "बुल्गारियाई",
+    "बर्मीज़",
+    "कातालान",
+    "कमोरो",
+    "चेचन",
+    "न्यानजा",
+    "चीनी",
+    "चूवाश",
+    "कोर्निश",
+    "कोर्सीकन",
+    "क्री",
+    "क्रोएशियाई",
+    "चेक",
+    "डेनिश",
+    "दिवेही",
+    "डच",
+    "ज़ोन्गखा",
+    "अंग्रेज़ी",
+    "एस्पेरेंतो",
+    "एस्टोनियाई",
+    "ईवे",
+    "फ़ैरोइज़",
+    "फिजियन",
+    "फ़िनिश",
+    "फ़्रेंच",
+    "फुलाह",
+    "गैलिशियन",
+    "जॉर्जियाई",
+    "जर्मन",
+    "यूनानी",
+    "गुआरानी",
+    "गुजराती",
+    "हैतियाई",
+    "हौसा",
+    "हिब्रू",
+    "हरैरो",
+    "हिन्दी",
+    "हिरी मोटू",
+    "हंगेरियाई",
+    "इंटरलिंगुआ",
+    "इंडोनेशियाई",
+    "ईन्टरलिंगुइ",
+    "आयरिश",
+    "ईग्बो",
+    "इनुपियाक्",
+    "इडौ",
+    "आइसलैंडिक",
+    "इतालवी",
+    "इनुक्टिटुट",
+    "जापानी",
+    "जावानीज़",
+    "कलालीसुत",
+    "कन्नड़",
+    "कनुरी",
+    "कश्मीरी",
+    "कज़ाख़",
+    "खमेर",
+    "किकुयू",
+    "किन्यारवांडा",
+    "किर्गीज़",
+    "कोमी",
+    "कोंगो",
+    "कोरियाई",
+    "कुर्दिश",
+    "क्वान्यामा",
+    "लैटिन",
+    "लग्ज़मबर्गी",
+    "गांडा",
+    "लिंबर्गिश",
+    "लिंगाला",
+    "लाओ",
+    "लिथुआनियाई",
+    "ल्यूबा-कटांगा",
+    "लातवियाई",
+    "मैंक्स",
+    "मकदूनियाई",
+    "मालागासी",
+    "मलय",
+    "मलयालम",
+    "माल्टीज़",
+    "माओरी",
+    "मराठी",
+    "मार्शलीज़",
+    "मंगोलियाई",
+    "नाउरू",
+    "नवाहो",
+    "उत्तरी देबेल",
+    "नेपाली",
+    "डोन्गा",
+    "नॉर्वेजियाई बोकमाल",
+    "नॉर्वेजियाई नॉयनॉर्स्क",
+    "नॉर्वेजियाई",
+    "सिचुआन यी",
+    "दक्षिण देबेल",
+    "ओसीटान",
+    "ओजिब्वा",
+    "चर्च साल्विक",
+    "ओरोमो",
+    "ओड़िया",
+    "ओस्सेटिक",
+    "पंजाबी",
+    "पाली",
+    "फ़ारसी",
+    "पोलिश",
+    "पश्तो",
+    "पुर्तगाली",
+    "क्वेचुआ",
+    "रोमान्श",
+    "रुन्दी",
+    "रोमानियाई",
+    "रूसी",
+    "संस्कृत",
+    "सार्दिनियन",
+    "सिंधी",
+    "नॉर्दन सामी",
+    "सामोन",
+    "सांगो",
+    "सर्बियाई",
+    "स्कॉटिश गाएलिक",
+    "शोणा",
+    "सिंहली",
+    "स्लोवाक",
+    "स्लोवेनियाई",
+    "सोमाली",
+    "दक्षिणी सेसेथो",
+    "स्पेनिश",
+    "सुंडानी",
+    "स्वाहिली",
+    "स्वाती",
+    "स्वीडिश",
+    "तमिल",
+    "तेलुगू",
+    "ताजिक",
+    "थाई",
+    "तिग्रीन्या",
+    "तिब्बती",
+    "तुर्कमेन",
+    "टैगलॉग",
+    "सेत्स्वाना",
+    "टोंगन",
+    "तुर्की",
+    "सोंगा",
+    "तातार",
+    "ट्वी",
+    "ताहितियन",
+    "उइगर",
+    "यूक्रेनियाई",
+    "उर्दू",
+    "उज़्बेक",
+    "वेन्दा",
+    "वियतनामी",
+    "वोलापुक",
+    "वाल्लून",
+    "वेल्श",
+    "वोलोफ़",
+    "पश्चिमी फ़्रिसियाई",
+    "ख़ोसा",
+    "यहूदी",
+    "योरूबा",
+    "ज़ुआंग",
+    "ज़ुलू",
+];
+
+static HU: [&str; 184] = [
+    "abház",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albán",
+    "amhara",
+    "arab",
+    "aragonéz",
+    "örmény",
+    "asszámi",
+    "avar",
+    "avesztán",
+    "ajmara",
+    "azerbajdzsáni",
+    "bambara",
+    "baskír",
+    "baszk",
+    "belarusz",
+    "bangla",
+    "",
+    "bislama",
+    "bosnyák",
+    "breton",
+    "bolgár",
+    "burmai",
+    "katalán",
+    "csamoró",
+    "csecsen",
+    "nyandzsa",
+    "kínai",
+    "csuvas",
+    "korni",
+    "korzikai",
+    "krí",
+    "horvát",
+    "cseh",
+    "dán",
+    "divehi",
+    "holland",
+    "dzsonga",
+    "angol",
+    "eszperantó",
+    "észt",
+    "eve",
+    "feröeri",
+    "fidzsi",
+    "finn",
+    "francia",
+    "fulani",
+    "gallego",
+    "grúz",
+    "német",
+    "görög",
+    "guarani",
+    "gudzsaráti",
+    "haiti kreol",
+    "hausza",
+    "héber",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "magyar",
+    "interlingva",
+    "indonéz",
+    "interlingue",
+    "ír",
+    "igbó",
+    "inupiak",
+    "idó",
+    "izlandi",
+    "olasz",
+    "inuktitut",
+    "japán",
+    "jávai",
+    "grönlandi",
+    "kannada",
+    "kanuri",
+    "kasmíri",
+    "kazah",
+    "khmer",
+    "kikuju",
+    "kinyarvanda",
+    "kirgiz",
+    "komi",
+    "kongo",
+    "koreai",
+    "kurd",
+    "kuanyama",
+    "latin",
+    "luxemburgi",
+    "ganda",
+    "limburgi",
+    "lingala",
+    "lao",
+    "litván",
+    "luba-katanga",
+    "lett",
+    "man-szigeti",
+    "macedón",
+    "malgas",
+    "maláj",
+    "malajálam",
+    "máltai",
+    "maori",
+    "maráthi",
+    "marshalli",
+    "mongol",
+    "naurui",
+    "navahó",
+    "északi ndebele",
+    "nepáli",
+    "ndonga",
+    "norvég (bokmål)",
+    "norvég (nynorsk)",
+    "norvég",
+    "szecsuán ji",
+    "déli ndebele",
+    "okszitán",
+    "ojibva",
+    "egyházi szláv",
+    "oromo",
+    "odia",
+    "oszét",
+    "pandzsábi",
+    "pali",
+    "perzsa",
+    "lengyel",
+    "pastu",
+    "portugál",
+    "kecsua",
+    "rétoromán",
+    "kirundi",
+    "román",
+    "orosz",
+    "szanszkrit",
+    "szardíniai",
+    "szindhi",
+    "északi számi",
+    "szamoai",
+    "szangó",
+    "szerb",
+    "skóciai kelta",
+    "sona",
+    "szingaléz",
+    "szlovák",
+    "szlovén",
+    "szomáli",
+    "déli szeszotó",
+    "spanyol",
+    "szundanéz",
+    "szuahéli",
+    "sziszuati",
+    "svéd",
+    "tamil",
+    "telugu",
+    "tadzsik",
+    "thai",
+    "tigrinya",
+    "tibeti",
+    "türkmén",
+    "tagalog",
+    "szecsuáni",
+    "tongai",
+    "török",
+    "conga",
+    "tatár",
+    "twi",
+    "tahiti",
+    "ujgur",
+    "ukrán",
+    "urdu",
+    "üzbég",
+    "venda",
+    "vietnámi",
+    "volapük",
+    "vallon",
+    "walesi",
+    "volof",
+    "nyugati fríz",
+    "xhosza",
+    "jiddis",
+    "joruba",
+    "zsuang",
+    "zulu",
+];
+
+static IA: [&str; 184] = [
+    "abkhazo",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albanese",
+    "amharico",
+    "arabe",
+    "aragonese",
+    "armenio",
+    "assamese",
+    "avaro",
+    "",
+    "aymara",
+    "azerbaidzhano",
+    "bambara",
+    "bashkir",
+    "basco",
+    "bielorusso",
+    "bengalese",
+    "",
+    "bislama",
+    "bosniaco",
+    "breton",
+    "bulgaro",
+    "birmano",
+    "catalano",
+    "chamorro",
+    "checheno",
+    "nyanja",
+    "chinese",
+    "chuvash",
+    "cornico",
+    "corso",
+    "",
+    "croato",
+    "checo",
+    "danese",
+    "divehi",
+    "nederlandese",
+    "dzongkha",
+    "anglese",
+    "esperanto",
+    "estoniano",
+    "ewe",
+    "feroese",
+    "fijiano",
+    "finnese",
+    "francese",
+    "fula",
+    "galleco",
+    "georgiano",
+    "germano",
+    "greco",
+    "guarani",
+    "gujarati",
+    "creolo haitian",
+    "hausa",
+    "hebreo",
+    "herero",
+    "hindi",
+    "",
+    "hungaro",
+    "interlingua",
+    "indonesiano",
+    "interlingue",
+    "irlandese",
+    "igbo",
+    "",
+    "ido",
+    "islandese",
+    "italiano",
+    "inuktitut",
+    "japonese",
+    "javanese",
+    "groenlandese",
+    "kannada",
+    "kanuri",
+    "kashmiri",
+    "kazakh",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirghizo",
+    "komi",
+    "",
+    "coreano",
+    "kurdo",
+    "kuanyama",
+    "latino",
+    "luxemburgese",
+    "luganda",
+    "limburgese",
+    "lingala",
+    "laotiano",
+    "lithuano",
+    "luba-katanga",
+    "letton",
+    "mannese",
+    "macedone",
+    "malgache",
+    "malay",
+    "malayalam",
+    "maltese",
+    "maori",
+    "marathi",
+    "marshallese",
+    "mongol",
+    "nauru",
+    "navajo",
+    "ndebele del nord",
+    "nepalese",
+    "ndonga",
+    "norvegiano bokmål",
+    "norvegiano nynorsk",
+    "norvegiano",
+    "yi de Sichuan",
+    "ndebele del sud",
+    "occitano",
+    "",
+    "slavo ecclesiastic",
+    "oromo",
+    "oriya",
+    "osseto",
+    "punjabi",
+    "",
+    "persa",
+    "polonese",
+    "pashto",
+    "portugese",
+    "quechua",
+    "romanche",
+    "rundi",
+    "romaniano",
+    "russo",
+    "sanscrito",
+    "sardo",
+    "sindhi",
+    "sami del nord",
+    "samoano",
+    "sango",
+    "serbo",
+    "gaelico scotese",
+    "shona",
+    "cingalese",
+    "slovaco",
+    "sloveno",
+    "somali",
+    "sotho del sud",
+    "espaniol",
+    "sundanese",
+    "swahili",
+    "swati",
+    "svedese",
+    "tamil",
+    "telugu",
+    "tajiko",
+    "thai",
+    "tigrinya",
+    "tibetano",
+    "turkmeno",
+    "",
+    "tswana",
+    "tongano",
+    "turco",
+    "tsonga",
+    "tataro",
+    "twi",
+    "tahitiano",
+    "uighur",
+    "ukrainiano",
+    "urdu",
+    "uzbeko",
+    "venda",
+    "vietnamese",
+    "volapük",
+    "wallon",
+    "gallese",
+    "wolof",
+    "frison occidental",
+    "xhosa",
+    "yiddish",
+    "yoruba",
+    "zhuang",
+    "zulu",
+];
+
+static ID: [&str; 184] = [
+    "Abkhaz",
+    "Afar",
+    "Afrikaans",
+    "Akan",
+    "Albania",
+    "Amharik",
+    "Arab",
+    "Aragon",
+    "Armenia",
+    "Assam",
+    "Avar",
+    "Avesta",
+    "Aymara",
+    "Azerbaijani",
+    "Bambara",
+    "Bashkir",
+    "Basque",
+    "Belarusia",
+    "Bengali",
+    "",
+    "Bislama",
+    "Bosnia",
+    "Breton",
+    "Bulgaria",
+    "Burma",
+    "Katalan",
+    "Chamorro",
+    "Chechen",
+    "Nyanja",
+    "Tionghoa",
+    "Chuvash",
+    "Kornish",
+    "Korsika",
+    "Kree",
+    "Kroasia",
+    "Ceko",
+    "Dansk",
+    "Divehi",
+    "Belanda",
+    "Dzongkha",
+    "Inggris",
+    "Esperanto",
+    "Estonia",
+    "Ewe",
+    "Faroe",
+    "Fiji",
+    "Suomi",
+    "Prancis",
+    "Fula",
+    "Galisia",
+    "Georgia",
+    "Jerman",
+    "Yunani",
+    "Guarani",
+    "Gujarat",
+    "Kreol Haiti",
+    "Hausa",
+    "Ibrani",
+    "Herero",
+    "Hindi",
+    "Hiri Motu",
+    "Hungaria",
+    "Interlingua",
+    "Indonesia",
+    "Interlingue",
+    "Irlandia",
+    "Igbo",
+    "Inupiak",
+    "Ido",
+    "Islandia",
+    "Italia",
+    "Inuktitut",
+    "Jepang",
+    "Jawa",
+    "Kalaallisut",
+    "Kannada",
+    "Kanuri",
+    "Kashmir",
+    "Kazakh",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kirgiz",
+    "Komi",
+    "Kongo",
+    "Korea",
+    "Kurdi",
+    "Kuanyama",
+    "Latin",
+    "Luksemburg",
+    "Ganda",
+    "Limburgia",
+    "Lingala",
+    "Lao",
+    "Lituania",
+    "Luba-Katanga",
+    "Latvia",
+    "Manx",
+    "Makedonia",
+    "Malagasi",
+    "Melayu",
+    "Malayalam",
+    "Malta",
+    "Maori",
+    "Marathi",
+    "Marshall",
+    "Mongolia",
+    "Nauru",
+    "Navajo",
+    "Ndebele Utara",
+    "Nepali",
+    "Ndonga",
+    "Bokmål Norwegia",
+    "Nynorsk Norwegia",
+    "Norwegia",
+    "Sichuan Yi",
+    "Ndebele Selatan",
+    "Ositania",
+    "Ojibwa",
+    "Bahasa Gereja Slavonia",
+    "Oromo",
+    "Oriya",
+    "Ossetia",
+    "Punjabi",
+    "Pali",
+    "Persia",
+    "Polski",
+    "Pashto",
+    "Portugis",
+    "Quechua",
+    "Reto-Roman",
+    "Rundi",
+    "Rumania",
+    "Rusia",
+    "Sanskerta",
+    "Sardinia",
+    "Sindhi",
+    "Sami Utara",
+    "Samoa",
+    "Sango",
+    "Serbia",
+    "Gaelik Skotlandia",
+    "Shona",
+    "Sinhala",
+    "Slovak",
+    "Slovenia",
+    "Somalia",
+    "Sotho Selatan",
+    "Spanyol",
+    "Sunda",
+    "Swahili",
+    "Swati",
+    "Swedia",
+    "Tamil",
+    "Telugu",
+    "Tajik",
+    "Thai",
+    "Tigrinya",
+    "Tibet",
+    "Turkmen",
+    "Tagalog",
+    "Tswana",
+    "Tonga",
+    "Turki",
+    "Tsonga",
+    "Tatar",
+    "Twi",
+    "Tahiti",
+    "Uyghur",
+    "Ukraina",
+    "Urdu",
+    "Uzbek",
+    "Venda",
+    "Vietnam",
+    "Volapuk",
+    "Walloon",
+    "Welsh",
+    "Wolof",
+    "Frisia Barat",
+    "Xhosa",
+    "Yiddish",
+    "Yoruba",
+    "Zhuang",
+    "Zulu",
+];
+
+static GA: [&str; 184] = [
+    "Abcáisis",
+    "Afáiris",
+    "Afracáinis",
+    "Acáinis",
+    "Albáinis",
+    "Amáiris",
+    "Araibis",
+    "Aragóinis",
+    "Airméinis",
+    "Asaimis",
+    "Aváiris",
+    "Aivéistis",
+    "Aidhmiris",
+    "Asarbaiseáinis",
+    "Bambairis",
+    "Baiscíris",
+    "Bascais",
+    "Bealarúisis",
+    "Beangáilis",
+    "",
+    "Bioslaimis",
+    "Boisnis",
+    "Briotáinis",
+    "Bulgáiris",
+    "Burmais",
+    "Catalóinis",
+    "Seamóiris",
+    "Seisnis",
+    "Siséivis",
+    "Sínis",
+    "Suvaisis",
+    "Coirnis",
+    "Corsaicis",
+    "Craís",
+    "Cróitis",
+    "Seicis",
+    "Danmhairgis",
+    "Divéihis",
+    "Ollainnis",
+    "Seoinicis",
+    "Béarla",
+    "Esperanto",
+    "Eastóinis",
+    "Éabhais",
+    "Faróis",
+    "Fidsis",
+    "Fionlainnis",
+    "Fraincis",
+    "Fuláinis",
+    "Gailísis",
+    "Seoirsis",
+    "Gearmáinis",
+    "Gréigis",
+    "Guaráinis",
+    "Gúisearáitis",
+    "Críol Háítí",
+    "Hásais",
+    "Eabhrais",
+    "Heiréiris",
+    "Hiondúis",
+    "Motúis Hírí",
+    "Ungáiris",
+    "Interlingua",
+    "Indinéisis",
+    "Interlingue",
+    "Gaeilge",
+    "Íogbóis",
+    "Iniúipiaicis",
+    "Ídis",
+    "Íoslainnis",
+    "Iodáilis",
+    "Ionúitis",
+    "Seapáinis",
+    "Iáivis",
+    "Kalaallisut",
+    "Cannadais",
+    "Canúiris",
+    "Caismíris",
+    "Casaicis",
+    "Ciméiris",
+    "Ciocúis",
+    "Ciniaruaindis",
+    "Cirgisis",
+    "Coimis",
+    "Congóis",
+    "Cóiréis",
+    "Coirdis",
+    "Cuainiáimis",
+    "Laidin",
+    "Lucsambuirgis",
+    "Lugandais",
+    "Liombuirgis",
+    "Liongáilis",
+    "Laoisis",
+    "Liotuáinis",
+    "Lúba-Cataingis",
+    "Laitvis",
+    "Manainnis",
+    "Macadóinis",
+    "Malagáisis",
+    "Malaeis",
+    "Mailéalaimis",
+    "Máltais",
+    "Maorais",
+    "Maraitis",
+    "Mairsillis",
+    "Mongóilis",
+    "Nárúis",
+    "Navachóis",
+    "N-deibéilis an Tuaiscirt",
+    "Neipeailis",
+    "Ndongais",
+    "Bocmál",
+    "Nua-Ioruais",
+    "Ioruais",
+    "Ís Shichuan",
+    "Ndeibéilis an Deiscirt",
+    "Ocsatáinis",
+    "Óisibis",
+    "Slavais na hEaglaise",
+    "Oraimis",
+    "Odia",
+    "Oiséitis",
+    "Puinseáibis",
+    "Páilis",
+    "Peirsis",
+    "Polainnis",
+    "Paistis",
+    "Portaingéilis",
+    "Ceatsuais",
+    "Rómainis",
+    "Rúindis",
+    "Rómáinis",
+    "Rúisis",
+    "Sanscrait",
+    "Sairdínis",
+    "Sindis",
+    "Sáimis an Tuaiscirt",
+    "Samóis",
+    "Sangóis",
+    "Seirbis",
+    "Gaeilge na hAlban",
+    "Seoinis",
+    "Siolóinis",
+    "Slóvaicis",
+    "Slóivéinis",
+    "Somáilis",
+    "Sútúis an Deiscirt",
+    "Spáinnis",
+    "Sundais",
+    "Svahaílis",
+    "Suaisis",
+    "Sualainnis",
+    "Tamailis",
+    "Teileagúis",
+    "Taidsícis",
+    "Téalainnis",
+    "Tigrinis",
+    "Tibéidis",
+    "Tuircméinis",
+    "Tagálaigis",
+    "Suáinis",
+    "Tongais",
+    "Tuircis",
+    "Songais",
+    "Tatairis",
+    "Tíbhis",
+    "Taihítis",
+    "Uigiúiris",
+    "Úcráinis",
+    "Urdúis",
+    "Úisbéiceastáinis",
+    "Veindis",
+    "Vítneaimis",
+    "Volapük",
+    "Vallúnais",
+    "Breatnais",
+    "Volaifis",
+    "Freaslainnis Iartharach",
+    "Cóisis",
+    "Giúdais",
+    "Iarúibis",
+    "Siuáingis",
+    "Súlúis",
+];
+
+static IG: [&str; 184] = [
+    "Abkaziani",
+    "Afar",
+    "Afrikaans",
+    "Akan",
+    "Asụsụ Albania",
+    "Amariikị",
+    "Arabiikị",
+    "Aragonisị",
+    "Armenianị",
+    "Asamisị",
+    "Avarịk",
+    "",
+    "Aymara",
+    "Azerbaijani",
+    "Bambara",
+    "Bashkir",
+    "Basque",
+    "Belarusian",
+    "Bangla",
+    "",
+    "Bislama",
+    "Bosnian",
+    "Breton",
+    "Bulgarian",
+    "Burmese",
+    "Catalan",
+    "Chamorro",
+    "Chechen",
+    "Nyanja",
+    "Chaịniiz",
+    "Chuvash",
+    "Cornish",
+    "Corsican",
+    "",
+    "Croatian",
+    "Czech",
+    "Danish",
+    "Divehi",
+    "Dutch",
+    "Dọzngọka",
+    "Bekee",
+    "Esperanto",
+    "Estonian",
+    "Ewe",
+    "Faroese",
+    "Fijanị",
+    "Finnish",
+    "French",
+    "Fula",
+    "Galician",
+    "Georgian",
+    "German",
+    "Grik",
+    "Guarani",
+    "Gujarati",
+    "Haịtịan ndị Cerọle",
+    "Hausa",
+    "Hebrew",
+    "Herero",
+    "Hindi",
+    "",
+    "Hungarian",
+    "Interlingua",
+    "Indonesian",
+    "Interlingue",
+    "Irish",
+    "Igbo",
+    "",
+    "Ido",
+    "Icelandic",
+    "Italian",
+    "Inuktitut",
+    "Japanese",
+    "Javanese",
+    "Kalaallisut",
+    "Kannada",
+    "Kanuri",
+    "Kashmiri",
+    "Kazakh",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kyrgyz",
+    "Komi",
+    "",
+    "Korean",
+    "Kurdish",
+    "Kuanyama",
+    "Latin",
+    "Luxembourgish",
+    "Ganda",
+    "Limburgish",
+    "Lingala",
+    "Lao",
+    "Lithuanian",
+    "Luba-Katanga",
+    "Latvian",
+    "Mansị",
+    "Macedonian",
+    "Malagasy",
+    "Malay",
+    "Malayalam",
+    "Asụsụ Malta",
+    "Māori",
+    "Asụsụ Marathi",
+    "Marshallese",
+    "Mọngolịan",
+    "Nauru",
+    "Navajo",
+    "North Ndebele",
+    "Nepali",
+    "Ndonga",
+    "Norwegian Bokmål",
+    "Norwegian Nynorsk",
+    "Norwegian",
+    "Sichuan Yi",
+    "South Ndebele",
+    "Asụsụ Osịtan",
+    "",
+    "Church slavic",
+    "Oromo",
+    "Ọdịa",
+    "Ossetic",
+    "Punjabi",
+    "",
+    "Asụsụ Persia",
+    "Asụsụ Polish",
+    "Pashọ",
+    "Pọrtụgụese",
+    "Asụsụ Quechua",
+    "Asụsụ Romansh",
+    "Rundi",
+    "Asụsụ Romanian",
+    "Asụsụ Russia",
+    "Asụsụ Sanskrit",
+    "Asụsụ Sardini",
+    "Asụsụ Sindhi",
+    "Northern Sami",
+    "Samoan",
+    "Sango",
+    "Asụsụ Serbia",
+    "Asụsụ Scottish Gaelic",
+    "Shona",
+    "Sinhala",
+    "Asụsụ Slovak",
+    "Asụsụ Slovenia",
+    "Somali",
+    "Southern Sotho",
+    "Spanish",
+    "Asụsụ Sundan",
+    "Asụsụ Swahili",
+    "Swati",
+    "Sụwidiishi",
+    "Tamil",
+    "Telugu",
+    "Tajik",
+    "Thai",
+    "Tigrinya",
+    "Tibetan",
+    "Turkmen",
+    "",
+    "Tswana",
+    "Tongan",
+    "Turkish",
+    "Tsonga",
+    "Asụsụ Tatar",
+    "",
+    "Tahitian",
+    "Uyghur",
+    "Asụsụ Ukrain",
+    "Urdu",
+    "Uzbek",
+    "Venda",
+    "Vietnamese",
+    "Volapük",
+    "Walloon",
+    "Welsh",
+    "Wolof",
+    "Ọdịda anyanwụ Frisian",
+    "Xhosa",
+    "Yiddish",
+    "Yoruba",
+    "Zhuang",
+    "Zulu",
+];
+
+static IS: [&str; 184] = [
+    "abkasíska",
+    "afár",
+    "afríkanska",
+    "akan",
+    "albanska",
+    "amharíska",
+    "arabíska",
+    "aragonska",
+    "armenska",
+    "assamska",
+    "avaríska",
+    "avestíska",
+    "aímara",
+    "aserska",
+    "bambara",
+    "baskír",
+    "baskneska",
+    "hvítrússneska",
+    "bengalska",
+    "",
+    "bíslama",
+    "bosníska",
+    "bretónska",
+    "búlgarska",
+    "búrmneska",
+    "katalónska",
+    "kamorró",
+    "tsjetsjenska",
+    "nýanja",
+    "kínverska",
+    "sjúvas",
+    "kornbreska",
+    "korsíska",
+    "krí",
+    "króatíska",
+    "tékkneska",
+    "danska",
+    "dívehí",
+    "hollenska",
+    "dsongka",
+    "enska",
+    "esperantó",
+    "eistneska",
+    "ewe",
+    "færeyska",
+    "fídjeyska",
+    "finnska",
+    "franska",
+    "fúla",
+    "galisíska",
+    "georgíska",
+    "þýska",
+    "gríska",
+    "gvaraní",
+    "gújaratí",
+    "haítíska",
+    "hása",
+    "hebreska",
+    "hereró",
+    "hindí",
+    "hírímótú",
+    "ungverska",
+    "interlingua",
+    "indónesíska",
+    "interlingve",
+    "írska",
+    "ígbó",
+    "ínúpíak",
+    "ídó",
+    "íslenska",
+    "ítalska",
+    "inúktitút",
+    "japanska",
+    "javanska",
+    "grænlenska",
+    "kannada",
+    "kanúrí",
+    "kasmírska",
+    "kasakska",
+    "kmer",
+    "kíkújú",
+    "kínjarvanda",
+    "kirgiska",
+    "komíska",
+    "kongóska",
+    "kóreska",
+    "kúrdíska",
+    "kúanjama",
+    "latína",
+    "lúxemborgíska",
+    "ganda",
+    "limbúrgíska",
+    "lingala",
+    "laó",
+    "litháíska",
+    "lúbakatanga",
+    "lettneska",
+    "manska",
+    "makedónska",
+    "malagasíska",
+    "malaíska",
+    "malajalam",
+    "maltneska",
+    "maorí",
+    "maratí",
+    "marshallska",
+    "mongólska",
+    "nárúska",
+    "navahó",
+    "norður-ndebele",
+    "nepalska",
+    "ndonga",
+    "norskt bókmál",
+    "nýnorska",
+    "norska",
+    "sísúanjí",
+    "suðurndebele",
+    "oksítaníska",
+    "ojibva",
+    "kirkjuslavneska",
+    "oromo",
+    "óría",
+    "ossetíska",
+    "púnjabí",
+    "palí",
+    "persneska",
+    "pólska",
+    "pastú",
+    "portúgalska",
+    "kvesjúa",
+    "rómanska",
+    "rúndí",
+    "rúmenska",
+    "rússneska",
+    "sanskrít",
+    "sardínska",
+    "sindí",
+    "norðursamíska",
+    "samóska",
+    "sangó",
+    "serbneska",
+    "skosk gelíska",
+    "shona",
+    "singalíska",
+    "slóvakíska",
+    "slóvenska",
+    "sómalska",
+    "suðursótó",
+    "spænska",
+    "súndanska",
+    "svahílí",
+    "svatí",
+    "sænska",
+    "tamílska",
+    "telúgú",
+    "tadsjikska",
+    "taílenska",
+    "tígrinja",
+    "tíbeska",
+    "túrkmenska",
+    "tagalog",
+    "tsúana",
+    "tongverska",
+    "tyrkneska",
+    "tsonga",
+    "tatarska",
+    "tví",
+    "tahítíska",
+    "úígúr",
+    "úkraínska",
+    "úrdú",
+    "úsbekska",
+    "venda",
+    "víetnamska",
+    "volapyk",
+    "vallónska",
+    "velska",
+    "volof",
+    "vesturfrísneska",
+    "sósa",
+    "jiddíska",
+    "jórúba",
+    "súang",
+    "súlú",
+];
+
+static IT: [&str; 184] = [
+    "abcaso",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albanese",
+    "amarico",
+    "arabo",
+    "aragonese",
+    "armeno",
+    "assamese",
+    "avaro",
+    "avestan",
+    "aymara",
+    "azerbaigiano",
+    "bambara",
+    "baschiro",
+    "basco",
+    "bielorusso",
+    "bengalese",
+    "",
+    "bislama",
+    "bosniaco",
+    "bretone",
+    "bulgaro",
+    "birmano",
+    "catalano",
+    "chamorro",
+    "ceceno",
+    "nyanja",
+    "cinese",
+    "ciuvascio",
+    "cornico",
+    "corso",
+    "cree",
+    "croato",
+    "ceco",
+    "danese",
+    "divehi",
+    "olandese",
+    "dzongkha",
+    "inglese",
+    "esperanto",
+    "estone",
+    "ewe",
+    "faroese",
+    "figiano",
+    "finlandese",
+    "francese",
+    "fulah",
+    "galiziano",
+    "georgiano",
+    "tedesco",
+    "greco",
+    "guaraní",
+    "gujarati",
+    "creolo haitiano",
+    "hausa",
+    "ebraico",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "ungherese",
+    "interlingua",
+    "indonesiano",
+    "interlingue",
+    "irlandese",
+    "igbo",
+    "inupiak",
+    "ido",
+    "islandese",
+    "italiano",
+    "inuktitut",
+    "giapponese",
+    "giavanese",
+    "groenlandese",
+    "kannada",
+    "kanuri",
+    "kashmiri",
+    "kazako",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirghiso",
+    "komi",
+    "kongo",
+    "coreano",
+    "curdo",
+    "kuanyama",
+    "latino",
+    "lussemburghese",
+    "ganda",
+    "limburghese",
+    "lingala",
+    "lao",
+    "lituano",
+    "luba-katanga",
+    "lettone",
+    "mannese",
+    "macedone",
+    "malgascio",
+    "malese",
+    "malayalam",
+    "maltese",
+    "maori",
+    "marathi",
+    "marshallese",
+    "mongolo",
+    "nauru",
+    "navajo",
+    "ndebele del nord",
+    "nepalese",
+    "ndonga",
+    "norvegese bokmål",
+    "norvegese nynorsk",
+    "norvegese",
+    "sichuan yi",
+    "ndebele del sud",
+    "occitano",
+    "ojibwa",
+    "slavo ecclesiastico",
+    "oromo",
+    "odia",
+    "ossetico",
+    "punjabi",
+    "pali",
+    "persiano",
+    "polacco",
+    "pashto",
+    "portoghese",
+    "quechua",
+    "romancio",
+    "rundi",
+    "rumeno",
+    "russo",
+    "sanscrito",
+    "sardo",
+    "sindhi",
+    "sami del nord",
+    "samoano",
+    "sango",
+    "serbo",
+    "gaelico scozzese",
+    "shona",
+    "singalese",
+    "slovacco",
+    "sloveno",
+    "somalo",
+    "sotho del sud",
+    "spagnolo",
+    "sundanese",
+    "swahili",
+    "swati",
+    "svedese",
+    "tamil",
+    "telugu",
+    "tagico",
+    "thailandese",
+    "tigrino",
+    "tibetano",
+    "turcomanno",
+    "tagalog",
+    "tswana",
+    "tongano",
+    "turco",
+    "tsonga",
+    "tataro",
+    "ci",
+    "taitiano",
+    "uiguro",
+    "ucraino",
+    "urdu",
+    "uzbeco",
+    "venda",
+    "vietnamita",
+    "volapük",
+    "vallone",
+    "gallese",
+    "wolof",
+    "frisone occidentale",
+    "xhosa",
+    "yiddish",
+    "yoruba",
+    "zhuang",
+    "zulu",
+];
+
+static JA: [&str; 184] = [
+    "アブハズ語",
+    "アファル語",
+    "アフリカーンス語",
+    "アカン語",
+    "アルバニア語",
+    "アムハラ語",
+    "アラビア語",
+    "アラゴン語",
+    "アルメニア語",
+    "アッサム語",
+    "アヴァル語",
+    "アヴェスタ語",
+    "アイマラ語",
+    "アゼルバイジャン語",
+    "バンバラ語",
+    "バシキール語",
+    "バスク語",
+    "ベラルーシ語",
+    "ベンガル語",
+    "",
+    "ビスラマ語",
+    "ボスニア語",
+    "ブルトン語",
+    "ブルガリア語",
+    "ミャンマー語",
+    "カタロニア語",
+    "チャモロ語",
+    "チェチェン語",
+    "ニャンジャ語",
+    "中国語",
+    "チュヴァシ語",
+    "コーンウォール語",
+    "コルシカ語",
+    "クリー語",
+    "クロアチア語",
+    "チェコ語",
+    "デンマーク語",
+    "ディベヒ語",
+    "オランダ語",
+    "ゾンカ語",
+    "英語",
+    "エスペラント語",
+    "エストニア語",
+    "エウェ語",
+    "フェロー語",
+    "フィジー語",
+    "フィンランド語",
+    "フランス語",
+    "フラ語",
+    "ガリシア語",
+    "ジョージア語",
+    "ドイツ語",
+    "ギリシャ語",
+    "グアラニー語",
+    "グジャラート語",
+    "ハイチ・クレオール語",
+    "ハウサ語",
+    "ヘブライ語",
+    "ヘレロ語",
+    "ヒンディー語",
+    "ヒリモツ語",
+    "ハンガリー語",
+    "インターリングア",
+    "インドネシア語",
+    "インターリング",
+    "アイルランド語",
+    "イボ語",
+    "イヌピアック語",
+    "イド語",
+    "アイスランド語",
+    "イタリア語",
+    "イヌクティトット語",
+    "日本語",
+    "ジャワ語",
+    "グリーンランド語",
+    "カンナダ語",
+    "カヌリ語",
+    "カシミール語",
+    "カザフ語",
+    "クメール語",
+    "キクユ語",
+    "キニアルワンダ語",
+    "キルギス語",
+    "コミ語",
+    "コンゴ語",
+    "韓国語",
+    "クルド語",
+    "クワニャマ語",
+    "ラテン語",
+    "ルクセンブルク語",
+    "ガンダ語",
+    "リンブルフ語",
+    "リンガラ語",
+    "ラオ語",
+    "リトアニア語",
+    "ルバ・カタンガ語",
+    "ラトビア語",
+    "マン島語",
+    "マケドニア語",
+    "マダガスカル語",
+    "マレー語",
+    "マラヤーラム語",
+    "マルタ語",
+    "マオリ語",
+    "マラーティー語",
+    "マーシャル語",
+    "モンゴル語",
+    "ナウル語",
+    "ナバホ語",
+    "北ンデベレ語",
+    "ネパール語",
+    "ンドンガ語",
+    "ノルウェー語(ブークモール)",
+    "ノルウェー語(ニーノシュク)",
+    "ノルウェー語",
+    "四川イ語",
+    "南ンデベレ語",
+    "オック語",
+    "オジブウェー語",
+    "教会スラブ語",
+    "オロモ語",
+    "オディア語",
+    "オセット語",
+    "パンジャブ語",
+    "パーリ語",
+    "ペルシア語",
+    "ポーランド語",
+    "パシュトゥー語",
+    "ポルトガル語",
+    "ケチュア語",
+    "ロマンシュ語",
+    "ルンディ語",
+    "ルーマニア語",
+    "ロシア語",
+    "サンスクリット語",
+    "サルデーニャ語",
+    "シンド語",
+    "北サーミ語",
+    "サモア語",
+    "サンゴ語",
+    "セルビア語",
+    "スコットランド・ゲール語",
+    "ショナ語",
+    "シンハラ語",
+    "スロバキア語",
+    "スロベニア語",
+    "ソマリ語",
+    "南部ソト語",
+    "スペイン語",
+    "スンダ語",
+    "スワヒリ語",
+    "スワジ語",
+    "スウェーデン語",
+    "タミル語",
+    "テルグ語",
+    "タジク語",
+    "タイ語",
+    "ティグリニア語",
+    "チベット語",
+    "トルクメン語",
+    "タガログ語",
+    "ツワナ語",
+    "トンガ語",
+    "トルコ語",
+    "ツォンガ語",
+    "タタール語",
+    "トウィ語",
+    "タヒチ語",
+    "ウイグル語",
+    "ウクライナ語",
+    "ウルドゥー語",
+    "ウズベク語",
+    "ベンダ語",
+    "ベトナム語",
+    "ヴォラピュク語",
+    "ワロン語",
+    "ウェールズ語",
+    "ウォロフ語",
+    "西フリジア語",
+    "コサ語",
+    "イディッシュ語",
+    "ヨルバ語",
+    "チワン語",
+    "ズールー語",
+];
+
+static JV: [&str; 184] = [
+    "Abkhazian",
+    "",
+    "Afrika",
+    "Akan",
+    "Albania",
+    "Amharik",
+    "Arab",
+    "Aragonese",
+    "Armenia",
+    "Assam",
+    "Avaric",
+    "",
+    "Aymara",
+    "Azerbaijan",
+    "Bambara",
+    "Bashkir",
+    "Basque",
+    "Belarus",
+    "Bengali",
+    "",
+    "Bislama",
+    "Bosnia lan Hercegovina",
+    "Breton",
+    "Bulgaria",
+    "Myanmar",
+    "Katala",
+    "Khamorro",
+    "Chechen",
+    "Nyanja",
+    "Tyonghwa",
+    "Khuvash",
+    "Kernowek",
+    "Korsika",
+    "",
+    "Kroasia",
+    "Ceska",
+    "Dansk",
+    "Divehi",
+    "Walanda",
+    "Dzongkha",
+    "Inggris",
+    "Esperanto",
+    "Estonia",
+    "Ewe",
+    "Faroe",
+    "Fijian",
+    "Suomi",
+    "Prancis",
+    "Fula",
+    "Galisia",
+    "Georgia",
+    "Jérman",
+    "Yunani",
+    "Guarani",
+    "Gujarat",
+    "Kreol Haiti",
+    "Hausa",
+    "Ibrani",
+    "Herero",
+    "India",
+    "",
+    "Hungaria",
+    "Interlingua",
+    "Indonesia",
+    "Interlingue",
+    "Irlandia",
+    "Iqbo",
+    "",
+    "Ido",
+    "Islandia",
+    "Italia",
+    "Inuktitut",
+    "Jepang",
+    "Jawa",
+    "Kalaallisut",
+    "Kannada",
+    "Kanuri",
+    "Kashmiri",
+    "Kazakh",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kirgis",
+    "Komi",
+    "",
+    "Korea",
+    "Kurdis",
+    "Kuanyama",
+    "Latin",
+    "Luksemburg",
+    "Ganda",
+    "Limburgish",
+    "Lingala",
+    "Laos",
+    "Lithuania",
+    "Luba-Katanga",
+    "Latvia",
+    "Manx",
+    "Makedonia",
+    "Malagasi",
+    "Melayu",
+    "Malayalam",
+    "Malta",
+    "Maori",
+    "Marathi",
+    "Marshallese",
+    "Mongolia",
+    "Nauru",
+    "Navajo",
+    "Ndebele Lor",
+    "Nepal",
+    "Ndonga",
+    "Bokmål Norwegia",
+    "Nynorsk Norwegia",
+    "Norwegia",
+    "Sichuan Yi",
+    "Ndebele Kidul",
+    "Ossitan",
+    "",
+    "Slavia Gerejani",
+    "Oromo",
+    "Odia",
+    "Ossetia",
+    "Punjab",
+    "",
+    "Persia",
+    "Polandia",
+    "Pashto",
+    "Portugis",
+    "Quechua",
+    "Roman",
+    "Rundi",
+    "Rumania",
+    "Rusia",
+    "Sanskerta",
+    "Sardinia",
+    "Sindhi",
+    "Sami Sisih Lor",
+    "Samoa",
+    "Sango",
+    "Serbia",
+    "Gaulia",
+    "Shona",
+    "Sinhala",
+    "Slowakia",
+    "Slovenia",
+    "Somalia",
+    "Sotho Sisih Kidul",
+    "Spanyol",
+    "Sunda",
+    "Swahili",
+    "Swati",
+    "Swedia",
+    "Tamil",
+    "Telugu",
+    "Tajik",
+    "Thailand",
+    "Tigrinya",
+    "Tibet",
+    "Turkmen",
+    "",
+    "Tswana",
+    "Tonga",
+    "Turki",
+    "Tsonga",
+    "Tatar",
+    "",
+    "Tahiti",
+    "Uighur",
+    "Ukraina",
+    "Urdu",
+    "Uzbek",
+    "Venda",
+    "Vietnam",
+    "Volapuk",
+    "Walloon",
+    "Welsh",
+    "Wolof",
+    "Frisia Sisih Kulon",
+    "Xhosa",
+    "Yiddish",
+    "Yoruba",
+    "Zhuang",
+    "Zulu",
+];
+
+static KN: [&str; 184] = [
+    "ಅಬ್ಖಾಜಿಯನ್",
+    "ಅಫಾರ್",
+    "ಆಫ್ರಿಕಾನ್ಸ್",
+    "ಅಕಾನ್",
+    "ಅಲ್ಬೇನಿಯನ್",
+    "ಅಂಹರಿಕ್",
+    "ಅರೇಬಿಕ್",
+    "ಅರಗೊನೀಸ್",
+    "ಅರ್ಮೇನಿಯನ್",
+    "ಅಸ್ಸಾಮೀಸ್",
+    "ಅವರಿಕ್",
+    "ಅವೆಸ್ಟನ್",
+    "ಅಯ್ಮಾರಾ",
+    "ಅಜೆರ್ಬೈಜಾನಿ",
+    "ಬಂಬಾರಾ",
+    "ಬಶ್ಕಿರ್",
+    "ಬಾಸ್ಕ್",
+    "ಬೆಲರೂಸಿಯನ್",
+    "ಬಾಂಗ್ಲಾ",
+    "",
+    "ಬಿಸ್ಲಾಮಾ",
+    "ಬೋಸ್ನಿಯನ್",
+    "ಬ್ರೆಟನ್",
+    "ಬಲ್ಗೇರಿಯನ್",
+    "ಬರ್ಮೀಸ್",
+    "ಕೆಟಲಾನ್",
+    "ಕಮೊರೊ",
+    "ಚಚೆನ್",
+    "ನ್ಯಾಂಜಾ",
+    "ಚೈನೀಸ್",
+    "ಚುವಾಶ್",
+    "ಕಾರ್ನಿಷ್",
+    "ಕೋರ್ಸಿಕನ್",
+    "ಕ್ರೀ",
+    "ಕ್ರೊಯೇಶಿಯನ್",
+    "ಜೆಕ್",
+    "ಡ್ಯಾನಿಶ್",
+    "ದಿವೆಹಿ",
+    "ಡಚ್",
+    "ಜೋಂಗ್\u{200C}ಖಾ",
+    "ಇಂಗ್ಲಿಷ್",
+    "ಎಸ್ಪೆರಾಂಟೊ",
+    "ಎಸ್ಟೊನಿಯನ್",
+    "ಈವ್",
+    "ಫರೋಸಿ",
+    "ಫಿಜಿಯನ್",
+    "ಫಿನ್ನಿಶ್",
+    "ಫ್ರೆಂಚ್",
+    "ಫುಲಾ",
+    "ಗ್ಯಾಲಿಶಿಯನ್",
+    "ಜಾರ್ಜಿಯನ್",
+    "ಜರ್ಮನ್",
+    "ಗ್ರೀಕ್",
+    "ಗೌರಾನಿ",
+    "ಗುಜರಾತಿ",
+    "ಹೈಟಿಯನ್ ಕ್ರಿಯೋಲಿ",
+    "ಹೌಸಾ",
+    "ಹೀಬ್ರೂ",
+    "ಹೆರೆರೊ",
+    "ಹಿಂದಿ",
+    "ಹಿರಿ ಮೊಟು",
+    "ಹಂಗೇರಿಯನ್",
+    "ಇಂಟರ್\u{200C}ಲಿಂಗ್ವಾ",
+    "ಇಂಡೋನೇಶಿಯನ್",
+    "ಇಂಟರ್\u{200C}ಲಿಂಗ್",
+    "ಐರಿಷ್",
+    "ಇಗ್ಬೊ",
+    "ಇನುಪಿಯಾಕ್",
+    "ಇಡೊ",
+    "ಐಸ್\u{200C}ಲ್ಯಾಂಡಿಕ್",
+    "ಇಟಾಲಿಯನ್",
+    "ಇನುಕ್ಟಿಟುಟ್",
+    "ಜಾಪನೀಸ್",
+    "ಜಾವಾನೀಸ್",
+    "ಕಲಾಲ್ಲಿಸುಟ್",
+    "ಕನ್ನಡ",
+    "ಕನುರಿ",
+    "ಕಾಶ್ಮೀರಿ",
+    "ಕಝಕ್",
+    "ಖಮೇರ್",
+    "ಕಿಕುಯು",
+    "ಕಿನ್ಯಾರ್\u{200C}ವಾಂಡಾ",
+    "ಕಿರ್ಗಿಜ್",
+    "ಕೋಮಿ",
+    "ಕಾಂಗೋ",
+    "ಕೊರಿಯನ್",
+    "ಕುರ್ದಿಷ್",
+    "ಕ್ವಾನ್\u{200C}ಯಾಮಾ",
+    "ಲ್ಯಾಟಿನ್",
+    "ಲಕ್ಸಂಬರ್ಗಿಷ್",
+    "ಗಾಂಡಾ",
+    "ಲಿಂಬರ್ಗಿಶ್",
+    "ಲಿಂಗಾಲ",
+    "ಲಾವೋ",
+    "ಲಿಥುವೇನಿಯನ್",
+    "ಲೂಬಾ-ಕಟಾಂಗಾ",
+    "ಲಾಟ್ವಿಯನ್",
+    "ಮ್ಯಾಂಕ್ಸ್",
+    "ಮೆಸಿಡೋನಿಯನ್",
+    "ಮಲಗಾಸಿ",
+    "ಮಲಯ್",
+    "ಮಲಯಾಳಂ",
+    "ಮಾಲ್ಟೀಸ್",
+    "ಮಾವೋರಿ",
+    "ಮರಾಠಿ",
+    "ಮಾರ್ಶಲ್ಲೀಸ್",
+    "ಮಂಗೋಲಿಯನ್",
+    "ನೌರು",
+    "ನವಾಜೊ",
+    "ಉತ್ತರ ದೆಬೆಲೆ",
+    "ನೇಪಾಳಿ",
+    "ಡೋಂಗಾ",
+    "ನಾರ್ವೆಜಿಯನ್ ಬೊಕ್ಮಲ್",
+    "ನಾರ್ವೇಜಿಯನ್ ನೈನಾರ್ಸ್ಕ್",
+    "ನಾರ್ವೇಜಿಯನ್",
+    "ಸಿಚುಅನ್ ಯಿ",
+    "ದಕ್ಷಿಣ ದೆಬೆಲೆ",
+    "ಒಸಿಟನ್",
+    "ಒಜಿಬ್ವಾ",
+    "ಚರ್ಚ್ ಸ್ಲಾವಿಕ್",
+    "ಒರೊಮೊ",
+    "ಒಡಿಯ",
+    "ಒಸ್ಸೆಟಿಕ್",
+    "ಪಂಜಾಬಿ",
+    "ಪಾಲಿ",
+    "ಪರ್ಶಿಯನ್",
+    "ಪೊಲಿಶ್",
+    "ಪಾಷ್ಟೋ",
+    "ಪೋರ್ಚುಗೀಸ್",
+    "ಕ್ವೆಚುವಾ",
+    "ರೊಮಾನ್ಶ್",
+    "ರುಂಡಿ",
+    "ರೊಮೇನಿಯನ್",
+    "ರಷ್ಯನ್",
+    "ಸಂಸ್ಕೃತ",
+    "ಸರ್ಡೀನಿಯನ್",
+    "ಸಿಂಧಿ",
+    "ಉತ್ತರ ಸಾಮಿ",
+    "ಸಮೋವನ್",
+    "ಸಾಂಗೋ",
+    "ಸೆರ್ಬಿಯನ್",
+    "ಸ್ಕಾಟಿಶ್ ಗೆಲಿಕ್",
+    "ಶೋನಾ",
+    "ಸಿಂಹಳ",
+    "ಸ್ಲೋವಾಕ್",
+    "ಸ್ಲೋವೇನಿಯನ್",
+    "ಸೊಮಾಲಿ",
+    "ದಕ್ಷಿಣ ಸೋಥೋ",
+    "ಸ್ಪ್ಯಾನಿಷ್",
+    "ಸುಂಡಾನೀಸ್",
+    "ಸ್ವಹಿಲಿ",
+    "ಸ್ವಾತಿ",
+    "ಸ್ವೀಡಿಷ್",
+    "ತಮಿಳು",
+    "ತೆಲುಗು",
+    "ತಾಜಿಕ್",
+    "ಥಾಯ್",
+    "ಟಿಗ್ರಿನ್ಯಾ",
+    "ಟಿಬೇಟಿಯನ್",
+    "ಟರ್ಕ್\u{200C}ಮೆನ್",
+    "ಟ್ಯಾಗಲೋಗ್",
+    "ಸ್ವಾನಾ",
+    "ಟೋಂಗನ್",
+    "ಟರ್ಕಿಶ್",
+    "ಸೋಂಗಾ",
+    "ಟಾಟರ್",
+    "ಟ್ವಿ",
+    "ಟಹೀಟಿಯನ್",
+    "ಉಯಿಘರ್",
+    "ಉಕ್ರೇನಿಯನ್",
+    "ಉರ್ದು",
+    "ಉಜ್ಬೇಕ್",
+    "ವೆಂಡಾ",
+    "ವಿಯೆಟ್ನಾಮೀಸ್",
+    "ವೋಲಾಪುಕ್",
+    "ವಾಲೂನ್",
+    "ವೆಲ್ಶ್",
+    "ವೋಲೋಫ್",
+    "ಪಶ್ಚಿಮ ಫ್ರಿಸಿಯನ್",
+    "ಕ್ಸೋಸ",
+    "ಯಿಡ್ಡಿಶ್",
+    "ಯೊರುಬಾ",
+    "ಝೂವಾಂಗ್",
+    "ಜುಲು",
+];
+
+static KS: [&str; 184] = [
+    "اَبخازِیان",
+    "اَفار",
+    "اَفریٖکانز",
+    "اَکان",
+    "البانِیَن",
+    "اَمہاری",
+    "عربی",
+    "اَراگونی",
+    "اَرمینیَن",
+    "اسٲمؠ",
+    "اَوارِک",
+    "اَویستَن",
+    "ایمارا",
+    "اَزَربیجانی",
+    "بَمبارا",
+    "بَشکیٖر",
+    "باسک",
+    "بیلَروٗشیَن",
+    "بَنگٲلؠ",
+    "",
+    "بِسلاما",
+    "بوسنِیَن",
+    "بریٹَن",
+    "بینا",
+    "بٔمیٖز",
+    "کَتلان",
+    "کَمورو",
+    "چیچَن",
+    "نِیَنجا",
+    "چیٖنی ﴿ترجمع اِشارٕ: خاص طور، مینڈارن چیٖنی۔﴾",
+    "چُواش",
+    "کورنِش",
+    "کارسِکَن",
+    "کری",
+    "کروشِیَن",
+    "چیک",
+    "ڈینِش",
+    "دِویہی",
+    "ڈَچ",
+    "زونگکھا",
+    "اَنگیٖزؠ",
+    "ایسپَرینٹو",
+    "ایسٹونیَن",
+    "ایٖو",
+    "فَروس",
+    "فِجیَن",
+    "فِنِش",
+    "فرانسیسی",
+    "فُلاہ",
+    "گیلِشِیَن",
+    "جارجِیَن",
+    "جٔرمَن",
+    "یوٗنٲنی",
+    "گُوارَنی",
+    "گُجرٲتی",
+    "ہیتِیاں",
+    "ہاوسا",
+    "عبرٲنؠ",
+    "ہیریٖرو",
+    "ہِندی",
+    "ہِری موتوٗ",
+    "ہَنگیریَن",
+    "اِنٹَرلِنگوا",
+    "اِنڈونیشیا",
+    "اِنٹَر لِننگویے",
+    "اَیرِش",
+    "اِگبو",
+    "اِنُپِیاک",
+    "اِڈو",
+    "آیِسلینڈِک",
+    "اِطالوی",
+    "اِنُکتِتوٗ",
+    "جاپٲنؠ",
+    "جَوَنیٖز",
+    "کَلالِسُت",
+    "کَنَڑ",
+    "کَنوٗری",
+    "کٲشُر",
+    "کازَخ",
+    "خَمیر",
+    "کِکُیوٗ",
+    "کِنیاوِندا",
+    "کِرگِز",
+    "کومی",
+    "کونگو",
+    "کوریَن",
+    "کُردِش",
+    "کُوانیاما",
+    "لاتیٖنی",
+    "لُکھزیمبورگِش",
+    "گاندا",
+    "لِمبٔرگِش",
+    "لِنگالا",
+    "لاو",
+    "لِتھوانِیَن",
+    "لوُبا کَتَنگا",
+    "لَتوِیَن",
+    "مینکس",
+    "میکَڈونیَن",
+    "مَلاگَسی",
+    "مَلَے",
+    "مٔلیالَم",
+    "مَلتیٖس",
+    "ماوری",
+    "مَرٲٹھؠ",
+    "مارشَلیٖز",
+    "مَنگولی",
+    "ناورُ",
+    "نَواجو",
+    "شُمال ڈَبیل",
+    "نیپٲلؠ",
+    "ڈونگا",
+    "ناروییَن بوکمال",
+    "ناروییَن نَے نورسک",
+    "ناروییَن",
+    "سِچوان یٖی",
+    "جنوب ڈیبیل",
+    "اوکسیٖٹَن",
+    "اوجِبوا",
+    "چٔرچ سلاوِک",
+    "اۆرومو",
+    "اۆرِیا",
+    "اۆسیٹِک",
+    "پَنجٲبؠ",
+    "پالی",
+    "فارسی",
+    "پالِش",
+    "پَشتوٗ",
+    "پُرتَگیٖز",
+    "کُویشُوا",
+    "رومانش",
+    "رُندی",
+    "رومٲنی",
+    "روٗسی",
+    "سَنسکرٕت",
+    "سراڈیٖنی",
+    "سِندی",
+    "شُمٲلی سَمی",
+    "سَمواَن",
+    "سَنگو",
+    "سٔربِیَن",
+    "سکوٹِش گیےلِک",
+    "شونا",
+    "سِنہالا",
+    "سلووَک",
+    "سلووینیَن",
+    "سومٲلی",
+    "جنوبی ستھو",
+    "ہسپانوی",
+    "سَنڈَنیٖز",
+    "سواہِلی",
+    "سواتی",
+    "سویٖڈِش",
+    "تَمِل",
+    "تیلگوٗ",
+    "تاجِک",
+    "تھاے",
+    "ٹِگرِنیا",
+    "تِبتی",
+    "تُرکمین",
+    "تَماشیک",
+    "سوانا",
+    "ٹونگا",
+    "تُرکِش",
+    "ژونگا",
+    "تَتار",
+    "توی",
+    "تاہیشِیَن",
+    "",
+    "یوٗکرینیٲیی",
+    "اُردوٗ",
+    "اُزبیک",
+    "ویندا",
+    "وِیَتنَمیٖز",
+    "وولَپُک",
+    "وَلوٗن",
+    "ویلش",
+    "وولوف",
+    "مغربی فرِشیَن",
+    "کھوسا",
+    "یِدِش",
+    "یورُبا",
+    "زُہانگ",
+    "زُلوٗ",
+];
+
+static KK: [&str; 184] = [
+    "абхаз тілі",
+    "афар тілі",
+    "африкаанс тілі",
+    "акан тілі",
+    "албан тілі",
+    "амхар тілі",
+    "араб тілі",
+    "арагон тілі",
+    "армян тілі",
+    "ассам тілі",
+    "авар тілі",
+    "",
+    "аймара тілі",
+    "әзірбайжан тілі",
+    "бамбара тілі",
+    "башқұрт тілі",
+    "баск тілі",
+    "беларусь тілі",
+    "бенгал тілі",
+    "",
+    "бислама тілі",
+    "босния тілі",
+    "бретон тілі",
+    "болгар тілі",
+    "бирма тілі",
+    "каталан тілі",
+    "чаморро тілі",
+    "шешен тілі",
+    "ньянджа тілі",
+    "қытай тілі",
+    "чуваш тілі",
+    "корн тілі",
+    "корсика тілі",
+    "",
+    "хорват тілі",
+    "чех тілі",
+    "дат тілі",
+    "дивехи тілі",
+    "нидерланд тілі",
+    "дзонг-кэ тілі",
+    "ағылшын тілі",
+    "эсперанто тілі",
+    "эстон тілі",
+    "эве тілі",
+    "фарер тілі",
+    "фиджи тілі",
+    "фин тілі",
+    "француз тілі",
+    "фула тілі",
+    "галисия тілі",
+    "грузин тілі",
+    "неміс тілі",
+    "грек тілі",
+    "гуарани тілі",
+    "гуджарати тілі",
+    "гаити тілі",
+    "хауса тілі",
+    "иврит тілі",
+    "гереро тілі",
+    "хинди тілі",
+    "",
+    "венгр тілі",
+    "интерлингва тілі",
+    "индонезия тілі",
+    "интерлингве тілі",
+    "ирланд тілі",
+    "игбо тілі",
+    "",
+    "идо тілі",
+    "исланд тілі",
+    "итальян тілі",
+    "инуктитут тілі",
+    "жапон тілі",
+    "ява тілі",
+    "калаалисут тілі",
+    "каннада тілі",
+    "канури тілі",
+    "кашмир тілі",
+    "қазақ тілі",
+    "кхмер тілі",
+    "кикуйю тілі",
+    "киньяруанда тілі",
+    "қырғыз тілі",
+    "коми тілі",
+    "",
+    "корей тілі",
+    "күрд тілі",
+    "кваньяма тілі",
+    "латын тілі",
+    "люксембург тілі",
+    "ганда тілі",
+    "лимбург тілі",
+    "лингала тілі",
+    "лаос тілі",
+    "литва тілі",
+    "луба-катанга тілі",
+    "латыш тілі",
+    "мэн тілі",
+    "македон тілі",
+    "малагаси тілі",
+    "малай тілі",
+    "малаялам тілі",
+    "мальта тілі",
+    "маори тілі",
+    "маратхи тілі",
+    "маршалл тілі",
+    "моңғол тілі",
+    "науру тілі",
+    "навахо тілі",
+    "солтүстік ндебеле тілі",
+    "непал тілі",
+    "ндонга тілі",
+    "норвегиялық букмол тілі",
+    "норвегиялық нюнорск тілі",
+    "норвег тілі",
+    "сычуан и тілі",
+    "оңтүстік ндебеле тілі",
+    "окситан тілі",
+    "",
+    "шіркеулік славян тілі",
+    "оромо тілі",
+    "ория тілі",
+    "осетин тілі",
+    "пенджаб тілі",
+    "",
+    "парсы тілі",
+    "поляк тілі",
+    "пушту тілі",
+    "португал тілі",
+    "кечуа тілі",
+    "романш тілі",
+    "рунди тілі",
+    "румын тілі",
+    "орыс тілі",
+    "санскрит тілі",
+    "сардин тілі",
+    "синдхи тілі",
+    "солтүстік саам тілі",
+    "самоа тілі",
+    "санго тілі",
+    "серб тілі",
+    "шотландиялық гэль тілі",
+    "шона тілі",
+    "сингал тілі",
+    "словак тілі",
+    "словен тілі",
+    "сомали тілі",
+    "оңтүстік сото тілі",
+    "испан тілі",
+    "сундан тілі",
+    "суахили тілі",
+    "свати тілі",
+    "швед тілі",
+    "тамил тілі",
+    "телугу тілі",
+    "тәжік тілі",
+    "тай тілі",
+    "тигринья тілі",
+    "тибет тілі",
+    "түрікмен тілі",
+    "",
+    "тсвана тілі",
+    "тонган тілі",
+    "түрік тілі",
+    "тсонга тілі",
+    "татар тілі",
+    "тви тілі",
+    "таити тілі",
+    "ұйғыр тілі",
+    "украин тілі",
+    "урду тілі",
+    "өзбек тілі",
+    "венда тілі",
+    "вьетнам тілі",
+    "волапюк тілі",
+    "валлон тілі",
+    "валлий тілі",
+    "волоф тілі",
+    "батыс фриз тілі",
+    "кхоса тілі",
+    "идиш тілі",
+    "йоруба тілі",
+    "чжуан тілі",
+    "зулу тілі",
+];
+
+static KM: [&str; 184] = [
+    "អាប់ខាហ៊្សាន",
+    "អាហ្វារ",
+    "អាហ្វ្រិកាន",
+    "អាកាន",
+    "អាល់បានី",
+    "អាំហារិក",
+    "អារ៉ាប់",
+    "អារ៉ាហ្គោន",
+    "អាមេនី",
+    "អាសាមីស",
+    "អាវ៉ារីក",
+    "អាវេស្ថាន",
+    "អីម៉ារ៉ា",
+    "អាស៊ែបៃហ្សង់",
+    "បាម្បារា",
+    "បាស្គៀ",
+    "បាសខ៍",
+    "បេឡារុស",
+    "បង់ក្លាដែស",
+    "",
+    "ប៊ីស្លាម៉ា",
+    "បូស្ន៊ី",
+    "ប្រ៊ីស្តុន",
+    "ប៊ុលហ្ការី",
+    "ភូមា",
+    "កាតាឡាន",
+    "ឈីម៉ូរ៉ូ",
+    "ឈីឆេន",
+    "ណានចា",
+    "ចិន",
+    "ឈូវ៉ាស",
+    "កូនីស",
+    "កូស៊ីខាន",
+    "",
+    "ក្រូអាត",
+    "ឆែក",
+    "ដាណឺម៉ាក",
+    "ទេវីហ៊ី",
+    "ហូឡង់",
+    "ដុងខា",
+    "អង់គ្លេស",
+    "អេស្ពេរ៉ាន់តូ",
+    "អេស្តូនី",
+    "អ៊ីវ",
+    "ហ្វារូស",
+    "ហ៊្វីជី",
+    "ហ្វាំងឡង់",
+    "បារាំង",
+    "ហ្វ៊ូឡា",
+    "ហ្កាលីស្យាន",
+    "ហ្សក\u{200B}ហ្ស៊ី",
+    "អាល្លឺម៉ង់",
+    "ក្រិក",
+    "ហ្គូរ៉ានី",
+    "ហ្គុយ៉ារ៉ាទី",
+    "ហៃទី",
+    "ហូសា",
+    "ហេប្រឺ",
+    "ហឺរីរ៉ូ",
+    "ហិណ្ឌី",
+    "",
+    "ហុងគ្រី",
+    "អ៊ីនធើលីង",
+    "ឥណ្ឌូណេស៊ី",
+    "អ៊ីនធើលីងវេ",
+    "អៀរឡង់",
+    "អ៊ីកបូ",
+    "",
+    "អ៊ីដូ",
+    "អ៊ីស្លង់",
+    "អ៊ីតាលី",
+    "អ៊ីនុកទីទុត",
+    "ជប៉ុន",
+    "ជ្វា",
+    "កាឡាលលីស៊ុត",
+    "ខាណាដា",
+    "កានូរី",
+    "កាស្មៀរ",
+    "កាហ្សាក់",
+    "ខ្មែរ",
+    "គីគូយូ",
+    "គិនយ៉ាវ៉ាន់ដា",
+    "\u{200B}កៀហ្ស៊ីស",
+    "កូមី",
+    "",
+    "កូរ៉េ",
+    "ឃឺដ",
+    "គូនយ៉ាម៉ា",
+    "ឡាតំាង",
+    "លុចសំបួ",
+    "ហ្កាន់ដា",
+    "លីមប៊ូស",
+    "លីនកាឡា",
+    "ឡាវ",
+    "លីទុយអានី",
+    "លូបាកាតានហ្គា",
+    "ឡាតវី",
+    "មេន",
+    "ម៉ាសេដូនី",
+    "ម៉ាឡាហ្គាស៊ី",
+    "ម៉ាឡេ",
+    "ម៉ាឡាយ៉ាឡាម",
+    "ម៉ាល់តា",
+    "ម៉ោរី",
+    "ម៉ារ៉ាធី",
+    "ម៉ាស់សល",
+    "ម៉ុងហ្គោលី",
+    "ណូរូ",
+    "ណាវ៉ាចូ",
+    "នេបេលេខាងជើង",
+    "នេប៉ាល់",
+    "នុនហ្គា",
+    "ន័រវែស បុកម៉ាល់",
+    "ន័រវែស នីនូស",
+    "ន័រវែស",
+    "ស៊ីឈាន់យី",
+    "នេប៊េលខាងត្បូង",
+    "អូសីតាន់",
+    "",
+    "ឈើជស្លាវិក",
+    "អូរ៉ូម៉ូ",
+    "អូឌៀ",
+    "អូស៊ីទិក",
+    "បឹនជាពិ",
+    "",
+    "ភឺសៀន",
+    "ប៉ូឡូញ",
+    "បាស្តូ",
+    "ព័រទុយហ្គាល់",
+    "ហ្គិកឈួ",
+    "រ៉ូម៉ង់",
+    "រុណ្ឌី",
+    "រូម៉ានី",
+    "រុស្ស៊ី",
+    "សំស្ក្រឹត",
+    "សាឌីនា",
+    "ស៊ីនឌី",
+    "សាមីខាងជើង",
+    "សាម័រ",
+    "សានហ្គោ",
+    "ស៊ែប",
+    "ស្កុតហ្កែលិគ",
+    "សូណា",
+    "ស្រីលង្កា",
+    "ស្លូវ៉ាគី",
+    "ស្លូវ៉ានី",
+    "សូម៉ាលី",
+    "សូថូខាងត្បូង",
+    "អេស្ប៉ាញ",
+    "ស៊ូដង់",
+    "ស្វាហ៊ីលី",
+    "ស្វាទី",
+    "ស៊ុយអែត",
+    "តាមីល",
+    "តេលុគុ",
+    "តាហ្ស៊ីគ",
+    "ថៃ",
+    "ទីហ្គ្រីញ៉ា",
+    "ទីបេ",
+    "តួកម៉េន",
+    "",
+    "ស្វាណា",
+    "តុងហ្គា",
+    "ទួរគី",
+    "សុងហ្គា",
+    "តាតា",
+    "ទ្វី",
+    "តាហ៊ីទី",
+    "អ៊ុយហ្គឺរ",
+    "អ៊ុយក្រែន",
+    "អ៊ូរឌូ",
+    "អ៊ូសបេគ",
+    "វេនដា",
+    "វៀតណាម",
+    "វូឡាពូក",
+    "វ៉ាលូន",
+    "វេល",
+    "វូឡុហ្វ",
+    "ហ្វ្រីស៊ានខាងលិច",
+    "ឃសា",
+    "យ៉ីឌីស",
+    "យរូបា",
+    "ហ្សួង",
+    "ហ្សូលូ",
+];
+
+static RW: [&str; 184] = [
+    "",
+    "",
+    "Ikinyafurikaneri",
+    "",
+    "Icyalubaniya",
+    "Inyamuhariki",
+    "Icyarabu",
+    "",
+    "Ikinyarumeniya",
+    "Icyasamizi",
+    "",
+    "",
+    "",
+    "Inyazeribayijani",
+    "",
+    "",
+    "Ikibasiki",
+    "Ikibelarusiya",
+    "Ikibengali",
+    "",
+    "",
+    "Inyebosiniya",
+    "Inyebiritoni",
+    "Urunyabuligariya",
+    "",
+    "Igikatalani",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "Igikorowasiya",
+    "Igiceke",
+    "Ikidaninwa",
+    "",
+    "Ikinerilande",
+    "",
+    "Icyongereza",
+    "Icyesiperanto",
+    "Icyesitoniya",
+    "",
+    "Inyefaroyizi",
+    "",
+    "Igifinilande",
+    "Igifaransa",
+    "",
+    "Ikigalisiya",
+    "Inyejeworujiya",
+    "Ikidage",
+    "Ikigereki",
+    "Inyaguwarani",
+    "Inyegujarati",
+    "",
+    "",
+    "Igiheburayo",
+    "",
+    "Igihindi",
+    "",
+    "Igihongiriya",
+    "Ururimi Gahuzamiryango",
+    "Ikinyendoziya",
+    "Uruhuzandimi",
+    "Ikirilandi",
+    "",
+    "",
+    "",
+    "Igisilande",
+    "Igitaliyani",
+    "",
+    "Ikiyapani",
+    "Inyejava",
+    "",
+    "Igikanada",
+    "",
+    "",
+    "",
+    "Igikambodiya",
+    "",
+    "Ikinyarwanda",
+    "Inkerigizi",
+    "",
+    "",
+    "Igikoreya",
+    "Inyekuridishi",
+    "",
+    "Ikilatini",
+    "",
+    "",
+    "",
+    "Ilingala",
+    "Ikilawotiyani",
+    "Ikilituwaniya",
+    "",
+    "Ikinyaletoviyani",
+    "",
+    "Ikimasedoniya",
+    "",
+    "Ikimalayi",
+    "Ikimalayalami",
+    "Ikimaliteze",
+    "",
+    "Ikimarati",
+    "",
+    "Ikimongoli",
+    "",
+    "",
+    "",
+    "Ikinepali",
+    "",
+    "",
+    "Inyenoruveji (Nyonorusiki)",
+    "Ikinoruveji",
+    "",
+    "",
+    "Inyogusitani",
+    "",
+    "",
+    "",
+    "Inyoriya",
+    "",
+    "Igipunjabi",
+    "",
+    "Inyeperisi",
+    "Igipolone",
+    "Impashito",
+    "Igiporutugali",
+    "",
+    "",
+    "",
+    "Ikinyarumaniya",
+    "Ikirusiya",
+    "Igisansikiri",
+    "",
+    "Igisindi",
+    "",
+    "",
+    "",
+    "Igiseribe",
+    "Ikigaluwa cy’Igisweduwa",
+    "",
+    "Inyesimpaleze",
+    "Igisilovaki",
+    "Ikinyasiloveniya",
+    "Igisomali",
+    "Inyesesoto",
+    "Icyesipanyolo",
+    "Inyesudani",
+    "Igiswahili",
+    "",
+    "Igisuweduwa",
+    "Igitamili",
+    "Igitelugu",
+    "",
+    "Igitayi",
+    "Inyatigirinya",
+    "",
+    "Inyeturukimeni",
+    "",
+    "",
+    "",
+    "Igiturukiya",
+    "",
+    "",
+    "Inyetuwi",
+    "",
+    "Ikiwiguri",
+    "Ikinyayukereni",
+    "Inyeyurudu",
+    "Inyeyuzubeki",
+    "",
+    "Ikinyaviyetinamu",
+    "",
+    "",
+    "Ikigaluwa",
+    "",
+    "Igifiriziyani",
+    "Inyehawusa",
+    "Inyeyidishi",
+    "",
+    "",
+    "Inyezulu",
+];
+
+static KY: [&str; 184] = [
+    "абхазча",
+    "афарча",
+    "африкаанча",
+    "аканча",
+    "албанча",
+    "амхарча",
+    "арабча",
+    "арагончо",
+    "армянча",
+    "ассамча",
+    "аварикче",
+    "",
+    "аймарача",
+    "азербайжанча",
+    "бамбарача",
+    "башкырча",
+    "баскча",
+    "беларусча",
+    "бангладешче",
+    "",
+    "бисламача",
+    "боснийче",
+    "бретончо",
+    "болгарча",
+    "бурмача",
+    "каталончо",
+    "чаморрочо",
+    "чеченче",
+    "ньянджача",
+    "кытайча",
+    "чувашча",
+    "корнишче",
+    "корсиканча",
+    "",
+    "хорватча",
+    "чехче",
+    "датча",
+    "дивехиче",
+    "голландча",
+    "жонгуча",
+    "англисче",
+    "эсперанто",
+    "эстончо",
+    "эбече",
+    "фарерче",
+    "фижиче",
+    "финче",
+    "французча",
+    "фулача",
+    "галисияча",
+    "грузинче",
+    "немисче",
+    "грекче",
+    "гуараниче",
+    "гужаратча",
+    "гаитиче",
+    "хаусача",
+    "ивритче",
+    "герерочо",
+    "хиндиче",
+    "",
+    "венгерче",
+    "интерлингва",
+    "индонезияча",
+    "интерлинг",
+    "ирландча",
+    "игбочо",
+    "",
+    "идочо",
+    "исландча",
+    "италиянча",
+    "инуктитутча",
+    "жапончо",
+    "жаванизче",
+    "калаалисутча",
+    "каннадача",
+    "кануриче",
+    "кашмирче",
+    "казакча",
+    "кмерче",
+    "кикуйиче",
+    "руандача",
+    "кыргызча",
+    "комиче",
+    "",
+    "корейче",
+    "курдча",
+    "куаньямача",
+    "латынча",
+    "люксембургча",
+    "гандача",
+    "лимбургиче",
+    "лингалача",
+    "лаочо",
+    "литовчо",
+    "луба-катангача",
+    "латышча",
+    "мэнксыча",
+    "македончо",
+    "малагасча",
+    "малайча",
+    "малайаламча",
+    "малтизче",
+    "маориче",
+    "маратиче",
+    "маршаллча",
+    "монголчо",
+    "науруча",
+    "наваджочо",
+    "түндүк ндыбелче",
+    "непалча",
+    "ндонгача",
+    "норвежче (букмал)",
+    "норвежче (нинорск)",
+    "норвежче",
+    "сычуань йиче",
+    "түштүк ндебелече",
+    "окситанча",
+    "",
+    "чиркөө славянча",
+    "оромочо",
+    "орияча",
+    "осетинче",
+    "пунжабиче",
+    "",
+    "фарсча",
+    "полякча",
+    "пуштуча",
+    "португалча",
+    "кечуача",
+    "романшча",
+    "рундиче",
+    "румынча",
+    "орусча",
+    "санскритче",
+    "сардинче",
+    "синдхиче",
+    "түндүк саамиче",
+    "самоанча",
+    "сангочо",
+    "сербче",
+    "шотладиялык гелча",
+    "шонача",
+    "сингалача",
+    "словакча",
+    "словенче",
+    "сомаличе",
+    "сесоточо",
+    "испанча",
+    "сунданча",
+    "суахиличе",
+    "сватиче",
+    "шведче",
+    "тамилче",
+    "телугуча",
+    "тажикче",
+    "тайча",
+    "тигриниача",
+    "тибетче",
+    "түркмөнчө",
+    "",
+    "тсванача",
+    "тонгача",
+    "түркчө",
+    "тсонгача",
+    "татарча",
+    "тви",
+    "таитиче",
+    "уйгурча",
+    "украинче",
+    "урдуча",
+    "өзбекче",
+    "вендача",
+    "вьетнамча",
+    "волапюкча",
+    "валлончо",
+    "уелшче",
+    "уолофчо",
+    "батыш фризче",
+    "косача",
+    "идишче",
+    "йорубача",
+    "чжуанча",
+    "зулуча",
+];
+
+static KO: [&str; 184] = [
+    "압카즈어",
+    "아파르어",
+    "아프리칸스어",
+    "아칸어",
+    "알바니아어",
+    "암하라어",
+    "아랍어",
+    "아라곤어",
+    "아르메니아어",
+    "아삼어",
+    "아바릭어",
+    "아베스타어",
+    "아이마라어",
+    "아제르바이잔어",
+    "밤바라어",
+    "바슈키르어",
+    "바스크어",
+    "벨라루스어",
+    "벵골어",
+    "",
+    "비슬라마어",
+    "보스니아어",
+    "브르타뉴어",
+    "불가리아어",
+    "버마어",
+    "카탈로니아어",
+    "차모로어",
+    "체첸어",
+    "냔자어",
+    "중국어",
+    "추바시어",
+    "콘월어",
+    "코르시카어",
+    "크리어",
+    "크로아티아어",
+    "체코어",
+    "덴마크어",
+    "디베히어",
+    "네덜란드어",
+    "종카어",
+    "영어",
+    "에스페란토어",
+    "에스토니아어",
+    "에웨어",
+    "페로어",
+    "피지어",
+    "핀란드어",
+    "프랑스어",
+    "풀라어",
+    "갈리시아어",
+    "조지아어",
+    "독일어",
+    "그리스어",
+    "과라니어",
+    "구자라트어",
+    "아이티어",
+    "하우사어",
+    "히브리어",
+    "헤레로어",
+    "힌디어",
+    "히리 모투어",
+    "헝가리어",
+    "인터링구아",
+    "인도네시아어",
+    "인테르링구에",
+    "아일랜드어",
+    "이그보어",
+    "이누피아크어",
+    "이도어",
+    "아이슬란드어",
+    "이탈리아어",
+    "이눅티투트어",
+    "일본어",
+    "자바어",
+    "그린란드어",
+    "칸나다어",
+    "칸누리어",
+    "카슈미르어",
+    "카자흐어",
+    "크메르어",
+    "키쿠유어",
+    "르완다어",
+    "키르기스어",
+    "코미어",
+    "콩고어",
+    "한국어",
+    "쿠르드어",
+    "쿠안야마어",
+    "라틴어",
+    "룩셈부르크어",
+    "간다어",
+    "림버거어",
+    "링갈라어",
+    "라오어",
+    "리투아니아어",
+    "루바-카탄가어",
+    "라트비아어",
+    "맹크스어",
+    "마케도니아어",
+    "말라가시어",
+    "말레이어",
+    "말라얄람어",
+    "몰타어",
+    "마오리어",
+    "마라티어",
+    "마셜어",
+    "몽골어",
+    "나우루어",
+    "나바호어",
+    "북부 은데벨레어",
+    "네팔어",
+    "느동가어",
+    "노르웨이어(보크말)",
+    "노르웨이어(니노르스크)",
+    "노르웨이어",
+    "쓰촨 이어",
+    "남부 은데벨레어",
+    "오크어",
+    "오지브와어",
+    "교회 슬라브어",
+    "오로모어",
+    "오리야어",
+    "오세트어",
+    "펀잡어",
+    "팔리어",
+    "페르시아어",
+    "폴란드어",
+    "파슈토어",
+    "포르투갈어",
+    "케추아어",
+    "로만시어",
+    "룬디어",
+    "루마니아어",
+    "러시아어",
+    "산스크리트어",
+    "사르디니아어",
+    "신디어",
+    "북부 사미어",
+    "사모아어",
+    "산고어",
+    "세르비아어",
+    "스코틀랜드 게일어",
+    "쇼나어",
+    "싱할라어",
+    "슬로바키아어",
+    "슬로베니아어",
+    "소말리아어",
+    "남부 소토어",
+    "스페인어",
+    "순다어",
+    "스와힐리어",
+    "시스와티어",
+    "스웨덴어",
+    "타밀어",
+    "텔루구어",
+    "타지크어",
+    "태국어",
+    "티그리냐어",
+    "티베트어",
+    "투르크멘어",
+    "타갈로그어",
+    "츠와나어",
+    "통가어",
+    "튀르키예어",
+    "총가어",
+    "타타르어",
+    "트위어",
+    "타히티어",
+    "위구르어",
+    "우크라이나어",
+    "우르두어",
+    "우즈베크어",
+    "벤다어",
+    "베트남어",
+    "볼라퓌크어",
+    "왈론어",
+    "웨일스어",
+    "월로프어",
+    "서부 프리지아어",
+    "코사어",
+    "이디시어",
+    "요루바어",
+    "주앙어",
+    "줄루어",
+];
+
+static KU: [&str; 184] = [
+    "abxazî",
+    "afarî",
+    "afrîkansî",
+    "akanî",
+    "arnawidî",
+    "amharî",
+    "erebî",
+    "aragonî",
+    "ermenî",
+    "asamî",
+    "avarî",
+    "",
+    "aymarayî",
+    "azerî",
+    "bambarayî",
+    "başkîrî",
+    "baskî",
+    "belarûsî",
+    "bengalî",
+    "",
+    "bîslamayî",
+    "bosnî",
+    "bretonî",
+    "bulgarî",
+    "burmayî",
+    "katalanî",
+    "çamoroyî",
+    "çeçenî",
+    "çîçewayî",
+    "çînî",
+    "çuvaşî",
+    "kornî",
+    "korsîkayî",
+    "",
+    "xirwatî",
+    "çekî",
+    "danmarkî",
+    "divehî",
+    "holendî",
+    "conxayî",
+    "îngilîzî",
+    "esperantoyî",
+    "estonî",
+    "eweyî",
+    "ferî",
+    "fîjî",
+    "fînî",
+    "fransizî",
+    "fulahî",
+    "galîsî",
+    "gurcî",
+    "almanî",
+    "yûnanî",
+    "guwaranî",
+    "gujaratî",
+    "haîtî",
+    "hawsayî",
+    "îbranî",
+    "hereroyî",
+    "hindî",
+    "",
+    "mecarî",
+    "înterlîngua",
+    "endonezyayî",
+    "înterlîngue",
+    "îrlendî",
+    "îgboyî",
+    "",
+    "îdoyî",
+    "îzlendî",
+    "îtalî",
+    "înuîtî",
+    "japonî",
+    "javayî",
+    "kalalîsûtî",
+    "kannadayî",
+    "kanurîyî",
+    "keşmîrî",
+    "qazaxî",
+    "ximêrî",
+    "kîkûyûyî",
+    "kînyariwandayî",
+    "kirgizî",
+    "komî",
+    "",
+    "koreyî",
+    "kurdî (kurmancî)",
+    "kwanyamayî",
+    "latînî",
+    "luksembûrgî",
+    "lugandayî",
+    "lîmbûrgî",
+    "lingalayî",
+    "lawsî",
+    "lîtwanî",
+    "luba-katangayî",
+    "latvîyayî",
+    "manksî",
+    "makedonî",
+    "malagasî",
+    "malezî",
+    "malayalamî",
+    "maltayî",
+    "maorî",
+    "maratî",
+    "marşalî",
+    "moxolî",
+    "nawrûyî",
+    "navajoyî",
+    "ndebelîya bakurî",
+    "nepalî",
+    "ndongayî",
+    "norwecî (bokmål)",
+    "norwecî (nynorsk)",
+    "norwecî",
+    "yîyîya siçuwayî",
+    "ndebelîya başûrî",
+    "oksîtanî",
+    "",
+    "slavîya kenîseyî",
+    "oromoyî",
+    "oriyayî",
+    "osetî",
+    "puncabî",
+    "",
+    "farisî",
+    "polonî",
+    "peştûyî",
+    "portugalî",
+    "keçwayî",
+    "romancî",
+    "rundî",
+    "romanî",
+    "rûsî",
+    "sanskrîtî",
+    "sardînî",
+    "sindhî",
+    "samîya bakur",
+    "samoayî",
+    "sangoyî",
+    "sirbî",
+    "gaelîka skotî",
+    "şonayî",
+    "kîngalî",
+    "slovakî",
+    "slovenî",
+    "somalî",
+    "sotoyîya başûr",
+    "spanî",
+    "sundanî",
+    "swahîlî",
+    "swazî",
+    "swêdî",
+    "tamîlî",
+    "telûgûyî",
+    "tacikî",
+    "tayî",
+    "tigrînî",
+    "tîbetî",
+    "tirkmenî",
+    "",
+    "tswanayî",
+    "tongî",
+    "tirkî",
+    "tsongayî",
+    "teterî",
+    "",
+    "tahîtî",
+    "oygurî",
+    "ukraynî",
+    "urdûyî",
+    "ozbekî",
+    "",
+    "vîetnamî",
+    "volapûkî",
+    "walonî",
+    "weylsî",
+    "wolofî",
+    "frîsî",
+    "xosayî",
+    "yidîşî",
+    "yorubayî",
+    "zhuangî",
+    "zuluyî",
+];
+
+static LB: [&str; 184] = [
+    "Abchasesch",
+    "Afar",
+    "Afrikaans",
+    "Akan",
+    "Albanesch",
+    "Amharesch",
+    "Arabesch",
+    "Aragonesesch",
+    "Armenesch",
+    "Assamesesch",
+    "Awaresch",
+    "Avestesch",
+    "Aymara",
+    "Aserbaidschanesch",
+    "Bambara-Sprooch",
+    "Baschkiresch",
+    "Baskesch",
+    "Wäissrussesch",
+    "Bengalesch",
+    "",
+    "Bislama",
+    "Bosnesch",
+    "Bretonesch",
+    "Bulgaresch",
+    "Birmanesch",
+    "Katalanesch",
+    "Chamorro-Sprooch",
+    "Tschetschenesch",
+    "Nyanja-Sprooch",
+    "Chinesesch",
+    "Tschuwaschesch",
+    "Kornesch",
+    "Korsesch",
+    "Cree",
+    "Kroatesch",
+    "Tschechesch",
+    "Dänesch",
+    "Maldivesch",
+    "Hollännesch",
+    "Bhutanesch",
+    "Englesch",
+    "Esperanto",
+    "Estnesch",
+    "Ewe-Sprooch",
+    "Färöesch",
+    "Fidschianesch",
+    "Finnesch",
+    "Franséisch",
+    "Ful",
+    "Galizesch",
+    "Georgesch",
+    "Däitsch",
+    "Griichesch",
+    "Guarani",
+    "Gujarati",
+    "Haitianesch",
+    "Hausa",
+    "Hebräesch",
+    "Herero-Sprooch",
+    "Hindi",
+    "Hiri-Motu",
+    "Ungaresch",
+    "Interlingua",
+    "Indonesesch",
+    "Interlingue",
+    "Iresch",
+    "Igbo-Sprooch",
+    "Inupiak",
+    "Ido-Sprooch",
+    "Islännesch",
+    "Italienesch",
+    "Inukitut",
+    "Japanesch",
+    "Javanesch",
+    "Grönlännesch",
+    "Kannada",
+    "Kanuri-Sprooch",
+    "Kaschmiresch",
+    "Kasachesch",
+    "Kambodschanesch",
+    "Kikuyu-Sprooch",
+    "Ruandesch",
+    "Kirgisesch",
+    "Komi-Sprooch",
+    "Kongolesesch",
+    "Koreanesch",
+    "Kurdesch",
+    "Kwanyama",
+    "Latäin",
+    "Lëtzebuergesch",
+    "Ganda-Sprooch",
+    "Limburgesch",
+    "Lingala",
+    "Laotesch",
+    "Litauesch",
+    "Luba-Katanga",
+    "Lettesch",
+    "Manx",
+    "Mazedonesch",
+    "Malagassi-Sprooch",
+    "Malaiesch",
+    "Malayalam",
+    "Maltesesch",
+    "Maori",
+    "Marathi",
+    "Marschallesesch",
+    "Mongolesch",
+    "Nauruesch",
+    "Navajo",
+    "Nord-Ndebele-Sprooch",
+    "Nepalesesch",
+    "Ndonga",
+    "Norwegesch Bokmål",
+    "Norwegesch Nynorsk",
+    "Norwegesch",
+    "Sichuan Yi",
+    "Süd-Ndebele-Sprooch",
+    "Okzitanesch",
+    "Ojibwa-Sprooch",
+    "Kiercheslawesch",
+    "Oromo",
+    "Orija",
+    "Ossetesch",
+    "Pandschabesch",
+    "Pali",
+    "Persesch",
+    "Polnesch",
+    "Paschtu",
+    "Portugisesch",
+    "Quechua",
+    "Rätoromanesch",
+    "Rundi-Sprooch",
+    "Rumänesch",
+    "Russesch",
+    "Sanskrit",
+    "Sardesch",
+    "Sindhi",
+    "Nordsamesch",
+    "Samoanesch",
+    "Sango",
+    "Serbesch",
+    "Schottescht Gällesch",
+    "Shona",
+    "Singhalesesch",
+    "Slowakesch",
+    "Slowenesch",
+    "Somali",
+    "Süd-Sotho-Sprooch",
+    "Spuenesch",
+    "Sundanesesch",
+    "Suaheli",
+    "Swazi",
+    "Schwedesch",
+    "Tamilesch",
+    "Telugu",
+    "Tadschikesch",
+    "Thailännesch",
+    "Tigrinja",
+    "Tibetesch",
+    "Turkmenesch",
+    "Dagalog",
+    "Tswana-Sprooch",
+    "Tongaesch",
+    "Tierkesch",
+    "Tsonga",
+    "Tataresch",
+    "Twi",
+    "Tahitesch",
+    "Uiguresch",
+    "Ukrainesch",
+    "Urdu",
+    "Usbekesch",
+    "Venda-Sprooch",
+    "Vietnamesesch",
+    "Volapük",
+    "Wallounesch",
+    "Walisesch",
+    "Wolof",
+    "Westfriesesch",
+    "Xhosa",
+    "Jiddesch",
+    "Yoruba",
+    "Zhuang",
+    "Zulu",
+];
+
+static LO: [&str; 184] = [
+    "ແອບຄາຊຽນ",
+    "ອະຟາ",
+    "ອາຟຣິການ",
+    "ອາການ",
+    "ອານບານຽນ",
+    "ອຳຮາຣິກ",
+    "ອາຣັບ",
+    "ອາຣາໂກເນັດ",
+    "ອາເມນຽນ",
+    "ອັສຊາມີສ",
+    "ອາວາຣິກ",
+    "ອາເວັສແຕນ",
+    "ອາຍມາລາ",
+    "ອາເຊີໄບຈານິ",
+    "ບາມບາຣາ",
+    "ບາຣກີ",
+    "ບັສກີ",
+    "ເບລາຣັສຊຽນ",
+    "ເບັງກາລີ",
+    "",
+    "ບິສລະມາ",
+    "ບອສນຽນ",
+    "ເບຣຕັນ",
+    "ບັງກາຣຽນ",
+    "ມຽນມາ",
+    "ຄາຕາລານ",
+    "ຊາມໍໂຣ",
+    "ຊີເຄນ",
+    "ນານຈາ",
+    "ຈີນ",
+    "ຊູວາຊ",
+    "ຄໍນິຊ",
+    "ຄໍຊິກາ",
+    "ຄີ",
+    "ໂຄຣເອທຽນ",
+    "ເຊກ",
+    "ແດນິຊ",
+    "ດີວີຮີ",
+    "ດັຊ",
+    "ດີຊອງຄາ",
+    "ອັງກິດ",
+    "ເອສປາຍ",
+    "ເອສໂຕນຽນ",
+    "ອິວາ",
+    "ຟາໂຣສ",
+    "ຟິຈຽນ",
+    "ຟິນນິຊ",
+    "ຝຣັ່ງ",
+    "ຟູລາ",
+    "ກາລິຊຽນ",
+    "ຈໍຈຽນ",
+    "ເຢຍລະມັນ",
+    "ກຣີກ",
+    "ກົວຣານີ",
+    "ກູຈາຣາຕິ",
+    "ໄຮຕຽນ",
+    "ເຮົາຊາ",
+    "ຮີບຣິວ",
+    "ເຮິຮິໂຣ",
+    "ຮິນດິ",
+    "ຮິຣິໂມຕູ",
+    "ຮັງກາຣຽນ",
+    "ອິນເຕີລິງລົວ",
+    "ອິນໂດເນຊຽນ",
+    "ອິນເຕີລິງກຣີ",
+    "ໄອຣິສ",
+    "ອິກໂບ",
+    "ອິນນູປຽກ",
+    "ອີໂດ",
+    "ໄອສແລນດິກ",
+    "ອິຕາລຽນ",
+    "ອິນນຸກຕິຕັດ",
+    "ຍີ່ປຸ່ນ",
+    "ຈາແວນີສ",
+    "ກຣີນແລນລິດ",
+    "ຄັນນາດາ",
+    "ຄານຸລິ",
+    "ຄາສເມຍຣິ",
+    "ຄາຊັກ",
+    "ຂະເໝນ",
+    "ຄິຄູຢຸ",
+    "ຄິນຢາວານດາ",
+    "ເກຍກີສ",
+    "ໂຄມິ",
+    "ຄອງໂກ",
+    "ເກົາຫລີ",
+    "ເຄີດິສ",
+    "ກວນຍາມາ",
+    "ລາຕິນ",
+    "ລັກເຊມບວກກິຊ",
+    "ແກນດາ",
+    "ລິມເບີກີຊ",
+    "ລິງກາລາ",
+    "ລາວ",
+    "ລິທົວນຽນ",
+    "ລູບາ-ຄາຕັງກາ",
+    "ລັດວຽນ",
+    "ແມງຊ໌",
+    "ແມຊິໂດນຽນ",
+    "ມາລາກາສຊີ",
+    "ມາເລ",
+    "ມາເລອາລຳ",
+    "ມອລທີສ",
+    "ມາວຣິ",
+    "ມາຣາທີ",
+    "ມາຊານເລັດ",
+    "ມອງໂກເລຍ",
+    "ນາຢູລູ",
+    "ນາວາໂຈ",
+    "ເອັນເດເບເລເໜືອ",
+    "ເນປາລີ",
+    "ເອັນດອງກາ",
+    "ນໍເວຈຽນ ບັອກມອລ",
+    "ນໍເວຈຽນ ນີນອກ",
+    "ນໍເວຈຽນ",
+    "ເສສວນ ອີ",
+    "ນີບີລີໃຕ້",
+    "ອັອກຊີຕານ",
+    "ໂອຈິບວາ",
+    "ໂບດສລາວິກ",
+    "ໂອໂຣໂມ",
+    "ໂອເດຍ",
+    "ອອດເຊຕິກ",
+    "ປັນຈາບີ",
+    "ປາລີ",
+    "ເປີຊຽນ",
+    "ໂປລິຊ",
+    "ປາສໂຕ",
+    "ປອກຕຸຍກິສ",
+    "ຄີຊົວ",
+    "ໂຣແມນຊ໌",
+    "ຣຸນດິ",
+    "ໂຣແມນຽນ",
+    "ລັດເຊຍ",
+    "ສັນສະກຣິດ",
+    "ສາດີນຽນ",
+    "ສິນທິ",
+    "ຊາມິເໜືອ",
+    "ຊາມົວ",
+    "ແຊງໂກ",
+    "ເຊີບຽນ",
+    "ສະກັອດເກລິກ",
+    "ໂຊນາ",
+    "ສິນຫາລາ",
+    "ສະໂລແວັກ",
+    "ສະໂລເວນຽນ",
+    "ໂຊມາລີ",
+    "ໂຊໂທໃຕ້",
+    "ສະແປນນິຊ",
+    "ຊຸນແດນນີສ",
+    "ຊວາຮີລິ",
+    "ສະວາຕິ",
+    "ສະວີດິຊ",
+    "ທາມິລ",
+    "ເຕລູກູ",
+    "ທາຈິກ",
+    "ໄທ",
+    "ຕິກຣິນຢາ",
+    "ທິເບທັນ",
+    "ເທີກເມັນ",
+    "ຕາກາລອກ",
+    "ເຕສະວານາ",
+    "ທອງການ",
+    "ເທີຄິຊ",
+    "ເຕຊອງກາ",
+    "ທາທາ",
+    "ທວີ",
+    "ຕາຮີຕຽນ",
+    "ອຸຍເຄີ",
+    "ຢູເຄຣນຽນ",
+    "ອູຣດູ",
+    "ອຸສເບກ",
+    "ເວນດາ",
+    "ຫວຽດນາມ",
+    "ໂວລາພັກ",
+    "ວໍລູມ",
+    "ເວວ",
+    "ວໍລອບ",
+    "ຟຣິຊຽນ ຕາເວັນຕົກ",
+    "ໂຮຊາ",
+    "ຢິວ",
+    "ໂຢຣູບາ",
+    "ຊວາງ",
+    "ຊູລູ",
+];
+
+static LT: [&str; 184] = [
+    "abchazų",
+    "afarų",
+    "afrikanų",
+    "akanų",
+    "albanų",
+    "amharų",
+    "arabų",
+    "aragonesų",
+    "armėnų",
+    "asamų",
+    "avarikų",
+    "avestų",
+    "aimarų",
+    "azerbaidžaniečių",
+    "bambarų",
+    "baškirų",
+    "baskų",
+    "baltarusių",
+    "bengalų",
+    "",
+    "bislama",
+    "bosnių",
+    "bretonų",
+    "bulgarų",
+    "birmiečių",
+    "katalonų",
+    "čamorų",
+    "čečėnų",
+    "nianjų",
+    "kinų",
+    "čiuvašų",
+    "kornų",
+    "korsikiečių",
+    "kry",
+    "kroatų",
+    "čekų",
+    "danų",
+    "divehų",
+    "olandų",
+    "botijų",
+    "anglų",
+    "esperanto",
+    "estų",
+    "evių",
+    "farerų",
+    "fidžių",
+    "suomių",
+    "prancūzų",
+    "fulahų",
+    "galisų",
+    "gruzinų",
+    "vokiečių",
+    "graikų",
+    "gvaranių",
+    "gudžaratų",
+    "Haičio",
+    "hausų",
+    "hebrajų",
+    "hererų",
+    "hindi",
+    "hiri motu",
+    "vengrų",
+    "tarpinė",
+    "indoneziečių",
+    "interkalba",
+    "airių",
+    "igbų",
+    "inupiakų",
+    "ido",
+    "islandų",
+    "italų",
+    "inukitut",
+    "japonų",
+    "javiečių",
+    "kalalisut",
+    "kanadų",
+    "kanurių",
+    "kašmyrų",
+    "kazachų",
+    "khmerų",
+    "kikujų",
+    "kinjaruandų",
+    "kirgizų",
+    "komi",
+    "Kongo",
+    "korėjiečių",
+    "kurdų",
+    "kuaniama",
+    "lotynų",
+    "liuksemburgiečių",
+    "ganda",
+    "limburgiečių",
+    "ngalų",
+    "laosiečių",
+    "lietuvių",
+    "luba katanga",
+    "latvių",
+    "meniečių",
+    "makedonų",
+    "malagasų",
+    "malajiečių",
+    "malajalių",
+    "maltiečių",
+    "maorių",
+    "maratų",
+    "Maršalo Salų",
+    "mongolų",
+    "naurų",
+    "navajų",
+    "šiaurės ndebelų",
+    "nepaliečių",
+    "ndongų",
+    "norvegų bukmolas",
+    "naujoji norvegų",
+    "norvegų",
+    "sičuan ji",
+    "pietų ndebele",
+    "očitarų",
+    "ojibva",
+    "bažnytinė slavų",
+    "oromų",
+    "odijų",
+    "osetinų",
+    "pendžabų",
+    "pali",
+    "persų",
+    "lenkų",
+    "puštūnų",
+    "portugalų",
+    "kečujų",
+    "retoromanų",
+    "rundi",
+    "rumunų",
+    "rusų",
+    "sanskritas",
+    "sardiniečių",
+    "sindų",
+    "šiaurės samių",
+    "Samoa",
+    "sango",
+    "serbų",
+    "škotų (gėlų)",
+    "šonų",
+    "sinhalų",
+    "slovakų",
+    "slovėnų",
+    "somaliečių",
+    "pietų Soto",
+    "ispanų",
+    "sundų",
+    "suahilių",
+    "svatų",
+    "švedų",
+    "tamilų",
+    "telugų",
+    "tadžikų",
+    "tajų",
+    "tigrajų",
+    "tibetiečių",
+    "turkmėnų",
+    "tagalogų",
+    "tsvanų",
+    "tonganų",
+    "turkų",
+    "tsongų",
+    "totorių",
+    "tvi",
+    "taitiečių",
+    "uigūrų",
+    "ukrainiečių",
+    "urdų",
+    "uzbekų",
+    "vendų",
+    "vietnamiečių",
+    "volapiuko",
+    "valonų",
+    "valų",
+    "volofų",
+    "vakarų fryzų",
+    "kosų",
+    "jidiš",
+    "jorubų",
+    "chuang",
+    "zulų",
+];
+
+static LV: [&str; 184] = [
+    "abhāzu",
+    "afāru",
+    "afrikandu",
+    "akanu",
+    "albāņu",
+    "amharu",
+    "arābu",
+    "aragoniešu",
+    "armēņu",
+    "asamiešu",
+    "avāru",
+    "avesta",
+    "aimaru",
+    "azerbaidžāņu",
+    "bambaru",
+    "baškīru",
+    "basku",
+    "baltkrievu",
+    "bengāļu",
+    "",
+    "bišlamā",
+    "bosniešu",
+    "bretoņu",
+    "bulgāru",
+    "birmiešu",
+    "katalāņu",
+    "čamorru",
+    "čečenu",
+    "čičeva",
+    "ķīniešu",
+    "čuvašu",
+    "korniešu",
+    "korsikāņu",
+    "krī",
+    "horvātu",
+    "čehu",
+    "dāņu",
+    "maldīviešu",
+    "holandiešu",
+    "dzongke",
+    "angļu",
+    "esperanto",
+    "igauņu",
+    "evu",
+    "fēru",
+    "fidžiešu",
+    "somu",
+    "franču",
+    "fulu",
+    "galisiešu",
+    "gruzīnu",
+    "vācu",
+    "grieķu",
+    "gvaranu",
+    "gudžaratu",
+    "haitiešu",
+    "hausu",
+    "ivrits",
+    "hereru",
+    "hindi",
+    "hirimotu",
+    "ungāru",
+    "interlingva",
+    "indonēziešu",
+    "interlingve",
+    "īru",
+    "igbo",
+    "inupiaku",
+    "ido",
+    "islandiešu",
+    "itāļu",
+    "inuītu",
+    "japāņu",
+    "javiešu",
+    "grenlandiešu",
+    "kannadu",
+    "kanuru",
+    "kašmiriešu",
+    "kazahu",
+    "khmeru",
+    "kikuju",
+    "kiņaruanda",
+    "kirgīzu",
+    "komiešu",
+    "kongu",
+    "korejiešu",
+    "kurdu",
+    "kvaņamu",
+    "latīņu",
+    "luksemburgiešu",
+    "gandu",
+    "limburgiešu",
+    "lingala",
+    "laosiešu",
+    "lietuviešu",
+    "lubakatanga",
+    "latviešu",
+    "meniešu",
+    "maķedoniešu",
+    "malagasu",
+    "malajiešu",
+    "malajalu",
+    "maltiešu",
+    "maoru",
+    "marathu",
+    "māršaliešu",
+    "mongoļu",
+    "nauruiešu",
+    "navahu",
+    "ziemeļndebelu",
+    "nepāliešu",
+    "ndongu",
+    "norvēģu bukmols",
+    "jaunnorvēģu",
+    "norvēģu",
+    "Sičuaņas ji",
+    "dienvidndebelu",
+    "oksitāņu",
+    "odžibvu",
+    "baznīcslāvu",
+    "oromu",
+    "oriju",
+    "osetīnu",
+    "pandžabu",
+    "pāli",
+    "persiešu",
+    "poļu",
+    "puštu",
+    "portugāļu",
+    "kečvu",
+    "retoromāņu",
+    "rundu",
+    "rumāņu",
+    "krievu",
+    "sanskrits",
+    "sardīniešu",
+    "sindhu",
+    "ziemeļsāmu",
+    "samoāņu",
+    "sango",
+    "serbu",
+    "skotu gēlu",
+    "šonu",
+    "singāļu",
+    "slovāku",
+    "slovēņu",
+    "somāļu",
+    "dienvidsotu",
+    "spāņu",
+    "zundu",
+    "svahili",
+    "svatu",
+    "zviedru",
+    "tamilu",
+    "telugu",
+    "tadžiku",
+    "taju",
+    "tigrinja",
+    "tibetiešu",
+    "turkmēņu",
+    "tagalu",
+    "cvanu",
+    "tongiešu",
+    "turku",
+    "congu",
+    "tatāru",
+    "tvī",
+    "taitiešu",
+    "uiguru",
+    "ukraiņu",
+    "urdu",
+    "uzbeku",
+    "vendu",
+    "vjetnamiešu",
+    "volapiks",
+    "valoņu",
+    "velsiešu",
+    "volofu",
+    "rietumfrīzu",
+    "khosu",
+    "jidišs",
+    "jorubu",
+    "džuanu",
+    "zulu",
+];
+
+static MK: [&str; 184] = [
+    "апхаски",
+    "афарски",
+    "африканс",
+    "акански",
+    "албански",
+    "амхарски",
+    "арапски",
+    "арагонски",
+    "ерменски",
+    "асамски",
+    "аварски",
+    "авестански",
+    "ајмарски",
+    "азербејџански",
+    "бамбара",
+    "башкирски",
+    "баскиски",
+    "белоруски",
+    "бенгалски",
+    "",
+    "бислама",
+    "босански",
+    "бретонски",
+    "бугарски",
+    "бурмански",
+    "каталонски",
+    "чаморски",
+    "чеченски",
+    "њанџа",
+    "кинески",
+    "чувашки",
+    "корнски",
+    "корзикански",
+    "кри",
+    "хрватски",
+    "чешки",
+    "дански",
+    "дивехи",
+    "холандски",
+    "ѕонгка",
+    "англиски",
+    "есперанто",
+    "естонски",
+    "еве",
+    "фарски",
+    "фиџиски",
+    "фински",
+    "француски",
+    "фула",
+    "галисиски",
+    "грузиски",
+    "германски",
+    "грчки",
+    "гварански",
+    "гуџарати",
+    "хаитски",
+    "хауса",
+    "хебрејски",
+    "хереро",
+    "хинди",
+    "хири моту",
+    "унгарски",
+    "интерлингва",
+    "индонезиски",
+    "интерлингве",
+    "ирски",
+    "игбо",
+    "инупијачки",
+    "идо",
+    "исландски",
+    "италијански",
+    "инуктитут",
+    "јапонски",
+    "јавански",
+    "калалисут",
+    "каннада",
+    "канури",
+    "кашмирски",
+    "казашки",
+    "кмерски",
+    "кикују",
+    "руандски",
+    "киргиски",
+    "коми",
+    "конго",
+    "корејски",
+    "курдски",
+    "квањама",
+    "латински",
+    "луксембуршки",
+    "ганда",
+    "лимбуршки",
+    "лингала",
+    "лаошки",
+    "литвански",
+    "луба-катанга",
+    "латвиски",
+    "манкс",
+    "македонски",
+    "малгашки",
+    "малајски",
+    "малајалски",
+    "малтешки",
+    "маорски",
+    "марати",
+    "маршалски",
+    "монголски",
+    "науруански",
+    "навахо",
+    "северен ндебеле",
+    "непалски",
+    "ндонга",
+    "норвешки букмол",
+    "норвешки нинорск",
+    "норвешки",
+    "сичуан ји",
+    "јужен ндебеле",
+    "окситански",
+    "оџибва",
+    "црковнословенски",
+    "оромо",
+    "одија",
+    "осетски",
+    "пенџапски",
+    "пали",
+    "персиски",
+    "полски",
+    "паштунски",
+    "португалски",
+    "кечуански",
+    "реторомански",
+    "рунди",
+    "романски",
+    "руски",
+    "санскрит",
+    "сардински",
+    "синди",
+    "северен сами",
+    "самоански",
+    "санго",
+    "српски",
+    "шкотски гелски",
+    "шона",
+    "синхалски",
+    "словачки",
+    "словенечки",
+    "сомалиски",
+    "сесото",
+    "шпански",
+    "сундски",
+    "свахили",
+    "свати",
+    "шведски",
+    "тамилски",
+    "телугу",
+    "таџикистански",
+    "тајландски",
+    "тигриња",
+    "тибетски",
+    "туркменски",
+    "тагалог",
+    "цвана",
+    "тонгајски",
+    "турски",
+    "цонга",
+    "татарски",
+    "тви",
+    "тахитски",
+    "ујгурски",
+    "украински",
+    "урду",
+    "узбечки",
+    "венда",
+    "виетнамски",
+    "волапик",
+    "валонски",
+    "велшки",
+    "волофски",
+    "западнофризиски",
+    "коса",
+    "јидиш",
+    "јорупски",
+    "џуаншки",
+    "зулу",
+];
+
+static MS: [&str; 184] = [
+    "Abkhazia",
+    "Afar",
+    "Afrikaans",
+    "Akan",
+    "Albania",
+    "Amharic",
+    "Arab",
+    "Aragon",
+    "Armenia",
+    "Assam",
+    "Avaric",
+    "Avestan",
+    "Aymara",
+    "Azerbaijan",
+    "Bambara",
+    "Bashkir",
+    "Basque",
+    "Belarus",
+    "Benggali",
+    "",
+    "Bislama",
+    "Bosnia",
+    "Breton",
+    "Bulgaria",
+    "Burma",
+    "Catalonia",
+    "Chamorro",
+    "Chechen",
+    "Nyanja",
+    "Cina",
+    "Chuvash",
+    "Cornish",
+    "Corsica",
+    "",
+    "Croatia",
+    "Czech",
+    "Denmark",
+    "Divehi",
+    "Belanda",
+    "Dzongkha",
+    "Inggeris",
+    "Esperanto",
+    "Estonia",
+    "Ewe",
+    "Faroe",
+    "Fiji",
+    "Finland",
+    "Perancis",
+    "Fulah",
+    "Galicia",
+    "Georgia",
+    "Jerman",
+    "Greek",
+    "Guarani",
+    "Gujarat",
+    "Kreol Haiti",
+    "Hausa",
+    "Ibrani",
+    "Herero",
+    "Hindi",
+    "",
+    "Hungary",
+    "Interlingua",
+    "Indonesia",
+    "Interlingue",
+    "Ireland",
+    "Igbo",
+    "",
+    "Ido",
+    "Iceland",
+    "Itali",
+    "Inuktitut",
+    "Jepun",
+    "Jawa",
+    "Kalaallisut",
+    "Kannada",
+    "Kanuri",
+    "Kashmir",
+    "Kazakhstan",
+    "Khmer",
+    "Kikuya",
+    "Kinyarwanda",
+    "Kirghiz",
+    "Komi",
+    "Kongo",
+    "Korea",
+    "Kurdish",
+    "Kuanyama",
+    "Latin",
+    "Luxembourg",
+    "Ganda",
+    "Limburgish",
+    "Lingala",
+    "Laos",
+    "Lithuania",
+    "Luba-Katanga",
+    "Latvia",
+    "Manx",
+    "Macedonia",
+    "Malagasy",
+    "Melayu",
+    "Malayalam",
+    "Malta",
+    "Maori",
+    "Marathi",
+    "Marshall",
+    "Mongolia",
+    "Nauru",
+    "Navajo",
+    "Ndebele Utara",
+    "Nepal",
+    "Ndonga",
+    "Bokmal Norway",
+    "Nynorsk Norway",
+    "Norway",
+    "Sichuan Yi",
+    "Ndebele Selatan",
+    "Occitania",
+    "",
+    "Slavik Gereja",
+    "Oromo",
+    "Odia",
+    "Ossete",
+    "Punjabi",
+    "",
+    "Parsi",
+    "Poland",
+    "Pashto",
+    "Portugis",
+    "Quechua",
+    "Romansh",
+    "Rundi",
+    "Romania",
+    "Rusia",
+    "Sanskrit",
+    "Sardinia",
+    "Sindhi",
+    "Sami Utara",
+    "Samoa",
+    "Sango",
+    "Serbia",
+    "Scots Gaelic",
+    "Shona",
+    "Sinhala",
+    "Slovak",
+    "Slovenia",
+    "Somali",
+    "Sotho Selatan",
+    "Sepanyol",
+    "Sunda",
+    "Swahili",
+    "Swati",
+    "Sweden",
+    "Tamil",
+    "Telugu",
+    "Tajik",
+    "Thai",
+    "Tigrinya",
+    "Tibet",
+    "Turkmen",
+    "",
+    "Tswana",
+    "Tonga",
+    "Turki",
+    "Tsonga",
+    "Tatar",
+    "Twi",
+    "Tahiti",
+    "Uyghur",
+    "Ukraine",
+    "Urdu",
+    "Uzbekistan",
+    "Venda",
+    "Vietnam",
+    "Volapük",
+    "Walloon",
+    "Wales",
+    "Wolof",
+    "Frisian Barat",
+    "Xhosa",
+    "Yiddish",
+    "Yoruba",
+    "Zhuang",
+    "Zulu",
+];
+
+static ML: [&str; 184] = [
+    "അബ്\u{200C}ഖാസിയൻ",
+    "അഫാർ",
+    "ആഫ്രിക്കാൻസ്",
+    "അകാൻ\u{200C}",
+    "അൽബേനിയൻ",
+    "അംഹാരിക്",
+    "അറബിക്",
+    "അരഗോണീസ്",
+    "അർമേനിയൻ",
+    "ആസ്സാമീസ്",
+    "അവാരിക്",
+    "അവസ്റ്റാൻ",
+    "അയ്മാറ",
+    "അസർബൈജാനി",
+    "ബംബാറ",
+    "ബഷ്ഖിർ",
+    "ബാസ്\u{200C}ക്",
+    "ബെലാറുഷ്യൻ",
+    "ബംഗ്ലാ",
+    "",
+    "ബിസ്\u{200C}ലാമ",
+    "ബോസ്നിയൻ",
+    "ബ്രെട്ടൺ",
+    "ബൾഗേറിയൻ",
+    "ബർമീസ്",
+    "കറ്റാലാൻ",
+    "ചമോറോ",
+    "ചെചൻ",
+    "ന്യൻജ",
+    "ചൈനീസ്",
+    "ചുവാഷ്",
+    "കോർണിഷ്",
+    "കോർസിക്കൻ",
+    "ക്രീ",
+    "ക്രൊയേഷ്യൻ",
+    "ചെക്ക്",
+    "ഡാനിഷ്",
+    "ദിവെഹി",
+    "ഡച്ച്",
+    "ദ്\u{200C}സോങ്ക",
+    "ഇംഗ്ലീഷ്",
+    "എസ്\u{200C}പരാന്റോ",
+    "എസ്റ്റോണിയൻ",
+    "യൂ",
+    "ഫാറോസ്",
+    "ഫിജിയൻ",
+    "ഫിന്നിഷ്",
+    "ഫ്രഞ്ച്",
+    "ഫുല",
+    "ഗലീഷ്യൻ",
+    "ജോർജിയൻ",
+    "ജർമ്മൻ",
+    "ഗ്രീക്ക്",
+    "ഗ്വരനി",
+    "ഗുജറാത്തി",
+    "ഹെയ്\u{200C}തിയൻ ക്രിയോൾ",
+    "ഹൗസ",
+    "ഹീബ്രു",
+    "ഹെരേരൊ",
+    "ഹിന്ദി",
+    "ഹിരി മോതു",
+    "ഹംഗേറിയൻ",
+    "ഇന്റർലിംഗ്വ",
+    "ഇന്തോനേഷ്യൻ",
+    "ഇന്റർലിംഗ്വേ",
+    "ഐറിഷ്",
+    "ഇഗ്ബോ",
+    "ഇനുപിയാക്",
+    "ഇഡോ",
+    "ഐസ്\u{200C}ലാൻഡിക്",
+    "ഇറ്റാലിയൻ",
+    "ഇനുക്റ്റിറ്റുട്ട്",
+    "ജാപ്പനീസ്",
+    "ജാവനീസ്",
+    "കലാല്ലിസുട്ട്",
+    "കന്നഡ",
+    "കനൂറി",
+    "കശ്\u{200C}മീരി",
+    "കസാഖ്",
+    "ഖമെർ",
+    "കികൂയു",
+    "കിന്യാർവാണ്ട",
+    "കിർഗിസ്",
+    "കോമി",
+    "കോംഗോ",
+    "കൊറിയൻ",
+    "കുർദ്ദിഷ്",
+    "ക്വാന്യമ",
+    "ലാറ്റിൻ",
+    "ലക്\u{200C}സംബർഗിഷ്",
+    "ഗാണ്ട",
+    "ലിംബർഗിഷ്",
+    "ലിംഗാല",
+    "ലാവോ",
+    "ലിത്വാനിയൻ",
+    "ലുബ-കറ്റംഗ",
+    "ലാറ്റ്വിയൻ",
+    "മാൻസ്",
+    "മാസിഡോണിയൻ",
+    "മലഗാസി",
+    "മലെയ്",
+    "മലയാളം",
+    "മാൾട്ടീസ്",
+    "മവോറി",
+    "മറാത്തി",
+    "മാർഷല്ലീസ്",
+    "മംഗോളിയൻ",
+    "നൗറു",
+    "നവാജോ",
+    "നോർത്ത് ഡെബിൾ",
+    "നേപ്പാളി",
+    "ഡോങ്ക",
+    "നോർവീജിയൻ ബുക്\u{200C}മൽ",
+    "നോർവീജിയൻ നൈനോർക്\u{200C}സ്",
+    "നോർവീജിയൻ",
+    "ഷുവാൻയി",
+    "ദക്ഷിണ നെഡിബിൾ",
+    "ഓക്\u{200C}സിറ്റൻ",
+    "ഓജിബ്വാ",
+    "ചർച്ച് സ്ലാവിക്",
+    "ഒറോമോ",
+    "ഒഡിയ",
+    "ഒസ്സെറ്റിക്",
+    "പഞ്ചാബി",
+    "പാലി",
+    "പേർഷ്യൻ",
+    "പോളിഷ്",
+    "പഷ്\u{200C}തോ",
+    "പോർച്ചുഗീസ്",
+    "ക്വെച്ചുവ",
+    "റൊമാഞ്ച്",
+    "റുണ്ടി",
+    "റൊമാനിയൻ",
+    "റഷ്യൻ",
+    "സംസ്\u{200C}കൃതം",
+    "സർഡിനിയാൻ",
+    "സിന്ധി",
+    "വടക്കൻ സമി",
+    "സമോവൻ",
+    "സാംഗോ",
+    "സെർബിയൻ",
+    "സ്കോട്ടിഷ് ഗൈലിക്",
+    "ഷോണ",
+    "സിംഹള",
+    "സ്ലോവാക്",
+    "സ്ലോവേനിയൻ",
+    "സോമാലി",
+    "തെക്കൻ സോതോ",
+    "സ്\u{200C}പാനിഷ്",
+    "സുണ്ടാനീസ്",
+    "സ്വാഹിലി",
+    "സ്വാറ്റി",
+    "സ്വീഡിഷ്",
+    "തമിഴ്",
+    "തെലുങ്ക്",
+    "താജിക്",
+    "തായ്",
+    "ടൈഗ്രിന്യ",
+    "ടിബറ്റൻ",
+    "തുർക്\u{200C}മെൻ",
+    "തഗാലോഗ്",
+    "സ്വാന",
+    "ടോംഗൻ",
+    "ടർക്കിഷ്",
+    "സോംഗ",
+    "ടാട്ടർ",
+    "ട്വി",
+    "താഹിതിയൻ",
+    "ഉയ്ഘുർ",
+    "ഉക്രേനിയൻ",
+    "ഉറുദു",
+    "ഉസ്\u{200C}ബെക്ക്",
+    "വെന്ദ",
+    "വിയറ്റ്നാമീസ്",
+    "വോളാപുക്",
+    "വല്ലൂൺ",
+    "വെൽഷ്",
+    "വൊളോഫ്",
+    "പശ്ചിമ ഫ്രിഷിയൻ",
+    "ഖോസ",
+    "യിദ്ദിഷ്",
+    "യൊറൂബാ",
+    "സ്വാംഗ്",
+    "സുലു",
+];
+
+static MT: [&str; 184] = [
+    "Abkażjan",
+    "Afar",
+    "Afrikans",
+    "Akan",
+    "Albaniż",
+    "Amhariku",
+    "Għarbi",
+    "Aragoniż",
+    "Armen",
+    "Assamiż",
+    "Avarik",
+    "Avestan",
+    "Aymara",
+    "Ażerbajġani",
+    "Bambara",
+    "Bashkir",
+    "Bask",
+    "Belarussu",
+    "Bengali",
+    "",
+    "Bislama",
+    "Bożnijaku",
+    "Breton",
+    "Bulgaru",
+    "Burmiż",
+    "Katalan",
+    "Chamorro",
+    "Chechen",
+    "Nyanja",
+    "Ċiniż",
+    "Chuvash",
+    "Korniku",
+    "Korsiku",
+    "Cree",
+    "Kroat",
+    "Ċek",
+    "Daniż",
+    "Divehi",
+    "Olandiż",
+    "Dzongkha",
+    "Ingliż",
+    "Esperanto",
+    "Estonjan",
+    "Ewe",
+    "Faroese",
+    "Fiġjan",
+    "Finlandiż",
+    "Franċiż",
+    "Fulah",
+    "Galiċjan",
+    "Ġorġjan",
+    "Ġermaniż",
+    "Grieg",
+    "Guarani",
+    "Gujarati",
+    "Creole ta’ Haiti",
+    "Hausa",
+    "Ebrajk",
+    "Herero",
+    "Hindi",
+    "Hiri Motu",
+    "Ungeriż",
+    "Interlingua",
+    "Indoneżjan",
+    "Interlingue",
+    "Irlandiż",
+    "Igbo",
+    "Inupjak",
+    "Ido",
+    "Iżlandiż",
+    "Taljan",
+    "Inuktitut",
+    "Ġappuniż",
+    "Ġavaniż",
+    "Kalallisut",
+    "Kannada",
+    "Kanuri",
+    "Kashmiri",
+    "Każak",
+    "Khmer",
+    "Kikuju",
+    "Kinjarwanda",
+    "Kirgiż",
+    "Komi",
+    "Kongo",
+    "Korean",
+    "Kurd",
+    "Kuanyama",
+    "Latin",
+    "Lussemburgiż",
+    "Ganda",
+    "Limburgish",
+    "Lingaljan",
+    "Laosjan",
+    "Litwan",
+    "Luba-Katanga",
+    "Latvjan",
+    "Manx",
+    "Maċedonjan",
+    "Malagasy",
+    "Malay",
+    "Malayalam",
+    "Malti",
+    "Maori",
+    "Marathi",
+    "Marshalljaniż",
+    "Mongoljan",
+    "Naurujan",
+    "Navajo",
+    "Ndebeli tat-Tramuntana",
+    "Nepaliż",
+    "Ndonga",
+    "Bokmal Norveġiż",
+    "Ninorsk Norveġiż",
+    "Norveġiż",
+    "Sichuan Yi",
+    "Ndebele tan-Nofsinhar",
+    "Oċċitan",
+    "Oġibwa",
+    "Slaviku tal-Knisja",
+    "Oromo",
+    "Odia",
+    "Ossettiku",
+    "Punjabi",
+    "Pali",
+    "Persjan",
+    "Pollakk",
+    "Pashto",
+    "Portugiż",
+    "Quechua",
+    "Romanz",
+    "Rundi",
+    "Rumen",
+    "Russu",
+    "Sanskrit",
+    "Sardinjan",
+    "Sindhi",
+    "Sami tat-Tramuntana",
+    "Samoan",
+    "Sango",
+    "Serb",
+    "Galliku Skoċċiż",
+    "Shona",
+    "Sinhala",
+    "Slovakk",
+    "Sloven",
+    "Somali",
+    "Soto tan-Nofsinhar",
+    "Spanjol",
+    "Sundaniż",
+    "Swahili",
+    "Swati",
+    "Żvediż",
+    "Tamil",
+    "Telugu",
+    "Taġik",
+    "Tajlandiż",
+    "Tigrinya",
+    "Tibetjan",
+    "Turkmeni",
+    "Tagalog",
+    "Tswana",
+    "Tongan",
+    "Tork",
+    "Tsonga",
+    "Tatar",
+    "Twi",
+    "Taħitjan",
+    "Uyghur",
+    "Ukren",
+    "Urdu",
+    "Uzbek",
+    "Venda",
+    "Vjetnamiż",
+    "Volapuk",
+    "Walloon",
+    "Welsh",
+    "Wolof",
+    "Frisian tal-Punent",
+    "Xhosa",
+    "Yiddish",
+    "Yoruba",
+    "Zhuang",
+    "Zulu",
+];
+
+static MI: [&str; 184] = [
+    "Apakāhiana",
+    "",
+    "Awherikāna",
+    "Ākana",
+    "Arapeiniana",
+    "Amahereka",
+    "Ārapi",
+    "Arakonihi",
+    "Āmeniana",
+    "Āhamēhi",
+    "Āwhāriki",
+    "",
+    "Aimāra",
+    "Atepaihānia",
+    "Pāpara",
+    "Pākīra",
+    "Pākihi",
+    "Perarūhiana",
+    "Pākara",
+    "",
+    "Pihirāma",
+    "Pōngiana",
+    "Peretana",
+    "Purukāriana",
+    "Pēmīhi",
+    "Katarana",
+    "Tiamoro",
+    "Tietiene",
+    "Niānia",
+    "Hainamana",
+    "Tiuwhāhi",
+    "Kōnihi",
+    "Kōhikana",
+    "",
+    "Koroātiana",
+    "Tieke",
+    "Teina",
+    "Tīwhehi",
+    "Tati",
+    "Tonoka",
+    "Ingarihi",
+    "Eheperāto",
+    "Etōniana",
+    "Ewe",
+    "Wharoīhi",
+    "Whītīana",
+    "Whinirānia",
+    "Wīwī",
+    "Whūra",
+    "Karīhia",
+    "Hōriana",
+    "Tiamana",
+    "Kariki",
+    "Kuaranī",
+    "Kutarāti",
+    "Kereō Haiti",
+    "Hauha",
+    "Hīperu",
+    "Herero",
+    "Hīni",
+    "",
+    "Hanekari",
+    "Inarīngua",
+    "Initonīhiana",
+    "",
+    "Airihi",
+    "Ikapo",
+    "",
+    "Īto",
+    "Tiorangi",
+    "Itāriana",
+    "Inukitetūta",
+    "Hapanihi",
+    "Hāwhanihi",
+    "Kararīhutu",
+    "Kanara",
+    "Kanuri",
+    "Kahimiri",
+    "Kahāka",
+    "Kimēra",
+    "Kikūiu",
+    "Kiniawāna",
+    "Kiakihi",
+    "Komi",
+    "",
+    "Kōreana",
+    "Kūrihi",
+    "Kuoniāma",
+    "Rātini",
+    "Rakapuō",
+    "Kānata",
+    "Ripūkuihi",
+    "Ringāra",
+    "Rao",
+    "Rituānia",
+    "Rupa Katanga",
+    "Rāwhia",
+    "Manaki",
+    "Makerōnia",
+    "Marakāhi",
+    "Marei",
+    "Mareiārama",
+    "Mārata",
+    "Māori",
+    "Marati",
+    "Mararīhi",
+    "Mongōria",
+    "Nauru",
+    "Nawahō",
+    "Enetepēra ki te Raki",
+    "Nepari",
+    "Natōka",
+    "Pakamō Nōwei",
+    "Nīnōka Nōwei",
+    "Nōwei",
+    "Hīhuana Eī",
+    "Enetepēra ki te Tonga",
+    "Ōkitana",
+    "",
+    "",
+    "Ōromo",
+    "Ōtia",
+    "Ōtītiki",
+    "Punutapi",
+    "",
+    "Pāhiana",
+    "Pōrihi",
+    "Pāhitō",
+    "Pōtukīhi",
+    "Kētua",
+    "Romānihi",
+    "Rūniti",
+    "Romeinia",
+    "Ruhiana",
+    "Hanahiti",
+    "Hārinia",
+    "Hiniti",
+    "Hami ki te Raki",
+    "Hāmoa",
+    "Hāngo",
+    "Hirupia",
+    "Keiriki Kotimana",
+    "Hōna",
+    "Hinihāra",
+    "Horowākia",
+    "Horowinia",
+    "Hamāri",
+    "Hōto ki te Tonga",
+    "Pāniora",
+    "Hunanīhi",
+    "Wāhīri",
+    "Wāti",
+    "Huitene",
+    "Tamira",
+    "Teruku",
+    "Tāhiki",
+    "Tai",
+    "Tekirinia",
+    "Tipete",
+    "Tākamana",
+    "",
+    "Hawāna",
+    "Tonga",
+    "Tākei",
+    "Honga",
+    "Tatā",
+    "",
+    "Tahiti",
+    "Wīkura",
+    "Ukareinga",
+    "Ūrutu",
+    "Ūpeke",
+    "Wēnera",
+    "Whitināmu",
+    "",
+    "Warūna",
+    "Werehi",
+    "Warawhe",
+    "Whirīhiana ki te Uru",
+    "Tōha",
+    "Irihi",
+    "Ōrūpa",
+    "",
+    "Tūru",
+];
+
+static MR: [&str; 184] = [
+    "अबखेजियन",
+    "अफार",
+    "अफ्रिकान्स",
+    "अकान",
+    "अल्बानियन",
+    "अम्हारिक",
+    "अरबी",
+    "अर्गोनीज",
+    "अर्मेनियन",
+    "आसामी",
+    "अ\u{200D}ॅव्हेरिक",
+    "अवेस्तन",
+    "ऐमरा",
+    "अझरबैजानी",
+    "बाम्बारा",
+    "बष्किर",
+    "बास्क",
+    "बेलारुशियन",
+    "बंगाली",
+    "",
+    "बिस्लामा",
+    "बोस्नियन",
+    "ब्रेतॉन",
+    "बल्गेरियन",
+    "बर्मी",
+    "कातालान",
+    "कॅमोरो",
+    "चेचेन",
+    "न्यान्जा",
+    "चीनी",
+    "चूवाश",
+    "कोर्निश",
+    "कॉर्सिकन",
+    "क्री",
+    "क्रोएशियन",
+    "झेक",
+    "डॅनिश",
+    "दिवेही",
+    "डच",
+    "झोंगखा",
+    "इंग्रजी",
+    "एस्परान्टो",
+    "इस्टोनियन",
+    "एवे",
+    "फरोइज",
+    "फिजियन",
+    "फिनिश",
+    "फ्रेंच",
+    "फुलाह",
+    "गॅलिशियन",
+    "जॉर्जियन",
+    "जर्मन",
+    "ग्रीक",
+    "गुआरनी",
+    "गुजराती",
+    "हैतीयन क्रेओल",
+    "हौसा",
+    "हिब्रू",
+    "हरेरो",
+    "हिंदी",
+    "हिरी मॉटू",
+    "हंगेरियन",
+    "इंटरलिंग्वा",
+    "इंडोनेशियन",
+    "इंटरलिंग",
+    "आयरिश",
+    "ईग्बो",
+    "इनूपियाक",
+    "इडौ",
+    "आईसलँडिक",
+    "इटालियन",
+    "इनुक्तीटुट",
+    "जपानी",
+    "जावानीज",
+    "कलाल्लिसत",
+    "कन्नड",
+    "कनुरी",
+    "काश्मीरी",
+    "कझाक",
+    "ख्मेर",
+    "किकुयू",
+    "किन्यार्वान्डा",
+    "किरगीझ",
+    "कोमी",
+    "काँगो",
+    "कोरियन",
+    "कुर्दिश",
+    "क्वान्यामा",
+    "लॅटिन",
+    "लक्झेंबर्गिश",
+    "गांडा",
+    "लिंबूर्गिश",
+    "लिंगाला",
+    "लाओ",
+    "लिथुआनियन",
+    "ल्यूबा-कटांगा",
+    "लात्व्हियन",
+    "मांक्स",
+    "मॅसेडोनियन",
+    "मलागसी",
+    "मलय",
+    "मल्याळम",
+    "माल्टिज्",
+    "माओरी",
+    "मराठी",
+    "मार्शलीज",
+    "मंगोलियन",
+    "नउरू",
+    "नावाजो",
+    "उत्तर देबेली",
+    "नेपाळी",
+    "डोंगा",
+    "नॉर्वेजियन बोकमाल",
+    "नॉर्वेजियन न्योर्स्क",
+    "नॉर्वेजियन",
+    "सिचुआन यी",
+    "दक्षिणात्य देबेली",
+    "ऑक्सितान",
+    "ओजिब्वा",
+    "चर्च स्लाव्हिक",
+    "ओरोमो",
+    "उडिया",
+    "ओस्सेटिक",
+    "पंजाबी",
+    "पाली",
+    "फारसी",
+    "पोलिश",
+    "पश्तो",
+    "पोर्तुगीज",
+    "क्वेचुआ",
+    "रोमान्श",
+    "रुन्दी",
+    "रोमानियन",
+    "रशियन",
+    "संस्कृत",
+    "सर्दिनियन",
+    "सिंधी",
+    "उत्तरी सामी",
+    "सामोअन",
+    "सांगो",
+    "सर्बियन",
+    "स्कॉटिश गेलिक",
+    "शोना",
+    "सिंहला",
+    "स्लोव्हाक",
+    "स्लोव्हेनियन",
+    "सोमाली",
+    "दक्षिणी सोथो",
+    "स्पॅनिश",
+    "सुंदानीज",
+    "स्वाहिली",
+    "स्वाती",
+    "स्वीडिश",
+    "तामिळ",
+    "तेलगू",
+    "ताजिक",
+    "थाई",
+    "तिग्रिन्या",
+    "तिबेटी",
+    "तुर्कमेन",
+    "टागालोग",
+    "त्स्वाना",
+    "टोंगन",
+    "तुर्की",
+    "सोंगा",
+    "तातर",
+    "ट्वी",
+    "ताहितीयन",
+    "उइगुर",
+    "युक्रेनियन",
+    "उर्दू",
+    "उझ्बेक",
+    "व्हेंदा",
+    "व्हिएतनामी",
+    "ओलापुक",
+    "वालून",
+    "वेल्श",
+    "वोलोफ",
+    "पश्चिमी फ्रिशियन",
+    "खोसा",
+    "यिद्दिश",
+    "योरुबा",
+    "झुआंग",
+    "झुलू",
+];
+
+static MN: [&str; 184] = [
+    "абхаз",
+    "афар",
+    "африкаанс",
+    "акан",
+    "албани",
+    "амхар",
+    "араб",
+    "арагон",
+    "армен",
+    "ассам",
+    "авар",
+    "",
+    "аймара",
+    "азербайжан",
+    "бамбара",
+    "башкир",
+    "баск",
+    "беларусь",
+    "бенгал",
+    "",
+    "бислам",
+    "босни",
+    "бретон",
+    "болгар",
+    "бирм",
+    "каталан",
+    "чаморро",
+    "чечень",
+    "нянжа",
+    "хятад",
+    "чуваш",
+    "корн",
+    "корсик",
+    "",
+    "хорват",
+    "чех",
+    "дани",
+    "дивехи",
+    "нидерланд",
+    "зонха",
+    "англи",
+    "эсперанто",
+    "эстони",
+    "эвэ",
+    "фарер",
+    "фижи",
+    "фин",
+    "франц",
+    "фула",
+    "галего",
+    "гүрж",
+    "герман",
+    "грек",
+    "гуарани",
+    "гужарати",
+    "Гаитийн креол",
+    "хауса",
+    "еврей",
+    "хереро",
+    "хинди",
+    "",
+    "мажар",
+    "интерлингво",
+    "индонези",
+    "нэгдмэл хэл",
+    "ирланд",
+    "игбо",
+    "",
+    "идо",
+    "исланд",
+    "итали",
+    "инуктитут",
+    "япон",
+    "ява",
+    "калалисут",
+    "каннада",
+    "канури",
+    "кашмир",
+    "казах",
+    "кхмер",
+    "кикуюү",
+    "киньяруанда",
+    "киргиз",
+    "коми",
+    "",
+    "солонгос",
+    "курд",
+    "куаньяма",
+    "латин",
+    "люксембург",
+    "ганда",
+    "лимбург",
+    "лингала",
+    "лаос",
+    "литва",
+    "луба-катанга",
+    "латви",
+    "манкс",
+    "македон",
+    "малагаси",
+    "малай",
+    "малаялам",
+    "малта",
+    "маори",
+    "марати",
+    "маршалл",
+    "монгол",
+    "науру",
+    "навахо",
+    "хойд ндебеле",
+    "балба",
+    "ндонга",
+    "норвегийн букмол",
+    "норвегийн нинорск",
+    "норвег",
+    "сычуань и",
+    "өмнөд ндебеле",
+    "окситан",
+    "",
+    "сүмийн славян",
+    "оромо",
+    "ория",
+    "оссетин",
+    "панжаби",
+    "",
+    "перс",
+    "польш",
+    "пушту",
+    "португал",
+    "кечуа",
+    "романш",
+    "рунди",
+    "румын",
+    "орос",
+    "санскрит",
+    "сардин",
+    "синдхи",
+    "хойд сами",
+    "самоа",
+    "санго",
+    "серб",
+    "шотландын гел",
+    "шона",
+    "синхала",
+    "словак",
+    "словени",
+    "сомали",
+    "сесото",
+    "испани",
+    "сундан",
+    "свахили",
+    "свати",
+    "швед",
+    "тамил",
+    "тэлүгү",
+    "тажик",
+    "тай",
+    "тигринья",
+    "төвд",
+    "туркмен",
+    "",
+    "цвана",
+    "тонга",
+    "турк",
+    "цонга",
+    "татар",
+    "тви",
+    "таити",
+    "уйгур",
+    "украин",
+    "урду",
+    "узбек",
+    "венда",
+    "вьетнам",
+    "волапюк",
+    "уоллун",
+    "уэльс",
+    "волоф",
+    "баруун фриз",
+    "хоса",
+    "иддиш",
+    "ёруба",
+    "чжуанг",
+    "зулу",
+];
+
+static NE: [&str; 184] = [
+    "अब्खाजियाली",
+    "अफार",
+    "अफ्रिकान्स",
+    "आकान",
+    "अल्बानियाली",
+    "अम्हारिक",
+    "अरबी",
+    "अरागोनी",
+    "आर्मेनियाली",
+    "आसामी",
+    "अवारिक",
+    "अवेस्तान",
+    "ऐमारा",
+    "अजरबैजानी",
+    "बाम्बारा",
+    "बास्किर",
+    "बास्क",
+    "बेलारुसी",
+    "बंगाली",
+    "",
+    "बिस्लाम",
+    "बोस्नियाली",
+    "ब्रेटन",
+    "बुल्गेरियाली",
+    "बर्मेली",
+    "क्याटालन",
+    "चामोर्रो",
+    "चेचेन",
+    "न्यान्जा",
+    "चिनियाँ",
+    "चुभास",
+    "कोर्निस",
+    "कोर्सिकन",
+    "क्री",
+    "क्रोयसियाली",
+    "चेक",
+    "डेनिस",
+    "दिबेही",
+    "डच",
+    "जोङ्खा",
+    "अङ्ग्रेजी",
+    "एस्पेरान्तो",
+    "इस्टोनियन",
+    "इवी",
+    "फारोज",
+    "फिजियन",
+    "फिनिस",
+    "फ्रान्सेली",
+    "फुलाह",
+    "गलिसियाली",
+    "जर्जियाली",
+    "जर्मन",
+    "ग्रीक",
+    "गुवारानी",
+    "गुजराती",
+    "हैटियाली क्रियोल",
+    "हाउसा",
+    "हिब्रु",
+    "हेरेरो",
+    "हिन्दी",
+    "हिरी मोटु",
+    "हङ्गेरियाली",
+    "इन्टर्लिङ्गुआ",
+    "इन्डोनेसियाली",
+    "इन्टरलिङ्ग्वे",
+    "आइरिस",
+    "इग्बो",
+    "इनुपिआक्",
+    "इडो",
+    "आइसल्यान्डियाली",
+    "इटालेली",
+    "इनुक्टिटुट",
+    "जापानी",
+    "जाभानी",
+    "कालालिसुट",
+    "कन्नाडा",
+    "कानुरी",
+    "कास्मिरी",
+    "काजाख",
+    "खमेर",
+    "किकुयु",
+    "किन्यारवान्डा",
+    "किर्गिज",
+    "कोमी",
+    "कोङ्गो",
+    "कोरियाली",
+    "कुर्दी",
+    "कुआन्यामा",
+    "ल्याटिन",
+    "लक्जेम्बर्गी",
+    "गान्डा",
+    "लिम्बुर्गी",
+    "लिङ्गाला",
+    "लाओ",
+    "लिथुआनियाली",
+    "लुबा-काताङ्गा",
+    "लात्भियाली",
+    "मान्क्स",
+    "म्यासेडोनियन",
+    "मलागासी",
+    "मलाय",
+    "मलयालम",
+    "माल्टिज",
+    "माओरी",
+    "मराठी",
+    "मार्साली",
+    "मङ्गोलियाली",
+    "नाउरू",
+    "नाभाजो",
+    "उत्तरी न्डेबेले",
+    "नेपाली",
+    "न्दोन्गा",
+    "नर्वेली बोकमाल",
+    "नर्वेली नाइनोर्स्क",
+    "नर्वेली",
+    "सिचुआन यि",
+    "दक्षिण न्देबेले",
+    "अक्सिटन",
+    "ओजिब्वा",
+    "चर्च स्लाभिक",
+    "ओरोमो",
+    "उडिया",
+    "ओस्सेटिक",
+    "पंजाबी",
+    "पाली",
+    "फारसी",
+    "पोलिस",
+    "पास्तो",
+    "पोर्तुगी",
+    "क्वेचुवा",
+    "रोमानिस",
+    "रुन्डी",
+    "रोमानियाली",
+    "रसियाली",
+    "संस्कृत",
+    "सार्डिनियाली",
+    "सिन्धी",
+    "उत्तरी सामी",
+    "सामोआ",
+    "साङ्गो",
+    "सर्बियाली",
+    "स्कटिस गाएलिक",
+    "शोना",
+    "सिन्हाली",
+    "स्लोभाकियाली",
+    "स्लोभेनियाली",
+    "सोमाली",
+    "दक्षिणी सोथो",
+    "स्पेनी",
+    "सुडानी",
+    "स्वाहिली",
+    "स्वाती",
+    "स्विडिस",
+    "तामिल",
+    "तेलुगु",
+    "ताजिक",
+    "थाई",
+    "टिग्रिन्या",
+    "तिब्बती",
+    "टर्कमेन",
+    "",
+    "ट्स्वाना",
+    "टोङ्गन",
+    "टर्किश",
+    "ट्सोङ्गा",
+    "तातार",
+    "",
+    "टाहिटियन",
+    "उइघुर",
+    "युक्रेनी",
+    "उर्दु",
+    "उज्बेकी",
+    "भेन्डा",
+    "भियतनामी",
+    "भोलापिक",
+    "वाल्लुन",
+    "वेल्श",
+    "वुलुफ",
+    "पश्चिमी फ्रिसियन",
+    "खोसा",
+    "यिद्दिस",
+    "योरूवा",
+    "झुुआङ्ग",
+    "जुलु",
+];
+
+static NN: [&str; 184] = [
+    "abkhasisk",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albansk",
+    "amharisk",
+    "arabisk",
+    "aragonsk",
+    "armensk",
+    "assamesisk",
+    "avarisk",
+    "avestisk",
+    "aymara",
+    "aserbajdsjansk",
+    "bambara",
+    "basjkirsk",
+    "baskisk",
+    "belarusisk",
+    "bengali",
+    "",
+    "bislama",
+    "bosnisk",
+    "bretonsk",
+    "bulgarsk",
+    "burmesisk",
+    "katalansk",
+    "chamorro",
+    "tsjetsjensk",
+    "nyanja",
+    "kinesisk",
+    "tsjuvansk",
+    "kornisk",
+    "korsikansk",
+    "cree",
+    "kroatisk",
+    "tsjekkisk",
+    "dansk",
+    "divehi",
+    "nederlandsk",
+    "dzongkha",
+    "engelsk",
+    "esperanto",
+    "estisk",
+    "ewe",
+    "færøysk",
+    "fijiansk",
+    "finsk",
+    "fransk",
+    "fulfulde",
+    "galisisk",
+    "georgisk",
+    "tysk",
+    "gresk",
+    "guarani",
+    "gujarati",
+    "haitisk",
+    "hausa",
+    "hebraisk",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "ungarsk",
+    "interlingua",
+    "indonesisk",
+    "interlingue",
+    "irsk",
+    "ibo",
+    "inupiak",
+    "ido",
+    "islandsk",
+    "italiensk",
+    "inuktitut",
+    "japansk",
+    "javanesisk",
+    "grønlandsk (kalaallisut)",
+    "kannada",
+    "kanuri",
+    "kasjmiri",
+    "kasakhisk",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirgisisk",
+    "komi",
+    "kikongo",
+    "koreansk",
+    "kurdisk",
+    "kuanyama",
+    "latin",
+    "luxemburgsk",
+    "ganda",
+    "limburgsk",
+    "lingala",
+    "laotisk",
+    "litauisk",
+    "luba-katanga",
+    "latvisk",
+    "manx",
+    "makedonsk",
+    "madagassisk",
+    "malayisk",
+    "malayalam",
+    "maltesisk",
+    "maori",
+    "marathi",
+    "marshallesisk",
+    "mongolsk",
+    "nauru",
+    "navajo",
+    "nord-ndebele",
+    "nepalsk",
+    "ndonga",
+    "norsk bokmål",
+    "norsk nynorsk",
+    "norsk",
+    "sichuan-yi",
+    "sør-ndebele",
+    "oksitansk",
+    "ojibwa",
+    "kyrkjeslavisk",
+    "oromo",
+    "odia",
+    "ossetisk",
+    "panjabi",
+    "pali",
+    "persisk",
+    "polsk",
+    "pashto",
+    "portugisisk",
+    "quechua",
+    "retoromansk",
+    "rundi",
+    "rumensk",
+    "russisk",
+    "sanskrit",
+    "sardinsk",
+    "sindhi",
+    "nordsamisk",
+    "samoansk",
+    "sango",
+    "serbisk",
+    "skotsk-gælisk",
+    "shona",
+    "singalesisk",
+    "slovakisk",
+    "slovensk",
+    "somali",
+    "sørsotho",
+    "spansk",
+    "sundanesisk",
+    "swahili",
+    "swati",
+    "svensk",
+    "tamil",
+    "telugu",
+    "tadsjikisk",
+    "thai",
+    "tigrinja",
+    "tibetansk",
+    "turkmensk",
+    "tagalog",
+    "tswana",
+    "tongansk",
+    "tyrkisk",
+    "tsonga",
+    "tatarisk",
+    "twi",
+    "tahitisk",
+    "uigurisk",
+    "ukrainsk",
+    "urdu",
+    "usbekisk",
+    "venda",
+    "vietnamesisk",
+    "volapyk",
+    "vallonsk",
+    "walisisk",
+    "wolof",
+    "vestfrisisk",
+    "xhosa",
+    "jiddisk",
+    "joruba",
+    "zhuang",
+    "zulu",
+];
+
+static NO: [&str; 184] = [
+    "abkhasisk",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albansk",
+    "amharisk",
+    "arabisk",
+    "aragonsk",
+    "armensk",
+    "assamesisk",
+    "avarisk",
+    "avestisk",
+    "aymara",
+    "aserbajdsjansk",
+    "bambara",
+    "basjkirsk",
+    "baskisk",
+    "belarusisk",
+    "bengali",
+    "",
+    "bislama",
+    "bosnisk",
+    "bretonsk",
+    "bulgarsk",
+    "burmesisk",
+    "katalansk",
+    "chamorro",
+    "tsjetsjensk",
+    "nyanja",
+    "kinesisk",
+    "tsjuvasjisk",
+    "kornisk",
+    "korsikansk",
+    "cree",
+    "kroatisk",
+    "tsjekkisk",
+    "dansk",
+    "divehi",
+    "nederlandsk",
+    "dzongkha",
+    "engelsk",
+    "esperanto",
+    "estisk",
+    "ewe",
+    "færøysk",
+    "fijiansk",
+    "finsk",
+    "fransk",
+    "fulfulde",
+    "galisisk",
+    "georgisk",
+    "tysk",
+    "gresk",
+    "guarani",
+    "gujarati",
+    "haitisk",
+    "hausa",
+    "hebraisk",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "ungarsk",
+    "interlingua",
+    "indonesisk",
+    "interlingue",
+    "irsk",
+    "ibo",
+    "inupiak",
+    "ido",
+    "islandsk",
+    "italiensk",
+    "inuktitut",
+    "japansk",
+    "javanesisk",
+    "grønlandsk",
+    "kannada",
+    "kanuri",
+    "kasjmiri",
+    "kasakhisk",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirgisisk",
+    "komi",
+    "kikongo",
+    "koreansk",
+    "kurdisk",
+    "kuanyama",
+    "latin",
+    "luxemburgsk",
+    "ganda",
+    "limburgsk",
+    "lingala",
+    "laotisk",
+    "litauisk",
+    "luba-katanga",
+    "latvisk",
+    "mansk",
+    "makedonsk",
+    "gassisk",
+    "malayisk",
+    "malayalam",
+    "maltesisk",
+    "maori",
+    "marathi",
+    "marshallesisk",
+    "mongolsk",
+    "nauru",
+    "navajo",
+    "nord-ndebele",
+    "nepali",
+    "ndonga",
+    "norsk bokmål",
+    "norsk nynorsk",
+    "norsk",
+    "sichuan-yi",
+    "sør-ndebele",
+    "oksitansk",
+    "ojibwa",
+    "kirkeslavisk",
+    "oromo",
+    "odia",
+    "ossetisk",
+    "panjabi",
+    "pali",
+    "persisk",
+    "polsk",
+    "pashto",
+    "portugisisk",
+    "quechua",
+    "retoromansk",
+    "rundi",
+    "rumensk",
+    "russisk",
+    "sanskrit",
+    "sardisk",
+    "sindhi",
+    "nordsamisk",
+    "samoansk",
+    "sango",
+    "serbisk",
+    "skotsk-gælisk",
+    "shona",
+    "singalesisk",
+    "slovakisk",
+    "slovensk",
+    "somali",
+    "sør-sotho",
+    "spansk",
+    "sundanesisk",
+    "swahili",
+    "swati",
+    "svensk",
+    "tamil",
+    "telugu",
+    "tadsjikisk",
+    "thai",
+    "tigrinja",
+    "tibetansk",
+    "turkmensk",
+    "tagalog",
+    "setswana",
+    "tongansk",
+    "tyrkisk",
+    "tsonga",
+    "tatarisk",
+    "twi",
+    "tahitisk",
+    "uigurisk",
+    "ukrainsk",
+    "urdu",
+    "usbekisk",
+    "venda",
+    "vietnamesisk",
+    "volapyk",
+    "vallonsk",
+    "walisisk",
+    "wolof",
+    "vestfrisisk",
+    "xhosa",
+    "jiddisk",
+    "joruba",
+    "zhuang",
+    "zulu",
+];
+
+static OR: [&str; 184] = [
+    "ଆବ୍ଖାଜିଆନ୍",
+    "ଅଫାର୍",
+    "ଆଫ୍ରିକୀୟ",
+    "ଅକନ୍",
+    "ଆଲବାନିଆନ୍",
+    "ଆମହାରକି",
+    "ଆରବିକ୍",
+    "ଆର୍ଗୋନୀ",
+    "ଆର୍ମେନିଆନ୍",
+    "ଆସାମୀୟ",
+    "ଆଭାରିକ୍",
+    "ଅବେସ୍ତନ",
+    "ଆୟମାରା",
+    "ଆଜେରବାଇଜାନି",
+    "ବାମ୍ବାରା",
+    "ବାଶକିର୍",
+    "ବାସ୍କ୍ୱି",
+    "ବେଲାରୁଷିଆନ୍",
+    "ବଙ୍ଗଳା",
+    "",
+    "ବିସଲାମା",
+    "ବୋସନିଆନ୍",
+    "ବ୍ରେଟନ୍",
+    "ବୁଲଗେରିଆନ୍",
+    "ବର୍ମୀଜ୍",
+    "କାଟାଲାନ୍",
+    "ଚାମୋରୋ",
+    "ଚେଚନ୍",
+    "ନିୟାଞ୍ଜ",
+    "ଚାଇନିଜ୍\u{200C}",
+    "ଚୁଭାଶ୍",
+    "କୋର୍ନିସ୍",
+    "କୋର୍ସିକାନ୍",
+    "କ୍ରୀ",
+    "କ୍ରୋଏସୀୟ",
+    "ଚେକ୍",
+    "ଡାନିସ୍\u{200C}",
+    "ଡିଭେହୀ",
+    "ଡଚ୍",
+    "ଦଡଜୋଙ୍ଗଖା",
+    "ଇଂରାଜୀ",
+    "ଏସ୍ପାରେଣ୍ଟୋ",
+    "ଏସ୍ତୋନିଆନ୍",
+    "ଇୱେ",
+    "ଫାରୋଇଜ୍\u{200C}",
+    "ଫିଜି",
+    "ଫିନ୍ନିସ୍",
+    "ଫରାସୀ",
+    "ଫୁଲାହ",
+    "ଗାଲିସିଆନ୍\u{200C}",
+    "ଜର୍ଜିଆନ୍\u{200C}",
+    "ଜର୍ମାନ",
+    "ଗ୍ରୀକ୍",
+    "ଗୁଆରାନୀ",
+    "ଗୁଜରାଟୀ",
+    "ହୈତାୟିନ୍",
+    "ହୌସା",
+    "ହିବ୍ରୁ",
+    "ହେରେରୋ",
+    "ହିନ୍ଦୀ",
+    "ହିରି ମୋଟୁ",
+    "ହଙ୍ଗେରୀୟ",
+    "ଇର୍ଣ୍ଟଲିଙ୍ଗୁଆ",
+    "ଇଣ୍ଡୋନେସୀୟ",
+    "ଇର୍ଣ୍ଟରଲିଙ୍ଗୁଇ",
+    "ଆଇରିସ୍\u{200C}",
+    "ଇଗବୋ",
+    "ଇନୁପିୟାକ୍",
+    "ଇଡୋ",
+    "ଆଇସଲାଣ୍ଡିକ୍",
+    "ଇଟାଲୀୟ",
+    "ଇନୁକଟିଟୁତ୍\u{200C}",
+    "ଜାପାନୀ",
+    "ଜାଭାନିଜ୍\u{200C}",
+    "କାଲାଲିସୁଟ୍",
+    "କନ୍ନଡ",
+    "କନୁରୀ",
+    "କାଶ୍ମିରୀ",
+    "କାଜାଖ୍\u{200C}",
+    "ଖାମେର୍",
+    "କୀକୁୟୁ",
+    "କିନ୍ୟାରୱାଣ୍ଡା",
+    "କୀରଗୀଜ୍",
+    "କୋମି",
+    "କଙ୍ଗୋ",
+    "କୋରିଆନ୍",
+    "କୁର୍ଦ୍ଦିଶ୍",
+    "କ୍ୱାନ୍ୟାମ୍",
+    "ଲାଟିନ୍",
+    "ଲକ୍ସେମବର୍ଗିସ୍",
+    "ଗନ୍ଦା",
+    "ଲିମ୍ବୁର୍ଗିସ୍",
+    "ଲିଙ୍ଗାଲା",
+    "ଲାଓ",
+    "ଲିଥୁଆନିଆନ୍",
+    "ଲ୍ୟୁବା-କାଟାଙ୍ଗା",
+    "ଲାଟଭିଆନ୍",
+    "ମାଁକ୍ସ",
+    "ମାସେଡୋନିଆନ୍",
+    "ମାଲାଗାସୀ",
+    "ମାଲୟ",
+    "ମାଲାୟଲମ୍",
+    "ମାଲଟୀଜ୍",
+    "ମାଓରୀ",
+    "ମରାଠୀ",
+    "ମାର୍ଶାଲୀଜ୍",
+    "ମଙ୍ଗୋଲୀୟ",
+    "ନାଉରୁ",
+    "ନାଭାଜୋ",
+    "ଉତ୍ତର ନେଡବେଲେ",
+    "ନେପାଳୀ",
+    "ଡୋଙ୍ଗା",
+    "ନରୱେଜିଆନ୍ ବୋକମଲ୍",
+    "ନରୱେଜିଆନ୍ ନିନର୍ସ୍କ୍\u{200C}",
+    "ନରୱେଜିଆନ୍",
+    "ସିଚୁଆନ୍ ୟୀ",
+    "ଦକ୍ଷିଣ ନେଡବେଲେ",
+    "ଓସିଟାନ୍",
+    "ଓଜିୱା",
+    "ଚର୍ଚ୍ଚ ସ୍ଲାଭିକ୍",
+    "ଓରୋମୋ",
+    "ଓଡ଼ିଆ",
+    "ଓସେଟିକ୍",
+    "ପଞ୍ଜାବୀ",
+    "ପାଲି",
+    "ପର୍ସିଆନ୍",
+    "ପୋଲିଶ୍",
+    "ପାସ୍ତୋ",
+    "ପର୍ତ୍ତୁଗୀଜ୍\u{200C}",
+    "କ୍ୱେଚୁଆ",
+    "ରୋମାନଶ୍\u{200C}",
+    "ରୁଣ୍ଡି",
+    "ରୋମାନିଆନ୍",
+    "ରୁଷିୟ",
+    "ସଂସ୍କୃତ",
+    "ସାର୍ଡିନିଆନ୍\u{200C}",
+    "ସିନ୍ଧୀ",
+    "ଉତ୍ତର ସାମି",
+    "ସାମୋଆନ୍",
+    "ସାଙ୍ଗୋ",
+    "ସର୍ବିୟ",
+    "ସ୍କଟିସ୍ ଗାଏଲିକ୍",
+    "ଶୋନା",
+    "ସିଂହଳ",
+    "ସ୍ଲୋଭାକ୍",
+    "ସ୍ଲୋଭେନିଆନ୍",
+    "ସୋମାଲିଆ",
+    "ସେସୋଥୋ",
+    "ସ୍ପାନିସ୍\u{200C}",
+    "ସୁଦାନୀଜ୍",
+    "ସ୍ୱାହିଲି",
+    "ସ୍ଵାତି",
+    "ସ୍ୱେଡିସ୍",
+    "ତାମିଲ୍",
+    "ତେଲୁଗୁ",
+    "ତାଜିକ୍",
+    "ଥାଇ",
+    "ଟାଇଗ୍ରିନିଆ",
+    "ତିବ୍ବତୀୟ",
+    "ତୁର୍କମେନ୍",
+    "ଟାଗାଲଗ୍",
+    "ସୱାନା",
+    "ଟୋଙ୍ଗା",
+    "ତୁର୍କିସ୍",
+    "ସୋଙ୍ଗା",
+    "ତାତାର୍",
+    "ତ୍ୱି",
+    "ତାହିତିଆନ୍",
+    "ୟୁଘୁର୍",
+    "ୟୁକ୍ରେନିଆନ୍",
+    "ଉର୍ଦ୍ଦୁ",
+    "ଉଜବେକ୍",
+    "ଭେଣ୍ଡା",
+    "ଭିଏତନାମିଜ୍",
+    "ବୋଲାପୁକ",
+    "ୱାଲୁନ୍",
+    "ୱେଲ୍ସ",
+    "ୱୋଲଫ୍",
+    "ପାଶ୍ଚାତ୍ୟ ଫ୍ରିସିଆନ୍",
+    "ଖୋସା",
+    "ୟିଡିସ୍",
+    "ୟୋରୁବା",
+    "ଜୁଆଙ୍ଗ୍\u{200C}",
+    "ଜୁଲୁ",
+];
+
+static PA: [&str; 184] = [
+    "ਅਬਖਾਜ਼ੀਅਨ",
+    "ਅਫ਼ਾਰ",
+    "ਅਫ਼ਰੀਕੀ",
+    "ਅਕਾਨ",
+    "ਅਲਬਾਨੀਆਈ",
+    "ਅਮਹਾਰਿਕ",
+    "ਅਰਬੀ",
+    "ਅਰਾਗੋਨੀ",
+    "ਅਰਮੀਨੀਆਈ",
+    "ਅਸਾਮੀ",
+    "ਅਵਾਰਿਕ",
+    "",
+    "ਅਈਮਾਰਾ",
+    "ਅਜ਼ਰਬਾਈਜਾਨੀ",
+    "ਬੰਬਾਰਾ",
+    "ਬਸ਼ਕੀਰ",
+    "ਬਾਸਕ",
+    "ਬੇਲਾਰੂਸੀ",
+    "ਬੰਗਾਲੀ",
+    "",
+    "ਬਿਸਲਾਮਾ",
+    "ਬੋਸਨੀਆਈ",
+    "ਬਰੇਟਨ",
+    "ਬੁਲਗਾਰੀਆਈ",
+    "ਬਰਮੀ",
+    "ਕੈਟਾਲਾਨ",
+    "ਚਮੋਰੋ",
+    "ਚੇਚਨ",
+    "ਨਯਾਂਜਾ",
+    "ਚੀਨੀ",
+    "ਚੁਵਾਸ਼",
+    "ਕੋਰਨਿਸ਼",
+    "ਕੋਰਸੀਕਨ",
+    "",
+    "ਕ੍ਰੋਏਸ਼ਿਆਈ",
+    "ਚੈੱਕ",
+    "ਡੈਨਿਸ਼",
+    "ਦਿਵੇਹੀ",
+    "ਡੱਚ",
+    "ਜ਼ੋਂਗਖਾ",
+    "ਅੰਗਰੇਜ਼ੀ",
+    "ਇਸਪੇਰਾਂਟੋ",
+    "ਇਸਟੋਨੀਆਈ",
+    "ਈਵਈ",
+    "ਫ਼ੇਰੋਸੇ",
+    "ਫ਼ਿਜ਼ੀ",
+    "ਫਿਨਿਸ਼",
+    "ਫਰਾਂਸੀਸੀ",
+    "ਫੁਲਾਹ",
+    "ਗੈਲਿਸ਼ਿਅਨ",
+    "ਜਾਰਜੀਆਈ",
+    "ਜਰਮਨ",
+    "ਯੂਨਾਨੀ",
+    "ਗੁਆਰਾਨੀ",
+    "ਗੁਜਰਾਤੀ",
+    "ਹੈਤੀਆਈ",
+    "ਹੌਸਾ",
+    "ਹਿਬਰੂ",
+    "ਹਰੇਰੋ",
+    "ਹਿੰਦੀ",
+    "",
+    "ਹੰਗਰੀਆਈ",
+    "ਇੰਟਰਲਿੰਗੁਆ",
+    "ਇੰਡੋਨੇਸ਼ੀਆਈ",
+    "ਇੰਟਰਲਿੰਗੁਈ",
+    "ਆਇਰਸ਼",
+    "ਇਗਬੋ",
+    "",
+    "ਇਡੂ",
+    "ਆਈਸਲੈਂਡਿਕ",
+    "ਇਤਾਲਵੀ",
+    "ਇੰਕਟੀਟੂਤ",
+    "ਜਪਾਨੀ",
+    "ਜਾਵਾਨੀਜ਼",
+    "ਕਲਾਅੱਲੀਸੁਟ",
+    "ਕੰਨੜ",
+    "ਕਨੂਰੀ",
+    "ਕਸ਼ਮੀਰੀ",
+    "ਕਜ਼ਾਖ਼",
+    "ਖਮੇਰ",
+    "ਕਿਕੂਯੂ",
+    "ਕਿਨਿਆਰਵਾਂਡਾ",
+    "ਕਿਰਗੀਜ਼",
+    "ਕੋਮੀ",
+    "",
+    "ਕੋਰੀਆਈ",
+    "ਕੁਰਦਿਸ਼",
+    "ਕੁਆਨਯਾਮਾ",
+    "ਲਾਤੀਨੀ",
+    "ਲਕਜ਼ਮਬਰਗਿਸ਼",
+    "ਗਾਂਡਾ",
+    "ਲਿਮਬੁਰਗੀ",
+    "ਲਿੰਗਾਲਾ",
+    "ਲਾਓ",
+    "ਲਿਥੁਆਨੀਅਨ",
+    "ਲੂਬਾ-ਕਾਟਾਂਗਾ",
+    "ਲਾਤੀਵੀ",
+    "ਮੈਂਕਸ",
+    "ਮੈਕਡੋਨੀਆਈ",
+    "ਮਾਲਾਗੈਸੀ",
+    "ਮਲਯ",
+    "ਮਲਿਆਲਮ",
+    "ਮਾਲਟੀਜ਼",
+    "ਮਾਉਰੀ",
+    "ਮਰਾਠੀ",
+    "ਮਾਰਸ਼ਲੀਜ਼",
+    "ਮੰਗੋਲੀ",
+    "ਨਾਉਰੂ",
+    "ਨਵਾਜੋ",
+    "ਉੱਤਰੀ ਨਡੇਬੇਲੇ",
+    "ਨੇਪਾਲੀ",
+    "ਐਂਡੋਂਗਾ",
+    "ਨਾਰਵੇਜਿਆਈ ਬੋਕਮਲ",
+    "ਨਾਰਵੇਜਿਆਈ ਨਿਓਨੌਰਸਕ",
+    "ਨਾਰਵੇਜਿਆਈ",
+    "ਸਿਚੁਆਨ ਯੀ",
+    "ਸਾਊਥ ਨਡੇਬੇਲੇ",
+    "ਓਕਸੀਟਾਨ",
+    "",
+    "ਚਰਚ ਸਲਾਵੀ",
+    "ਓਰੋਮੋ",
+    "ਉੜੀਆ",
+    "ਓਸੈਟਿਕ",
+    "ਪੰਜਾਬੀ",
+    "ਪਾਲੀ",
+    "ਫ਼ਾਰਸੀ",
+    "ਪੋਲੈਂਡੀ",
+    "ਪਸ਼ਤੋ",
+    "ਪੁਰਤਗਾਲੀ",
+    "ਕਕੇਸ਼ੁਆ",
+    "ਰੋਮਾਂਸ਼",
+    "ਰੁੰਡੀ",
+    "ਰੋਮਾਨੀਆਈ",
+    "ਰੂਸੀ",
+    "ਸੰਸਕ੍ਰਿਤ",
+    "ਸਾਰਡੀਨੀਆਈ",
+    "ਸਿੰਧੀ",
+    "ਉੱਤਰੀ ਸਾਮੀ",
+    "ਸਾਮੋਨ",
+    "ਸਾਂਗੋ",
+    "ਸਰਬੀਆਈ",
+    "ਸਕਾਟਿਸ਼ ਗੇਲਿਕ",
+    "ਸ਼ੋਨਾ",
+    "ਸਿੰਹਾਲਾ",
+    "ਸਲੋਵਾਕ",
+    "ਸਲੋਵੇਨੀਆਈ",
+    "ਸੋਮਾਲੀ",
+    "ਦੱਖਣੀ ਸੋਥੋ",
+    "ਸਪੇਨੀ",
+    "ਸੂੰਡਾਨੀ",
+    "ਸਵਾਹਿਲੀ",
+    "ਸਵਾਤੀ",
+    "ਸਵੀਡਿਸ਼",
+    "ਤਮਿਲ",
+    "ਤੇਲਗੂ",
+    "ਤਾਜਿਕ",
+    "ਥਾਈ",
+    "ਤਿਗ੍ਰੀਨਿਆ",
+    "ਤਿੱਬਤੀ",
+    "ਤੁਰਕਮੇਨ",
+    "",
+    "ਤਸਵਾਨਾ",
+    "ਟੌਂਗਨ",
+    "ਤੁਰਕੀ",
+    "ਸੋਂਗਾ",
+    "ਤਤਾਰ",
+    "ਤ੍ਵਿ",
+    "ਤਾਹੀਟੀ",
+    "ਉਇਗੁਰ",
+    "ਯੂਕਰੇਨੀਆਈ",
+    "ਉਰਦੂ",
+    "ਉਜ਼ਬੇਕ",
+    "ਵੇਂਡਾ",
+    "ਵੀਅਤਨਾਮੀ",
+    "ਵੋਲਾਪੂਕ",
+    "ਵਲੂਨ",
+    "ਵੈਲਸ਼",
+    "ਵੋਲੋਫ",
+    "ਪੱਛਮੀ ਫ੍ਰਿਸੀਅਨ",
+    "ਖੋਸਾ",
+    "ਯਿਦਿਸ਼",
+    "ਯੋਰੂਬਾ",
+    "ਜ਼ੁਆਂਗ",
+    "ਜ਼ੁਲੂ",
+];
+
+static FA: [&str; 184] = [
+    "آبخازی",
+    "آفاری",
+    "آفریکانس",
+    "آکان",
+    "آلبانیایی",
+    "امهری",
+    "عربی",
+    "آراگونی",
+    "ارمنی",
+    "آسامی",
+    "آواری",
+    "اوستایی",
+    "آیمارایی",
+    "ترکی آذربایجانی",
+    "بامبارایی",
+    "باشقیری",
+    "باسکی",
+    "بلاروسی",
+    "بنگالی",
+    "",
+    "بیسلاما",
+    "بوسنیایی",
+    "برتون",
+    "بلغاری",
+    "برمه\u{200C}ای",
+    "کاتالان",
+    "چامورویی",
+    "چچنی",
+    "نیانجایی",
+    "چینی",
+    "چوواشی",
+    "کورنی",
+    "کورسی",
+    "کریایی",
+    "کروات",
+    "چکی",
+    "دانمارکی",
+    "دیوهی",
+    "هلندی",
+    "دزونگخا",
+    "انگلیسی",
+    "اسپرانتو",
+    "استونیایی",
+    "اوه\u{200C}ای",
+    "فارویی",
+    "فیجیایی",
+    "فنلاندی",
+    "فرانسوی",
+    "فولانی",
+    "گالیسیایی",
+    "گرجی",
+    "آلمانی",
+    "یونانی",
+    "گوارانی",
+    "گجراتی",
+    "هائیتیایی",
+    "هوسایی",
+    "عبری",
+    "هریرویی",
+    "هندی",
+    "موتویی هیری",
+    "مجاری",
+    "اینترلینگوا",
+    "اندونزیایی",
+    "اکسیدنتال",
+    "ایرلندی",
+    "ایگبویی",
+    "اینوپیک",
+    "ایدو",
+    "ایسلندی",
+    "ایتالیایی",
+    "اینوکتیتوت",
+    "ژاپنی",
+    "جاوه\u{200C}ای",
+    "گرینلندی",
+    "کانارا",
+    "کانوریایی",
+    "کشمیری",
+    "قزاقی",
+    "خمری",
+    "کیکویویی",
+    "کینیارواندایی",
+    "قرقیزی",
+    "کومیایی",
+    "کنگویی",
+    "کره\u{200C}ای",
+    "کردی",
+    "کوانیاما",
+    "لاتین",
+    "لوگزامبورگی",
+    "گاندایی",
+    "لیمبورگی",
+    "لینگالا",
+    "لائوسی",
+    "لیتوانیایی",
+    "لوبایی‐کاتانگا",
+    "لتونیایی",
+    "مانی",
+    "مقدونی",
+    "مالاگاسی",
+    "مالایی",
+    "مالایالامی",
+    "مالتی",
+    "مائوری",
+    "مراتی",
+    "مارشالی",
+    "مغولی",
+    "نائورویی",
+    "ناواهویی",
+    "انده\u{200C}بله\u{200C}ای شمالی",
+    "نپالی",
+    "اندونگایی",
+    "نروژی بوک\u{200C}مُل",
+    "نروژی نی\u{200C}نُشک",
+    "نروژی",
+    "یی سیچوان",
+    "انده\u{200C}بله\u{200C}ای جنوبی",
+    "اکسیتان",
+    "اوجیبوایی",
+    "اسلاوی کلیسایی",
+    "اورومویی",
+    "اوریه\u{200C}ای",
+    "آسی",
+    "پنجابی",
+    "پالی",
+    "فارسی",
+    "لهستانی",
+    "پشتو",
+    "پرتغالی",
+    "کچوایی",
+    "رومانش",
+    "روندیایی",
+    "رومانیایی",
+    "روسی",
+    "سانسکریت",
+    "ساردینیایی",
+    "سندی",
+    "سامی شمالی",
+    "ساموآیی",
+    "سانگو",
+    "صربی",
+    "گیلی اسکاتلندی",
+    "شونایی",
+    "سینهالی",
+    "اسلواکی",
+    "اسلوونیایی",
+    "سومالیایی",
+    "سوتوی جنوبی",
+    "اسپانیایی",
+    "سوندایی",
+    "سواحیلی",
+    "سوازیایی",
+    "سوئدی",
+    "تامیلی",
+    "تلوگویی",
+    "تاجیکی",
+    "تایلندی",
+    "تیگرینیایی",
+    "تبتی",
+    "ترکمنی",
+    "تاگالوگی",
+    "تسوانایی",
+    "تونگایی",
+    "ترکی استانبولی",
+    "تسونگایی",
+    "تاتاری",
+    "توی\u{200C}یایی",
+    "تاهیتیایی",
+    "اویغوری",
+    "اوکراینی",
+    "اردو",
+    "ازبکی",
+    "وندایی",
+    "ویتنامی",
+    "ولاپوک",
+    "والونی",
+    "ولزی",
+    "ولوفی",
+    "فریسی غربی",
+    "خوسایی",
+    "یدی",
+    "یوروبایی",
+    "ژوانگی",
+    "زولویی",
+];
+
+static PL: [&str; 184] = [
+    "abchaski",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albański",
+    "amharski",
+    "arabski",
+    "aragoński",
+    "ormiański",
+    "asamski",
+    "awarski",
+    "awestyjski",
+    "ajmara",
+    "azerbejdżański",
+    "bambara",
+    "baszkirski",
+    "baskijski",
+    "białoruski",
+    "bengalski",
+    "",
+    "bislama",
+    "bośniacki",
+    "bretoński",
+    "bułgarski",
+    "birmański",
+    "kataloński",
+    "czamorro",
+    "czeczeński",
+    "njandża",
+    "chiński",
+    "czuwaski",
+    "kornijski",
+    "korsykański",
+    "kri",
+    "chorwacki",
+    "czeski",
+    "duński",
+    "malediwski",
+    "niderlandzki",
+    "dzongkha",
+    "angielski",
+    "esperanto",
+    "estoński",
+    "ewe",
+    "farerski",
+    "fidżijski",
+    "fiński",
+    "francuski",
+    "fulani",
+    "galicyjski",
+    "gruziński",
+    "niemiecki",
+    "grecki",
+    "guarani",
+    "gudżarati",
+    "kreolski haitański",
+    "hausa",
+    "hebrajski",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "węgierski",
+    "interlingua",
+    "indonezyjski",
+    "interlingue",
+    "irlandzki",
+    "igbo",
+    "inupiak",
+    "ido",
+    "islandzki",
+    "włoski",
+    "inuktitut",
+    "japoński",
+    "jawajski",
+    "grenlandzki",
+    "kannada",
+    "kanuri",
+    "kaszmirski",
+    "kazachski",
+    "khmerski",
+    "kikuju",
+    "kinya-ruanda",
+    "kirgiski",
+    "komi",
+    "kongo",
+    "koreański",
+    "kurdyjski",
+    "kwanyama",
+    "łaciński",
+    "luksemburski",
+    "ganda",
+    "limburski",
+    "lingala",
+    "laotański",
+    "litewski",
+    "luba-katanga",
+    "łotewski",
+    "manx",
+    "macedoński",
+    "malgaski",
+    "malajski",
+    "malajalam",
+    "maltański",
+    "maoryjski",
+    "marathi",
+    "marszalski",
+    "mongolski",
+    "nauruański",
+    "nawaho",
+    "ndebele północny",
+    "nepalski",
+    "ndonga",
+    "norweski (bokmål)",
+    "norweski (nynorsk)",
+    "norweski",
+    "syczuański",
+    "ndebele południowy",
+    "oksytański",
+    "odżibwa",
+    "cerkiewnosłowiański",
+    "oromo",
+    "orija",
+    "osetyjski",
+    "pendżabski",
+    "palijski",
+    "perski",
+    "polski",
+    "paszto",
+    "portugalski",
+    "keczua",
+    "retoromański",
+    "rundi",
+    "rumuński",
+    "rosyjski",
+    "sanskryt",
+    "sardyński",
+    "sindhi",
+    "północnolapoński",
+    "samoański",
+    "sango",
+    "serbski",
+    "szkocki gaelicki",
+    "shona",
+    "syngaleski",
+    "słowacki",
+    "słoweński",
+    "somalijski",
+    "sotho południowy",
+    "hiszpański",
+    "sundajski",
+    "suahili",
+    "suazi",
+    "szwedzki",
+    "tamilski",
+    "telugu",
+    "tadżycki",
+    "tajski",
+    "tigrinia",
+    "tybetański",
+    "turkmeński",
+    "tagalski",
+    "setswana",
+    "tonga",
+    "turecki",
+    "tsonga",
+    "tatarski",
+    "twi",
+    "tahitański",
+    "ujgurski",
+    "ukraiński",
+    "urdu",
+    "uzbecki",
+    "venda",
+    "wietnamski",
+    "wolapik",
+    "waloński",
+    "walijski",
+    "wolof",
+    "zachodniofryzyjski",
+    "khosa",
+    "jidysz",
+    "joruba",
+    "czuang",
+    "zulu",
+];
+
+static PS: [&str; 184] = [
+    "ابخازي",
+    "افري",
+    "افریکانسي",
+    "اکاني",
+    "الباني",
+    "امهاري",
+    "عربي",
+    "اراگونېسي",
+    "آرمينيايي",
+    "اسامي",
+    "اواري",
+    "",
+    "ایماري",
+    "اذربایجاني",
+    "بمبارا",
+    "باشکير",
+    "باسکي",
+    "بېلاروسي",
+    "بنگالي",
+    "",
+    "بسلاما",
+    "بوسني",
+    "برېتون",
+    "بلغاري",
+    "برمایی",
+    "کټلاني",
+    "چمورو",
+    "چيچني",
+    "نیانجا",
+    "چیني",
+    "چوواشي",
+    "کورنيشي",
+    "کورسيکاني",
+    "",
+    "کروايشيايي",
+    "چېکي",
+    "ډنمارکي",
+    "ديویهی",
+    "هالېنډي",
+    "ژونگکه",
+    "انګليسي",
+    "اسپرانتو",
+    "حبشي",
+    "ايو",
+    "فاروئې",
+    "فجیان",
+    "فینلنډي",
+    "فرانسوي",
+    "فولاح",
+    "ګلېشيايي",
+    "جورجيائي",
+    "الماني",
+    "یوناني",
+    "ګوراني",
+    "ګجراتي",
+    "هيټي کريول",
+    "هوسا",
+    "عبراني",
+    "هیرورو",
+    "هندي",
+    "",
+    "هنګري",
+    "انټرلنګوا",
+    "انډونېزي",
+    "آسا نا جبة",
+    "ائيرلېنډي",
+    "اګبو",
+    "",
+    "اڊو",
+    "ايسلنډي",
+    "ایټالوي",
+    "انوکتیتوت",
+    "جاپاني",
+    "جاوايي",
+    "کالالیست",
+    "کناډا",
+    "کنوری",
+    "کشمیري",
+    "قازق",
+    "خمر",
+    "ککوؤو",
+    "کینیارونډا",
+    "کرغيزي",
+    "کومی",
+    "",
+    "کوریایی",
+    "کردي",
+    "کواناما",
+    "لاتیني",
+    "لوګزامبورګي",
+    "ګانده",
+    "لمبرگیانی",
+    "لنګالا",
+    "لاو",
+    "ليتواني",
+    "لوبا-کټنګا",
+    "لېټواني",
+    "مینکس",
+    "مقدوني",
+    "ملغاسي",
+    "ملایا",
+    "مالايالم",
+    "مالټايي",
+    "ماوري",
+    "مراټهي",
+    "مارشلیز",
+    "منګولیایی",
+    "نایرو",
+    "نواجو",
+    "شمالي نديبل",
+    "نېپالي",
+    "ندونگا",
+    "ناروېئي (بوکمال)",
+    "ناروېئي (نائنورسک)",
+    "ناروېئي",
+    "سیچیان یی",
+    "سويلي نديبيل",
+    "اوکسيټاني",
+    "",
+    "د کليسا سلاوي",
+    "اورومو",
+    "اوڊيا",
+    "اوسيټک",
+    "پنجابي",
+    "",
+    "فارسي",
+    "پولنډي",
+    "پښتو",
+    "پورتګالي",
+    "کېچوا",
+    "رومانیش",
+    "رونډی",
+    "رومانیایی",
+    "روسي",
+    "سنسکریټ",
+    "سارڊيني",
+    "سندهي",
+    "شمالي سامي",
+    "ساموآن",
+    "سانګو",
+    "سربيائي",
+    "سکاټلېنډي ګېلک",
+    "شونا",
+    "سينهالي",
+    "سلوواکي",
+    "سلوواني",
+    "سومالي",
+    "سويلي سوتو",
+    "هسپانوي",
+    "سوډاني",
+    "سواهېلي",
+    "سواتی",
+    "سویډنی",
+    "تامل",
+    "تېليګو",
+    "تاجکي",
+    "تايلېنډي",
+    "تيګريني",
+    "تبتي",
+    "ترکمني",
+    "",
+    "سووانا",
+    "تونګان",
+    "ترکي",
+    "سونګا",
+    "تاتار",
+    "",
+    "تاهیتي",
+    "اويغوري",
+    "اوکرايني",
+    "اردو",
+    "اوزبکي",
+    "ویندا",
+    "وېتنامي",
+    "والاپوک",
+    "والون",
+    "ويلشي",
+    "ولوف",
+    "لوېديځ فريشي",
+    "خوسا",
+    "يديش",
+    "یوروبا",
+    "ژوانګ",
+    "زولو",
+];
+
+static PT: [&str; 184] = [
+    "abcázio",
+    "afar",
+    "africâner",
+    "akan",
+    "albanês",
+    "amárico",
+    "árabe",
+    "aragonês",
+    "armênio",
+    "assamês",
+    "avárico",
+    "avéstico",
+    "aimará",
+    "azerbaijano",
+    "bambara",
+    "bashkir",
+    "basco",
+    "bielorrusso",
+    "bengali",
+    "",
+    "bislamá",
+    "bósnio",
+    "bretão",
+    "búlgaro",
+    "birmanês",
+    "catalão",
+    "chamorro",
+    "checheno",
+    "nianja",
+    "chinês",
+    "tchuvache",
+    "córnico",
+    "corso",
+    "cree",
+    "croata",
+    "tcheco",
+    "dinamarquês",
+    "divehi",
+    "holandês",
+    "dzonga",
+    "inglês",
+    "esperanto",
+    "estoniano",
+    "ewe",
+    "feroês",
+    "fijiano",
+    "finlandês",
+    "francês",
+    "fula",
+    "galego",
+    "georgiano",
+    "alemão",
+    "grego",
+    "guarani",
+    "guzerate",
+    "haitiano",
+    "hauçá",
+    "hebraico",
+    "herero",
+    "híndi",
+    "hiri motu",
+    "húngaro",
+    "interlíngua",
+    "indonésio",
+    "interlingue",
+    "irlandês",
+    "igbo",
+    "inupiaque",
+    "ido",
+    "islandês",
+    "italiano",
+    "inuktitut",
+    "japonês",
+    "javanês",
+    "groenlandês",
+    "canarim",
+    "canúri",
+    "caxemira",
+    "cazaque",
+    "khmer",
+    "quicuio",
+    "quiniaruanda",
+    "quirguiz",
+    "komi",
+    "congolês",
+    "coreano",
+    "curdo",
+    "cuanhama",
+    "latim",
+    "luxemburguês",
+    "luganda",
+    "limburguês",
+    "lingala",
+    "laosiano",
+    "lituano",
+    "luba-catanga",
+    "letão",
+    "manx",
+    "macedônio",
+    "malgaxe",
+    "malaio",
+    "malaiala",
+    "maltês",
+    "maori",
+    "marati",
+    "marshalês",
+    "mongol",
+    "nauruano",
+    "navajo",
+    "ndebele do norte",
+    "nepalês",
+    "dongo",
+    "bokmål norueguês",
+    "nynorsk norueguês",
+    "norueguês",
+    "sichuan yi",
+    "ndebele do sul",
+    "occitânico",
+    "ojibwa",
+    "eslavo eclesiástico",
+    "oromo",
+    "oriá",
+    "osseto",
+    "panjabi",
+    "páli",
+    "persa",
+    "polonês",
+    "pashto",
+    "português",
+    "quíchua",
+    "romanche",
+    "rundi",
+    "romeno",
+    "russo",
+    "sânscrito",
+    "sardo",
+    "sindi",
+    "sami setentrional",
+    "samoano",
+    "sango",
+    "sérvio",
+    "gaélico escocês",
+    "xona",
+    "cingalês",
+    "eslovaco",
+    "esloveno",
+    "somali",
+    "soto do sul",
+    "espanhol",
+    "sundanês",
+    "suaíli",
+    "suázi",
+    "sueco",
+    "tâmil",
+    "télugo",
+    "tadjique",
+    "tailandês",
+    "tigrínia",
+    "tibetano",
+    "turcomeno",
+    "tagalo",
+    "tswana",
+    "tonganês",
+    "turco",
+    "tsonga",
+    "tártaro",
+    "twi",
+    "taitiano",
+    "uigur",
+    "ucraniano",
+    "urdu",
+    "uzbeque",
+    "venda",
+    "vietnamita",
+    "volapuque",
+    "valão",
+    "galês",
+    "uolofe",
+    "frísio ocidental",
+    "xhosa",
+    "iídiche",
+    "iorubá",
+    "zhuang",
+    "zulu",
+];
+
+static QU: [&str; 184] = [
+    "Abjasia",
+    "",
+    "Afrikaans Simi",
+    "Akan Simi",
+    "Albanes Simi",
+    "Amarico Simi",
+    "Arabe Simi",
+    "Aragonesa",
+    "Armenio Simi",
+    "Asames Simi",
+    "Avaric",
+    "",
+    "Aymara Simi",
+    "Azerbaiyano Simi",
+    "Bambara Simi",
+    "Baskir Simi",
+    "Euskera Simi",
+    "Bielorruso Simi",
+    "Bangla Simi",
+    "",
+    "Bislama",
+    "Bosnio Simi",
+    "Breton Simi",
+    "Bulgaro Simi",
+    "Birmano Simi",
+    "Catalan Simi",
+    "Chamorro Simi",
+    "Checheno Simi",
+    "Nyanja Simi",
+    "Chino Simi",
+    "Chuvash Simi",
+    "Córnico Simi",
+    "Corso Simi",
+    "",
+    "Croata Simi",
+    "Checo Simi",
+    "Danes Simi",
+    "Divehi Simi",
+    "Neerlandes Simi",
+    "Butanés Simi",
+    "Ingles Simi",
+    "Esperanto Simi",
+    "Estonio Simi",
+    "Ewé Simi",
+    "Feroes Simi",
+    "Fiyiano Simi",
+    "Fines Simi",
+    "Frances Simi",
+    "Fulah Simi",
+    "Gallego Simi",
+    "Georgiano Simi",
+    "Aleman Simi",
+    "Griego Simi",
+    "Guaraní Simi",
+    "Gujarati Simi",
+    "Haitiano Criollo Simi",
+    "Hausa Simi",
+    "Hebreo Simi",
+    "Herero Simi",
+    "Hindi Simi",
+    "",
+    "Hungaro Simi",
+    "Interlingua Simi",
+    "Indonesio Simi",
+    "",
+    "Irlandes Simi",
+    "Igbo Simi",
+    "",
+    "Ido Simi",
+    "Islandes Simi",
+    "Italiano Simi",
+    "Inuktitut Simi",
+    "Japones Simi",
+    "Javanés Simi",
+    "Groenlandes Simi",
+    "Kannada Simi",
+    "Kanuri Simi",
+    "Cachemir Simi",
+    "Kazajo Simi",
+    "Khmer Simi",
+    "Kikuyu Simi",
+    "Kinyarwanda Simi",
+    "Kirghiz Simi",
+    "Komi Simi",
+    "",
+    "Coreano Simi",
+    "Kurdo Simi",
+    "Kuanyama Simi",
+    "Latín Simi",
+    "Luxemburgues Simi",
+    "Luganda Simi",
+    "Limburgues Simi",
+    "Lingala Simi",
+    "Lao Simi",
+    "Lituano Simi",
+    "Luba-Katanga Simi",
+    "Leton Simi",
+    "Manés Simi",
+    "Macedonio Simi",
+    "Malgache Simi",
+    "Malayo Simi",
+    "Malayalam Simi",
+    "Maltes Simi",
+    "Maori Simi",
+    "Marathi Simi",
+    "Marshallese Simi",
+    "Mongol Simi",
+    "Nauru Simi",
+    "Navajo Simi",
+    "Ndebele septentrional Simi",
+    "Nepali Simi",
+    "Ndonga Simi",
+    "Noruego Bokmål Simi",
+    "Noruego Nynorsk Simi",
+    "Noruego Simi",
+    "Yi Simi",
+    "Ndebele del Sur Simi",
+    "Occitano Simi",
+    "",
+    "Eslavo Eclesiástico Simi",
+    "Oromo Simi",
+    "Odia Simi",
+    "Osetio Simi",
+    "Punyabi Simi",
+    "",
+    "Persa Simi",
+    "Polaco Simi",
+    "Pashto Simi",
+    "Portugues Simi",
+    "Runasimi",
+    "Romanche Simi",
+    "Rundi Simi",
+    "Rumano Simi",
+    "Ruso Simi",
+    "Sanscrito Simi",
+    "Sardinian Simi",
+    "Sindhi Simi",
+    "Chincha Sami Simi",
+    "Samoano Simi",
+    "Sango Simi",
+    "Serbio Simi",
+    "Gaelico Escoces Simi",
+    "Shona Simi",
+    "Cingales Simi",
+    "Eslovaco Simi",
+    "Esloveno Simi",
+    "Somali Simi",
+    "Soto Meridional Simi",
+    "Español Simi",
+    "Sundanés Simi",
+    "Suajili Simi",
+    "Swati Simi",
+    "Sueco Simi",
+    "Tamil Simi",
+    "Telugu Simi",
+    "Tayiko Simi",
+    "Tailandes Simi",
+    "Tigriña Simi",
+    "Tibetano Simi",
+    "Turcomano Simi",
+    "",
+    "Setsuana Simi",
+    "Tongano Simi",
+    "Turco Simi",
+    "Tsonga Simi",
+    "Tartaro Simi",
+    "",
+    "Tahití Simi",
+    "Uigur Simi",
+    "Ucraniano Simi",
+    "Urdu Simi",
+    "Uzbeko Simi",
+    "Venda Simi",
+    "Vietnamita Simi",
+    "Volapük Simi",
+    "Valona Simi",
+    "Gales Simi",
+    "Wolof Simi",
+    "Frison Simi",
+    "Isixhosa Simi",
+    "Yiddish Simi",
+    "Yoruba Simi",
+    "",
+    "Isizulu Simi",
+];
+
+static RM: [&str; 184] = [
+    "abchasian",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albanais",
+    "amaric",
+    "arab",
+    "aragonais",
+    "armen",
+    "assami",
+    "avaric",
+    "avestic",
+    "aymara",
+    "aserbeidschanic",
+    "bambara",
+    "baschkir",
+    "basc",
+    "bieloruss",
+    "bengal",
+    "",
+    "bislama",
+    "bosniac",
+    "breton",
+    "bulgar",
+    "birman",
+    "catalan",
+    "chamorro",
+    "tschetschen",
+    "nyanja",
+    "chinais",
+    "tschuvasch",
+    "cornic",
+    "cors",
+    "cree",
+    "croat",
+    "tschec",
+    "danais",
+    "maledivic",
+    "ollandais",
+    "dzongkha",
+    "englais",
+    "esperanto",
+    "eston",
+    "ewe",
+    "feroais",
+    "fidschian",
+    "finlandais",
+    "franzos",
+    "fulah",
+    "galician",
+    "georgian",
+    "tudestg",
+    "grec",
+    "guarani",
+    "gujarati",
+    "creol haitian",
+    "haussa",
+    "ebraic",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "ungarais",
+    "interlingua",
+    "indonais",
+    "interlingue",
+    "irlandais",
+    "igbo",
+    "inupiak",
+    "ido",
+    "islandais",
+    "talian",
+    "inuktitut",
+    "giapunais",
+    "javanais",
+    "grönlandais",
+    "kannada",
+    "kanuri",
+    "kashmiri",
+    "casac",
+    "cambodschan",
+    "kikuyu",
+    "kinyarwanda",
+    "kirghis",
+    "komi",
+    "kongo",
+    "corean",
+    "curd",
+    "kuanyama",
+    "latin",
+    "luxemburgais",
+    "ganda",
+    "limburgais",
+    "lingala",
+    "laot",
+    "lituan",
+    "luba-katanga",
+    "letton",
+    "manx",
+    "macedon",
+    "malagassi",
+    "malaic",
+    "malayalam",
+    "maltais",
+    "maori",
+    "marathi",
+    "marschallais",
+    "mongolic",
+    "nauru",
+    "navajo",
+    "ndebele dal nord",
+    "nepalais",
+    "ndonga",
+    "norvegais bokmål",
+    "norvegiais nynorsk",
+    "norvegiais",
+    "sichuan yi",
+    "ndebele dal sid",
+    "occitan",
+    "ojibwa",
+    "slav da baselgia",
+    "oromo",
+    "oriya",
+    "ossetic",
+    "punjabi",
+    "pali",
+    "persian",
+    "polac",
+    "paschto",
+    "portugais",
+    "quechua",
+    "rumantsch",
+    "rundi",
+    "rumen",
+    "russ",
+    "sanscrit",
+    "sard",
+    "sindhi",
+    "sami dal nord",
+    "samoan",
+    "sango",
+    "serb",
+    "gaelic scot",
+    "shona",
+    "singalais",
+    "slovac",
+    "sloven",
+    "somali",
+    "sotho dal sid",
+    "spagnol",
+    "sundanais",
+    "suahili",
+    "swazi",
+    "svedais",
+    "tamil",
+    "telugu",
+    "tadjik",
+    "tailandais",
+    "tigrinya",
+    "tibetan",
+    "turkmen",
+    "tagalog",
+    "tswana",
+    "tonga",
+    "tirc",
+    "tsonga",
+    "tatar",
+    "twi",
+    "tahitian",
+    "uiguric",
+    "ucranais",
+    "urdu",
+    "usbec",
+    "venda",
+    "vietnamais",
+    "volapuk",
+    "vallon",
+    "kimric",
+    "wolof",
+    "fris",
+    "xhosa",
+    "jiddic",
+    "yoruba",
+    "zhuang",
+    "zulu",
+];
+
+static RO: [&str; 184] = [
+    "abhază",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albaneză",
+    "amharică",
+    "arabă",
+    "aragoneză",
+    "armeană",
+    "asameză",
+    "avară",
+    "avestană",
+    "aymara",
+    "azeră",
+    "bambara",
+    "bașkiră",
+    "bască",
+    "belarusă",
+    "bengaleză",
+    "",
+    "bislama",
+    "bosniacă",
+    "bretonă",
+    "bulgară",
+    "birmană",
+    "catalană",
+    "chamorro",
+    "cecenă",
+    "nyanja",
+    "chineză",
+    "ciuvașă",
+    "cornică",
+    "corsicană",
+    "cree",
+    "croată",
+    "cehă",
+    "daneză",
+    "divehi",
+    "neerlandeză",
+    "dzongkha",
+    "engleză",
+    "esperanto",
+    "estonă",
+    "ewe",
+    "feroeză",
+    "fijiană",
+    "finlandeză",
+    "franceză",
+    "fulah",
+    "galiciană",
+    "georgiană",
+    "germană",
+    "greacă",
+    "guarani",
+    "gujarati",
+    "haitiană",
+    "hausa",
+    "ebraică",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "maghiară",
+    "interlingua",
+    "indoneziană",
+    "interlingue",
+    "irlandeză",
+    "igbo",
+    "inupiak",
+    "ido",
+    "islandeză",
+    "italiană",
+    "inuktitut",
+    "japoneză",
+    "javaneză",
+    "kalaallisut",
+    "kannada",
+    "kanuri",
+    "cașmiră",
+    "kazahă",
+    "khmeră",
+    "kikuyu",
+    "kinyarwanda",
+    "kârgâză",
+    "komi",
+    "congoleză",
+    "coreeană",
+    "kurdă",
+    "kuanyama",
+    "latină",
+    "luxemburgheză",
+    "ganda",
+    "limburgheză",
+    "lingala",
+    "laoțiană",
+    "lituaniană",
+    "luba-katanga",
+    "letonă",
+    "manx",
+    "macedoneană",
+    "malgașă",
+    "malaeză",
+    "malayalam",
+    "malteză",
+    "maori",
+    "marathi",
+    "marshalleză",
+    "mongolă",
+    "nauru",
+    "navajo",
+    "ndebele de nord",
+    "nepaleză",
+    "ndonga",
+    "norvegiană bokmål",
+    "norvegiană nynorsk",
+    "norvegiană",
+    "yi din Sichuan",
+    "ndebele de sud",
+    "occitană",
+    "ojibwa",
+    "slavonă",
+    "oromo",
+    "odia",
+    "osetă",
+    "punjabi",
+    "pali",
+    "persană",
+    "poloneză",
+    "paștună",
+    "portugheză",
+    "quechua",
+    "romanșă",
+    "kirundi",
+    "română",
+    "rusă",
+    "sanscrită",
+    "sardiniană",
+    "sindhi",
+    "sami de nord",
+    "samoană",
+    "sango",
+    "sârbă",
+    "gaelică scoțiană",
+    "shona",
+    "singhaleză",
+    "slovacă",
+    "slovenă",
+    "somaleză",
+    "sesotho",
+    "spaniolă",
+    "sundaneză",
+    "swahili",
+    "swati",
+    "suedeză",
+    "tamilă",
+    "telugu",
+    "tadjică",
+    "thailandeză",
+    "tigrină",
+    "tibetană",
+    "turkmenă",
+    "tagalog",
+    "setswana",
+    "tongană",
+    "turcă",
+    "tsonga",
+    "tătară",
+    "twi",
+    "tahitiană",
+    "uigură",
+    "ucraineană",
+    "urdu",
+    "uzbecă",
+    "venda",
+    "vietnameză",
+    "volapuk",
+    "valonă",
+    "galeză",
+    "wolof",
+    "frizonă occidentală",
+    "xhosa",
+    "idiș",
+    "yoruba",
+    "zhuang",
+    "zulu",
+];
+
+static RU: [&str; 184] = [
+    "абхазский",
+    "афарский",
+    "африкаанс",
+    "акан",
+    "албанский",
+    "амхарский",
+    "арабский",
+    "арагонский",
+    "армянский",
+    "ассамский",
+    "аварский",
+    "авестийский",
+    "аймара",
+    "азербайджанский",
+    "бамбара",
+    "башкирский",
+    "баскский",
+    "белорусский",
+    "бенгальский",
+    "",
+    "бислама",
+    "боснийский",
+    "бретонский",
+    "болгарский",
+    "бирманский",
+    "каталанский",
+    "чаморро",
+    "чеченский",
+    "ньянджа",
+    "китайский",
+    "чувашский",
+    "корнский",
+    "корсиканский",
+    "кри",
+    "хорватский",
+    "чешский",
+    "датский",
+    "мальдивский",
+    "нидерландский",
+    "дзонг-кэ",
+    "английский",
+    "эсперанто",
+    "эстонский",
+    "эве",
+    "фарерский",
+    "фиджи",
+    "финский",
+    "французский",
+    "фулах",
+    "галисийский",
+    "грузинский",
+    "немецкий",
+    "греческий",
+    "гуарани",
+    "гуджарати",
+    "гаитянский",
+    "хауса",
+    "иврит",
+    "гереро",
+    "хинди",
+    "хиримоту",
+    "венгерский",
+    "интерлингва",
+    "индонезийский",
+    "интерлингве",
+    "ирландский",
+    "игбо",
+    "инупиак",
+    "идо",
+    "исландский",
+    "итальянский",
+    "инуктитут",
+    "японский",
+    "яванский",
+    "гренландский",
+    "каннада",
+    "канури",
+    "кашмири",
+    "казахский",
+    "кхмерский",
+    "кикуйю",
+    "киньяруанда",
+    "киргизский",
+    "коми",
+    "конго",
+    "корейский",
+    "курдский",
+    "кунама",
+    "латинский",
+    "люксембургский",
+    "ганда",
+    "лимбургский",
+    "лингала",
+    "лаосский",
+    "литовский",
+    "луба-катанга",
+    "латышский",
+    "мэнский",
+    "македонский",
+    "малагасийский",
+    "малайский",
+    "малаялам",
+    "мальтийский",
+    "маори",
+    "маратхи",
+    "маршалльский",
+    "монгольский",
+    "науру",
+    "навахо",
+    "северный ндебеле",
+    "непальский",
+    "ндонга",
+    "норвежский букмол",
+    "нюнорск",
+    "норвежский",
+    "носу",
+    "южный ндебеле",
+    "окситанский",
+    "оджибва",
+    "церковнославянский",
+    "оромо",
+    "ория",
+    "осетинский",
+    "панджаби",
+    "пали",
+    "персидский",
+    "польский",
+    "пушту",
+    "португальский",
+    "кечуа",
+    "романшский",
+    "рунди",
+    "румынский",
+    "русский",
+    "санскрит",
+    "сардинский",
+    "синдхи",
+    "северносаамский",
+    "самоанский",
+    "санго",
+    "сербский",
+    "гэльский",
+    "шона",
+    "сингальский",
+    "словацкий",
+    "словенский",
+    "сомали",
+    "южный сото",
+    "испанский",
+    "сунданский",
+    "суахили",
+    "свази",
+    "шведский",
+    "тамильский",
+    "телугу",
+    "таджикский",
+    "тайский",
+    "тигринья",
+    "тибетский",
+    "туркменский",
+    "тагалог",
+    "тсвана",
+    "тонганский",
+    "турецкий",
+    "тсонга",
+    "татарский",
+    "тви",
+    "таитянский",
+    "уйгурский",
+    "украинский",
+    "урду",
+    "узбекский",
+    "венда",
+    "вьетнамский",
+    "волапюк",
+    "валлонский",
+    "валлийский",
+    "волоф",
+    "западнофризский",
+    "коса",
+    "идиш",
+    "йоруба",
+    "чжуань",
+    "зулу",
+];
+
+static SC: [&str; 184] = [
+    "abcasu",
+    "",
+    "afrikaans",
+    "akan",
+    "albanesu",
+    "amàricu",
+    "àrabu",
+    "aragonesu",
+    "armenu",
+    "assamesu",
+    "avaru",
+    "",
+    "aimara",
+    "azerbaigianu",
+    "bambara",
+    "baschiru",
+    "bascu",
+    "bielorussu",
+    "bengalesu",
+    "",
+    "bislama",
+    "bosnìacu",
+    "brètone",
+    "bùlgaru",
+    "burmesu",
+    "catalanu",
+    "chamorru",
+    "cecenu",
+    "nyanja",
+    "tzinesu",
+    "ciuvàsciu",
+    "còrnicu",
+    "corsicanu",
+    "",
+    "croatu",
+    "tzecu",
+    "danesu",
+    "malvidianu",
+    "olandesu",
+    "dzongkha",
+    "inglesu",
+    "esperanto",
+    "èstone",
+    "ewe",
+    "faroesu",
+    "fijianu",
+    "finlandesu",
+    "frantzesu",
+    "fulah",
+    "galitzianu",
+    "georgianu",
+    "tedescu",
+    "grecu",
+    "guaranì",
+    "gujarati",
+    "crèolu haitianu",
+    "hausa",
+    "ebreu",
+    "herero",
+    "hindi",
+    "",
+    "ungheresu",
+    "interlìngua",
+    "indonesianu",
+    "interlìngue",
+    "irlandesu",
+    "igbo",
+    "",
+    "ido",
+    "islandesu",
+    "italianu",
+    "inuktitut",
+    "giaponesu",
+    "giavanesu",
+    "groenlandesu",
+    "kannada",
+    "kanuri",
+    "kashmiri",
+    "kazacu",
+    "khmer",
+    "kikuyu",
+    "kinyarwanda",
+    "chirghisu",
+    "komi",
+    "",
+    "coreanu",
+    "curdu",
+    "kuanyama",
+    "latinu",
+    "lussemburghesu",
+    "ganda",
+    "limburghesu",
+    "lingala",
+    "laotianu",
+    "lituanu",
+    "luba-katanga",
+    "lètone",
+    "mannesu",
+    "matzèdone",
+    "malgàsciu",
+    "malesu",
+    "malayalam",
+    "maltesu",
+    "maori",
+    "marathi",
+    "marshallesu",
+    "mòngolu",
+    "nauru",
+    "navajo",
+    "ndebele de su nord",
+    "nepalesu",
+    "ndonga",
+    "norvegesu bokmål",
+    "norvegesu nynorsk",
+    "norvegesu",
+    "sichuan yi",
+    "ndebele de su sud",
+    "otzitanu",
+    "",
+    "islavu eclesiàsticu",
+    "oromo",
+    "odia",
+    "ossèticu",
+    "punjabi",
+    "",
+    "persianu",
+    "polacu",
+    "pashto",
+    "portoghesu",
+    "quechua",
+    "romànciu",
+    "rundi",
+    "rumenu",
+    "russu",
+    "sànscritu",
+    "sardu",
+    "sindhi",
+    "sami setentrionale",
+    "samoanu",
+    "sango",
+    "serbu",
+    "gaèlicu iscotzesu",
+    "shona",
+    "singalesu",
+    "islovacu",
+    "islovenu",
+    "sòmalu",
+    "sotho meridionale",
+    "ispagnolu",
+    "sundanesu",
+    "swahili",
+    "swati",
+    "isvedesu",
+    "tamil",
+    "telugu",
+    "tagicu",
+    "tailandesu",
+    "tigrignu",
+    "tibetanu",
+    "turcmenu",
+    "",
+    "tswana",
+    "tonganu",
+    "turcu",
+    "tsonga",
+    "tàtaru",
+    "",
+    "taitianu",
+    "uiguru",
+    "ucrainu",
+    "urdu",
+    "uzbecu",
+    "venda",
+    "vietnamita",
+    "volapük",
+    "vallonu",
+    "gallesu",
+    "wolof",
+    "frisone otzidentale",
+    "xhosa",
+    "yiddish",
+    "yoruba",
+    "zhuang",
+    "zulu",
+];
+
+static SD: [&str; 184] = [
+    "ابقازیان",
+    "افار",
+    "آفريڪي",
+    "اڪان",
+    "الباني",
+    "امهاري",
+    "عربي",
+    "ارگني",
+    "ارماني",
+    "آسامي",
+    "اويرس",
+    "",
+    "ایمارا",
+    "آزربائيجاني",
+    "بمبارا",
+    "ڪينيڊا",
+    "باسق",
+    "بيلاروسي",
+    "بنگلا",
+    "",
+    "بسلاما",
+    "بوسنيائي",
+    "بريٽن",
+    "بلغاريائي",
+    "برمي",
+    "ڪيٽالان",
+    "چمورو",
+    "چیچن",
+    "نيانجا",
+    "چيني",
+    "چو واش",
+    "ڪورنش",
+    "ڪارسيڪائي",
+    "",
+    "ڪروشيائي",
+    "چيڪ",
+    "ڊينش",
+    "دويهي",
+    "ڊچ",
+    "زونخا",
+    "انگريزي",
+    "ايسپرانٽو",
+    "ايستونائي",
+    "ايو",
+    "فيروايس",
+    "فجي",
+    "فنش",
+    "فرانسيسي",
+    "فلاهه",
+    "گليشئين",
+    "جارجيائي",
+    "جرمن",
+    "يوناني",
+    "گواراني",
+    "گجراتي",
+    "هيٽي ڪرولي",
+    "هوسا",
+    "عبراني",
+    "هريرو",
+    "هندي",
+    "",
+    "هنگري",
+    "انٽرلنگئا",
+    "انڊونيشي",
+    "انٽرلنگئي",
+    "آئرش",
+    "اگبو",
+    "",
+    "ادو",
+    "آئيس لينڊڪ",
+    "اطالوي",
+    "انو ڪتوت",
+    "جاپاني",
+    "جاونيز",
+    "ڪالا ليسٽ",
+    "ڪناڊا",
+    "ڪنوري",
+    "ڪشميري",
+    "قازق",
+    "خمر",
+    "اڪويو",
+    "ڪنيار وانڊا",
+    "ڪرغيز",
+    "ڪومي",
+    "",
+    "ڪوريائي",
+    "ڪردي",
+    "ڪنياما",
+    "لاطيني",
+    "لگزمبرگ",
+    "گاندا",
+    "لمبرگش",
+    "لنگالا",
+    "لائو",
+    "ليٿونيائي",
+    "لوبا-ڪتانگا",
+    "لاتوين",
+    "مينڪس",
+    "ميسي ڊونيائي",
+    "ملاگاسي",
+    "ملي",
+    "مليالم",
+    "مالٽي",
+    "مائوري",
+    "مراٺي",
+    "مارشليز",
+    "منگولي",
+    "نائو",
+    "نواجو",
+    "اتر دبيلي",
+    "نيپالي",
+    "ڊونگا",
+    "نارويائي بوڪمال",
+    "نارويائي نيوناسڪ",
+    "نارويجيائي",
+    "سچوان يي",
+    "ڏکڻ دبيلي",
+    "آڪسيٽن",
+    "",
+    "چرچ سلاوی",
+    "اورومو",
+    "اوڊيا",
+    "اوسيٽڪ",
+    "پنجابي",
+    "",
+    "فارسي",
+    "پولش",
+    "پشتو",
+    "پورٽگليز",
+    "ڪيچوا",
+    "رومانش",
+    "رونڊي",
+    "روماني",
+    "روسي",
+    "سنسڪرت",
+    "سارڊيني",
+    "سنڌي",
+    "اتر سامي",
+    "سموئا",
+    "سانگو",
+    "سربيائي",
+    "اسڪاٽش گيلڪ",
+    "شونا",
+    "سنهالا",
+    "سلواڪي",
+    "سلوويني",
+    "سومالي",
+    "ڏکڻ سوٿي",
+    "هسپانوي",
+    "سوڊاني",
+    "سواحيلي",
+    "سواتي",
+    "سويڊش",
+    "تامل",
+    "تلگو",
+    "تاجڪ",
+    "ٿائي",
+    "تگرينيائي",
+    "تبيتائي",
+    "ترڪمين",
+    "",
+    "تسوانا",
+    "تونگن",
+    "ترڪي",
+    "سونگا",
+    "تاتار",
+    "",
+    "تاهيتي",
+    "يوغور",
+    "يوڪراني",
+    "اردو",
+    "ازبڪ",
+    "وينڊا",
+    "ويتنامي",
+    "والپڪ",
+    "ولون",
+    "ويلش",
+    "وولوف",
+    "مغربي فريشن",
+    "زھوسا",
+    "يدش",
+    "يوروبا",
+    "جوئنگ",
+    "زولو",
+];
+
+static SR: [&str; 184] = [
+    "абхаски",
+    "афарски",
+    "африканс",
+    "акански",
+    "албански",
+    "амхарски",
+    "арапски",
+    "арагонски",
+    "јерменски",
+    "асамски",
+    "аварски",
+    "авестански",
+    "ајмара",
+    "азербејџански",
+    "бамбара",
+    "башкирски",
+    "баскијски",
+    "белоруски",
+    "бенгалски",
+    "",
+    "бислама",
+    "босански",
+    "бретонски",
+    "бугарски",
+    "бурмански",
+    "каталонски",
+    "чаморо",
+    "чеченски",
+    "њанџа",
+    "кинески",
+    "чувашки",
+    "корнволски",
+    "корзикански",
+    "кри",
+    "хрватски",
+    "чешки",
+    "дански",
+    "малдивски",
+    "холандски",
+    "џонга",
+    "енглески",
+    "есперанто",
+    "естонски",
+    "еве",
+    "фарски",
+    "фиџијски",
+    "фински",
+    "француски",
+    "фула",
+    "галицијски",
+    "грузијски",
+    "немачки",
+    "грчки",
+    "гварани",
+    "гуџарати",
+    "хаићански",
+    "хауса",
+    "хебрејски",
+    "хереро",
+    "хинди",
+    "хири моту",
+    "мађарски",
+    "интерлингва",
+    "индонежански",
+    "интерлингве",
+    "ирски",
+    "игбо",
+    "инупик",
+    "идо",
+    "исландски",
+    "италијански",
+    "инуктитутски",
+    "јапански",
+    "јавански",
+    "гренландски",
+    "канада",
+    "канури",
+    "кашмирски",
+    "казашки",
+    "кмерски",
+    "кикују",
+    "кињаруанда",
+    "киргиски",
+    "коми",
+    "конго",
+    "корејски",
+    "курдски",
+    "квањама",
+    "латински",
+    "луксембуршки",
+    "ганда",
+    "лимбуршки",
+    "лингала",
+    "лаоски",
+    "литвански",
+    "луба-катанга",
+    "летонски",
+    "манкс",
+    "македонски",
+    "малгашки",
+    "малајски",
+    "малајалам",
+    "малтешки",
+    "маорски",
+    "марати",
+    "маршалски",
+    "монголски",
+    "науруски",
+    "навахо",
+    "северни ндебеле",
+    "непалски",
+    "ндонга",
+    "норвешки букмол",
+    "норвешки нинорск",
+    "норвешки",
+    "сечуански ји",
+    "јужни ндебеле",
+    "окситански",
+    "оџибве",
+    "црквенословенски",
+    "оромо",
+    "одија",
+    "осетински",
+    "пенџапски",
+    "пали",
+    "персијски",
+    "пољски",
+    "паштунски",
+    "португалски",
+    "кечуа",
+    "романш",
+    "кирунди",
+    "румунски",
+    "руски",
+    "санскрит",
+    "сардински",
+    "синди",
+    "северни сами",
+    "самоански",
+    "санго",
+    "српски",
+    "шкотски гелски",
+    "шона",
+    "синхалешки",
+    "словачки",
+    "словеначки",
+    "сомалски",
+    "сесото",
+    "шпански",
+    "сундски",
+    "свахили",
+    "свази",
+    "шведски",
+    "тамилски",
+    "телугу",
+    "таџички",
+    "тајски",
+    "тигриња",
+    "тибетански",
+    "туркменски",
+    "тагалог",
+    "цвана",
+    "тонгански",
+    "турски",
+    "цонга",
+    "татарски",
+    "тви",
+    "тахићански",
+    "ујгурски",
+    "украјински",
+    "урду",
+    "узбечки",
+    "венда",
+    "вијетнамски",
+    "волапик",
+    "валонски",
+    "велшки",
+    "волоф",
+    "западни фризијски",
+    "коса",
+    "јидиш",
+    "јоруба",
+    "џуаншки",
+    "зулу",
+];
+
+static GD: [&str; 184] = [
+    "Abchasais",
+    "Afar",
+    "Afraganais",
+    "Akan",
+    "Albàinis",
+    "Amtharais",
+    "Arabais",
+    "Aragonais",
+    "Airmeinis",
+    "Asamais",
+    "Avarais",
+    "Avestanais",
+    "Aymara",
+    "Asarbaideànais",
+    "Bambara",
+    "Bashkir",
+    "Basgais",
+    "Bealaruisis",
+    "Bangla",
+    "",
+    "Bislama",
+    "Bosnais",
+    "Breatnais",
+    "Bulgarais",
+    "Burmais",
+    "Catalanais",
+    "Chamorro",
+    "Deideanais",
+    "Nyanja",
+    "Sìnis",
+    "Chuvash",
+    "Còrnais",
+    "Corsais",
+    "Cree",
+    "Cròthaisis",
+    "Seicis",
+    "Danmhairgis",
+    "Divehi",
+    "Duitsis",
+    "Dzongkha",
+    "Beurla",
+    "Esperanto",
+    "Eastoinis",
+    "Ewe",
+    "Fàrothais",
+    "Fìdis",
+    "Fionnlannais",
+    "Fraingis",
+    "Fulah",
+    "Gailìsis",
+    "Cairtbheilis",
+    "Gearmailtis",
+    "Greugais",
+    "Guaraní",
+    "Gujarati",
+    "Crìtheol Haidhti",
+    "Hausa",
+    "Eabhra",
+    "Herero",
+    "Hindis",
+    "Hiri Motu",
+    "Ungairis",
+    "Interlingua",
+    "Innd-Innsis",
+    "Interlingue",
+    "Gaeilge",
+    "Igbo",
+    "Inupiaq",
+    "Ido",
+    "Innis Tìlis",
+    "Eadailtis",
+    "Inuktitut",
+    "Seapanais",
+    "Deàbhanais",
+    "Kalaallisut",
+    "Kannada",
+    "Kanuri",
+    "Caismiris",
+    "Casachais",
+    "Cmèar",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Cìorgasais",
+    "Komi",
+    "Kongo",
+    "Coirèanais",
+    "Cùrdais",
+    "Kuanyama",
+    "Laideann",
+    "Lugsamburgais",
+    "Ganda",
+    "Cànan Limburg",
+    "Lingala",
+    "Làtho",
+    "Liotuainis",
+    "Luba-Katanga",
+    "Laitbheis",
+    "Gaelg",
+    "Masadonais",
+    "Malagasais",
+    "Malaidhis",
+    "Malayalam",
+    "Maltais",
+    "Māori",
+    "Marathi",
+    "Marshallais",
+    "Mongolais",
+    "Nabhru",
+    "Navajo",
+    "Ndebele Thuathach",
+    "Neapàlais",
+    "Ndonga",
+    "Bokmål na Nirribhidh",
+    "Nynorsk na Nirribhidh",
+    "Nirribhis",
+    "Yi Sichuan",
+    "Ndebele Dheasach",
+    "Ogsatanais",
+    "Ojibwa",
+    "Slàbhais na h-Eaglaise",
+    "Oromo",
+    "Odia",
+    "Ossetic",
+    "Panjabi",
+    "Pali",
+    "Peirsis",
+    "Pòlainnis",
+    "Pashto",
+    "Portagailis",
+    "Quechua",
+    "Rumains",
+    "Kirundi",
+    "Romàinis",
+    "Ruisis",
+    "Sanskrit",
+    "Sàrdais",
+    "Sindhi",
+    "Sàmais Thuathach",
+    "Samothais",
+    "Sango",
+    "Sèirbis",
+    "Gàidhlig",
+    "Shona",
+    "Sinhala",
+    "Slòbhacais",
+    "Slòbhainis",
+    "Somàilis",
+    "Sesotho",
+    "Spàinntis",
+    "Cànan Sunda",
+    "Kiswahili",
+    "Swati",
+    "Suainis",
+    "Taimilis",
+    "Telugu",
+    "Taidigis",
+    "Cànan nan Tàidh",
+    "Tigrinya",
+    "Tibeitis",
+    "Turcmanais",
+    "Tagalog",
+    "Tswana",
+    "Tonga",
+    "Turcais",
+    "Tsonga",
+    "Tatarais",
+    "Twi",
+    "Cànan Tahiti",
+    "Ùigiurais",
+    "Ucràinis",
+    "Ùrdu",
+    "Usbagais",
+    "Venda",
+    "Bhiet-Namais",
+    "Volapük",
+    "Walloon",
+    "Cuimris",
+    "Wolof",
+    "Frìoslannais Shiarach",
+    "Xhosa",
+    "Iùdhais",
+    "Yoruba",
+    "Zhuang",
+    "Zulu",
+];
+
+static SI: [&str; 184] = [
+    "ඇබ්කාසියානු",
+    "අෆාර්",
+    "අෆ්\u{200D}රිකාන්ස්",
+    "අකාන්",
+    "ඇල්බේනියානු",
+    "ඇම්හාරික්",
+    "අරාබි",
+    "ඇරගොනීස්",
+    "ආර්මේනියානු",
+    "ඇසෑම්",
+    "ඇවරික්",
+    "",
+    "අයිමරා",
+    "අසර්බයිජාන්",
+    "බම්බරා",
+    "බාෂ්කිර්",
+    "බාස්ක්",
+    "බෙලරුසියානු",
+    "බෙංගාලි",
+    "",
+    "බිස්ලමා",
+    "බොස්නියානු",
+    "බ්\u{200D}රේටොන්",
+    "බල්ගේරියානු",
+    "බුරුම",
+    "කැටලන්",
+    "චමොරො",
+    "චෙච්නියානු",
+    "න්යන්ජා",
+    "චීන",
+    "චවේෂ්",
+    "කෝනීසියානු",
+    "කෝසිකානු",
+    "",
+    "කෝඒෂියානු",
+    "චෙක්",
+    "ඩැනිශ්",
+    "ඩිවෙහි",
+    "ලන්දේසි",
+    "ඩිසොන්කා",
+    "ඉංග්\u{200D}රීසි",
+    "එස්පැරන්ටෝ",
+    "එස්තෝනියානු",
+    "ඉව්",
+    "ෆාරෝස්",
+    "ෆීජි",
+    "ෆින්ලන්ත",
+    "ප්\u{200D}රංශ",
+    "ෆුලාහ්",
+    "ගැලීසියානු",
+    "ජෝර්ජියානු",
+    "ජර්මන්",
+    "ග්\u{200D}රීක",
+    "ගුවාරනි",
+    "ගුජරාටි",
+    "හයිටි",
+    "හෝසා",
+    "හීබෲ",
+    "හෙරෙරො",
+    "හින්දි",
+    "",
+    "හන්ගේරියානු",
+    "ඉන්ටලින්ගුආ",
+    "ඉන්දුනීසියානු",
+    "ඉන්ටර්ලින්ග්",
+    "අයර්ලන්ත",
+    "ඉග්බෝ",
+    "",
+    "ඉඩො",
+    "අයිස්ලන්ත",
+    "ඉතාලි",
+    "ඉනුක්ටිටුට්",
+    "ජපන්",
+    "ජාවා",
+    "කලාලිසට්",
+    "කණ්ණඩ",
+    "කනුරි",
+    "කාෂ්මීර්",
+    "කසාඛ්",
+    "කමර්",
+    "කිකුයු",
+    "කින්යර්වන්ඩා",
+    "කිර්ගිස්",
+    "කොමි",
+    "",
+    "කොරියානු",
+    "කුර්දි",
+    "කුයන්යමා",
+    "ලතින්",
+    "ලක්සැම්බර්ග්",
+    "ගන්ඩා",
+    "ලිම්බර්ගිශ්",
+    "ලින්ගලා",
+    "ලාඕ",
+    "ලිතුවේනියානු",
+    "ලුබා-කටන්ගා",
+    "ලැට්වියානු",
+    "මැන්ක්ස්",
+    "මැසිඩෝනියානු",
+    "මලගාසි",
+    "මැලේ",
+    "මලයාලම්",
+    "මොල්ටිස්",
+    "මාවොරි",
+    "මරාති",
+    "මාශලීස්",
+    "මොංගෝලියානු",
+    "නෞරු",
+    "නවාජො",
+    "උතුරු එන්ඩිබෙලෙ",
+    "නේපාල",
+    "න්ඩොන්ගා",
+    "නෝර්වීජියානු බොක්මල්",
+    "නෝර්වීජියානු නයිනෝර්ස්ක්",
+    "නෝර්වීජියානු",
+    "සිචුආන් යී",
+    "සෞත් ඩ්බේල්",
+    "ඔසිටාන්",
+    "",
+    "චර්ච් ස්ලැවික්",
+    "ඔරොමෝ",
+    "ඔඩියා",
+    "ඔසිටෙක්",
+    "පන්ජාබි",
+    "",
+    "පර්සියානු",
+    "පෝලන්ත",
+    "පෂ්ටො",
+    "පෘතුගීසි",
+    "ක්වීචුවා",
+    "රොමෑන්ශ්",
+    "රුන්ඩි",
+    "රොමේනියානු",
+    "රුසියානු",
+    "සංස්කෘත",
+    "සාර්ඩිනිඅන්",
+    "සින්ධි",
+    "උතුරු සාමි",
+    "සෑමොඅන්",
+    "සන්ග්\u{200D}රෝ",
+    "සර්බියානු",
+    "ස්කොට්ටිශ් ගෙලික්",
+    "ශෝනා",
+    "සිංහල",
+    "ස්ලෝවැක්",
+    "ස්ලෝවේනියානු",
+    "සෝමාලි",
+    "සතර්න් සොතො",
+    "ස්පාඤ්ඤ",
+    "සන්ඩනීසියානු",
+    "ස්වාහිලි",
+    "ස්වති",
+    "ස්වීඩන්",
+    "දෙමළ",
+    "තෙළිඟු",
+    "ටජික්",
+    "තායි",
+    "ටිග්\u{200D}රින්යා",
+    "ටිබෙට්",
+    "ටර්ක්මෙන්",
+    "",
+    "ස්වනා",
+    "ටොංගා",
+    "තුර්කි",
+    "සොන්ග",
+    "ටාටර්",
+    "",
+    "ටහිටියන්",
+    "උයිගර්",
+    "යුක්රේනියානු",
+    "උර්දු",
+    "උස්බෙක්",
+    "වෙන්ඩා",
+    "වියට්නාම්",
+    "වොලපූක්",
+    "වෑලූන්",
+    "වෙල්ෂ්",
+    "වොලොෆ්",
+    "බටහිර ෆ්\u{200D}රිසියානු",
+    "ශෝසා",
+    "යිඩිශ්",
+    "යොරූබා",
+    "ෂුවාං",
+    "සුලු",
+];
+
+static SK: [&str; 184] = [
+    "abcházčina",
+    "afarčina",
+    "afrikánčina",
+    "akančina",
+    "albánčina",
+    "amharčina",
+    "arabčina",
+    "aragónčina",
+    "arménčina",
+    "ásamčina",
+    "avarčina",
+    "avestčina",
+    "aymarčina",
+    "azerbajdžančina",
+    "bambarčina",
+    "baškirčina",
+    "baskičtina",
+    "bieloruština",
+    "bengálčina",
+    "",
+    "bislama",
+    "bosniačtina",
+    "bretónčina",
+    "bulharčina",
+    "barmčina",
+    "katalánčina",
+    "čamorčina",
+    "čečenčina",
+    "ňandža",
+    "čínština",
+    "čuvaština",
+    "kornčina",
+    "korzičtina",
+    "krí",
+    "chorvátčina",
+    "čeština",
+    "dánčina",
+    "maldivčina",
+    "holandčina",
+    "dzongkha",
+    "angličtina",
+    "esperanto",
+    "estónčina",
+    "eweština",
+    "faerčina",
+    "fidžijčina",
+    "fínčina",
+    "francúzština",
+    "fulbčina",
+    "galícijčina",
+    "gruzínčina",
+    "nemčina",
+    "gréčtina",
+    "guaraníjčina",
+    "gudžarátčina",
+    "haitská kreolčina",
+    "hauština",
+    "hebrejčina",
+    "herero",
+    "hindčina",
+    "hiri motu",
+    "maďarčina",
+    "interlingua",
+    "indonézština",
+    "interlingue",
+    "írčina",
+    "igboština",
+    "inupik",
+    "ido",
+    "islandčina",
+    "taliančina",
+    "inuktitut",
+    "japončina",
+    "jávčina",
+    "grónčina",
+    "kannadčina",
+    "kanurijčina",
+    "kašmírčina",
+    "kazaština",
+    "khmérčina",
+    "kikujčina",
+    "rwandčina",
+    "kirgizština",
+    "komijčina",
+    "kongčina",
+    "kórejčina",
+    "kurdčina",
+    "kuaňama",
+    "latinčina",
+    "luxemburčina",
+    "gandčina",
+    "limburčina",
+    "lingalčina",
+    "laoština",
+    "litovčina",
+    "lubčina (katanžská)",
+    "lotyština",
+    "mančina",
+    "macedónčina",
+    "malgaština",
+    "malajčina",
+    "malajálamčina",
+    "maltčina",
+    "maorijčina",
+    "maráthčina",
+    "marshallčina",
+    "mongolčina",
+    "nauruština",
+    "navaho",
+    "ndebelčina (severná)",
+    "nepálčina",
+    "ndonga",
+    "nórčina (bokmal)",
+    "nórčina (nynorsk)",
+    "nórčina",
+    "s’čchuanská iovčina",
+    "ndebelčina (južná)",
+    "okcitánčina",
+    "odžibva",
+    "cirkevná slovančina",
+    "oromčina",
+    "uríjčina",
+    "osetčina",
+    "pandžábčina",
+    "pálí",
+    "perzština",
+    "poľština",
+    "paštčina",
+    "portugalčina",
+    "kečuánčina",
+    "rétorománčina",
+    "rundčina",
+    "rumunčina",
+    "ruština",
+    "sanskrit",
+    "sardínčina",
+    "sindhčina",
+    "saamčina (severná)",
+    "samojčina",
+    "sango",
+    "srbčina",
+    "škótska gaelčina",
+    "šončina",
+    "sinhalčina",
+    "slovenčina",
+    "slovinčina",
+    "somálčina",
+    "sothčina (južná)",
+    "španielčina",
+    "sundčina",
+    "swahilčina",
+    "svazijčina",
+    "švédčina",
+    "tamilčina",
+    "telugčina",
+    "tadžičtina",
+    "thajčina",
+    "tigriňa",
+    "tibetčina",
+    "turkménčina",
+    "tagalčina",
+    "tswančina",
+    "tongčina",
+    "turečtina",
+    "tsongčina",
+    "tatárčina",
+    "twi",
+    "tahitčina",
+    "ujgurčina",
+    "ukrajinčina",
+    "urdčina",
+    "uzbečtina",
+    "vendčina",
+    "vietnamčina",
+    "volapük",
+    "valónčina",
+    "waleština",
+    "wolofčina",
+    "západná frízština",
+    "xhoština",
+    "jidiš",
+    "jorubčina",
+    "čuangčina",
+    "zuluština",
+];
+
+static SL: [&str; 184] = [
+    "abhaščina",
+    "afarščina",
+    "afrikanščina",
+    "akanščina",
+    "albanščina",
+    "amharščina",
+    "arabščina",
+    "aragonščina",
+    "armenščina",
+    "asamščina",
+    "avarščina",
+    "avestijščina",
+    "ajmarščina",
+    "azerbajdžanščina",
+    "bambarščina",
+    "baškirščina",
+    "baskovščina",
+    "beloruščina",
+    "bengalščina",
+    "",
+    "bislamščina",
+    "bosanščina",
+    "bretonščina",
+    "bolgarščina",
+    "burmanščina",
+    "katalonščina",
+    "čamorščina",
+    "čečenščina",
+    "njanščina",
+    "kitajščina",
+    "čuvaščina",
+    "kornijščina",
+    "korziščina",
+    "krijščina",
+    "hrvaščina",
+    "češčina",
+    "danščina",
+    "diveščina",
+    "nizozemščina",
+    "dzonka",
+    "angleščina",
+    "esperanto",
+    "estonščina",
+    "evenščina",
+    "ferščina",
+    "fidžijščina",
+    "finščina",
+    "francoščina",
+    "fulščina",
+    "galicijščina",
+    "gruzijščina",
+    "nemščina",
+    "grščina",
+    "gvaranijščina",
+    "gudžaratščina",
+    "haitijska kreolščina",
+    "havščina",
+    "hebrejščina",
+    "herero",
+    "hindijščina",
+    "hiri motu",
+    "madžarščina",
+    "interlingva",
+    "indonezijščina",
+    "interlingve",
+    "irščina",
+    "igboščina",
+    "inupiaščina",
+    "ido",
+    "islandščina",
+    "italijanščina",
+    "inuktitutščina",
+    "japonščina",
+    "javanščina",
+    "grenlandščina",
+    "kanareščina",
+    "kanurščina",
+    "kašmirščina",
+    "kazaščina",
+    "kmerščina",
+    "kikujščina",
+    "ruandščina",
+    "kirgiščina",
+    "komijščina",
+    "kongovščina",
+    "korejščina",
+    "kurdščina",
+    "kvanjama",
+    "latinščina",
+    "luksemburščina",
+    "ganda",
+    "limburščina",
+    "lingala",
+    "laoščina",
+    "litovščina",
+    "luba-katanga",
+    "latvijščina",
+    "manščina",
+    "makedonščina",
+    "malgaščina",
+    "malajščina",
+    "malajalamščina",
+    "malteščina",
+    "maorščina",
+    "maratščina",
+    "marshallovščina",
+    "mongolščina",
+    "naurujščina",
+    "navajščina",
+    "severna ndebelščina",
+    "nepalščina",
+    "ndonga",
+    "knjižna norveščina",
+    "novonorveščina",
+    "norveščina",
+    "sečuanska jiščina",
+    "južna ndebelščina",
+    "okcitanščina",
+    "anašinabščina",
+    "stara cerkvena slovanščina",
+    "oromo",
+    "odijščina",
+    "osetinščina",
+    "pandžabščina",
+    "palijščina",
+    "perzijščina",
+    "poljščina",
+    "paštunščina",
+    "portugalščina",
+    "kečuanščina",
+    "retoromanščina",
+    "rundščina",
+    "romunščina",
+    "ruščina",
+    "sanskrt",
+    "sardinščina",
+    "sindščina",
+    "severna samijščina",
+    "samoanščina",
+    "sango",
+    "srbščina",
+    "škotska gelščina",
+    "šonščina",
+    "sinhalščina",
+    "slovaščina",
+    "slovenščina",
+    "somalščina",
+    "sesoto",
+    "španščina",
+    "sundanščina",
+    "svahili",
+    "svazijščina",
+    "švedščina",
+    "tamilščina",
+    "telugijščina",
+    "tadžiščina",
+    "tajščina",
+    "tigrajščina",
+    "tibetanščina",
+    "turkmenščina",
+    "tagalogščina",
+    "cvanščina",
+    "tongščina",
+    "turščina",
+    "congščina",
+    "tatarščina",
+    "tvi",
+    "tahitščina",
+    "ujgurščina",
+    "ukrajinščina",
+    "urdujščina",
+    "uzbeščina",
+    "venda",
+    "vietnamščina",
+    "volapik",
+    "valonščina",
+    "valižanščina",
+    "volofščina",
+    "zahodna frizijščina",
+    "koščina",
+    "jidiš",
+    "jorubščina",
+    "džuangščina",
+    "zulujščina",
+];
+
+static SO: [&str; 184] = [
+    "U dhashay Abkhazia",
+    "",
+    "Afrikaanka",
+    "Akan",
+    "Albeeniyaan",
+    "Axmaar",
+    "Carabi",
+    "U dhashay Aragon",
+    "Armeeniyaan",
+    "Asaamiis",
+    "U dhashay Avar",
+    "",
+    "U dhashay Aymar",
+    "Asarbayjan",
+    "Bambaara",
+    "Bashkir",
+    "Basquu",
+    "Beleruusiyaan",
+    "Bangladesh",
+    "",
+    "U dhashay Bislam",
+    "Bosniyaan",
+    "Biriton",
+    "Bulgeeriyaan",
+    "Burmese",
+    "Katalaan",
+    "Chamorro",
+    "Jejen",
+    "Inyaanja",
+    "Shinees",
+    "Chuvash",
+    "Kornish",
+    "Korsikan",
+    "",
+    "Koro’eeshiyaan",
+    "Jeeg",
+    "Dhaanish",
+    "Divehi",
+    "Holandays",
+    "D’zongqa",
+    "Ingiriisi",
+    "Isberaanto",
+    "Istooniyaan",
+    "Eewe",
+    "Farowsi",
+    "Fijian",
+    "Finishka",
+    "Faransiis",
+    "Fuulah",
+    "Galiisiyaan",
+    "Joorijiyaan",
+    "Jarmal",
+    "Giriik",
+    "Guarani",
+    "Gujaraati",
+    "Heeytiyaan Karawle",
+    "Hawsa",
+    "Cibraani",
+    "Herero",
+    "Hindi",
+    "",
+    "Hangariyaan",
+    "Interlinguwa",
+    "Indunusiyaan",
+    "Interlingue",
+    "Ayrish",
+    "Igbo",
+    "",
+    "Ido",
+    "Ayslandays",
+    "Talyaani",
+    "Inuktitut",
+    "Jabaaniis",
+    "Jafaaniis",
+    "Kalaallisuut",
+    "Kannadays",
+    "Kanuri",
+    "Kaashmiir",
+    "Kasaaq",
+    "Kamboodhian",
+    "Kikuuyu",
+    "Ruwaandha",
+    "Kirgiis",
+    "Komi",
+    "",
+    "Kuuriyaan",
+    "Kurdishka",
+    "Kuanyama",
+    "Laatiin",
+    "Luksaamboorgish",
+    "Gandha",
+    "Limburgish",
+    "Lingala",
+    "Lao",
+    "Lituwaanays",
+    "Luuba-kataanga",
+    "Laatfiyaan",
+    "Mankis",
+    "Masadooniyaan",
+    "Malagaasi",
+    "Malaay",
+    "Malayalam",
+    "Maltiis",
+    "Maaoori",
+    "Maarati",
+    "Marshallese",
+    "Mangooli",
+    "Nauru",
+    "Navajo",
+    "Indhebeele Waqooyi",
+    "Nebaali",
+    "Ndonga",
+    "Nawrijii Bokmål",
+    "Nawriijiga Nynorsk",
+    "Nawriiji",
+    "Sijuwan Yi",
+    "South Ndebele",
+    "Occitan",
+    "",
+    "Kaniisadda Islaafik",
+    "Oromo",
+    "Oodhiya",
+    "Oseetic",
+    "Bunjaabi",
+    "",
+    "Faarisi",
+    "Boolish",
+    "Bashtuu",
+    "Boortaqiis",
+    "Quechua",
+    "Romaanis",
+    "Rundhi",
+    "Romanka",
+    "Ruush",
+    "Sanskrit",
+    "Sardinian",
+    "Siindhi",
+    "Sami Waqooyi",
+    "Samowan",
+    "Sango",
+    "Seerbiyaan",
+    "Iskot Giilik",
+    "Shoona",
+    "Sinhala",
+    "Isloofaak",
+    "Islofeeniyaan",
+    "Soomaali",
+    "Sesooto",
+    "Isbaanish",
+    "Suudaaniis",
+    "Sawaaxili",
+    "Swati",
+    "Iswiidhish",
+    "Tamiil",
+    "Teluugu",
+    "Taajik",
+    "Taaylandays",
+    "Tigrinya",
+    "Tibeetaan",
+    "Turkumaanish",
+    "",
+    "Tswana",
+    "Toongan",
+    "Turkish",
+    "Tsonga",
+    "Taatar",
+    "",
+    "Tahitian",
+    "Uighur",
+    "Yukreeniyaan",
+    "Urduu",
+    "Usbakis",
+    "Venda",
+    "Fiitnaamays",
+    "Folabuuk",
+    "Walloon",
+    "Welsh",
+    "Woolof",
+    "Firiisiyan Galbeed",
+    "Xhosa",
+    "Yadhish",
+    "Yoruuba",
+    "Zhuang",
+    "Zuulu",
+];
+
+static ES: [&str; 184] = [
+    "abjasio",
+    "afar",
+    "afrikáans",
+    "akan",
+    "albanés",
+    "amárico",
+    "árabe",
+    "aragonés",
+    "armenio",
+    "asamés",
+    "avar",
+    "avéstico",
+    "aimara",
+    "azerbaiyano",
+    "bambara",
+    "baskir",
+    "euskera",
+    "bielorruso",
+    "bengalí",
+    "",
+    "bislama",
+    "bosnio",
+    "bretón",
+    "búlgaro",
+    "birmano",
+    "catalán",
+    "chamorro",
+    "checheno",
+    "nyanja",
+    "chino",
+    "chuvasio",
+    "córnico",
+    "corso",
+    "cree",
+    "croata",
+    "checo",
+    "danés",
+    "divehi",
+    "neerlandés",
+    "dzongkha",
+    "inglés",
+    "esperanto",
+    "estonio",
+    "ewé",
+    "feroés",
+    "fiyiano",
+    "finés",
+    "francés",
+    "fula",
+    "gallego",
+    "georgiano",
+    "alemán",
+    "griego",
+    "guaraní",
+    "guyaratí",
+    "criollo haitiano",
+    "hausa",
+    "hebreo",
+    "herero",
+    "hindi",
+    "hiri motu",
+    "húngaro",
+    "interlingua",
+    "indonesio",
+    "interlingue",
+    "irlandés",
+    "igbo",
+    "inupiaq",
+    "ido",
+    "islandés",
+    "italiano",
+    "inuktitut",
+    "japonés",
+    "javanés",
+    "groenlandés",
+    "canarés",
+    "kanuri",
+    "cachemir",
+    "kazajo",
+    "jemer",
+    "kikuyu",
+    "kinyarwanda",
+    "kirguís",
+    "komi",
+    "kongo",
+    "coreano",
+    "kurdo",
+    "kuanyama",
+    "latín",
+    "luxemburgués",
+    "ganda",
+    "limburgués",
+    "lingala",
+    "lao",
+    "lituano",
+    "luba-katanga",
+    "letón",
+    "manés",
+    "macedonio",
+    "malgache",
+    "malayo",
+    "malayálam",
+    "maltés",
+    "maorí",
+    "maratí",
+    "marshalés",
+    "mongol",
+    "nauruano",
+    "navajo",
+    "ndebele septentrional",
+    "nepalí",
+    "ndonga",
+    "noruego bokmal",
+    "noruego nynorsk",
+    "noruego",
+    "yi de Sichuán",
+    "ndebele meridional",
+    "occitano",
+    "ojibwa",
+    "eslavo eclesiástico",
+    "oromo",
+    "oriya",
+    "osético",
+    "punyabí",
+    "pali",
+    "persa",
+    "polaco",
+    "pastún",
+    "portugués",
+    "quechua",
+    "romanche",
+    "kirundi",
+    "rumano",
+    "ruso",
+    "sánscrito",
+    "sardo",
+    "sindi",
+    "sami septentrional",
+    "samoano",
+    "sango",
+    "serbio",
+    "gaélico escocés",
+    "shona",
+    "cingalés",
+    "eslovaco",
+    "esloveno",
+    "somalí",
+    "sotho meridional",
+    "español",
+    "sundanés",
+    "suajili",
+    "suazi",
+    "sueco",
+    "tamil",
+    "telugu",
+    "tayiko",
+    "tailandés",
+    "tigriña",
+    "tibetano",
+    "turcomano",
+    "tagalo",
+    "setsuana",
+    "tongano",
+    "turco",
+    "tsonga",
+    "tártaro",
+    "twi",
+    "tahitiano",
+    "uigur",
+    "ucraniano",
+    "urdu",
+    "uzbeko",
+    "venda",
+    "vietnamita",
+    "volapük",
+    "valón",
+    "galés",
+    "wólof",
+    "frisón occidental",
+    "xhosa",
+    "yidis",
+    "yoruba",
+    "zhuang",
+    "zulú",
+];
+
+static SW: [&str; 184] = [
+    "Kiabkhazi",
+    "Kiafar",
+    "Kiafrikana",
+    "Kiakani",
+    "Kialbania",
+    "Kiamhari",
+    "Kiarabu",
+    "Kiaragoni",
+    "Kiarmenia",
+    "Kiassam",
+    "Kiavari",
+    "",
+    "Kiaymara",
+    "Kiazerbaijani",
+    "Kibambara",
+    "Kibashkiri",
+    "Kibaski",
+    "Kibelarusi",
+    "Kibengali",
+    "",
+    "Kibislama",
+    "Kibosnia",
+    "Kibretoni",
+    "Kibulgaria",
+    "Kiburma",
+    "Kikatalani",
+    "Kichamorro",
+    "Kichechenia",
+    "Kinyanja",
+    "Kichina",
+    "Kichuvash",
+    "Kikorni",
+    "Kikosikani",
+    "",
+    "Kikorasia",
+    "Kicheki",
+    "Kidenmaki",
+    "Kidivehi",
+    "Kiholanzi",
+    "Kizongkha",
+    "Kiingereza",
+    "Kiesperanto",
+    "Kiestonia",
+    "Kiewe",
+    "Kifaroe",
+    "Kifiji",
+    "Kifini",
+    "Kifaransa",
+    "Kifulani",
+    "Kigalisi",
+    "Kijojia",
+    "Kijerumani",
+    "Kigiriki",
+    "Kiguarani",
+    "Kigujarati",
+    "Kihaiti",
+    "Kihausa",
+    "Kiebrania",
+    "Kiherero",
+    "Kihindi",
+    "",
+    "Kihungaria",
+    "Kiintalingua",
+    "Kiindonesia",
+    "lugha ya kisayansi",
+    "Kiayalandi",
+    "Kiigbo",
+    "",
+    "Kiido",
+    "Kiisilandi",
+    "Kiitaliano",
+    "Kiinuktituti",
+    "Kijapani",
+    "Kijava",
+    "Kikalaallisut",
+    "Kikannada",
+    "Kikanuri",
+    "Kikashmiri",
+    "Kikazakh",
+    "Kikambodia",
+    "Kikikuyu",
+    "Kinyarwanda",
+    "Kikyrgyz",
+    "Kikomi",
+    "Kikongo",
+    "Kikorea",
+    "Kikurdi",
+    "Kikwanyama",
+    "Kilatini",
+    "Kilasembagi",
+    "Kiganda",
+    "Limburgish",
+    "Kilingala",
+    "Kilaosi",
+    "Kilithuania",
+    "Kiluba-Katanga",
+    "Kilatvia",
+    "Kimanx",
+    "Kimacedonia",
+    "Kimalagasi",
+    "Kimalei",
+    "Kimalayalamu",
+    "Kimalta",
+    "Kimaori",
+    "Kimarathi",
+    "Kimashale",
+    "Kimongolia",
+    "Kinauru",
+    "Kinavajo",
+    "Kindebele cha Kaskazini",
+    "Kinepali",
+    "Kindonga",
+    "Kinorwe cha Bokmal",
+    "Kinorwe cha Nynorsk",
+    "Kinorwe",
+    "Kiyi cha Sichuan",
+    "Kindebele",
+    "Kiokitani",
+    "",
+    "Kislovakia cha Kanisa",
+    "Kioromo",
+    "Kioriya",
+    "Kiosetia",
+    "Kipunjabi",
+    "",
+    "Kiajemi",
+    "Kipolandi",
+    "Kipashto",
+    "Kireno",
+    "Kikechua",
+    "Kiromanshi",
+    "Kirundi",
+    "Kiromania",
+    "Kirusi",
+    "Kisanskriti",
+    "Kisardinia",
+    "Kisindhi",
+    "Kisami cha Kaskazini",
+    "Kisamoa",
+    "Kisango",
+    "Kiserbia",
+    "Kigaeli cha Uskoti",
+    "Kishona",
+    "Kisinhala",
+    "Kislovakia",
+    "Kislovenia",
+    "Kisomali",
+    "Kisotho",
+    "Kihispania",
+    "Kisunda",
+    "Kiswahili",
+    "Kiswati",
+    "Kiswidi",
+    "Kitamili",
+    "Kitelugu",
+    "Kitajiki",
+    "Kithai",
+    "Kitigrinya",
+    "Kitibeti",
+    "Kiturukimeni",
+    "",
+    "Kitswana",
+    "Kitonga",
+    "Kituruki",
+    "Kitsonga",
+    "Kitatari",
+    "Twi",
+    "Kitahiti",
+    "Kiuyghur",
+    "Kiukraini",
+    "Kiurdu",
+    "Kiuzbeki",
+    "Kivenda",
+    "Kivietinamu",
+    "Kivolapuk",
+    "Kiwaloon",
+    "Kiwelisi",
+    "Kiwolofu",
+    "Kifrisia cha Magharibi",
+    "Kixhosa",
+    "Kiyiddi",
+    "Kiyoruba",
+    "Kizhuang",
+    "Kizulu",
+];
+
+static SV: [&str; 184] = [
+    "abchaziska",
+    "afar",
+    "afrikaans",
+    "akan",
+    "albanska",
+    "amhariska",
+    "arabiska",
+    "aragonesiska",
+    "armeniska",
+    "assamesiska",
+    "avariska",
+    "avestiska",
+    "aymara",
+    "azerbajdzjanska",
+    "bambara",
+    "basjkiriska",
+    "baskiska",
+    "belarusiska",
+    "bengali",
+    "",
+    "bislama",
+    "bosniska",
+    "bretonska",
+    "bulgariska",
+    "burmesiska",
+    "katalanska",
+    "chamorro",
+    "tjetjenska",
+    "nyanja",
+    "kinesiska",
+    "tjuvasjiska",
+    "korniska",
+    "korsikanska",
+    "cree",
+    "kroatiska",
+    "tjeckiska",
+    "danska",
+    "divehi",
+    "nederländska",
+    "dzongkha",
+    "engelska",
+    "esperanto",
+    "estniska",
+    "ewe",
+    "färöiska",
+    "fijianska",
+    "finska",
+    "franska",
+    "fulani",
+    "galiciska",
+    "georgiska",
+    "tyska",
+    "grekiska",
+    "guaraní",
+    "gujarati",
+    "haitiska",
+    "hausa",
+    "hebreiska",
+    "herero",
+    "hindi",
+    "hirimotu",
+    "ungerska",
+    "interlingua",
+    "indonesiska",
+    "interlingue",
+    "iriska",
+    "igbo",
+    "inupiak",
+    "ido",
+    "isländska",
+    "italienska",
+    "inuktitut",
+    "japanska",
+    "javanesiska",
+    "grönländska",
+    "kannada",
+    "kanuri",
+    "kashmiriska",
+    "kazakiska",
+    "kambodjanska",
+    "kikuyu",
+    "kinjarwanda",
+    "kirgiziska",
+    "kome",
+    "kikongo",
+    "koreanska",
+    "kurdiska",
+    "kuanyama",
+    "latin",
+    "luxemburgiska",
+    "luganda",
+    "limburgiska",
+    "lingala",
+    "laotiska",
+    "litauiska",
+    "luba-katanga",
+    "lettiska",
+    "manx",
+    "makedonska",
+    "malagassiska",
+    "malajiska",
+    "malayalam",
+    "maltesiska",
+    "maori",
+    "marathi",
+    "marshalliska",
+    "mongoliska",
+    "nauruanska",
+    "navaho",
+    "nordndebele",
+    "nepalesiska",
+    "ndonga",
+    "norskt bokmål",
+    "nynorska",
+    "norska",
+    "szezuan i",
+    "sydndebele",
+    "occitanska",
+    "odjibwa",
+    "kyrkslaviska",
+    "oromo",
+    "oriya",
+    "ossetiska",
+    "punjabi",
+    "pali",
+    "persiska",
+    "polska",
+    "afghanska",
+    "portugisiska",
+    "quechua",
+    "rätoromanska",
+    "rundi",
+    "rumänska",
+    "ryska",
+    "sanskrit",
+    "sardinska",
+    "sindhi",
+    "nordsamiska",
+    "samoanska",
+    "sango",
+    "serbiska",
+    "skotsk gaeliska",
+    "shona",
+    "singalesiska",
+    "slovakiska",
+    "slovenska",
+    "somaliska",
+    "sydsotho",
+    "spanska",
+    "sundanesiska",
+    "swahili",
+    "swati",
+    "svenska",
+    "tamil",
+    "telugu",
+    "tadzjikiska",
+    "thailändska",
+    "tigrinja",
+    "tibetanska",
+    "turkmeniska",
+    "tagalog",
+    "tswana",
+    "tonganska",
+    "turkiska",
+    "tsonga",
+    "tatariska",
+    "twi",
+    "tahitiska",
+    "uiguriska",
+    "ukrainska",
+    "urdu",
+    "uzbekiska",
+    "venda",
+    "vietnamesiska",
+    "volapük",
+    "vallonska",
+    "walesiska",
+    "wolof",
+    "västfrisiska",
+    "xhosa",
+    "jiddisch",
+    "yoruba",
+    "zhuang",
+    "zulu",
+];
+
+static TA: [&str; 184] = [
+    "அப்காஜியான்",
+    "அஃபார்",
+    "ஆஃப்ரிகான்ஸ்",
+    "அகான்",
+    "அல்பேனியன்",
+    "அம்ஹாரிக்",
+    "அரபிக்",
+    "ஆர்கோனீஸ்",
+    "ஆர்மேனியன்",
+    "அஸ்ஸாமீஸ்",
+    "அவேரிக்",
+    "அவெஸ்தான்",
+    "அய்மரா",
+    "அசர்பைஜானி",
+    "பம்பாரா",
+    "பஷ்கிர்",
+    "பாஸ்க்",
+    "பெலாருஷியன்",
+    "வங்காளம்",
+    "",
+    "பிஸ்லாமா",
+    "போஸ்னியன்",
+    "பிரெட்டன்",
+    "பல்கேரியன்",
+    "பர்மீஸ்",
+    "கேட்டலான்",
+    "சாமோரோ",
+    "செச்சென்",
+    "நயன்ஜா",
+    "சீனம்",
+    "சுவாஷ்",
+    "கார்னிஷ்",
+    "கார்சிகன்",
+    "க்ரீ",
+    "குரோஷியன்",
+    "செக்",
+    "டேனிஷ்",
+    "திவேஹி",
+    "டச்சு",
+    "பூடானி",
+    "ஆங்கிலம்",
+    "எஸ்பரேன்டோ",
+    "எஸ்டோனியன்",
+    "ஈவ்",
+    "ஃபரோயிஸ்",
+    "ஃபிஜியன்",
+    "ஃபின்னிஷ்",
+    "பிரெஞ்சு",
+    "ஃபுலா",
+    "காலிஸியன்",
+    "ஜார்ஜியன்",
+    "ஜெர்மன்",
+    "கிரேக்கம்",
+    "க்வாரனி",
+    "குஜராத்தி",
+    "ஹைத்தியன் க்ரியோலி",
+    "ஹௌஸா",
+    "ஹீப்ரூ",
+    "ஹெரேரோ",
+    "இந்தி",
+    "ஹிரி மோட்டு",
+    "ஹங்கேரியன்",
+    "இன்டர்லிங்வா",
+    "இந்தோனேஷியன்",
+    "இன்டர்லிங்",
+    "ஐரிஷ்",
+    "இக்போ",
+    "இனுபியாக்",
+    "இடோ",
+    "ஐஸ்லேண்டிக்",
+    "இத்தாலியன்",
+    "இனுகிடூட்",
+    "ஜப்பானியம்",
+    "ஜாவனீஸ்",
+    "கலாலிசூட்",
+    "கன்னடம்",
+    "கனுரி",
+    "காஷ்மிரி",
+    "கசாக்",
+    "கெமெர்",
+    "கிகுயூ",
+    "கின்யாருவான்டா",
+    "கிர்கிஸ்",
+    "கொமி",
+    "காங்கோ",
+    "கொரியன்",
+    "குர்திஷ்",
+    "குவான்யாமா",
+    "லத்தின்",
+    "லக்ஸம்போர்கிஷ்",
+    "கான்டா",
+    "லிம்பர்கிஷ்",
+    "லிங்காலா",
+    "லாவோ",
+    "லிதுவேனியன்",
+    "லுபா-கடாங்கா",
+    "லாட்வியன்",
+    "மேங்க்ஸ்",
+    "மாஸிடோனியன்",
+    "மலகாஸி",
+    "மலாய்",
+    "மலையாளம்",
+    "மால்டிஸ்",
+    "மௌரி",
+    "மராத்தி",
+    "மார்ஷெலீஸ்",
+    "மங்கோலியன்",
+    "நவ்ரூ",
+    "நவாஜோ",
+    "வடக்கு தெபெலே",
+    "நேபாளி",
+    "தோங்கா",
+    "நார்வேஜியன் பொக்மால்",
+    "நார்வேஜியன் நியூநார்ஸ்க்",
+    "நார்வேஜியன்",
+    "சிசுவான் ஈ",
+    "தெற்கு தெபெலே",
+    "ஒக்கிடன்",
+    "ஒஜிப்வா",
+    "சர்ச் ஸ்லாவிக்",
+    "ஒரோமோ",
+    "ஒடியா",
+    "ஒசெட்டிக்",
+    "பஞ்சாபி",
+    "பாலி",
+    "பெர்ஷியன்",
+    "போலிஷ்",
+    "பஷ்தோ",
+    "போர்ச்சுகீஸ்",
+    "க்வெச்சுவா",
+    "ரோமான்ஷ்",
+    "ருண்டி",
+    "ரோமேனியன்",
+    "ரஷியன்",
+    "சமஸ்கிருதம்",
+    "சார்தீனியன்",
+    "சிந்தி",
+    "வடக்கு சமி",
+    "சமோவான்",
+    "சாங்கோ",
+    "செர்பியன்",
+    "ஸ்காட்ஸ் கேலிக்",
+    "ஷோனா",
+    "சிங்களம்",
+    "ஸ்லோவாக்",
+    "ஸ்லோவேனியன்",
+    "சோமாலி",
+    "தெற்கு ஸோதோ",
+    "ஸ்பானிஷ்",
+    "சுண்டானீஸ்",
+    "ஸ்வாஹிலி",
+    "ஸ்வாடீ",
+    "ஸ்வீடிஷ்",
+    "தமிழ்",
+    "தெலுங்கு",
+    "தஜிக்",
+    "தாய்",
+    "டிக்ரின்யா",
+    "திபெத்தியன்",
+    "துருக்மென்",
+    "டாகாலோக்",
+    "ஸ்வானா",
+    "டோங்கான்",
+    "துருக்கிஷ்",
+    "ஸோங்கா",
+    "டாடர்",
+    "ட்வி",
+    "தஹிதியன்",
+    "உய்குர்",
+    "உக்ரைனியன்",
+    "உருது",
+    "உஸ்பெக்",
+    "வென்டா",
+    "வியட்நாமீஸ்",
+    "ஒலாபூக்",
+    "ஒவாலூன்",
+    "வேல்ஷ்",
+    "ஓலோஃப்",
+    "மேற்கு ஃப்ரிஷியன்",
+    "ஹோசா",
+    "யெட்டிஷ்",
+    "யோருபா",
+    "ஜுவாங்",
+    "ஜுலு",
+];
+
+static TE: [&str; 184] = [
+    "అబ్ఖాజియన్",
+    "అఫార్",
+    "ఆఫ్రికాన్స్",
+    "అకాన్",
+    "అల్బేనియన్",
+    "అమ్హారిక్",
+    "అరబిక్",
+    "అరగోనిస్",
+    "ఆర్మీనియన్",
+    "అస్సామీస్",
+    "అవారిక్",
+    "అవేస్టాన్",
+    "ఐమారా",
+    "అజర్బైజాని",
+    "బంబారా",
+    "బాష్కిర్",
+    "బాస్క్యూ",
+    "బెలారుషియన్",
+    "బంగ్లా",
+    "",
+    "బిస్లామా",
+    "బోస్నియన్",
+    "బ్రెటన్",
+    "బల్గేరియన్",
+    "బర్మీస్",
+    "కాటలాన్",
+    "చమర్రో",
+    "చెచెన్",
+    "న్యాన్జా",
+    "చైనీస్",
+    "చువాష్",
+    "కోర్నిష్",
+    "కోర్సికన్",
+    "క్రి",
+    "క్రొయేషియన్",
+    "చెక్",
+    "డానిష్",
+    "దివేహి",
+    "డచ్",
+    "జోంఖా",
+    "ఇంగ్లీష్",
+    "ఎస్పెరాంటో",
+    "ఎస్టోనియన్",
+    "యూ",
+    "ఫారోస్",
+    "ఫిజియన్",
+    "ఫిన్నిష్",
+    "ఫ్రెంచ్",
+    "ఫ్యుల",
+    "గాలిషియన్",
+    "జార్జియన్",
+    "జర్మన్",
+    "గ్రీక్",
+    "గ్వారనీ",
+    "గుజరాతీ",
+    "హైటియన్ క్రియోల్",
+    "హౌసా",
+    "హిబ్రూ",
+    "హెరెరో",
+    "హిందీ",
+    "హిరి మోటు",
+    "హంగేరియన్",
+    "ఇంటర్లింగ్వా",
+    "ఇండోనేషియన్",
+    "ఇంటర్లింగ్",
+    "ఐరిష్",
+    "ఇగ్బో",
+    "ఇనుపైయాక్",
+    "ఈడో",
+    "ఐస్లాండిక్",
+    "ఇటాలియన్",
+    "ఇనుక్టిటుట్",
+    "జపనీస్",
+    "జావనీస్",
+    "కలాల్లిసూట్",
+    "కన్నడ",
+    "కానురి",
+    "కాశ్మీరి",
+    "కజఖ్",
+    "ఖ్మేర్",
+    "కికుయు",
+    "కిన్యర్వాండా",
+    "కిర్గిజ్",
+    "కోమి",
+    "కోంగో",
+    "కొరియన్",
+    "కుర్దిష్",
+    "క్వాన్యామ",
+    "లాటిన్",
+    "లక్సెంబర్గిష్",
+    "గాండా",
+    "లిమ్బర్గిష్",
+    "లింగాల",
+    "లావో",
+    "లిథువేనియన్",
+    "లూబ-కటాంగ",
+    "లాట్వియన్",
+    "మాంక్స్",
+    "మాసిడోనియన్",
+    "మలగాసి",
+    "మలయ్",
+    "మలయాళం",
+    "మాల్టీస్",
+    "మావొరీ",
+    "మరాఠీ",
+    "మార్షలీస్",
+    "మంగోలియన్",
+    "నౌరు",
+    "నవాజొ",
+    "ఉత్తర దెబెలె",
+    "నేపాలీ",
+    "డోంగా",
+    "నార్వేజియన్ బొక్మాల్",
+    "నార్వేజియాన్ న్యోర్స్క్",
+    "నార్వేజియన్",
+    "శిషువన్ ఈ",
+    "దక్షిణ దెబెలె",
+    "ఆక్సిటన్",
+    "చేవా",
+    "చర్చ్ స్లావిక్",
+    "ఒరోమో",
+    "ఒడియా",
+    "ఒసేటిక్",
+    "పంజాబీ",
+    "పాలీ",
+    "పర్షియన్",
+    "పోలిష్",
+    "పాష్టో",
+    "పోర్చుగీస్",
+    "కెచువా",
+    "రోమన్ష్",
+    "రుండి",
+    "రొమేనియన్",
+    "రష్యన్",
+    "సంస్కృతం",
+    "సార్డీనియన్",
+    "సింధీ",
+    "ఉత్తర సామి",
+    "సమోవన్",
+    "సాంగో",
+    "సెర్బియన్",
+    "స్కాటిష్ గేలిక్",
+    "షోన",
+    "సింహళం",
+    "స్లోవక్",
+    "స్లోవేనియన్",
+    "సోమాలి",
+    "దక్షిణ సోతో",
+    "స్పానిష్",
+    "సండానీస్",
+    "స్వాహిలి",
+    "స్వాతి",
+    "స్వీడిష్",
+    "తమిళం",
+    "తెలుగు",
+    "తజిక్",
+    "థాయ్",
+    "టిగ్రిన్యా",
+    "టిబెటన్",
+    "తుర్క్\u{200C}మెన్",
+    "టగలాగ్",
+    "స్వానా",
+    "టాంగాన్",
+    "టర్కిష్",
+    "సోంగా",
+    "టాటర్",
+    "ట్వి",
+    "తహితియన్",
+    "ఉయ్\u{200C}ఘర్",
+    "ఉక్రెయినియన్",
+    "ఉర్దూ",
+    "ఉజ్బెక్",
+    "వెండా",
+    "వియత్నామీస్",
+    "వోలాపుక్",
+    "వాలూన్",
+    "వెల్ష్",
+    "ఉలూఫ్",
+    "పశ్చిమ ఫ్రిసియన్",
+    "షోసా",
+    "ఇడ్డిష్",
+    "యోరుబా",
+    "జువాన్",
+    "జూలూ",
+];
+
+static TG: [&str; 184] = [
+    "",
+    "",
+    "африкаанс",
+    "",
+    "албанӣ",
+    "амҳарӣ",
+    "арабӣ",
+    "",
+    "арманӣ",
+    "ассомӣ",
+    "",
+    "",
+    "",
+    "озарбойҷонӣ",
+    "",
+    "бошқирдӣ",
+    "баскӣ",
+    "белорусӣ",
+    "бинғолӣ",
+    "",
+    "",
+    "босниягӣ",
+    "бретонӣ",
+    "булғорӣ",
+    "бирманӣ",
+    "каталонӣ",
+    "",
+    "",
+    "нянҷа",
+    "хитоӣ",
+    "",
+    "",
+    "корсиканӣ",
+    "",
+    "хорватӣ",
+    "чехӣ",
+    "даниягӣ",
+    "дивеҳӣ",
+    "голландӣ",
+    "дзонгха",
+    "англисӣ",
+    "эсперанто",
+    "эстонӣ",
+    "",
+    "фарерӣ",
+    "",
+    "финӣ",
+    "франсузӣ",
+    "фулаҳ",
+    "галисиягӣ",
+    "гурҷӣ",
+    "немисӣ",
+    "юнонӣ",
+    "гуаранӣ",
+    "гуҷаротӣ",
+    "гаитии креолӣ",
+    "ҳауса",
+    "ибронӣ",
+    "ҳереро",
+    "ҳиндӣ",
+    "",
+    "маҷорӣ",
+    "",
+    "индонезӣ",
+    "",
+    "ирландӣ",
+    "игбо",
+    "",
+    "",
+    "исландӣ",
+    "италиявӣ",
+    "инуктитутӣ",
+    "японӣ",
+    "",
+    "",
+    "каннада",
+    "канурӣ",
+    "кашмирӣ",
+    "қазоқӣ",
+    "кхмерӣ",
+    "",
+    "киняруанда",
+    "қирғизӣ",
+    "",
+    "",
+    "кореягӣ",
+    "курдӣ",
+    "",
+    "лотинӣ",
+    "люксембургӣ",
+    "",
+    "",
+    "",
+    "лаосӣ",
+    "литвонӣ",
+    "",
+    "латишӣ",
+    "",
+    "мақдунӣ",
+    "малагасӣ",
+    "малайӣ",
+    "малаяламӣ",
+    "малтӣ",
+    "маорӣ",
+    "маратҳӣ",
+    "",
+    "муғулӣ",
+    "",
+    "",
+    "",
+    "непалӣ",
+    "",
+    "",
+    "",
+    "норвегӣ",
+    "",
+    "",
+    "окситанӣ",
+    "",
+    "",
+    "оромо",
+    "одия",
+    "",
+    "панҷобӣ",
+    "",
+    "форсӣ",
+    "лаҳистонӣ",
+    "пушту",
+    "португалӣ",
+    "кечуа",
+    "ретороманӣ",
+    "",
+    "руминӣ",
+    "русӣ",
+    "санскрит",
+    "",
+    "синдӣ",
+    "самии шимолӣ",
+    "",
+    "",
+    "сербӣ",
+    "шотландии гэлӣ",
+    "",
+    "сингалӣ",
+    "словакӣ",
+    "словенӣ",
+    "сомалӣ",
+    "",
+    "испанӣ",
+    "",
+    "",
+    "",
+    "шведӣ",
+    "тамилӣ",
+    "телугу",
+    "тоҷикӣ",
+    "тайӣ",
+    "тигриня",
+    "тибетӣ",
+    "туркманӣ",
+    "",
+    "",
+    "тонганӣ",
+    "туркӣ",
+    "",
+    "тоторӣ",
+    "",
+    "",
+    "ӯйғурӣ",
+    "украинӣ",
+    "урду",
+    "ӯзбекӣ",
+    "венда",
+    "ветнамӣ",
+    "",
+    "",
+    "валлӣ",
+    "волоф",
+    "фризии ғарбӣ",
+    "",
+    "идиш",
+    "йоруба",
+    "",
+    "",
+];
+
+static TH: [&str; 184] = [
+    "อับฮาเซีย",
+    "อะฟาร์",
+    "แอฟริกานส์",
+    "อาคาน",
+    "แอลเบเนีย",
+    "อัมฮารา",
+    "อาหรับ",
+    "อารากอน",
+    "อาร์เมเนีย",
+    "อัสสัม",
+    "อาวาร์",
+    "อเวสตะ",
+    "ไอย์มารา",
+    "อาเซอร์ไบจาน",
+    "บัมบารา",
+    "บัชคีร์",
+    "บาสก์",
+    "เบลารุส",
+    "บังกลา",
+    "",
+    "บิสลามา",
+    "บอสเนีย",
+    "เบรตัน",
+    "บัลแกเรีย",
+    "พม่า",
+    "คาตาลัน",
+    "ชามอร์โร",
+    "เชเชน",
+    "เนียนจา",
+    "จีน",
+    "ชูวัช",
+    "คอร์นิช",
+    "คอร์ซิกา",
+    "ครี",
+    "โครเอเชีย",
+    "เช็ก",
+    "เดนมาร์ก",
+    "ธิเวหิ",
+    "ดัตช์",
+    "ซองคา",
+    "อังกฤษ",
+    "เอสเปรันโต",
+    "เอสโตเนีย",
+    "เอเว",
+    "แฟโร",
+    "ฟิจิ",
+    "ฟินแลนด์",
+    "ฝรั่งเศส",
+    "ฟูลาห์",
+    "กาลิเซีย",
+    "จอร์เจีย",
+    "เยอรมัน",
+    "กรีก",
+    "กัวรานี",
+    "คุชราต",
+    "เฮติครีโอล",
+    "เฮาซา",
+    "ฮิบรู",
+    "เฮเรโร",
+    "ฮินดี",
+    "ฮีรีโมตู",
+    "ฮังการี",
+    "อินเตอร์ลิงกัว",
+    "อินโดนีเซีย",
+    "อินเตอร์ลิงกิว",
+    "ไอริช",
+    "อิกโบ",
+    "อีนูเปียก",
+    "อีโด",
+    "ไอซ์แลนด์",
+    "อิตาลี",
+    "อินุกติตุต",
+    "ญี่ปุ่น",
+    "ชวา",
+    "กรีนแลนด์",
+    "กันนาดา",
+    "คานูรี",
+    "แคชเมียร์",
+    "คาซัค",
+    "เขมร",
+    "กีกูยู",
+    "รวันดา",
+    "คีร์กีซ",
+    "โกมิ",
+    "คองโก",
+    "เกาหลี",
+    "เคิร์ด",
+    "กวนยามา",
+    "ละติน",
+    "ลักเซมเบิร์ก",
+    "ยูกันดา",
+    "ลิมเบิร์ก",
+    "ลิงกาลา",
+    "ลาว",
+    "ลิทัวเนีย",
+    "ลูบา-กาตองกา",
+    "ลัตเวีย",
+    "มานซ์",
+    "มาซิโดเนีย",
+    "มาลากาซี",
+    "มาเลย์",
+    "มาลายาลัม",
+    "มอลตา",
+    "เมารี",
+    "มราฐี",
+    "มาร์แชลล์",
+    "มองโกเลีย",
+    "นาอูรู",
+    "นาวาโฮ",
+    "เอ็นเดเบเลเหนือ",
+    "เนปาล",
+    "ดองกา",
+    "นอร์เวย์บุคมอล",
+    "นอร์เวย์นีนอสก์",
+    "นอร์เวย์",
+    "เสฉวนยี่",
+    "เอ็นเดเบเลใต้",
+    "อ็อกซิตัน",
+    "โอจิบวา",
+    "เชอร์ชสลาวิก",
+    "โอโรโม",
+    "โอดิยา",
+    "ออสเซเตีย",
+    "ปัญจาบ",
+    "บาลี",
+    "เปอร์เซีย",
+    "โปแลนด์",
+    "พัชโต",
+    "โปรตุเกส",
+    "เคชวา",
+    "โรแมนซ์",
+    "บุรุนดี",
+    "โรมาเนีย",
+    "รัสเซีย",
+    "สันสกฤต",
+    "ซาร์เดญา",
+    "สินธิ",
+    "ซามิเหนือ",
+    "ซามัว",
+    "ซันโก",
+    "เซอร์เบีย",
+    "เกลิกสกอต",
+    "โชนา",
+    "สิงหล",
+    "สโลวัก",
+    "สโลวีเนีย",
+    "โซมาลี",
+    "โซโทใต้",
+    "สเปน",
+    "ซุนดา",
+    "สวาฮีลี",
+    "สวาติ",
+    "สวีเดน",
+    "ทมิฬ",
+    "เตลูกู",
+    "ทาจิก",
+    "ไทย",
+    "ติกริญญา",
+    "ทิเบต",
+    "เติร์กเมน",
+    "ตากาล็อก",
+    "สวานา",
+    "ตองกา",
+    "ตุรกี",
+    "ซองกา",
+    "ตาตาร์",
+    "ทวิ",
+    "ตาฮิตี",
+    "อุยกูร์",
+    "ยูเครน",
+    "อูรดู",
+    "อุซเบก",
+    "เวนดา",
+    "เวียดนาม",
+    "โวลาพึค",
+    "วอลลูน",
+    "เวลส์",
+    "โวลอฟ",
+    "ฟริเซียนตะวันตก",
+    "คะห์โอซา",
+    "ยิดดิช",
+    "โยรูบา",
+    "จ้วง",
+    "ซูลู",
+];
+
+static TI: [&str; 184] = [
+    "ኣብካዝኛ",
+    "አፋር",
+    "ኣፍሪካንስ",
+    "ኣካን",
+    "ኣልባንኛ",
+    "ኣምሓርኛ",
+    "ዓረብኛ",
+    "ኣራጎንኛ",
+    "ኣርሜንኛ",
+    "ኣሳሜዝኛ",
+    "ኣቫርኛ",
+    "",
+    "ኣይማራ",
+    "ኣዘርባጃንኛ",
+    "ባምባራ",
+    "ባሽኪር",
+    "ባስክኛ",
+    "ቤላሩስኛ",
+    "በንጋሊ",
+    "",
+    "ቢስላማ",
+    "ቦዝንኛ",
+    "ብረቶንኛ",
+    "ቡልጋርኛ",
+    "በርምኛ",
+    "ካታላን",
+    "ቻሞሮ",
+    "ቸቸንይና",
+    "ንያንጃ",
+    "ቻይንኛ",
+    "ቹቫሽኛ",
+    "ኮርንኛ",
+    "ኮርስኛ",
+    "",
+    "ክሮኤሽያን",
+    "ቸክኛ",
+    "ዳኒሽ",
+    "ዲቨሂ",
+    "ዳች",
+    "ድዞንግካ",
+    "እንግሊዝኛ",
+    "ኤስፐራንቶ",
+    "ኤስቶንኛ",
+    "ኢው",
+    "ፋሮእይና",
+    "ፊጅያንኛ",
+    "ፊንላንድኛ",
+    "ፈረንሳይኛ",
+    "ፉላ",
+    "ጋሊሽያን",
+    "ጆርጅያንኛ",
+    "ጀርመን",
+    "ግሪኽኛ",
+    "ጓራኒ",
+    "ጉጃራቲ",
+    "ክርዮል ሃይትኛ",
+    "ሃውሳ",
+    "እብራይስጢ",
+    "ሄረሮ",
+    "ሂንዲ",
+    "",
+    "ሃንጋርኛ",
+    "ኢንተርሊንጓ",
+    "ኢንዶነዥኛ",
+    "ኢንተርሊንጔ",
+    "ኣየርላንድኛ",
+    "ኢግቦ",
+    "",
+    "ኢዶ",
+    "ኣይስላንድኛ",
+    "ጥልያን",
+    "ኢናክቲቱት",
+    "ጃፓንኛ",
+    "ጃቫንኛ",
+    "ግሪንላንድኛ",
+    "ካንናዳ",
+    "ካኑሪ",
+    "ካሽሚሪ",
+    "ካዛክ",
+    "ክመር",
+    "ኪኩዩ",
+    "ኪንያርዋንዳ",
+    "ኪርጊዝኛ",
+    "ኮሚ",
+    "",
+    "ኮርይኛ",
+    "ኩርዲሽ",
+    "ክዋንያማ",
+    "ላቲን",
+    "ሉክሰምበርግኛ",
+    "ጋንዳ",
+    "ሊምበርግኛ",
+    "ሊንጋላ",
+    "ላኦ",
+    "ሊትዌንኛ",
+    "ሉባ-ካታንጋ",
+    "ላትቭኛ",
+    "ማንክስ",
+    "መቄዶንኛ",
+    "ማላጋሲ",
+    "ማላይኛ",
+    "ማላያላም",
+    "ማልትኛ",
+    "ማኦሪ",
+    "ማራቲ",
+    "ማርሻሊዝኛ",
+    "ሞንጎልኛ",
+    "ናውርዋንኛ",
+    "ናቫሆ",
+    "ሰሜን ኤንደበለ",
+    "ኔፓሊ",
+    "ኤንዶንጋ",
+    "ኖርወያዊ ቦክማል",
+    "ኖርወያዊ ናይኖርስክ",
+    "ኖርወይኛ",
+    "ሲችዋን ዪ",
+    "ደቡብ ኤንደበለ",
+    "ኦክሲታንኛ",
+    "",
+    "ቤተ-ክርስትያን ስላቭኛ",
+    "ኦሮሞ",
+    "ኦድያ",
+    "ኦሰትኛ",
+    "ፑንጃቢ",
+    "",
+    "ፋርስኛ",
+    "ፖሊሽ",
+    "ፓሽቶ",
+    "ፖርቱጊዝኛ",
+    "ቀችዋ",
+    "ሮማንሽ",
+    "ኪሩንዲ",
+    "ሩማንኛ",
+    "ሩስኛ",
+    "ሳንስክሪት",
+    "ሳርዲንኛ",
+    "ሲንድሂ",
+    "ሰሜናዊ ሳሚ",
+    "ሳሞእኛ",
+    "ሳንጎ",
+    "ሰርቢያኛ",
+    "ስኮትላንዳዊ ጋኤሊክኛ",
+    "ሾና",
+    "ሲንሃላ",
+    "ስሎቫክኛ",
+    "ስሎቬንኛ",
+    "ሶማሊ",
+    "ደቡባዊ ሶቶ",
+    "ስጳንኛ",
+    "ሱዳንኛ",
+    "ስዋሂሊ",
+    "ስዋዚ",
+    "ስዊድንኛ",
+    "ታሚል",
+    "ተሉጉ",
+    "ታጂክኛ",
+    "ታይኛ",
+    "ትግርኛ",
+    "ቲበታንኛ",
+    "ቱርክመንኛ",
+    "",
+    "ስዋና",
+    "ቶንጋንኛ",
+    "ቱርክኛ",
+    "ሶንጋ",
+    "ታታር",
+    "ትዊ",
+    "ታሂትኛ",
+    "ኡይጉር",
+    "ዩክረይንኛ",
+    "ኡርዱ",
+    "ኡዝበክኛ",
+    "ቨንዳ",
+    "ቬትናምኛ",
+    "ቮላፑክ",
+    "ዋሎን",
+    "ዌልስኛ",
+    "ዎሎፍ",
+    "ምዕራባዊ ፍሪስኛ",
+    "ኮሳ",
+    "ይሁድኛ",
+    "ዮሩባ",
+    "ዙኣንግ",
+    "ዙሉ",
+];
+
+static TK: [&str; 184] = [
+    "abhaz dili",
+    "afar dili",
+    "afrikaans dili",
+    "akan dili",
+    "alban dili",
+    "amhar dili",
+    "arap dili",
+    "aragon dili",
+    "ermeni dili",
+    "assam dili",
+    "awar dili",
+    "",
+    "aýmara dili",
+    "azerbaýjan dili",
+    "bamana",
+    "başgyrt dili",
+    "bask dili",
+    "belarus dili",
+    "bengal dili",
+    "",
+    "bislama dili",
+    "bosniýa dili",
+    "breton dili",
+    "bolgar dili",
+    "birma dili",
+    "katalan dili",
+    "çamorro",
+    "çeçen dili",
+    "nýanja dili",
+    "hytaý dili",
+    "çuwaş dili",
+    "korn dili",
+    "korsikan dili",
+    "",
+    "horwat dili",
+    "çeh dili",
+    "daniýa dili",
+    "diwehi dili",
+    "niderland dili",
+    "dzong-ke dili",
+    "iňlis dili",
+    "esperanto dili",
+    "eston dili",
+    "ewe dili",
+    "farer dili",
+    "fiji dili",
+    "fin dili",
+    "fransuz dili",
+    "fula dili",
+    "galisiý dili",
+    "gruzin dili",
+    "nemes dili",
+    "grek dili",
+    "guarani dili",
+    "gujarati dili",
+    "gaiti kreol dili",
+    "hausa dili",
+    "ýewreý dili",
+    "gerero dili",
+    "hindi dili",
+    "",
+    "wenger dili",
+    "interlingwa dili",
+    "indonez dili",
+    "interlingwe dili",
+    "irland dili",
+    "igbo dili",
+    "",
+    "ido dili",
+    "island dili",
+    "italýan dili",
+    "inuktitut dili",
+    "ýapon dili",
+    "ýawa dili",
+    "grenland dili",
+    "kannada dili",
+    "kanuri",
+    "kaşmiri dili",
+    "gazak dili",
+    "khmer dili",
+    "kikuýu dili",
+    "kinýaruanda dili",
+    "gyrgyz dili",
+    "komi dili",
+    "",
+    "koreý dili",
+    "kürt dili",
+    "kwanýama dili",
+    "latyn dili",
+    "lýuksemburg dili",
+    "ganda dili",
+    "limburg dili",
+    "lingala dili",
+    "laos dili",
+    "litwa dili",
+    "luba-katanga dili",
+    "latyş dili",
+    "men dili",
+    "makedon dili",
+    "malagasiý dili",
+    "malaý dili",
+    "malaýalam dili",
+    "malta dili",
+    "maori dili",
+    "marathi dili",
+    "marşall dili",
+    "mongol dili",
+    "nauru dili",
+    "nawaho dili",
+    "demirgazyk ndebele dili",
+    "nepal dili",
+    "ndonga dili",
+    "norwegiýa bukmol dili",
+    "norwegiýa nýunorsk dili",
+    "norweg dili",
+    "syçuan-i dili",
+    "günorta ndebele dili",
+    "oksitan dili",
+    "",
+    "buthana slaw dili",
+    "oromo dili",
+    "oriýa dili",
+    "osetin dili",
+    "penjab dili",
+    "",
+    "pars dili",
+    "polýak dili",
+    "peştun dili",
+    "portugal dili",
+    "keçua dili",
+    "retoroman dili",
+    "rundi dili",
+    "rumyn dili",
+    "rus dili",
+    "sanskrit dili",
+    "sardin dili",
+    "sindhi dili",
+    "demirgazyk saam dili",
+    "samoa dili",
+    "sango dili",
+    "serb dili",
+    "şotland kelt dili",
+    "şona dili",
+    "singal dili",
+    "slowak dili",
+    "slowen dili",
+    "somali dili",
+    "günorta soto dili",
+    "ispan dili",
+    "sundan dili",
+    "suahili dili",
+    "swati dili",
+    "şwed dili",
+    "tamil dili",
+    "telugu dili",
+    "täjik dili",
+    "taý dili",
+    "tigrinýa dili",
+    "tibet dili",
+    "türkmen dili",
+    "",
+    "tswana dili",
+    "tongan dili",
+    "türk dili",
+    "tsonga dili",
+    "tatar dili",
+    "",
+    "taiti dili",
+    "uýgur dili",
+    "ukrain dili",
+    "urdu",
+    "özbek dili",
+    "wenda dili",
+    "wýetnam dili",
+    "wolapýuk dili",
+    "wallon dili",
+    "walliý dili",
+    "wolof dili",
+    "günbatar friz dili",
+    "kosa dili",
+    "idiş dili",
+    "ýoruba dili",
+    "çžuan dili",
+    "zulu dili",
+];
+
+static TO: [&str; 184] = [
+    "lea fakaʻapakasia",
+    "lea fakaʻafāla",
+    "lea fakaʻafilikana",
+    "lea fakaʻakani",
+    "lea fakaʻalapēnia",
+    "lea fakaʻameliki",
+    "lea fakaʻalepea",
+    "lea fakaʻalakoni",
+    "lea fakaʻāmenia",
+    "lea fakaʻasamia",
+    "lea fakaʻavaliki",
+    "lea fakaʻavesitani",
+    "lea fakaʻaimala",
+    "lea fakaʻasepaisani",
+    "lea fakapamipala",
+    "lea fakapasikili",
+    "lea fakapāsiki",
+    "lea fakapelalusi",
+    "lea fakapāngilā",
+    "",
+    "lea fakapisilama",
+    "lea fakaposinia",
+    "lea fakapeletoni",
+    "lea fakapulukalia",
+    "lea fakapema",
+    "lea fakakatalani",
+    "lea fakakamolo",
+    "lea fakasese",
+    "lea fakanianisa",
+    "lea fakasiaina",
+    "lea fakasuvasa",
+    "lea fakakoniuali",
+    "lea fakakōsika",
+    "lea fakakelī",
+    "lea fakakuloisia",
+    "lea fakaseki",
+    "lea fakatenimaʻake",
+    "lea fakativehi",
+    "lea fakahōlani",
+    "lea fakatisōngika",
+    "lea fakapālangi",
+    "lea fakaʻesipulanito",
+    "lea fakaʻesitōnia",
+    "lea fakaʻeue",
+    "lea fakafaloe",
+    "lea fakafisi",
+    "lea fakafinilani",
+    "lea fakafalanisē",
+    "lea fakafulā",
+    "lea fakakalisia",
+    "lea fakaseōsia",
+    "lea fakasiamane",
+    "lea fakakalisi",
+    "lea fakakualani",
+    "lea fakakutalati",
+    "lea fakahaiti",
+    "lea fakahausa",
+    "lea fakahepelū",
+    "lea fakahelelo",
+    "lea fakahinitī",
+    "lea fakahili-motu",
+    "lea fakahungakalia",
+    "lea fakavahaʻalea",
+    "lea fakaʻinitōnesia",
+    "lea fakavahaʻalingikē",
+    "lea fakaʻaelani",
+    "lea fakaʻikipō",
+    "lea fakaʻinupiaki",
+    "lea fakaʻito",
+    "lea fakaʻaisilani",
+    "lea fakaʻītali",
+    "lea fakaʻinuketītuti",
+    "lea fakasiapani",
+    "lea fakasava",
+    "lea fakakalaʻalisuti",
+    "lea fakakanata",
+    "lea fakakanuli",
+    "lea fakakāsimila",
+    "lea fakakasaki",
+    "lea fakakamipōtia",
+    "lea fakakikuiu",
+    "lea fakakiniāuanita",
+    "lea fakakīsisi",
+    "lea fakakomi",
+    "lea fakakongikō",
+    "lea fakakōlea",
+    "lea fakakulitī",
+    "lea fakakuaniama",
+    "lea fakalatina",
+    "lea fakalakisimipeki",
+    "lea fakakanita",
+    "lea fakalimipūliki",
+    "lea lingikala",
+    "lea fakalau",
+    "lea fakalituania",
+    "lea fakalupa-katanga",
+    "lea fakalativia",
+    "lea fakamangikī",
+    "lea fakamasitōnia",
+    "lea fakamalakasi",
+    "lea fakamalei",
+    "lea fakaʻinitia-malāialami",
+    "lea fakamalita",
+    "lea fakamāuli",
+    "lea fakamalati",
+    "lea fakamāsolo",
+    "lea fakamongokōlia",
+    "lea fakanaulu",
+    "lea fakanavaho",
+    "lea fakanetepele-tokelau",
+    "lea fakanepali",
+    "lea fakanetongikā",
+    "lea fakanouaē-pokimali",
+    "lea fakanoauē-ninosiki",
+    "lea fakanouaē",
+    "lea fakasisiuani-ī",
+    "lea fakanetepele-tonga",
+    "lea fakaʻokitane",
+    "lea fakaʻosipiuā",
+    "lea fakasilavia-fakasiasi",
+    "lea fakaʻolomo",
+    "lea fakaʻotia",
+    "lea fakaʻosetiki",
+    "lea fakapūnusapi",
+    "lea fakapāli",
+    "lea fakapēsia",
+    "lea fakapolani",
+    "lea fakapasitō",
+    "lea fakapotukali",
+    "lea fakakuetisa",
+    "lea fakalaito-lomēnia",
+    "lea fakaluaniti",
+    "lea fakalōmenia",
+    "lea fakalūsia",
+    "lea fakasanisukuliti",
+    "lea fakasaletīnia",
+    "lea fakasīniti",
+    "lea fakasami-tokelau",
+    "lea fakahaʻamoa",
+    "lea fakasangikō",
+    "lea fakasēpia",
+    "lea fakakaeliki",
+    "lea fakasiona",
+    "lea fakasingihala",
+    "lea fakasolāvaki",
+    "lea fakasolovenia",
+    "lea fakasomali",
+    "lea fakasoto-tonga",
+    "lea fakasipēnisi",
+    "lea fakasunitā",
+    "lea fakasuahili",
+    "lea fakasuati",
+    "lea fakasuēteni",
+    "lea fakatamili",
+    "lea fakaʻinitia-teluku",
+    "lea fakatāsiki",
+    "lea fakatailani",
+    "lea fakatikilinia",
+    "lea fakatipeti",
+    "lea fakatēkimeni",
+    "lea fakatakāloka",
+    "lea fakatisuana",
+    "lea fakatonga",
+    "lea fakatoake",
+    "lea fakatisonga",
+    "lea fakatatale",
+    "lea fakatusuī",
+    "lea fakatahiti",
+    "lea fakaʻuikūli",
+    "lea fakaʻūkalaʻine",
+    "lea fakaʻūtū",
+    "lea fakaʻusipeki",
+    "lea fakavenitā",
+    "lea fakavietinami",
+    "lea fakavolapiki",
+    "lea fakaʻualonia",
+    "lea fakauēlesi",
+    "lea fakaʻuolofo",
+    "lea fakafilisia-hihifo",
+    "lea fakatōsa",
+    "lea fakaītisi",
+    "lea fakaʻiōlupa",
+    "lea fakasuangi",
+    "lea fakasulu",
+];
+
+static TR: [&str; 184] = [
+    "Abhazca",
+    "Afar",
+    "Afrikaanca",
+    "Akan",
+    "Arnavutça",
+    "Amharca",
+    "Arapça",
+    "Aragonca",
+    "Ermenice",
+    "Assamca",
+    "Avar dili",
+    "Avestçe",
+    "Aymara",
+    "Azerbaycan dili",
+    "Bambara",
+    "Başkırtça",
+    "Baskça",
+    "Belarusça",
+    "Bengalce",
+    "",
+    "Bislama",
+    "Boşnakça",
+    "Bretonca",
+    "Bulgarca",
+    "Birman dili",
+    "Katalanca",
+    "Çamorro dili",
+    "Çeçence",
+    "Nyanja",
+    "Çince",
+    "Çuvaşça",
+    "Kernevekçe",
+    "Korsikaca",
+    "Krice",
+    "Hırvatça",
+    "Çekçe",
+    "Danca",
+    "Divehi dili",
+    "Felemenkçe",
+    "Dzongkha",
+    "İngilizce",
+    "Esperanto",
+    "Estonca",
+    "Ewe",
+    "Faroe dili",
+    "Fiji dili",
+    "Fince",
+    "Fransızca",
+    "Fula dili",
+    "Galiçyaca",
+    "Gürcüce",
+    "Almanca",
+    "Yunanca",
+    "Guarani dili",
+    "Güceratça",
+    "Haiti Kreyolu",
+    "Hausa dili",
+    "İbranice",
+    "Herero dili",
+    "Hintçe",
+    "Hiri Motu",
+    "Macarca",
+    "İnterlingua",
+    "Endonezce",
+    "Interlingue",
+    "İrlandaca",
+    "İbo dili",
+    "İnyupikçe",
+    "Ido",
+    "İzlandaca",
+    "İtalyanca",
+    "İnuktitut dili",
+    "Japonca",
+    "Cava dili",
+    "Grönland dili",
+    "Kannada dili",
+    "Kanuri dili",
+    "Keşmir dili",
+    "Kazakça",
+    "Khmer dili",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kırgızca",
+    "Komi",
+    "Kongo dili",
+    "Korece",
+    "Kürtçe",
+    "Kuanyama",
+    "Latince",
+    "Lüksemburgca",
+    "Ganda",
+    "Limburgca",
+    "Lingala",
+    "Lao dili",
+    "Litvanca",
+    "Luba-Katanga",
+    "Letonca",
+    "Man dili",
+    "Makedonca",
+    "Malgaşça",
+    "Malayca",
+    "Malayalam dili",
+    "Maltaca",
+    "Maori dili",
+    "Marathi dili",
+    "Marshall Adaları dili",
+    "Moğolca",
+    "Nauru dili",
+    "Navaho dili",
+    "Kuzey Ndebele",
+    "Nepalce",
+    "Ndonga",
+    "Norveççe Bokmål",
+    "Norveççe Nynorsk",
+    "Norveççe",
+    "Sichuan Yi",
+    "Güney Ndebele",
+    "Oksitan dili",
+    "Ojibva dili",
+    "Kilise Slavcası",
+    "Oromo dili",
+    "Oriya dili",
+    "Osetçe",
+    "Pencapça",
+    "Pali",
+    "Farsça",
+    "Lehçe",
+    "Peştuca",
+    "Portekizce",
+    "Keçuva dili",
+    "Romanşça",
+    "Kirundi",
+    "Rumence",
+    "Rusça",
+    "Sanskrit",
+    "Sardunya dili",
+    "Sindhi dili",
+    "Kuzey Laponcası",
+    "Samoa dili",
+    "Sango",
+    "Sırpça",
+    "İskoç Gaelcesi",
+    "Şona dili",
+    "Sinhali dili",
+    "Slovakça",
+    "Slovence",
+    "Somalice",
+    "Güney Sotho dili",
+    "İspanyolca",
+    "Sunda dili",
+    "Svahili dili",
+    "Sisvati",
+    "İsveççe",
+    "Tamilce",
+    "Telugu dili",
+    "Tacikçe",
+    "Tayca",
+    "Tigrinya dili",
+    "Tibetçe",
+    "Türkmence",
+    "Tagalogca",
+    "Setsvana",
+    "Tonga dili",
+    "Türkçe",
+    "Tsonga",
+    "Tatarca",
+    "Tvi",
+    "Tahiti dili",
+    "Uygurca",
+    "Ukraynaca",
+    "Urduca",
+    "Özbekçe",
+    "Venda dili",
+    "Vietnamca",
+    "Volapük",
+    "Valonca",
+    "Galce",
+    "Volofça",
+    "Batı Frizcesi",
+    "Zosa dili",
+    "Yidiş",
+    "Yorubaca",
+    "Zhuangca",
+    "Zuluca",
+];
+
+static TT: [&str; 184] = [
+    "",
+    "",
+    "африкаанс",
+    "",
+    "албан",
+    "амхар",
+    "гарәп",
+    "",
+    "әрмән",
+    "ассам",
+    "",
+    "",
+    "",
+    "әзәрбайҗан",
+    "",
+    "башкорт",
+    "баск",
+    "белорус",
+    "бенгали",
+    "",
+    "",
+    "босния",
+    "бретон",
+    "болгар",
+    "бирма",
+    "каталан",
+    "",
+    "",
+    "ньянҗа",
+    "кытай",
+    "",
+    "",
+    "корсика",
+    "",
+    "хорват",
+    "чех",
+    "дания",
+    "мальдив",
+    "голланд",
+    "дзонг-кха",
+    "инглиз",
+    "эсперанто",
+    "эстон",
+    "",
+    "фарер",
+    "",
+    "фин",
+    "француз",
+    "фула",
+    "галисия",
+    "грузин",
+    "алман",
+    "грек",
+    "гуарани",
+    "гуҗарати",
+    "гаити креол",
+    "хауса",
+    "яһүд",
+    "гереро",
+    "һинд",
+    "",
+    "венгр",
+    "",
+    "индонезия",
+    "",
+    "ирланд",
+    "игбо",
+    "",
+    "",
+    "исланд",
+    "итальян",
+    "инуктикут",
+    "япон",
+    "",
+    "",
+    "каннада",
+    "канури",
+    "кашмири",
+    "казакъ",
+    "кхмер",
+    "",
+    "руанда",
+    "кыргыз",
+    "",
+    "",
+    "корея",
+    "көрд",
+    "",
+    "латин",
+    "люксембург",
+    "",
+    "",
+    "",
+    "лаос",
+    "литва",
+    "",
+    "латыш",
+    "",
+    "македон",
+    "малагаси",
+    "малай",
+    "малаялам",
+    "мальта",
+    "маори",
+    "маратхи",
+    "",
+    "монгол",
+    "",
+    "",
+    "",
+    "непали",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "окситан",
+    "",
+    "",
+    "оромо",
+    "ория",
+    "",
+    "пәнҗаби",
+    "",
+    "фарсы",
+    "поляк",
+    "пушту",
+    "португал",
+    "кечуа",
+    "ретороман",
+    "",
+    "румын",
+    "рус",
+    "санскрит",
+    "",
+    "синдһи",
+    "төньяк саам",
+    "",
+    "",
+    "серб",
+    "шотланд гэль",
+    "",
+    "сингал",
+    "словак",
+    "словен",
+    "сомали",
+    "",
+    "испан",
+    "",
+    "",
+    "",
+    "швед",
+    "тамил",
+    "телугу",
+    "таҗик",
+    "тай",
+    "тигринья",
+    "тибет",
+    "төрекмән",
+    "",
+    "",
+    "тонга",
+    "төрек",
+    "",
+    "татар",
+    "",
+    "",
+    "уйгыр",
+    "украин",
+    "урду",
+    "үзбәк",
+    "венда",
+    "вьетнам",
+    "",
+    "",
+    "уэльс",
+    "волоф",
+    "",
+    "",
+    "идиш",
+    "йоруба",
+    "",
+    "",
+];
+
+static UG: [&str; 184] = [
+    "ئابخازچە",
+    "ئافارچە",
+    "ئافرىكانچە",
+    "ئاكانچە",
+    "ئالبانچە",
+    "ئامھارچە",
+    "ئەرەبچە",
+    "ئاراگونچە",
+    "ئەرمېنچە",
+    "ئاسامچە",
+    "ئاۋارچە",
+    "ئاۋېستاچە",
+    "ئايماراچە",
+    "ئەزەربەيجانچە",
+    "بامباراچە",
+    "باشقىرتچە",
+    "باسكىچە",
+    "بېلارۇسچە",
+    "بېنگالچە",
+    "",
+    "بىسلاماچە",
+    "بوسىنچە",
+    "بىرېتونچە",
+    "بۇلغارچە",
+    "بىرماچە",
+    "كاتالانچە",
+    "چامورروچە",
+    "چېچىنچە",
+    "نىيانجاچە",
+    "خەنزۇچە",
+    "چۇۋاشچە",
+    "كورنىشچە",
+    "كورسۇچە",
+    "كرىچە",
+    "كىرودىچە",
+    "چېخچە",
+    "دانىشچە",
+    "دىۋېخچە",
+    "گوللاندچە",
+    "زوڭخاچە",
+    "ئىنگلىزچە",
+    "ئېسپرانتوچە",
+    "ئېستونچە",
+    "ئېۋېچە",
+    "فائېروچە",
+    "فىجىچە",
+    "فىنچە",
+    "فىرانسۇزچە",
+    "فۇلاھچە",
+    "گالىچە",
+    "گىرۇزچە",
+    "گېرمانچە",
+    "گىرېكچە",
+    "گۇئارانىچە",
+    "گۇجاراتچە",
+    "ھايتىچە",
+    "خائۇساچە",
+    "ئىبرانىيچە",
+    "خېرېروچە",
+    "ھىندىچە",
+    "ھىرى موتۇچە",
+    "ۋېنگىرچە",
+    "ئارىلىق تىل",
+    "ھىندونېزچە",
+    "ئىنتىرلىڭچە",
+    "ئىرېلاندچە",
+    "ئىگبوچە",
+    "ئىنۇپىكچە",
+    "ئىدوچە",
+    "ئىسلاندچە",
+    "ئىتالىيانچە",
+    "ئىنۇكتىتۇتچە",
+    "ياپونچە",
+    "ياۋاچە",
+    "گىرېنلاندچە",
+    "كانناداچە",
+    "كانۇرچە",
+    "كەشمىرچە",
+    "قازاقچە",
+    "كىمېرچە",
+    "كىكۇيۇچە",
+    "كېنىيەرىۋانداچە",
+    "قىرغىزچە",
+    "كومىچە",
+    "كونگوچە",
+    "كورېيەچە",
+    "كۇردچە",
+    "كىۋانياماچە",
+    "لاتىنچە",
+    "لىيۇكسېمبۇرگچە",
+    "گانداچە",
+    "لىمبۇرگچە",
+    "لىنگالاچە",
+    "لائوسچە",
+    "لىتۋانىچە",
+    "لۇبا-كاتانگاچە",
+    "لاتچە",
+    "مانچە",
+    "ماكېدونچە",
+    "مالاگاسچە",
+    "مالايچە",
+    "مالايالامچە",
+    "مالتاچە",
+    "ماۋرىچە",
+    "ماراتىچە",
+    "مارشالچە",
+    "موڭغۇلچە",
+    "ناۋرۇچە",
+    "ناۋاخوچە",
+    "شىمالى ندەبەلەچە",
+    "نېپالچە",
+    "ندونگاچە",
+    "نورۋىگىيە بوكمالچە",
+    "يېڭى نورۋېگچە",
+    "نورۋېگچە",
+    "يىچە (سىچۈەن)",
+    "جەنۇبى ندەبەلەچە",
+    "ئوكسىتچە",
+    "ئوجىبۋاچە",
+    "قەدىمكى سلاۋيانچە",
+    "ئوروموچە",
+    "ئودىياچە",
+    "ئوسسېتچەچە",
+    "پەنجابچە",
+    "پالىچە",
+    "پارسچە",
+    "پولەكچە",
+    "پۇشتۇچە",
+    "پورتۇگالچە",
+    "كېچىۋاچە",
+    "رومانسچە",
+    "رۇندىچە",
+    "رومىنچە",
+    "رۇسچە",
+    "سانسكرىتچە",
+    "ساردىنىيەچە",
+    "سىندىچە",
+    "شىمالىي سامىچە",
+    "ساموئاچە",
+    "سانگوچە",
+    "سېربچە",
+    "شوتلاندىيە گايلچىسى",
+    "شوناچە",
+    "سىنگالچە",
+    "سىلوۋاكچە",
+    "سىلوۋېنچە",
+    "سومالىچە",
+    "سوتوچە",
+    "ئىسپانچە",
+    "سۇنداچە",
+    "سىۋاھىلچە",
+    "سىۋاتىچە",
+    "شىۋېدچە",
+    "تامىلچە",
+    "تېلۇگۇچە",
+    "تاجىكچە",
+    "تايلاندچە",
+    "تىگرىنياچە",
+    "تىبەتچە",
+    "تۈركمەنچە",
+    "تاگالوگچە",
+    "سىۋاناچە",
+    "تونگانچە",
+    "تۈركچە",
+    "سونگاچە",
+    "تاتارچە",
+    "تىۋىچە",
+    "تاختىچە",
+    "ئۇيغۇرچە",
+    "ئۇكرائىنچە",
+    "ئوردۇچە",
+    "ئۆزبېكچە",
+    "ۋېنداچە",
+    "ۋىيېتنامچە",
+    "ۋولاپۇكچە",
+    "ۋاللۇنچە",
+    "ۋېلشچە",
+    "ۋولوفچە",
+    "غەربىي فىرسچە",
+    "خوساچە",
+    "يىددىشچە",
+    "يورۇباچە",
+    "جۇاڭچە",
+    "زۇلۇچە",
+];
+
+static UK: [&str; 184] = [
+    "абхазька",
+    "афарська",
+    "африкаанс",
+    "акан",
+    "албанська",
+    "амхарська",
+    "арабська",
+    "арагонська",
+    "вірменська",
+    "асамська",
+    "аварська",
+    "авестійська",
+    "аймара",
+    "азербайджанська",
+    "бамбара",
+    "башкирська",
+    "баскська",
+    "білоруська",
+    "бенгальська",
+    "",
+    "біслама",
+    "боснійська",
+    "бретонська",
+    "болгарська",
+    "бірманська",
+    "каталонська",
+    "чаморро",
+    "чеченська",
+    "ньянджа",
+    "китайська",
+    "чуваська",
+    "корнська",
+    "корсиканська",
+    "крі",
+    "хорватська",
+    "чеська",
+    "данська",
+    "дивехі",
+    "нідерландська",
+    "дзонг-ке",
+    "англійська",
+    "есперанто",
+    "естонська",
+    "еве",
+    "фарерська",
+    "фіджі",
+    "фінська",
+    "французька",
+    "фула",
+    "галісійська",
+    "грузинська",
+    "німецька",
+    "грецька",
+    "гуарані",
+    "гуджараті",
+    "гаїтянська креольська",
+    "хауса",
+    "іврит",
+    "гереро",
+    "гінді",
+    "хірі-моту",
+    "угорська",
+    "інтерлінгва",
+    "індонезійська",
+    "інтерлінгве",
+    "ірландська",
+    "ігбо",
+    "інупіак",
+    "ідо",
+    "ісландська",
+    "італійська",
+    "інуктитут",
+    "японська",
+    "яванська",
+    "калааллісут",
+    "каннада",
+    "канурі",
+    "кашмірська",
+    "казахська",
+    "кхмерська",
+    "кікуйю",
+    "кіньяруанда",
+    "киргизька",
+    "комі",
+    "конґолезька",
+    "корейська",
+    "курдська",
+    "кунама",
+    "латинська",
+    "люксембурзька",
+    "ганда",
+    "лімбургійська",
+    "лінгала",
+    "лаоська",
+    "литовська",
+    "луба-катанга",
+    "латиська",
+    "менкська",
+    "македонська",
+    "малагасійська",
+    "малайська",
+    "малаялам",
+    "мальтійська",
+    "маорі",
+    "маратхі",
+    "маршалльська",
+    "монгольська",
+    "науру",
+    "навахо",
+    "північна ндебеле",
+    "непальська",
+    "ндонга",
+    "норвезька (букмол)",
+    "норвезька (нюношк)",
+    "норвезька",
+    "сичуаньська ї",
+    "ндебелє південна",
+    "окситанська",
+    "оджібва",
+    "церковнословʼянська",
+    "оромо",
+    "одія",
+    "осетинська",
+    "панджабі",
+    "палі",
+    "перська",
+    "польська",
+    "пушту",
+    "португальська",
+    "кечуа",
+    "ретороманська",
+    "рунді",
+    "румунська",
+    "російська",
+    "санскрит",
+    "сардинська",
+    "синдхі",
+    "північносаамська",
+    "самоанська",
+    "санго",
+    "сербська",
+    "шотландська гельська",
+    "шона",
+    "сингальська",
+    "словацька",
+    "словенська",
+    "сомалі",
+    "південна сото",
+    "іспанська",
+    "сунданська",
+    "суахілі",
+    "сисваті",
+    "шведська",
+    "тамільська",
+    "телугу",
+    "таджицька",
+    "тайська",
+    "тигринья",
+    "тибетська",
+    "туркменська",
+    "тагальська",
+    "тсвана",
+    "тонганська",
+    "турецька",
+    "тсонга",
+    "татарська",
+    "тві",
+    "таїтянська",
+    "уйгурська",
+    "українська",
+    "урду",
+    "узбецька",
+    "венда",
+    "вʼєтнамська",
+    "волапюк",
+    "валлонська",
+    "валлійська",
+    "волоф",
+    "західнофризька",
+    "кхоса",
+    "їдиш",
+    "йоруба",
+    "чжуан",
+    "зулуська",
+];
+
+static UR: [&str; 184] = [
+    "ابقازیان",
+    "افار",
+    "افریقی",
+    "اکان",
+    "البانی",
+    "امہاری",
+    "عربی",
+    "اراگونیز",
+    "آرمینیائی",
+    "آسامی",
+    "اواری",
+    "",
+    "ایمارا",
+    "آذربائیجانی",
+    "بمبارا",
+    "باشکیر",
+    "باسکی",
+    "بیلاروسی",
+    "بنگلہ",
+    "",
+    "بسلاما",
+    "بوسنیائی",
+    "بریٹن",
+    "بلغاری",
+    "برمی",
+    "کیٹالان",
+    "چیمارو",
+    "چیچن",
+    "نیانجا",
+    "چینی",
+    "چوواش",
+    "کورنش",
+    "کوراسیکن",
+    "",
+    "کراتی",
+    "چیک",
+    "ڈینش",
+    "ڈیویہی",
+    "ڈچ",
+    "ژونگکھا",
+    "انگریزی",
+    "ایسپرانٹو",
+    "اسٹونین",
+    "ایو",
+    "فیروئیز",
+    "فجی",
+    "فینیش",
+    "فرانسیسی",
+    "فولہ",
+    "گالیشیائی",
+    "جارجیائی",
+    "جرمن",
+    "یونانی",
+    "گُارانی",
+    "گجراتی",
+    "ہیتی",
+    "ہؤسا",
+    "عبرانی",
+    "ہریرو",
+    "ہندی",
+    "",
+    "ہنگیرین",
+    "بین لسانیات",
+    "انڈونیثیائی",
+    "غربی",
+    "آئرش",
+    "اِگبو",
+    "",
+    "ایڈو",
+    "آئس لینڈک",
+    "اطالوی",
+    "اینُکٹیٹٹ",
+    "جاپانی",
+    "جاوی",
+    "کالاليست",
+    "کنّاڈا",
+    "کنوری",
+    "کشمیری",
+    "قزاخ",
+    "خمیر",
+    "کیکویو",
+    "کینیاروانڈا",
+    "کرغیزی",
+    "کومی",
+    "کانگو",
+    "کوریائی",
+    "کردش",
+    "کونیاما",
+    "لاطینی",
+    "لکسمبرگیش",
+    "گینڈا",
+    "لیمبرگش",
+    "لِنگَلا",
+    "لاؤ",
+    "لیتھوینین",
+    "لبا-کاتانجا",
+    "لیٹوین",
+    "مینکس",
+    "مقدونیائی",
+    "ملاگاسی",
+    "مالے",
+    "مالایالم",
+    "مالٹی",
+    "ماؤری",
+    "مراٹھی",
+    "مارشلیز",
+    "منگولین",
+    "ناؤرو",
+    "نواجو",
+    "شمالی دبیل",
+    "نیپالی",
+    "نڈونگا",
+    "نارویجین بوکمل",
+    "نارویجین نینورسک",
+    "نارویجین",
+    "سچوان ای",
+    "جنوبی نڈیبیلی",
+    "آکسیٹان",
+    "",
+    "چرچ سلاوک",
+    "اورومو",
+    "اڑیہ",
+    "اوسیٹک",
+    "پنجابی",
+    "",
+    "فارسی",
+    "پولش",
+    "پشتو",
+    "پُرتگالی",
+    "کویچوآ",
+    "رومانش",
+    "رونڈی",
+    "رومینین",
+    "روسی",
+    "سنسکرت",
+    "سردینین",
+    "سندھی",
+    "شمالی سامی",
+    "ساموآن",
+    "ساںغو",
+    "سربین",
+    "سکاٹش گیلک",
+    "شونا",
+    "سنہالا",
+    "سلوواک",
+    "سلووینیائی",
+    "صومالی",
+    "جنوبی سوتھو",
+    "ہسپانوی",
+    "سنڈانیز",
+    "سواحلی",
+    "سواتی",
+    "سویڈش",
+    "تمل",
+    "تیلگو",
+    "تاجک",
+    "تھائی",
+    "ٹگرینیا",
+    "تبتی",
+    "ترکمان",
+    "ٹیگا لوگ",
+    "سوانا",
+    "ٹونگن",
+    "ترکی",
+    "زونگا",
+    "تاتار",
+    "توی",
+    "تاہیتی",
+    "یوئگہر",
+    "یوکرینیائی",
+    "اردو",
+    "ازبیک",
+    "وینڈا",
+    "ویتنامی",
+    "وولاپوک",
+    "والون",
+    "ویلش",
+    "وولوف",
+    "مغربی فریسیئن",
+    "ژوسا",
+    "یدش",
+    "یوروبا",
+    "ژوانگی",
+    "زولو",
+];
+
+static UZ: [&str; 184] = [
+    "abxaz",
+    "afar",
+    "afrikaans",
+    "akan",
+    "alban",
+    "amxar",
+    "arab",
+    "aragon",
+    "arman",
+    "assam",
+    "avar",
+    "",
+    "aymara",
+    "ozarbayjon",
+    "bambara",
+    "boshqird",
+    "bask",
+    "belarus",
+    "bengal",
+    "",
+    "bislama",
+    "bosniy",
+    "breton",
+    "bolgar",
+    "birman",
+    "katalan",
+    "chamorro",
+    "chechen",
+    "cheva",
+    "xitoy",
+    "chuvash",
+    "korn",
+    "korsikan",
+    "",
+    "xorvat",
+    "chex",
+    "dan",
+    "divexi",
+    "niderland",
+    "dzongka",
+    "inglizcha",
+    "esperanto",
+    "estoncha",
+    "eve",
+    "farercha",
+    "fiji",
+    "fincha",
+    "fransuzcha",
+    "fula",
+    "galisiy",
+    "gruzincha",
+    "nemischa",
+    "grek",
+    "guarani",
+    "gujarot",
+    "gaityan",
+    "xausa",
+    "ivrit",
+    "gerero",
+    "hind",
+    "",
+    "venger",
+    "interlingva",
+    "indonez",
+    "interlingve",
+    "irland",
+    "igbo",
+    "",
+    "ido",
+    "island",
+    "italyan",
+    "inuktitut",
+    "yapon",
+    "yavan",
+    "grenland",
+    "kannada",
+    "kanuri",
+    "kashmircha",
+    "qozoqcha",
+    "xmer",
+    "kikuyu",
+    "kinyaruanda",
+    "qirgʻizcha",
+    "komi",
+    "",
+    "koreyscha",
+    "kurdcha",
+    "kvanyama",
+    "lotincha",
+    "lyuksemburgcha",
+    "ganda",
+    "limburg",
+    "lingala",
+    "laos",
+    "litva",
+    "luba-katanga",
+    "latishcha",
+    "men",
+    "makedon",
+    "malagasiy",
+    "malay",
+    "malayalam",
+    "maltiy",
+    "maori",
+    "maratxi",
+    "marshall",
+    "mongol",
+    "nauru",
+    "navaxo",
+    "shimoliy ndebele",
+    "nepal",
+    "ndonga",
+    "norveg-bokmal",
+    "norveg-nyunorsk",
+    "norveg",
+    "sichuan",
+    "janubiy ndebel",
+    "oksitan",
+    "",
+    "slavyan (cherkov)",
+    "oromo",
+    "oriya",
+    "osetin",
+    "panjobcha",
+    "",
+    "fors",
+    "polyakcha",
+    "pushtu",
+    "portugalcha",
+    "kechua",
+    "romansh",
+    "rundi",
+    "rumincha",
+    "ruscha",
+    "sanskrit",
+    "sardin",
+    "sindhi",
+    "shimoliy saam",
+    "samoa",
+    "sango",
+    "serbcha",
+    "shotland-gel",
+    "shona",
+    "singal",
+    "slovakcha",
+    "slovencha",
+    "somalicha",
+    "janubiy soto",
+    "ispancha",
+    "sundan",
+    "suaxili",
+    "svati",
+    "shved",
+    "tamil",
+    "telugu",
+    "tojik",
+    "tay",
+    "tigrinya",
+    "tibet",
+    "turkman",
+    "",
+    "tsvana",
+    "tongan",
+    "turk",
+    "tsonga",
+    "tatar",
+    "",
+    "taiti",
+    "uyg‘ur",
+    "ukrain",
+    "urdu",
+    "o‘zbek",
+    "venda",
+    "vyetnam",
+    "volapyuk",
+    "vallon",
+    "valliy",
+    "volof",
+    "g‘arbiy friz",
+    "kxosa",
+    "idish",
+    "yoruba",
+    "Chjuan",
+    "zulu",
+];
+
+static VI: [&str; 184] = [
+    "Tiếng Abkhazia",
+    "Tiếng Afar",
+    "Tiếng Hà Lan (Nam Phi)",
+    "Tiếng Akan",
+    "Tiếng Albania",
+    "Tiếng Amharic",
+    "Tiếng Ả Rập",
+    "Tiếng Aragon",
+    "Tiếng Armenia",
+    "Tiếng Assam",
+    "Tiếng Avaric",
+    "Tiếng Avestan",
+    "Tiếng Aymara",
+    "Tiếng Azerbaijan",
+    "Tiếng Bambara",
+    "Tiếng Bashkir",
+    "Tiếng Basque",
+    "Tiếng Belarus",
+    "Tiếng Bangla",
+    "",
+    "Tiếng Bislama",
+    "Tiếng Bosnia",
+    "Tiếng Breton",
+    "Tiếng Bulgaria",
+    "Tiếng Miến Điện",
+    "Tiếng Catalan",
+    "Tiếng Chamorro",
+    "Tiếng Chechen",
+    "Tiếng Nyanja",
+    "Tiếng Trung",
+    "Tiếng Chuvash",
+    "Tiếng Cornwall",
+    "Tiếng Corsica",
+    "Tiếng Cree",
+    "Tiếng Croatia",
+    "Tiếng Séc",
+    "Tiếng Đan Mạch",
+    "Tiếng Divehi",
+    "Tiếng Hà Lan",
+    "Tiếng Dzongkha",
+    "Tiếng Anh",
+    "Tiếng Quốc Tế Ngữ",
+    "Tiếng Estonia",
+    "Tiếng Ewe",
+    "Tiếng Faroe",
+    "Tiếng Fiji",
+    "Tiếng Phần Lan",
+    "Tiếng Pháp",
+    "Tiếng Fulah",
+    "Tiếng Galician",
+    "Tiếng Georgia",
+    "Tiếng Đức",
+    "Tiếng Hy Lạp",
+    "Tiếng Guarani",
+    "Tiếng Gujarati",
+    "Tiếng Haiti",
+    "Tiếng Hausa",
+    "Tiếng Do Thái",
+    "Tiếng Herero",
+    "Tiếng Hindi",
+    "Tiếng Hiri Motu",
+    "Tiếng Hungary",
+    "Tiếng Khoa Học Quốc Tế",
+    "Tiếng Indonesia",
+    "Tiếng Interlingue",
+    "Tiếng Ireland",
+    "Tiếng Igbo",
+    "Tiếng Inupiaq",
+    "Tiếng Ido",
+    "Tiếng Iceland",
+    "Tiếng Italy",
+    "Tiếng Inuktitut",
+    "Tiếng Nhật",
+    "Tiếng Java",
+    "Tiếng Kalaallisut",
+    "Tiếng Kannada",
+    "Tiếng Kanuri",
+    "Tiếng Kashmir",
+    "Tiếng Kazakh",
+    "Tiếng Khmer",
+    "Tiếng Kikuyu",
+    "Tiếng Kinyarwanda",
+    "Tiếng Kyrgyz",
+    "Tiếng Komi",
+    "Tiếng Kongo",
+    "Tiếng Hàn",
+    "Tiếng Kurd",
+    "Tiếng Kuanyama",
+    "Tiếng La-tinh",
+    "Tiếng Luxembourg",
+    "Tiếng Ganda",
+    "Tiếng Limburg",
+    "Tiếng Lingala",
+    "Tiếng Lào",
+    "Tiếng Litva",
+    "Tiếng Luba-Katanga",
+    "Tiếng Latvia",
+    "Tiếng Manx",
+    "Tiếng Macedonia",
+    "Tiếng Malagasy",
+    "Tiếng Mã Lai",
+    "Tiếng Malayalam",
+    "Tiếng Malta",
+    "Tiếng Māori",
+    "Tiếng Marathi",
+    "Tiếng Marshall",
+    "Tiếng Mông Cổ",
+    "Tiếng Nauru",
+    "Tiếng Navajo",
+    "Tiếng Ndebele Miền Bắc",
+    "Tiếng Nepal",
+    "Tiếng Ndonga",
+    "Tiếng Na Uy (Bokmål)",
+    "Tiếng Na Uy (Nynorsk)",
+    "Tiếng Na Uy",
+    "Tiếng Di Tứ Xuyên",
+    "Tiếng Ndebele Miền Nam",
+    "Tiếng Occitan",
+    "Tiếng Ojibwa",
+    "Tiếng Slavơ Nhà thờ",
+    "Tiếng Oromo",
+    "Tiếng Odia",
+    "Tiếng Ossetic",
+    "Tiếng Punjab",
+    "Tiếng Pali",
+    "Tiếng Ba Tư",
+    "Tiếng Ba Lan",
+    "Tiếng Pashto",
+    "Tiếng Bồ Đào Nha",
+    "Tiếng Quechua",
+    "Tiếng Romansh",
+    "Tiếng Rundi",
+    "Tiếng Romania",
+    "Tiếng Nga",
+    "Tiếng Phạn",
+    "Tiếng Sardinia",
+    "Tiếng Sindhi",
+    "Tiếng Sami Miền Bắc",
+    "Tiếng Samoa",
+    "Tiếng Sango",
+    "Tiếng Serbia",
+    "Tiếng Gael Scotland",
+    "Tiếng Shona",
+    "Tiếng Sinhala",
+    "Tiếng Slovak",
+    "Tiếng Slovenia",
+    "Tiếng Somali",
+    "Tiếng Sotho Miền Nam",
+    "Tiếng Tây Ban Nha",
+    "Tiếng Sunda",
+    "Tiếng Swahili",
+    "Tiếng Swati",
+    "Tiếng Thụy Điển",
+    "Tiếng Tamil",
+    "Tiếng Telugu",
+    "Tiếng Tajik",
+    "Tiếng Thái",
+    "Tiếng Tigrinya",
+    "Tiếng Tây Tạng",
+    "Tiếng Turkmen",
+    "Tiếng Tagalog",
+    "Tiếng Tswana",
+    "Tiếng Tonga",
+    "Tiếng Thổ Nhĩ Kỳ",
+    "Tiếng Tsonga",
+    "Tiếng Tatar",
+    "Tiếng Twi",
+    "Tiếng Tahiti",
+    "Tiếng Uyghur",
+    "Tiếng Ukraina",
+    "Tiếng Urdu",
+    "Tiếng Uzbek",
+    "Tiếng Venda",
+    "Tiếng Việt",
+    "Tiếng Volapük",
+    "Tiếng Walloon",
+    "Tiếng Wales",
+    "Tiếng Wolof",
+    "Tiếng Frisia",
+    "Tiếng Xhosa",
+    "Tiếng Yiddish",
+    "Tiếng Yoruba",
+    "Tiếng Choang",
+    "Tiếng Zulu",
+];
+
+static CY: [&str; 184] = [
+    "Abchaseg",
+    "Affareg",
+    "Affricaneg",
+    "Acaneg",
+    "Albaneg",
+    "Amhareg",
+    "Arabeg",
+    "Aragoneg",
+    "Armeneg",
+    "Asameg",
+    "Afareg",
+    "Afestaneg",
+    "Aymareg",
+    "Aserbaijaneg",
+    "Bambareg",
+    "Bashcorteg",
+    "Basgeg",
+    "Belarwseg",
+    "Bengaleg",
+    "",
+    "Bislama",
+    "Bosnieg",
+    "Llydaweg",
+    "Bwlgareg",
+    "Byrmaneg",
+    "Catalaneg",
+    "Tsiamorro",
+    "Tsietsieneg",
+    "Nianja",
+    "Tsieinëeg",
+    "Tshwfasheg",
+    "Cernyweg",
+    "Corseg",
+    "Cri",
+    "Croateg",
+    "Tsieceg",
+    "Daneg",
+    "Difehi",
+    "Iseldireg",
+    "Dzongkha",
+    "Saesneg",
+    "Esperanto",
+    "Estoneg",
+    "Ewe",
+    "Ffaröeg",
+    "Ffijïeg",
+    "Ffinneg",
+    "Ffrangeg",
+    "Ffwla",
+    "Galisieg",
+    "Georgeg",
+    "Almaeneg",
+    "Groeg",
+    "Guaraní",
+    "Gwjarati",
+    "Creol Haiti",
+    "Hawsa",
+    "Hebraeg",
+    "Herero",
+    "Hindi",
+    "",
+    "Hwngareg",
+    "Interlingua",
+    "Indoneseg",
+    "Interlingue",
+    "Gwyddeleg",
+    "Igbo",
+    "Inwpiaceg",
+    "Ido",
+    "Islandeg",
+    "Eidaleg",
+    "Inwctitwt",
+    "Japaneeg",
+    "Jafanaeg",
+    "Kalaallisut",
+    "Kannada",
+    "Canwri",
+    "Cashmireg",
+    "Casacheg",
+    "Chmereg",
+    "Kikuyu",
+    "Ciniarŵandeg",
+    "Cirgiseg",
+    "Comi",
+    "Congo",
+    "Coreeg",
+    "Cwrdeg",
+    "Kuanyama",
+    "Lladin",
+    "Lwcsembwrgeg",
+    "Ganda",
+    "Limbwrgeg",
+    "Lingala",
+    "Laoeg",
+    "Lithwaneg",
+    "Luba-Katanga",
+    "Latfieg",
+    "Manaweg",
+    "Macedoneg",
+    "Malagaseg",
+    "Maleieg",
+    "Malayalam",
+    "Malteg",
+    "Māori",
+    "Marathi",
+    "Marsialeg",
+    "Mongoleg",
+    "Nawrŵeg",
+    "Nafaho",
+    "Ndebele Gogleddol",
+    "Nepaleg",
+    "Ndonga",
+    "Norwyeg Bokmål",
+    "Norwyeg Nynorsk",
+    "Norwyeg",
+    "Nwosw",
+    "Ndebele Deheuol",
+    "Ocsitaneg",
+    "Ojibwa",
+    "Hen Slafoneg",
+    "Oromo",
+    "Odia",
+    "Oseteg",
+    "Pwnjabeg",
+    "Pali",
+    "Perseg",
+    "Pwyleg",
+    "Pashto",
+    "Portiwgaleg",
+    "Quechua",
+    "Románsh",
+    "Rwndi",
+    "Rwmaneg",
+    "Rwseg",
+    "Sansgrit",
+    "Sardeg",
+    "Sindhi",
+    "Sami Gogleddol",
+    "Samöeg",
+    "Sango",
+    "Serbeg",
+    "Gaeleg yr Alban",
+    "Shona",
+    "Sinhaleg",
+    "Slofaceg",
+    "Slofeneg",
+    "Somaleg",
+    "Sesotheg Deheuol",
+    "Sbaeneg",
+    "Swndaneg",
+    "Swahili",
+    "Swati",
+    "Swedeg",
+    "Tamileg",
+    "Telugu",
+    "Tajiceg",
+    "Thai",
+    "Tigrinya",
+    "Tibeteg",
+    "Tyrcmeneg",
+    "Tagalog",
+    "Tswana",
+    "Tongeg",
+    "Tyrceg",
+    "Tsongaeg",
+    "Tatareg",
+    "Twi",
+    "Tahitïeg",
+    "Uighur",
+    "Wcreineg",
+    "Wrdw",
+    "Wsbeceg",
+    "Fendeg",
+    "Fietnameg",
+    "Folapük",
+    "Walwneg",
+    "Cymraeg",
+    "Woloff",
+    "Ffriseg y Gorllewin",
+    "Xhosa",
+    "Iddew-Almaeneg",
+    "Iorwba",
+    "Zhuang",
+    "Swlw",
+];
+
+static WO: [&str; 184] = [
+    "",
+    "",
+    "Afrikaans",
+    "",
+    "Albane",
+    "Amharik",
+    "Arabic",
+    "",
+    "Armaniye",
+    "Asame",
+    "",
+    "",
+    "",
+    "Aserbayjane",
+    "",
+    "Baskir",
+    "Bask",
+    "Belaris",
+    "Baŋla",
+    "",
+    "",
+    "Bosñak",
+    "Breton",
+    "Bilgaar",
+    "Birmes",
+    "Katalan",
+    "",
+    "",
+    "Sewa",
+    "Sinuwaa",
+    "",
+    "",
+    "Kors",
+    "",
+    "Krowat",
+    "Cek",
+    "Danuwa",
+    "Diweyi",
+    "Neyerlànde",
+    "Dsongkaa",
+    "Àngale",
+    "Esperantoo",
+    "Estoñiye",
+    "",
+    "Feroos",
+    "",
+    "Feylànde",
+    "Farañse",
+    "Pël",
+    "Galisiye",
+    "Sorsiye",
+    "Almaa",
+    "Gereg",
+    "Garani",
+    "Gujarati",
+    "Kereyolu Ayti",
+    "Hawsa",
+    "Ebrë",
+    "Herero",
+    "Endo",
+    "",
+    "Ongruwaa",
+    "",
+    "Endonesiye",
+    "",
+    "Irlànde",
+    "Igbo",
+    "",
+    "",
+    "Islànde",
+    "Italiye",
+    "Inuktitit",
+    "Sapone",
+    "",
+    "",
+    "Kannadaa",
+    "Kanuri",
+    "Kashmiri",
+    "Kasax",
+    "Xmer",
+    "",
+    "Kinyarwànda",
+    "Kirgiis",
+    "",
+    "",
+    "Koreye",
+    "Kurdi",
+    "",
+    "Latin",
+    "Liksàmbursuwaa",
+    "",
+    "",
+    "",
+    "Laaw",
+    "Lituyaniye",
+    "",
+    "Letoniye",
+    "",
+    "Maseduwaane",
+    "Malagasi",
+    "Malay",
+    "Malayalam",
+    "Malt",
+    "Mawri",
+    "Marati",
+    "",
+    "Mongoliye",
+    "",
+    "",
+    "",
+    "Nepale",
+    "",
+    "",
+    "",
+    "Nerwesiye",
+    "",
+    "",
+    "Ositan",
+    "",
+    "",
+    "Oromo",
+    "Oja",
+    "",
+    "Punjabi",
+    "",
+    "Pers",
+    "Polone",
+    "Pasto",
+    "Purtugees",
+    "Kesuwa",
+    "Romaas",
+    "",
+    "Rumaniyee",
+    "Rus",
+    "Sanskrit",
+    "",
+    "Sindi",
+    "Penku Sami",
+    "",
+    "",
+    "Serb",
+    "Galuwaa bu Ekos",
+    "",
+    "Sinala",
+    "Eslowaki (Eslowak)",
+    "Esloweniye",
+    "Somali (làkk)",
+    "",
+    "Español",
+    "",
+    "",
+    "",
+    "Suweduwaa",
+    "Tamil",
+    "Telugu",
+    "Tajis",
+    "Tay",
+    "Tigriña",
+    "Tibetan",
+    "Tirkmen",
+    "",
+    "",
+    "Tongan",
+    "Tirk",
+    "",
+    "Tatar",
+    "",
+    "",
+    "Uygur",
+    "Ikreniye",
+    "Urdu",
+    "Usbek",
+    "Wenda",
+    "Wiyetnaamiye",
+    "",
+    "",
+    "Wels",
+    "Wolof",
+    "",
+    "",
+    "Yidis",
+    "Yoruba",
+    "",
+    "",
+];
+
+static FY: [&str; 184] = [
+    "Abchazysk",
+    "Afar",
+    "Afrikaansk",
+    "Akan",
+    "Albaneesk",
+    "Amhaarsk",
+    "Arabysk",
+    "Aragoneesk",
+    "Armeensk",
+    "Assameesk",
+    "Avarysk",
+    "Avestysk",
+    "Aymara",
+    "Azerbeidzjaansk",
+    "Bambara",
+    "Basjkiersk",
+    "Baskysk",
+    "Wyt-Russysk",
+    "Bengaalsk",
+    "",
+    "Bislama",
+    "Bosnysk",
+    "Bretonsk",
+    "Bulgaarsk",
+    "Birmees",
+    "Katalaansk",
+    "Chamorro",
+    "Tsjetsjeensk",
+    "Nyanja",
+    "Sineesk",
+    "Tsjoevasjysk",
+    "Cornish",
+    "Korsikaansk",
+    "Cree",
+    "Kroatysk",
+    "Tsjechysk",
+    "Deensk",
+    "Divehi",
+    "Nederlânsk",
+    "Dzongkha",
+    "Ingelsk",
+    "Esperanto",
+    "Estlânsk",
+    "Ewe",
+    "Faeröersk",
+    "Fijysk",
+    "Finsk",
+    "Frânsk",
+    "Fulah",
+    "Galisysk",
+    "Georgysk",
+    "Dútsk",
+    "Gryks",
+    "Guaraní",
+    "Gujarati",
+    "Haïtiaansk",
+    "Hausa",
+    "Hebreeuwsk",
+    "Herero",
+    "Hindi",
+    "Hiri Motu",
+    "Hongaarsk",
+    "Interlingua",
+    "Yndonezysk",
+    "Interlingue",
+    "Iersk",
+    "Igbo",
+    "Inupiaq",
+    "Ido",
+    "Yslâns",
+    "Italiaansk",
+    "Inuktitut",
+    "Japans",
+    "Javaansk",
+    "Grienlâns",
+    "Kannada",
+    "Kanuri",
+    "Kasjmiri",
+    "Kazachs",
+    "Khmer",
+    "Kikuyu",
+    "Kinyarwanda",
+    "Kirgizysk",
+    "Komi",
+    "Kongo",
+    "Koreaansk",
+    "Koerdysk",
+    "Kuanyama",
+    "Latyn",
+    "Luxemburgs",
+    "Ganda",
+    "Limburgs",
+    "Lingala",
+    "Laotiaansk",
+    "Litouws",
+    "Luba-Katanga",
+    "Letlâns",
+    "Manks",
+    "Macedonysk",
+    "Malagasysk",
+    "Maleis",
+    "Malayalam",
+    "Maltees",
+    "Maori",
+    "Marathi",
+    "Marshallees",
+    "Mongools",
+    "Nauruaansk",
+    "Navajo",
+    "Noard-Ndbele",
+    "Nepalees",
+    "Ndonga",
+    "Noors - Bokmål",
+    "Noors - Nynorsk",
+    "Noors",
+    "Sichuan Yi",
+    "Sûd-Ndbele",
+    "Occitaansk",
+    "Ojibwa",
+    "Kerkslavysk",
+    "Oromo",
+    "Odia",
+    "Ossetysk",
+    "Punjabi",
+    "Pali",
+    "Perzysk",
+    "Poalsk",
+    "Pasjtoe",
+    "Portugeesk",
+    "Quechua",
+    "Reto-Romaansk",
+    "Kirundi",
+    "Roemeensk",
+    "Russysk",
+    "Sanskriet",
+    "Sardinysk",
+    "Sindhi",
+    "Noard-Samysk",
+    "Samoaansk",
+    "Sango",
+    "Servysk",
+    "Schotsk Gaelic",
+    "Shona",
+    "Singalees",
+    "Slowaaks",
+    "Sloveensk",
+    "Somalysk",
+    "Sûd-Sotho",
+    "Spaansk",
+    "Soendaneesk",
+    "Swahili",
+    "Swazi",
+    "Zweeds",
+    "Tamil",
+    "Telugu",
+    "Tadzjieks",
+    "Thais",
+    "Tigrinya",
+    "Tibetaansk",
+    "Turkmeens",
+    "Tagalog",
+    "Tswana",
+    "Tongaansk",
+    "Turks",
+    "Tsonga",
+    "Tataars",
+    "Twi",
+    "Tahitysk",
+    "Oeigoers",
+    "Oekraïens",
+    "Urdu",
+    "Oezbeeks",
+    "Venda",
+    "Vietnamees",
+    "Volapük",
+    "Waals",
+    "Welsk",
+    "Wolof",
+    "Frysk",
+    "Xhosa",
+    "Jiddysk",
+    "Yoruba",
+    "Zhuang",
+    "Zulu",
+];
+
+static YO: [&str; 184] = [
+    "Èdè Abasia",
+    "",
+    "Èdè Afrikani",
+    "Èdè Akani",
+    "Èdè Albania",
+    "Èdè Amariki",
+    "Èdè Lárúbáwá",
+    "Èdè Aragoni",
+    "Èdè Armenia",
+    "Èdè Assam",
+    "Èdè Afariki",
+    "",
+    "Èdè Amara",
+    "Èdè Asabaijani",
+    "Èdè Báḿbàrà",
+    "Èdè Bashiri",
+    "Èdè Baski",
+    "Èdè Belarusi",
+    "Èdè Bengali",
+    "",
+    "Èdè Bisilama",
+    "Èdè Bosnia",
+    "Èdè Bretoni",
+    "Èdè Bugaria",
+    "Èdè Bumiisi",
+    "Èdè Katala",
+    "Èdè S̩amoro",
+    "Èdè Chechen",
+    "Ńyájà",
+    "Edè Ṣáínà",
+    "Èdè Ṣufasi",
+    "Èdè Kọ́nììṣì",
+    "Èdè Corsican",
+    "",
+    "Èdè Kroatia",
+    "Èdè Seeki",
+    "Èdè Denmaki",
+    "Èdè Difehi",
+    "Èdè Dọ́ọ̀ṣì",
+    "Èdè Dzongkha",
+    "Èdè Gẹ̀ẹ́sì",
+    "Èdè Esperanto",
+    "Èdè Estonia",
+    "Èdè Ewè",
+    "Èdè Faroesi",
+    "Èdè Fiji",
+    "Èdè Finisi",
+    "Èdè Faransé",
+    "Èdè Fúlàní",
+    "Èdè Galicia",
+    "Èdè Georgia",
+    "Èdè Jámánì",
+    "Èdè Giriki",
+    "Èdè Guarani",
+    "Èdè Gujarati",
+    "Haitian Creole",
+    "Èdè Hausa",
+    "Èdè Heberu",
+    "Èdè Herero",
+    "Èdè Híńdì",
+    "",
+    "Èdè Hungaria",
+    "Èdè pipo",
+    "Èdè Indonéṣíà",
+    "Èdè àtọwọ́dá",
+    "Èdè Ireland",
+    "Èdè Yíbò",
+    "",
+    "Èdè Ido",
+    "Èdè Icelandic",
+    "Èdè Ítálì",
+    "Èdè Inukitu",
+    "Èdè Jàpáànù",
+    "Èdè Javanasi",
+    "Kalaalísùtì",
+    "Èdè Kannada",
+    "Èdè Kanuri",
+    "Kaṣímirì",
+    "Kaṣakì",
+    "Èdè kameri",
+    "Kíkúyù",
+    "Èdè Ruwanda",
+    "Kírígíìsì",
+    "Èdè Komi",
+    "",
+    "Èdè Kòríà",
+    "Kọdiṣì",
+    "Èdè Kuayama",
+    "Èdè Latini",
+    "Lùṣẹ́mbọ́ọ̀gì",
+    "Ganda",
+    "Èdè Limbogishi",
+    "Lìǹgálà",
+    "Láò",
+    "Èdè Lithuania",
+    "Lúbà-Katanga",
+    "Èdè látífíànì",
+    "Máǹkì",
+    "Èdè Masidonia",
+    "Malagasì",
+    "Èdè Malaya",
+    "Málàyálámù",
+    "Èdè Malta",
+    "Màórì",
+    "Èdè marathi",
+    "Èdè Mashali",
+    "Mòngólíà",
+    "Èdè Nauru",
+    "Èdè Nafajo",
+    "Àríwá Ndebele",
+    "Èdè Nepali",
+    "Èdè Ndonga",
+    "Nọ́ọ́wè Bokímàl",
+    "Nọ́ọ́wè Nínọ̀sìkì",
+    "Èdè Norway",
+    "Ṣíkuán Yì",
+    "Èdè Gusu Ndebele",
+    "Èdè Ọ̀kísítáànì",
+    "",
+    "Èdè Síláfííkì Ilé Ìjọ́sìn",
+    "Òròmọ́",
+    "Èdè Òdíà",
+    "Ọṣẹ́tíìkì",
+    "Èdè Punjabi",
+    "",
+    "Èdè Pasia",
+    "Èdè Póláǹdì",
+    "Páshítò",
+    "Èdè Pọtogí",
+    "Kúẹ́ńjùà",
+    "Rómáǹṣì",
+    "Rúńdì",
+    "Èdè Romania",
+    "Èdè Rọ́ṣíà",
+    "Èdè awon ara Indo",
+    "Èdè Sadini",
+    "Èdè Sindhi",
+    "Apáàríwá Sami",
+    "Sámóánù",
+    "Sango",
+    "Èdè Serbia",
+    "Èdè Gaelik ti Ilu Scotland",
+    "Ṣọnà",
+    "Èdè Sinhalese",
+    "Èdè Slovaki",
+    "Èdè Slovenia",
+    "Èdè ara Somalia",
+    "Èdè Sesoto",
+    "Èdè Sípáníìṣì",
+    "Èdè Sudanísì",
+    "Èdè Swahili",
+    "Èdè Suwati",
+    "Èdè Suwidiisi",
+    "Èdè Tamili",
+    "Èdè Telugu",
+    "Èdè Tàjíìkì",
+    "Èdè Tai",
+    "Èdè Tigrinya",
+    "Tibetán",
+    "Èdè Turkmen",
+    "",
+    "Èdè Suwana",
+    "Tóńgàn",
+    "Èdè Tọọkisi",
+    "Èdè Songa",
+    "Tátárì",
+    "",
+    "Èdè Tahiti",
+    "Yúgọ̀",
+    "Èdè Ukania",
+    "Èdè Udu",
+    "Èdè Uzbek",
+    "Èdè Fenda",
+    "Èdè Jetinamu",
+    "Fọ́lápùùkù",
+    "Èdè Waluni",
+    "Èdè Welshi",
+    "Wọ́lọ́ọ̀fù",
+    "Èdè Frisia",
+    "Èdè Xhosa",
+    "Èdè Yiddishi",
+    "Èdè Yorùbá",
+    "Ṣúwáànù",
+    "Èdè Ṣulu",
+];
+
+static ZU: [&str; 184] = [
+    "isi-Abkhazian",
+    "isi-Afar",
+    "i-Afrikaans",
+    "isi-Akan",
+    "isi-Albania",
+    "isi-Amharic",
+    "isi-Arabic",
+    "isi-Aragonese",
+    "isi-Armenia",
+    "isi-Assamese",
+    "isi-Avaric",
+    "",
+    "isi-Aymara",
+    "isi-Azerbaijani",
+    "isi-Bambara",
+    "isi-Bashkir",
+    "isi-Basque",
+    "isi-Belarusian",
+    "isi-Bengali",
+    "",
+    "isi-Bislama",
+    "isi-Bosnian",
+    "isi-Breton",
+    "isi-Bulgari",
+    "isi-Burmese",
+    "isi-Catalan",
+    "isi-Chamorro",
+    "isi-Chechen",
+    "isi-Nyanja",
+    "isi-Chinese",
+    "isi-Chuvash",
+    "isi-Cornish",
+    "isi-Corsican",
+    "",
+    "isi-Croatian",
+    "isi-Czech",
+    "isi-Danish",
+    "isi-Divehi",
+    "isi-Dutch",
+    "isi-Dzongkha",
+    "i-English",
+    "isi-Esperanto",
+    "isi-Estonia",
+    "isi-Ewe",
+    "isi-Faroese",
+    "isi-Fijian",
+    "isi-Finnish",
+    "isi-French",
+    "isi-Fulah",
+    "isi-Galicia",
+    "isi-Georgian",
+    "isi-German",
+    "isi-Greek",
+    "isi-Guarani",
+    "isi-Gujarati",
+    "isi-Haitian",
+    "isi-Hausa",
+    "isi-Hebrew",
+    "isi-Herero",
+    "isi-Hindi",
+    "",
+    "isi-Hungarian",
+    "izilimi ezihlangene",
+    "isi-Indonesian",
+    "izimili",
+    "isi-Irish",
+    "isi-Igbo",
+    "",
+    "isi-Ido",
+    "isi-Icelandic",
+    "isi-Italian",
+    "isi-Inuktitut",
+    "isi-Japanese",
+    "isi-Javanese",
+    "isi-Kalaallisut",
+    "isi-Kannada",
+    "isi-Kanuri",
+    "isi-Kashmiri",
+    "isi-Kazakh",
+    "isi-Khmer",
+    "isi-Kikuyu",
+    "isi-Kinyarwanda",
+    "isi-Kyrgyz",
+    "isi-Komi",
+    "isi-Kongo",
+    "isi-Korean",
+    "isi-Kurdish",
+    "isi-Kuanyama",
+    "isi-Latin",
+    "isi-Luxembourgish",
+    "isi-Ganda",
+    "isi-Limburgish",
+    "isi-Lingala",
+    "isi-Lao",
+    "isi-Lithuanian",
+    "isi-Luba-Katanga",
+    "isi-Latvian",
+    "isi-Manx",
+    "isi-Macedonian",
+    "isi-Malagasy",
+    "isi-Malay",
+    "isi-Malayalam",
+    "isi-Maltese",
+    "isi-Maori",
+    "isi-Marathi",
+    "isi-Marshallese",
+    "isi-Mongolian",
+    "isi-Nauru",
+    "isi-Navajo",
+    "isi-North Ndebele",
+    "isi-Nepali",
+    "isi-Ndonga",
+    "isi-Norwegian Bokmål",
+    "isi-Norwegian Nynorsk",
+    "isi-Norwegian",
+    "isi-Sichuan Yi",
+    "isi-South Ndebele",
+    "isi-Occitan",
+    "",
+    "isi-Church Slavic",
+    "isi-Oromo",
+    "isi-Odia",
+    "isi-Ossetic",
+    "isi-Punjabi",
+    "",
+    "isi-Persian",
+    "isi-Polish",
+    "isi-Pashto",
+    "isi-Portuguese",
+    "isi-Quechua",
+    "isi-Romansh",
+    "isi-Rundi",
+    "isi-Romanian",
+    "isi-Russian",
+    "isi-Sanskrit",
+    "isi-Sardinian",
+    "isi-Sindhi",
+    "isi-Northern Sami",
+    "isi-Samoan",
+    "isi-Sango",
+    "isi-Serbian",
+    "isi-Scottish Gaelic",
+    "isiShona",
+    "isi-Sinhala",
+    "isi-Slovak",
+    "isi-Slovenian",
+    "isi-Somali",
+    "isi-Southern Sotho",
+    "isi-Spanish",
+    "isi-Sundanese",
+    "isiSwahili",
+    "isiSwati",
+    "isi-Swedish",
+    "isi-Tamil",
+    "isi-Telugu",
+    "isi-Tajik",
+    "isi-Thai",
+    "isi-Tigrinya",
+    "isi-Tibetan",
+    "isi-Turkmen",
+    "",
+    "isi-Tswana",
+    "isi-Tongan",
+    "isi-Turkish",
+    "isi-Tsonga",
+    "isi-Tatar",
+    "isi-Twi",
+    "isi-Tahitian",
+    "isi-Uighur",
+    "isi-Ukrainian",
+    "isi-Urdu",
+    "isi-Uzbek",
+    "isi-Venda",
+    "isi-Vietnamese",
+    "isi-Volapük",
+    "isi-Walloon",
+    "isi-Welsh",
+    "isi-Wolof",
+    "isi-Western Frisian",
+    "isiXhosa",
+    "isi-Yiddish",
+    "isi-Yoruba",
+    "IsiZhuang",
+    "isiZulu",
+];
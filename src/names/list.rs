@@ -0,0 +1,211 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::LanguageCode;
+
+/// A CLDR list pattern: the text before and after the last element it joins.
+type Pattern = (&'static str, &'static str);
+
+/// Joins language names into a list like "English, French, and German", using the CLDR "and"
+/// list patterns and [names](LanguageCode::name_in) of the display language.
+///
+/// Names CLDR doesn't have in the display language fall back to their English names, and display
+/// languages without list patterns join every name with `, `.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{format_language_list, LanguageCode};
+///
+/// let languages = [LanguageCode::En, LanguageCode::Fr, LanguageCode::De];
+/// assert_eq!(
+///     format_language_list(&languages, LanguageCode::En),
+///     "English, French, and German"
+/// );
+/// assert_eq!(
+///     format_language_list(&languages, LanguageCode::De),
+///     "Englisch, Französisch und Deutsch"
+/// );
+/// assert_eq!(
+///     format_language_list(&[LanguageCode::Fr, LanguageCode::It], LanguageCode::Es),
+///     "francés e italiano"
+/// );
+/// ```
+#[must_use]
+pub fn format_language_list(languages: &[LanguageCode], in_language: LanguageCode) -> String {
+    let [start, middle, end, pair] = patterns(in_language);
+    let names: Vec<&str> = languages
+        .iter()
+        .map(|language| language.name_in(in_language).unwrap_or(language.name()))
+        .collect();
+
+    let mut list = String::new();
+    let mut suffixes = Vec::new();
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            let pattern = match (names.len(), i) {
+                (2, _) => pair,
+                (len, i) if i == len - 1 => end,
+                (_, 1) => start,
+                _ => middle,
+            };
+            let (before, after) = special_case(in_language, pattern, name);
+            list.push_str(before);
+            suffixes.push(after);
+        }
+        list.push_str(name);
+    }
+    for suffix in suffixes.into_iter().rev() {
+        list.push_str(suffix);
+    }
+    list
+}
+
+/// Applies the contextual forms of conjunctions: Spanish uses "e" instead of "y" before an /i/
+/// sound, and Hebrew adds a hyphen to "ו" before a word not in Hebrew script.
+fn special_case(in_language: LanguageCode, pattern: Pattern, next: &str) -> Pattern {
+    match (in_language, pattern) {
+        (LanguageCode::Es, (" y ", "")) => {
+            let next = next.to_lowercase();
+            let mut chars = next.chars();
+            let i_sound = match chars.next() {
+                Some('i' | 'í') => true,
+                Some('h') => {
+                    matches!(chars.next(), Some('i' | 'í'))
+                        && !matches!(chars.next(), Some('a' | 'e' | 'o' | 'u'))
+                }
+                _ => false,
+            };
+            if i_sound {
+                (" e ", "")
+            } else {
+                pattern
+            }
+        }
+        (LanguageCode::He, (" ו", ""))
+            if !next
+                .chars()
+                .next()
+                .is_some_and(|c| ('\u{0590}'..='\u{05FF}').contains(&c)) =>
+        {
+            (" ו\u{2011}", "")
+        }
+        _ => pattern,
+    }
+}
+
+/// Returns the start, middle, end and two element patterns of a display language.
+#[rustfmt::skip]
+const fn patterns(in_language: LanguageCode) -> [Pattern; 4] {
+    match in_language {
+        LanguageCode::Af | LanguageCode::Fy | LanguageCode::Nl => [(", ", ""), (", ", ""), (" en ", ""), (" en ", "")],
+        LanguageCode::Ak => [(", ", ""), (", ", ""), (", ne ", ""), (" ne ", "")],
+        LanguageCode::Am => [("፣ ", ""), ("፣ ", ""), (" እና ", ""), (" እና ", "")],
+        LanguageCode::Ar => [(" و", ""), (" و", ""), (" و", ""), (" و", "")],
+        LanguageCode::As => [(", ", ""), (", ", ""), (" আৰু ", ""), (" আৰু ", "")],
+        LanguageCode::Az => [(", ", ""), (", ", ""), (" və ", ""), (" və ", "")],
+        LanguageCode::Be | LanguageCode::Uk => [(", ", ""), (", ", ""), (" і ", ""), (" і ", "")],
+        LanguageCode::Bg | LanguageCode::Mk | LanguageCode::Ru | LanguageCode::Sr => [(", ", ""), (", ", ""), (" и ", ""), (" и ", "")],
+        LanguageCode::Bn => [(", ", ""), (", ", ""), (" এবং ", ""), (" এবং ", "")],
+        LanguageCode::Br => [(", ", ""), (", ", ""), (" ha ", ""), (" ha ", "")],
+        LanguageCode::Bs | LanguageCode::Ca | LanguageCode::Hr | LanguageCode::Pl => [(", ", ""), (", ", ""), (" i ", ""), (" i ", "")],
+        LanguageCode::Cs => [(", ", ""), (", ", ""), (" a\u{A0}", ""), (" a\u{A0}", "")],
+        LanguageCode::Cv => [(", ", ""), (", ", ""), (" тата ", ""), (" тата ", "")],
+        LanguageCode::Cy => [(", ", ""), (", ", ""), (", a(c) ", ""), (" a(c) ", "")],
+        LanguageCode::Da | LanguageCode::Is | LanguageCode::Nb | LanguageCode::Nn | LanguageCode::No => [(", ", ""), (", ", ""), (" og ", ""), (" og ", "")],
+        LanguageCode::De => [(", ", ""), (", ", ""), (" und ", ""), (" und ", "")],
+        LanguageCode::Ee => [(", ", ""), (", ", ""), (", kple ", ""), (" kple ", "")],
+        LanguageCode::El => [(", ", ""), (", ", ""), (" και ", ""), (" και ", "")],
+        LanguageCode::En | LanguageCode::Ug => [(", ", ""), (", ", ""), (", and ", ""), (" and ", "")],
+        LanguageCode::Es => [(", ", ""), (", ", ""), (" y ", ""), (" y ", "")],
+        LanguageCode::Et | LanguageCode::Fi => [(", ", ""), (", ", ""), (" ja ", ""), (" ja ", "")],
+        LanguageCode::Eu => [(", ", ""), (", ", ""), (" eta ", ""), (" eta ", "")],
+        LanguageCode::Fa => [("،\u{200F} ", ""), ("،\u{200F} ", ""), ("، و ", ""), (" و ", "")],
+        LanguageCode::Fo => [(", ", ""), (", ", ""), (", og ", ""), (" og ", "")],
+        LanguageCode::Fr => [(", ", ""), (", ", ""), (" et ", ""), (" et ", "")],
+        LanguageCode::Ga | LanguageCode::Gd => [(", ", ""), (", ", ""), (" agus ", ""), (" agus ", "")],
+        LanguageCode::Gl | LanguageCode::Ia | LanguageCode::It | LanguageCode::Pt | LanguageCode::Rm | LanguageCode::Sc => [(", ", ""), (", ", ""), (" e ", ""), (" e ", "")],
+        LanguageCode::Gu => [(", ", ""), (", ", ""), (" અને ", ""), (" અને ", "")],
+        LanguageCode::Ha => [(", ", ""), (", ", ""), (", da ", ""), (" da ", "")],
+        LanguageCode::He => [(", ", ""), (", ", ""), (" ו", ""), (" ו", "")],
+        LanguageCode::Hi => [(", ", ""), (", ", ""), (", और ", ""), (" और ", "")],
+        LanguageCode::Hu => [(", ", ""), (", ", ""), (" és ", ""), (" és ", "")],
+        LanguageCode::Hy => [(", ", ""), (", ", ""), (" և ", ""), (" և ", "")],
+        LanguageCode::Id => [(", ", ""), (", ", ""), (", dan ", ""), (" dan ", "")],
+        LanguageCode::Ie => [(", ", ""), (", ", ""), (", ", ""), (" e ", "")],
+        LanguageCode::Ig => [(", ", ""), (", ", ""), (", na ", ""), (" na ", "")],
+        LanguageCode::Ii => [("、", ""), ("、", ""), ("ꌋꆀ", ""), ("ꌋꆀ", "")],
+        LanguageCode::Ja => [("、", ""), ("、", ""), ("、", ""), ("、", "")],
+        LanguageCode::Jv => [(", ", ""), (", ", ""), (", lan ", ""), (" lan ", "")],
+        LanguageCode::Ka => [(", ", ""), (", ", ""), (" და ", ""), (" და ", "")],
+        LanguageCode::Kk => [(", ", ""), (", ", ""), (", ", ""), (" және ", "")],
+        LanguageCode::Km => [(", ", ""), (", ", ""), (" និង ", ""), (" និង\u{200B}", "")],
+        LanguageCode::Kn => [(", ", ""), (", ", ""), (", ಮತ್ತು ", ""), (" ಮತ್ತು ", "")],
+        LanguageCode::Ko => [(", ", ""), (", ", ""), (" 및 ", ""), (" 및 ", "")],
+        LanguageCode::Ks => [("، ", ""), ("، ", ""), ("، تٕہ ", ""), (" تٕہ ", "")],
+        LanguageCode::Ku => [(", ", ""), (", ", ""), (" û ", ""), (" û ", "")],
+        LanguageCode::Ky => [(", ", ""), (", ", ""), (" жана ", ""), (" жана ", "")],
+        LanguageCode::Lb => [(", ", ""), (", ", ""), (" a(n) ", ""), (" a(n) ", "")],
+        LanguageCode::Lo => [(", ", ""), (", ", ""), (", ", ""), (" ແລະ ", "")],
+        LanguageCode::Lt => [(", ", ""), (", ", ""), (" ir ", ""), (" ir ", "")],
+        LanguageCode::Lv => [(", ", ""), (", ", ""), (" un ", ""), (" un ", "")],
+        LanguageCode::Ml => [(", ", ""), (", ", ""), (", ", " എന്നിവ"), (" കൂടാതെ ", "")],
+        LanguageCode::Mr => [(", ", ""), (", ", ""), (" आणि ", ""), (" आणि ", "")],
+        LanguageCode::Ms => [(", ", ""), (", ", ""), (" dan ", ""), (" dan ", "")],
+        LanguageCode::Mt => [(", ", ""), (", ", ""), (", u ", ""), (" u ", "")],
+        LanguageCode::My => [(" - ", ""), (" - ", ""), ("နှင့် ", ""), ("နှင့် ", "")],
+        LanguageCode::Ne => [(",", ""), (", ", ""), (" र ", ""), (" र ", "")],
+        LanguageCode::Or => [(", ", ""), (", ", ""), (", ଓ ", ""), (" ଓ ", "")],
+        LanguageCode::Pa => [(", ", ""), (", ", ""), (" ਅਤੇ ", ""), (" ਅਤੇ ", "")],
+        LanguageCode::Ps => [("، ", ""), ("، ", ""), ("، او ", ""), (" او ", "")],
+        LanguageCode::Ro => [(", ", ""), (", ", ""), (" și ", ""), (" și ", "")],
+        LanguageCode::Sa => [(", ", ""), (", ", ""), (", तथा ", ""), (" तथा ", "")],
+        LanguageCode::Sd => [("، ", ""), ("، ", ""), ("، ۽ ", ""), (" ۽ ", "")],
+        LanguageCode::Si => [(", ", ""), (", ", ""), (", සහ ", ""), (" සහ ", "")],
+        LanguageCode::Sk => [(", ", ""), (", ", ""), (" a ", ""), (" a\u{A0}", "")],
+        LanguageCode::Sl => [(", ", ""), (", ", ""), (" in ", ""), (" in ", "")],
+        LanguageCode::So => [(", ", ""), (", ", ""), (" iyo ", ""), (" iyo ", "")],
+        LanguageCode::Sq => [(", ", ""), (", ", ""), (" dhe ", ""), (" dhe ", "")],
+        LanguageCode::Su => [(", ", ""), (", ", ""), (", sareng ", ""), (" sareng ", "")],
+        LanguageCode::Sv => [(", ", ""), (", ", ""), (" och ", ""), (" och ", "")],
+        LanguageCode::Sw => [(", ", ""), (", ", ""), (" na ", ""), (" na ", "")],
+        LanguageCode::Ta => [(", ", ""), (", ", ""), (" மற்றும் ", ""), (" மற்றும் ", "")],
+        LanguageCode::Te => [(", ", ""), (", ", ""), (" మరియు ", ""), (" మరియు ", "")],
+        LanguageCode::Tg => [(", ", ""), (", ", ""), (", ", ""), (" ва ", "")],
+        LanguageCode::Th => [(" ", ""), (" ", ""), (" และ", ""), ("และ", "")],
+        LanguageCode::Ti => [("፣ ", ""), ("፣ ", ""), ("ን ", "ን"), ("ን ", "ን")],
+        LanguageCode::Tk => [(", ", ""), (", ", ""), (" we ", ""), (" we ", "")],
+        LanguageCode::To => [(" mo ", ""), (" mo ", ""), (" mo ", ""), (" mo ", "")],
+        LanguageCode::Tr => [(", ", ""), (", ", ""), (" ve ", ""), (" ve ", "")],
+        LanguageCode::Tt => [(", ", ""), (", ", ""), (" һәм ", ""), (" һәм ", "")],
+        LanguageCode::Ur => [("، ", ""), ("، ", ""), ("، اور ", ""), (" اور ", "")],
+        LanguageCode::Uz => [(", ", ""), (", ", ""), (" va ", ""), (" va ", "")],
+        LanguageCode::Vi => [(", ", ""), (", ", ""), (" và ", ""), (" và ", "")],
+        LanguageCode::Yo => [(", ", ""), (", ", ""), (", ", ""), (" àti", "")],
+        LanguageCode::Zh => [("、", ""), ("、", ""), ("和", ""), ("和", "")],
+        LanguageCode::Zu => [(", ", ""), (", ", ""), (", ne-", ""), (" ne-", "")],
+        _ => [(", ", ""), (", ", ""), (", ", ""), (", ", "")],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_language_list;
+    use crate::LanguageCode;
+
+    #[test]
+    fn lists() {
+        use LanguageCode::*;
+
+        assert_eq!(format_language_list(&[], En), "");
+        assert_eq!(format_language_list(&[Ja], En), "Japanese");
+        assert_eq!(format_language_list(&[En, Fr], En), "English and French");
+        assert_eq!(
+            format_language_list(&[De, Es, Fr, It], Fr),
+            "allemand, espagnol, français et italien"
+        );
+        assert_eq!(format_language_list(&[Es, Hi], Es), "español e hindi");
+        assert_eq!(format_language_list(&[Es, Hu], Es), "español y húngaro");
+        assert!(format_language_list(&[De, En, Fr], Ml).ends_with(" എന്നിവ"));
+    }
+}
@@ -0,0 +1,145 @@
+//! Language negotiation with [RFC 4647](https://www.rfc-editor.org/rfc/rfc4647) language ranges.
+//!
+//! The requested ranges are given in order of preference, such as the ranges of an
+//! `Accept-Language` header sorted by their quality values.
+//!
+//! # Examples
+//!
+//! ```
+//! use isolanguage_1::{negotiate, LanguageCode, LanguageRange, LanguageTag};
+//!
+//! let requested: Vec<LanguageRange> = ["fr-CH", "de-*", "*"]
+//!     .iter()
+//!     .map(|range| range.parse().unwrap())
+//!     .collect();
+//! let available: Vec<LanguageTag> = ["en", "de-AT", "fr"]
+//!     .iter()
+//!     .map(|tag| tag.parse().unwrap())
+//!     .collect();
+//!
+//! assert_eq!(negotiate::lookup(&requested, &available).unwrap().to_string(), "fr");
+//! assert_eq!(negotiate::filter(&requested, &available)[0].to_string(), "de-AT");
+//! assert_eq!(
+//!     negotiate::lookup_language(&requested, &[LanguageCode::En, LanguageCode::Fr]),
+//!     Some(LanguageCode::Fr)
+//! );
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::{LanguageCode, LanguageRange, LanguageTag};
+
+/// Returns the available tags matched by any of the requested ranges, using RFC 4647 extended
+/// filtering.
+///
+/// Tags matched by an earlier range come first; tags matched by the same range keep their order.
+#[must_use]
+pub fn filter<'a>(
+    requested: &[LanguageRange],
+    available: &'a [LanguageTag],
+) -> Vec<&'a LanguageTag> {
+    let mut matched = Vec::new();
+    for range in requested {
+        for tag in available {
+            if range.matches(tag) && !matched.contains(&tag) {
+                matched.push(tag);
+            }
+        }
+    }
+    matched
+}
+
+/// Returns the single available tag that best matches the requested ranges, using RFC 4647
+/// lookup.
+///
+/// Each range is progressively truncated until it equals an available tag, so `zh-Hant-TW` falls
+/// back to `zh-Hant` and then `zh`. Wildcards are ignored, and a `*` range matches nothing.
+#[must_use]
+pub fn lookup<'a>(
+    requested: &[LanguageRange],
+    available: &'a [LanguageTag],
+) -> Option<&'a LanguageTag> {
+    let available: Vec<(String, &LanguageTag)> = available
+        .iter()
+        .map(|tag| (tag.to_string().to_ascii_lowercase(), tag))
+        .collect();
+    requested
+        .iter()
+        .flat_map(LanguageRange::lookup_fallbacks)
+        .find_map(|fallback| {
+            available
+                .iter()
+                .find(|(tag, _)| *tag == fallback)
+                .map(|&(_, tag)| tag)
+        })
+}
+
+/// Returns the available language that best matches the requested ranges, using RFC 4647 lookup.
+#[must_use]
+pub fn lookup_language(
+    requested: &[LanguageRange],
+    available: &[LanguageCode],
+) -> Option<LanguageCode> {
+    requested
+        .iter()
+        .flat_map(LanguageRange::lookup_fallbacks)
+        .find_map(|fallback| {
+            available
+                .iter()
+                .copied()
+                .find(|language| language.code() == fallback)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{filter, lookup, lookup_language};
+    use crate::{LanguageCode, LanguageRange, LanguageTag};
+
+    fn ranges(ranges: &[&str]) -> Vec<LanguageRange> {
+        ranges.iter().map(|range| range.parse().unwrap()).collect()
+    }
+
+    fn tags(tags: &[&str]) -> Vec<LanguageTag> {
+        tags.iter().map(|tag| tag.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn filtering() {
+        let available = tags(&["en-GB", "de-CH", "de", "fr-CH"]);
+        let matched: Vec<String> = filter(&ranges(&["*-CH", "de"]), &available)
+            .iter()
+            .map(|tag| tag.to_string())
+            .collect();
+        assert_eq!(matched, ["de-CH", "fr-CH", "de"]);
+        assert!(filter(&ranges(&["ja"]), &available).is_empty());
+    }
+
+    #[test]
+    fn lookups() {
+        let available = tags(&["zh-Hant", "en"]);
+        assert_eq!(
+            lookup(&ranges(&["zh-Hant-TW", "en"]), &available).map(ToString::to_string),
+            Some("zh-Hant".to_owned())
+        );
+        assert_eq!(lookup(&ranges(&["*", "ja"]), &available), None);
+        assert_eq!(
+            lookup_language(
+                &ranges(&["nb-NO", "no"]),
+                &[LanguageCode::No, LanguageCode::Nb]
+            ),
+            Some(LanguageCode::Nb)
+        );
+        assert_eq!(lookup_language(&ranges(&["*"]), &[LanguageCode::En]), None);
+        assert_eq!(
+            lookup_language(&ranges(&["*-CH"]), &[LanguageCode::Ch, LanguageCode::De]),
+            None
+        );
+        assert_eq!(
+            lookup(&ranges(&["*-CH", "en"]), &tags(&["ch", "de-CH", "en"]))
+                .map(|tag| tag.to_string()),
+            Some("en".to_owned())
+        );
+    }
+}
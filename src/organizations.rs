@@ -0,0 +1,99 @@
+//! Official languages of international organizations.
+
+use crate::{LanguageCode, LanguageSet};
+
+/// The six official languages of the United Nations, in the order of
+/// [`LANGUAGE_CODES`](crate::LANGUAGE_CODES).
+pub const UN_LANGUAGES: [LanguageCode; 6] = [
+    LanguageCode::Ar,
+    LanguageCode::Zh,
+    LanguageCode::En,
+    LanguageCode::Fr,
+    LanguageCode::Ru,
+    LanguageCode::Es,
+];
+
+/// The 24 official languages of the European Union, in the order of
+/// [`LANGUAGE_CODES`](crate::LANGUAGE_CODES).
+pub const EU_LANGUAGES: [LanguageCode; 24] = [
+    LanguageCode::Bg,
+    LanguageCode::Hr,
+    LanguageCode::Cs,
+    LanguageCode::Da,
+    LanguageCode::Nl,
+    LanguageCode::En,
+    LanguageCode::Et,
+    LanguageCode::Fi,
+    LanguageCode::Fr,
+    LanguageCode::De,
+    LanguageCode::El,
+    LanguageCode::Hu,
+    LanguageCode::Ga,
+    LanguageCode::It,
+    LanguageCode::Lt,
+    LanguageCode::Lv,
+    LanguageCode::Mt,
+    LanguageCode::Pl,
+    LanguageCode::Pt,
+    LanguageCode::Ro,
+    LanguageCode::Sk,
+    LanguageCode::Sl,
+    LanguageCode::Es,
+    LanguageCode::Sv,
+];
+
+/// The languages of [`UN_LANGUAGES`], for checking membership.
+const UN: LanguageSet = LanguageSet::from_slice(&UN_LANGUAGES);
+
+impl LanguageCode {
+    /// Returns whether the language is one of the [official languages of the United
+    /// Nations](UN_LANGUAGES).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert!(LanguageCode::Ru.is_un_official());
+    /// assert!(!LanguageCode::De.is_un_official());
+    /// ```
+    #[must_use]
+    pub const fn is_un_official(self) -> bool {
+        UN.contains(self)
+    }
+
+    /// Returns whether the language is one of the [official languages of the European
+    /// Union](EU_LANGUAGES).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert!(LanguageCode::Ga.is_eu_official());
+    /// assert!(!LanguageCode::Ca.is_eu_official());
+    /// ```
+    #[must_use]
+    pub const fn is_eu_official(self) -> bool {
+        LanguageSet::EU_OFFICIAL.contains(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, LanguageSet, EU_LANGUAGES, UN_LANGUAGES};
+
+    #[test]
+    fn sorted() {
+        assert!(UN_LANGUAGES.windows(2).all(|w| w[0] < w[1]));
+        assert!(EU_LANGUAGES.windows(2).all(|w| w[0] < w[1]));
+        assert!(LanguageSet::EU_OFFICIAL.iter().eq(EU_LANGUAGES));
+        assert_eq!(
+            LanguageCode::iter()
+                .filter(|language| language.is_eu_official())
+                .count(),
+            24
+        );
+        assert!(LanguageCode::En.is_un_official() && LanguageCode::En.is_eu_official());
+    }
+}
@@ -0,0 +1,927 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+use core::iter::FusedIterator;
+use core::str::Split;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer};
+
+use crate::maps::{self, CODE_B_TO_LANGUAGE, CODE_TO_LANGUAGE, CODE_T_TO_LANGUAGE};
+use crate::{LanguageCode, ParseError, LANGUAGE_CODES};
+
+/// Withdrawn ISO 639-1 codes and the languages that replaced them.
+pub(crate) const LEGACY_CODES: [(&str, LanguageCode); 5] = [
+    ("in", LanguageCode::Id),
+    ("iw", LanguageCode::He),
+    ("ji", LanguageCode::Yi),
+    ("jw", LanguageCode::Jv),
+    ("mo", LanguageCode::Ro),
+];
+
+/// Quotation marks stripped by [`LanguageCode::parse_lenient`].
+const QUOTES: [char; 11] = ['"', '\'', '`', '‘', '’', '‚', '“', '”', '„', '«', '»'];
+
+/// Withdrawn ISO 639-2 codes and the languages that replaced them.
+pub(crate) const DEPRECATED_639_2_CODES: [(&str, LanguageCode); 4] = [
+    ("jaw", LanguageCode::Jv),
+    ("mol", LanguageCode::Ro),
+    ("scc", LanguageCode::Sr),
+    ("scr", LanguageCode::Hr),
+];
+
+/// ISO 639-2 collective codes, which identify groups of languages rather than a single language,
+/// and their names.
+#[rustfmt::skip]
+const COLLECTIVE_CODES: [(&str, &str); 65] = [
+    ("afa", "Afro-Asiatic languages"),
+    ("alg", "Algonquian languages"),
+    ("apa", "Apache languages"),
+    ("art", "Artificial languages"),
+    ("ath", "Athapascan languages"),
+    ("aus", "Australian languages"),
+    ("bad", "Banda languages"),
+    ("bai", "Bamileke languages"),
+    ("bat", "Baltic languages"),
+    ("ber", "Berber languages"),
+    ("bnt", "Bantu languages"),
+    ("btk", "Batak languages"),
+    ("cai", "Central American Indian languages"),
+    ("cau", "Caucasian languages"),
+    ("cel", "Celtic languages"),
+    ("cmc", "Chamic languages"),
+    ("cpe", "Creoles and pidgins, English based"),
+    ("cpf", "Creoles and pidgins, French-based"),
+    ("cpp", "Creoles and pidgins, Portuguese-based"),
+    ("crp", "Creoles and pidgins"),
+    ("cus", "Cushitic languages"),
+    ("day", "Land Dayak languages"),
+    ("dra", "Dravidian languages"),
+    ("fiu", "Finno-Ugrian languages"),
+    ("gem", "Germanic languages"),
+    ("him", "Himachali languages"),
+    ("ijo", "Ijo languages"),
+    ("inc", "Indic languages"),
+    ("ine", "Indo-European languages"),
+    ("ira", "Iranian languages"),
+    ("iro", "Iroquoian languages"),
+    ("kar", "Karen languages"),
+    ("khi", "Khoisan languages"),
+    ("kro", "Kru languages"),
+    ("map", "Austronesian languages"),
+    ("mkh", "Mon-Khmer languages"),
+    ("mno", "Manobo languages"),
+    ("mun", "Munda languages"),
+    ("myn", "Mayan languages"),
+    ("nah", "Nahuatl languages"),
+    ("nai", "North American Indian languages"),
+    ("nic", "Niger-Kordofanian languages"),
+    ("nub", "Nubian languages"),
+    ("oto", "Otomian languages"),
+    ("paa", "Papuan languages"),
+    ("phi", "Philippine languages"),
+    ("pra", "Prakrit languages"),
+    ("roa", "Romance languages"),
+    ("sai", "South American Indian languages"),
+    ("sal", "Salishan languages"),
+    ("sem", "Semitic languages"),
+    ("sgn", "Sign Languages"),
+    ("sio", "Siouan languages"),
+    ("sit", "Sino-Tibetan languages"),
+    ("sla", "Slavic languages"),
+    ("smi", "Sami languages"),
+    ("son", "Songhai languages"),
+    ("ssa", "Nilo-Saharan languages"),
+    ("tai", "Tai languages"),
+    ("tup", "Tupi languages"),
+    ("tut", "Altaic languages"),
+    ("wak", "Wakashan languages"),
+    ("wen", "Sorbian languages"),
+    ("ypk", "Yupik languages"),
+    ("znd", "Zande languages"),
+];
+
+/// Common alternative English names of languages, in addition to their ISO names.
+pub(crate) const ALIASES: [(&str, LanguageCode); 45] = [
+    ("Azeri", LanguageCode::Az),
+    ("Bangla", LanguageCode::Bn),
+    ("Bokmål", LanguageCode::Nb),
+    ("Castilian", LanguageCode::Es),
+    ("Chewa", LanguageCode::Ny),
+    ("Chuang", LanguageCode::Za),
+    ("Dhivehi", LanguageCode::Dv),
+    ("Farsi", LanguageCode::Fa),
+    ("Flemish", LanguageCode::Nl),
+    ("Frisian", LanguageCode::Fy),
+    ("Fula", LanguageCode::Ff),
+    ("Gikuyu", LanguageCode::Ki),
+    ("Greenlandic", LanguageCode::Kl),
+    ("Haitian Creole", LanguageCode::Ht),
+    ("Khmer", LanguageCode::Km),
+    ("Kirundi", LanguageCode::Rn),
+    ("Kwanyama", LanguageCode::Kj),
+    ("Kyrgyz", LanguageCode::Ky),
+    ("Laotian", LanguageCode::Lo),
+    ("Letzeburgesch", LanguageCode::Lb),
+    ("Limburgish", LanguageCode::Li),
+    ("Luganda", LanguageCode::Lg),
+    ("Maldivian", LanguageCode::Dv),
+    ("Modern Greek", LanguageCode::El),
+    ("Moldavian", LanguageCode::Ro),
+    ("Moldovan", LanguageCode::Ro),
+    ("Navaho", LanguageCode::Nv),
+    ("Nuosu", LanguageCode::Ii),
+    ("Nyanja", LanguageCode::Ny),
+    ("Nynorsk", LanguageCode::Nn),
+    ("Occidental", LanguageCode::Ie),
+    ("Odia", LanguageCode::Or),
+    ("Ojibwe", LanguageCode::Oj),
+    ("Old Church Slavonic", LanguageCode::Cu),
+    ("Ossetic", LanguageCode::Os),
+    ("Panjabi", LanguageCode::Pa),
+    ("Pushto", LanguageCode::Ps),
+    ("Scottish Gaelic", LanguageCode::Gd),
+    ("Sesotho", LanguageCode::St),
+    ("Setswana", LanguageCode::Tn),
+    ("Sinhalese", LanguageCode::Si),
+    ("Slovene", LanguageCode::Sl),
+    ("Swazi", LanguageCode::Ss),
+    ("Uyghur", LanguageCode::Ug),
+    ("Valencian", LanguageCode::Ca),
+];
+
+/// Returns the language with an ISO 639-2 T or B code.
+pub(crate) fn from_code_639_2(code: &str, case_insensitive: bool) -> Option<LanguageCode> {
+    if case_insensitive {
+        LANGUAGE_CODES.iter().copied().find(|language| {
+            language.code_t().eq_ignore_ascii_case(code)
+                || language.code_b().eq_ignore_ascii_case(code)
+        })
+    } else {
+        maps::get(&CODE_T_TO_LANGUAGE, code).or_else(|| maps::get(&CODE_B_TO_LANGUAGE, code))
+    }
+}
+
+impl LanguageCode {
+    /// Parses a two letter code like [`try_from`](Self::try_from), but also accepts the withdrawn
+    /// codes `in`, `iw`, `ji`, `jw` and `mo` as the languages that replaced them. This is a
+    /// shorthand for [`Parser::accept_legacy`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the code is neither a current nor a withdrawn ISO 639-1 code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_code_with_aliases("iw").unwrap(), LanguageCode::He);
+    /// assert_eq!(LanguageCode::from_code_with_aliases("mo").unwrap(), LanguageCode::Ro);
+    /// assert_eq!(LanguageCode::from_code_with_aliases("he").unwrap(), LanguageCode::He);
+    /// assert!(LanguageCode::try_from("iw").is_err());
+    /// ```
+    pub fn from_code_with_aliases(code: &str) -> Result<Self, ParseError> {
+        Parser::new().accept_legacy(true).parse(code)
+    }
+
+    /// Parses a two letter code from messy input, ignoring ASCII case, surrounding whitespace and
+    /// surrounding quotes, like `" FR "` or `'de'`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if what remains isn't a valid ISO 639-1 code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::parse_lenient(" fr ").unwrap(), LanguageCode::Fr);
+    /// assert_eq!(LanguageCode::parse_lenient("\"DE\"\n").unwrap(), LanguageCode::De);
+    /// assert_eq!(LanguageCode::parse_lenient("« Nl »").unwrap(), LanguageCode::Nl);
+    /// assert!(LanguageCode::parse_lenient("f r").is_err());
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self, ParseError> {
+        let code = s.trim_matches(|c: char| c.is_whitespace() || QUOTES.contains(&c));
+        Self::from_bytes_ignore_case(code.as_bytes())
+            .ok_or_else(|| ParseError::normalized(s, &code.to_ascii_lowercase()))
+    }
+
+    /// Parses a list of two letter codes separated by commas, like `"en, fr,de"`, trimming
+    /// whitespace around each code and skipping empty entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let languages: Result<Vec<_>, _> = LanguageCode::parse_list("en, fr,de,").collect();
+    /// assert_eq!(
+    ///     languages.unwrap(),
+    ///     [LanguageCode::En, LanguageCode::Fr, LanguageCode::De]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn parse_list(s: &str) -> ParseList<'_> {
+        Self::parse_list_with(s, &[','])
+    }
+
+    /// Parses a list of two letter codes like [`parse_list`](Self::parse_list), but split on any
+    /// of the given separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let mut languages = LanguageCode::parse_list_with("en;fr xx", &[';', ' ']);
+    /// assert_eq!(languages.next().unwrap().unwrap(), LanguageCode::En);
+    /// assert_eq!(languages.next().unwrap().unwrap(), LanguageCode::Fr);
+    /// assert!(languages.next().unwrap().is_err());
+    /// assert!(languages.next().is_none());
+    /// ```
+    #[must_use]
+    pub fn parse_list_with<'a>(s: &'a str, separators: &'a [char]) -> ParseList<'a> {
+        ParseList {
+            entries: s.split(separators),
+        }
+    }
+
+    /// Parses an ISO 639-2/T (terminological) code, the reverse of [`code_t`](Self::code_t).
+    ///
+    /// # Errors
+    ///
+    /// Fails if the code isn't exactly the T code of a language. B codes that differ from the T
+    /// code, like `dut`, are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_code_t("nld").unwrap(), LanguageCode::Nl);
+    /// assert!(LanguageCode::from_code_t("dut").is_err());
+    /// ```
+    pub fn from_code_t(code: &str) -> Result<Self, ParseCodeTError> {
+        maps::get(&CODE_T_TO_LANGUAGE, code).ok_or_else(|| ParseCodeTError {
+            code: code.to_owned(),
+        })
+    }
+
+    /// Parses an ISO 639-2/B (bibliographic) code, as used in MARC records, the reverse of
+    /// [`code_b`](Self::code_b).
+    ///
+    /// # Errors
+    ///
+    /// Fails if the code isn't exactly the B code of a language. T codes that differ from the B
+    /// code, like `nld`, are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_code_b("dut").unwrap(), LanguageCode::Nl);
+    /// assert_eq!(LanguageCode::from_code_b("chi").unwrap(), LanguageCode::Zh);
+    /// assert!(LanguageCode::from_code_b("nld").is_err());
+    /// ```
+    pub fn from_code_b(code: &str) -> Result<Self, ParseCodeBError> {
+        maps::get(&CODE_B_TO_LANGUAGE, code).ok_or_else(|| ParseCodeBError {
+            code: code.to_owned(),
+        })
+    }
+
+    /// Looks up a language by any of its codes: the 2 letter ISO 639-1 code, the ISO 639-2/T code
+    /// or the ISO 639-2/B code. The code must match exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_any("nl"), Some(LanguageCode::Nl));
+    /// assert_eq!(LanguageCode::from_any("nld"), Some(LanguageCode::Nl));
+    /// assert_eq!(LanguageCode::from_any("dut"), Some(LanguageCode::Nl));
+    /// assert_eq!(LanguageCode::from_any("Dutch"), None);
+    /// ```
+    #[must_use]
+    pub fn from_any(code: &str) -> Option<Self> {
+        match code.len() {
+            2 => maps::get(&CODE_TO_LANGUAGE, code),
+            3 => from_code_639_2(code, false),
+            _ => None,
+        }
+    }
+
+    /// Parses an ISO 639-2 T or B code, also accepting the withdrawn codes `jaw`, `mol`, `scc` and
+    /// `scr` and normalizing them to the languages that replaced them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, ParsedCode};
+    ///
+    /// assert_eq!(
+    ///     LanguageCode::from_code_639_2("scc"),
+    ///     Some(ParsedCode { language: LanguageCode::Sr, deprecated: true })
+    /// );
+    /// assert_eq!(
+    ///     LanguageCode::from_code_639_2("srp"),
+    ///     Some(ParsedCode { language: LanguageCode::Sr, deprecated: false })
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_code_639_2(code: &str) -> Option<ParsedCode> {
+        if let Some(language) = from_code_639_2(code, false) {
+            return Some(ParsedCode {
+                language,
+                deprecated: false,
+            });
+        }
+        DEPRECATED_639_2_CODES
+            .iter()
+            .find(|&&(deprecated, _)| deprecated == code)
+            .map(|&(_, language)| ParsedCode {
+                language,
+                deprecated: true,
+            })
+    }
+}
+
+/// An iterator over the languages of a list, returned by [`LanguageCode::parse_list`] and
+/// [`LanguageCode::parse_list_with`].
+#[derive(Debug, Clone)]
+pub struct ParseList<'a> {
+    entries: Split<'a, &'a [char]>,
+}
+
+impl Iterator for ParseList<'_> {
+    type Item = Result<LanguageCode, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries
+            .by_ref()
+            .map(str::trim)
+            .find(|entry| !entry.is_empty())
+            .map(LanguageCode::try_from)
+    }
+}
+
+impl FusedIterator for ParseList<'_> {}
+
+impl ParseError {
+    /// Creates an error for input that isn't a language, classifying it as is.
+    pub(crate) fn new(input: &str) -> Self {
+        Self::normalized(input, input)
+    }
+
+    /// Creates an error for input that isn't a language, classifying it by the form a parser
+    /// compared against the codes, like the input without surrounding whitespace.
+    pub(crate) fn normalized(input: &str, normalized: &str) -> Self {
+        Self {
+            language: input.to_owned(),
+            kind: ParseErrorKind::of(normalized),
+        }
+    }
+
+    /// Returns why the input isn't a valid two letter code, so that callers can react to each
+    /// kind of mistake differently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, ParseErrorKind};
+    ///
+    /// let kind = |s: &str| s.parse::<LanguageCode>().unwrap_err().kind();
+    /// assert_eq!(kind("English"), ParseErrorKind::WrongLength);
+    /// assert_eq!(kind("EN"), ParseErrorKind::InvalidCharacters);
+    /// assert_eq!(kind("xx"), ParseErrorKind::Unknown);
+    /// assert_eq!(
+    ///     kind("iw"),
+    ///     ParseErrorKind::Deprecated { replacement: LanguageCode::He }
+    /// );
+    /// assert_eq!(
+    ///     kind("eng"),
+    ///     ParseErrorKind::ThreeLetterCode { language: LanguageCode::En }
+    /// );
+    /// assert_eq!(
+    ///     kind("cel"),
+    ///     ParseErrorKind::CollectiveCode { name: "Celtic languages" }
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+}
+
+/// Why a [`ParseError`] occurred, returned by [`ParseError::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input isn't two bytes long.
+    WrongLength,
+    /// The input has characters other than lowercase ASCII letters.
+    InvalidCharacters,
+    /// The input looks like a code but isn't assigned to a language.
+    Unknown,
+    /// The input is a withdrawn code, like `iw`.
+    Deprecated {
+        /// The language that replaced the code.
+        replacement: LanguageCode,
+    },
+    /// The input is an ISO 639-2 T or B code instead of an ISO 639-1 code.
+    ThreeLetterCode {
+        /// The language of the code.
+        language: LanguageCode,
+    },
+    /// The input is an ISO 639-2 collective code, like `ber`, which identifies a group of
+    /// languages that has no ISO 639-1 code.
+    CollectiveCode {
+        /// The name of the group, like `Berber languages`.
+        name: &'static str,
+    },
+}
+
+impl ParseErrorKind {
+    /// Classifies input that isn't a language. Codes assigned to something else are recognized
+    /// regardless of ASCII case.
+    fn of(input: &str) -> Self {
+        let lowercase = input.to_ascii_lowercase();
+        let lowercase = lowercase.as_str();
+        if let Some(language) = from_code_639_2(lowercase, false) {
+            Self::ThreeLetterCode { language }
+        } else if let Ok(i) = COLLECTIVE_CODES.binary_search_by_key(&lowercase, |&(code, _)| code) {
+            Self::CollectiveCode {
+                name: COLLECTIVE_CODES[i].1,
+            }
+        } else if let Some(&(_, replacement)) =
+            LEGACY_CODES.iter().find(|&&(code, _)| code == lowercase)
+        {
+            Self::Deprecated { replacement }
+        } else if input.len() != 2 {
+            Self::WrongLength
+        } else if !input.bytes().all(|byte| byte.is_ascii_lowercase()) {
+            Self::InvalidCharacters
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// A language parsed from a code that may have been withdrawn from the standard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsedCode {
+    /// The language, or the language that replaced a withdrawn code.
+    pub language: LanguageCode,
+    /// Whether the code has been withdrawn.
+    pub deprecated: bool,
+}
+
+/// An error parsing a language from its ISO 639-2/T code.
+#[derive(Debug, Clone)]
+pub struct ParseCodeTError {
+    /// The code that could not be parsed.
+    pub code: String,
+}
+
+impl Display for ParseCodeTError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} is not a valid ISO 639-2/T language code", self.code)
+    }
+}
+
+/// An error parsing a language from its ISO 639-2/B code.
+#[derive(Debug, Clone)]
+pub struct ParseCodeBError {
+    /// The code that could not be parsed.
+    pub code: String,
+}
+
+impl Display for ParseCodeBError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} is not a valid ISO 639-2/B language code", self.code)
+    }
+}
+
+/// A configurable parser of languages, for inputs that are less strict than the 2 letter codes
+/// accepted by [`FromStr`](core::str::FromStr).
+///
+/// Every option is off by default, and the builder methods are `const` so that a parser can be
+/// stored in a `const` and used from a `#[serde(deserialize_with)]` function.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, Parser};
+///
+/// const PARSER: Parser = Parser::new()
+///     .case_insensitive(true)
+///     .accept_639_2(true)
+///     .accept_legacy(true)
+///     .accept_names(true)
+///     .strip_regions(true);
+///
+/// assert_eq!(PARSER.parse("DE").unwrap(), LanguageCode::De);
+/// assert_eq!(PARSER.parse("dut").unwrap(), LanguageCode::Nl);
+/// assert_eq!(PARSER.parse("iw").unwrap(), LanguageCode::He);
+/// assert_eq!(PARSER.parse("farsi").unwrap(), LanguageCode::Fa);
+/// assert_eq!(PARSER.parse("pt_BR").unwrap(), LanguageCode::Pt);
+/// assert!(Parser::new().parse("DE").is_err());
+/// ```
+///
+/// With serde:
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use isolanguage_1::{LanguageCode, Parser};
+/// use serde::{Deserialize, Deserializer};
+///
+/// fn lenient<'de, D: Deserializer<'de>>(deserializer: D) -> Result<LanguageCode, D::Error> {
+///     Parser::new().case_insensitive(true).deserialize(deserializer)
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(deserialize_with = "lenient")]
+///     language: LanguageCode,
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Parser {
+    case_insensitive: bool,
+    accept_639_2: bool,
+    accept_legacy: bool,
+    accept_names: bool,
+    strip_regions: bool,
+}
+
+impl Parser {
+    /// Creates a parser that only accepts 2 letter codes in lower case, like
+    /// [`FromStr`](core::str::FromStr).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            case_insensitive: false,
+            accept_639_2: false,
+            accept_legacy: false,
+            accept_names: false,
+            strip_regions: false,
+        }
+    }
+
+    /// Sets whether codes and names are matched ignoring ASCII case.
+    #[must_use]
+    pub const fn case_insensitive(mut self, yes: bool) -> Self {
+        self.case_insensitive = yes;
+        self
+    }
+
+    /// Sets whether ISO 639-2 T and B codes like `nld` and `dut` are accepted.
+    #[must_use]
+    pub const fn accept_639_2(mut self, yes: bool) -> Self {
+        self.accept_639_2 = yes;
+        self
+    }
+
+    /// Sets whether the withdrawn ISO 639-1 codes `in`, `iw`, `ji`, `jw` and `mo` are accepted as
+    /// the languages that replaced them. Together with [`accept_639_2`](Self::accept_639_2), this
+    /// also accepts the withdrawn ISO 639-2 codes `jaw`, `mol`, `scc` and `scr`.
+    #[must_use]
+    pub const fn accept_legacy(mut self, yes: bool) -> Self {
+        self.accept_legacy = yes;
+        self
+    }
+
+    /// Sets whether English names like `German`, and common aliases like `Farsi`, are accepted.
+    #[must_use]
+    pub const fn accept_names(mut self, yes: bool) -> Self {
+        self.accept_names = yes;
+        self
+    }
+
+    /// Sets whether anything after the first `-` or `_`, like the region of `en-US` or `en_US`, is
+    /// ignored.
+    #[must_use]
+    pub const fn strip_regions(mut self, yes: bool) -> Self {
+        self.strip_regions = yes;
+        self
+    }
+
+    /// Parses a language according to the options.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input isn't a language accepted by the options.
+    pub fn parse(&self, s: &str) -> Result<LanguageCode, ParseError> {
+        self.parse_whole(s)
+            .or_else(|| {
+                let (language, _) = s.split_once(['-', '_']).filter(|_| self.strip_regions)?;
+                self.parse_whole(language)
+            })
+            .ok_or_else(|| {
+                let normalized = match s.split_once(['-', '_']) {
+                    Some((language, _)) if self.strip_regions => language,
+                    _ => s,
+                };
+                if self.case_insensitive {
+                    ParseError::normalized(s, &normalized.to_ascii_lowercase())
+                } else {
+                    ParseError::normalized(s, normalized)
+                }
+            })
+    }
+
+    fn parse_whole(&self, s: &str) -> Option<LanguageCode> {
+        let eq = |a: &str| {
+            if self.case_insensitive {
+                a.eq_ignore_ascii_case(s)
+            } else {
+                a == s
+            }
+        };
+
+        if let Some(language) = LANGUAGE_CODES
+            .iter()
+            .copied()
+            .find(|language| eq(language.code()))
+        {
+            return Some(language);
+        }
+        if self.accept_639_2 {
+            if let Some(language) = from_code_639_2(s, self.case_insensitive) {
+                return Some(language);
+            }
+        }
+        if self.accept_legacy {
+            if let Some(&(_, language)) = LEGACY_CODES.iter().find(|(code, _)| eq(code)) {
+                return Some(language);
+            }
+            if self.accept_639_2 {
+                if let Some(&(_, language)) =
+                    DEPRECATED_639_2_CODES.iter().find(|(code, _)| eq(code))
+                {
+                    return Some(language);
+                }
+            }
+        }
+        if self.accept_names {
+            if let Some(language) = LANGUAGE_CODES
+                .iter()
+                .copied()
+                .find(|language| eq(language.name()))
+            {
+                return Some(language);
+            }
+            if let Some(&(_, language)) = ALIASES.iter().find(|(alias, _)| eq(alias)) {
+                return Some(language);
+            }
+        }
+        None
+    }
+
+    /// Deserializes a language from a string according to the options.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the value isn't a string or isn't a language accepted by the options.
+    #[cfg(feature = "serde")]
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        &self,
+        deserializer: D,
+    ) -> Result<LanguageCode, D::Error> {
+        self.parse(&String::deserialize(deserializer)?)
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        from_code_639_2, ParseErrorKind, Parser, ALIASES, COLLECTIVE_CODES, DEPRECATED_639_2_CODES,
+        LEGACY_CODES,
+    };
+    use crate::LanguageCode;
+
+    #[test]
+    fn aliases() {
+        for (code, language) in LEGACY_CODES {
+            assert_eq!(
+                LanguageCode::from_code_with_aliases(code).unwrap(),
+                language
+            );
+        }
+        for language in LanguageCode::iter() {
+            assert_eq!(
+                LanguageCode::from_code_with_aliases(language.code()).unwrap(),
+                language
+            );
+        }
+        assert!(LanguageCode::from_code_with_aliases("IW").is_err());
+        assert!(LanguageCode::from_code_with_aliases("heb").is_err());
+    }
+
+    #[test]
+    fn lenient() {
+        for input in ["en", " EN", "'en'", "\t\"En\" ", "“en”", "`eN`"] {
+            assert_eq!(
+                LanguageCode::parse_lenient(input).unwrap(),
+                LanguageCode::En
+            );
+        }
+        for input in ["", "\"\"", "e n", "eng"] {
+            assert!(LanguageCode::parse_lenient(input).is_err(), "{}", input);
+        }
+        assert_eq!(
+            LanguageCode::parse_lenient(" xx ").unwrap_err().language,
+            " xx "
+        );
+    }
+
+    #[test]
+    fn list() {
+        let languages: Vec<_> = LanguageCode::parse_list(" ja ,,ko, zh ")
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            languages,
+            [LanguageCode::Ja, LanguageCode::Ko, LanguageCode::Zh]
+        );
+        assert_eq!(LanguageCode::parse_list("").count(), 0);
+        assert_eq!(LanguageCode::parse_list(" , ").count(), 0);
+        let errors: Vec<_> = LanguageCode::parse_list_with("en|EN\tfr|xx", &['|', '\t'])
+            .filter_map(Result::err)
+            .map(|error| error.language)
+            .collect();
+        assert_eq!(errors, ["EN", "xx"]);
+    }
+
+    #[test]
+    fn error_kinds() {
+        let kind = |s: &str| LanguageCode::try_from(s).unwrap_err().kind();
+        assert_eq!(kind(""), ParseErrorKind::WrongLength);
+        assert_eq!(kind("e"), ParseErrorKind::WrongLength);
+        assert_eq!(kind("é"), ParseErrorKind::InvalidCharacters);
+        assert_eq!(kind("e1"), ParseErrorKind::InvalidCharacters);
+        assert_eq!(
+            kind("ENG"),
+            ParseErrorKind::ThreeLetterCode {
+                language: LanguageCode::En
+            }
+        );
+        assert_eq!(
+            kind("IW"),
+            ParseErrorKind::Deprecated {
+                replacement: LanguageCode::He
+            }
+        );
+        assert_eq!(kind("qq"), ParseErrorKind::Unknown);
+        assert_eq!(
+            kind("ber"),
+            ParseErrorKind::CollectiveCode {
+                name: "Berber languages"
+            }
+        );
+        assert_eq!(
+            kind("him"),
+            ParseErrorKind::CollectiveCode {
+                name: "Himachali languages"
+            }
+        );
+        assert!(COLLECTIVE_CODES
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+        for (code, _) in COLLECTIVE_CODES {
+            assert!(from_code_639_2(code, false).is_none());
+        }
+        for (code, replacement) in LEGACY_CODES {
+            assert_eq!(kind(code), ParseErrorKind::Deprecated { replacement });
+        }
+
+        // Errors from lenient parsers are classified by what was compared against the codes.
+        let error = LanguageCode::parse_lenient(" xx ").unwrap_err();
+        assert_eq!(error.language, " xx ");
+        assert_eq!(error.kind(), ParseErrorKind::Unknown);
+        let parser = Parser::new().strip_regions(true);
+        assert_eq!(
+            parser.parse("xx-US").unwrap_err().kind(),
+            ParseErrorKind::Unknown
+        );
+        assert_eq!(
+            Parser::new().parse("xx-US").unwrap_err().kind(),
+            ParseErrorKind::WrongLength
+        );
+        assert_eq!(
+            Parser::new()
+                .case_insensitive(true)
+                .parse("XX")
+                .unwrap_err()
+                .kind(),
+            ParseErrorKind::Unknown
+        );
+        for language in LanguageCode::iter() {
+            for code in [language.code_t(), language.code_b()] {
+                assert_eq!(kind(code), ParseErrorKind::ThreeLetterCode { language });
+            }
+        }
+    }
+
+    #[test]
+    fn code_t() {
+        for language in LanguageCode::iter() {
+            assert_eq!(
+                LanguageCode::from_code_t(language.code_t()).unwrap(),
+                language
+            );
+        }
+        let error = LanguageCode::from_code_t("NLD").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "NLD is not a valid ISO 639-2/T language code"
+        );
+    }
+
+    #[test]
+    fn code_b() {
+        for language in LanguageCode::iter() {
+            assert_eq!(
+                LanguageCode::from_code_b(language.code_b()).unwrap(),
+                language
+            );
+        }
+        assert!(LanguageCode::from_code_b("zho").is_err());
+    }
+
+    #[test]
+    fn from_any() {
+        for language in LanguageCode::iter() {
+            for code in [language.code(), language.code_t(), language.code_b()] {
+                assert_eq!(LanguageCode::from_any(code), Some(language));
+            }
+        }
+        assert_eq!(LanguageCode::from_any("NL"), None);
+        assert_eq!(LanguageCode::from_any(""), None);
+    }
+
+    #[test]
+    fn options() {
+        let parser = Parser::new();
+        assert_eq!(parser.parse("nl").unwrap(), LanguageCode::Nl);
+        for input in ["NL", "nld", "iw", "Dutch", "nl-BE"] {
+            assert!(parser.parse(input).is_err(), "{}", input);
+        }
+
+        assert_eq!(
+            parser.case_insensitive(true).parse("Nl").unwrap(),
+            LanguageCode::Nl
+        );
+        assert!(parser.accept_639_2(true).parse("NLD").is_err());
+        assert_eq!(
+            parser.accept_639_2(true).parse("dut").unwrap(),
+            LanguageCode::Nl
+        );
+        assert_eq!(
+            parser.accept_legacy(true).parse("mo").unwrap(),
+            LanguageCode::Ro
+        );
+        assert_eq!(
+            parser.accept_names(true).parse("Luba-Katanga").unwrap(),
+            LanguageCode::Lu
+        );
+        assert_eq!(
+            parser.strip_regions(true).parse("nl-BE").unwrap(),
+            LanguageCode::Nl
+        );
+        assert!(parser.strip_regions(true).parse("nl-").is_ok());
+        assert!(parser.strip_regions(true).parse("-nl").is_err());
+    }
+
+    #[test]
+    fn tables() {
+        for language in LanguageCode::iter() {
+            assert_eq!(from_code_639_2(language.code_t(), false), Some(language));
+            assert_eq!(from_code_639_2(language.code_b(), false), Some(language));
+        }
+        for (code, _) in LEGACY_CODES {
+            assert!(code.parse::<LanguageCode>().is_err());
+        }
+        for (code, language) in DEPRECATED_639_2_CODES {
+            assert_eq!(from_code_639_2(code, true), None);
+            let parsed = LanguageCode::from_code_639_2(code).unwrap();
+            assert_eq!((parsed.language, parsed.deprecated), (language, true));
+        }
+        let parser = Parser::new().accept_639_2(true);
+        assert!(parser.parse("scr").is_err());
+        assert_eq!(
+            parser.accept_legacy(true).parse("scr").unwrap(),
+            LanguageCode::Hr
+        );
+        let names = Parser::new().accept_names(true);
+        for (alias, _) in ALIASES {
+            assert!(LanguageCode::iter().all(|language| language.name() != alias));
+            assert!(names.parse(alias).is_ok());
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! The plural categories of each language, from the [CLDR](https://cldr.unicode.org/) plural
+//! rules. Enabled by the `cldr-plurals` feature.
+
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::LanguageCode;
+
+/// A CLDR plural category, which selects the form of a message for a number.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, PluralCategory};
+///
+/// assert_eq!(
+///     LanguageCode::En.plural_categories(),
+///     Some(&[PluralCategory::One, PluralCategory::Other][..])
+/// );
+/// assert_eq!(PluralCategory::Few.to_string(), "few");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum PluralCategory {
+    /// Used for zero in some languages, like Latvian.
+    Zero,
+    /// Used for one, and in some languages other numbers like 21.
+    One,
+    /// Used for two, and in some languages other numbers like 22.
+    Two,
+    /// Used for small numbers, like 2 to 4 in Czech.
+    Few,
+    /// Used for large numbers or fractions, like 5 to 20 in Russian.
+    Many,
+    /// Used for all other numbers. Every language has this category.
+    Other,
+}
+
+impl PluralCategory {
+    /// Returns the CLDR name of the category, like `one` or `other`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl Display for PluralCategory {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl LanguageCode {
+    /// Returns the categories the language's cardinal plural rules distinguish, in the order
+    /// `zero`, `one`, `two`, `few`, `many`, `other`, which are the message forms a translation into
+    /// it needs.
+    ///
+    /// Returns `None` for languages CLDR has no plural rules for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, PluralCategory};
+    ///
+    /// use PluralCategory::*;
+    /// assert_eq!(LanguageCode::Ja.plural_categories(), Some(&[Other][..]));
+    /// assert_eq!(
+    ///     LanguageCode::Pl.plural_categories(),
+    ///     Some(&[One, Few, Many, Other][..])
+    /// );
+    /// assert_eq!(LanguageCode::La.plural_categories(), None);
+    /// ```
+    #[must_use]
+    pub const fn plural_categories(self) -> Option<&'static [PluralCategory]> {
+        use PluralCategory::*;
+        match self {
+            Self::Bm
+            | Self::Bo
+            | Self::Dz
+            | Self::Id
+            | Self::Ig
+            | Self::Ii
+            | Self::Ja
+            | Self::Jv
+            | Self::Km
+            | Self::Ko
+            | Self::Lo
+            | Self::Ms
+            | Self::My
+            | Self::Sg
+            | Self::Su
+            | Self::Th
+            | Self::To
+            | Self::Vi
+            | Self::Wo
+            | Self::Yo
+            | Self::Zh => Some(&[Other]),
+            Self::Af
+            | Self::Ak
+            | Self::Am
+            | Self::An
+            | Self::As
+            | Self::Az
+            | Self::Bg
+            | Self::Bn
+            | Self::Ce
+            | Self::Da
+            | Self::De
+            | Self::Dv
+            | Self::Ee
+            | Self::El
+            | Self::En
+            | Self::Eo
+            | Self::Et
+            | Self::Eu
+            | Self::Fa
+            | Self::Ff
+            | Self::Fi
+            | Self::Fo
+            | Self::Fy
+            | Self::Gl
+            | Self::Gu
+            | Self::Ha
+            | Self::Hi
+            | Self::Hu
+            | Self::Hy
+            | Self::Ia
+            | Self::Ie
+            | Self::Io
+            | Self::Is
+            | Self::Ka
+            | Self::Kk
+            | Self::Kl
+            | Self::Kn
+            | Self::Ks
+            | Self::Ku
+            | Self::Ky
+            | Self::Lb
+            | Self::Lg
+            | Self::Ln
+            | Self::Mg
+            | Self::Mk
+            | Self::Ml
+            | Self::Mn
+            | Self::Mr
+            | Self::Nb
+            | Self::Nd
+            | Self::Ne
+            | Self::Nl
+            | Self::Nn
+            | Self::No
+            | Self::Nr
+            | Self::Ny
+            | Self::Om
+            | Self::Or
+            | Self::Os
+            | Self::Pa
+            | Self::Ps
+            | Self::Rm
+            | Self::Sc
+            | Self::Sd
+            | Self::Si
+            | Self::Sn
+            | Self::So
+            | Self::Sq
+            | Self::Ss
+            | Self::St
+            | Self::Sv
+            | Self::Sw
+            | Self::Ta
+            | Self::Te
+            | Self::Ti
+            | Self::Tk
+            | Self::Tl
+            | Self::Tn
+            | Self::Tr
+            | Self::Ts
+            | Self::Ug
+            | Self::Ur
+            | Self::Uz
+            | Self::Ve
+            | Self::Vo
+            | Self::Wa
+            | Self::Xh
+            | Self::Yi
+            | Self::Zu => Some(&[One, Other]),
+            Self::Cv | Self::Lv => Some(&[Zero, One, Other]),
+            Self::He | Self::Iu | Self::Se => Some(&[One, Two, Other]),
+            Self::Bs | Self::Hr | Self::Ro | Self::Sr => Some(&[One, Few, Other]),
+            Self::Ca | Self::Es | Self::Fr | Self::It | Self::Pt => Some(&[One, Many, Other]),
+            Self::Gd | Self::Sl => Some(&[One, Two, Few, Other]),
+            Self::Be | Self::Cs | Self::Lt | Self::Pl | Self::Ru | Self::Sk | Self::Uk => {
+                Some(&[One, Few, Many, Other])
+            }
+            Self::Br | Self::Ga | Self::Gv | Self::Mt => Some(&[One, Two, Few, Many, Other]),
+            Self::Ar | Self::Cy | Self::Kw => Some(&[Zero, One, Two, Few, Many, Other]),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, PluralCategory};
+
+    #[test]
+    fn plural_categories() {
+        for language in LanguageCode::iter() {
+            if let Some(categories) = language.plural_categories() {
+                assert!(categories.windows(2).all(|w| w[0] < w[1]), "{:?}", language);
+                assert_eq!(categories.last(), Some(&PluralCategory::Other));
+            }
+        }
+        assert_eq!(
+            LanguageCode::Ar.plural_categories().map(<[_]>::len),
+            Some(6)
+        );
+        assert_eq!(PluralCategory::Many.as_str(), "many");
+    }
+}
@@ -0,0 +1,53 @@
+use crate::LanguageCode;
+
+impl LanguageCode {
+    /// Returns the name of PostgreSQL's built-in full text search configuration for the language,
+    /// for use as the `regconfig` argument of functions like `to_tsvector`.
+    ///
+    /// Languages without a built-in configuration return `None`; those are usually indexed with
+    /// the `'simple'` configuration instead. The Armenian, Basque, Catalan, Hindi, Serbian and
+    /// Yiddish configurations were added in PostgreSQL 16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::Es.postgres_regconfig(), Some("spanish"));
+    /// assert_eq!(LanguageCode::Ja.postgres_regconfig(), None);
+    /// ```
+    #[must_use]
+    pub const fn postgres_regconfig(self) -> Option<&'static str> {
+        Some(match self {
+            Self::Ar => "arabic",
+            Self::Hy => "armenian",
+            Self::Eu => "basque",
+            Self::Ca => "catalan",
+            Self::Da => "danish",
+            Self::Nl => "dutch",
+            Self::En => "english",
+            Self::Fi => "finnish",
+            Self::Fr => "french",
+            Self::De => "german",
+            Self::El => "greek",
+            Self::Hi => "hindi",
+            Self::Hu => "hungarian",
+            Self::Id => "indonesian",
+            Self::Ga => "irish",
+            Self::It => "italian",
+            Self::Lt => "lithuanian",
+            Self::Ne => "nepali",
+            Self::No | Self::Nb | Self::Nn => "norwegian",
+            Self::Pt => "portuguese",
+            Self::Ro => "romanian",
+            Self::Ru => "russian",
+            Self::Sr => "serbian",
+            Self::Es => "spanish",
+            Self::Sv => "swedish",
+            Self::Ta => "tamil",
+            Self::Tr => "turkish",
+            Self::Yi => "yiddish",
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,279 @@
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{LanguageCode, LanguageTag};
+
+/// An [RFC 4647](https://www.rfc-editor.org/rfc/rfc4647) language range, like `*`, `en-US` or
+/// `zh-*-TW`.
+///
+/// Ranges are used to describe a set of acceptable languages, such as in an `Accept-Language`
+/// header or a configuration file. They can contain wildcards (`*`), which match any sequence of
+/// subtags.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, LanguageRange, LanguageTag};
+///
+/// let range: LanguageRange = "de-*-CH".parse().unwrap();
+/// assert!(range.matches(&"de-CH".parse().unwrap()));
+/// assert!(range.matches(&"de-Latn-CH".parse().unwrap()));
+/// assert!(!range.matches(&"de-DE".parse().unwrap()));
+///
+/// let range: LanguageRange = "en".parse().unwrap();
+/// assert!(range.matches(&"en-GB".parse().unwrap()));
+/// assert!(range.matches_language(LanguageCode::En));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LanguageRange {
+    /// The subtags of the range in lower case, where `*` is a wildcard.
+    subtags: Vec<String>,
+}
+
+impl LanguageRange {
+    /// The range `*`, which matches every language.
+    #[must_use]
+    pub fn any() -> Self {
+        Self {
+            subtags: vec!["*".to_owned()],
+        }
+    }
+
+    /// Returns whether this is a basic language range, one that only has a wildcard if it is
+    /// exactly `*`.
+    #[must_use]
+    pub fn is_basic(&self) -> bool {
+        self.subtags.len() == 1 || !self.subtags.iter().any(|subtag| subtag == "*")
+    }
+
+    /// Returns whether the range matches the tag, using RFC 4647 extended filtering.
+    ///
+    /// For basic language ranges, this is the same as basic filtering except that subtags of the
+    /// tag may be skipped, so that `de-DE` matches `de-Latn-DE`.
+    #[must_use]
+    pub fn matches(&self, tag: &LanguageTag) -> bool {
+        let tag = tag.to_string().to_ascii_lowercase();
+        let mut tag = tag.split('-');
+        let mut range = self.subtags.iter().map(String::as_str);
+
+        match (range.next(), tag.next()) {
+            (Some("*"), _) => {}
+            (Some(range), Some(tag)) if range == tag => {}
+            _ => return false,
+        }
+
+        for subtag in range {
+            if subtag == "*" {
+                continue;
+            }
+            loop {
+                match tag.next() {
+                    None => return false,
+                    Some(tag_subtag) if tag_subtag == subtag => break,
+                    Some(tag_subtag) if tag_subtag.len() == 1 => return false,
+                    Some(_) => {}
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns whether the range matches a tag consisting of only the language.
+    #[must_use]
+    pub fn matches_language(&self, language: LanguageCode) -> bool {
+        self.matches(&LanguageTag::new(language))
+    }
+
+    /// Returns the subtags of the range in lower case, where `*` is a wildcard.
+    pub fn subtags(&self) -> impl Iterator<Item = &str> {
+        self.subtags.iter().map(String::as_str)
+    }
+
+    /// Returns the ranges used for RFC 4647 lookup, from most to least specific: the range with
+    /// wildcards removed, then progressively truncated. A range whose language is a wildcard has
+    /// no fallbacks, as it matches nothing in lookup.
+    pub(crate) fn lookup_fallbacks(&self) -> Vec<String> {
+        if self.subtags.first().is_some_and(|subtag| subtag == "*") {
+            return Vec::new();
+        }
+        let mut subtags: Vec<&str> = self.subtags().filter(|&subtag| subtag != "*").collect();
+        let mut fallbacks = Vec::new();
+        while !subtags.is_empty() {
+            fallbacks.push(subtags.join("-"));
+            subtags.pop();
+            if subtags.last().is_some_and(|subtag| subtag.len() == 1) {
+                subtags.pop();
+            }
+        }
+        fallbacks
+    }
+}
+
+impl From<LanguageCode> for LanguageRange {
+    fn from(language: LanguageCode) -> Self {
+        Self {
+            subtags: vec![language.code().to_owned()],
+        }
+    }
+}
+
+impl From<&LanguageTag> for LanguageRange {
+    fn from(tag: &LanguageTag) -> Self {
+        Self {
+            subtags: tag
+                .to_string()
+                .to_ascii_lowercase()
+                .split('-')
+                .map(str::to_owned)
+                .collect(),
+        }
+    }
+}
+
+impl Display for LanguageRange {
+    /// Writes the range with the case conventions of language tags, like `zh-*-TW`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = String::new();
+        for (i, subtag) in self.subtags.iter().enumerate() {
+            if i > 0 {
+                s.push('-');
+            }
+            let alphabetic = subtag.bytes().all(|byte| byte.is_ascii_alphabetic());
+            if i > 0 && alphabetic && subtag.len() == 2 {
+                s.push_str(&subtag.to_ascii_uppercase());
+            } else if i > 0 && alphabetic && subtag.len() == 4 {
+                s.push_str(&subtag[..1].to_ascii_uppercase());
+                s.push_str(&subtag[1..]);
+            } else {
+                s.push_str(subtag);
+            }
+        }
+        f.pad(&s)
+    }
+}
+
+impl FromStr for LanguageRange {
+    type Err = ParseLanguageRangeError;
+
+    /// Parses a basic or extended language range, ignoring ASCII case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valid = s.split('-').enumerate().all(|(i, subtag)| {
+            subtag == "*"
+                || (1..=8).contains(&subtag.len())
+                    && if i == 0 {
+                        subtag.bytes().all(|byte| byte.is_ascii_alphabetic())
+                    } else {
+                        subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())
+                    }
+        });
+        if !valid {
+            return Err(ParseLanguageRangeError {
+                range: s.to_owned(),
+            });
+        }
+        Ok(Self {
+            subtags: s.split('-').map(str::to_ascii_lowercase).collect(),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for LanguageRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for LanguageRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// An error parsing a [`LanguageRange`].
+#[derive(Debug, Clone)]
+pub struct ParseLanguageRangeError {
+    /// The range that could not be parsed.
+    pub range: String,
+}
+
+impl Display for ParseLanguageRangeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} is not a valid language range", self.range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, LanguageRange, LanguageTag};
+
+    fn matches(range: &str, tag: &str) -> bool {
+        range
+            .parse::<LanguageRange>()
+            .unwrap()
+            .matches(&tag.parse::<LanguageTag>().unwrap())
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "ZH-*-tw".parse::<LanguageRange>().unwrap().to_string(),
+            "zh-*-TW"
+        );
+        assert_eq!(
+            "sr-latn".parse::<LanguageRange>().unwrap().to_string(),
+            "sr-Latn"
+        );
+        assert!("*".parse::<LanguageRange>().unwrap().is_basic());
+        assert!(!"*-CH".parse::<LanguageRange>().unwrap().is_basic());
+        assert!("".parse::<LanguageRange>().is_err());
+        assert!("en--US".parse::<LanguageRange>().is_err());
+        assert!("1-US".parse::<LanguageRange>().is_err());
+    }
+
+    #[test]
+    fn padding() {
+        let range: LanguageRange = "zh-*-tw".parse().unwrap();
+        assert_eq!(format!("[{:>9}]", range), "[  zh-*-TW]");
+    }
+
+    #[test]
+    fn filtering() {
+        assert!(matches("*", "ja"));
+        assert!(matches("en", "en"));
+        assert!(matches("en", "en-US"));
+        assert!(!matches("en-US", "en"));
+        assert!(!matches("en", "eo"));
+        assert!(matches("*-CH", "de-CH"));
+        assert!(matches("*-CH", "fr-Latn-CH"));
+        assert!(!matches("*-CH", "de-DE"));
+        assert!(matches("de-DE", "de-Latn-DE"));
+        assert!(matches("de-*-DE", "de-DE-1901"));
+        assert!(!matches("de-DE", "de-Latn"));
+        assert!(LanguageRange::any().matches_language(LanguageCode::Zu));
+    }
+
+    #[test]
+    fn lookup_fallbacks() {
+        let range: LanguageRange = "zh-Hant-CN-x-private1".parse().unwrap();
+        assert_eq!(
+            range.lookup_fallbacks(),
+            ["zh-hant-cn-x-private1", "zh-hant-cn", "zh-hant", "zh"]
+        );
+        assert!(LanguageRange::any().lookup_fallbacks().is_empty());
+        let range: LanguageRange = "*-CH".parse().unwrap();
+        assert!(range.lookup_fallbacks().is_empty());
+        let range: LanguageRange = "de-*-CH".parse().unwrap();
+        assert_eq!(range.lookup_fallbacks(), ["de-ch", "de"]);
+    }
+}
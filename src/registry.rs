@@ -0,0 +1,298 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::hash::{Hash, Hasher};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{LanguageCode, LanguageRange};
+
+/// The built-in languages together with custom languages registered at runtime, such as
+/// constructed languages or pseudo-locales.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{CustomLanguage, Language, LanguageCode, Registry};
+///
+/// let mut registry = Registry::new();
+/// let klingon = registry
+///     .register(CustomLanguage::new("tlh", "Klingon").with_metadata("script", "Latn"))
+///     .unwrap();
+///
+/// assert_eq!(registry.get("tlh"), Some(klingon.clone()));
+/// assert_eq!(registry.get("fr"), Some(Language::Builtin(LanguageCode::Fr)));
+/// assert_eq!(klingon.name(), "Klingon");
+/// assert_eq!(klingon.metadata("script"), Some("Latn"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    custom: Vec<Language>,
+}
+
+impl Registry {
+    /// Creates a registry with only the built-in languages.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { custom: Vec::new() }
+    }
+
+    /// Registers a custom language, returning its handle.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the code is not made of ASCII alphanumeric subtags separated by `-`, if it is any
+    /// code of a built-in language, like `en`, `eng` or `deu`, or if a custom language already has
+    /// it (ignoring ASCII case).
+    pub fn register(&mut self, language: CustomLanguage) -> Result<Language, RegisterError> {
+        let valid = language.code.split('-').all(|subtag| {
+            (1..=8).contains(&subtag.len())
+                && subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())
+        });
+        let builtin = LanguageCode::from_any(&language.code.to_ascii_lowercase()).is_some();
+        if !valid || builtin || self.get(&language.code).is_some() {
+            return Err(RegisterError {
+                code: language.code,
+            });
+        }
+        let language = Language::Custom(Arc::new(language));
+        self.custom.push(language.clone());
+        Ok(language)
+    }
+
+    /// Looks up a built-in or custom language by its code, ignoring ASCII case.
+    #[must_use]
+    pub fn get(&self, code: &str) -> Option<Language> {
+        if let Some(language) = LanguageCode::from_bytes_ignore_case(code.as_bytes()) {
+            return Some(Language::Builtin(language));
+        }
+        self.custom
+            .iter()
+            .find(|language| language.code().eq_ignore_ascii_case(code))
+            .cloned()
+    }
+
+    /// Iterates over the built-in languages followed by the custom languages, in the order they
+    /// were registered.
+    pub fn iter(&self) -> impl Iterator<Item = Language> + '_ {
+        LanguageCode::iter()
+            .map(Language::Builtin)
+            .chain(self.custom.iter().cloned())
+    }
+
+    /// Returns the available language that best matches the requested ranges, using RFC 4647
+    /// lookup like [`negotiate::lookup_language`](crate::negotiate::lookup_language).
+    #[must_use]
+    pub fn lookup(&self, requested: &[LanguageRange], available: &[Language]) -> Option<Language> {
+        requested
+            .iter()
+            .flat_map(LanguageRange::lookup_fallbacks)
+            .find_map(|fallback| {
+                available
+                    .iter()
+                    .find(|language| language.code().eq_ignore_ascii_case(&fallback))
+                    .cloned()
+            })
+    }
+
+    /// Deserializes a language from its code, looking it up in this registry.
+    ///
+    /// This can be used with `#[serde(deserialize_with)]` through a closure or wrapper function.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the value is not a string or the code isn't in the registry.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_language<'de, D: Deserializer<'de>>(
+        &self,
+        deserializer: D,
+    ) -> Result<Language, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        self.get(&code)
+            .ok_or_else(|| de::Error::custom(format_args!("unknown language code {}", code)))
+    }
+}
+
+/// A language registered at runtime with [`Registry::register`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomLanguage {
+    /// The language's code, like `tlh` or `en-XA`.
+    pub code: String,
+    /// The language's name.
+    pub name: String,
+    /// Arbitrary metadata about the language.
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl CustomLanguage {
+    /// Creates a custom language with no metadata.
+    #[must_use]
+    pub fn new(code: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            name: name.into(),
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a metadata entry.
+    #[must_use]
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// A handle to a built-in or custom language, obtained from a [`Registry`].
+///
+/// Handles are cheap to clone, and compare equal if their codes do. With the `serde` feature, they
+/// serialize as their code, and are deserialized with [`Registry::deserialize_language`].
+#[derive(Debug, Clone)]
+pub enum Language {
+    /// A built-in ISO 639-1 language.
+    Builtin(LanguageCode),
+    /// A custom language.
+    Custom(Arc<CustomLanguage>),
+}
+
+impl Language {
+    /// Returns the language's code.
+    #[must_use]
+    pub fn code(&self) -> &str {
+        match self {
+            Self::Builtin(language) => language.code(),
+            Self::Custom(language) => &language.code,
+        }
+    }
+
+    /// Returns the language's name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Builtin(language) => language.name(),
+            Self::Custom(language) => &language.name,
+        }
+    }
+
+    /// Returns a metadata entry of a custom language. Built-in languages have no metadata.
+    #[must_use]
+    pub fn metadata(&self, key: &str) -> Option<&str> {
+        match self {
+            Self::Builtin(_) => None,
+            Self::Custom(language) => language.metadata.get(key).map(String::as_str),
+        }
+    }
+
+    /// Returns the built-in language, or `None` if this is a custom language.
+    #[must_use]
+    pub fn builtin(&self) -> Option<LanguageCode> {
+        match *self {
+            Self::Builtin(language) => Some(language),
+            Self::Custom(_) => None,
+        }
+    }
+}
+
+impl From<LanguageCode> for Language {
+    fn from(language: LanguageCode) -> Self {
+        Self::Builtin(language)
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for Language {}
+
+impl Hash for Language {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code().hash(state);
+    }
+}
+
+impl Display for Language {
+    /// Writes the language's name.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Language {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// An error registering a custom language with an invalid or taken code.
+#[derive(Debug, Clone)]
+pub struct RegisterError {
+    /// The code of the language that could not be registered.
+    pub code: String,
+}
+
+impl Display for RegisterError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} is not a valid, unused language code", self.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CustomLanguage, Language, LanguageCode, LanguageRange, Registry};
+
+    #[test]
+    fn register() {
+        let mut registry = Registry::new();
+        let pseudo = registry
+            .register(CustomLanguage::new("en-XA", "Pseudo-English"))
+            .unwrap();
+        assert_eq!(registry.get("EN-xa"), Some(pseudo.clone()));
+        assert_eq!(pseudo.builtin(), None);
+        assert!(registry
+            .register(CustomLanguage::new("en-xa", "Duplicate"))
+            .is_err());
+        assert!(registry
+            .register(CustomLanguage::new("EN", "English"))
+            .is_err());
+        for code in ["eng", "DEU", "ger"] {
+            assert!(registry
+                .register(CustomLanguage::new(code, "Duplicate"))
+                .is_err());
+        }
+        assert!(registry
+            .register(CustomLanguage::new("x y", "Invalid"))
+            .is_err());
+        assert_eq!(registry.iter().count(), 185);
+
+        let available = [pseudo.clone(), Language::from(LanguageCode::En)];
+        let lookup =
+            |range: &str| registry.lookup(&[range.parse::<LanguageRange>().unwrap()], &available);
+        assert_eq!(lookup("en-XA"), Some(pseudo));
+        assert_eq!(lookup("en-US"), Some(Language::Builtin(LanguageCode::En)));
+        assert_eq!(lookup("fr"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let mut registry = Registry::new();
+        let klingon = registry
+            .register(CustomLanguage::new("tlh", "Klingon"))
+            .unwrap();
+        assert_eq!(serde_json::to_string(&klingon).unwrap(), r#""tlh""#);
+        let mut deserializer = serde_json::Deserializer::from_str(r#""tlh""#);
+        assert_eq!(
+            registry.deserialize_language(&mut deserializer).unwrap(),
+            klingon
+        );
+        let mut deserializer = serde_json::Deserializer::from_str(r#""xx""#);
+        assert!(registry.deserialize_language(&mut deserializer).is_err());
+    }
+}
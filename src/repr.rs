@@ -0,0 +1,240 @@
+//! Wrappers that (de)serialize and display a language in a particular representation.
+//!
+//! [`LanguageCode`]'s `Display` writes its name while its `FromStr` parses its code, so it doesn't
+//! round-trip through `serde_with::DisplayFromStr`. The wrappers display and parse the same
+//! representation, so they do. With the `serde_with` feature, they can also be used directly as
+//! `serde_as` adapters for `LanguageCode` fields:
+//!
+//! ```
+//! # #[cfg(feature = "serde_with")] {
+//! use isolanguage_1::{Code3B, LanguageCode};
+//! use serde::{Deserialize, Serialize};
+//! use serde_with::serde_as;
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize)]
+//! struct Book {
+//!     #[serde_as(as = "Code3B")]
+//!     language: LanguageCode,
+//! }
+//!
+//! let book = Book { language: LanguageCode::Nl };
+//! assert_eq!(serde_json::to_string(&book).unwrap(), r#"{"language":"dut"}"#);
+//! # }
+//! ```
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use alloc::string::String;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::maps::{
+    self, CODE_B_TO_LANGUAGE, CODE_TO_LANGUAGE, CODE_T_TO_LANGUAGE, NAME_TO_LANGUAGE,
+};
+use crate::{LanguageCode, ParseError};
+
+macro_rules! representations {
+    ($($(#[$attr:meta])* $name:ident($getter:ident, $map:ident),)*) => { $(
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(pub LanguageCode);
+
+        impl From<LanguageCode> for $name {
+            fn from(language: LanguageCode) -> Self {
+                Self(language)
+            }
+        }
+
+        impl From<$name> for LanguageCode {
+            fn from(wrapper: $name) -> Self {
+                wrapper.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.pad(self.0.$getter())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseError;
+
+            /// Parses the representation exactly, without ignoring case.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                maps::get(&$map, s)
+                    .map(Self)
+                    .ok_or_else(|| ParseError::new(s))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.0.$getter())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(de::Error::custom)
+            }
+        }
+
+        #[cfg(feature = "serde_with")]
+        impl serde_with::SerializeAs<LanguageCode> for $name {
+            fn serialize_as<S: Serializer>(
+                source: &LanguageCode,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                Self(*source).serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde_with")]
+        impl<'de> serde_with::DeserializeAs<'de, LanguageCode> for $name {
+            fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<LanguageCode, D::Error> {
+                Self::deserialize(deserializer).map(|wrapper| wrapper.0)
+            }
+        }
+    )* };
+}
+
+representations! {
+    /// A language represented by its 2 letter ISO 639-1 code, like `nl`.
+    ///
+    /// This is the same representation [`LanguageCode`] itself uses with serde, but its `Display`
+    /// implementation writes the code instead of the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{Code2, LanguageCode};
+    ///
+    /// assert_eq!(Code2(LanguageCode::Nl).to_string(), "nl");
+    /// assert_eq!("nl".parse::<Code2>().unwrap(), Code2(LanguageCode::Nl));
+    /// ```
+    Code2(code, CODE_TO_LANGUAGE),
+
+    /// A language represented by its ISO 639-2 T code, like `nld`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{Code3T, LanguageCode};
+    ///
+    /// assert_eq!(Code3T(LanguageCode::Nl).to_string(), "nld");
+    /// assert_eq!("nld".parse::<Code3T>().unwrap(), Code3T(LanguageCode::Nl));
+    /// assert!("dut".parse::<Code3T>().is_err());
+    /// ```
+    Code3T(code_t, CODE_T_TO_LANGUAGE),
+
+    /// A language represented by its ISO 639-2 B code, like `dut`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{Code3B, LanguageCode};
+    ///
+    /// assert_eq!(Code3B(LanguageCode::Nl).to_string(), "dut");
+    /// assert_eq!("dut".parse::<Code3B>().unwrap(), Code3B(LanguageCode::Nl));
+    /// ```
+    Code3B(code_b, CODE_B_TO_LANGUAGE),
+
+    /// A language represented by its ISO name, like `Dutch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, Name};
+    ///
+    /// assert_eq!(Name(LanguageCode::Nl).to_string(), "Dutch");
+    /// assert_eq!("Dutch".parse::<Name>().unwrap(), Name(LanguageCode::Nl));
+    /// ```
+    Name(name, NAME_TO_LANGUAGE),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Code2, Code3B, Code3T, LanguageCode, Name};
+
+    #[test]
+    fn round_trip() {
+        for language in LanguageCode::iter() {
+            assert_eq!(
+                Code2(language).to_string().parse::<Code2>().unwrap().0,
+                language
+            );
+            assert_eq!(
+                Code3T(language).to_string().parse::<Code3T>().unwrap().0,
+                language
+            );
+            assert_eq!(
+                Code3B(language).to_string().parse::<Code3B>().unwrap().0,
+                language
+            );
+            assert_eq!(
+                Name(language).to_string().parse::<Name>().unwrap().0,
+                language
+            );
+        }
+    }
+
+    #[test]
+    fn padding() {
+        assert_eq!(format!("[{:^5}]", Code2(LanguageCode::Nl)), "[ nl  ]");
+        assert_eq!(format!("[{:*<5}]", Code3B(LanguageCode::Nl)), "[dut**]");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        use std::collections::BTreeMap;
+
+        let map: BTreeMap<Code3B, Name> =
+            [(Code3B(LanguageCode::Zh), Name(LanguageCode::Zh))].into();
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"chi":"Chinese"}"#);
+        assert_eq!(
+            serde_json::from_str::<BTreeMap<Code3B, Name>>(&json).unwrap(),
+            map
+        );
+        assert!(serde_json::from_str::<Code3T>(r#""chi""#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde_with")]
+    fn serde_with() {
+        use serde::{Deserialize, Serialize};
+        use serde_with::{serde_as, DisplayFromStr};
+
+        #[serde_as]
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            #[serde_as(as = "DisplayFromStr")]
+            display: Code2,
+            #[serde_as(as = "Name")]
+            name: LanguageCode,
+            #[serde_as(as = "Vec<Code3T>")]
+            list: Vec<LanguageCode>,
+        }
+
+        let record = Record {
+            display: Code2(LanguageCode::Ja),
+            name: LanguageCode::De,
+            list: vec![LanguageCode::Fr, LanguageCode::Nl],
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            json,
+            r#"{"display":"ja","name":"German","list":["fra","nld"]}"#
+        );
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+    }
+}
@@ -0,0 +1,114 @@
+//! Sample sentences in each language, for testing fonts and text shaping. Enabled by the
+//! `sample-text` feature.
+
+use crate::LanguageCode;
+
+impl LanguageCode {
+    /// Returns the first sentence of Article 1 of the Universal Declaration of Human Rights in the
+    /// language, written in its [primary script](Self::script), or `None` if no translation is
+    /// included.
+    ///
+    /// For languages without a sample, the [autonym](Self::autonym) is the next best text to render.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(
+    ///     LanguageCode::En.sample_text(),
+    ///     Some("All human beings are born free and equal in dignity and rights.")
+    /// );
+    /// assert_eq!(LanguageCode::Zh.sample_text(), Some("人人生而自由，在尊严和权利上一律平等。"));
+    /// assert_eq!(LanguageCode::Aa.sample_text(), None);
+    /// ```
+    #[must_use]
+    #[rustfmt::skip]
+    pub const fn sample_text(self) -> Option<&'static str> {
+        Some(match self {
+            Self::Af => "Alle menslike wesens word vry, met gelyke waardigheid en regte, gebore.",
+            Self::Ar => "يولد جميع الناس أحرارًا متساوين في الكرامة والحقوق.",
+            Self::Be => "Усе людзі нараджаюцца свабоднымі і роўнымі ў сваёй годнасці і правах.",
+            Self::Bg => "Всички хора се раждат свободни и равни по достойнство и права.",
+            Self::Bn => "সমস্ত মানুষ স্বাধীনভাবে সমান মর্যাদা এবং অধিকার নিয়ে জন্মগ্রহণ করে।",
+            Self::Ca => "Tots els éssers humans neixen lliures i iguals en dignitat i en drets.",
+            Self::Cs => "Všichni lidé rodí se svobodní a sobě rovní co do důstojnosti a práv.",
+            Self::Cy => "Genir pawb yn rhydd ac yn gydradd â'i gilydd mewn urddas a hawliau.",
+            Self::Da => "Alle mennesker er født frie og lige i værdighed og rettigheder.",
+            Self::De => "Alle Menschen sind frei und gleich an Würde und Rechten geboren.",
+            Self::El => "Όλοι οι άνθρωποι γεννιούνται ελεύθεροι και ίσοι στην αξιοπρέπεια και τα δικαιώματα.",
+            Self::En => "All human beings are born free and equal in dignity and rights.",
+            Self::Eo => "Ĉiuj homoj estas denaske liberaj kaj egalaj laŭ digno kaj rajtoj.",
+            Self::Es => "Todos los seres humanos nacen libres e iguales en dignidad y derechos.",
+            Self::Et => "Kõik inimesed sünnivad vabadena ja võrdsetena oma väärikuselt ja õigustelt.",
+            Self::Eu => "Gizon-emakume guztiak aske jaiotzen dira, duintasun eta eskubide berberak dituztela.",
+            Self::Fa => "تمام افراد بشر آزاد به دنیا می‌آیند و از لحاظ حیثیت و حقوق با هم برابرند.",
+            Self::Fi => "Kaikki ihmiset syntyvät vapaina ja tasavertaisina arvoltaan ja oikeuksiltaan.",
+            Self::Fr => "Tous les êtres humains naissent libres et égaux en dignité et en droits.",
+            Self::Ga => "Saolaítear na daoine uile saor agus comhionann ina ndínit agus ina gcearta.",
+            Self::Gl => "Tódolos seres humanos nacen libres e iguais en dignidade e dereitos.",
+            Self::He => "כל בני האדם נולדו בני חורין ושווים בערכם ובזכויותיהם.",
+            Self::Hi => "सभी मनुष्यों को गौरव और अधिकारों के मामले में जन्मजात स्वतन्त्रता और समानता प्राप्त है।",
+            Self::Hr => "Sva ljudska bića rađaju se slobodna i jednaka u dostojanstvu i pravima.",
+            Self::Hu => "Minden emberi lény szabadon születik és egyenlő méltósága és joga van.",
+            Self::Hy => "Բոլոր մարդիկ ծնվում են ազատ ու հավասար իրենց արժանապատվությամբ ու իրավունքներով։",
+            Self::Id => "Semua orang dilahirkan merdeka dan mempunyai martabat dan hak-hak yang sama.",
+            Self::Is => "Hver maður er borinn frjáls og jafn öðrum að virðingu og réttindum.",
+            Self::It => "Tutti gli esseri umani nascono liberi ed eguali in dignità e diritti.",
+            Self::Ja => "すべての人間は、生まれながらにして自由であり、かつ、尊厳と権利とについて平等である。",
+            Self::Ka => "ყველა ადამიანი იბადება თავისუფალი და თანასწორი თავისი ღირსებითა და უფლებებით.",
+            Self::Ko => "모든 인간은 태어날 때부터 자유로우며 그 존엄과 권리에 있어 동등하다.",
+            Self::La => "Omnes homines dignitate et iure liberi et pares nascuntur.",
+            Self::Lt => "Visi žmonės gimsta laisvi ir lygūs savo orumu ir teisėmis.",
+            Self::Lv => "Visi cilvēki piedzimst brīvi un vienlīdzīgi savā pašcieņā un tiesībās.",
+            Self::Mk => "Сите човечки суштества се раѓаат слободни и еднакви по достоинство и права.",
+            Self::Ms => "Semua manusia dilahirkan bebas dan samarata dari segi kemuliaan dan hak-hak.",
+            Self::Mt => "Il-bnedmin kollha jitwieldu ħielsa u ugwali fid-dinjità u d-drittijiet.",
+            Self::Nb | Self::No => "Alle mennesker er født frie og med samme menneskeverd og menneskerettigheter.",
+            Self::Nl => "Alle mensen worden vrij en gelijk in waardigheid en rechten geboren.",
+            Self::Pl => "Wszyscy ludzie rodzą się wolni i równi pod względem swej godności i swych praw.",
+            Self::Pt => "Todos os seres humanos nascem livres e iguais em dignidade e em direitos.",
+            Self::Ro => "Toate ființele umane se nasc libere și egale în demnitate și în drepturi.",
+            Self::Ru => "Все люди рождаются свободными и равными в своем достоинстве и правах.",
+            Self::Sk => "Všetci ľudia sa rodia slobodní a sebe rovní, čo sa týka ich dôstojnosti a práv.",
+            Self::Sl => "Vsi ljudje se rodijo svobodni in imajo enako dostojanstvo in enake pravice.",
+            Self::Sq => "Të gjithë njerëzit lindin të lirë dhe të barabartë në dinjitet dhe në të drejta.",
+            Self::Sr => "Сва људска бића рађају се слободна и једнака у достојанству и правима.",
+            Self::Sv => "Alla människor är födda fria och lika i värde och rättigheter.",
+            Self::Sw => "Watu wote wamezaliwa huru, hadhi na haki zao ni sawa.",
+            Self::Th => "มนุษย์ทั้งหลายเกิดมามีอิสระและเสมอภาคกันในเกียรติศักดิ์และสิทธิ",
+            Self::Tl => "Ang lahat ng tao'y isinilang na malaya at pantay-pantay sa karangalan at mga karapatan.",
+            Self::Tr => "Bütün insanlar hür, haysiyet ve haklar bakımından eşit doğarlar.",
+            Self::Uk => "Всі люди народжуються вільними і рівними у своїй гідності та правах.",
+            Self::Vi => "Tất cả mọi người sinh ra đều được tự do và bình đẳng về nhân phẩm và quyền.",
+            Self::Zh => "人人生而自由，在尊严和权利上一律平等。",
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LanguageCode;
+
+    #[test]
+    fn sample_text() {
+        let mut samples = 0;
+        for language in LanguageCode::iter() {
+            let Some(sample) = language.sample_text() else {
+                continue;
+            };
+            samples += 1;
+            assert_eq!(sample.trim(), sample);
+            // Samples are written in the language's own script, so only Latin ones contain ASCII
+            // letters.
+            assert_eq!(
+                sample.contains(|c: char| c.is_ascii_alphabetic()),
+                language.script() == "Latn",
+                "{:?}",
+                language
+            );
+        }
+        assert!(samples > 50);
+    }
+}
@@ -0,0 +1,181 @@
+//! Bindings exposing [`LanguageCode`] to embedded scripting languages. Enabled by the `mlua` and
+//! `rhai` features.
+
+use crate::maps::get;
+use crate::{LanguageCode, NAME_TO_LANGUAGE};
+
+#[cfg(feature = "mlua")]
+impl mlua::UserData for LanguageCode {
+    fn add_fields<F: mlua::UserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("code", |_, this| Ok(this.code()));
+        fields.add_field_method_get("code_t", |_, this| Ok(this.code_t()));
+        fields.add_field_method_get("code_b", |_, this| Ok(this.code_b()));
+        fields.add_field_method_get("name", |_, this| Ok(this.name()));
+        fields.add_field_method_get("family", |_, this| Ok(this.family()));
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_meta_method(mlua::MetaMethod::Eq, |_, this, other: Self| {
+            Ok(*this == other)
+        });
+        methods.add_meta_method(mlua::MetaMethod::ToString, |_, this, ()| Ok(this.code()));
+    }
+}
+
+#[cfg(feature = "mlua")]
+impl mlua::FromLua for LanguageCode {
+    /// Converts a `LanguageCode` userdata or a 2 letter code.
+    fn from_lua(value: mlua::Value, _lua: &mlua::Lua) -> mlua::Result<Self> {
+        match value {
+            mlua::Value::UserData(data) => Ok(*data.borrow::<Self>()?),
+            mlua::Value::String(code) => {
+                code.to_str()?
+                    .parse()
+                    .map_err(|e| mlua::Error::FromLuaConversionError {
+                        from: "string",
+                        to: "LanguageCode".into(),
+                        message: Some(alloc::string::ToString::to_string(&e)),
+                    })
+            }
+            other => Err(mlua::Error::FromLuaConversionError {
+                from: other.type_name(),
+                to: "LanguageCode".into(),
+                message: None,
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "mlua")]
+impl LanguageCode {
+    /// Returns a Lua table of constructors for languages: `from_code`, `from_name` and `all`.
+    /// `from_code` and `from_name` return `nil` for unknown languages.
+    ///
+    /// The application must enable one of `mlua`'s Lua version features.
+    ///
+    /// # Errors
+    ///
+    /// Fails if Lua fails to allocate the table or its functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let lua = mlua::Lua::new();
+    /// lua.globals()
+    ///     .set("LanguageCode", LanguageCode::lua_constructors(&lua).unwrap())
+    ///     .unwrap();
+    /// let name: String = lua
+    ///     .load("return LanguageCode.from_code('nl').name")
+    ///     .eval()
+    ///     .unwrap();
+    /// assert_eq!(name, "Dutch");
+    /// ```
+    pub fn lua_constructors(lua: &mlua::Lua) -> mlua::Result<mlua::Table> {
+        let table = lua.create_table()?;
+        table.set(
+            "from_code",
+            lua.create_function(|_, code: mlua::String| Ok(code.to_str()?.parse::<Self>().ok()))?,
+        )?;
+        table.set(
+            "from_name",
+            lua.create_function(|_, name: mlua::String| {
+                Ok(get(&NAME_TO_LANGUAGE, &name.to_str()?))
+            })?,
+        )?;
+        table.set(
+            "all",
+            lua.create_function(|_, ()| Ok(crate::LANGUAGE_CODES.to_vec()))?,
+        )?;
+        Ok(table)
+    }
+}
+
+#[cfg(feature = "rhai")]
+impl rhai::CustomType for LanguageCode {
+    /// Registers the type as `LanguageCode` with the getters `code`, `code_t`, `code_b`, `name`
+    /// and `family`, equality, and the constructors `language_from_code` and `language_from_name`,
+    /// which return `()` for unknown languages.
+    fn build(mut builder: rhai::TypeBuilder<Self>) {
+        fn optional(language: Option<LanguageCode>) -> rhai::Dynamic {
+            language.map_or(rhai::Dynamic::UNIT, rhai::Dynamic::from)
+        }
+
+        builder
+            .with_name("LanguageCode")
+            .with_get("code", |this: &mut Self| this.code())
+            .with_get("code_t", |this: &mut Self| this.code_t())
+            .with_get("code_b", |this: &mut Self| this.code_b())
+            .with_get("name", |this: &mut Self| this.name())
+            .with_get("family", |this: &mut Self| this.family())
+            .with_fn("==", |a: Self, b: Self| a == b)
+            .with_fn("!=", |a: Self, b: Self| a != b)
+            .with_fn("to_string", |this: &mut Self| this.code())
+            .with_fn("to_debug", |this: &mut Self| this.code())
+            .with_fn("language_from_code", |code: &str| {
+                optional(code.parse().ok())
+            })
+            .with_fn("language_from_name", |name: &str| {
+                optional(get(&NAME_TO_LANGUAGE, name))
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "mlua")]
+    #[test]
+    fn lua() {
+        use crate::LanguageCode;
+
+        let lua = mlua::Lua::new();
+        lua.globals()
+            .set(
+                "LanguageCode",
+                LanguageCode::lua_constructors(&lua).unwrap(),
+            )
+            .unwrap();
+        let language: LanguageCode = lua
+            .load(
+                r#"
+                local nl = LanguageCode.from_code("nl")
+                assert(nl == LanguageCode.from_name("Dutch"))
+                assert(nl ~= LanguageCode.from_code("en"))
+                assert(nl.code_b == "dut" and tostring(nl) == "nl")
+                assert(LanguageCode.from_code("xx") == nil)
+                assert(#LanguageCode.all() == 184)
+                return nl
+                "#,
+            )
+            .eval()
+            .unwrap();
+        assert_eq!(language, LanguageCode::Nl);
+        assert_eq!(
+            lua.load("'ja'").eval::<LanguageCode>().unwrap(),
+            LanguageCode::Ja
+        );
+        assert!(lua.load("1").eval::<LanguageCode>().is_err());
+    }
+
+    #[cfg(feature = "rhai")]
+    #[test]
+    fn rhai() {
+        use crate::LanguageCode;
+
+        let mut engine = rhai::Engine::new();
+        engine.build_type::<LanguageCode>();
+        let language: LanguageCode = engine
+            .eval(
+                r#"
+                let nl = language_from_code("nl");
+                if nl != language_from_name("Dutch") || nl == language_from_code("en") { throw "eq"; }
+                if nl.code_t != "nld" || `${nl}` != "nl" { throw "getters"; }
+                if language_from_code("xx") != () { throw "unknown"; }
+                nl
+                "#,
+            )
+            .unwrap();
+        assert_eq!(language, LanguageCode::Nl);
+    }
+}
@@ -0,0 +1,314 @@
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::LanguageCode;
+
+/// The direction text is written in, as used by the HTML `dir` attribute.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{Direction, LanguageCode};
+///
+/// assert_eq!(LanguageCode::Ur.text_direction(), Direction::Rtl);
+/// assert_eq!(Direction::Rtl.to_string(), "rtl");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Direction {
+    /// Left to right, like English.
+    Ltr,
+    /// Right to left, like Arabic.
+    Rtl,
+}
+
+impl Direction {
+    /// Returns the value of the HTML `dir` attribute, `ltr` or `rtl`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl LanguageCode {
+    /// Returns the [ISO 15924](https://unicode.org/iso15924/) code of the script the language is
+    /// most likely written in, like `Latn` or `Cyrl`, from CLDR's likely subtags.
+    ///
+    /// Languages written in several scripts return the most common one, so Serbian returns `Cyrl`
+    /// and Chinese returns `Hans`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::Ru.script(), "Cyrl");
+    /// assert_eq!(LanguageCode::Ja.script(), "Jpan");
+    /// assert_eq!(LanguageCode::Sw.script(), "Latn");
+    /// ```
+    #[must_use]
+    pub const fn script(self) -> &'static str {
+        match self {
+            Self::Ar | Self::Fa | Self::Ks | Self::Ps | Self::Sd | Self::Ug | Self::Ur => "Arab",
+            Self::Hy => "Armn",
+            Self::Ae => "Avst",
+            Self::As | Self::Bn => "Beng",
+            Self::Cr | Self::Iu | Self::Oj => "Cans",
+            Self::Ab
+            | Self::Av
+            | Self::Ba
+            | Self::Be
+            | Self::Bg
+            | Self::Ce
+            | Self::Cu
+            | Self::Cv
+            | Self::Kk
+            | Self::Kv
+            | Self::Ky
+            | Self::Mk
+            | Self::Mn
+            | Self::Os
+            | Self::Ru
+            | Self::Sr
+            | Self::Tg
+            | Self::Tt
+            | Self::Uk => "Cyrl",
+            Self::Bh | Self::Hi | Self::Mr | Self::Ne | Self::Sa => "Deva",
+            Self::Am | Self::Ti => "Ethi",
+            Self::Ka => "Geor",
+            Self::El => "Grek",
+            Self::Gu => "Gujr",
+            Self::Pa => "Guru",
+            Self::Zh => "Hans",
+            Self::He | Self::Yi => "Hebr",
+            Self::Ja => "Jpan",
+            Self::Km => "Khmr",
+            Self::Kn => "Knda",
+            Self::Ko => "Kore",
+            Self::Lo => "Laoo",
+            Self::Ml => "Mlym",
+            Self::My => "Mymr",
+            Self::Or => "Orya",
+            Self::Si => "Sinh",
+            Self::Ta => "Taml",
+            Self::Te => "Telu",
+            Self::Dv => "Thaa",
+            Self::Th => "Thai",
+            Self::Bo | Self::Dz => "Tibt",
+            Self::Ii => "Yiii",
+            _ => "Latn",
+        }
+    }
+
+    /// Returns the ISO 15924 codes of every script the language is commonly written in, starting
+    /// with its default [script](Self::script), for deciding which fonts and input methods it
+    /// needs.
+    ///
+    /// Japanese and Korean are followed by the scripts their combined codes are made of, so
+    /// Japanese returns `Jpan`, `Hani`, `Hira` and `Kana`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let azerbaijani: Vec<_> = LanguageCode::Az.scripts().collect();
+    /// assert_eq!(azerbaijani, ["Latn", "Cyrl", "Arab"]);
+    /// let french: Vec<_> = LanguageCode::Fr.scripts().collect();
+    /// assert_eq!(french, ["Latn"]);
+    /// ```
+    pub fn scripts(self) -> impl Iterator<Item = &'static str> {
+        let several = self.several_scripts();
+        let single = several.is_empty().then(|| self.script());
+        several.iter().copied().chain(single)
+    }
+
+    const fn several_scripts(self) -> &'static [&'static str] {
+        match self {
+            Self::Az | Self::Uz => &["Latn", "Cyrl", "Arab"],
+            Self::Bs => &["Latn", "Cyrl"],
+            Self::Cr | Self::Iu | Self::Oj => &["Cans", "Latn"],
+            Self::Bm => &["Latn", "Nkoo"],
+            Self::Ff => &["Latn", "Adlm", "Arab"],
+            Self::Ha | Self::Ku | Self::Ms => &["Latn", "Arab"],
+            Self::Ja => &["Jpan", "Hani", "Hira", "Kana"],
+            Self::Kk => &["Cyrl", "Latn", "Arab"],
+            Self::Ko => &["Kore", "Hang", "Hani"],
+            Self::Ks | Self::Sd => &["Arab", "Deva"],
+            Self::Mn => &["Cyrl", "Mong"],
+            Self::Pa => &["Guru", "Arab"],
+            Self::Sr => &["Cyrl", "Latn"],
+            Self::Ug => &["Arab", "Cyrl"],
+            Self::Zh => &["Hans", "Hant"],
+            _ => &[],
+        }
+    }
+
+    /// Returns whether the language's [script](Self::script) is written right to left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert!(LanguageCode::Ar.is_rtl());
+    /// assert!(!LanguageCode::En.is_rtl());
+    /// ```
+    #[must_use]
+    pub const fn is_rtl(self) -> bool {
+        matches!(
+            self.script().as_bytes(),
+            b"Arab" | b"Avst" | b"Hebr" | b"Nkoo" | b"Syrc" | b"Thaa"
+        )
+    }
+
+    /// Returns the direction the language's [script](Self::script) is written in, for setting
+    /// `dir="rtl"` on Arabic, Hebrew, Persian, Urdu, Divehi and other right to left text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{Direction, LanguageCode};
+    ///
+    /// assert_eq!(LanguageCode::He.text_direction(), Direction::Rtl);
+    /// assert_eq!(LanguageCode::Ru.text_direction(), Direction::Ltr);
+    /// ```
+    #[must_use]
+    pub const fn text_direction(self) -> Direction {
+        if self.is_rtl() {
+            Direction::Rtl
+        } else {
+            Direction::Ltr
+        }
+    }
+}
+
+impl LanguageCode {
+    /// Returns the language's names for itself in each script it is commonly written in, as
+    /// pairs of an ISO 15924 code and the name, starting with its default [script](Self::script).
+    ///
+    /// Languages written in one script return their [`autonym`](Self::autonym) in that script, and
+    /// languages without an autonym return nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let serbian: Vec<_> = LanguageCode::Sr.endonyms().collect();
+    /// assert_eq!(serbian, [("Cyrl", "Српски"), ("Latn", "Srpski")]);
+    /// let german: Vec<_> = LanguageCode::De.endonyms().collect();
+    /// assert_eq!(german, [("Latn", "Deutsch")]);
+    /// ```
+    pub fn endonyms(self) -> impl Iterator<Item = (&'static str, &'static str)> {
+        let several = self.endonyms_in_scripts();
+        let single = self
+            .autonym()
+            .filter(|_| several.is_empty())
+            .map(|autonym| (self.script(), autonym));
+        several.iter().copied().chain(single)
+    }
+
+    const fn endonyms_in_scripts(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Az => &[("Latn", "Azərbaycan"), ("Cyrl", "Азәрбајҹан")],
+            Self::Bs => &[("Latn", "Bosanski"), ("Cyrl", "Босански")],
+            Self::Kk => &[("Cyrl", "Қазақ тілі"), ("Latn", "Qazaq tili")],
+            Self::Ks => &[("Arab", "کٲشُر"), ("Deva", "कॉशुर")],
+            Self::Ku => &[("Latn", "Kurdî"), ("Arab", "کوردی")],
+            Self::Mn => &[("Cyrl", "Монгол"), ("Mong", "ᠮᠣᠩᠭᠣᠯ")],
+            Self::Ms => &[("Latn", "Melayu"), ("Arab", "ملايو")],
+            Self::Pa => &[("Guru", "ਪੰਜਾਬੀ"), ("Arab", "پنجابی")],
+            Self::Sd => &[("Arab", "سنڌي"), ("Deva", "सिन्धी")],
+            Self::Sr => &[("Cyrl", "Српски"), ("Latn", "Srpski")],
+            Self::Uz => &[("Latn", "O‘zbek"), ("Cyrl", "Ўзбек")],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, LanguageCode, LANGUAGE_CODES};
+
+    #[test]
+    fn scripts() {
+        for language in LANGUAGE_CODES {
+            let script = language.script().as_bytes();
+            assert_eq!(script.len(), 4);
+            assert!(script[0].is_ascii_uppercase());
+            assert!(script[1..].iter().all(u8::is_ascii_lowercase));
+        }
+        assert_eq!(LanguageCode::Yi.script(), "Hebr");
+        assert!(LanguageCode::Dv.is_rtl());
+        assert!(!LanguageCode::Hi.is_rtl());
+    }
+
+    #[test]
+    fn directions() {
+        let rtl: Vec<_> = LANGUAGE_CODES
+            .into_iter()
+            .filter(|language| language.text_direction() == Direction::Rtl)
+            .collect();
+        assert_eq!(
+            rtl,
+            [
+                LanguageCode::Ar,
+                LanguageCode::Ae,
+                LanguageCode::Dv,
+                LanguageCode::He,
+                LanguageCode::Ks,
+                LanguageCode::Fa,
+                LanguageCode::Ps,
+                LanguageCode::Sd,
+                LanguageCode::Ug,
+                LanguageCode::Ur,
+                LanguageCode::Yi,
+            ]
+        );
+        assert_eq!(format!("[{:>4}]", Direction::Ltr), "[ ltr]");
+    }
+
+    #[test]
+    fn endonyms() {
+        for language in LANGUAGE_CODES {
+            let endonyms: Vec<_> = language.endonyms().collect();
+            match endonyms.first() {
+                Some(&(script, name)) => {
+                    assert_eq!(script, language.script(), "{:?}", language);
+                    assert_eq!(Some(name), language.autonym(), "{:?}", language);
+                }
+                None => assert_eq!(language.autonym(), None),
+            }
+        }
+        assert_eq!(LanguageCode::Pa.endonyms().count(), 2);
+        assert_eq!(LanguageCode::Bh.endonyms().count(), 0);
+    }
+
+    #[test]
+    fn all_scripts() {
+        for language in LANGUAGE_CODES {
+            let scripts: Vec<_> = language.scripts().collect();
+            assert_eq!(scripts[0], language.script(), "{:?}", language);
+            for (i, script) in scripts.iter().enumerate() {
+                assert!(!scripts[i + 1..].contains(script), "{:?}", language);
+            }
+        }
+        assert_eq!(
+            LanguageCode::Zh.scripts().collect::<Vec<_>>(),
+            ["Hans", "Hant"]
+        );
+    }
+}
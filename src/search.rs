@@ -0,0 +1,401 @@
+//! Searching languages by their names.
+
+use alloc::string::String;
+
+use crate::parser::ALIASES;
+use crate::{maps, LanguageCode, NAME_TO_LANGUAGE};
+
+impl LanguageCode {
+    /// Returns the languages whose ISO names start with a prefix, ignoring ASCII case, in
+    /// alphabetical order of their names. This doesn't allocate, so it's suitable for
+    /// autocompletion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let matches: Vec<_> = LanguageCode::search_prefix("nor").collect();
+    /// assert_eq!(
+    ///     matches,
+    ///     [
+    ///         LanguageCode::Nd,
+    ///         LanguageCode::Se,
+    ///         LanguageCode::No,
+    ///         LanguageCode::Nb,
+    ///         LanguageCode::Nn,
+    ///     ]
+    /// );
+    /// ```
+    pub fn search_prefix(prefix: &str) -> impl Iterator<Item = Self> + '_ {
+        NAME_TO_LANGUAGE
+            .iter()
+            .filter(move |(name, _)| {
+                name.get(..prefix.len())
+                    .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+            })
+            .map(|&(_, language)| language)
+    }
+}
+
+impl LanguageCode {
+    /// Returns common alternative English names of the language besides its ISO
+    /// [`name`](Self::name), like `Kyrgyz` for Kirghiz or `Farsi` for Persian.
+    ///
+    /// These are accepted by [`Parser::accept_names`](crate::Parser::accept_names) and
+    /// [`from_name_folded`](Self::from_name_folded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let names: Vec<_> = LanguageCode::Dv.alternative_names().collect();
+    /// assert_eq!(names, ["Dhivehi", "Maldivian"]);
+    /// assert_eq!(LanguageCode::Fr.alternative_names().count(), 0);
+    /// ```
+    pub fn alternative_names(self) -> impl Iterator<Item = &'static str> {
+        ALIASES
+            .iter()
+            .filter(move |&&(_, language)| language == self)
+            .map(|&(name, _)| name)
+    }
+
+    /// Looks up a language by its ISO name or one of its [alternative
+    /// names](Self::alternative_names), ignoring case and diacritics, so that `volapuk` finds
+    /// Volapük and `Norwegian Bokmal` finds Norwegian Bokmål.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_name_folded("Volapuk"), Some(LanguageCode::Vo));
+    /// assert_eq!(LanguageCode::from_name_folded("norwegian bokmal"), Some(LanguageCode::Nb));
+    /// assert_eq!(LanguageCode::from_name_folded("Frênch"), Some(LanguageCode::Fr));
+    /// assert_eq!(LanguageCode::from_name_folded("uyghur"), Some(LanguageCode::Ug));
+    /// ```
+    #[must_use]
+    pub fn from_name_folded(name: &str) -> Option<Self> {
+        let name = fold(name);
+        maps::get(&FOLDED_NAMES, &name).or_else(|| {
+            ALIASES
+                .iter()
+                .find(|(alias, _)| fold(alias) == name)
+                .map(|&(_, language)| language)
+        })
+    }
+
+    /// Returns the languages whose ISO names start with a prefix, ignoring case and diacritics,
+    /// in alphabetical order of their folded names. See [`search_prefix`](Self::search_prefix).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let matches: Vec<_> = LanguageCode::search_prefix_folded("Norwegian Bokm").collect();
+    /// assert_eq!(matches, [LanguageCode::Nb]);
+    /// ```
+    pub fn search_prefix_folded(prefix: &str) -> impl Iterator<Item = Self> {
+        let prefix = fold(prefix);
+        FOLDED_NAMES
+            .iter()
+            .filter(move |(name, _)| name.starts_with(&prefix))
+            .map(|&(_, language)| language)
+    }
+}
+
+/// Lowercases a string and replaces Latin letters with diacritics by their ASCII base letters.
+fn fold(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.chars().flat_map(char::to_lowercase) {
+        let base = match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+            'æ' => "ae",
+            'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+            'ð' | 'ď' | 'đ' => "d",
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+            'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+            'ĥ' | 'ħ' => "h",
+            'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+            'ĳ' => "ij",
+            'ĵ' => "j",
+            'ķ' | 'ĸ' => "k",
+            'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+            'ñ' | 'ń' | 'ņ' | 'ň' | 'ŋ' => "n",
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+            'œ' => "oe",
+            'ŕ' | 'ŗ' | 'ř' => "r",
+            'ś' | 'ŝ' | 'ş' | 'š' | 'ſ' => "s",
+            'ß' => "ss",
+            'ţ' | 'ť' | 'ŧ' => "t",
+            'þ' => "th",
+            'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+            'ŵ' => "w",
+            'ý' | 'ÿ' | 'ŷ' => "y",
+            'ź' | 'ż' | 'ž' => "z",
+            _ => {
+                folded.push(c);
+                continue;
+            }
+        };
+        folded.push_str(base);
+    }
+    folded
+}
+
+/// Every language keyed by its ISO name lowercased and folded to ASCII, sorted in byte order.
+#[rustfmt::skip]
+static FOLDED_NAMES: [(&str, LanguageCode); 184] = [
+    ("abkhazian", LanguageCode::Ab),
+    ("afar", LanguageCode::Aa),
+    ("afrikaans", LanguageCode::Af),
+    ("akan", LanguageCode::Ak),
+    ("albanian", LanguageCode::Sq),
+    ("amharic", LanguageCode::Am),
+    ("arabic", LanguageCode::Ar),
+    ("aragonese", LanguageCode::An),
+    ("armenian", LanguageCode::Hy),
+    ("assamese", LanguageCode::As),
+    ("avaric", LanguageCode::Av),
+    ("avestan", LanguageCode::Ae),
+    ("aymara", LanguageCode::Ay),
+    ("azerbaijani", LanguageCode::Az),
+    ("bambara", LanguageCode::Bm),
+    ("bashkir", LanguageCode::Ba),
+    ("basque", LanguageCode::Eu),
+    ("belarusian", LanguageCode::Be),
+    ("bengali", LanguageCode::Bn),
+    ("bihari languages", LanguageCode::Bh),
+    ("bislama", LanguageCode::Bi),
+    ("bosnian", LanguageCode::Bs),
+    ("breton", LanguageCode::Br),
+    ("bulgarian", LanguageCode::Bg),
+    ("burmese", LanguageCode::My),
+    ("catalan", LanguageCode::Ca),
+    ("central khmer", LanguageCode::Km),
+    ("chamorro", LanguageCode::Ch),
+    ("chechen", LanguageCode::Ce),
+    ("chichewa", LanguageCode::Ny),
+    ("chinese", LanguageCode::Zh),
+    ("church slavic", LanguageCode::Cu),
+    ("chuvash", LanguageCode::Cv),
+    ("cornish", LanguageCode::Kw),
+    ("corsican", LanguageCode::Co),
+    ("cree", LanguageCode::Cr),
+    ("croatian", LanguageCode::Hr),
+    ("czech", LanguageCode::Cs),
+    ("danish", LanguageCode::Da),
+    ("divehi", LanguageCode::Dv),
+    ("dutch", LanguageCode::Nl),
+    ("dzongkha", LanguageCode::Dz),
+    ("english", LanguageCode::En),
+    ("esperanto", LanguageCode::Eo),
+    ("estonian", LanguageCode::Et),
+    ("ewe", LanguageCode::Ee),
+    ("faroese", LanguageCode::Fo),
+    ("fijian", LanguageCode::Fj),
+    ("finnish", LanguageCode::Fi),
+    ("french", LanguageCode::Fr),
+    ("fulah", LanguageCode::Ff),
+    ("gaelic", LanguageCode::Gd),
+    ("galician", LanguageCode::Gl),
+    ("ganda", LanguageCode::Lg),
+    ("georgian", LanguageCode::Ka),
+    ("german", LanguageCode::De),
+    ("greek", LanguageCode::El),
+    ("guarani", LanguageCode::Gn),
+    ("gujarati", LanguageCode::Gu),
+    ("haitian", LanguageCode::Ht),
+    ("hausa", LanguageCode::Ha),
+    ("hebrew", LanguageCode::He),
+    ("herero", LanguageCode::Hz),
+    ("hindi", LanguageCode::Hi),
+    ("hiri motu", LanguageCode::Ho),
+    ("hungarian", LanguageCode::Hu),
+    ("icelandic", LanguageCode::Is),
+    ("ido", LanguageCode::Io),
+    ("igbo", LanguageCode::Ig),
+    ("indonesian", LanguageCode::Id),
+    ("interlingua", LanguageCode::Ia),
+    ("interlingue", LanguageCode::Ie),
+    ("inuktitut", LanguageCode::Iu),
+    ("inupiaq", LanguageCode::Ik),
+    ("irish", LanguageCode::Ga),
+    ("italian", LanguageCode::It),
+    ("japanese", LanguageCode::Ja),
+    ("javanese", LanguageCode::Jv),
+    ("kalaallisut", LanguageCode::Kl),
+    ("kannada", LanguageCode::Kn),
+    ("kanuri", LanguageCode::Kr),
+    ("kashmiri", LanguageCode::Ks),
+    ("kazakh", LanguageCode::Kk),
+    ("kikuyu", LanguageCode::Ki),
+    ("kinyarwanda", LanguageCode::Rw),
+    ("kirghiz", LanguageCode::Ky),
+    ("komi", LanguageCode::Kv),
+    ("kongo", LanguageCode::Kg),
+    ("korean", LanguageCode::Ko),
+    ("kuanyama", LanguageCode::Kj),
+    ("kurdish", LanguageCode::Ku),
+    ("lao", LanguageCode::Lo),
+    ("latin", LanguageCode::La),
+    ("latvian", LanguageCode::Lv),
+    ("limburgan", LanguageCode::Li),
+    ("lingala", LanguageCode::Ln),
+    ("lithuanian", LanguageCode::Lt),
+    ("luba-katanga", LanguageCode::Lu),
+    ("luxembourgish", LanguageCode::Lb),
+    ("macedonian", LanguageCode::Mk),
+    ("malagasy", LanguageCode::Mg),
+    ("malay", LanguageCode::Ms),
+    ("malayalam", LanguageCode::Ml),
+    ("maltese", LanguageCode::Mt),
+    ("manx", LanguageCode::Gv),
+    ("maori", LanguageCode::Mi),
+    ("marathi", LanguageCode::Mr),
+    ("marshallese", LanguageCode::Mh),
+    ("mongolian", LanguageCode::Mn),
+    ("nauru", LanguageCode::Na),
+    ("navajo", LanguageCode::Nv),
+    ("ndonga", LanguageCode::Ng),
+    ("nepali", LanguageCode::Ne),
+    ("north ndebele", LanguageCode::Nd),
+    ("northern sami", LanguageCode::Se),
+    ("norwegian", LanguageCode::No),
+    ("norwegian bokmal", LanguageCode::Nb),
+    ("norwegian nynorsk", LanguageCode::Nn),
+    ("occitan", LanguageCode::Oc),
+    ("ojibwa", LanguageCode::Oj),
+    ("oriya", LanguageCode::Or),
+    ("oromo", LanguageCode::Om),
+    ("ossetian", LanguageCode::Os),
+    ("pali", LanguageCode::Pi),
+    ("pashto", LanguageCode::Ps),
+    ("persian", LanguageCode::Fa),
+    ("polish", LanguageCode::Pl),
+    ("portuguese", LanguageCode::Pt),
+    ("punjabi", LanguageCode::Pa),
+    ("quechua", LanguageCode::Qu),
+    ("romanian", LanguageCode::Ro),
+    ("romansh", LanguageCode::Rm),
+    ("rundi", LanguageCode::Rn),
+    ("russian", LanguageCode::Ru),
+    ("samoan", LanguageCode::Sm),
+    ("sango", LanguageCode::Sg),
+    ("sanskrit", LanguageCode::Sa),
+    ("sardinian", LanguageCode::Sc),
+    ("serbian", LanguageCode::Sr),
+    ("shona", LanguageCode::Sn),
+    ("sichuan yi", LanguageCode::Ii),
+    ("sindhi", LanguageCode::Sd),
+    ("sinhala", LanguageCode::Si),
+    ("slovak", LanguageCode::Sk),
+    ("slovenian", LanguageCode::Sl),
+    ("somali", LanguageCode::So),
+    ("south ndebele", LanguageCode::Nr),
+    ("southern sotho", LanguageCode::St),
+    ("spanish", LanguageCode::Es),
+    ("sundanese", LanguageCode::Su),
+    ("swahili", LanguageCode::Sw),
+    ("swati", LanguageCode::Ss),
+    ("swedish", LanguageCode::Sv),
+    ("tagalog", LanguageCode::Tl),
+    ("tahitian", LanguageCode::Ty),
+    ("tajik", LanguageCode::Tg),
+    ("tamil", LanguageCode::Ta),
+    ("tatar", LanguageCode::Tt),
+    ("telugu", LanguageCode::Te),
+    ("thai", LanguageCode::Th),
+    ("tibetan", LanguageCode::Bo),
+    ("tigrinya", LanguageCode::Ti),
+    ("tonga", LanguageCode::To),
+    ("tsonga", LanguageCode::Ts),
+    ("tswana", LanguageCode::Tn),
+    ("turkish", LanguageCode::Tr),
+    ("turkmen", LanguageCode::Tk),
+    ("twi", LanguageCode::Tw),
+    ("uighur", LanguageCode::Ug),
+    ("ukrainian", LanguageCode::Uk),
+    ("urdu", LanguageCode::Ur),
+    ("uzbek", LanguageCode::Uz),
+    ("venda", LanguageCode::Ve),
+    ("vietnamese", LanguageCode::Vi),
+    ("volapuk", LanguageCode::Vo),
+    ("walloon", LanguageCode::Wa),
+    ("welsh", LanguageCode::Cy),
+    ("western frisian", LanguageCode::Fy),
+    ("wolof", LanguageCode::Wo),
+    ("xhosa", LanguageCode::Xh),
+    ("yiddish", LanguageCode::Yi),
+    ("yoruba", LanguageCode::Yo),
+    ("zhuang", LanguageCode::Za),
+    ("zulu", LanguageCode::Zu),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{fold, FOLDED_NAMES};
+    use crate::LanguageCode;
+
+    #[test]
+    fn folded_names() {
+        assert!(FOLDED_NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for language in LanguageCode::iter() {
+            assert_eq!(
+                LanguageCode::from_name_folded(language.name()),
+                Some(language)
+            );
+        }
+        assert!(FOLDED_NAMES.iter().all(|(name, _)| name.is_ascii()));
+    }
+
+    #[test]
+    fn alternative_names() {
+        let mut count = 0;
+        for language in LanguageCode::iter() {
+            for name in language.alternative_names() {
+                assert_eq!(LanguageCode::from_name_folded(name), Some(language));
+                count += 1;
+            }
+        }
+        assert_eq!(count, 45);
+        assert_eq!(
+            LanguageCode::Ny.alternative_names().collect::<Vec<_>>(),
+            ["Chewa", "Nyanja"]
+        );
+        assert_eq!(
+            LanguageCode::from_name_folded("BOKMAL"),
+            Some(LanguageCode::Nb)
+        );
+    }
+
+    #[test]
+    fn fold_diacritics() {
+        assert_eq!(fold("Volapük"), "volapuk");
+        assert_eq!(fold("Straße ÆØÅ"), "strasse aeoa");
+        assert_eq!(fold("日本語"), "日本語");
+    }
+
+    #[test]
+    fn search_prefix() {
+        assert_eq!(LanguageCode::search_prefix("").count(), 184);
+        assert_eq!(
+            LanguageCode::search_prefix("Norwegian B").collect::<Vec<_>>(),
+            [LanguageCode::Nb]
+        );
+        assert_eq!(
+            LanguageCode::search_prefix("volapü").collect::<Vec<_>>(),
+            [LanguageCode::Vo]
+        );
+        assert_eq!(LanguageCode::search_prefix("Volapü").count(), 1);
+        assert_eq!(LanguageCode::search_prefix("Klingon").count(), 0);
+        // Doesn't panic when the prefix ends inside a multibyte character of a name.
+        assert_eq!(LanguageCode::search_prefix("Volapu").count(), 0);
+        assert_eq!(
+            LanguageCode::search_prefix_folded("VOLAPU").collect::<Vec<_>>(),
+            [LanguageCode::Vo]
+        );
+    }
+}
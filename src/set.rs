@@ -0,0 +1,400 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::iter::{FromIterator, FusedIterator};
+use core::str::FromStr;
+
+use crate::{LanguageCode, LANGUAGE_CODES};
+
+/// A set of languages.
+///
+/// The set is stored as a bitset with one bit per language, so it is `Copy` and can be built in
+/// const contexts.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, LanguageSet};
+///
+/// const NORDIC: LanguageSet = LanguageSet::from_slice(&[
+///     LanguageCode::Da,
+///     LanguageCode::Fi,
+///     LanguageCode::Is,
+///     LanguageCode::No,
+///     LanguageCode::Sv,
+/// ]);
+///
+/// assert!(NORDIC.contains(LanguageCode::Is));
+/// assert!(!NORDIC.contains(LanguageCode::De));
+/// assert_eq!(NORDIC.len(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LanguageSet([u64; 3]);
+
+impl LanguageSet {
+    /// The set containing no languages.
+    pub const EMPTY: Self = Self([0; 3]);
+
+    /// The set containing every language.
+    pub const ALL: Self = Self::from_slice(&LANGUAGE_CODES);
+
+    /// The languages written right to left, according to [`LanguageCode::is_rtl`].
+    pub const RTL: Self = {
+        let mut set = Self::EMPTY;
+        let mut i = 0;
+        while i < LANGUAGE_CODES.len() {
+            if LANGUAGE_CODES[i].is_rtl() {
+                set = set.with(LANGUAGE_CODES[i]);
+            }
+            i += 1;
+        }
+        set
+    };
+
+    /// The languages most likely written in the Latin script, according to
+    /// [`LanguageCode::script`].
+    pub const LATIN_SCRIPT: Self = {
+        let mut set = Self::EMPTY;
+        let mut i = 0;
+        while i < LANGUAGE_CODES.len() {
+            if matches!(LANGUAGE_CODES[i].script().as_bytes(), b"Latn") {
+                set = set.with(LANGUAGE_CODES[i]);
+            }
+            i += 1;
+        }
+        set
+    };
+
+    /// The constructed languages, like Esperanto, whose [family](LanguageCode::family) is
+    /// `Constructed`.
+    pub const CONSTRUCTED: Self = {
+        let mut set = Self::EMPTY;
+        let mut i = 0;
+        while i < LANGUAGE_CODES.len() {
+            if matches!(LANGUAGE_CODES[i].family().as_bytes(), b"Constructed") {
+                set = set.with(LANGUAGE_CODES[i]);
+            }
+            i += 1;
+        }
+        set
+    };
+
+    /// The 24 official languages of the European Union: [`EU_LANGUAGES`](crate::EU_LANGUAGES) as a
+    /// set.
+    pub const EU_OFFICIAL: Self = Self::from_slice(&crate::EU_LANGUAGES);
+
+    /// Creates an empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Creates a set from a slice of languages.
+    #[must_use]
+    pub const fn from_slice(languages: &[LanguageCode]) -> Self {
+        let mut set = Self::EMPTY;
+        let mut i = 0;
+        while i < languages.len() {
+            set = set.with(languages[i]);
+            i += 1;
+        }
+        set
+    }
+
+    /// Returns the set with the language added.
+    #[must_use]
+    pub const fn with(self, language: LanguageCode) -> Self {
+        let (word, bit) = position(language);
+        let mut words = self.0;
+        words[word] |= bit;
+        Self(words)
+    }
+
+    /// Returns the set with the language removed.
+    #[must_use]
+    pub const fn without(self, language: LanguageCode) -> Self {
+        let (word, bit) = position(language);
+        let mut words = self.0;
+        words[word] &= !bit;
+        Self(words)
+    }
+
+    /// Adds a language to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, language: LanguageCode) -> bool {
+        let inserted = !self.contains(language);
+        *self = self.with(language);
+        inserted
+    }
+
+    /// Removes a language from the set, returning whether it was present.
+    pub fn remove(&mut self, language: LanguageCode) -> bool {
+        let removed = self.contains(language);
+        *self = self.without(language);
+        removed
+    }
+
+    /// Returns whether the set contains the language.
+    #[must_use]
+    pub const fn contains(&self, language: LanguageCode) -> bool {
+        let (word, bit) = position(language);
+        self.0[word] & bit != 0
+    }
+
+    /// Returns the number of languages in the set.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        (self.0[0].count_ones() + self.0[1].count_ones() + self.0[2].count_ones()) as usize
+    }
+
+    /// Returns whether the set contains no languages.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0[0] == 0 && self.0[1] == 0 && self.0[2] == 0
+    }
+
+    /// Returns the languages in either set.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self([
+            self.0[0] | other.0[0],
+            self.0[1] | other.0[1],
+            self.0[2] | other.0[2],
+        ])
+    }
+
+    /// Returns the languages in both sets.
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self([
+            self.0[0] & other.0[0],
+            self.0[1] & other.0[1],
+            self.0[2] & other.0[2],
+        ])
+    }
+
+    /// Returns the languages in this set but not the other.
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self([
+            self.0[0] & !other.0[0],
+            self.0[1] & !other.0[1],
+            self.0[2] & !other.0[2],
+        ])
+    }
+
+    /// Returns an iterator over the languages in the set, in the order of
+    /// [`LANGUAGE_CODES`](crate::LANGUAGE_CODES).
+    #[inline]
+    pub fn iter(&self) -> SetIter {
+        SetIter {
+            set: *self,
+            index: 0,
+        }
+    }
+}
+
+const fn position(language: LanguageCode) -> (usize, u64) {
+    let index = language as usize;
+    (index / 64, 1 << (index % 64))
+}
+
+impl FromIterator<LanguageCode> for LanguageSet {
+    fn from_iter<I: IntoIterator<Item = LanguageCode>>(iter: I) -> Self {
+        let mut set = Self::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<LanguageCode> for LanguageSet {
+    fn extend<I: IntoIterator<Item = LanguageCode>>(&mut self, iter: I) {
+        for language in iter {
+            self.insert(language);
+        }
+    }
+}
+
+impl FromStr for LanguageSet {
+    type Err = ParseLanguageSetError;
+
+    /// Parses 2 letter language codes separated by `+` or `,`, like `en+fr+de` or `en, fr, de`,
+    /// ignoring ASCII case and whitespace around each code. An empty string is the empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, LanguageSet};
+    ///
+    /// let set: LanguageSet = "en+FR, de".parse().unwrap();
+    /// assert_eq!(set.len(), 3);
+    /// assert!(set.contains(LanguageCode::Fr));
+    ///
+    /// let error = "en,xx,,fra".parse::<LanguageSet>().unwrap_err();
+    /// assert_eq!(error.invalid, ["xx", "", "fra"]);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(Self::EMPTY);
+        }
+        let mut set = Self::EMPTY;
+        let mut invalid = Vec::new();
+        for code in s.split(['+', ',']).map(str::trim) {
+            match LanguageCode::from_bytes_ignore_case(code.as_bytes()) {
+                Some(language) => {
+                    set.insert(language);
+                }
+                None => invalid.push(code.to_owned()),
+            }
+        }
+        if invalid.is_empty() {
+            Ok(set)
+        } else {
+            Err(ParseLanguageSetError { invalid })
+        }
+    }
+}
+
+impl IntoIterator for LanguageSet {
+    type Item = LanguageCode;
+    type IntoIter = SetIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &LanguageSet {
+    type Item = LanguageCode;
+    type IntoIter = SetIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the languages in a [`LanguageSet`], created by [`LanguageSet::iter`].
+#[derive(Debug, Clone)]
+pub struct SetIter {
+    set: LanguageSet,
+    index: usize,
+}
+
+impl Iterator for SetIter {
+    type Item = LanguageCode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&language) = LANGUAGE_CODES.get(self.index) {
+            self.index += 1;
+            if self.set.contains(language) {
+                return Some(language);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for SetIter {
+    fn len(&self) -> usize {
+        LANGUAGE_CODES[self.index.min(LANGUAGE_CODES.len())..]
+            .iter()
+            .filter(|&&language| self.set.contains(language))
+            .count()
+    }
+}
+
+impl FusedIterator for SetIter {}
+
+/// An error parsing a [`LanguageSet`].
+#[derive(Debug, Clone)]
+pub struct ParseLanguageSetError {
+    /// The entries that are not language codes, in the order they appeared.
+    pub invalid: Vec<String>,
+}
+
+impl Display for ParseLanguageSetError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("invalid language codes: ")?;
+        for (i, code) in self.invalid.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?}", code)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, LanguageSet, LANGUAGE_CODES};
+
+    #[test]
+    fn insert_remove() {
+        let mut set = LanguageSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(LanguageCode::Zu));
+        assert!(!set.insert(LanguageCode::Zu));
+        assert!(set.insert(LanguageCode::Ab));
+        assert_eq!(set.len(), 2);
+        assert!(set.remove(LanguageCode::Zu));
+        assert!(!set.remove(LanguageCode::Zu));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [LanguageCode::Ab]);
+    }
+
+    #[test]
+    fn all() {
+        assert_eq!(LanguageSet::ALL.len(), LANGUAGE_CODES.len());
+        assert!(LanguageSet::ALL.iter().eq(LANGUAGE_CODES.iter().copied()));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: LanguageSet = [LanguageCode::En, LanguageCode::Fr]
+            .iter()
+            .copied()
+            .collect();
+        let b = LanguageSet::from_slice(&[LanguageCode::Fr, LanguageCode::De]);
+        assert_eq!(a.union(b).len(), 3);
+        assert_eq!(
+            a.intersection(b).iter().collect::<Vec<_>>(),
+            [LanguageCode::Fr]
+        );
+        assert_eq!(
+            a.difference(b).iter().collect::<Vec<_>>(),
+            [LanguageCode::En]
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "ja,en".parse::<LanguageSet>().unwrap(),
+            LanguageSet::from_slice(&[LanguageCode::En, LanguageCode::Ja])
+        );
+        assert!(" ".parse::<LanguageSet>().unwrap().is_empty());
+        let error = "en+ +zz".parse::<LanguageSet>().unwrap_err();
+        assert_eq!(error.to_string(), r#"invalid language codes: "", "zz""#);
+    }
+
+    #[test]
+    fn predefined() {
+        assert_eq!(LanguageSet::EU_OFFICIAL.len(), 24);
+        assert!(LanguageSet::RTL.contains(LanguageCode::He));
+        assert!(!LanguageSet::RTL.contains(LanguageCode::Hi));
+        assert!(LanguageSet::LATIN_SCRIPT.contains(LanguageCode::Vi));
+        assert!(!LanguageSet::LATIN_SCRIPT.contains(LanguageCode::Ru));
+        assert_eq!(LanguageSet::CONSTRUCTED, "eo+ia+ie+io+vo".parse().unwrap());
+        assert!(LanguageSet::RTL
+            .intersection(LanguageSet::LATIN_SCRIPT)
+            .is_empty());
+    }
+}
@@ -0,0 +1,352 @@
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use crate::{BySpeakers, LanguageCode, LanguageSet, LANGUAGE_CODES};
+
+/// The estimated world population in millions, used as the denominator of
+/// [`CoverageReport::share`].
+pub const WORLD_POPULATION_MILLIONS: u32 = 8_100;
+
+impl LanguageCode {
+    /// Returns a rough estimate of the number of first language speakers of the language, in
+    /// millions, based on Ethnologue and Wikipedia figures.
+    ///
+    /// Returns `None` for languages with fewer than a million native speakers. Macrolanguages
+    /// include all their varieties, so Chinese counts speakers of Mandarin, Cantonese, Wu and Min.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert!(LanguageCode::Es.native_speakers() > LanguageCode::En.native_speakers());
+    /// assert_eq!(LanguageCode::Is.native_speakers(), None);
+    /// ```
+    #[must_use]
+    pub const fn native_speakers(self) -> Option<u32> {
+        match self {
+            Self::Zh => Some(1200),
+            Self::Es => Some(485),
+            Self::En => Some(380),
+            Self::Ar => Some(360),
+            Self::Hi => Some(345),
+            Self::Pt => Some(236),
+            Self::Bn => Some(234),
+            Self::Ru => Some(147),
+            Self::Ja => Some(123),
+            Self::Pa => Some(120),
+            Self::Vi => Some(86),
+            Self::Tr => Some(84),
+            Self::Mr | Self::Te => Some(83),
+            Self::Ko => Some(81),
+            Self::Ta => Some(79),
+            Self::De => Some(76),
+            Self::Fr => Some(74),
+            Self::Ur => Some(70),
+            Self::Jv => Some(68),
+            Self::It => Some(64),
+            Self::Fa => Some(62),
+            Self::Gu => Some(57),
+            Self::Ha => Some(50),
+            Self::Yo => Some(45),
+            Self::Kn => Some(44),
+            Self::Id => Some(43),
+            Self::Pl | Self::Ps => Some(40),
+            Self::Ml | Self::Om => Some(37),
+            Self::Or => Some(34),
+            Self::Am | Self::My | Self::Uk => Some(33),
+            Self::Sd | Self::Su => Some(32),
+            Self::Ig => Some(31),
+            Self::Tl => Some(28),
+            Self::Ku => Some(26),
+            Self::Mg | Self::Nl => Some(25),
+            Self::Az | Self::Ro => Some(24),
+            Self::So => Some(22),
+            Self::Th => Some(21),
+            Self::Ms => Some(19),
+            Self::Km | Self::Ne | Self::Si | Self::Sw => Some(16),
+            Self::As => Some(15),
+            Self::El | Self::Hu | Self::Kk => Some(13),
+            Self::Ht | Self::Rw | Self::Zu => Some(12),
+            Self::Sn | Self::Ug => Some(11),
+            Self::Cs | Self::Sv => Some(10),
+            Self::Ti => Some(9),
+            Self::Qu | Self::Tg | Self::Xh => Some(8),
+            Self::Af | Self::Bg | Self::Sq | Self::Tk => Some(7),
+            Self::Da | Self::Gn | Self::Sr | Self::St | Self::Wo => Some(6),
+            Self::Be
+            | Self::Fi
+            | Self::He
+            | Self::Hr
+            | Self::Hy
+            | Self::Ky
+            | Self::Mn
+            | Self::No
+            | Self::Sk
+            | Self::Tt => Some(5),
+            Self::Ca | Self::Ka | Self::Lo => Some(4),
+            Self::Lt => Some(3),
+            Self::Bs | Self::Gl | Self::Mk | Self::Sl => Some(2),
+            Self::Ba | Self::Cv | Self::Et | Self::Lv => Some(1),
+            _ => None,
+        }
+    }
+}
+
+impl LanguageCode {
+    /// Returns a rough estimate of the total number of first and second language speakers of the
+    /// language, in millions, based on Ethnologue's 2023 figures as listed on Wikipedia.
+    ///
+    /// Returns `None` for languages without a well-known estimate of their second language
+    /// speakers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert!(LanguageCode::En.total_speakers() > LanguageCode::Zh.total_speakers());
+    /// assert_eq!(LanguageCode::Is.total_speakers(), None);
+    /// ```
+    #[must_use]
+    pub const fn total_speakers(self) -> Option<u32> {
+        match self {
+            Self::En => Some(1515),
+            Self::Zh => Some(1350),
+            Self::Hi => Some(610),
+            Self::Es => Some(560),
+            Self::Ar => Some(380),
+            Self::Fr => Some(310),
+            Self::Bn => Some(273),
+            Self::Pt => Some(264),
+            Self::Ru => Some(255),
+            Self::Ur => Some(232),
+            Self::Id => Some(199),
+            Self::De => Some(134),
+            Self::Ja => Some(123),
+            Self::Mr => Some(99),
+            Self::Te => Some(96),
+            Self::Tr => Some(90),
+            Self::Ta | Self::Vi => Some(86),
+            Self::Ko => Some(82),
+            Self::Ha => Some(79),
+            Self::Sw => Some(72),
+            Self::It | Self::Jv => Some(68),
+            Self::Gu => Some(62),
+            Self::Th => Some(61),
+            Self::Am => Some(60),
+            Self::Kn => Some(59),
+            Self::Yo => Some(46),
+            Self::My => Some(43),
+            Self::Pl => Some(41),
+            _ => None,
+        }
+    }
+
+    /// Returns the estimated [native](Self::native_speakers) and [total](Self::total_speakers)
+    /// speakers of the language together, or `None` if there is no native speaker estimate.
+    ///
+    /// Where there is no estimate of second language speakers, the total is the number of native
+    /// speakers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, SpeakerCount};
+    ///
+    /// assert_eq!(
+    ///     LanguageCode::Fr.speaker_count(),
+    ///     Some(SpeakerCount { native: 74, total: 310 })
+    /// );
+    /// assert_eq!(
+    ///     LanguageCode::Nl.speaker_count(),
+    ///     Some(SpeakerCount { native: 25, total: 25 })
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn speaker_count(self) -> Option<SpeakerCount> {
+        let Some(native) = self.native_speakers() else {
+            return None;
+        };
+        let total = match self.total_speakers() {
+            Some(total) => total,
+            None => native,
+        };
+        Some(SpeakerCount { native, total })
+    }
+}
+
+/// Estimated numbers of speakers of a language, in millions, returned by
+/// [`LanguageCode::speaker_count`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpeakerCount {
+    /// The first language speakers.
+    pub native: u32,
+    /// The first and second language speakers.
+    pub total: u32,
+}
+
+impl LanguageCode {
+    /// Returns an iterator over the languages with a [native speaker
+    /// estimate](Self::native_speakers), from most to fewest speakers. Languages with the same
+    /// estimate are in the order of [`LANGUAGE_CODES`].
+    ///
+    /// The order is computed at compile time, so taking the first few languages is cheap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let top: Vec<_> = LanguageCode::iter_by_speakers().take(3).collect();
+    /// assert_eq!(top, [LanguageCode::Zh, LanguageCode::Es, LanguageCode::En]);
+    /// ```
+    #[inline]
+    pub fn iter_by_speakers() -> BySpeakers {
+        BySpeakers::default()
+    }
+}
+
+const SPEAKER_ESTIMATES: usize = {
+    let mut count = 0;
+    let mut i = 0;
+    while i < LANGUAGE_CODES.len() {
+        if LANGUAGE_CODES[i].native_speakers().is_some() {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+};
+
+/// The languages with a native speaker estimate, from most to fewest speakers.
+pub(crate) static BY_SPEAKERS: [LanguageCode; SPEAKER_ESTIMATES] = {
+    let mut sorted = [LanguageCode::Aa; SPEAKER_ESTIMATES];
+    let mut len = 0;
+    let mut i = 0;
+    while i < LANGUAGE_CODES.len() {
+        if let Some(speakers) = LANGUAGE_CODES[i].native_speakers() {
+            sorted[len] = LANGUAGE_CODES[i];
+            let mut j = len;
+            while j > 0 && speakers_of(sorted[j - 1]) < speakers {
+                sorted[j] = sorted[j - 1];
+                sorted[j - 1] = LANGUAGE_CODES[i];
+                j -= 1;
+            }
+            len += 1;
+        }
+        i += 1;
+    }
+    sorted
+};
+
+const fn speakers_of(language: LanguageCode) -> u32 {
+    match language.native_speakers() {
+        Some(speakers) => speakers,
+        None => 0,
+    }
+}
+
+/// How much of the world population a set of languages reaches, returned by
+/// [`LanguageSet::coverage_report`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageReport {
+    /// The native speakers of the covered languages, in millions.
+    pub covered_speakers: u32,
+    /// The estimated share of the world population who speak a covered language natively, from 0
+    /// to 1.
+    pub share: f64,
+    /// The languages not in the set with their native speakers in millions, largest first.
+    pub largest_uncovered: Vec<(LanguageCode, u32)>,
+}
+
+impl LanguageSet {
+    /// Estimates the share of the world population reached by the languages in the set, using
+    /// [`LanguageCode::native_speakers`].
+    ///
+    /// This ignores second language speakers, so it underestimates the reach of lingua francas
+    /// like English.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, LanguageSet};
+    ///
+    /// let supported = LanguageSet::from_slice(&[LanguageCode::En, LanguageCode::Es]);
+    /// let report = supported.coverage_report();
+    /// assert_eq!(report.covered_speakers, 865);
+    /// assert!(report.share > 0.1);
+    /// assert_eq!(report.largest_uncovered[0].0, LanguageCode::Zh);
+    /// ```
+    #[must_use]
+    pub fn coverage_report(&self) -> CoverageReport {
+        let mut covered_speakers = 0;
+        let mut largest_uncovered = Vec::new();
+        for language in LANGUAGE_CODES {
+            if let Some(speakers) = language.native_speakers() {
+                if self.contains(language) {
+                    covered_speakers += speakers;
+                } else {
+                    largest_uncovered.push((language, speakers));
+                }
+            }
+        }
+        largest_uncovered.sort_by_key(|&(_, speakers)| Reverse(speakers));
+        CoverageReport {
+            covered_speakers,
+            share: f64::from(covered_speakers) / f64::from(WORLD_POPULATION_MILLIONS),
+            largest_uncovered,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, LanguageSet, WORLD_POPULATION_MILLIONS};
+
+    #[test]
+    fn coverage() {
+        let report = LanguageSet::new().coverage_report();
+        assert_eq!(report.covered_speakers, 0);
+        assert_eq!(report.largest_uncovered[1], (LanguageCode::Es, 485));
+
+        let all: LanguageSet = crate::LANGUAGE_CODES.into_iter().collect();
+        let report = all.coverage_report();
+        assert!(report.largest_uncovered.is_empty());
+        assert!(report.covered_speakers < WORLD_POPULATION_MILLIONS);
+    }
+
+    #[test]
+    fn by_speakers() {
+        assert_eq!(
+            LanguageCode::iter_by_speakers().len(),
+            LanguageCode::iter()
+                .filter(|language| language.native_speakers().is_some())
+                .count()
+        );
+        let speakers: Vec<_> = LanguageCode::iter_by_speakers()
+            .map(|language| language.native_speakers().unwrap())
+            .collect();
+        assert!(speakers.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert_eq!(
+            LanguageCode::iter_by_speakers().last(),
+            Some(LanguageCode::Lv)
+        );
+    }
+
+    #[test]
+    fn totals() {
+        for language in LanguageCode::iter() {
+            if let Some(total) = language.total_speakers() {
+                assert!(
+                    total >= language.native_speakers().unwrap(),
+                    "{:?}",
+                    language
+                );
+            }
+            if let Some(count) = language.speaker_count() {
+                assert!(count.total >= count.native);
+            }
+        }
+    }
+}
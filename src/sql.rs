@@ -0,0 +1,147 @@
+//! SQL statements that keep database constraints in sync with the crate's table of languages.
+//!
+//! Languages are stored by their 2 letter code. Identifiers are quoted for the chosen
+//! [`Dialect`].
+//!
+//! # Examples
+//!
+//! ```
+//! use isolanguage_1::sql::{self, Dialect};
+//!
+//! let check = sql::check_constraint(Dialect::Postgres, "lang");
+//! assert!(check.starts_with(r#"CHECK ("lang" IN ('ab', 'aa', "#));
+//!
+//! let create = sql::create_enum_type(Dialect::Postgres, "language").unwrap();
+//! assert!(create.starts_with(r#"CREATE TYPE "language" AS ENUM ('ab', "#));
+//! assert_eq!(sql::create_enum_type(Dialect::Sqlite, "language"), None);
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::LanguageCode;
+
+/// A dialect of SQL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Dialect {
+    /// PostgreSQL.
+    Postgres,
+    /// MySQL and MariaDB.
+    MySql,
+    /// SQLite.
+    Sqlite,
+}
+
+impl Dialect {
+    fn quote_identifier(self, identifier: &str) -> String {
+        match self {
+            Self::MySql => format!("`{}`", identifier.replace('`', "``")),
+            Self::Postgres | Self::Sqlite => format!("\"{}\"", identifier.replace('"', "\"\"")),
+        }
+    }
+}
+
+fn quote_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+fn code_list() -> String {
+    LanguageCode::codes()
+        .map(quote_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Returns a statement creating an enum type of every language code, or `None` if the dialect
+/// doesn't have named enum types.
+///
+/// Only PostgreSQL has them; MySQL declares enums inline in column types instead.
+#[must_use]
+pub fn create_enum_type(dialect: Dialect, name: &str) -> Option<String> {
+    match dialect {
+        Dialect::Postgres => Some(format!(
+            "CREATE TYPE {} AS ENUM ({});",
+            dialect.quote_identifier(name),
+            code_list()
+        )),
+        Dialect::MySql | Dialect::Sqlite => None,
+    }
+}
+
+/// Returns a `CHECK` clause restricting a column to the language codes.
+#[must_use]
+pub fn check_constraint(dialect: Dialect, column: &str) -> String {
+    format!(
+        "CHECK ({} IN ({}))",
+        dialect.quote_identifier(column),
+        code_list()
+    )
+}
+
+/// Returns a statement creating a reference table of languages, with the columns `code` (the
+/// primary key), `code_t`, `code_b`, `name` and `family`.
+#[must_use]
+pub fn create_table(dialect: Dialect, table: &str) -> String {
+    let column = |name| dialect.quote_identifier(name);
+    format!(
+        "CREATE TABLE {} ({} CHAR(2) PRIMARY KEY, {} CHAR(3) NOT NULL, {} CHAR(3) NOT NULL, {} VARCHAR(64) NOT NULL, {} VARCHAR(64) NOT NULL);",
+        dialect.quote_identifier(table),
+        column("code"),
+        column("code_t"),
+        column("code_b"),
+        column("name"),
+        column("family"),
+    )
+}
+
+/// Returns a statement inserting every language into a reference table created by
+/// [`create_table`].
+#[must_use]
+pub fn seed_inserts(dialect: Dialect, table: &str) -> String {
+    let column = |name| dialect.quote_identifier(name);
+    let rows = LanguageCode::iter()
+        .map(|language| {
+            format!(
+                "({}, {}, {}, {}, {})",
+                quote_string(language.code()),
+                quote_string(language.code_t()),
+                quote_string(language.code_b()),
+                quote_string(language.name()),
+                quote_string(language.family()),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+    format!(
+        "INSERT INTO {} ({}, {}, {}, {}, {}) VALUES\n{};",
+        dialect.quote_identifier(table),
+        column("code"),
+        column("code_t"),
+        column("code_b"),
+        column("name"),
+        column("family"),
+        rows,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_constraint, create_table, quote_string, seed_inserts, Dialect};
+
+    #[test]
+    fn statements() {
+        assert_eq!(quote_string("N'Ko"), "'N''Ko'");
+        assert!(check_constraint(Dialect::MySql, "la`ng").starts_with("CHECK (`la``ng` IN ('ab', "));
+        assert!(check_constraint(Dialect::Sqlite, "lang").ends_with(", 'zu'))"));
+        assert!(create_table(Dialect::Postgres, "languages")
+            .starts_with(r#"CREATE TABLE "languages" ("code" CHAR(2) PRIMARY KEY, "#));
+
+        let inserts = seed_inserts(Dialect::Sqlite, "languages");
+        assert!(inserts.starts_with(
+            "INSERT INTO \"languages\" (\"code\", \"code_t\", \"code_b\", \"name\", \"family\") VALUES\n('ab', 'abk', 'abk', 'Abkhazian', 'Northwest Caucasian'),\n"
+        ));
+        assert_eq!(inserts.lines().count(), 185);
+    }
+}
@@ -0,0 +1,132 @@
+//! Conversions to [`tantivy`]'s stemmer languages, enabled by the `tantivy` feature.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use tantivy::tokenizer::Language;
+
+use crate::LanguageCode;
+
+impl LanguageCode {
+    /// Returns the language's [`tantivy`] stemmer, or `None` if tantivy doesn't have one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    /// use tantivy::tokenizer::Language;
+    ///
+    /// assert_eq!(LanguageCode::Nl.tantivy_stemmer(), Some(Language::Dutch));
+    /// assert_eq!(LanguageCode::Ja.tantivy_stemmer(), None);
+    /// ```
+    #[must_use]
+    pub const fn tantivy_stemmer(self) -> Option<Language> {
+        Some(match self {
+            Self::Ar => Language::Arabic,
+            Self::Da => Language::Danish,
+            Self::Nl => Language::Dutch,
+            Self::En => Language::English,
+            Self::Fi => Language::Finnish,
+            Self::Fr => Language::French,
+            Self::De => Language::German,
+            Self::El => Language::Greek,
+            Self::Hu => Language::Hungarian,
+            Self::It => Language::Italian,
+            Self::No | Self::Nb | Self::Nn => Language::Norwegian,
+            Self::Pt => Language::Portuguese,
+            Self::Ro => Language::Romanian,
+            Self::Ru => Language::Russian,
+            Self::Es => Language::Spanish,
+            Self::Sv => Language::Swedish,
+            Self::Ta => Language::Tamil,
+            Self::Tr => Language::Turkish,
+            _ => return None,
+        })
+    }
+}
+
+impl TryFrom<LanguageCode> for Language {
+    type Error = NoStemmerError;
+
+    /// Calls [`LanguageCode::tantivy_stemmer`].
+    fn try_from(language: LanguageCode) -> Result<Self, Self::Error> {
+        language
+            .tantivy_stemmer()
+            .ok_or(NoStemmerError { language })
+    }
+}
+
+impl From<Language> for LanguageCode {
+    fn from(language: Language) -> Self {
+        match language {
+            Language::Arabic => Self::Ar,
+            Language::Danish => Self::Da,
+            Language::Dutch => Self::Nl,
+            Language::English => Self::En,
+            Language::Finnish => Self::Fi,
+            Language::French => Self::Fr,
+            Language::German => Self::De,
+            Language::Greek => Self::El,
+            Language::Hungarian => Self::Hu,
+            Language::Italian => Self::It,
+            Language::Norwegian => Self::No,
+            Language::Portuguese => Self::Pt,
+            Language::Romanian => Self::Ro,
+            Language::Russian => Self::Ru,
+            Language::Spanish => Self::Es,
+            Language::Swedish => Self::Sv,
+            Language::Tamil => Self::Ta,
+            Language::Turkish => Self::Tr,
+        }
+    }
+}
+
+/// An error converting a language that has no [`tantivy`] stemmer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoStemmerError {
+    /// The language that has no stemmer.
+    pub language: LanguageCode,
+}
+
+impl Display for NoStemmerError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "tantivy has no stemmer for {}", self.language)
+    }
+}
+
+impl Error for NoStemmerError {}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use tantivy::tokenizer::Language;
+
+    use crate::LanguageCode;
+
+    #[test]
+    fn stemmers() {
+        assert_eq!(
+            Language::try_from(LanguageCode::En).unwrap(),
+            Language::English
+        );
+        assert_eq!(
+            Language::try_from(LanguageCode::Nb).unwrap(),
+            Language::Norwegian
+        );
+        assert_eq!(
+            Language::try_from(LanguageCode::Zh).unwrap_err().language,
+            LanguageCode::Zh
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for language in LanguageCode::iter() {
+            if let Some(stemmer) = language.tantivy_stemmer() {
+                assert_eq!(LanguageCode::from(stemmer).tantivy_stemmer(), Some(stemmer));
+            }
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Locale identifiers used for localized app store listings on
+//! [App Store Connect](https://developer.apple.com/help/app-store-connect/reference/app-store-localizations)
+//! and [Google Play](https://support.google.com/googleplay/android-developer/answer/9844778).
+//!
+//! Each store supports a fixed set of listing locales. Converting a [`Locale`] picks the listing
+//! locale for its exact region if there is one, and otherwise the store's default listing locale
+//! for the language.
+//!
+//! # Examples
+//!
+//! ```
+//! use isolanguage_1::{store, LanguageCode, Locale};
+//!
+//! let locale: Locale = "pt-BR".parse().unwrap();
+//! assert_eq!(store::app_store_locale(locale), Some("pt-BR"));
+//! assert_eq!(store::play_store_locale(LanguageCode::He.into()), Some("iw-IL"));
+//! assert_eq!(
+//!     store::from_app_store_locale("zh-Hant"),
+//!     Some("zh-TW".parse().unwrap())
+//! );
+//! ```
+
+use crate::{LanguageCode, Locale, Region};
+
+/// A listing locale: its identifier, its language and the regions it is used for. The first region
+/// is the one the identifier converts back to.
+type Listing = (&'static str, LanguageCode, &'static [&'static str]);
+
+/// App Store Connect listing locales. The first listing of each language is its default.
+const APP_STORE: &[Listing] = &[
+    ("ar-SA", LanguageCode::Ar, &["SA"]),
+    ("ca", LanguageCode::Ca, &[]),
+    ("zh-Hans", LanguageCode::Zh, &["CN", "SG"]),
+    ("zh-Hant", LanguageCode::Zh, &["TW", "HK", "MO"]),
+    ("hr", LanguageCode::Hr, &[]),
+    ("cs", LanguageCode::Cs, &[]),
+    ("da", LanguageCode::Da, &[]),
+    ("nl-NL", LanguageCode::Nl, &["NL"]),
+    ("en-US", LanguageCode::En, &["US"]),
+    ("en-AU", LanguageCode::En, &["AU"]),
+    ("en-CA", LanguageCode::En, &["CA"]),
+    ("en-GB", LanguageCode::En, &["GB"]),
+    ("fi", LanguageCode::Fi, &[]),
+    ("fr-FR", LanguageCode::Fr, &["FR"]),
+    ("fr-CA", LanguageCode::Fr, &["CA"]),
+    ("de-DE", LanguageCode::De, &["DE"]),
+    ("el", LanguageCode::El, &[]),
+    ("he", LanguageCode::He, &[]),
+    ("hi", LanguageCode::Hi, &[]),
+    ("hu", LanguageCode::Hu, &[]),
+    ("id", LanguageCode::Id, &[]),
+    ("it", LanguageCode::It, &[]),
+    ("ja", LanguageCode::Ja, &[]),
+    ("ko", LanguageCode::Ko, &[]),
+    ("ms", LanguageCode::Ms, &[]),
+    ("no", LanguageCode::No, &[]),
+    ("no", LanguageCode::Nb, &[]),
+    ("pl", LanguageCode::Pl, &[]),
+    ("pt-BR", LanguageCode::Pt, &["BR"]),
+    ("pt-PT", LanguageCode::Pt, &["PT"]),
+    ("ro", LanguageCode::Ro, &[]),
+    ("ru", LanguageCode::Ru, &[]),
+    ("sk", LanguageCode::Sk, &[]),
+    ("es-ES", LanguageCode::Es, &["ES"]),
+    ("es-MX", LanguageCode::Es, &["MX", "419"]),
+    ("sv", LanguageCode::Sv, &[]),
+    ("th", LanguageCode::Th, &[]),
+    ("tr", LanguageCode::Tr, &[]),
+    ("uk", LanguageCode::Uk, &[]),
+    ("vi", LanguageCode::Vi, &[]),
+];
+
+/// Google Play listing locales. The first listing of each language is its default.
+const PLAY_STORE: &[Listing] = &[
+    ("af", LanguageCode::Af, &[]),
+    ("am", LanguageCode::Am, &[]),
+    ("ar", LanguageCode::Ar, &[]),
+    ("hy-AM", LanguageCode::Hy, &["AM"]),
+    ("az-AZ", LanguageCode::Az, &["AZ"]),
+    ("bn-BD", LanguageCode::Bn, &["BD"]),
+    ("eu-ES", LanguageCode::Eu, &["ES"]),
+    ("be", LanguageCode::Be, &[]),
+    ("bg", LanguageCode::Bg, &[]),
+    ("my-MM", LanguageCode::My, &["MM"]),
+    ("ca", LanguageCode::Ca, &[]),
+    ("zh-CN", LanguageCode::Zh, &["CN", "SG"]),
+    ("zh-TW", LanguageCode::Zh, &["TW"]),
+    ("zh-HK", LanguageCode::Zh, &["HK", "MO"]),
+    ("hr", LanguageCode::Hr, &[]),
+    ("cs-CZ", LanguageCode::Cs, &["CZ"]),
+    ("da-DK", LanguageCode::Da, &["DK"]),
+    ("nl-NL", LanguageCode::Nl, &["NL"]),
+    ("en-US", LanguageCode::En, &["US"]),
+    ("en-AU", LanguageCode::En, &["AU"]),
+    ("en-CA", LanguageCode::En, &["CA"]),
+    ("en-GB", LanguageCode::En, &["GB"]),
+    ("en-IN", LanguageCode::En, &["IN"]),
+    ("en-SG", LanguageCode::En, &["SG"]),
+    ("en-ZA", LanguageCode::En, &["ZA"]),
+    ("et", LanguageCode::Et, &[]),
+    ("fi-FI", LanguageCode::Fi, &["FI"]),
+    ("fr-FR", LanguageCode::Fr, &["FR"]),
+    ("fr-CA", LanguageCode::Fr, &["CA"]),
+    ("gl-ES", LanguageCode::Gl, &["ES"]),
+    ("ka-GE", LanguageCode::Ka, &["GE"]),
+    ("de-DE", LanguageCode::De, &["DE"]),
+    ("el-GR", LanguageCode::El, &["GR"]),
+    ("gu", LanguageCode::Gu, &[]),
+    ("iw-IL", LanguageCode::He, &["IL"]),
+    ("hi-IN", LanguageCode::Hi, &["IN"]),
+    ("hu-HU", LanguageCode::Hu, &["HU"]),
+    ("is-IS", LanguageCode::Is, &["IS"]),
+    ("id", LanguageCode::Id, &[]),
+    ("it-IT", LanguageCode::It, &["IT"]),
+    ("ja-JP", LanguageCode::Ja, &["JP"]),
+    ("kn-IN", LanguageCode::Kn, &["IN"]),
+    ("kk", LanguageCode::Kk, &[]),
+    ("km-KH", LanguageCode::Km, &["KH"]),
+    ("ko-KR", LanguageCode::Ko, &["KR"]),
+    ("ky-KG", LanguageCode::Ky, &["KG"]),
+    ("lo-LA", LanguageCode::Lo, &["LA"]),
+    ("lv", LanguageCode::Lv, &[]),
+    ("lt", LanguageCode::Lt, &[]),
+    ("mk-MK", LanguageCode::Mk, &["MK"]),
+    ("ms", LanguageCode::Ms, &[]),
+    ("ms-MY", LanguageCode::Ms, &["MY"]),
+    ("ml-IN", LanguageCode::Ml, &["IN"]),
+    ("mr-IN", LanguageCode::Mr, &["IN"]),
+    ("mn-MN", LanguageCode::Mn, &["MN"]),
+    ("ne-NP", LanguageCode::Ne, &["NP"]),
+    ("no-NO", LanguageCode::No, &["NO"]),
+    ("no-NO", LanguageCode::Nb, &["NO"]),
+    ("fa", LanguageCode::Fa, &[]),
+    ("fa-AE", LanguageCode::Fa, &["AE"]),
+    ("fa-AF", LanguageCode::Fa, &["AF"]),
+    ("fa-IR", LanguageCode::Fa, &["IR"]),
+    ("pl-PL", LanguageCode::Pl, &["PL"]),
+    ("pt-BR", LanguageCode::Pt, &["BR"]),
+    ("pt-PT", LanguageCode::Pt, &["PT"]),
+    ("pa", LanguageCode::Pa, &[]),
+    ("ro", LanguageCode::Ro, &[]),
+    ("rm", LanguageCode::Rm, &[]),
+    ("ru-RU", LanguageCode::Ru, &["RU"]),
+    ("sr", LanguageCode::Sr, &[]),
+    ("si-LK", LanguageCode::Si, &["LK"]),
+    ("sk", LanguageCode::Sk, &[]),
+    ("sl", LanguageCode::Sl, &[]),
+    ("es-ES", LanguageCode::Es, &["ES"]),
+    ("es-419", LanguageCode::Es, &["419"]),
+    ("es-US", LanguageCode::Es, &["US"]),
+    ("sw", LanguageCode::Sw, &[]),
+    ("sv-SE", LanguageCode::Sv, &["SE"]),
+    ("ta-IN", LanguageCode::Ta, &["IN"]),
+    ("te-IN", LanguageCode::Te, &["IN"]),
+    ("th", LanguageCode::Th, &[]),
+    ("tr-TR", LanguageCode::Tr, &["TR"]),
+    ("uk", LanguageCode::Uk, &[]),
+    ("ur", LanguageCode::Ur, &[]),
+    ("vi", LanguageCode::Vi, &[]),
+    ("zu", LanguageCode::Zu, &[]),
+];
+
+fn to_store(listings: &[Listing], locale: Locale) -> Option<&'static str> {
+    let mut languages = listings
+        .iter()
+        .filter(|(_, language, _)| *language == locale.language);
+    let default = languages.clone().next()?;
+    let exact = locale
+        .region
+        .and_then(|region| languages.find(|(_, _, regions)| regions.contains(&region.as_str())));
+    Some(exact.unwrap_or(default).0)
+}
+
+fn from_store(listings: &[Listing], id: &str) -> Option<Locale> {
+    let (_, language, regions) = listings.iter().find(|(listing, _, _)| *listing == id)?;
+    Some(Locale::new(
+        *language,
+        regions.first().map(|region| Region::from_const(region)),
+    ))
+}
+
+/// Returns the App Store Connect listing locale to use for a locale, or `None` if the App Store
+/// doesn't support its language.
+#[must_use]
+pub fn app_store_locale(locale: Locale) -> Option<&'static str> {
+    to_store(APP_STORE, locale)
+}
+
+/// Parses an App Store Connect listing locale, like `en-US` or `zh-Hans`.
+#[must_use]
+pub fn from_app_store_locale(id: &str) -> Option<Locale> {
+    from_store(APP_STORE, id)
+}
+
+/// Returns the Google Play listing locale to use for a locale, or `None` if Google Play doesn't
+/// support its language.
+#[must_use]
+pub fn play_store_locale(locale: Locale) -> Option<&'static str> {
+    to_store(PLAY_STORE, locale)
+}
+
+/// Parses a Google Play listing locale, like `en-US` or `es-419`.
+#[must_use]
+pub fn from_play_store_locale(id: &str) -> Option<Locale> {
+    from_store(PLAY_STORE, id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        app_store_locale, from_app_store_locale, from_play_store_locale, play_store_locale,
+        APP_STORE, PLAY_STORE,
+    };
+    use crate::{LanguageCode, Locale};
+
+    fn locale(s: &str) -> Locale {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn app_store() {
+        assert_eq!(app_store_locale(locale("en")), Some("en-US"));
+        assert_eq!(app_store_locale(locale("en-GB")), Some("en-GB"));
+        assert_eq!(app_store_locale(locale("en-NZ")), Some("en-US"));
+        assert_eq!(app_store_locale(locale("zh-HK")), Some("zh-Hant"));
+        assert_eq!(app_store_locale(locale("nb-NO")), Some("no"));
+        assert_eq!(app_store_locale(locale("cy")), None);
+        assert_eq!(from_app_store_locale("ca"), Some(locale("ca")));
+        assert_eq!(from_app_store_locale("es-MX"), Some(locale("es-MX")));
+        assert_eq!(from_app_store_locale("en"), None);
+    }
+
+    #[test]
+    fn play_store() {
+        assert_eq!(play_store_locale(locale("es-419")), Some("es-419"));
+        assert_eq!(play_store_locale(locale("es-AR")), Some("es-ES"));
+        assert_eq!(play_store_locale(locale("he")), Some("iw-IL"));
+        assert_eq!(from_play_store_locale("iw-IL"), Some(locale("he-IL")));
+        assert_eq!(
+            from_play_store_locale("no-NO").unwrap().language,
+            LanguageCode::No
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for listings in [APP_STORE, PLAY_STORE] {
+            for &(id, _, _) in listings {
+                let locale = super::from_store(listings, id).unwrap();
+                assert_eq!(super::to_store(listings, locale), Some(id));
+            }
+        }
+    }
+}
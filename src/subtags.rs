@@ -0,0 +1,136 @@
+//! Metadata from the IANA Language Subtag Registry.
+
+use crate::parser::LEGACY_CODES;
+use crate::{LanguageCode, LANGUAGE_CODES};
+
+/// The date the ISO 639-1 codes were added to the registry, when it was created from the codes
+/// registered under RFC 3066.
+const REGISTRY_CREATED: &str = "2005-10-16";
+
+/// The registry record of an ISO 639-1 language subtag, returned by
+/// [`LanguageCode::subtag_record`] and [`LanguageCode::subtag_record_of`].
+///
+/// Dates are formatted as in the registry, `YYYY-MM-DD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubtagRecord {
+    /// The subtag, in lowercase.
+    pub subtag: &'static str,
+    /// The date the subtag was added to the registry.
+    pub added: &'static str,
+    /// The date the subtag was deprecated, if it has been.
+    pub deprecated: Option<&'static str>,
+    /// The language to use instead of a deprecated subtag.
+    pub preferred_value: Option<LanguageCode>,
+}
+
+impl SubtagRecord {
+    /// Returns whether the subtag is deprecated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert!(!LanguageCode::He.subtag_record().is_deprecated());
+    /// assert!(LanguageCode::subtag_record_of("iw").unwrap().is_deprecated());
+    /// ```
+    #[must_use]
+    pub const fn is_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+    }
+}
+
+impl LanguageCode {
+    /// Returns the language's record in the IANA Language Subtag Registry.
+    ///
+    /// None of the current ISO 639-1 codes are deprecated; use
+    /// [`subtag_record_of`](Self::subtag_record_of) to look up withdrawn codes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let record = LanguageCode::Id.subtag_record();
+    /// assert_eq!(record.subtag, "id");
+    /// assert_eq!(record.added, "2005-10-16");
+    /// assert_eq!(record.deprecated, None);
+    /// ```
+    #[must_use]
+    pub const fn subtag_record(self) -> SubtagRecord {
+        SubtagRecord {
+            subtag: self.code(),
+            added: REGISTRY_CREATED,
+            deprecated: None,
+            preferred_value: None,
+        }
+    }
+
+    /// Looks up the registry record of a 2 letter subtag, ignoring ASCII case. Unlike
+    /// [`subtag_record`](Self::subtag_record), this also finds the deprecated subtags `in`, `iw`,
+    /// `ji`, `jw` and `mo`, with the date they were deprecated and the language that replaced them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// let record = LanguageCode::subtag_record_of("mo").unwrap();
+    /// assert_eq!(record.deprecated, Some("2008-11-22"));
+    /// assert_eq!(record.preferred_value, Some(LanguageCode::Ro));
+    /// assert_eq!(LanguageCode::subtag_record_of("DE"), Some(LanguageCode::De.subtag_record()));
+    /// assert_eq!(LanguageCode::subtag_record_of("xx"), None);
+    /// ```
+    #[must_use]
+    pub fn subtag_record_of(subtag: &str) -> Option<SubtagRecord> {
+        if let Some(language) = LANGUAGE_CODES
+            .iter()
+            .find(|language| language.code().eq_ignore_ascii_case(subtag))
+        {
+            return Some(language.subtag_record());
+        }
+        LEGACY_CODES
+            .iter()
+            .find(|&&(code, _)| code.eq_ignore_ascii_case(subtag))
+            .map(|&(code, language)| SubtagRecord {
+                subtag: code,
+                added: REGISTRY_CREATED,
+                deprecated: Some(deprecated(code)),
+                preferred_value: Some(language),
+            })
+    }
+}
+
+/// Returns the date a withdrawn ISO 639-1 code was deprecated in the registry.
+fn deprecated(code: &str) -> &'static str {
+    match code {
+        "jw" => "2001-08-13",
+        "mo" => "2008-11-22",
+        _ => "1989-01-01",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::LEGACY_CODES;
+    use crate::LanguageCode;
+
+    #[test]
+    fn subtag_records() {
+        for language in LanguageCode::iter() {
+            let record = LanguageCode::subtag_record_of(language.code()).unwrap();
+            assert_eq!(record, language.subtag_record());
+            assert!(!record.is_deprecated());
+        }
+        for (code, language) in LEGACY_CODES {
+            let record = LanguageCode::subtag_record_of(code).unwrap();
+            assert_eq!(record.subtag, code);
+            assert_eq!(record.preferred_value, Some(language));
+            assert!(record.deprecated.unwrap() < "2010");
+        }
+        assert_eq!(
+            LanguageCode::subtag_record_of("IW").unwrap().deprecated,
+            Some("1989-01-01")
+        );
+    }
+}
@@ -0,0 +1,449 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter, Write};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{LanguageCode, Locale, Region};
+
+/// A [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag whose primary language has an
+/// ISO 639-1 code, like `en`, `zh-Hant-TW`, `sl-rozaj` or `th-TH-u-nu-thai`.
+///
+/// Parsing is case-insensitive, and the tag is normalized to the recommended case when displayed.
+/// Extensions such as Unicode (`-u-`) and transformed content (`-t-`) extensions, and private use
+/// subtags (`-x-`), are kept in the order they were given.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, LanguageTag};
+///
+/// let tag: LanguageTag = "ZH-hant-tw".parse().unwrap();
+/// assert_eq!(tag.language(), LanguageCode::Zh);
+/// assert_eq!(tag.script(), Some("Hant"));
+/// assert_eq!(tag.region().unwrap().as_str(), "TW");
+/// assert_eq!(tag.to_string(), "zh-Hant-TW");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LanguageTag {
+    language: LanguageCode,
+    script: Option<[u8; 4]>,
+    region: Option<Region>,
+    variants: Vec<String>,
+    /// Extension singletons and their subtags, like `(b'u', "ca-buddhist")`.
+    extensions: Vec<(u8, String)>,
+    private_use: Option<String>,
+}
+
+impl LanguageTag {
+    /// Creates a tag with only a primary language.
+    #[must_use]
+    pub const fn new(language: LanguageCode) -> Self {
+        Self {
+            language,
+            script: None,
+            region: None,
+            variants: Vec::new(),
+            extensions: Vec::new(),
+            private_use: None,
+        }
+    }
+
+    /// Returns the primary language of the tag.
+    #[must_use]
+    pub const fn language(&self) -> LanguageCode {
+        self.language
+    }
+
+    /// Returns the ISO 15924 script subtag of the tag, like `Latn`.
+    #[must_use]
+    pub fn script(&self) -> Option<&str> {
+        self.script
+            .as_ref()
+            .map(|script| core::str::from_utf8(script).expect("scripts are ASCII"))
+    }
+
+    /// Returns the region subtag of the tag.
+    #[must_use]
+    pub const fn region(&self) -> Option<Region> {
+        self.region
+    }
+
+    /// Returns the variant subtags of the tag, like `rozaj`.
+    #[must_use]
+    pub fn variants(&self) -> &[String] {
+        &self.variants
+    }
+
+    /// Returns the subtags of an extension in lower case, like `ca-buddhist` for the `u` extension
+    /// of `th-u-ca-buddhist`.
+    ///
+    /// The singleton is matched ignoring ASCII case. Private use subtags are returned by
+    /// [`private_use`](Self::private_use) instead.
+    #[must_use]
+    pub fn extension(&self, singleton: char) -> Option<&str> {
+        let singleton = u8::try_from(singleton).ok()?.to_ascii_lowercase();
+        self.extensions
+            .iter()
+            .find(|&&(other, _)| other == singleton)
+            .map(|(_, subtags)| subtags.as_str())
+    }
+
+    /// Returns the private use subtags of the tag in lower case, like `foo-bar` for
+    /// `en-x-foo-bar`.
+    #[must_use]
+    pub fn private_use(&self) -> Option<&str> {
+        self.private_use.as_deref()
+    }
+
+    /// Returns the value of a Unicode extension keyword, like `buddhist` for the key `ca` of
+    /// `th-u-ca-buddhist`.
+    ///
+    /// Keywords without a value, like `kn` in `de-u-co-phonebk-kn`, have the value `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageTag;
+    ///
+    /// let tag: LanguageTag = "de-DE-u-co-phonebk-kn-hc-h23".parse().unwrap();
+    /// assert_eq!(tag.unicode_keyword("co"), Some("phonebk"));
+    /// assert_eq!(tag.unicode_keyword("kn"), Some("true"));
+    /// assert_eq!(tag.hour_cycle(), Some("h23"));
+    /// assert_eq!(tag.calendar(), None);
+    /// ```
+    #[must_use]
+    pub fn unicode_keyword(&self, key: &str) -> Option<&str> {
+        let extension = self.extension('u')?;
+        let mut value: Option<(usize, usize)> = None;
+        let mut offset = 0;
+        for subtag in extension.split('-') {
+            let end = offset + subtag.len();
+            match value {
+                None if subtag.len() == 2 && subtag.eq_ignore_ascii_case(key) => {
+                    value = Some((end + 1, end + 1));
+                }
+                Some(_) if subtag.len() == 2 => break,
+                Some((start, _)) => value = Some((start, end)),
+                None => {}
+            }
+            offset = end + 1;
+        }
+        match value? {
+            (start, end) if start < end => Some(&extension[start..end]),
+            _ => Some("true"),
+        }
+    }
+
+    /// Returns the calendar of the tag's Unicode extension, like `buddhist` for `-u-ca-buddhist`.
+    #[must_use]
+    pub fn calendar(&self) -> Option<&str> {
+        self.unicode_keyword("ca")
+    }
+
+    /// Returns the collation of the tag's Unicode extension, like `phonebk` for `-u-co-phonebk`.
+    #[must_use]
+    pub fn collation(&self) -> Option<&str> {
+        self.unicode_keyword("co")
+    }
+
+    /// Returns the hour cycle of the tag's Unicode extension, like `h23` for `-u-hc-h23`.
+    #[must_use]
+    pub fn hour_cycle(&self) -> Option<&str> {
+        self.unicode_keyword("hc")
+    }
+
+    /// Returns the numbering system of the tag's Unicode extension, like `thai` for `-u-nu-thai`.
+    #[must_use]
+    pub fn numbering_system(&self) -> Option<&str> {
+        self.unicode_keyword("nu")
+    }
+
+    /// Returns the language and region of the tag, discarding its other subtags.
+    #[must_use]
+    pub const fn locale(&self) -> Locale {
+        Locale::new(self.language, self.region)
+    }
+}
+
+impl LanguageCode {
+    /// Returns the primary language of a BCP 47 language tag, like the `Accept-Language` header or
+    /// the HTML `lang` attribute. The whole tag is validated, as by [`LanguageTag`]'s `FromStr`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the tag isn't valid or its primary language has no ISO 639-1 code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::LanguageCode;
+    ///
+    /// assert_eq!(LanguageCode::from_language_tag("en-US").unwrap(), LanguageCode::En);
+    /// assert_eq!(LanguageCode::from_language_tag("zh-Hant-TW").unwrap(), LanguageCode::Zh);
+    /// assert!(LanguageCode::from_language_tag("en_US").is_err());
+    /// ```
+    pub fn from_language_tag(tag: &str) -> Result<Self, ParseLanguageTagError> {
+        tag.parse::<LanguageTag>().map(|tag| tag.language)
+    }
+}
+
+impl From<LanguageCode> for LanguageTag {
+    fn from(language: LanguageCode) -> Self {
+        Self::new(language)
+    }
+}
+
+impl From<Locale> for LanguageTag {
+    fn from(locale: Locale) -> Self {
+        Self {
+            region: locale.region,
+            ..Self::new(locale.language)
+        }
+    }
+}
+
+impl Display for LanguageTag {
+    /// Writes the tag in its normalized case.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = String::from(self.language.code());
+        if let Some(script) = self.script() {
+            write!(s, "-{}", script)?;
+        }
+        if let Some(region) = self.region {
+            write!(s, "-{}", region)?;
+        }
+        for variant in &self.variants {
+            write!(s, "-{}", variant)?;
+        }
+        for (singleton, subtags) in &self.extensions {
+            write!(s, "-{}-{}", char::from(*singleton), subtags)?;
+        }
+        if let Some(private_use) = &self.private_use {
+            write!(s, "-x-{}", private_use)?;
+        }
+        f.pad(&s)
+    }
+}
+
+impl FromStr for LanguageTag {
+    type Err = ParseLanguageTagError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseLanguageTagError { tag: s.to_owned() };
+
+        let mut subtags = s.split('-').peekable();
+        if subtags
+            .clone()
+            .any(|subtag| subtag.is_empty() || subtag.len() > 8 || !is_alphanumeric(subtag))
+        {
+            return Err(error());
+        }
+
+        let language = subtags
+            .next()
+            .filter(|language| language.len() == 2)
+            .and_then(|language| language.to_ascii_lowercase().parse().ok())
+            .ok_or_else(error)?;
+        let mut tag = Self::new(language);
+
+        if let Some(script) = subtags.next_if(|subtag| subtag.len() == 4 && is_alphabetic(subtag)) {
+            let mut bytes = [0; 4];
+            for (i, byte) in script.bytes().enumerate() {
+                bytes[i] = if i == 0 {
+                    byte.to_ascii_uppercase()
+                } else {
+                    byte.to_ascii_lowercase()
+                };
+            }
+            tag.script = Some(bytes);
+        }
+
+        if let Some(region) = subtags.peek().and_then(|region| Region::new(region)) {
+            subtags.next();
+            tag.region = Some(region);
+        }
+
+        while let Some(variant) = subtags.next_if(|subtag| subtag.len() > 1) {
+            let starts_with_digit = variant.as_bytes()[0].is_ascii_digit();
+            if !(variant.len() >= 5 || variant.len() == 4 && starts_with_digit) {
+                return Err(error());
+            }
+            let variant = variant.to_ascii_lowercase();
+            if tag.variants.contains(&variant) {
+                return Err(error());
+            }
+            tag.variants.push(variant);
+        }
+
+        while let Some(singleton) = subtags.next() {
+            let singleton = singleton.as_bytes()[0].to_ascii_lowercase();
+            let mut extension: Vec<&str> = Vec::new();
+            if singleton == b'x' {
+                extension.extend(subtags.by_ref());
+            } else {
+                while let Some(subtag) = subtags.next_if(|subtag| subtag.len() > 1) {
+                    extension.push(subtag);
+                }
+            }
+            let extension = extension.join("-").to_ascii_lowercase();
+            if extension.is_empty() {
+                return Err(error());
+            }
+            if singleton == b'x' {
+                tag.private_use = Some(extension);
+            } else if tag.extensions.iter().any(|&(other, _)| other == singleton) {
+                return Err(error());
+            } else {
+                tag.extensions.push((singleton, extension));
+            }
+        }
+
+        Ok(tag)
+    }
+}
+
+fn is_alphanumeric(s: &str) -> bool {
+    s.bytes().all(|byte| byte.is_ascii_alphanumeric())
+}
+
+fn is_alphabetic(s: &str) -> bool {
+    s.bytes().all(|byte| byte.is_ascii_alphabetic())
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for LanguageTag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for LanguageTag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// An error parsing a [`LanguageTag`].
+#[derive(Debug, Clone)]
+pub struct ParseLanguageTagError {
+    /// The tag that could not be parsed.
+    pub tag: String,
+}
+
+impl Display for ParseLanguageTagError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} is not a valid BCP 47 tag with an ISO 639-1 language",
+            self.tag
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, LanguageTag, Locale};
+
+    fn normalize(s: &str) -> Option<String> {
+        s.parse::<LanguageTag>().ok().map(|tag| tag.to_string())
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(normalize("en").as_deref(), Some("en"));
+        assert_eq!(normalize("EN-us").as_deref(), Some("en-US"));
+        assert_eq!(normalize("sr-latn").as_deref(), Some("sr-Latn"));
+        assert_eq!(normalize("es-419").as_deref(), Some("es-419"));
+        assert_eq!(
+            normalize("de-CH-1901-Rozaj").as_deref(),
+            Some("de-CH-1901-rozaj")
+        );
+        assert_eq!(
+            format!("[{:<12}]", "zh-hant-tw".parse::<LanguageTag>().unwrap()),
+            "[zh-Hant-TW  ]"
+        );
+        assert_eq!(
+            "pt-BR".parse::<LanguageTag>().unwrap().locale(),
+            "pt-BR".parse::<Locale>().unwrap()
+        );
+    }
+
+    #[test]
+    fn extensions() {
+        let tag: LanguageTag = "EN-US-u-CA-gregory-NU-latn-t-ja-x-Private-1"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            tag.to_string(),
+            "en-US-u-ca-gregory-nu-latn-t-ja-x-private-1"
+        );
+        assert_eq!(tag.region().unwrap().as_str(), "US");
+        assert_eq!(tag.extension('U'), Some("ca-gregory-nu-latn"));
+        assert_eq!(tag.extension('t'), Some("ja"));
+        assert_eq!(tag.extension('x'), None);
+        assert_eq!(tag.private_use(), Some("private-1"));
+        assert_eq!(tag.calendar(), Some("gregory"));
+        assert_eq!(tag.numbering_system(), Some("latn"));
+        assert_eq!(tag.collation(), None);
+
+        let tag: LanguageTag = "ja-JP-u-ca-japanese-islamic-kn".parse().unwrap();
+        assert_eq!(tag.calendar(), Some("japanese-islamic"));
+        assert_eq!(tag.unicode_keyword("kn"), Some("true"));
+        assert_eq!(normalize("sl-rozaj-x-a").as_deref(), Some("sl-rozaj-x-a"));
+    }
+
+    #[test]
+    fn invalid() {
+        for tag in [
+            "",
+            "e",
+            "eng",
+            "xx",
+            "en-",
+            "en--US",
+            "en_US",
+            "en-USA",
+            "en-1901-1901",
+            "en-a",
+            "en-u",
+            "en-u-ca-gregory-u-nu-latn",
+            "en-x",
+            "en-u-x-foo",
+            "en-verylongsubtag",
+        ] {
+            assert!(tag.parse::<LanguageTag>().is_err(), "{}", tag);
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(LanguageTag::from(LanguageCode::Fr).to_string(), "fr");
+        let locale = "fr-CA".parse::<Locale>().unwrap();
+        assert_eq!(LanguageTag::from(locale).to_string(), "fr-CA");
+    }
+
+    #[test]
+    fn from_language_tag() {
+        assert_eq!(
+            LanguageCode::from_language_tag("PT-br").unwrap(),
+            LanguageCode::Pt
+        );
+        assert_eq!(
+            LanguageCode::from_language_tag("sl-rozaj-biske").unwrap(),
+            LanguageCode::Sl
+        );
+        assert_eq!(
+            LanguageCode::from_language_tag("de").unwrap(),
+            LanguageCode::De
+        );
+        assert!(LanguageCode::from_language_tag("gsw-CH").is_err());
+        assert!(LanguageCode::from_language_tag("").is_err());
+    }
+}
@@ -0,0 +1,82 @@
+//! Zero-copy conversions between languages and bytes, for languages embedded in plain data
+//! structures. Enabled by the `bytemuck` and `zerocopy` features.
+//!
+//! A language is stored as its `u8` discriminant, its index in [`LANGUAGE_CODES`]. Bytes outside
+//! that range are rejected by the checked conversions.
+//!
+//! [`LANGUAGE_CODES`]: crate::LANGUAGE_CODES
+
+#[cfg(feature = "bytemuck")]
+use crate::{LanguageCode, LANGUAGE_CODES};
+
+// SAFETY: `LanguageCode` is a fieldless `#[repr(u8)]` enum whose discriminants are every value
+// from 0 to `LANGUAGE_CODES.len() - 1`.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Contiguous for LanguageCode {
+    type Int = u8;
+    const MIN_VALUE: u8 = 0;
+    const MAX_VALUE: u8 = LANGUAGE_CODES.len() as u8 - 1;
+}
+
+// SAFETY: `LanguageCode` is a `#[repr(u8)]` enum with no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::NoUninit for LanguageCode {}
+
+// SAFETY: every `u8` accepted by `is_valid_bit_pattern` is the discriminant of a variant.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::CheckedBitPattern for LanguageCode {
+    type Bits = u8;
+
+    fn is_valid_bit_pattern(bits: &u8) -> bool {
+        usize::from(*bits) < LANGUAGE_CODES.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, LANGUAGE_CODES};
+
+    #[test]
+    fn discriminants() {
+        for (i, language) in LANGUAGE_CODES.into_iter().enumerate() {
+            assert_eq!(language as usize, i);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn bytemuck() {
+        use bytemuck::checked;
+        use bytemuck::Contiguous;
+
+        assert_eq!(
+            LanguageCode::from_integer(LanguageCode::Zu as u8),
+            Some(LanguageCode::Zu)
+        );
+        assert_eq!(LanguageCode::from_integer(184), None);
+        assert_eq!(
+            checked::try_cast_slice::<u8, LanguageCode>(&[0, 183]).unwrap(),
+            [LanguageCode::Ab, LanguageCode::Zu]
+        );
+        assert!(checked::try_cast_slice::<u8, LanguageCode>(&[0, 184]).is_err());
+        assert_eq!(bytemuck::bytes_of(&LanguageCode::Aa), [1]);
+    }
+
+    #[test]
+    #[cfg(feature = "zerocopy")]
+    fn zerocopy() {
+        use zerocopy::{Immutable, IntoBytes, KnownLayout, TryFromBytes};
+
+        #[derive(Debug, TryFromBytes, IntoBytes, KnownLayout, Immutable)]
+        #[repr(C)]
+        struct Record {
+            language: LanguageCode,
+            level: u8,
+        }
+
+        let record = Record::try_ref_from_bytes(&[183, 2]).unwrap();
+        assert_eq!(record.language, LanguageCode::Zu);
+        assert_eq!(record.as_bytes(), [183, 2]);
+        assert!(Record::try_ref_from_bytes(&[200, 2]).is_err());
+    }
+}
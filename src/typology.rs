@@ -0,0 +1,373 @@
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::LanguageCode;
+
+/// The dominant order of subject, object and verb in a clause, following
+/// [WALS feature 81A](https://wals.info/feature/81A).
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, WordOrder};
+///
+/// assert_eq!(LanguageCode::Ja.word_order(), Some(WordOrder::Sov));
+/// assert_eq!(WordOrder::Vso.to_string(), "VSO");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum WordOrder {
+    /// Subject, object, verb, like Japanese.
+    Sov,
+    /// Subject, verb, object, like English.
+    Svo,
+    /// Verb, subject, object, like Irish.
+    Vso,
+    /// Verb, object, subject, like Malagasy.
+    Vos,
+    /// Object, verb, subject.
+    Ovs,
+    /// Object, subject, verb.
+    Osv,
+    /// No single order is dominant, like in German, which is SOV in subordinate clauses and
+    /// verb-second in main clauses.
+    NoDominant,
+}
+
+impl WordOrder {
+    /// Returns the abbreviation of the order, like `SOV`, or `none` if no order is dominant.
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Sov => "SOV",
+            Self::Svo => "SVO",
+            Self::Vso => "VSO",
+            Self::Vos => "VOS",
+            Self::Ovs => "OVS",
+            Self::Osv => "OSV",
+            Self::NoDominant => "none",
+        }
+    }
+}
+
+impl Display for WordOrder {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.abbreviation())
+    }
+}
+
+/// How a language distinguishes formal and informal address.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{Formality, LanguageCode};
+///
+/// assert_eq!(LanguageCode::De.formality(), Some(Formality::Pronominal));
+/// assert_eq!(LanguageCode::Ko.formality(), Some(Formality::Honorific));
+/// assert!(!LanguageCode::En.has_formality_distinction());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum Formality {
+    /// Separate informal and formal second person pronouns (a T–V distinction), like German `du`
+    /// and `Sie`.
+    Pronominal,
+    /// A system of honorific speech levels marked on verbs and vocabulary, like Japanese keigo.
+    Honorific,
+}
+
+impl LanguageCode {
+    /// Returns the dominant order of subject, object and verb in the language.
+    ///
+    /// Following WALS, German and Dutch, which are verb-second in main clauses and verb-final in
+    /// subordinate clauses, return [`WordOrder::NoDominant`]. Languages without reliable data, such
+    /// as historical languages, return `None`.
+    #[must_use]
+    pub const fn word_order(self) -> Option<WordOrder> {
+        match self {
+            Self::Aa
+            | Self::Ab
+            | Self::Am
+            | Self::As
+            | Self::Av
+            | Self::Ay
+            | Self::Az
+            | Self::Ba
+            | Self::Bh
+            | Self::Bm
+            | Self::Bn
+            | Self::Bo
+            | Self::Ce
+            | Self::Cv
+            | Self::Dv
+            | Self::Dz
+            | Self::Eu
+            | Self::Fa
+            | Self::Gu
+            | Self::Hi
+            | Self::Ho
+            | Self::Hy
+            | Self::Ii
+            | Self::Ik
+            | Self::Iu
+            | Self::Ja
+            | Self::Ka
+            | Self::Kk
+            | Self::Kl
+            | Self::Kn
+            | Self::Ko
+            | Self::Kr
+            | Self::Ku
+            | Self::Ky
+            | Self::Ml
+            | Self::Mn
+            | Self::Mr
+            | Self::My
+            | Self::Ne
+            | Self::Nv
+            | Self::Om
+            | Self::Or
+            | Self::Os
+            | Self::Pa
+            | Self::Pi
+            | Self::Ps
+            | Self::Qu
+            | Self::Sa
+            | Self::Sd
+            | Self::Si
+            | Self::So
+            | Self::Ta
+            | Self::Te
+            | Self::Tg
+            | Self::Ti
+            | Self::Tk
+            | Self::Tr
+            | Self::Tt
+            | Self::Ug
+            | Self::Ur
+            | Self::Uz => Some(WordOrder::Sov),
+            Self::Ak
+            | Self::An
+            | Self::Be
+            | Self::Bg
+            | Self::Bi
+            | Self::Bs
+            | Self::Ca
+            | Self::Co
+            | Self::Cs
+            | Self::Da
+            | Self::Ee
+            | Self::En
+            | Self::Eo
+            | Self::Es
+            | Self::Et
+            | Self::Ff
+            | Self::Fi
+            | Self::Fo
+            | Self::Fr
+            | Self::Gl
+            | Self::Gn
+            | Self::Ha
+            | Self::He
+            | Self::Hr
+            | Self::Ht
+            | Self::Hz
+            | Self::Ia
+            | Self::Id
+            | Self::Ie
+            | Self::Ig
+            | Self::Io
+            | Self::Is
+            | Self::It
+            | Self::Jv
+            | Self::Kg
+            | Self::Ki
+            | Self::Kj
+            | Self::Km
+            | Self::Lg
+            | Self::Ln
+            | Self::Lo
+            | Self::Lt
+            | Self::Lu
+            | Self::Lv
+            | Self::Mh
+            | Self::Mk
+            | Self::Ms
+            | Self::Mt
+            | Self::Nb
+            | Self::Nd
+            | Self::Ng
+            | Self::Nn
+            | Self::No
+            | Self::Nr
+            | Self::Ny
+            | Self::Oc
+            | Self::Pl
+            | Self::Pt
+            | Self::Rm
+            | Self::Rn
+            | Self::Ro
+            | Self::Ru
+            | Self::Rw
+            | Self::Sc
+            | Self::Se
+            | Self::Sg
+            | Self::Sk
+            | Self::Sl
+            | Self::Sn
+            | Self::Sq
+            | Self::Sr
+            | Self::Ss
+            | Self::St
+            | Self::Su
+            | Self::Sv
+            | Self::Sw
+            | Self::Th
+            | Self::Tn
+            | Self::Ts
+            | Self::Tw
+            | Self::Uk
+            | Self::Ve
+            | Self::Vi
+            | Self::Vo
+            | Self::Wa
+            | Self::Wo
+            | Self::Xh
+            | Self::Yi
+            | Self::Yo
+            | Self::Za
+            | Self::Zh
+            | Self::Zu => Some(WordOrder::Svo),
+            Self::Ar
+            | Self::Ch
+            | Self::Cy
+            | Self::Ga
+            | Self::Gd
+            | Self::Gv
+            | Self::Mi
+            | Self::Sm
+            | Self::Tl
+            | Self::To
+            | Self::Ty => Some(WordOrder::Vso),
+            Self::Fj | Self::Mg => Some(WordOrder::Vos),
+            Self::Cr | Self::De | Self::El | Self::Hu | Self::Nl => Some(WordOrder::NoDominant),
+            _ => None,
+        }
+    }
+
+    /// Returns how the language distinguishes formal and informal address, which determines
+    /// whether translations into it need a register to be chosen.
+    ///
+    /// Returns `None` for languages without such a distinction, like English, and for languages
+    /// the crate has no data for.
+    #[must_use]
+    pub const fn formality(self) -> Option<Formality> {
+        match self {
+            Self::Af
+            | Self::Az
+            | Self::Ba
+            | Self::Be
+            | Self::Bg
+            | Self::Bn
+            | Self::Bs
+            | Self::Ca
+            | Self::Cs
+            | Self::Cy
+            | Self::De
+            | Self::El
+            | Self::Es
+            | Self::Et
+            | Self::Eu
+            | Self::Fa
+            | Self::Fi
+            | Self::Fr
+            | Self::Fy
+            | Self::Gl
+            | Self::Gu
+            | Self::Hi
+            | Self::Hr
+            | Self::Hu
+            | Self::Hy
+            | Self::Id
+            | Self::It
+            | Self::Ka
+            | Self::Kk
+            | Self::Kn
+            | Self::Ky
+            | Self::Lb
+            | Self::Lt
+            | Self::Lv
+            | Self::Mk
+            | Self::Ml
+            | Self::Mn
+            | Self::Mr
+            | Self::Ms
+            | Self::Ne
+            | Self::Nl
+            | Self::Oc
+            | Self::Pa
+            | Self::Pl
+            | Self::Pt
+            | Self::Ro
+            | Self::Ru
+            | Self::Si
+            | Self::Sk
+            | Self::Sl
+            | Self::Sq
+            | Self::Sr
+            | Self::Ta
+            | Self::Te
+            | Self::Tk
+            | Self::Tr
+            | Self::Tt
+            | Self::Uk
+            | Self::Ur
+            | Self::Uz
+            | Self::Yi
+            | Self::Zh => Some(Formality::Pronominal),
+            Self::Bo
+            | Self::Ja
+            | Self::Jv
+            | Self::Km
+            | Self::Ko
+            | Self::Lo
+            | Self::My
+            | Self::Su
+            | Self::Th => Some(Formality::Honorific),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the language distinguishes formal and informal address. Equivalent to
+    /// `self.formality().is_some()`.
+    #[must_use]
+    pub const fn has_formality_distinction(self) -> bool {
+        self.formality().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formality, LanguageCode, WordOrder};
+
+    #[test]
+    fn word_order() {
+        assert_eq!(LanguageCode::En.word_order(), Some(WordOrder::Svo));
+        assert_eq!(LanguageCode::Cy.word_order(), Some(WordOrder::Vso));
+        assert_eq!(LanguageCode::De.word_order(), Some(WordOrder::NoDominant));
+        assert_eq!(LanguageCode::La.word_order(), None);
+    }
+
+    #[test]
+    fn formality() {
+        assert_eq!(LanguageCode::Fr.formality(), Some(Formality::Pronominal));
+        assert_eq!(LanguageCode::Ja.formality(), Some(Formality::Honorific));
+        assert!(LanguageCode::Ru.has_formality_distinction());
+        assert!(!LanguageCode::Ga.has_formality_distinction());
+    }
+}
@@ -0,0 +1,172 @@
+//! Checks the crate's tables against an embedded snapshot of the official registry. Enabled by the
+//! `registry-snapshot` feature.
+//!
+//! The snapshot covers the ISO 639-1 codes and their ISO 639-2 codes. Language names aren't
+//! checked, since the crate uses the short ISO 639-1 names rather than ISO 639-3 reference names.
+//!
+//! # Examples
+//!
+//! ```
+//! use isolanguage_1::validate;
+//!
+//! let report = validate::check_integrity();
+//! assert!(report.is_ok(), "{}", report);
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use crate::LanguageCode;
+
+mod registry;
+
+/// Compares every language against the registry snapshot.
+#[must_use]
+pub fn check_integrity() -> IntegrityReport {
+    check(&registry::REGISTRY)
+}
+
+fn check(registry: &[(&'static str, &'static str, &'static str)]) -> IntegrityReport {
+    let mut report = IntegrityReport::default();
+
+    for &(code, code_t, code_b) in registry {
+        let language = match code.parse::<LanguageCode>() {
+            Ok(language) => language,
+            Err(_) => {
+                report.missing.push(code);
+                continue;
+            }
+        };
+        for (field, expected, actual) in [
+            (Field::CodeT, code_t, language.code_t()),
+            (Field::CodeB, code_b, language.code_b()),
+        ] {
+            if expected != actual {
+                report.mismatches.push(Mismatch {
+                    language,
+                    field,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    report.unregistered = LanguageCode::iter()
+        .filter(|language| !registry.iter().any(|&(code, _, _)| code == language.code()))
+        .collect();
+
+    report
+}
+
+/// The differences between the crate's tables and the registry snapshot, returned by
+/// [`check_integrity`].
+///
+/// Displays as one line per difference.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// Codes in the registry that the crate doesn't have.
+    pub missing: Vec<&'static str>,
+    /// Languages in the crate that aren't in the registry.
+    pub unregistered: Vec<LanguageCode>,
+    /// Languages whose codes differ from the registry.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl IntegrityReport {
+    /// Returns whether the crate's tables match the registry exactly.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.unregistered.is_empty() && self.mismatches.is_empty()
+    }
+}
+
+impl Display for IntegrityReport {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for code in &self.missing {
+            writeln!(f, "missing: {}", code)?;
+        }
+        for language in &self.unregistered {
+            writeln!(f, "unregistered: {}", language.code())?;
+        }
+        for mismatch in &self.mismatches {
+            writeln!(f, "{}", mismatch)?;
+        }
+        Ok(())
+    }
+}
+
+/// A code of a language that differs from the registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The language whose code differs.
+    pub language: LanguageCode,
+    /// Which code differs.
+    pub field: Field,
+    /// The code in the registry.
+    pub expected: &'static str,
+    /// The code in the crate.
+    pub actual: &'static str,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: expected {}, found {}",
+            self.language.code(),
+            self.field,
+            self.expected,
+            self.actual
+        )
+    }
+}
+
+/// A code checked against the registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    /// The ISO 639-2/T code, [`LanguageCode::code_t`].
+    CodeT,
+    /// The ISO 639-2/B code, [`LanguageCode::code_b`].
+    CodeB,
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::CodeT => "code_t",
+            Self::CodeB => "code_b",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, check_integrity, Field, Mismatch};
+    use crate::LanguageCode;
+
+    #[test]
+    fn integrity() {
+        let report = check_integrity();
+        assert!(report.is_ok(), "{}", report);
+    }
+
+    #[test]
+    fn differences() {
+        let report = check(&[("xx", "xxx", "xxx"), ("nl", "nld", "nld")]);
+        assert_eq!(report.missing, ["xx"]);
+        assert_eq!(report.unregistered.len(), 183);
+        assert_eq!(
+            report.mismatches,
+            [Mismatch {
+                language: LanguageCode::Nl,
+                field: Field::CodeB,
+                expected: "nld",
+                actual: "dut",
+            }]
+        );
+        assert!(report
+            .to_string()
+            .contains("nl code_b: expected nld, found dut\n"));
+    }
+}
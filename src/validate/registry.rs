@@ -0,0 +1,194 @@
+//! A snapshot of the ISO 639-1 codes and their ISO 639-2 codes, from the ISO 639-3 code table
+//! published by SIL International.
+
+/// `(ISO 639-1, ISO 639-2/T, ISO 639-2/B)` codes, sorted by ISO 639-1 code.
+///
+/// `bh` is a collective code, which ISO 639-3 doesn't cover; its row is from the ISO 639-2
+/// registry. `sh` (Serbo-Croatian) is listed by ISO 639-3 but was withdrawn from ISO 639-1, so it
+/// is left out.
+pub(super) const REGISTRY: [(&str, &str, &str); 184] = [
+    ("aa", "aar", "aar"),
+    ("ab", "abk", "abk"),
+    ("ae", "ave", "ave"),
+    ("af", "afr", "afr"),
+    ("ak", "aka", "aka"),
+    ("am", "amh", "amh"),
+    ("an", "arg", "arg"),
+    ("ar", "ara", "ara"),
+    ("as", "asm", "asm"),
+    ("av", "ava", "ava"),
+    ("ay", "aym", "aym"),
+    ("az", "aze", "aze"),
+    ("ba", "bak", "bak"),
+    ("be", "bel", "bel"),
+    ("bg", "bul", "bul"),
+    ("bh", "bih", "bih"),
+    ("bi", "bis", "bis"),
+    ("bm", "bam", "bam"),
+    ("bn", "ben", "ben"),
+    ("bo", "bod", "tib"),
+    ("br", "bre", "bre"),
+    ("bs", "bos", "bos"),
+    ("ca", "cat", "cat"),
+    ("ce", "che", "che"),
+    ("ch", "cha", "cha"),
+    ("co", "cos", "cos"),
+    ("cr", "cre", "cre"),
+    ("cs", "ces", "cze"),
+    ("cu", "chu", "chu"),
+    ("cv", "chv", "chv"),
+    ("cy", "cym", "wel"),
+    ("da", "dan", "dan"),
+    ("de", "deu", "ger"),
+    ("dv", "div", "div"),
+    ("dz", "dzo", "dzo"),
+    ("ee", "ewe", "ewe"),
+    ("el", "ell", "gre"),
+    ("en", "eng", "eng"),
+    ("eo", "epo", "epo"),
+    ("es", "spa", "spa"),
+    ("et", "est", "est"),
+    ("eu", "eus", "baq"),
+    ("fa", "fas", "per"),
+    ("ff", "ful", "ful"),
+    ("fi", "fin", "fin"),
+    ("fj", "fij", "fij"),
+    ("fo", "fao", "fao"),
+    ("fr", "fra", "fre"),
+    ("fy", "fry", "fry"),
+    ("ga", "gle", "gle"),
+    ("gd", "gla", "gla"),
+    ("gl", "glg", "glg"),
+    ("gn", "grn", "grn"),
+    ("gu", "guj", "guj"),
+    ("gv", "glv", "glv"),
+    ("ha", "hau", "hau"),
+    ("he", "heb", "heb"),
+    ("hi", "hin", "hin"),
+    ("ho", "hmo", "hmo"),
+    ("hr", "hrv", "hrv"),
+    ("ht", "hat", "hat"),
+    ("hu", "hun", "hun"),
+    ("hy", "hye", "arm"),
+    ("hz", "her", "her"),
+    ("ia", "ina", "ina"),
+    ("id", "ind", "ind"),
+    ("ie", "ile", "ile"),
+    ("ig", "ibo", "ibo"),
+    ("ii", "iii", "iii"),
+    ("ik", "ipk", "ipk"),
+    ("io", "ido", "ido"),
+    ("is", "isl", "ice"),
+    ("it", "ita", "ita"),
+    ("iu", "iku", "iku"),
+    ("ja", "jpn", "jpn"),
+    ("jv", "jav", "jav"),
+    ("ka", "kat", "geo"),
+    ("kg", "kon", "kon"),
+    ("ki", "kik", "kik"),
+    ("kj", "kua", "kua"),
+    ("kk", "kaz", "kaz"),
+    ("kl", "kal", "kal"),
+    ("km", "khm", "khm"),
+    ("kn", "kan", "kan"),
+    ("ko", "kor", "kor"),
+    ("kr", "kau", "kau"),
+    ("ks", "kas", "kas"),
+    ("ku", "kur", "kur"),
+    ("kv", "kom", "kom"),
+    ("kw", "cor", "cor"),
+    ("ky", "kir", "kir"),
+    ("la", "lat", "lat"),
+    ("lb", "ltz", "ltz"),
+    ("lg", "lug", "lug"),
+    ("li", "lim", "lim"),
+    ("ln", "lin", "lin"),
+    ("lo", "lao", "lao"),
+    ("lt", "lit", "lit"),
+    ("lu", "lub", "lub"),
+    ("lv", "lav", "lav"),
+    ("mg", "mlg", "mlg"),
+    ("mh", "mah", "mah"),
+    ("mi", "mri", "mao"),
+    ("mk", "mkd", "mac"),
+    ("ml", "mal", "mal"),
+    ("mn", "mon", "mon"),
+    ("mr", "mar", "mar"),
+    ("ms", "msa", "may"),
+    ("mt", "mlt", "mlt"),
+    ("my", "mya", "bur"),
+    ("na", "nau", "nau"),
+    ("nb", "nob", "nob"),
+    ("nd", "nde", "nde"),
+    ("ne", "nep", "nep"),
+    ("ng", "ndo", "ndo"),
+    ("nl", "nld", "dut"),
+    ("nn", "nno", "nno"),
+    ("no", "nor", "nor"),
+    ("nr", "nbl", "nbl"),
+    ("nv", "nav", "nav"),
+    ("ny", "nya", "nya"),
+    ("oc", "oci", "oci"),
+    ("oj", "oji", "oji"),
+    ("om", "orm", "orm"),
+    ("or", "ori", "ori"),
+    ("os", "oss", "oss"),
+    ("pa", "pan", "pan"),
+    ("pi", "pli", "pli"),
+    ("pl", "pol", "pol"),
+    ("ps", "pus", "pus"),
+    ("pt", "por", "por"),
+    ("qu", "que", "que"),
+    ("rm", "roh", "roh"),
+    ("rn", "run", "run"),
+    ("ro", "ron", "rum"),
+    ("ru", "rus", "rus"),
+    ("rw", "kin", "kin"),
+    ("sa", "san", "san"),
+    ("sc", "srd", "srd"),
+    ("sd", "snd", "snd"),
+    ("se", "sme", "sme"),
+    ("sg", "sag", "sag"),
+    ("si", "sin", "sin"),
+    ("sk", "slk", "slo"),
+    ("sl", "slv", "slv"),
+    ("sm", "smo", "smo"),
+    ("sn", "sna", "sna"),
+    ("so", "som", "som"),
+    ("sq", "sqi", "alb"),
+    ("sr", "srp", "srp"),
+    ("ss", "ssw", "ssw"),
+    ("st", "sot", "sot"),
+    ("su", "sun", "sun"),
+    ("sv", "swe", "swe"),
+    ("sw", "swa", "swa"),
+    ("ta", "tam", "tam"),
+    ("te", "tel", "tel"),
+    ("tg", "tgk", "tgk"),
+    ("th", "tha", "tha"),
+    ("ti", "tir", "tir"),
+    ("tk", "tuk", "tuk"),
+    ("tl", "tgl", "tgl"),
+    ("tn", "tsn", "tsn"),
+    ("to", "ton", "ton"),
+    ("tr", "tur", "tur"),
+    ("ts", "tso", "tso"),
+    ("tt", "tat", "tat"),
+    ("tw", "twi", "twi"),
+    ("ty", "tah", "tah"),
+    ("ug", "uig", "uig"),
+    ("uk", "ukr", "ukr"),
+    ("ur", "urd", "urd"),
+    ("uz", "uzb", "uzb"),
+    ("ve", "ven", "ven"),
+    ("vi", "vie", "vie"),
+    ("vo", "vol", "vol"),
+    ("wa", "wln", "wln"),
+    ("wo", "wol", "wol"),
+    ("xh", "xho", "xho"),
+    ("yi", "yid", "yid"),
+    ("yo", "yor", "yor"),
+    ("za", "zha", "zha"),
+    ("zh", "zho", "chi"),
+    ("zu", "zul", "zul"),
+];
@@ -0,0 +1,242 @@
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{LanguageCode, Region, LANGUAGE_CODES};
+
+/// A continental region of the world, as defined by the
+/// [UN M.49](https://unstats.un.org/unsd/methodology/m49/) standard.
+///
+/// # Examples
+///
+/// ```
+/// use isolanguage_1::{LanguageCode, Region, WorldRegion};
+///
+/// assert_eq!(LanguageCode::Pt.regions().len(), 4);
+/// assert_eq!(WorldRegion::containing(Region::from_const("BR")), Some(WorldRegion::Americas));
+/// assert!(LanguageCode::languages_in_region(WorldRegion::Oceania).any(|l| l == LanguageCode::Mi));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum WorldRegion {
+    /// Africa, M.49 area `002`.
+    Africa,
+    /// North, Central and South America and the Caribbean, M.49 area `019`.
+    Americas,
+    /// Asia, M.49 area `142`.
+    Asia,
+    /// Europe, M.49 area `150`.
+    Europe,
+    /// Oceania, M.49 area `009`.
+    Oceania,
+}
+
+impl WorldRegion {
+    /// Every world region.
+    pub const ALL: [Self; 5] = [
+        Self::Africa,
+        Self::Americas,
+        Self::Asia,
+        Self::Europe,
+        Self::Oceania,
+    ];
+
+    /// Returns the English name of the region.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Africa => "Africa",
+            Self::Americas => "Americas",
+            Self::Asia => "Asia",
+            Self::Europe => "Europe",
+            Self::Oceania => "Oceania",
+        }
+    }
+
+    /// Returns the 3 digit M.49 area code of the region.
+    #[must_use]
+    pub const fn m49_code(self) -> &'static str {
+        match self {
+            Self::Africa => "002",
+            Self::Americas => "019",
+            Self::Asia => "142",
+            Self::Europe => "150",
+            Self::Oceania => "009",
+        }
+    }
+
+    /// Returns the world region containing a country, or `None` for Antarctica and area codes.
+    #[must_use]
+    #[rustfmt::skip]
+    pub fn containing(region: Region) -> Option<Self> {
+        match region.as_str().as_bytes() {
+            b"AO" | b"BF" | b"BI" | b"BJ" | b"BW" | b"CD" | b"CF" | b"CG" | b"CI" | b"CM" | b"CV" | b"DJ" | b"DZ" | b"EG" | b"EH" | b"ER" | b"ET" | b"GA" | b"GH" | b"GM" | b"GN" | b"GQ" | b"GW" | b"IO" | b"KE" | b"KM" | b"LR" | b"LS" | b"LY" | b"MA" | b"MG" | b"ML" | b"MR" | b"MU" | b"MW" | b"MZ" | b"NA" | b"NE" | b"NG" | b"RE" | b"RW" | b"SC" | b"SD" | b"SH" | b"SL" | b"SN" | b"SO" | b"SS" | b"ST" | b"SZ" | b"TD" | b"TF" | b"TG" | b"TN" | b"TZ" | b"UG" | b"YT" | b"ZA" | b"ZM" | b"ZW" => Some(Self::Africa),
+            b"AG" | b"AI" | b"AR" | b"AW" | b"BB" | b"BL" | b"BM" | b"BO" | b"BQ" | b"BR" | b"BS" | b"BV" | b"BZ" | b"CA" | b"CL" | b"CO" | b"CR" | b"CU" | b"CW" | b"DM" | b"DO" | b"EC" | b"FK" | b"GD" | b"GF" | b"GL" | b"GP" | b"GS" | b"GT" | b"GY" | b"HN" | b"HT" | b"JM" | b"KN" | b"KY" | b"LC" | b"MF" | b"MQ" | b"MS" | b"MX" | b"NI" | b"PA" | b"PE" | b"PM" | b"PR" | b"PY" | b"SR" | b"SV" | b"SX" | b"TC" | b"TT" | b"US" | b"UY" | b"VC" | b"VE" | b"VG" | b"VI" => Some(Self::Americas),
+            b"AE" | b"AF" | b"AM" | b"AZ" | b"BD" | b"BH" | b"BN" | b"BT" | b"CN" | b"CY" | b"GE" | b"HK" | b"ID" | b"IL" | b"IN" | b"IQ" | b"IR" | b"JO" | b"JP" | b"KG" | b"KH" | b"KP" | b"KR" | b"KW" | b"KZ" | b"LA" | b"LB" | b"LK" | b"MM" | b"MN" | b"MO" | b"MV" | b"MY" | b"NP" | b"OM" | b"PH" | b"PK" | b"PS" | b"QA" | b"SA" | b"SG" | b"SY" | b"TH" | b"TJ" | b"TL" | b"TM" | b"TR" | b"TW" | b"UZ" | b"VN" | b"YE" => Some(Self::Asia),
+            b"AD" | b"AL" | b"AT" | b"AX" | b"BA" | b"BE" | b"BG" | b"BY" | b"CH" | b"CZ" | b"DE" | b"DK" | b"EE" | b"ES" | b"FI" | b"FO" | b"FR" | b"GB" | b"GG" | b"GI" | b"GR" | b"HR" | b"HU" | b"IE" | b"IM" | b"IS" | b"IT" | b"JE" | b"LI" | b"LT" | b"LU" | b"LV" | b"MC" | b"MD" | b"ME" | b"MK" | b"MT" | b"NL" | b"NO" | b"PL" | b"PT" | b"RO" | b"RS" | b"RU" | b"SE" | b"SI" | b"SJ" | b"SK" | b"SM" | b"UA" | b"VA" | b"XK" => Some(Self::Europe),
+            b"AS" | b"AU" | b"CC" | b"CK" | b"CX" | b"FJ" | b"FM" | b"GU" | b"HM" | b"KI" | b"MH" | b"MP" | b"NC" | b"NF" | b"NR" | b"NU" | b"NZ" | b"PF" | b"PG" | b"PN" | b"PW" | b"SB" | b"TK" | b"TO" | b"TV" | b"UM" | b"VU" | b"WF" | b"WS" => Some(Self::Oceania),
+            _ => None,
+        }
+    }
+}
+
+impl Display for WorldRegion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl LanguageCode {
+    /// Returns the world regions where the language is predominantly spoken, in the order of
+    /// [`WorldRegion::ALL`].
+    ///
+    /// These are the regions containing the language's home country according to CLDR, and the
+    /// countries where it is the most likely language or has a glibc locale. Constructed languages
+    /// like Esperanto have no regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, WorldRegion};
+    ///
+    /// assert_eq!(LanguageCode::Ja.regions(), [WorldRegion::Asia]);
+    /// assert!(LanguageCode::Eo.regions().is_empty());
+    /// ```
+    #[must_use]
+    #[rustfmt::skip]
+    pub const fn regions(self) -> &'static [WorldRegion] {
+        match self {
+            Self::Eo | Self::Ia | Self::Ie | Self::Io | Self::Vo => &[],
+            Self::Aa | Self::Af | Self::Ak | Self::Am | Self::Bm | Self::Ee | Self::Ff | Self::Ha | Self::Hz | Self::Ig | Self::Kg | Self::Ki | Self::Kj | Self::Kr | Self::Lg | Self::Ln | Self::Lu | Self::Mg | Self::Nd | Self::Ng | Self::Nr | Self::Ny | Self::Om | Self::Rn | Self::Rw | Self::Sg | Self::Sn | Self::So | Self::Ss | Self::St | Self::Sw | Self::Ti | Self::Tn | Self::Ts | Self::Tw | Self::Ve | Self::Wo | Self::Xh | Self::Yo | Self::Zu => &[WorldRegion::Africa],
+            Self::Ay | Self::Cr | Self::Gn | Self::Ht | Self::Ik | Self::Iu | Self::Kl | Self::Nv | Self::Oj | Self::Qu => &[WorldRegion::Americas],
+            Self::Ab | Self::Ae | Self::As | Self::Az | Self::Bh | Self::Bn | Self::Bo | Self::Dv | Self::Dz | Self::Fa | Self::Gu | Self::He | Self::Hi | Self::Hy | Self::Id | Self::Ii | Self::Ja | Self::Jv | Self::Ka | Self::Kk | Self::Km | Self::Kn | Self::Ko | Self::Ks | Self::Ku | Self::Ky | Self::Lo | Self::Ml | Self::Mn | Self::Mr | Self::My | Self::Ne | Self::Or | Self::Pa | Self::Pi | Self::Ps | Self::Sa | Self::Sd | Self::Si | Self::Su | Self::Ta | Self::Te | Self::Tg | Self::Th | Self::Tk | Self::Tl | Self::Tr | Self::Ug | Self::Ur | Self::Uz | Self::Vi | Self::Za | Self::Zh => &[WorldRegion::Asia],
+            Self::An | Self::Av | Self::Ba | Self::Be | Self::Bg | Self::Br | Self::Bs | Self::Ca | Self::Ce | Self::Co | Self::Cs | Self::Cu | Self::Cv | Self::Cy | Self::Da | Self::De | Self::Et | Self::Eu | Self::Fi | Self::Fo | Self::Fy | Self::Ga | Self::Gd | Self::Gl | Self::Gv | Self::Hr | Self::Hu | Self::Is | Self::It | Self::Kv | Self::Kw | Self::La | Self::Lb | Self::Li | Self::Lt | Self::Lv | Self::Mk | Self::Mt | Self::Nb | Self::Nn | Self::No | Self::Oc | Self::Pl | Self::Rm | Self::Ro | Self::Sc | Self::Se | Self::Sk | Self::Sl | Self::Sq | Self::Sr | Self::Sv | Self::Tt | Self::Uk | Self::Wa => &[WorldRegion::Europe],
+            Self::Bi | Self::Ch | Self::Fj | Self::Ho | Self::Mh | Self::Mi | Self::Na | Self::Sm | Self::To | Self::Ty => &[WorldRegion::Oceania],
+            Self::Ar => &[WorldRegion::Africa, WorldRegion::Asia],
+            Self::Nl | Self::Yi => &[WorldRegion::Americas, WorldRegion::Europe],
+            Self::El | Self::Os | Self::Ru => &[WorldRegion::Asia, WorldRegion::Europe],
+            Self::Ms => &[WorldRegion::Asia, WorldRegion::Oceania],
+            Self::Es => &[WorldRegion::Africa, WorldRegion::Americas, WorldRegion::Europe],
+            Self::Pt => &[WorldRegion::Africa, WorldRegion::Americas, WorldRegion::Asia, WorldRegion::Europe],
+            Self::Fr => &[WorldRegion::Africa, WorldRegion::Americas, WorldRegion::Europe, WorldRegion::Oceania],
+            Self::En => &[WorldRegion::Africa, WorldRegion::Americas, WorldRegion::Asia, WorldRegion::Europe, WorldRegion::Oceania],
+        }
+    }
+
+    /// Returns the languages predominantly spoken in a world region, in the order of
+    /// [`LANGUAGE_CODES`].
+    pub fn languages_in_region(region: WorldRegion) -> impl Iterator<Item = Self> {
+        LANGUAGE_CODES
+            .into_iter()
+            .filter(move |language| language.regions().contains(&region))
+    }
+
+    /// Returns the single world region the language originates from, for grouping languages by
+    /// continent in a picker.
+    ///
+    /// For languages spoken in several [regions](Self::regions), this is the region they spread
+    /// from, so English, Spanish and Portuguese are European and Arabic is Asian. Constructed
+    /// languages return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, WorldRegion};
+    ///
+    /// assert_eq!(LanguageCode::Pt.continent(), Some(WorldRegion::Europe));
+    /// assert_eq!(LanguageCode::Sw.continent(), Some(WorldRegion::Africa));
+    /// assert_eq!(LanguageCode::Eo.continent(), None);
+    /// ```
+    #[must_use]
+    pub const fn continent(self) -> Option<WorldRegion> {
+        match self {
+            Self::Ar | Self::Ms => Some(WorldRegion::Asia),
+            Self::El
+            | Self::En
+            | Self::Es
+            | Self::Fr
+            | Self::Nl
+            | Self::Os
+            | Self::Pt
+            | Self::Ru
+            | Self::Yi => Some(WorldRegion::Europe),
+            _ => match self.regions() {
+                [region] => Some(*region),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the languages originating from a world region, in the order of
+    /// [`LANGUAGE_CODES`]. Unlike [`languages_in_region`](Self::languages_in_region), every
+    /// language is in at most one region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use isolanguage_1::{LanguageCode, WorldRegion};
+    ///
+    /// let european: Vec<_> = LanguageCode::languages_from(WorldRegion::Europe).collect();
+    /// assert!(european.contains(&LanguageCode::En));
+    /// assert!(!european.contains(&LanguageCode::Ar));
+    /// ```
+    pub fn languages_from(region: WorldRegion) -> impl Iterator<Item = Self> {
+        LANGUAGE_CODES
+            .into_iter()
+            .filter(move |language| language.continent() == Some(region))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LanguageCode, Region, WorldRegion, LANGUAGE_CODES};
+
+    #[test]
+    fn regions() {
+        for language in LANGUAGE_CODES {
+            let regions = language.regions();
+            assert!(regions.windows(2).all(|w| w[0] < w[1]), "{:?}", language);
+        }
+        assert_eq!(LanguageCode::En.regions(), WorldRegion::ALL,);
+        assert_eq!(
+            LanguageCode::Es.regions(),
+            [
+                WorldRegion::Africa,
+                WorldRegion::Americas,
+                WorldRegion::Europe
+            ]
+        );
+        assert_eq!(
+            LanguageCode::languages_in_region(WorldRegion::Americas).count(),
+            16
+        );
+    }
+
+    #[test]
+    fn containing() {
+        assert_eq!(
+            WorldRegion::containing(Region::from_const("tr")),
+            Some(WorldRegion::Asia)
+        );
+        assert_eq!(WorldRegion::containing(Region::from_const("AQ")), None);
+        assert_eq!(WorldRegion::containing(Region::from_const("419")), None);
+        assert_eq!(WorldRegion::Oceania.m49_code(), "009");
+    }
+
+    #[test]
+    fn continents() {
+        for language in LANGUAGE_CODES {
+            match language.continent() {
+                Some(continent) => assert!(language.regions().contains(&continent)),
+                None => assert!(language.regions().is_empty()),
+            }
+        }
+        let total: usize = WorldRegion::ALL
+            .into_iter()
+            .map(|region| LanguageCode::languages_from(region).count())
+            .sum();
+        assert_eq!(total, 184 - 5);
+    }
+}
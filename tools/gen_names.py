@@ -0,0 +1,110 @@
+#!/usr/bin/env python3
+"""Generates src/names/data.rs from the CLDR language display names baked into ICU4X.
+
+The names come from the `icu_experimental_data` crate, version 0.1.1, which contains the CLDR 45
+display names. Download and unpack it, then run from the repository root:
+
+    curl -L https://crates.io/api/v1/crates/icu_experimental_data/0.1.1/download | tar -xzC /tmp
+    python3 tools/gen_names.py /tmp/icu_experimental_data-0.1.1 > src/names/data.rs
+
+Only display languages with names for at least 100 of the crate's languages are included.
+"""
+
+import re
+import struct
+import sys
+import unicodedata
+
+CLDR_VERSION = 45
+DATA_FILE = "data/macros/displaynames_languages_v1.rs.data"
+
+
+def language_codes():
+    """Returns the crate's 2 letter codes in the order of `LANGUAGE_CODES`."""
+    lib = open("src/lib.rs", encoding="utf-8").read()
+    table = lib[lib.index("languages_table! {"):]
+    return re.findall(r'^\s*\(\w+, "([a-z]{2})", ', table, re.M)
+
+
+def rust_bytes(literal):
+    """Decodes the contents of a Rust byte string literal."""
+    escapes = {"0": 0, "n": 10, "r": 13, "t": 9, "\\": 92, '"': 34, "'": 39}
+    out = bytearray()
+    i = 0
+    while i < len(literal):
+        c = literal[i]
+        if c == "\\":
+            n = literal[i + 1]
+            if n == "x":
+                out.append(int(literal[i + 2 : i + 4], 16))
+                i += 4
+            else:
+                out.append(escapes[n])
+                i += 2
+            continue
+        out += c.encode("utf-8")
+        i += 1
+    return bytes(out)
+
+
+def var_zero_vec(data):
+    """Decodes a `VarZeroVec<str>` with 16 bit indices."""
+    (n,) = struct.unpack("<I", data[:4])
+    indices = [struct.unpack("<H", data[4 + 2 * i : 6 + 2 * i])[0] for i in range(n)]
+    strings = data[4 + 2 * n :]
+    indices.append(len(strings))
+    return [strings[indices[i] : indices[i + 1]].decode("utf-8") for i in range(n)]
+
+
+def display_names(crate_dir):
+    """Returns a map from display locale, like `DE`, to a map from language code to name."""
+    text = open(f"{crate_dir}/{DATA_FILE}", encoding="utf-8").read()
+    names = {}
+    for m in re.finditer(r"static ([A-Z_0-9]+): <[^=]*= (.*?)\n                };", text, re.S):
+        literals = re.findall(r'b"((?:[^"\\]|\\.)*)"', m.group(2))
+        keys = rust_bytes(literals[0])
+        values = var_zero_vec(rust_bytes(literals[1]))
+        codes = [keys[i : i + 3].rstrip(b"\0").decode() for i in range(0, len(keys), 3)]
+        assert len(codes) == len(values), m.group(1)
+        names[m.group(1)] = dict(zip(codes, values))
+    return names
+
+
+def literal(s):
+    s = s.replace("\\", "\\\\").replace('"', '\\"')
+    s = "".join("\\u{%X}" % ord(c) if unicodedata.category(c) == "Cf" else c for c in s)
+    return f'"{s}"'
+
+
+def main():
+    codes = language_codes()
+    names = display_names(sys.argv[1])
+    displays = [
+        code.upper()
+        for code in codes
+        if code.upper() in names and sum(c in names[code.upper()] for c in codes) >= 100
+    ]
+
+    out = [
+        f"// Generated from the CLDR {CLDR_VERSION} language display names in icu_experimental_data"
+        " 0.1.1 by",
+        "// tools/gen_names.py, which describes how to regenerate it. Do not edit by hand.",
+        "",
+        "use crate::LanguageCode;",
+        "",
+        "/// Returns the names of every language in the display language, in the order of",
+        "/// `LANGUAGE_CODES`. Missing names are empty.",
+        f"pub(super) const fn names(display: LanguageCode) -> Option<&'static [&'static str; {len(codes)}]> {{",
+        "    Some(match display {",
+    ]
+    out += [f"        LanguageCode::{d[0]}{d[1].lower()} => &{d}," for d in displays]
+    out += ["        _ => return None,", "    })", "}"]
+    for display in displays:
+        out += ["", f"static {display}: [&str; {len(codes)}] = ["]
+        out += [f"    {literal(names[display].get(code, ''))}," for code in codes]
+        out.append("];")
+    sys.stdout.write("\n".join(out) + "\n")
+
+
+if __name__ == "__main__":
+    main()